@@ -22,6 +22,11 @@ normalized and rounded perfectly, i.e., within 0.5 ULP of the true value.
 The representation ([u64], [i16]) instead of the more natural [(u64, i16)]
 is used because (u64, i16) has a ton of padding which would make the table
 even larger, and it's already uncomfortably large (6 KiB).
+
+It also outputs the 128 bit approximations of powers of five used by the
+Eisel-Lemire algorithm, as (high, low) pairs of 64 bit words. These are
+truncated for nonnegative exponents, and rounded up for negative ones, as the
+algorithm expects.
 """
 from __future__ import print_function
 import sys
@@ -116,6 +121,8 @@ def main():
     print_short_powers(32, 24)
     print()
     print_short_powers(64, 53)
+    print()
+    print_powers_of_five_128()
 
 
 def print_proper_powers():
@@ -153,5 +160,43 @@ def print_short_powers(num_bits, significand_size):
     print("];")
 
 
+def print_powers_of_five_128():
+    # The smallest power of ten that can round to a non-zero f64, and the
+    # largest one that can be finite, for digits that fit into a u64.
+    MIN_Q = -342
+    MAX_Q = 308
+    powers = []
+    for q in range(MIN_Q, 0):
+        power5 = 5 ** -q
+        z = 0
+        while (1 << z) < power5:
+            z += 1
+        if q >= -27:
+            b = z + 127
+            c = 2 ** b // power5 + 1
+        else:
+            b = 2 * z + 2 * 64
+            c = 2 ** b // power5 + 1
+            while c >= (1 << 128):
+                c //= 2
+        powers.append((q, c))
+    for q in range(0, MAX_Q + 1):
+        power5 = 5 ** q
+        while power5 < (1 << 127):
+            power5 *= 2
+        while power5 >= (1 << 128):
+            power5 //= 2
+        powers.append((q, power5))
+    print("pub const SMALLEST_POWER_OF_FIVE: i16 = {};".format(MIN_Q))
+    print("pub const LARGEST_POWER_OF_FIVE: i16 = {};".format(MAX_Q))
+    print()
+    typ = "[(u64, u64); {}]".format(len(powers))
+    print("pub const POWERS_OF_FIVE_128: ", typ, " = [", sep='')
+    mask = (1 << 64) - 1
+    for q, c in powers:
+        print("    (0x{:016x}, 0x{:016x}), // 5^{}".format(c >> 64, c & mask, q))
+    print("];")
+
+
 if __name__ == '__main__':
     main()
@@ -71,6 +71,7 @@
 
 use boxed::Box;
 
+use core::any::Any;
 use core::sync::atomic;
 use core::sync::atomic::Ordering::{Acquire, Relaxed, Release, SeqCst};
 use core::borrow;
@@ -293,6 +294,58 @@ impl<T: ?Sized> Arc<T> {
         this.inner().strong.load(SeqCst)
     }
 
+    /// Returns a raw pointer to the value.
+    ///
+    /// The pointer is the same for all the `Arc`s and `Weak`s that share the
+    /// value, and does not change for as long as the allocation lives, so it
+    /// can be used to identify the value. It is valid to dereference for as
+    /// long as there is an `Arc` to the value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(arc_introspection)]
+    ///
+    /// use std::sync::Arc;
+    ///
+    /// let five = Arc::new(5);
+    /// let other_five = five.clone();
+    ///
+    /// assert_eq!(Arc::as_ptr(&five), Arc::as_ptr(&other_five));
+    /// assert_eq!(unsafe { *Arc::as_ptr(&five) }, 5);
+    /// ```
+    #[inline]
+    #[unstable(feature = "arc_introspection", issue = "0")]
+    pub fn as_ptr(this: &Self) -> *const T {
+        &this.inner().data
+    }
+
+    /// Returns the offset in bytes of the value from the start of the
+    /// allocation that holds it along with the reference counts.
+    ///
+    /// Subtracting this from `as_ptr` gives the address that the allocator
+    /// returned, so that memory profilers can attribute the allocation to
+    /// the `Arc`. The offset is the same for all values of a sized type.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(arc_introspection)]
+    ///
+    /// use std::mem;
+    /// use std::sync::Arc;
+    ///
+    /// let five = Arc::new(5u8);
+    ///
+    /// // the strong and the weak count come first
+    /// assert_eq!(Arc::data_offset(&five), 2 * mem::size_of::<usize>());
+    /// ```
+    #[inline]
+    #[unstable(feature = "arc_introspection", issue = "0")]
+    pub fn data_offset(this: &Self) -> usize {
+        Arc::as_ptr(this) as *const u8 as usize - *this.ptr as *const u8 as usize
+    }
+
     #[inline]
     fn inner(&self) -> &ArcInner<T> {
         // This unsafety is ok because while this arc is alive we're guaranteed
@@ -459,6 +512,36 @@ impl<T: Clone> Arc<T> {
     }
 }
 
+impl Arc<Any + Send + Sync> {
+    /// Attempts to downcast the `Arc<Any + Send + Sync>` to a concrete type,
+    /// returning the original `Arc` if it is not of that type.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(any_downcast)]
+    ///
+    /// use std::any::Any;
+    /// use std::sync::Arc;
+    ///
+    /// let value: Arc<Any + Send + Sync> = Arc::new(String::from("foo"));
+    /// let value = value.downcast::<i32>().unwrap_err();
+    /// assert_eq!(*value.downcast::<String>().unwrap(), "foo");
+    /// ```
+    #[inline]
+    #[unstable(feature = "any_downcast", issue = "0")]
+    pub fn downcast<T: Any + Send + Sync>(self) -> Result<Arc<T>, Self> {
+        if (*self).is::<T>() {
+            // the value is at the same offset in `ArcInner<T>`, so the counts are shared
+            let ptr = *self.ptr as *mut ArcInner<T>;
+            mem::forget(self);
+            Ok(Arc { ptr: unsafe { Shared::new(ptr) } })
+        } else {
+            Err(self)
+        }
+    }
+}
+
 impl<T: ?Sized> Arc<T> {
     /// Returns a mutable reference to the contained value if the `Arc<T>` has
     /// one strong reference and no weak references.
@@ -670,6 +753,36 @@ impl<T: ?Sized> Weak<T> {
         }
     }
 
+    /// Returns true if the two `Weak`s point to the same allocation, even if
+    /// the value in it has already been dropped.
+    ///
+    /// Two `Weak`s created by `Weak::new` never point to the same allocation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(weak_ptr_eq)]
+    ///
+    /// use std::sync::{Arc, Weak};
+    ///
+    /// let first = Arc::new(5);
+    /// let first_weak = Arc::downgrade(&first);
+    /// let second_weak = Arc::downgrade(&Arc::new(5));
+    ///
+    /// assert!(first_weak.ptr_eq(&Arc::downgrade(&first)));
+    /// assert!(!first_weak.ptr_eq(&second_weak));
+    /// drop(first);
+    /// assert!(first_weak.ptr_eq(&first_weak.clone()));
+    ///
+    /// let empty: Weak<i32> = Weak::new();
+    /// assert!(!empty.ptr_eq(&Weak::new()));
+    /// ```
+    #[inline]
+    #[unstable(feature = "weak_ptr_eq", issue = "0")]
+    pub fn ptr_eq(&self, other: &Weak<T>) -> bool {
+        *self.ptr as *const u8 == *other.ptr as *const u8
+    }
+
     #[inline]
     fn inner(&self) -> &ArcInner<T> {
         // See comments above for why this is "safe"
@@ -958,6 +1071,8 @@ mod tests {
     use super::{Arc, Weak};
     use std::sync::Mutex;
     use std::convert::From;
+    use std::any::Any;
+    use std::string::String;
 
     struct Canary(*mut atomic::AtomicUsize);
 
@@ -1194,11 +1309,54 @@ mod tests {
         assert!(123 == *foo_arc);
     }
 
+    #[test]
+    fn test_downcast() {
+        let x: Arc<Any + Send + Sync> = Arc::new(String::from("foo"));
+        let weak = Arc::downgrade(&x);
+        let x = x.downcast::<u32>().unwrap_err();
+        let y = x.clone().downcast::<String>().unwrap();
+        assert_eq!(*y, "foo");
+        assert_eq!(Arc::strong_count(&y), 2);
+        drop(x);
+        drop(y);
+        assert!(weak.upgrade().is_none());
+    }
+
     #[test]
     fn test_new_weak() {
         let foo: Weak<usize> = Weak::new();
         assert!(foo.upgrade().is_none());
     }
+
+    #[test]
+    fn test_as_ptr() {
+        let a = Arc::new(5u64);
+        let b = a.clone();
+        let w = Arc::downgrade(&a);
+        assert_eq!(Arc::as_ptr(&a), &*b as *const u64);
+        assert_eq!(Arc::as_ptr(&w.upgrade().unwrap()), Arc::as_ptr(&a));
+        assert_eq!(Arc::data_offset(&a), 2 * ::core::mem::size_of::<usize>());
+
+        // the data of an unsized value is also after the counts
+        let s: Arc<[u64]> = Arc::new([1, 2, 3]);
+        assert_eq!(Arc::as_ptr(&s) as *const u64, s.as_ptr());
+        assert_eq!(Arc::data_offset(&s), Arc::data_offset(&a));
+    }
+
+    #[test]
+    fn test_weak_ptr_eq() {
+        let a = Arc::new(0);
+        let w = Arc::downgrade(&a);
+        let x = w.clone();
+        assert!(w.ptr_eq(&x));
+        assert!(w.ptr_eq(&Arc::downgrade(&a.clone())));
+        assert!(!w.ptr_eq(&Arc::downgrade(&Arc::new(0))));
+        drop(a);
+        assert!(w.ptr_eq(&x));
+        let empty: Weak<i32> = Weak::new();
+        assert!(empty.ptr_eq(&empty.clone()));
+        assert!(!empty.ptr_eq(&Weak::new()));
+    }
 }
 
 #[stable(feature = "rust1", since = "1.0.0")]
@@ -60,6 +60,8 @@ use core::any::Any;
 use core::borrow;
 use core::cmp::Ordering;
 use core::fmt;
+use core::async_iter::AsyncIterator;
+use core::future::Future;
 use core::hash::{self, Hash};
 use core::marker::{self, Unsize};
 use core::mem;
@@ -67,6 +69,7 @@ use core::ops::{CoerceUnsized, Deref, DerefMut};
 use core::ops::{BoxPlace, Boxed, InPlace, Place, Placer};
 use core::ptr::{self, Unique};
 use core::raw::TraitObject;
+use core::task::{Context, Poll};
 use core::convert::From;
 
 /// A value that represents the heap. This is the default place that the `box`
@@ -395,6 +398,135 @@ impl<T> From<T> for Box<T> {
     }
 }
 
+impl<T> Box<[T]> {
+    /// Creates a boxed slice from the elements of an iterator whose length is
+    /// known up front, with a single allocation of exactly that length.
+    ///
+    /// Collecting into a `Vec` and converting it with `into_boxed_slice` can
+    /// allocate more than needed and then shrink; this allocates `len()`
+    /// elements once. Should the iterator produce fewer elements than it
+    /// reported, the allocation is shrunk to the elements it produced;
+    /// elements beyond the reported length are not consumed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(box_from_iter_exact)]
+    ///
+    /// let squares: Box<[u32]> = Box::from_iter_exact((1..5).map(|x| x * x));
+    /// assert_eq!(&*squares, &[1, 4, 9, 16]);
+    /// ```
+    #[unstable(feature = "box_from_iter_exact", issue = "0")]
+    pub fn from_iter_exact<I>(iter: I) -> Box<[T]>
+        where I: IntoIterator<Item = T>, I::IntoIter: ExactSizeIterator
+    {
+        // Drops the elements written so far if the iterator panics.
+        struct PartialSlice<T> {
+            buf: RawVec<T>,
+            len: usize,
+        }
+
+        impl<T> Drop for PartialSlice<T> {
+            fn drop(&mut self) {
+                unsafe {
+                    for i in 0..self.len {
+                        ptr::drop_in_place(self.buf.ptr().offset(i as isize));
+                    }
+                }
+            }
+        }
+
+        let mut iter = iter.into_iter();
+        let len = iter.len();
+        let mut partial = PartialSlice { buf: RawVec::with_capacity(len), len: 0 };
+        while partial.len < len {
+            match iter.next() {
+                Some(x) => unsafe {
+                    ptr::write(partial.buf.ptr().offset(partial.len as isize), x);
+                    partial.len += 1;
+                },
+                None => break,
+            }
+        }
+
+        unsafe {
+            let len = partial.len;
+            let mut buf = ptr::read(&partial.buf);
+            mem::forget(partial);
+            buf.shrink_to_fit(len);
+            buf.into_box()
+        }
+    }
+}
+
+impl Box<str> {
+    /// Formats `args` directly into a boxed string slice.
+    ///
+    /// Unlike `format!(...).into_boxed_str()`, which grows a `String` and
+    /// then shrinks it, this formats the arguments once to measure the
+    /// result and once more into an allocation of exactly that size. It is
+    /// a good fit for long-lived strings, where the extra formatting pass
+    /// is cheaper than the wasted capacity or the reallocation.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a formatting trait implementation returns an error, like
+    /// `format!`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(box_str_from_fmt)]
+    ///
+    /// let s: Box<str> = Box::str_from_fmt(format_args!("{}-{}", 1, "two"));
+    /// assert_eq!(&*s, "1-two");
+    /// ```
+    #[unstable(feature = "box_str_from_fmt", issue = "0")]
+    pub fn str_from_fmt(args: fmt::Arguments) -> Box<str> {
+        struct Measure(usize);
+
+        impl fmt::Write for Measure {
+            fn write_str(&mut self, s: &str) -> fmt::Result {
+                self.0 += s.len();
+                Ok(())
+            }
+        }
+
+        struct Writer {
+            buf: RawVec<u8>,
+            len: usize,
+        }
+
+        impl fmt::Write for Writer {
+            fn write_str(&mut self, s: &str) -> fmt::Result {
+                // Only grows if the second pass doesn't agree with the
+                // first, such as for a `Display` impl with side effects.
+                self.buf.reserve(self.len, s.len());
+                unsafe {
+                    ptr::copy_nonoverlapping(s.as_ptr(),
+                                             self.buf.ptr().offset(self.len as isize),
+                                             s.len());
+                }
+                self.len += s.len();
+                Ok(())
+            }
+        }
+
+        let mut measure = Measure(0);
+        fmt::write(&mut measure, args)
+            .expect("a formatting trait implementation returned an error");
+        let mut writer = Writer { buf: RawVec::with_capacity(measure.0), len: 0 };
+        fmt::write(&mut writer, args)
+            .expect("a formatting trait implementation returned an error");
+
+        unsafe {
+            let Writer { mut buf, len } = writer;
+            buf.shrink_to_fit(len);
+            mem::transmute(buf.into_box()) // bytes to str ~magic
+        }
+    }
+}
+
 impl Box<Any> {
     #[inline]
     #[stable(feature = "rust1", since = "1.0.0")]
@@ -413,6 +545,21 @@ impl Box<Any> {
             Err(self)
         }
     }
+
+    #[inline]
+    #[unstable(feature = "any_downcast", issue = "0")]
+    /// Downcasts the box to a concrete type, without checking that it is of
+    /// that type.
+    ///
+    /// # Safety
+    ///
+    /// The contained value must be of type `T`; `is::<T>()` must be true.
+    pub unsafe fn downcast_unchecked<T: Any>(self) -> Box<T> {
+        debug_assert!(self.is::<T>());
+        let raw = Box::into_raw(self);
+        let to: TraitObject = mem::transmute::<*mut Any, TraitObject>(raw);
+        Box::from_raw(to.data as *mut T)
+    }
 }
 
 impl Box<Any + Send> {
@@ -425,6 +572,32 @@ impl Box<Any + Send> {
             mem::transmute::<Box<Any>, Box<Any + Send>>(s)
         })
     }
+
+    #[inline]
+    #[unstable(feature = "any_downcast", issue = "0")]
+    /// Forwards to the method defined on the type `Box<Any>`.
+    pub unsafe fn downcast_unchecked<T: Any>(self) -> Box<T> {
+        <Box<Any>>::downcast_unchecked(self)
+    }
+}
+
+impl Box<Any + Send + Sync> {
+    #[inline]
+    #[unstable(feature = "any_downcast", issue = "0")]
+    /// Attempt to downcast the box to a concrete type.
+    pub fn downcast<T: Any>(self) -> Result<Box<T>, Box<Any + Send + Sync>> {
+        <Box<Any>>::downcast(self).map_err(|s| unsafe {
+            // reapply the Send and Sync markers
+            mem::transmute::<Box<Any>, Box<Any + Send + Sync>>(s)
+        })
+    }
+
+    #[inline]
+    #[unstable(feature = "any_downcast", issue = "0")]
+    /// Forwards to the method defined on the type `Box<Any>`.
+    pub unsafe fn downcast_unchecked<T: Any>(self) -> Box<T> {
+        <Box<Any>>::downcast_unchecked(self)
+    }
 }
 
 #[stable(feature = "rust1", since = "1.0.0")]
@@ -646,3 +819,25 @@ impl<T: ?Sized> AsMut<T> for Box<T> {
         &mut **self
     }
 }
+
+#[unstable(feature = "futures_api", issue = "0")]
+impl<F: ?Sized + Future> Future for Box<F> {
+    type Output = F::Output;
+
+    fn poll(&mut self, cx: &mut Context) -> Poll<F::Output> {
+        (**self).poll(cx)
+    }
+}
+
+#[unstable(feature = "async_iterator", issue = "0")]
+impl<I: ?Sized + AsyncIterator> AsyncIterator for Box<I> {
+    type Item = I::Item;
+
+    fn poll_next(&mut self, cx: &mut Context) -> Poll<Option<I::Item>> {
+        (**self).poll_next(cx)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (**self).size_hint()
+    }
+}
@@ -52,6 +52,18 @@ fn any_move() {
     assert!(b.downcast::<Box<i32>>().is_err());
 }
 
+#[test]
+fn any_send_sync_move() {
+    let a = Box::new(8) as Box<Any + Send + Sync>;
+    let a = a.downcast::<Test>().err().unwrap();
+    assert!(a.downcast::<i32>().unwrap() == Box::new(8));
+
+    let b = Box::new(Test) as Box<Any + Send>;
+    assert!(unsafe { b.downcast_unchecked::<Test>() } == Box::new(Test));
+    let c = Box::new(8) as Box<Any>;
+    assert!(unsafe { c.downcast_unchecked::<i32>() } == Box::new(8));
+}
+
 #[test]
 fn test_show() {
     let a = Box::new(8) as Box<Any>;
@@ -117,3 +129,108 @@ fn raw_trait() {
         assert_eq!(19, y.get());
     }
 }
+
+#[test]
+fn from_iter_exact() {
+    let b: Box<[u32]> = Box::from_iter_exact((1..5).map(|x| x * x));
+    assert_eq!(&*b, &[1, 4, 9, 16]);
+
+    let empty: Box<[u32]> = Box::from_iter_exact(None);
+    assert!(empty.is_empty());
+
+    let zst: Box<[()]> = Box::from_iter_exact(vec![(), (), ()]);
+    assert_eq!(zst.len(), 3);
+}
+
+#[test]
+fn from_iter_exact_wrong_len() {
+    // An iterator reporting more elements than it produces.
+    struct Short(u32);
+
+    impl Iterator for Short {
+        type Item = u32;
+
+        fn next(&mut self) -> Option<u32> {
+            if self.0 < 2 {
+                self.0 += 1;
+                Some(self.0)
+            } else {
+                None
+            }
+        }
+
+        fn size_hint(&self) -> (usize, Option<usize>) {
+            (5, Some(5))
+        }
+    }
+
+    impl ExactSizeIterator for Short {}
+
+    let b: Box<[u32]> = Box::from_iter_exact(Short(0));
+    assert_eq!(&*b, &[1, 2]);
+
+    // Elements past the reported length are left in the iterator.
+    let mut it = vec![1, 2, 3].into_iter();
+    let b: Box<[u32]> = Box::from_iter_exact(it.by_ref().take(2));
+    assert_eq!(&*b, &[1, 2]);
+    assert_eq!(it.next(), Some(3));
+}
+
+#[test]
+fn from_iter_exact_panic_drops_elements() {
+    use std::cell::Cell;
+    use std::panic;
+    use std::rc::Rc;
+
+    struct Counted(Rc<Cell<u32>>);
+
+    impl Drop for Counted {
+        fn drop(&mut self) {
+            self.0.set(self.0.get() + 1);
+        }
+    }
+
+    let drops = Rc::new(Cell::new(0));
+    let d = drops.clone();
+    let result = panic::catch_unwind(panic::AssertUnwindSafe(move || {
+        Box::from_iter_exact((0..4).map(|i| {
+            if i == 3 {
+                panic!("iterator panicked");
+            }
+            Counted(d.clone())
+        }))
+    }));
+    assert!(result.is_err());
+    assert_eq!(drops.get(), 3);
+}
+
+#[test]
+fn str_from_fmt() {
+    let s: Box<str> = Box::str_from_fmt(format_args!("{}-{}", 1, "two"));
+    assert_eq!(&*s, "1-two");
+
+    let empty: Box<str> = Box::str_from_fmt(format_args!(""));
+    assert_eq!(&*empty, "");
+}
+
+#[test]
+fn str_from_fmt_unstable_display() {
+    use core::fmt;
+    use std::cell::Cell;
+
+    // Displays as a longer string each time it is formatted.
+    struct Growing(Cell<usize>);
+
+    impl fmt::Display for Growing {
+        fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            self.0.set(self.0.get() + 1);
+            for _ in 0..self.0.get() {
+                try!(f.write_str("ab"));
+            }
+            Ok(())
+        }
+    }
+
+    let s: Box<str> = Box::str_from_fmt(format_args!("{}", Growing(Cell::new(0))));
+    assert_eq!(&*s, "abab");
+}
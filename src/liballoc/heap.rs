@@ -15,9 +15,10 @@
                       tracing garbage collector",
             issue = "27700")]
 
-use core::{isize, usize};
+use core::{isize, mem, usize};
 #[cfg(not(test))]
 use core::intrinsics::{min_align_of, size_of};
+use core::sync::atomic::{AtomicPtr, Ordering};
 
 #[allow(improper_ctypes)]
 extern "C" {
@@ -44,6 +45,110 @@ fn check_size_and_alignment(size: usize, align: usize) {
                   align);
 }
 
+/// An allocation made or released through this module, as reported to the
+/// allocation hook.
+///
+/// The sizes and alignments are the ones that were passed in, not the usable
+/// sizes.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum AllocEvent {
+    /// `allocate` returned `ptr`.
+    Allocate { ptr: *mut u8, size: usize, align: usize },
+    /// `reallocate` moved the allocation at `old_ptr` to `ptr`, or either it
+    /// or `reallocate_inplace` resized it in place, in which case both are
+    /// the same.
+    Reallocate { old_ptr: *mut u8, ptr: *mut u8, old_size: usize, size: usize, align: usize },
+    /// `deallocate` released `ptr`.
+    Deallocate { ptr: *mut u8, size: usize, align: usize },
+}
+
+/// A function that is called after every successful allocation,
+/// reallocation and deallocation, as set with `set_alloc_hook`.
+pub type AllocHook = fn(&AllocEvent);
+
+static ALLOC_HOOK: AtomicPtr<()> = AtomicPtr::new(0 as *mut ());
+
+/// Sets the hook that is called after every successful allocation,
+/// reallocation and deallocation, and returns the previous one.
+///
+/// This lets profilers and leak checkers observe the allocator without
+/// replacing it. Each hook is a plain function, so one that wants to chain
+/// onto the previous hook keeps it, like in an `AtomicPtr`, and calls it
+/// itself.
+///
+/// The hook runs on the thread that allocates, and must not panic. What it
+/// allocates and deallocates itself isn't reported to it, so it can record
+/// events in collections of its own. On targets without thread-local
+/// statics, it is called for those as well, and must not allocate.
+///
+/// # Examples
+///
+/// ```
+/// #![feature(heap_api)]
+///
+/// use std::heap::{self, AllocEvent};
+/// use std::sync::atomic::{AtomicUsize, ATOMIC_USIZE_INIT, Ordering};
+///
+/// static ALLOCATIONS: AtomicUsize = ATOMIC_USIZE_INIT;
+///
+/// fn count(event: &AllocEvent) {
+///     if let AllocEvent::Allocate { .. } = *event {
+///         ALLOCATIONS.fetch_add(1, Ordering::SeqCst);
+///     }
+/// }
+///
+/// heap::set_alloc_hook(count);
+/// let v = vec![1, 2, 3];
+/// heap::take_alloc_hook();
+///
+/// assert!(ALLOCATIONS.load(Ordering::SeqCst) >= 1);
+/// # drop(v);
+/// ```
+pub fn set_alloc_hook(hook: AllocHook) -> Option<AllocHook> {
+    to_hook(ALLOC_HOOK.swap(hook as *mut (), Ordering::AcqRel))
+}
+
+/// Removes the allocation hook and returns it, if there was one.
+pub fn take_alloc_hook() -> Option<AllocHook> {
+    to_hook(ALLOC_HOOK.swap(0 as *mut (), Ordering::AcqRel))
+}
+
+#[inline]
+fn alloc_hook() -> Option<AllocHook> {
+    to_hook(ALLOC_HOOK.load(Ordering::Acquire))
+}
+
+#[inline]
+fn to_hook(hook: *mut ()) -> Option<AllocHook> {
+    if hook.is_null() {
+        None
+    } else {
+        Some(unsafe { mem::transmute::<*mut (), AllocHook>(hook) })
+    }
+}
+
+// Set while the hook runs on this thread, so that it doesn't see its own
+// allocations, which would otherwise recurse.
+#[cfg(target_thread_local)]
+#[thread_local]
+static mut IN_ALLOC_HOOK: bool = false;
+
+#[cfg(target_thread_local)]
+fn call_alloc_hook(hook: AllocHook, event: &AllocEvent) {
+    unsafe {
+        if !IN_ALLOC_HOOK {
+            IN_ALLOC_HOOK = true;
+            hook(event);
+            IN_ALLOC_HOOK = false;
+        }
+    }
+}
+
+#[cfg(not(target_thread_local))]
+fn call_alloc_hook(hook: AllocHook, event: &AllocEvent) {
+    hook(event)
+}
+
 // FIXME: #13996: mark the `allocate` and `reallocate` return value as `noalias`
 
 /// Return a pointer to `size` bytes of memory aligned to `align`.
@@ -56,7 +161,13 @@ fn check_size_and_alignment(size: usize, align: usize) {
 #[inline]
 pub unsafe fn allocate(size: usize, align: usize) -> *mut u8 {
     check_size_and_alignment(size, align);
-    __rust_allocate(size, align)
+    let ptr = __rust_allocate(size, align);
+    if let Some(hook) = alloc_hook() {
+        if !ptr.is_null() {
+            call_alloc_hook(hook, &AllocEvent::Allocate { ptr: ptr, size: size, align: align });
+        }
+    }
+    ptr
 }
 
 /// Resize the allocation referenced by `ptr` to `size` bytes.
@@ -76,7 +187,19 @@ pub unsafe fn allocate(size: usize, align: usize) -> *mut u8 {
 #[inline]
 pub unsafe fn reallocate(ptr: *mut u8, old_size: usize, size: usize, align: usize) -> *mut u8 {
     check_size_and_alignment(size, align);
-    __rust_reallocate(ptr, old_size, size, align)
+    let new_ptr = __rust_reallocate(ptr, old_size, size, align);
+    if let Some(hook) = alloc_hook() {
+        if !new_ptr.is_null() {
+            call_alloc_hook(hook, &AllocEvent::Reallocate {
+                old_ptr: ptr,
+                ptr: new_ptr,
+                old_size: old_size,
+                size: size,
+                align: align,
+            });
+        }
+    }
+    new_ptr
 }
 
 /// Resize the allocation referenced by `ptr` to `size` bytes.
@@ -98,7 +221,19 @@ pub unsafe fn reallocate_inplace(ptr: *mut u8,
                                  align: usize)
                                  -> usize {
     check_size_and_alignment(size, align);
-    __rust_reallocate_inplace(ptr, old_size, size, align)
+    let usable = __rust_reallocate_inplace(ptr, old_size, size, align);
+    if let Some(hook) = alloc_hook() {
+        if usable == usable_size(size, align) {
+            call_alloc_hook(hook, &AllocEvent::Reallocate {
+                old_ptr: ptr,
+                ptr: ptr,
+                old_size: old_size,
+                size: size,
+                align: align,
+            });
+        }
+    }
+    usable
 }
 
 /// Deallocates the memory referenced by `ptr`.
@@ -110,7 +245,10 @@ pub unsafe fn reallocate_inplace(ptr: *mut u8,
 /// any value in range_inclusive(requested_size, usable_size).
 #[inline]
 pub unsafe fn deallocate(ptr: *mut u8, old_size: usize, align: usize) {
-    __rust_deallocate(ptr, old_size, align)
+    __rust_deallocate(ptr, old_size, align);
+    if let Some(hook) = alloc_hook() {
+        call_alloc_hook(hook, &AllocEvent::Deallocate { ptr: ptr, size: old_size, align: align });
+    }
 }
 
 /// Returns the usable size of an allocation created with the specified the
@@ -181,6 +319,46 @@ mod tests {
         }
     }
 
+    #[test]
+    fn alloc_hook() {
+        use core::sync::atomic::{AtomicUsize, ATOMIC_USIZE_INIT, Ordering};
+        use heap::AllocEvent;
+
+        // Other tests allocate at the same time, so only count an unusual size.
+        static EVENTS: AtomicUsize = ATOMIC_USIZE_INIT;
+        fn hook(event: &AllocEvent) {
+            let size = match *event {
+                AllocEvent::Allocate { size, .. } => size,
+                AllocEvent::Reallocate { old_size, .. } => old_size,
+                AllocEvent::Deallocate { size, .. } => size,
+            };
+            if size == 12345 {
+                EVENTS.fetch_add(1, Ordering::SeqCst);
+                // The hook doesn't see what it allocates itself.
+                if cfg!(target_thread_local) {
+                    unsafe { heap::deallocate(heap::allocate(12345, 64), 12345, 64) }
+                }
+            }
+        }
+
+        assert!(heap::set_alloc_hook(hook).is_none());
+        unsafe {
+            let ptr = heap::allocate(12345, 64);
+            assert!(!ptr.is_null());
+            let ptr = heap::reallocate(ptr, 12345, 23456, 64);
+            assert!(!ptr.is_null());
+            heap::deallocate(ptr, 23456, 64);
+            let ptr = heap::allocate(12345, 64);
+            heap::deallocate(ptr, 12345, 64);
+        }
+        assert!(heap::take_alloc_hook().is_some());
+        assert!(heap::take_alloc_hook().is_none());
+        unsafe {
+            heap::deallocate(heap::allocate(12345, 64), 12345, 64);
+        }
+        assert_eq!(EVENTS.load(Ordering::SeqCst), 4);
+    }
+
     #[bench]
     fn alloc_owned_small(b: &mut Bencher) {
         b.iter(|| {
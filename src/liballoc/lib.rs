@@ -73,26 +73,32 @@
 #![cfg_attr(not(stage0), deny(warnings))]
 
 #![feature(allocator)]
+#![feature(associated_consts)]
 #![feature(box_syntax)]
+#![feature(cfg_target_thread_local)]
 #![feature(coerce_unsized)]
 #![feature(const_fn)]
 #![feature(core_intrinsics)]
 #![feature(custom_attribute)]
 #![feature(dropck_parametricity)]
 #![feature(fundamental)]
+#![feature(async_iterator)]
+#![feature(futures_api)]
 #![feature(lang_items)]
+#![feature(local_waker)]
 #![feature(needs_allocator)]
 #![feature(optin_builtin_traits)]
 #![feature(placement_in_syntax)]
 #![feature(shared)]
 #![feature(staged_api)]
+#![feature(thread_local)]
 #![feature(unboxed_closures)]
 #![feature(unique)]
 #![feature(unsafe_no_drop_flag, filling_drop)]
 #![feature(unsize)]
 
 #![cfg_attr(not(test), feature(raw, fn_traits, placement_new_protocol))]
-#![cfg_attr(test, feature(test, box_heap))]
+#![cfg_attr(test, feature(test, box_heap, box_from_iter_exact, box_str_from_fmt))]
 
 // Allow testing this library
 
@@ -122,5 +128,6 @@ pub mod arc;
 pub mod rc;
 pub mod raw_vec;
 pub mod oom;
+pub mod task;
 
 pub use oom::oom;
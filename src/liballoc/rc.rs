@@ -156,6 +156,7 @@ use boxed::Box;
 #[cfg(test)]
 use std::boxed::Box;
 
+use core::any::Any;
 use core::borrow;
 use core::cell::Cell;
 use core::cmp::Ordering;
@@ -355,6 +356,36 @@ impl<T: ?Sized> Rc<T> {
     }
 }
 
+impl Rc<Any> {
+    /// Attempts to downcast the `Rc<Any>` to a concrete type, returning the
+    /// original `Rc` if it is not of that type.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(any_downcast)]
+    ///
+    /// use std::any::Any;
+    /// use std::rc::Rc;
+    ///
+    /// let value: Rc<Any> = Rc::new(5u8);
+    /// let value = value.downcast::<i8>().unwrap_err();
+    /// assert_eq!(*value.downcast::<u8>().unwrap(), 5);
+    /// ```
+    #[inline]
+    #[unstable(feature = "any_downcast", issue = "0")]
+    pub fn downcast<T: Any>(self) -> Result<Rc<T>, Rc<Any>> {
+        if (*self).is::<T>() {
+            // the value is at the same offset in `RcBox<T>`, so the counts are shared
+            let ptr = *self.ptr as *mut RcBox<T>;
+            forget(self);
+            Ok(Rc { ptr: unsafe { Shared::new(ptr) } })
+        } else {
+            Err(self)
+        }
+    }
+}
+
 impl<T: Clone> Rc<T> {
     /// Make a mutable reference into the given `Rc<T>` by cloning the inner
     /// data if the `Rc<T>` doesn't have one strong reference and no weak
@@ -941,6 +972,7 @@ mod tests {
     use std::mem::drop;
     use std::clone::Clone;
     use std::convert::From;
+    use std::any::Any;
 
     #[test]
     fn test_clone() {
@@ -1151,6 +1183,19 @@ mod tests {
         assert!(123 == *foo_rc);
     }
 
+    #[test]
+    fn test_downcast() {
+        let x: Rc<Any> = Rc::new(RefCell::new(5));
+        let weak = Rc::downgrade(&x);
+        let x = x.downcast::<i32>().unwrap_err();
+        let y = x.clone().downcast::<RefCell<i32>>().unwrap();
+        *y.borrow_mut() = 6;
+        assert_eq!(Rc::strong_count(&y), 2);
+        drop(y);
+        assert_eq!(*x.downcast::<RefCell<i32>>().unwrap().borrow(), 6);
+        assert!(weak.upgrade().is_none());
+    }
+
     #[test]
     fn test_new_weak() {
         let foo: Weak<usize> = Weak::new();
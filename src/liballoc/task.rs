@@ -0,0 +1,182 @@
+// Copyright 2016 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Wakers backed by reference-counted tasks.
+//!
+//! Implementing [`Wake`] for a type shared through an `Arc` is enough to
+//! turn it into a `Waker`, without writing a `RawWakerVTable` by hand;
+//! [`LocalWake`] does the same for an `Rc` and a `LocalWaker`.
+//!
+//! [`Wake`]: trait.Wake.html
+//! [`LocalWake`]: trait.LocalWake.html
+
+#![unstable(feature = "futures_api", issue = "0")]
+
+use core::convert::From;
+use core::mem;
+use core::task::{LocalWaker, RawWaker, RawWakerVTable, Waker};
+
+use arc::Arc;
+use rc::Rc;
+
+/// The implementation of waking a task on an executor.
+///
+/// A `Waker` can be created from an `Arc<W>` for any `W: Wake`. The
+/// `Waker` keeps a reference to the `Arc`, and calls `wake` on it whenever
+/// it is woken.
+///
+/// # Examples
+///
+/// ```
+/// #![feature(futures_api)]
+///
+/// use std::sync::Arc;
+/// use std::sync::atomic::{AtomicUsize, Ordering};
+/// use std::task::{Wake, Waker};
+///
+/// struct CountingWaker(AtomicUsize);
+///
+/// impl Wake for CountingWaker {
+///     fn wake(&self) {
+///         self.0.fetch_add(1, Ordering::SeqCst);
+///     }
+/// }
+///
+/// let count = Arc::new(CountingWaker(AtomicUsize::new(0)));
+/// let waker = Waker::from(count.clone());
+/// waker.wake_by_ref();
+/// waker.clone().wake();
+/// assert_eq!(count.0.load(Ordering::SeqCst), 2);
+/// ```
+pub trait Wake: Send + Sync {
+    /// Wakes this task.
+    fn wake(&self);
+}
+
+/// The implementation of waking a task that never leaves its thread.
+///
+/// This is the `Rc` counterpart of [`Wake`](trait.Wake.html): a
+/// `LocalWaker` can be created from an `Rc<W>` for any `W: LocalWake`.
+#[unstable(feature = "local_waker", issue = "0")]
+pub trait LocalWake {
+    /// Wakes this task.
+    fn wake(&self);
+}
+
+// Every type implementing `Wake` gets a vtable of its own, so that the data
+// pointer can be the `Arc<W>` that the `Waker` is created from: creating the
+// `Waker` doesn't allocate, and cloning it only bumps the reference count.
+trait ArcWakerVTable {
+    const VTABLE: &'static RawWakerVTable;
+}
+
+impl<W: Wake + 'static> ArcWakerVTable for W {
+    const VTABLE: &'static RawWakerVTable = &RawWakerVTable::new(arc_waker_clone::<W>,
+                                                                 arc_waker_wake::<W>,
+                                                                 arc_waker_wake_by_ref::<W>,
+                                                                 arc_waker_drop::<W>);
+}
+
+// An `Arc` of a sized type is a single pointer, which can be stored as the
+// data pointer and turned back into the `Arc` it came from.
+unsafe fn arc_waker_data<W>(waker: Arc<W>) -> *const () {
+    mem::transmute(waker)
+}
+
+unsafe fn arc_waker_from_data<W>(data: *const ()) -> Arc<W> {
+    mem::transmute(data)
+}
+
+unsafe fn arc_waker_clone<W: Wake + 'static>(data: *const ()) -> RawWaker {
+    let waker = arc_waker_from_data::<W>(data);
+    let clone = waker.clone();
+    mem::forget(waker);
+    RawWaker::new(arc_waker_data(clone), <W as ArcWakerVTable>::VTABLE)
+}
+
+unsafe fn arc_waker_wake<W: Wake>(data: *const ()) {
+    arc_waker_from_data::<W>(data).wake();
+}
+
+unsafe fn arc_waker_wake_by_ref<W: Wake>(data: *const ()) {
+    let waker = arc_waker_from_data::<W>(data);
+    waker.wake();
+    mem::forget(waker);
+}
+
+unsafe fn arc_waker_drop<W>(data: *const ()) {
+    drop(arc_waker_from_data::<W>(data));
+}
+
+impl<W: Wake + 'static> From<Arc<W>> for Waker {
+    /// Uses a `Wake`-able type as a `Waker`.
+    ///
+    /// This doesn't allocate: the `Waker` holds on to `waker` itself.
+    fn from(waker: Arc<W>) -> Waker {
+        unsafe {
+            let vtable = <W as ArcWakerVTable>::VTABLE;
+            Waker::from_raw(RawWaker::new(arc_waker_data(waker), vtable))
+        }
+    }
+}
+
+// The same goes for `LocalWake` and `Rc`.
+trait RcWakerVTable {
+    const VTABLE: &'static RawWakerVTable;
+}
+
+impl<W: LocalWake + 'static> RcWakerVTable for W {
+    const VTABLE: &'static RawWakerVTable = &RawWakerVTable::new(rc_waker_clone::<W>,
+                                                                 rc_waker_wake::<W>,
+                                                                 rc_waker_wake_by_ref::<W>,
+                                                                 rc_waker_drop::<W>);
+}
+
+unsafe fn rc_waker_data<W>(waker: Rc<W>) -> *const () {
+    mem::transmute(waker)
+}
+
+unsafe fn rc_waker_from_data<W>(data: *const ()) -> Rc<W> {
+    mem::transmute(data)
+}
+
+unsafe fn rc_waker_clone<W: LocalWake + 'static>(data: *const ()) -> RawWaker {
+    let waker = rc_waker_from_data::<W>(data);
+    let clone = waker.clone();
+    mem::forget(waker);
+    RawWaker::new(rc_waker_data(clone), <W as RcWakerVTable>::VTABLE)
+}
+
+unsafe fn rc_waker_wake<W: LocalWake>(data: *const ()) {
+    rc_waker_from_data::<W>(data).wake();
+}
+
+unsafe fn rc_waker_wake_by_ref<W: LocalWake>(data: *const ()) {
+    let waker = rc_waker_from_data::<W>(data);
+    waker.wake();
+    mem::forget(waker);
+}
+
+unsafe fn rc_waker_drop<W>(data: *const ()) {
+    drop(rc_waker_from_data::<W>(data));
+}
+
+#[unstable(feature = "local_waker", issue = "0")]
+impl<W: LocalWake + 'static> From<Rc<W>> for LocalWaker {
+    /// Uses a `LocalWake`-able type as a `LocalWaker`.
+    ///
+    /// This doesn't allocate: the `LocalWaker` holds on to `waker` itself.
+    fn from(waker: Rc<W>) -> LocalWaker {
+        unsafe {
+            let vtable = <W as RcWakerVTable>::VTABLE;
+            LocalWaker::from_raw(RawWaker::new(rc_waker_data(waker), vtable))
+        }
+    }
+}
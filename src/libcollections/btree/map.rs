@@ -1580,12 +1580,22 @@ impl<K: Ord, V> Extend<(K, V)> for BTreeMap<K, V> {
             self.insert(k, v);
         }
     }
+
+    #[inline]
+    fn extend_one(&mut self, (k, v): (K, V)) {
+        self.insert(k, v);
+    }
 }
 
 impl<'a, K: Ord + Copy, V: Copy> Extend<(&'a K, &'a V)> for BTreeMap<K, V> {
     fn extend<I: IntoIterator<Item = (&'a K, &'a V)>>(&mut self, iter: I) {
         self.extend(iter.into_iter().map(|(&key, &value)| (key, value)));
     }
+
+    #[inline]
+    fn extend_one(&mut self, (&k, &v): (&'a K, &'a V)) {
+        self.insert(k, v);
+    }
 }
 
 impl<K: Hash, V: Hash> Hash for BTreeMap<K, V> {
@@ -1875,6 +1885,63 @@ impl<'a, K: Ord, V> Entry<'a, K, V> {
         }
     }
 
+    /// Ensures a value is in the entry by inserting the result of the default function if empty,
+    /// and returns a mutable reference to the value in the entry.
+    ///
+    /// The function is given a reference to the key, so that the key does not need to be
+    /// cloned beforehand to compute the value from it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(entry_or_insert_with_key)]
+    /// use std::collections::BTreeMap;
+    ///
+    /// let mut lengths = BTreeMap::new();
+    /// for word in "btree map".split(' ') {
+    ///     lengths.entry(word).or_insert_with_key(|w| w.len());
+    /// }
+    /// assert_eq!(lengths["btree"], 5);
+    /// assert_eq!(lengths["map"], 3);
+    /// ```
+    #[unstable(feature = "entry_or_insert_with_key", issue = "0")]
+    pub fn or_insert_with_key<F: FnOnce(&K) -> V>(self, default: F) -> &'a mut V {
+        match self {
+            Occupied(entry) => entry.into_mut(),
+            Vacant(entry) => {
+                let value = default(entry.key());
+                entry.insert(value)
+            }
+        }
+    }
+
+    /// Calls `f` with a mutable reference to the value if the entry is occupied, and returns
+    /// the entry for further use, usually with one of the `or_insert` methods.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(entry_and_modify)]
+    /// use std::collections::BTreeMap;
+    ///
+    /// let mut counts = BTreeMap::new();
+    /// for ch in "banana".chars() {
+    ///     counts.entry(ch).and_modify(|n| *n += 1).or_insert(1);
+    /// }
+    /// assert_eq!(counts[&'a'], 3);
+    /// assert_eq!(counts[&'b'], 1);
+    /// ```
+    #[unstable(feature = "entry_and_modify", issue = "0")]
+    pub fn and_modify<F: FnOnce(&mut V)>(self, f: F) -> Self {
+        match self {
+            Occupied(mut entry) => {
+                f(entry.get_mut());
+                Occupied(entry)
+            }
+            Vacant(entry) => Vacant(entry),
+        }
+    }
+
     /// Returns a reference to this entry's key.
     #[stable(feature = "map_entry_keys", since = "1.10.0")]
     pub fn key(&self) -> &K {
@@ -1903,22 +1970,48 @@ impl<'a, K: Ord, V> VacantEntry<'a, K, V> {
     /// and returns a mutable reference to it.
     #[stable(feature = "rust1", since = "1.0.0")]
     pub fn insert(self, value: V) -> &'a mut V {
-        *self.length += 1;
+        self.insert_entry(value).into_mut()
+    }
 
-        let out_ptr;
+    /// Sets the value of the entry with the VacantEntry's key,
+    /// and returns an OccupiedEntry for it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(entry_insert)]
+    /// use std::collections::BTreeMap;
+    /// use std::collections::btree_map::Entry;
+    ///
+    /// let mut map = BTreeMap::new();
+    /// if let Entry::Vacant(v) = map.entry("poneyland") {
+    ///     let mut o = v.insert_entry(37);
+    ///     assert_eq!(o.key(), &"poneyland");
+    ///     *o.get_mut() += 5;
+    /// }
+    /// assert_eq!(map["poneyland"], 42);
+    /// ```
+    #[unstable(feature = "entry_insert", issue = "0")]
+    pub fn insert_entry(self, value: V) -> OccupiedEntry<'a, K, V> {
+        *self.length += 1;
 
         let mut ins_k;
         let mut ins_v;
         let mut ins_edge;
 
-        let mut cur_parent = match self.handle.insert(self.key, value) {
-            (Fit(handle), _) => return handle.into_kv_mut().1,
-            (Split(left, k, v, right), ptr) => {
+        let (kv, mut cur_parent) = match self.handle.insert(self.key, value) {
+            (Fit(handle), _) => {
+                return OccupiedEntry {
+                    handle: handle.forget_node_type(),
+                    length: self.length,
+                    _marker: PhantomData,
+                };
+            }
+            (Split(left, k, v, right), kv) => {
                 ins_k = k;
                 ins_v = v;
                 ins_edge = right;
-                out_ptr = ptr;
-                left.ascend().map_err(|n| n.into_root_mut())
+                (kv, left.ascend().map_err(|n| n.into_root_mut()))
             }
         };
 
@@ -1926,7 +2019,7 @@ impl<'a, K: Ord, V> VacantEntry<'a, K, V> {
             match cur_parent {
                 Ok(parent) => {
                     match parent.insert(ins_k, ins_v, ins_edge) {
-                        Fit(_) => return unsafe { &mut *out_ptr },
+                        Fit(_) => break,
                         Split(left, k, v, right) => {
                             ins_k = k;
                             ins_v = v;
@@ -1937,10 +2030,16 @@ impl<'a, K: Ord, V> VacantEntry<'a, K, V> {
                 }
                 Err(root) => {
                     root.push_level().push(ins_k, ins_v, ins_edge);
-                    return unsafe { &mut *out_ptr };
+                    break;
                 }
             }
         }
+
+        OccupiedEntry {
+            handle: kv.forget_node_type(),
+            length: self.length,
+            _marker: PhantomData,
+        }
     }
 }
 
@@ -1988,6 +2087,66 @@ impl<'a, K: Ord, V> OccupiedEntry<'a, K, V> {
         self.remove_kv().1
     }
 
+    /// Takes the value out of the entry and replaces it with the result of `f`, or removes the
+    /// entry if `f` returns `None`. Returns the entry in its new state.
+    ///
+    /// If `f` panics, the entry is removed from the map.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(entry_replace_with)]
+    /// use std::collections::BTreeMap;
+    /// use std::collections::btree_map::Entry;
+    ///
+    /// let mut map = BTreeMap::new();
+    /// map.insert("a", 2);
+    ///
+    /// if let Entry::Occupied(o) = map.entry("a") {
+    ///     let entry = o.replace_entry_with(|_, v| if v > 1 { Some(v - 1) } else { None });
+    ///     assert!(match entry { Entry::Occupied(..) => true, Entry::Vacant(..) => false });
+    /// }
+    /// assert_eq!(map["a"], 1);
+    ///
+    /// if let Entry::Occupied(o) = map.entry("a") {
+    ///     let entry = o.replace_entry_with(|_, v| if v > 1 { Some(v - 1) } else { None });
+    ///     assert_eq!(entry.key(), &"a");
+    /// }
+    /// assert!(map.is_empty());
+    /// ```
+    #[unstable(feature = "entry_replace_with", issue = "0")]
+    pub fn replace_entry_with<F>(mut self, f: F) -> Entry<'a, K, V>
+        where F: FnOnce(&K, V) -> Option<V>
+    {
+        // Removing the pair may change the height of the tree, but not the location of its
+        // root, which is where the search for the place of the key starts again.
+        let root = unsafe {
+            self.handle.reborrow_mut().into_node().into_root_mut() as *mut node::Root<K, V>
+        };
+        let OccupiedEntry { handle, length, .. } = self;
+        let (key, value) = OccupiedEntry {
+            handle: handle,
+            length: &mut *length,
+            _marker: PhantomData,
+        }.remove_kv();
+
+        let root: &'a mut node::Root<K, V> = unsafe { &mut *root };
+        let handle = match search::search_tree(root.as_mut(), &key) {
+            GoDown(handle) => handle,
+            Found(_) => unreachable!(),
+        };
+        let entry = VacantEntry {
+            key: key,
+            handle: handle,
+            length: length,
+            _marker: PhantomData,
+        };
+        match f(entry.key(), value) {
+            Some(value) => Occupied(entry.insert_entry(value)),
+            None => Vacant(entry),
+        }
+    }
+
     fn remove_kv(self) -> (K, V) {
         *self.length -= 1;
 
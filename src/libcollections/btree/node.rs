@@ -508,6 +508,18 @@ impl<'a, K, V, Type> NodeRef<marker::Mut<'a>, K, V, Type> {
         }
     }
 
+    /// Takes out another mutable reference to the same node, with the same lifetime. This is
+    /// even more dangerous than `reborrow_mut`, and only meant for keeping track of a
+    /// position while the tree is changed through the original reference.
+    unsafe fn alias(&self) -> NodeRef<marker::Mut<'a>, K, V, Type> {
+        NodeRef {
+            height: self.height,
+            node: self.node,
+            root: self.root,
+            _marker: PhantomData
+        }
+    }
+
     fn as_leaf_mut(&mut self) -> &mut LeafNode<K, V> {
         unsafe {
             &mut *(*self.node as *mut LeafNode<K, V>)
@@ -803,6 +815,15 @@ impl<BorrowType, K, V, NodeType> Handle<NodeRef<BorrowType, K, V, NodeType>, mar
     }
 }
 
+impl<BorrowType, K, V> Handle<NodeRef<BorrowType, K, V, marker::Leaf>, marker::KV> {
+    /// Removes any static information about whether the node of this handle is a `Leaf` or
+    /// an `Internal` node.
+    pub fn forget_node_type(self)
+            -> Handle<NodeRef<BorrowType, K, V, marker::LeafOrInternal>, marker::KV> {
+        Handle::new_kv(self.node.forget_type(), self.idx)
+    }
+}
+
 impl<BorrowType, K, V, NodeType, HandleType> PartialEq
         for Handle<NodeRef<BorrowType, K, V, NodeType>, HandleType> {
 
@@ -912,29 +933,41 @@ impl<'a, K, V> Handle<NodeRef<marker::Mut<'a>, K, V, marker::Leaf>, marker::Edge
     /// Inserts a new key/value pair between the key/value pairs to the right and left of
     /// this edge. This method splits the node if there isn't enough room.
     ///
-    /// The returned pointer points to the inserted value.
+    /// The returned handle points to the inserted pair. It stays valid while a split is
+    /// inserted into the ancestors of this node, since that never moves a leaf.
     pub fn insert(mut self, key: K, val: V)
-            -> (InsertResult<'a, K, V, marker::Leaf>, *mut V) {
+            -> (InsertResult<'a, K, V, marker::Leaf>,
+                Handle<NodeRef<marker::Mut<'a>, K, V, marker::Leaf>, marker::KV>) {
 
         if self.node.len() < CAPACITY {
-            let ptr = self.insert_fit(key, val);
-            (InsertResult::Fit(Handle::new_kv(self.node, self.idx)), ptr)
+            self.insert_fit(key, val);
+            let kv = unsafe { Handle::new_kv(self.node.alias(), self.idx) };
+            (InsertResult::Fit(Handle::new_kv(self.node, self.idx)), kv)
         } else {
             let middle = Handle::new_kv(self.node, B);
             let (mut left, k, v, mut right) = middle.split();
-            let ptr = if self.idx <= B {
+            let kv = if self.idx <= B {
                 unsafe {
-                    Handle::new_edge(left.reborrow_mut(), self.idx).insert_fit(key, val)
+                    Handle::new_edge(left.reborrow_mut(), self.idx).insert_fit(key, val);
+                    Handle::new_kv(left.alias(), self.idx)
                 }
             } else {
                 unsafe {
                     Handle::new_edge(
                         right.as_mut().cast_unchecked::<marker::Leaf>(),
                         self.idx - (B + 1)
-                    ).insert_fit(key, val)
+                    ).insert_fit(key, val);
+                    // The new node is not a root for long, so refer to the one of `left`.
+                    let node = NodeRef {
+                        height: 0,
+                        node: right.node.as_ptr(),
+                        root: left.root,
+                        _marker: PhantomData
+                    };
+                    Handle::new_kv(node, self.idx - (B + 1))
                 }
             };
-            (InsertResult::Split(left, k, v, right), ptr)
+            (InsertResult::Split(left, k, v, right), kv)
         }
     }
 }
@@ -291,6 +291,11 @@ impl<E: CLike> Extend<E> for EnumSet<E> {
             self.insert(element);
         }
     }
+
+    #[inline]
+    fn extend_one(&mut self, element: E) {
+        self.insert(element);
+    }
 }
 
 #[stable(feature = "extend_ref", since = "1.2.0")]
@@ -298,4 +303,9 @@ impl<'a, E: 'a + CLike + Copy> Extend<&'a E> for EnumSet<E> {
     fn extend<I: IntoIterator<Item = &'a E>>(&mut self, iter: I) {
         self.extend(iter.into_iter().cloned());
     }
+
+    #[inline]
+    fn extend_one(&mut self, &element: &'a E) {
+        self.insert(element);
+    }
 }
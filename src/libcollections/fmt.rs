@@ -137,6 +137,8 @@
 //! * `b` ⇒ [`Binary`](trait.Binary.html)
 //! * `e` ⇒ [`LowerExp`](trait.LowerExp.html)
 //! * `E` ⇒ [`UpperExp`](trait.UpperExp.html)
+//! * `g` ⇒ [`LowerGeneral`](trait.LowerGeneral.html)
+//! * `G` ⇒ [`UpperGeneral`](trait.UpperGeneral.html)
 //!
 //! What this means is that any type of argument which implements the
 //! `fmt::Binary` trait can then be formatted with `{:b}`. Implementations
@@ -499,6 +501,8 @@ pub use core::fmt::{Display, Debug};
 pub use core::fmt::{LowerHex, UpperHex, Pointer};
 #[stable(feature = "rust1", since = "1.0.0")]
 pub use core::fmt::{LowerExp, UpperExp};
+#[unstable(feature = "fmt_general", issue = "0")]
+pub use core::fmt::{LowerGeneral, UpperGeneral};
 #[stable(feature = "rust1", since = "1.0.0")]
 pub use core::fmt::Error;
 #[stable(feature = "rust1", since = "1.0.0")]
@@ -35,6 +35,7 @@
 #![feature(box_syntax)]
 #![feature(core_intrinsics)]
 #![feature(dropck_parametricity)]
+#![feature(extend_one)]
 #![feature(fmt_internals)]
 #![feature(heap_api)]
 #![feature(inclusive_range)]
@@ -44,10 +45,12 @@
 #![feature(placement_in)]
 #![feature(placement_new_protocol)]
 #![feature(shared)]
+#![feature(slice_get_slice)]
 #![feature(slice_patterns)]
 #![feature(specialization)]
 #![feature(staged_api)]
 #![feature(step_by)]
+#![feature(str_internals)]
 #![feature(unboxed_closures)]
 #![feature(unicode)]
 #![feature(unique)]
@@ -983,6 +983,11 @@ impl<A> Extend<A> for LinkedList<A> {
     fn extend<T: IntoIterator<Item = A>>(&mut self, iter: T) {
         <Self as SpecExtend<T>>::spec_extend(self, iter);
     }
+
+    #[inline]
+    fn extend_one(&mut self, elt: A) {
+        self.push_back(elt);
+    }
 }
 
 impl<I: IntoIterator> SpecExtend<I> for LinkedList<I::Item> {
@@ -1004,6 +1009,11 @@ impl<'a, T: 'a + Copy> Extend<&'a T> for LinkedList<T> {
     fn extend<I: IntoIterator<Item = &'a T>>(&mut self, iter: I) {
         self.extend(iter.into_iter().cloned());
     }
+
+    #[inline]
+    fn extend_one(&mut self, &elt: &'a T) {
+        self.push_back(elt);
+    }
 }
 
 #[stable(feature = "rust1", since = "1.0.0")]
@@ -90,6 +90,7 @@ use core::cmp::Ordering::{self, Greater, Less};
 use core::cmp;
 use core::mem::size_of;
 use core::mem;
+use core::ops;
 use core::ptr;
 use core::slice as core_slice;
 
@@ -106,6 +107,10 @@ pub use core::slice::{SplitMut, ChunksMut, Split};
 pub use core::slice::{SplitN, RSplitN, SplitNMut, RSplitNMut};
 #[stable(feature = "rust1", since = "1.0.0")]
 pub use core::slice::{from_raw_parts, from_raw_parts_mut};
+#[unstable(feature = "slice_try_copy", issue = "0")]
+pub use core::slice::LengthMismatchError;
+#[unstable(feature = "slice_get_slice", issue = "0")]
+pub use core::slice::SliceIndex;
 
 ////////////////////////////////////////////////////////////////////////////////
 // Basic slice extension methods
@@ -299,6 +304,62 @@ impl<T> [T] {
         core_slice::SliceExt::get_unchecked_mut(self, index)
     }
 
+    /// Returns the subslice for a range of indices, or `None` if the range
+    /// is out of bounds.
+    ///
+    /// This takes any of the ranges that slices can be indexed by, from
+    /// `ops` and from `range`. An empty range selects an empty subslice at
+    /// its start.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(slice_get_slice)]
+    ///
+    /// let v = [10, 40, 30];
+    /// assert_eq!(Some(&[10, 40][..]), v.get_slice(0..2));
+    /// assert_eq!(Some(&[40, 30][..]), v.get_slice(1..));
+    /// assert_eq!(None, v.get_slice(0..4));
+    /// assert_eq!(None, v.get_slice(2..1));
+    /// ```
+    #[unstable(feature = "slice_get_slice", issue = "0")]
+    #[inline]
+    pub fn get_slice<I>(&self, index: I) -> Option<&[T]>
+        where I: SliceIndex<T>
+    {
+        core_slice::SliceExt::get_slice(self, index)
+    }
+
+    /// Returns the mutable subslice for a range of indices, or `None` if the
+    /// range is out of bounds.
+    #[unstable(feature = "slice_get_slice", issue = "0")]
+    #[inline]
+    pub fn get_slice_mut<I>(&mut self, index: I) -> Option<&mut [T]>
+        where I: SliceIndex<T>
+    {
+        core_slice::SliceExt::get_slice_mut(self, index)
+    }
+
+    /// Returns the subslice for a range of indices, without doing bounds
+    /// checking.
+    #[unstable(feature = "slice_get_slice", issue = "0")]
+    #[inline]
+    pub unsafe fn get_slice_unchecked<I>(&self, index: I) -> &[T]
+        where I: SliceIndex<T>
+    {
+        core_slice::SliceExt::get_slice_unchecked(self, index)
+    }
+
+    /// Returns the mutable subslice for a range of indices, without doing
+    /// bounds checking.
+    #[unstable(feature = "slice_get_slice", issue = "0")]
+    #[inline]
+    pub unsafe fn get_slice_unchecked_mut<I>(&mut self, index: I) -> &mut [T]
+        where I: SliceIndex<T>
+    {
+        core_slice::SliceExt::get_slice_unchecked_mut(self, index)
+    }
+
     /// Returns an raw pointer to the slice's buffer
     ///
     /// The caller must ensure that the slice outlives the pointer this
@@ -778,6 +839,72 @@ impl<T> [T] {
         core_slice::SliceExt::binary_search_by_key(self, b, f)
     }
 
+    /// Binary search a sorted slice for a given element, starting from the
+    /// index `hint`.
+    ///
+    /// This returns the same kind of result as `binary_search`, but first
+    /// gallops from `hint` with doubling steps, so finding an element `d`
+    /// positions away from the hint takes `O(log d)` comparisons. This makes
+    /// repeated searches for increasing values, like when merging sorted
+    /// sequences, cheap when each search starts from the previous result.
+    ///
+    /// A `hint` past the end of the slice is treated like its length.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// #![feature(slice_binary_search_from)]
+    ///
+    /// let s = [0, 1, 1, 1, 1, 2, 3, 5, 8, 13, 21, 34, 55];
+    ///
+    /// assert_eq!(s.binary_search_from(6, &13), Ok(9));
+    /// assert_eq!(s.binary_search_from(12, &4), Err(7));
+    /// assert_eq!(s.binary_search_from(100, &100), Err(13));
+    ///
+    /// // find the positions of sorted keys, each starting at the last one
+    /// let mut pos = 0;
+    /// let mut found = vec![];
+    /// for &k in &[2, 8, 40] {
+    ///     pos = match s.binary_search_from(pos, &k) { Ok(i) | Err(i) => i };
+    ///     found.push(pos);
+    /// }
+    /// assert_eq!(found, [5, 8, 12]);
+    /// ```
+    #[unstable(feature = "slice_binary_search_from", issue = "0")]
+    #[inline]
+    pub fn binary_search_from(&self, hint: usize, x: &T) -> Result<usize, usize>
+        where T: Ord
+    {
+        core_slice::SliceExt::binary_search_from(self, hint, x)
+    }
+
+    /// Binary search a sorted slice with a comparator function, starting
+    /// from the index `hint`.
+    ///
+    /// The comparator function should implement an order consistent with
+    /// the sort order of the underlying slice, as for `binary_search_by`.
+    /// See `binary_search_from` for how the hint is used.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// #![feature(slice_binary_search_from)]
+    ///
+    /// let s = [0, 1, 1, 1, 1, 2, 3, 5, 8, 13, 21, 34, 55];
+    ///
+    /// let seek = 5;
+    /// assert_eq!(s.binary_search_from_by(0, |probe| probe.cmp(&seek)), Ok(7));
+    /// let seek = 0;
+    /// assert_eq!(s.binary_search_from_by(12, |probe| probe.cmp(&seek)), Ok(0));
+    /// ```
+    #[unstable(feature = "slice_binary_search_from", issue = "0")]
+    #[inline]
+    pub fn binary_search_from_by<F>(&self, hint: usize, f: F) -> Result<usize, usize>
+        where F: FnMut(&T) -> Ordering
+    {
+        core_slice::SliceExt::binary_search_from_by(self, hint, f)
+    }
+
     /// This is equivalent to `self.sort_by(|a, b| a.cmp(b))`.
     ///
     /// This sort is stable and `O(n log n)` worst-case but allocates
@@ -890,6 +1017,94 @@ impl<T> [T] {
         core_slice::SliceExt::copy_from_slice(self, src)
     }
 
+    /// Copies the elements from `src` into `self` if the two slices have the
+    /// same length.
+    ///
+    /// This is `clone_from_slice` with an error instead of a panic: if the
+    /// lengths differ, `self` is left untouched and the error has both of
+    /// them.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// #![feature(slice_try_copy)]
+    ///
+    /// let mut dst = [0, 0, 0];
+    ///
+    /// assert!(dst.try_clone_from_slice(&[1, 2, 3]).is_ok());
+    /// assert_eq!(dst, [1, 2, 3]);
+    ///
+    /// let err = dst.try_clone_from_slice(&[4, 5]).unwrap_err();
+    /// assert_eq!((err.dst_len(), err.src_len()), (3, 2));
+    /// assert_eq!(dst, [1, 2, 3]);
+    /// ```
+    #[unstable(feature = "slice_try_copy", issue = "0")]
+    pub fn try_clone_from_slice(&mut self, src: &[T]) -> Result<(), LengthMismatchError>
+        where T: Clone
+    {
+        core_slice::SliceExt::try_clone_from_slice(self, src)
+    }
+
+    /// Copies all elements from `src` into `self`, using a memcpy, if the two
+    /// slices have the same length.
+    ///
+    /// This is `copy_from_slice` with an error instead of a panic: if the
+    /// lengths differ, `self` is left untouched and the error has both of
+    /// them.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// #![feature(slice_try_copy)]
+    ///
+    /// let mut buf = [0u8; 4];
+    /// let packet = [1, 2, 3, 4, 5];
+    ///
+    /// let err = buf.try_copy_from_slice(&packet).unwrap_err();
+    /// assert_eq!(err.to_string(), "destination and source slices have different lengths \
+    ///                              (destination has 4 elements, source has 5)");
+    /// assert_eq!(buf, [0; 4]);
+    ///
+    /// buf.try_copy_from_slice(&packet[1..]).unwrap();
+    /// assert_eq!(buf, [2, 3, 4, 5]);
+    /// ```
+    #[unstable(feature = "slice_try_copy", issue = "0")]
+    pub fn try_copy_from_slice(&mut self, src: &[T]) -> Result<(), LengthMismatchError>
+        where T: Copy
+    {
+        core_slice::SliceExt::try_copy_from_slice(self, src)
+    }
+
+    /// Copies the elements in the range `src` to the part of `self` starting
+    /// at `dest`, using a memmove.
+    ///
+    /// The source and destination may overlap.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if `src` starts after it ends, if it ends past
+    /// the end of the slice, or if `dest` is too close to the end of the slice
+    /// for all of the elements to fit. The message says which and has the
+    /// offending range and the length of the slice.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// #![feature(slice_copy_within)]
+    ///
+    /// let mut bytes = *b"Hello, World!";
+    ///
+    /// bytes.copy_within(1..5, 8);
+    /// assert_eq!(&bytes, b"Hello, Wello!");
+    ///
+    /// bytes.copy_within(0..6, 2);
+    /// assert_eq!(&bytes, b"HeHello,ello!");
+    /// ```
+    #[unstable(feature = "slice_copy_within", issue = "0")]
+    pub fn copy_within(&mut self, src: ops::Range<usize>, dest: usize) where T: Copy {
+        core_slice::SliceExt::copy_within(self, src, dest)
+    }
+
 
     /// Copies `self` into a new `Vec`.
     #[stable(feature = "rust1", since = "1.0.0")]
@@ -19,14 +19,17 @@
 // It's cleaner to just turn off the unused_imports warning than to fix them.
 #![allow(unused_imports)]
 
+use core::cell::Cell;
+use core::ops::{self, Index};
 use core::str as core_str;
 use core::str::pattern::Pattern;
 use core::str::pattern::{Searcher, ReverseSearcher, DoubleEndedSearcher};
+use core::str::utf8_is_cont_byte;
 use core::mem;
 use rustc_unicode::str::{UnicodeStr, Utf16Encoder};
 
 use vec_deque::VecDeque;
-use borrow::{Borrow, ToOwned};
+use borrow::{Borrow, Cow, ToOwned};
 use string::String;
 use rustc_unicode;
 use vec::Vec;
@@ -136,6 +139,166 @@ impl<'a> Iterator for EncodeUtf16<'a> {
     }
 }
 
+/// Converts between `char` indices and byte offsets of a string, remembering
+/// the last position so that conversions near it are cheap.
+///
+/// `str::byte_index_of_char` and `str::char_index_of_byte` walk the string
+/// from the start each time. A `CharIndexer` walks from the position of the
+/// previous conversion instead, forwards or backwards, or from the start if
+/// that is closer, so that moving a cursor or going through positions in
+/// order takes time proportional to the distance moved.
+///
+/// It also indexes the string by `char` positions: `indexer[n]` is the `n`-th
+/// `char` as a one-`char` string slice, and `indexer[a..b]` the slice from the
+/// `a`-th up to the `b`-th `char`. Like slicing by bytes, these panic if the
+/// positions are past the end of the string.
+///
+/// # Examples
+///
+/// ```
+/// #![feature(str_char_index)]
+/// use std::str::CharIndexer;
+///
+/// let indexer = CharIndexer::new("Löwe 老虎 Léopard");
+/// assert_eq!(indexer.byte_index(5), Some(6));
+/// assert_eq!(indexer.byte_index(6), Some(9));
+/// assert_eq!(indexer.char_index(12), Some(7));
+///
+/// assert_eq!(&indexer[1], "ö");
+/// assert_eq!(&indexer[5..7], "老虎");
+/// assert_eq!(&indexer[8..], "Léopard");
+/// ```
+#[derive(Clone, Debug)]
+#[unstable(feature = "str_char_index", issue = "0")]
+pub struct CharIndexer<'a> {
+    s: &'a str,
+    /// The `char` index and byte offset of the last position.
+    pos: Cell<(usize, usize)>,
+}
+
+impl<'a> CharIndexer<'a> {
+    /// Creates an indexer for `s`, positioned at its start.
+    #[unstable(feature = "str_char_index", issue = "0")]
+    pub fn new(s: &'a str) -> CharIndexer<'a> {
+        CharIndexer { s: s, pos: Cell::new((0, 0)) }
+    }
+
+    /// Returns the string that is indexed.
+    #[unstable(feature = "str_char_index", issue = "0")]
+    pub fn as_str(&self) -> &'a str {
+        self.s
+    }
+
+    /// Returns the byte offset of the `n`-th `char`, like
+    /// `str::byte_index_of_char`.
+    #[unstable(feature = "str_char_index", issue = "0")]
+    pub fn byte_index(&self, n: usize) -> Option<usize> {
+        let bytes = self.s.as_bytes();
+        let (mut c, mut b) = self.pos.get();
+        if n < c && n < c - n {
+            c = 0;
+            b = 0;
+        }
+        while c < n {
+            if b == bytes.len() {
+                self.pos.set((c, b));
+                return None;
+            }
+            b += 1;
+            while b < bytes.len() && utf8_is_cont_byte(bytes[b]) {
+                b += 1;
+            }
+            c += 1;
+        }
+        while c > n {
+            b -= 1;
+            while utf8_is_cont_byte(bytes[b]) {
+                b -= 1;
+            }
+            c -= 1;
+        }
+        self.pos.set((c, b));
+        Some(b)
+    }
+
+    /// Returns the index of the `char` that starts at the byte offset
+    /// `index`, like `str::char_index_of_byte`.
+    #[unstable(feature = "str_char_index", issue = "0")]
+    pub fn char_index(&self, index: usize) -> Option<usize> {
+        if !self.s.is_char_boundary(index) {
+            return None;
+        }
+        let bytes = self.s.as_bytes();
+        let (mut c, mut b) = self.pos.get();
+        if index < b && index < b - index {
+            c = 0;
+            b = 0;
+        }
+        let chars = |bytes: &[u8]| bytes.iter().filter(|&&x| !utf8_is_cont_byte(x)).count();
+        if index >= b {
+            c += chars(&bytes[b..index]);
+        } else {
+            c -= chars(&bytes[index..b]);
+        }
+        self.pos.set((c, index));
+        Some(c)
+    }
+
+    /// Returns the byte offset of the `n`-th `char`, panicking like slicing
+    /// does if there is no such `char` or end of the string.
+    fn byte_index_or_fail(&self, n: usize) -> usize {
+        match self.byte_index(n) {
+            Some(b) => b,
+            None => panic!("char index {} is past the end of the string", n),
+        }
+    }
+}
+
+#[unstable(feature = "str_char_index", issue = "0")]
+impl<'a> Index<usize> for CharIndexer<'a> {
+    type Output = str;
+
+    fn index(&self, n: usize) -> &str {
+        let start = self.byte_index_or_fail(n);
+        if start == self.s.len() {
+            panic!("char index {} is past the end of the string", n);
+        }
+        let end = self.byte_index_or_fail(n + 1);
+        &self.s[start..end]
+    }
+}
+
+#[unstable(feature = "str_char_index", issue = "0")]
+impl<'a> Index<ops::Range<usize>> for CharIndexer<'a> {
+    type Output = str;
+
+    fn index(&self, index: ops::Range<usize>) -> &str {
+        assert!(index.start <= index.end, "char range starts at {} but ends at {}",
+                index.start, index.end);
+        let start = self.byte_index_or_fail(index.start);
+        let end = self.byte_index_or_fail(index.end);
+        &self.s[start..end]
+    }
+}
+
+#[unstable(feature = "str_char_index", issue = "0")]
+impl<'a> Index<ops::RangeTo<usize>> for CharIndexer<'a> {
+    type Output = str;
+
+    fn index(&self, index: ops::RangeTo<usize>) -> &str {
+        &self.s[..self.byte_index_or_fail(index.end)]
+    }
+}
+
+#[unstable(feature = "str_char_index", issue = "0")]
+impl<'a> Index<ops::RangeFrom<usize>> for CharIndexer<'a> {
+    type Output = str;
+
+    fn index(&self, index: ops::RangeFrom<usize>) -> &str {
+        &self.s[self.byte_index_or_fail(index.start)..]
+    }
+}
+
 // Return the initial codepoint accumulator for the first byte.
 // The first byte is special, only want bottom 5 bits for width 2, 4 bits
 // for width 3, and 3 bits for width 4
@@ -241,6 +404,60 @@ impl str {
         core_str::StrExt::is_char_boundary(self, index)
     }
 
+    /// Returns the byte offset of the `n`-th `char` of the string, counting
+    /// from zero.
+    ///
+    /// `n` may be the number of `char`s, for the offset of the end of the
+    /// string. Returns `None` if `n` is greater than that.
+    ///
+    /// This has to walk the string up to the `char`, so it takes `O(n)` time.
+    /// `CharIndexer` makes a series of nearby conversions cheaper.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(str_char_index)]
+    ///
+    /// let s = "Löwe 老虎";
+    /// assert_eq!(s.byte_index_of_char(0), Some(0));
+    /// assert_eq!(s.byte_index_of_char(2), Some(3));
+    /// assert_eq!(s.byte_index_of_char(6), Some(9));
+    /// assert_eq!(s.byte_index_of_char(7), Some(s.len()));
+    /// assert_eq!(s.byte_index_of_char(8), None);
+    /// ```
+    #[unstable(feature = "str_char_index", issue = "0")]
+    #[inline]
+    pub fn byte_index_of_char(&self, n: usize) -> Option<usize> {
+        core_str::StrExt::byte_index_of_char(self, n)
+    }
+
+    /// Returns how many `char`s come before the byte offset `index`, which is
+    /// the index of the `char` that starts there.
+    ///
+    /// Returns `None` if `index` is not on a `char` boundary, as given by
+    /// `is_char_boundary`, including if it is greater than `self.len()`.
+    ///
+    /// This has to walk the string up to `index`, so it takes `O(index)` time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(str_char_index)]
+    ///
+    /// let s = "Löwe 老虎";
+    /// assert_eq!(s.char_index_of_byte(3), Some(2));
+    /// assert_eq!(s.char_index_of_byte(9), Some(6));
+    /// assert_eq!(s.char_index_of_byte(s.len()), Some(7));
+    ///
+    /// // second byte of `ö`
+    /// assert_eq!(s.char_index_of_byte(2), None);
+    /// ```
+    #[unstable(feature = "str_char_index", issue = "0")]
+    #[inline]
+    pub fn char_index_of_byte(&self, index: usize) -> Option<usize> {
+        core_str::StrExt::char_index_of_byte(self, index)
+    }
+
     /// Converts a string slice to a byte slice.
     ///
     /// # Examples
@@ -644,6 +861,49 @@ impl str {
         EncodeUtf16 { encoder: Utf16Encoder::new(self[..].chars()) }
     }
 
+    /// Returns the number of `u16`s of the string encoded as UTF-16, which
+    /// `encode_utf16()` would yield.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(encode_utf16_into)]
+    ///
+    /// assert_eq!("Löwe".encoded_utf16_len(), 4);
+    /// assert_eq!("𝕊 = 老虎".encoded_utf16_len(), 7);
+    /// ```
+    #[unstable(feature = "encode_utf16_into", issue = "0")]
+    pub fn encoded_utf16_len(&self) -> usize {
+        core_str::StrExt::encoded_utf16_len(self)
+    }
+
+    /// Writes the string as UTF-16 into the start of `dst`, and returns the
+    /// number of `u16`s written.
+    ///
+    /// Returns `None`, and leaves `dst` alone, if `dst` is shorter than
+    /// `encoded_utf16_len()`.
+    ///
+    /// # Examples
+    ///
+    /// A NUL-terminated string, as Windows APIs take them:
+    ///
+    /// ```
+    /// #![feature(encode_utf16_into)]
+    ///
+    /// let s = "C:\\Löwe";
+    /// let mut buf = vec![0; s.encoded_utf16_len() + 1];
+    /// let len = s.encode_utf16_into(&mut buf).unwrap();
+    /// assert_eq!(len, 7);
+    /// assert_eq!(buf.last(), Some(&0));
+    /// assert_eq!(String::from_utf16(&buf[..len]).unwrap(), s);
+    ///
+    /// assert_eq!(s.encode_utf16_into(&mut [0; 6]), None);
+    /// ```
+    #[unstable(feature = "encode_utf16_into", issue = "0")]
+    pub fn encode_utf16_into(&self, dst: &mut [u16]) -> Option<usize> {
+        core_str::StrExt::encode_utf16_into(self, dst)
+    }
+
     /// Returns `true` if the given pattern matches a sub-slice of
     /// this string slice.
     ///
@@ -1590,6 +1850,51 @@ impl str {
         result
     }
 
+    /// Replaces all matches of a pattern with another string, without
+    /// allocating if there are none.
+    ///
+    /// This is like [`replace`], except that if the pattern doesn't match,
+    /// the original string slice is returned as a borrowed [`Cow`] instead
+    /// of being copied into a new [`String`].
+    ///
+    /// [`replace`]: #method.replace
+    /// [`Cow`]: borrow/enum.Cow.html
+    /// [`String`]: string/struct.String.html
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// #![feature(str_replace_cow)]
+    ///
+    /// use std::borrow::Cow;
+    ///
+    /// let s = "this is old";
+    ///
+    /// assert_eq!(s.replace_cow("old", "new"), "this is new");
+    /// assert_eq!(s.replace_cow("cookie monster", "little lamb"), Cow::Borrowed(s));
+    /// ```
+    #[unstable(feature = "str_replace_cow", issue = "0")]
+    pub fn replace_cow<'a, P: Pattern<'a>>(&'a self, from: P, to: &str) -> Cow<'a, str> {
+        let mut matches = self.match_indices(from);
+        let (start, part) = match matches.next() {
+            Some(first) => first,
+            None => return Cow::Borrowed(self),
+        };
+        let mut result = String::with_capacity(self.len());
+        result.push_str(unsafe { self.slice_unchecked(0, start) });
+        result.push_str(to);
+        let mut last_end = start + part.len();
+        for (start, part) in matches {
+            result.push_str(unsafe { self.slice_unchecked(last_end, start) });
+            result.push_str(to);
+            last_end = start + part.len();
+        }
+        result.push_str(unsafe { self.slice_unchecked(last_end, self.len()) });
+        Cow::Owned(result)
+    }
+
     /// Returns the lowercase equivalent of this string slice, as a new [`String`].
     ///
     /// 'Lowercase' is defined according to the terms of the Unicode Derived Core Property
@@ -57,10 +57,11 @@
 
 use core::fmt;
 use core::hash;
-use core::iter::FromIterator;
+use core::iter::{FromIterator, Map};
 use core::mem;
 use core::ops::{self, Add, Index, IndexMut};
 use core::ptr;
+use core::slice::Chunks;
 use core::str::pattern::Pattern;
 use rustc_unicode::char::{decode_utf16, REPLACEMENT_CHARACTER};
 use rustc_unicode::str as unicode_str;
@@ -326,6 +327,36 @@ pub struct FromUtf8Error {
 #[derive(Debug)]
 pub struct FromUtf16Error(());
 
+/// The UTF-16 code units of `v` in the given byte order, after a leading byte
+/// order mark if there is one. An odd byte at the end is left out.
+fn utf16_units(v: &[u8], big_endian: bool) -> Map<Chunks<u8>, fn(&[u8]) -> u16> {
+    fn le(b: &[u8]) -> u16 { b[0] as u16 | (b[1] as u16) << 8 }
+    fn be(b: &[u8]) -> u16 { (b[0] as u16) << 8 | b[1] as u16 }
+
+    let bom: &[u8] = if big_endian { &[0xFE, 0xFF] } else { &[0xFF, 0xFE] };
+    let v = if v.starts_with(bom) { &v[2..] } else { v };
+    let unit: fn(&[u8]) -> u16 = if big_endian { be } else { le };
+    v[..v.len() & !1].chunks(2).map(unit)
+}
+
+fn from_utf16_bytes(v: &[u8], big_endian: bool) -> Result<String, FromUtf16Error> {
+    if v.len() % 2 != 0 {
+        return Err(FromUtf16Error(()));
+    }
+    decode_utf16(utf16_units(v, big_endian)).collect::<Result<_, _>>()
+                                            .map_err(|_| FromUtf16Error(()))
+}
+
+fn from_utf16_bytes_lossy(v: &[u8], big_endian: bool) -> String {
+    let mut s: String = decode_utf16(utf16_units(v, big_endian))
+                            .map(|r| r.unwrap_or(REPLACEMENT_CHARACTER))
+                            .collect();
+    if v.len() % 2 != 0 {
+        s.push(REPLACEMENT_CHARACTER);
+    }
+    s
+}
+
 impl String {
     /// Creates a new empty `String`.
     ///
@@ -686,6 +717,102 @@ impl String {
         decode_utf16(v.iter().cloned()).map(|r| r.unwrap_or(REPLACEMENT_CHARACTER)).collect()
     }
 
+    /// Decode UTF-16 in little-endian byte order, like `from_utf16`, but from
+    /// bytes, returning `Err` if `v` contains any invalid data or has an odd
+    /// length.
+    ///
+    /// This is the UTF-16 of Windows and of file formats like NTFS and the
+    /// registry. The bytes don't have to be aligned for `u16`. A leading byte
+    /// order mark of `FF FE` is skipped.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// #![feature(str_from_utf16_endian)]
+    ///
+    /// // 𝄞mu
+    /// let v = &[0x34, 0xD8, 0x1E, 0xDD, 0x6d, 0x00, 0x75, 0x00];
+    /// assert_eq!(String::from("𝄞mu"), String::from_utf16le(v).unwrap());
+    ///
+    /// // a byte order mark, then `A`
+    /// assert_eq!(String::from("A"), String::from_utf16le(&[0xFF, 0xFE, 0x41, 0x00]).unwrap());
+    ///
+    /// // an odd number of bytes
+    /// assert!(String::from_utf16le(&[0x6d, 0x00, 0x75]).is_err());
+    /// ```
+    #[unstable(feature = "str_from_utf16_endian", issue = "0")]
+    pub fn from_utf16le(v: &[u8]) -> Result<String, FromUtf16Error> {
+        from_utf16_bytes(v, false)
+    }
+
+    /// Decode UTF-16 in little-endian byte order, like `from_utf16le`,
+    /// replacing invalid data, and an odd byte at the end, with the
+    /// replacement character (U+FFFD).
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// #![feature(str_from_utf16_endian)]
+    ///
+    /// // mu<invalid>ic<odd byte>
+    /// let v = &[0x6d, 0x00, 0x75, 0x00, 0x00, 0xD8, 0x69, 0x00, 0x63, 0x00, 0x73];
+    /// assert_eq!(String::from("mu\u{FFFD}ic\u{FFFD}"), String::from_utf16le_lossy(v));
+    /// ```
+    #[unstable(feature = "str_from_utf16_endian", issue = "0")]
+    pub fn from_utf16le_lossy(v: &[u8]) -> String {
+        from_utf16_bytes_lossy(v, false)
+    }
+
+    /// Decode UTF-16 in big-endian byte order, like `from_utf16`, but from
+    /// bytes, returning `Err` if `v` contains any invalid data or has an odd
+    /// length.
+    ///
+    /// The bytes don't have to be aligned for `u16`. A leading byte order
+    /// mark of `FE FF` is skipped.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// #![feature(str_from_utf16_endian)]
+    ///
+    /// // 𝄞mu
+    /// let v = &[0xD8, 0x34, 0xDD, 0x1E, 0x00, 0x6d, 0x00, 0x75];
+    /// assert_eq!(String::from("𝄞mu"), String::from_utf16be(v).unwrap());
+    ///
+    /// // 𝄞 without its low surrogate
+    /// assert!(String::from_utf16be(&[0xD8, 0x34, 0x00, 0x6d]).is_err());
+    /// ```
+    #[unstable(feature = "str_from_utf16_endian", issue = "0")]
+    pub fn from_utf16be(v: &[u8]) -> Result<String, FromUtf16Error> {
+        from_utf16_bytes(v, true)
+    }
+
+    /// Decode UTF-16 in big-endian byte order, like `from_utf16be`,
+    /// replacing invalid data, and an odd byte at the end, with the
+    /// replacement character (U+FFFD).
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// #![feature(str_from_utf16_endian)]
+    ///
+    /// // <invalid>mu
+    /// let v = &[0xDD, 0x1E, 0x00, 0x6d, 0x00, 0x75];
+    /// assert_eq!(String::from("\u{FFFD}mu"), String::from_utf16be_lossy(v));
+    /// ```
+    #[unstable(feature = "str_from_utf16_endian", issue = "0")]
+    pub fn from_utf16be_lossy(v: &[u8]) -> String {
+        from_utf16_bytes_lossy(v, true)
+    }
+
     /// Creates a new `String` from a length, capacity, and pointer.
     ///
     /// # Safety
@@ -1428,6 +1555,16 @@ impl Extend<char> for String {
             self.push(ch)
         }
     }
+
+    #[inline]
+    fn extend_one(&mut self, ch: char) {
+        self.push(ch);
+    }
+
+    #[inline]
+    fn extend_reserve(&mut self, additional: usize) {
+        self.reserve(additional);
+    }
 }
 
 #[stable(feature = "extend_ref", since = "1.2.0")]
@@ -1435,6 +1572,16 @@ impl<'a> Extend<&'a char> for String {
     fn extend<I: IntoIterator<Item = &'a char>>(&mut self, iter: I) {
         self.extend(iter.into_iter().cloned());
     }
+
+    #[inline]
+    fn extend_one(&mut self, &ch: &'a char) {
+        self.push(ch);
+    }
+
+    #[inline]
+    fn extend_reserve(&mut self, additional: usize) {
+        self.reserve(additional);
+    }
 }
 
 #[stable(feature = "rust1", since = "1.0.0")]
@@ -1444,6 +1591,11 @@ impl<'a> Extend<&'a str> for String {
             self.push_str(s)
         }
     }
+
+    #[inline]
+    fn extend_one(&mut self, s: &'a str) {
+        self.push_str(s);
+    }
 }
 
 #[stable(feature = "extend_string", since = "1.4.0")]
@@ -1453,6 +1605,11 @@ impl Extend<String> for String {
             self.push_str(&s)
         }
     }
+
+    #[inline]
+    fn extend_one(&mut self, s: String) {
+        self.push_str(&s);
+    }
 }
 
 /// A convenience impl that delegates to the impl for `&str`
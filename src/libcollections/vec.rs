@@ -469,6 +469,11 @@ impl<T> Vec<T> {
     /// Note that this will drop any excess capacity. Calling this and
     /// converting back to a vector with `into_vec()` is equivalent to calling
     /// `shrink_to_fit()`.
+    ///
+    /// The excess capacity is given back by reallocating the buffer in place
+    /// where the allocator supports it, so the elements are not copied to a
+    /// fresh allocation of the exact size unless the allocator has to move
+    /// them, and nothing is reallocated when the capacity already matches.
     #[stable(feature = "rust1", since = "1.0.0")]
     pub fn into_boxed_slice(mut self) -> Box<[T]> {
         unsafe {
@@ -666,21 +671,180 @@ impl<T> Vec<T> {
     pub fn retain<F>(&mut self, mut f: F)
         where F: FnMut(&T) -> bool
     {
+        self.retain_indexed(|_, x| f(x))
+    }
+
+    /// Retains only the elements specified by the predicate, passing a
+    /// mutable reference to it.
+    ///
+    /// In other words, remove all elements `e` such that `f(&mut e)` returns
+    /// false. This method operates in place, visits every element exactly
+    /// once in the original order, and preserves the order of the retained
+    /// elements.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(vec_retain_mut)]
+    ///
+    /// let mut vec = vec![1, 2, 3, 4];
+    /// vec.retain_mut(|x| {
+    ///     *x *= 10;
+    ///     *x != 30
+    /// });
+    /// assert_eq!(vec, [10, 20, 40]);
+    /// ```
+    #[unstable(feature = "vec_retain_mut", issue = "0")]
+    pub fn retain_mut<F>(&mut self, mut f: F)
+        where F: FnMut(&mut T) -> bool
+    {
+        self.retain_indexed(|_, x| f(x))
+    }
+
+    /// Retains only the elements specified by the predicate, passing the
+    /// element's index before any were removed along with a mutable
+    /// reference to it.
+    ///
+    /// In other words, remove all elements `e` at index `i` such that
+    /// `f(i, &mut e)` returns false. This method operates in place, visits
+    /// every element exactly once in the original order, and preserves the
+    /// order of the retained elements.
+    ///
+    /// If `f` panics, the elements it has not been called on yet are kept,
+    /// as is the element it panicked on.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(vec_retain_mut)]
+    ///
+    /// let mut vec = vec!['a', 'b', 'c', 'd', 'e'];
+    /// vec.retain_indexed(|i, _| i % 2 == 0);
+    /// assert_eq!(vec, ['a', 'c', 'e']);
+    /// ```
+    #[unstable(feature = "vec_retain_mut", issue = "0")]
+    pub fn retain_indexed<F>(&mut self, mut f: F)
+        where F: FnMut(usize, &mut T) -> bool
+    {
+        // The elements before `processed` that were kept have been moved down
+        // by `deleted` places, and the others dropped. Whether this finishes
+        // or `f` panics, the guard moves the unprocessed elements down to close
+        // the gap. Until then the length is zero, so that nothing is dropped
+        // twice even if the guard is never run.
+        struct BackshiftOnDrop<'a, T: 'a> {
+            v: &'a mut Vec<T>,
+            processed: usize,
+            deleted: usize,
+            len: usize,
+        }
+
+        impl<'a, T: 'a> Drop for BackshiftOnDrop<'a, T> {
+            fn drop(&mut self) {
+                unsafe {
+                    if self.deleted > 0 {
+                        let src = self.v.as_mut_ptr().offset(self.processed as isize);
+                        let dst = src.offset(-(self.deleted as isize));
+                        ptr::copy(src, dst, self.len - self.processed);
+                    }
+                    self.v.set_len(self.len - self.deleted);
+                }
+            }
+        }
+
         let len = self.len();
-        let mut del = 0;
-        {
-            let v = &mut **self;
-
-            for i in 0..len {
-                if !f(&v[i]) {
-                    del += 1;
-                } else if del > 0 {
-                    v.swap(i - del, i);
+        unsafe { self.set_len(0) };
+        let mut g = BackshiftOnDrop { v: self, processed: 0, deleted: 0, len: len };
+
+        while g.processed < len {
+            unsafe {
+                let cur = g.v.as_mut_ptr().offset(g.processed as isize);
+                if f(g.processed, &mut *cur) {
+                    if g.deleted > 0 {
+                        ptr::copy_nonoverlapping(cur, cur.offset(-(g.deleted as isize)), 1);
+                    }
+                    g.processed += 1;
+                } else {
+                    // count it as processed first, so that the guard does not
+                    // keep it if its destructor panics
+                    g.processed += 1;
+                    g.deleted += 1;
+                    ptr::drop_in_place(cur);
                 }
             }
         }
-        if del > 0 {
-            self.truncate(len - del);
+    }
+
+    /// Removes all elements `e` such that `f(&mut e)` returns true, by
+    /// swapping the last element into the place of each removed one.
+    ///
+    /// This is to `retain` what `swap_remove` is to `remove`: it does not
+    /// preserve the order of the elements, but moves each element at most
+    /// once. Every element is passed to `f` exactly once, though not in the
+    /// original order.
+    ///
+    /// If `f` panics, the elements it has not been called on yet are kept,
+    /// as is the element it panicked on.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(vec_swap_remove_if)]
+    ///
+    /// let mut vec = vec![1, 2, 3, 4, 5, 6];
+    /// vec.swap_remove_if(|x| *x % 3 == 0);
+    /// assert_eq!(vec, [1, 2, 5, 4]);
+    /// ```
+    #[unstable(feature = "vec_swap_remove_if", issue = "0")]
+    pub fn swap_remove_if<F>(&mut self, mut f: F)
+        where F: FnMut(&mut T) -> bool
+    {
+        let mut i = 0;
+        while i < self.len() {
+            if f(&mut self[i]) {
+                // the last element is now at `i`, and still has to be checked
+                self.swap_remove(i);
+            } else {
+                i += 1;
+            }
+        }
+    }
+
+    /// Removes the elements at all of the given indices, by swapping the
+    /// last element into the place of each removed one.
+    ///
+    /// The indices are those before any element was removed, so they do not
+    /// have to be adjusted for each other, and must be strictly increasing.
+    /// Like `swap_remove`, this does not preserve the order of the elements,
+    /// and it takes `O(indices.len())` time.
+    ///
+    /// # Panics
+    ///
+    /// Panics if any index is out of bounds, in which case no element is
+    /// removed. With debug assertions enabled, also panics if the indices
+    /// are not strictly increasing; otherwise unspecified elements are
+    /// removed for those.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(vec_swap_remove_if)]
+    ///
+    /// let mut vec = vec!['a', 'b', 'c', 'd', 'e', 'f'];
+    /// vec.swap_remove_many(&[0, 1, 4]);
+    /// assert_eq!(vec, ['d', 'f', 'c']);
+    /// ```
+    #[unstable(feature = "vec_swap_remove_if", issue = "0")]
+    pub fn swap_remove_many(&mut self, indices: &[usize]) {
+        debug_assert!(indices.windows(2).all(|w| w[0] < w[1]),
+                      "indices are not strictly increasing");
+        if let Some(&last) = indices.last() {
+            assert!(last < self.len(), "index out of bounds");
+        }
+        // Going from the largest index down, the element swapped into each
+        // place always comes from after every index that is left, so none of
+        // them is invalidated.
+        for &index in indices.iter().rev() {
+            self.swap_remove(index);
         }
     }
 
@@ -1393,6 +1557,16 @@ impl<T> Extend<T> for Vec<T> {
     fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
         <Self as SpecExtend<I>>::spec_extend(self, iter);
     }
+
+    #[inline]
+    fn extend_one(&mut self, item: T) {
+        self.push(item);
+    }
+
+    #[inline]
+    fn extend_reserve(&mut self, additional: usize) {
+        self.reserve(additional);
+    }
 }
 
 impl<I: IntoIterator> SpecExtend<I> for Vec<I::Item> {
@@ -1414,6 +1588,8 @@ impl<T> Vec<T> {
         //      for item in iterator {
         //          self.push(item);
         //      }
+        let (lower, _) = iterator.size_hint();
+        self.reserve(lower);
         while let Some(element) = iterator.next() {
             let len = self.len();
             if len == self.capacity() {
@@ -1434,6 +1610,16 @@ impl<'a, T: 'a + Copy> Extend<&'a T> for Vec<T> {
     fn extend<I: IntoIterator<Item = &'a T>>(&mut self, iter: I) {
         self.extend(iter.into_iter().cloned());
     }
+
+    #[inline]
+    fn extend_one(&mut self, &item: &'a T) {
+        self.push(item);
+    }
+
+    #[inline]
+    fn extend_reserve(&mut self, additional: usize) {
+        self.reserve(additional);
+    }
 }
 
 macro_rules! __impl_slice_eq1 {
@@ -1686,17 +1686,87 @@ impl<T> VecDeque<T> {
     pub fn retain<F>(&mut self, mut f: F)
         where F: FnMut(&T) -> bool
     {
-        let len = self.len();
-        let mut del = 0;
-        for i in 0..len {
-            if !f(&self[i]) {
-                del += 1;
-            } else if del > 0 {
-                self.swap(i - del, i);
+        self.retain_mut(|x| f(x))
+    }
+
+    /// Retains only the elements specified by the predicate, passing a
+    /// mutable reference to it.
+    ///
+    /// In other words, remove all elements `e` such that `f(&mut e)` returns
+    /// false. This method operates in place, visits every element exactly
+    /// once in the original order, and preserves the order of the retained
+    /// elements.
+    ///
+    /// If `f` panics, the elements it has not been called on yet are kept,
+    /// as is the element it panicked on.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(vec_retain_mut)]
+    ///
+    /// use std::collections::VecDeque;
+    ///
+    /// let mut buf = VecDeque::new();
+    /// buf.extend(1..5);
+    /// buf.retain_mut(|x| {
+    ///     *x *= 10;
+    ///     *x != 30
+    /// });
+    ///
+    /// let v: Vec<_> = buf.into_iter().collect();
+    /// assert_eq!(&v[..], &[10, 20, 40]);
+    /// ```
+    #[unstable(feature = "vec_retain_mut", issue = "0")]
+    pub fn retain_mut<F>(&mut self, mut f: F)
+        where F: FnMut(&mut T) -> bool
+    {
+        // This is the same as `Vec::retain_indexed`, with the indices wrapping
+        // around the ring buffer. Until the guard runs, the deque is empty.
+        struct BackshiftOnDrop<'a, T: 'a> {
+            deque: &'a mut VecDeque<T>,
+            processed: usize,
+            deleted: usize,
+            len: usize,
+        }
+
+        impl<'a, T: 'a> Drop for BackshiftOnDrop<'a, T> {
+            fn drop(&mut self) {
+                let tail = self.deque.tail;
+                if self.deleted > 0 {
+                    for i in self.processed..self.len {
+                        let src = self.deque.wrap_add(tail, i);
+                        let dst = self.deque.wrap_add(tail, i - self.deleted);
+                        unsafe { self.deque.copy_nonoverlapping(dst, src, 1) };
+                    }
+                }
+                self.deque.head = self.deque.wrap_add(tail, self.len - self.deleted);
             }
         }
-        if del > 0 {
-            self.truncate(len - del);
+
+        let len = self.len();
+        self.head = self.tail;
+        let mut g = BackshiftOnDrop { deque: self, processed: 0, deleted: 0, len: len };
+
+        while g.processed < len {
+            let tail = g.deque.tail;
+            let idx = g.deque.wrap_add(tail, g.processed);
+            unsafe {
+                let cur = g.deque.ptr().offset(idx as isize);
+                if f(&mut *cur) {
+                    if g.deleted > 0 {
+                        let dst = g.deque.wrap_sub(idx, g.deleted);
+                        g.deque.copy_nonoverlapping(dst, idx, 1);
+                    }
+                    g.processed += 1;
+                } else {
+                    // count it as processed first, so that the guard does not
+                    // keep it if its destructor panics
+                    g.processed += 1;
+                    g.deleted += 1;
+                    ptr::drop_in_place(cur);
+                }
+            }
         }
     }
 }
@@ -2113,10 +2183,23 @@ impl<'a, T> IntoIterator for &'a mut VecDeque<T> {
 #[stable(feature = "rust1", since = "1.0.0")]
 impl<A> Extend<A> for VecDeque<A> {
     fn extend<T: IntoIterator<Item = A>>(&mut self, iter: T) {
-        for elt in iter {
+        let iterator = iter.into_iter();
+        let (lower, _) = iterator.size_hint();
+        self.reserve(lower);
+        for elt in iterator {
             self.push_back(elt);
         }
     }
+
+    #[inline]
+    fn extend_one(&mut self, elt: A) {
+        self.push_back(elt);
+    }
+
+    #[inline]
+    fn extend_reserve(&mut self, additional: usize) {
+        self.reserve(additional);
+    }
 }
 
 #[stable(feature = "extend_ref", since = "1.2.0")]
@@ -2124,6 +2207,16 @@ impl<'a, T: 'a + Copy> Extend<&'a T> for VecDeque<T> {
     fn extend<I: IntoIterator<Item = &'a T>>(&mut self, iter: I) {
         self.extend(iter.into_iter().cloned());
     }
+
+    #[inline]
+    fn extend_one(&mut self, &elt: &'a T) {
+        self.push_back(elt);
+    }
+
+    #[inline]
+    fn extend_reserve(&mut self, additional: usize) {
+        self.reserve(additional);
+    }
 }
 
 #[stable(feature = "rust1", since = "1.0.0")]
@@ -2135,6 +2228,12 @@ impl<T: fmt::Debug> fmt::Debug for VecDeque<T> {
 
 #[stable(feature = "vecdeque_vec_conversions", since = "1.10.0")]
 impl<T> From<Vec<T>> for VecDeque<T> {
+    /// Turns a `Vec<T>` into a `VecDeque<T>`, reusing its buffer.
+    ///
+    /// This is `O(1)` and does not reallocate if the capacity of the vector
+    /// is a power of two, at least two, and larger than its length, because
+    /// the `VecDeque` needs a free slot. Otherwise the buffer is grown to the
+    /// next power of two, which may move the elements.
     fn from(mut other: Vec<T>) -> Self {
         unsafe {
             let other_buf = other.as_mut_ptr();
@@ -2163,6 +2262,12 @@ impl<T> From<Vec<T>> for VecDeque<T> {
 
 #[stable(feature = "vecdeque_vec_conversions", since = "1.10.0")]
 impl<T> From<VecDeque<T>> for Vec<T> {
+    /// Turns a `VecDeque<T>` into a `Vec<T>`, reusing its buffer.
+    ///
+    /// This never reallocates. If the elements are stored at the start of the
+    /// buffer, as they are after creating the `VecDeque` from a `Vec` and
+    /// then pushing to the back, this is `O(1)`. Otherwise the elements are
+    /// moved in place to the start of the buffer, which is `O(n)`.
     fn from(other: VecDeque<T>) -> Self {
         unsafe {
             let buf = other.buf.ptr();
@@ -2173,7 +2278,10 @@ impl<T> From<VecDeque<T>> for Vec<T> {
 
             // Need to move the ring to the front of the buffer, as vec will expect this.
             if other.is_contiguous() {
-                ptr::copy(buf.offset(tail as isize), buf, len);
+                // nothing to do if it is already at the front
+                if tail != 0 {
+                    ptr::copy(buf.offset(tail as isize), buf, len);
+                }
             } else {
                 if (tail - head) >= cmp::min((cap - tail), head) {
                     // There is enough free space in the centre for the shortest block so we can
@@ -2591,4 +2699,27 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn test_vec_conversions_reuse_buffer() {
+        use super::super::vec::Vec;
+
+        for cap_pwr in 1..7 {
+            let cap = 1 << cap_pwr;
+            for len in 0..cap {
+                let mut vec = Vec::with_capacity(cap);
+                vec.extend(0..len);
+                let ptr = vec.as_ptr();
+
+                let vd = VecDeque::from(vec);
+                assert_eq!(vd.cap(), cap);
+                assert_eq!(vd.ptr() as *const _, ptr);
+
+                let vec = Vec::from(vd);
+                assert_eq!(vec.as_ptr(), ptr);
+                assert_eq!(vec.capacity(), cap);
+                assert!(vec.into_iter().eq(0..len));
+            }
+        }
+    }
 }
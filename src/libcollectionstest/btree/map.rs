@@ -479,6 +479,83 @@ fn test_vacant_entry_key() {
     assert_eq!(a[key], value);
 }
 
+#[test]
+fn test_entry_and_modify() {
+    let mut map = BTreeMap::new();
+    for &x in &[3, 1, 3, 2, 3, 1] {
+        map.entry(x).and_modify(|n| *n += 1).or_insert(1);
+    }
+    assert_eq!(map.into_iter().collect::<Vec<_>>(), [(1, 2), (2, 1), (3, 3)]);
+}
+
+#[test]
+fn test_or_insert_with_key() {
+    let mut map = BTreeMap::new();
+    assert_eq!(*map.entry(5).or_insert_with_key(|k| k * 10), 50);
+    assert_eq!(*map.entry(5).or_insert_with_key(|_| panic!()), 50);
+    assert_eq!(map.len(), 1);
+}
+
+#[test]
+fn test_insert_entry() {
+    let mut map = BTreeMap::new();
+    // insert in a scattered order, so that nodes are split everywhere in the tree
+    for i in 0..1000 {
+        let k = i * 7919 % 1000;
+        match map.entry(k) {
+            Occupied(_) => unreachable!(),
+            Vacant(view) => {
+                let mut view = view.insert_entry(k);
+                assert_eq!(*view.key(), k);
+                assert_eq!(view.insert(k * 2), k);
+            }
+        }
+    }
+    assert_eq!(map.len(), 1000);
+    for (i, (&k, &v)) in map.iter().enumerate() {
+        assert_eq!((k, v), (i, i * 2));
+    }
+}
+
+#[test]
+fn test_replace_entry_with() {
+    let mut map: BTreeMap<_, _> = (0..1000).map(|i| (i, i)).collect();
+    let mut removed = 0;
+    for i in 0..1000 {
+        let k = i * 7919 % 1000;
+        match map.entry(k) {
+            Vacant(_) => unreachable!(),
+            Occupied(view) => {
+                match view.replace_entry_with(|&k, v| if k % 3 == 0 { Some(v + 1) } else { None }) {
+                    Occupied(view) => assert_eq!((k % 3, *view.get()), (0, k + 1)),
+                    Vacant(view) => {
+                        assert_eq!(*view.key(), k);
+                        removed += 1;
+                    }
+                }
+            }
+        }
+        assert_eq!(map.len(), 1000 - removed);
+    }
+    assert_eq!(map.len(), 334);
+    assert!(map.iter().all(|(&k, &v)| k % 3 == 0 && v == k + 1));
+
+    // the vacant entry can be filled again
+    match map.entry(1) {
+        Occupied(_) => unreachable!(),
+        Vacant(view) => assert_eq!(*view.insert_entry(5).get(), 5),
+    }
+    match map.entry(0) {
+        Vacant(_) => unreachable!(),
+        Occupied(view) => {
+            if let Vacant(view) = view.replace_entry_with(|_, _| None) {
+                view.insert(7);
+            }
+        }
+    }
+    assert_eq!((map[&0], map[&1], map.len()), (7, 5, 335));
+}
+
 macro_rules! create_append_test {
     ($name:ident, $len:expr) => {
         #[test]
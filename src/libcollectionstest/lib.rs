@@ -17,17 +17,33 @@
 #![feature(collections)]
 #![feature(collections_bound)]
 #![feature(const_fn)]
+#![feature(cow_is_borrowed)]
 #![feature(fn_traits)]
+#![feature(encode_utf16_into)]
 #![feature(enumset)]
+#![feature(entry_and_modify)]
+#![feature(entry_insert)]
+#![feature(entry_or_insert_with_key)]
+#![feature(entry_replace_with)]
+#![feature(extend_one)]
 #![feature(linked_list_contains)]
 #![feature(pattern)]
 #![feature(rand)]
+#![feature(rc_counts)]
+#![feature(slice_binary_search_from)]
+#![feature(slice_copy_within)]
+#![feature(slice_try_copy)]
 #![feature(step_by)]
+#![feature(str_char_index)]
 #![feature(str_escape)]
+#![feature(str_from_utf16_endian)]
+#![feature(str_replace_cow)]
 #![feature(test)]
 #![feature(unboxed_closures)]
 #![feature(unicode)]
 #![feature(vec_deque_contains)]
+#![feature(vec_retain_mut)]
+#![feature(vec_swap_remove_if)]
 
 extern crate collections;
 extern crate test;
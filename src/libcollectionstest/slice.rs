@@ -408,6 +408,32 @@ fn test_binary_search() {
     assert_eq!([1, 2, 3, 4, 5].binary_search(&0).ok(), None);
 }
 
+#[test]
+fn test_binary_search_from() {
+    // every hint finds the same positions as `binary_search` for unique
+    // elements and for the gaps between them
+    let v: Vec<i32> = (0..37).map(|i| i * 2).collect();
+    for hint in 0..v.len() + 3 {
+        for x in -1..76 {
+            assert_eq!(v.binary_search_from(hint, &x), v.binary_search(&x), "{} {}", hint, x);
+        }
+    }
+
+    let empty: [i32; 0] = [];
+    assert_eq!(empty.binary_search_from(0, &1), Err(0));
+    assert_eq!(empty.binary_search_from(5, &1), Err(0));
+
+    let dups = [1, 1, 1, 2, 2, 3];
+    assert_eq!(dups.binary_search_from(1, &1), Ok(1));
+    assert!(match dups.binary_search_from(0, &2) { Ok(3...4) => true, _ => false });
+    assert!(match dups.binary_search_from(5, &1) { Ok(0...2) => true, _ => false });
+
+    // an element near the hint takes only a few comparisons
+    let mut count = 0;
+    assert_eq!(v.binary_search_from_by(20, |p| { count += 1; p.cmp(&44) }), Ok(22));
+    assert_eq!(count, 4);
+}
+
 #[test]
 fn test_reverse() {
     let mut v = vec![10, 20];
@@ -1176,6 +1202,70 @@ fn test_copy_from_slice_dst_shorter() {
     dst.copy_from_slice(&src);
 }
 
+#[test]
+#[should_panic(expected = "(destination has 5 elements, source has 4)")]
+fn test_clone_from_slice_lengths() {
+    let src = [0, 1, 2, 3];
+    let mut dst = [0; 5];
+    dst.clone_from_slice(&src);
+}
+
+#[test]
+fn test_try_copy_from_slice() {
+    let src = [0, 1, 2, 3];
+    let mut dst = [9; 5];
+    let err = dst.try_copy_from_slice(&src).unwrap_err();
+    assert_eq!((err.dst_len(), err.src_len()), (5, 4));
+    assert_eq!(dst, [9; 5]);
+    assert_eq!(dst[1..].try_copy_from_slice(&src), Ok(()));
+    assert_eq!(dst, [9, 0, 1, 2, 3]);
+
+    let src = vec![String::from("a"), String::from("b")];
+    let mut dst = vec![String::new(); 3];
+    let err = dst.try_clone_from_slice(&src).unwrap_err();
+    assert_eq!(err.to_string(),
+               "destination and source slices have different lengths \
+                (destination has 3 elements, source has 2)");
+    assert!(dst[..2].try_clone_from_slice(&src).is_ok());
+    assert_eq!(dst, ["a", "b", ""]);
+}
+
+#[test]
+fn test_copy_within() {
+    let mut v = [0, 1, 2, 3, 4, 5, 6, 7];
+    v.copy_within(0..3, 5);
+    assert_eq!(v, [0, 1, 2, 3, 4, 0, 1, 2]);
+    // overlapping, in both directions
+    v.copy_within(1..6, 2);
+    assert_eq!(v, [0, 1, 1, 2, 3, 4, 0, 2]);
+    v.copy_within(2..8, 0);
+    assert_eq!(v, [1, 2, 3, 4, 0, 2, 0, 2]);
+    v.copy_within(8..8, 8);
+    v.copy_within(3..3, 0);
+    assert_eq!(v, [1, 2, 3, 4, 0, 2, 0, 2]);
+}
+
+#[test]
+#[should_panic(expected = "copy_within source range 2..9 out of range for slice of length 8")]
+fn test_copy_within_src_too_long() {
+    let mut v = [0; 8];
+    v.copy_within(2..9, 0);
+}
+
+#[test]
+#[should_panic(expected = "copy_within destination range 6..9 out of range for slice of length 8")]
+fn test_copy_within_dest_too_close() {
+    let mut v = [0; 8];
+    v.copy_within(0..3, 6);
+}
+
+#[test]
+#[should_panic(expected = "copy_within source range starts at 5 but ends at 4")]
+fn test_copy_within_backwards() {
+    let mut v = [0; 8];
+    v.copy_within(5..4, 0);
+}
+
 mod bench {
     use std::{mem, ptr};
     use std::__rand::{Rng, thread_rng};
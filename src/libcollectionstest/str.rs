@@ -40,6 +40,28 @@ fn test_rfind() {
     assert_eq!("ประเทศไทย中华Việt Nam".rfind(|c: char| c == '华'), Some(30));
 }
 
+#[test]
+fn test_find_char_repeated_bytes() {
+    // U+A041 is `EA 81 81`, so the last byte of the needle also shows up in
+    // the middle of it, and at the end of U+0441 and U+A081.
+    let s = "\u{441}\u{A041}\u{A041}x\u{A081}\u{A041}";
+    assert_eq!(s.find('\u{A041}'), Some(2));
+    assert_eq!(s.rfind('\u{A041}'), Some(12));
+    assert_eq!(s.find('\u{441}'), Some(0));
+    assert_eq!(s.rfind('\u{441}'), Some(0));
+    assert_eq!(s.rfind('\u{A081}'), Some(9));
+    assert!(s.find('\u{81}').is_none());
+    assert!(s.rfind('\u{81}').is_none());
+    assert_eq!(s.rsplit('\u{A041}').collect::<Vec<_>>(), ["", "x\u{A081}", "", "\u{441}"]);
+
+    let mut it = s.match_indices('\u{A041}').map(|(i, _)| i);
+    assert_eq!(it.next(), Some(2));
+    assert_eq!(it.next_back(), Some(12));
+    assert_eq!(it.next_back(), Some(5));
+    assert_eq!(it.next(), None);
+    assert_eq!(it.next_back(), None);
+}
+
 #[test]
 fn test_collect() {
     let empty = "";
@@ -229,6 +251,21 @@ fn test_replace() {
     assert_eq!(" test test ".replace(test, ""), "   ");
 }
 
+#[test]
+fn test_replace_cow() {
+    let a = "a";
+    assert!("".replace_cow(a, "b").is_borrowed());
+    assert!("xyz".replace_cow(a, "b").is_borrowed());
+    assert_eq!("xyz".replace_cow(a, "b"), "xyz");
+    assert!("a".replace_cow(a, "b").is_owned());
+    assert_eq!("a".replace_cow(a, "b"), "b");
+    assert_eq!("ab".replace_cow(a, "b"), "bb");
+    let test = "test";
+    assert_eq!(" test test ".replace_cow(test, "toast"), " toast toast ");
+    assert_eq!(" test test ".replace_cow(test, ""), "   ");
+    assert_eq!("ประเทศไทย中华".replace_cow("ะเ", "x"), "ปรxทศไทย中华");
+}
+
 #[test]
 fn test_replace_2a() {
     let data = "ประเทศไทย中华";
@@ -360,6 +397,62 @@ fn test_is_char_boundary() {
         }
     }
 }
+
+#[test]
+fn test_char_index_conversions() {
+    let s = "ศไทย中华Việt Nam β-release 🐱123";
+    let n = s.chars().count();
+    for (c, (b, ch)) in s.char_indices().enumerate() {
+        assert_eq!(s.byte_index_of_char(c), Some(b));
+        assert_eq!(s.char_index_of_byte(b), Some(c));
+        for j in 1..ch.len_utf8() {
+            assert_eq!(s.char_index_of_byte(b + j), None);
+        }
+    }
+    assert_eq!(s.byte_index_of_char(n), Some(s.len()));
+    assert_eq!(s.byte_index_of_char(n + 1), None);
+    assert_eq!(s.char_index_of_byte(s.len()), Some(n));
+    assert_eq!(s.char_index_of_byte(s.len() + 1), None);
+    assert_eq!("".byte_index_of_char(0), Some(0));
+    assert_eq!("".char_index_of_byte(0), Some(0));
+}
+
+#[test]
+fn test_char_indexer() {
+    use std::str::CharIndexer;
+
+    let s = "ศไทย中华Việt Nam β-release 🐱123";
+    let positions: Vec<_> = s.char_indices().map(|(b, _)| b).chain(Some(s.len())).collect();
+    let indexer = CharIndexer::new(s);
+    // forwards, backwards, and jumping around from the remembered position
+    let order = (0..positions.len()).chain((0..positions.len()).rev())
+                                    .chain(vec![3, 29, 1, 28, 0, 15, 16, 2]);
+    for c in order {
+        assert_eq!(indexer.byte_index(c), Some(positions[c]), "{}", c);
+        assert_eq!(indexer.char_index(positions[c]), Some(c), "{}", c);
+    }
+    assert_eq!(indexer.byte_index(positions.len()), None);
+    assert_eq!(indexer.char_index(1), None);
+    assert_eq!(indexer.char_index(s.len() + 1), None);
+    assert_eq!(indexer.byte_index(4), Some(12));
+
+    assert_eq!(&indexer[4], "中");
+    assert_eq!(&indexer[6..10], "Việt");
+    assert_eq!(&indexer[..4], "ศไทย");
+    assert_eq!(&indexer[25..], "🐱123");
+    assert_eq!(&indexer[29..], "");
+    assert_eq!(indexer.as_str(), s);
+}
+
+#[test]
+#[should_panic(expected = "char index 29 is past the end of the string")]
+fn test_char_indexer_fail() {
+    use std::str::CharIndexer;
+
+    let indexer = CharIndexer::new("ศไทย中华Việt Nam β-release 🐱123");
+    &indexer[29];
+}
+
 const LOREM_PARAGRAPH: &'static str = "\
 Lorem ipsum dolor sit amet, consectetur adipiscing elit. Suspendisse quis lorem sit amet dolor \
 ultricies condimentum. Praesent iaculis purus elit, ac malesuada quam malesuada in. Duis sed orci \
@@ -1144,6 +1237,23 @@ fn test_utf16_code_units() {
                [0xE9, 0xD83D, 0xDCA9])
 }
 
+#[test]
+fn test_encode_utf16_into() {
+    for s in &["", "x", "é\u{1F4A9}", "Löwe 老虎 Léopard", "\u{10FFFF}\u{FFFF}\u{10000}"] {
+        let units: Vec<u16> = s.encode_utf16().collect();
+        assert_eq!(s.encoded_utf16_len(), units.len());
+
+        let mut buf = vec![0xFFFF; units.len() + 1];
+        assert_eq!(s.encode_utf16_into(&mut buf), Some(units.len()));
+        assert_eq!(&buf[..units.len()], &units[..]);
+        assert_eq!(buf[units.len()], 0xFFFF);
+    }
+
+    let mut buf = [0; 2];
+    assert_eq!("é\u{1F4A9}".encode_utf16_into(&mut buf), None);
+    assert_eq!(buf, [0, 0]);
+}
+
 #[test]
 fn starts_with_in_unicode() {
     assert!(!"├── Cargo.toml".starts_with("# "));
@@ -1256,6 +1366,18 @@ fn test_cow_from() {
     }
 }
 
+#[test]
+fn test_cow_is_borrowed() {
+    let borrowed: Cow<str> = Cow::Borrowed("borrowed");
+    let owned: Cow<str> = Cow::Owned(String::from("owned"));
+    assert!(borrowed.is_borrowed() && !borrowed.is_owned());
+    assert!(owned.is_owned() && !owned.is_borrowed());
+
+    let mut cow = borrowed;
+    cow.to_mut().push('!');
+    assert!(cow.is_owned());
+}
+
 mod pattern {
     use std::str::pattern::Pattern;
     use std::str::pattern::{Searcher, ReverseSearcher};
@@ -1393,6 +1515,11 @@ mod pattern {
         Reject(1, 2),
         Reject(2, 3),
     ]);
+    make_test!(char_searcher_multibyte_needle, '\u{A041}', "\u{441}\u{A041}x", [
+        Reject(0, 2),
+        Match (2, 5),
+        Reject(5, 6),
+    ]);
 
 }
 
@@ -171,6 +171,41 @@ fn test_from_utf16_lossy() {
                String::from("\u{FFFD}𐒋\u{FFFD}"));
 }
 
+#[test]
+fn test_from_utf16_endian() {
+    let s = "𐐒𐑉𐐮 a·\u{20000}";
+    let units: Vec<u16> = s.encode_utf16().collect();
+    let le: Vec<u8> = units.iter().flat_map(|&u| vec![u as u8, (u >> 8) as u8]).collect();
+    let be: Vec<u8> = units.iter().flat_map(|&u| vec![(u >> 8) as u8, u as u8]).collect();
+    assert_eq!(String::from_utf16le(&le).unwrap(), s);
+    assert_eq!(String::from_utf16be(&be).unwrap(), s);
+    assert_eq!(String::from_utf16le_lossy(&le), s);
+    assert_eq!(String::from_utf16be_lossy(&be), s);
+    // the bytes of a slice that starts in the middle of a `u16` decode all the same
+    let mut odd = vec![0];
+    odd.extend_from_slice(&le);
+    assert_eq!(String::from_utf16le(&odd[1..]).unwrap(), s);
+
+    // only the byte order mark of the given order is skipped
+    assert_eq!(String::from_utf16le(&[0xFF, 0xFE, 0x61, 0x00]).unwrap(), "a");
+    assert_eq!(String::from_utf16be(&[0xFE, 0xFF, 0x00, 0x61]).unwrap(), "a");
+    assert_eq!(String::from_utf16le(&[0xFE, 0xFF, 0x61, 0x00]).unwrap(), "\u{FFFE}a");
+    assert_eq!(String::from_utf16be(&[0xFF, 0xFE]).unwrap(), "\u{FFFE}");
+    assert_eq!(String::from_utf16le(&[0xFF, 0xFE, 0xFF, 0xFE]).unwrap(), "\u{FEFF}");
+    assert_eq!(String::from_utf16le(&[]).unwrap(), "");
+
+    // odd lengths and invalid data
+    assert!(String::from_utf16le(&[0x61]).is_err());
+    assert!(String::from_utf16be(&[0x00, 0x61, 0x00]).is_err());
+    assert!(String::from_utf16le(&[0x00, 0xD8, 0x61, 0x00]).is_err());
+    assert!(String::from_utf16be(&[0xDC, 0x00]).is_err());
+    assert_eq!(String::from_utf16le_lossy(&[0x61]), "\u{FFFD}");
+    assert_eq!(String::from_utf16be_lossy(&[0x00, 0x61, 0x00]), "a\u{FFFD}");
+    assert_eq!(String::from_utf16le_lossy(&[0x00, 0xD8, 0x61, 0x00]), "\u{FFFD}a");
+    assert_eq!(String::from_utf16be_lossy(&[0xFE, 0xFF, 0xD8, 0x00, 0xDC]),
+               "\u{FFFD}\u{FFFD}");
+}
+
 #[test]
 fn test_push_bytes() {
     let mut s = String::from("ABC");
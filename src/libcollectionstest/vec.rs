@@ -117,6 +117,27 @@ fn test_extend_ref() {
     assert_eq!(v, [1, 2, 3, 4, 5, 6, 7]);
 }
 
+#[test]
+fn test_extend_one_reserve() {
+    let mut v: Vec<i32> = Vec::new();
+    Extend::<i32>::extend_reserve(&mut v, 10);
+    assert!(v.capacity() >= 10);
+    let cap = v.capacity();
+    v.extend_one(1);
+    v.extend_one(&2);
+    assert_eq!(v, [1, 2]);
+    assert_eq!(v.capacity(), cap);
+
+    let mut s = String::new();
+    Extend::<char>::extend_reserve(&mut s, 3);
+    assert!(s.capacity() >= 3);
+    s.extend_one('a');
+    s.extend_one(&'b');
+    s.extend_one("cd");
+    s.extend_one(String::from("é"));
+    assert_eq!(s, "abcdé");
+}
+
 #[test]
 fn test_slice_from_mut() {
     let mut values = vec![1, 2, 3, 4, 5];
@@ -212,6 +233,131 @@ fn test_retain() {
     assert_eq!(vec, [2, 4]);
 }
 
+#[test]
+fn test_retain_mut() {
+    let mut vec = vec![1, 2, 3, 4, 5];
+    vec.retain_mut(|x| {
+        *x += 1;
+        *x % 2 == 0
+    });
+    assert_eq!(vec, [2, 4, 6]);
+
+    let mut vec: Vec<i32> = vec![];
+    vec.retain_mut(|_| false);
+    assert_eq!(vec, []);
+}
+
+#[test]
+fn test_retain_indexed() {
+    let mut vec = vec![10, 11, 11, 12, 13];
+    let mut seen = vec![];
+    vec.retain_indexed(|i, x| {
+        seen.push(i);
+        *x == 10 + i as i32
+    });
+    assert_eq!(vec, [10, 11, 13]);
+    assert_eq!(seen, [0, 1, 2, 3, 4]);
+}
+
+#[test]
+fn test_retain_drops() {
+    let mut drops = [0; 6];
+    {
+        let mut vec: Vec<_> = drops.iter_mut().map(|d| DropCounter { count: d }).collect();
+        let mut i = 0;
+        vec.retain(|_| {
+            i += 1;
+            i % 3 != 0
+        });
+        assert_eq!(vec.len(), 4);
+    }
+    assert_eq!(drops, [1; 6]);
+}
+
+#[test]
+fn test_retain_panic() {
+    use std::panic::{self, AssertUnwindSafe};
+    use std::rc::Rc;
+
+    let rcs: Vec<_> = (0..6).map(Rc::new).collect();
+    let mut vec = rcs.clone();
+    let result = panic::catch_unwind(AssertUnwindSafe(|| {
+        vec.retain_indexed(|i, x| {
+            if i == 3 {
+                panic!("retain panic");
+            }
+            **x != 1
+        })
+    }));
+    assert!(result.is_err());
+    // the element that was removed is dropped, and the rest kept in order
+    let values: Vec<_> = vec.iter().map(|x| **x).collect();
+    assert_eq!(values, [0, 2, 3, 4, 5]);
+    assert_eq!(Rc::strong_count(&rcs[1]), 1);
+    assert!(rcs.iter().enumerate().all(|(i, x)| i == 1 || Rc::strong_count(x) == 2));
+}
+
+#[test]
+fn test_swap_remove_if() {
+    let mut vec = vec![1, 2, 3, 4, 5, 6, 9];
+    let mut seen = vec![];
+    vec.swap_remove_if(|x| {
+        seen.push(*x);
+        *x % 3 == 0
+    });
+    assert_eq!(vec, [1, 2, 5, 4]);
+    seen.sort();
+    assert_eq!(seen, [1, 2, 3, 4, 5, 6, 9]);
+
+    vec.swap_remove_if(|_| true);
+    assert_eq!(vec, []);
+
+    let mut drops = [0; 6];
+    {
+        let mut vec: Vec<_> = drops.iter_mut().map(|d| DropCounter { count: d }).collect();
+        let mut i = 0;
+        vec.swap_remove_if(|_| {
+            i += 1;
+            i % 2 == 0
+        });
+        assert_eq!(vec.len(), 3);
+    }
+    assert_eq!(drops, [1; 6]);
+}
+
+#[test]
+fn test_swap_remove_many() {
+    let mut vec = vec!['a', 'b', 'c', 'd', 'e', 'f'];
+    vec.swap_remove_many(&[0, 1, 4]);
+    assert_eq!(vec, ['d', 'f', 'c']);
+
+    // the last elements, and nothing at all
+    let mut vec: Vec<_> = (0..8).collect();
+    vec.swap_remove_many(&[2, 6, 7]);
+    assert_eq!(vec, [0, 1, 5, 3, 4]);
+    vec.swap_remove_many(&[]);
+    assert_eq!(vec, [0, 1, 5, 3, 4]);
+    vec.swap_remove_many(&[0, 1, 2, 3, 4]);
+    assert_eq!(vec, []);
+}
+
+#[test]
+fn test_swap_remove_many_out_of_bounds() {
+    use std::panic::{self, AssertUnwindSafe};
+
+    let mut vec = vec![1, 2, 3];
+    let result = panic::catch_unwind(AssertUnwindSafe(|| vec.swap_remove_many(&[0, 3])));
+    assert!(result.is_err());
+    assert_eq!(vec, [1, 2, 3]);
+}
+
+#[test]
+#[cfg_attr(debug_assertions, should_panic)]
+fn test_swap_remove_many_unsorted() {
+    let mut vec = vec![1, 2, 3];
+    vec.swap_remove_many(&[1, 1]);
+}
+
 #[test]
 fn zero_sized_values() {
     let mut v = Vec::new();
@@ -961,6 +961,61 @@ fn test_retain() {
     assert_eq!(&v[..], &[2, 4]);
 }
 
+#[test]
+fn test_vec_from_vec_deque() {
+    let mut buf: VecDeque<_> = (1..4).collect();
+    buf.push_front(0);
+    buf.push_front(-1);
+    assert_eq!(Vec::from(buf), [-1, 0, 1, 2, 3]);
+
+    let mut buf = VecDeque::with_capacity(7);
+    buf.extend(0..6);
+    buf.drain(..5);
+    buf.extend(6..12);
+    assert_eq!(Vec::from(buf), [5, 6, 7, 8, 9, 10, 11]);
+}
+
+#[test]
+fn test_retain_mut() {
+    // with the elements wrapping around the end of the buffer
+    let mut buf = VecDeque::with_capacity(7);
+    buf.extend(0..5);
+    buf.drain(..4);
+    buf.extend(5..11);
+    buf.retain_mut(|x| {
+        *x *= 10;
+        *x != 60 && *x != 90
+    });
+    let v: Vec<_> = buf.into_iter().collect();
+    assert_eq!(&v[..], &[40, 50, 70, 80, 100]);
+}
+
+#[test]
+fn test_retain_panic() {
+    use std::panic::{self, AssertUnwindSafe};
+    use std::rc::Rc;
+
+    let rcs: Vec<_> = (0..6).map(Rc::new).collect();
+    let mut buf = VecDeque::with_capacity(7);
+    buf.extend(rcs.iter().take(4).cloned());
+    buf.drain(..4);
+    buf.extend(rcs.iter().cloned());
+    let result = panic::catch_unwind(AssertUnwindSafe(|| {
+        buf.retain_mut(|x| {
+            if **x == 3 {
+                panic!("retain panic");
+            }
+            **x != 1
+        })
+    }));
+    assert!(result.is_err());
+    // the element that was removed is dropped, and the rest kept in order
+    let values: Vec<_> = buf.iter().map(|x| **x).collect();
+    assert_eq!(values, [0, 2, 3, 4, 5]);
+    assert_eq!(Rc::strong_count(&rcs[1]), 1);
+    assert!(rcs.iter().enumerate().all(|(i, x)| i == 1 || Rc::strong_count(x) == 2));
+}
+
 #[test]
 fn test_extend_ref() {
     let mut v = VecDeque::new();
@@ -72,7 +72,7 @@
 #![stable(feature = "rust1", since = "1.0.0")]
 
 use fmt;
-use marker::Send;
+use marker::{Send, Sync};
 use mem::transmute;
 use option::Option::{self, Some, None};
 use raw::TraitObject;
@@ -124,6 +124,13 @@ impl fmt::Debug for Any + Send {
     }
 }
 
+#[unstable(feature = "any_downcast", issue = "0")]
+impl fmt::Debug for Any + Send + Sync {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.pad("Any")
+    }
+}
+
 impl Any {
     /// Returns true if the boxed type is the same as `T`
     #[stable(feature = "rust1", since = "1.0.0")]
@@ -174,6 +181,34 @@ impl Any {
             None
         }
     }
+
+    /// Returns a reference to the boxed value as type `T`, without checking
+    /// that it is of that type.
+    ///
+    /// # Safety
+    ///
+    /// The value must be of type `T`; `is::<T>()` must be true.
+    #[unstable(feature = "any_downcast", issue = "0")]
+    #[inline]
+    pub unsafe fn downcast_ref_unchecked<T: Any>(&self) -> &T {
+        debug_assert!(self.is::<T>());
+        let to: TraitObject = transmute(self);
+        &*(to.data as *const T)
+    }
+
+    /// Returns a mutable reference to the boxed value as type `T`, without
+    /// checking that it is of that type.
+    ///
+    /// # Safety
+    ///
+    /// The value must be of type `T`; `is::<T>()` must be true.
+    #[unstable(feature = "any_downcast", issue = "0")]
+    #[inline]
+    pub unsafe fn downcast_mut_unchecked<T: Any>(&mut self) -> &mut T {
+        debug_assert!(self.is::<T>());
+        let to: TraitObject = transmute(self);
+        &mut *(to.data as *const T as *mut T)
+    }
 }
 
 impl Any+Send {
@@ -197,6 +232,57 @@ impl Any+Send {
     pub fn downcast_mut<T: Any>(&mut self) -> Option<&mut T> {
         Any::downcast_mut::<T>(self)
     }
+
+    /// Forwards to the method defined on the type `Any`.
+    #[unstable(feature = "any_downcast", issue = "0")]
+    #[inline]
+    pub unsafe fn downcast_ref_unchecked<T: Any>(&self) -> &T {
+        Any::downcast_ref_unchecked::<T>(self)
+    }
+
+    /// Forwards to the method defined on the type `Any`.
+    #[unstable(feature = "any_downcast", issue = "0")]
+    #[inline]
+    pub unsafe fn downcast_mut_unchecked<T: Any>(&mut self) -> &mut T {
+        Any::downcast_mut_unchecked::<T>(self)
+    }
+}
+
+impl Any+Send+Sync {
+    /// Forwards to the method defined on the type `Any`.
+    #[unstable(feature = "any_downcast", issue = "0")]
+    #[inline]
+    pub fn is<T: Any>(&self) -> bool {
+        Any::is::<T>(self)
+    }
+
+    /// Forwards to the method defined on the type `Any`.
+    #[unstable(feature = "any_downcast", issue = "0")]
+    #[inline]
+    pub fn downcast_ref<T: Any>(&self) -> Option<&T> {
+        Any::downcast_ref::<T>(self)
+    }
+
+    /// Forwards to the method defined on the type `Any`.
+    #[unstable(feature = "any_downcast", issue = "0")]
+    #[inline]
+    pub fn downcast_mut<T: Any>(&mut self) -> Option<&mut T> {
+        Any::downcast_mut::<T>(self)
+    }
+
+    /// Forwards to the method defined on the type `Any`.
+    #[unstable(feature = "any_downcast", issue = "0")]
+    #[inline]
+    pub unsafe fn downcast_ref_unchecked<T: Any>(&self) -> &T {
+        Any::downcast_ref_unchecked::<T>(self)
+    }
+
+    /// Forwards to the method defined on the type `Any`.
+    #[unstable(feature = "any_downcast", issue = "0")]
+    #[inline]
+    pub unsafe fn downcast_mut_unchecked<T: Any>(&mut self) -> &mut T {
+        Any::downcast_mut_unchecked::<T>(self)
+    }
 }
 
 
@@ -211,7 +297,8 @@ impl Any+Send {
 /// printing, and showing.
 ///
 /// A `TypeId` is currently only available for types which ascribe to `'static`,
-/// but this limitation may be removed in the future.
+/// but this limitation may be removed in the future. `TypeId::of_erased`
+/// identifies other types for diagnostics with an `ErasedTypeId`.
 #[derive(Clone, Copy, PartialEq, Eq, Debug, Hash)]
 #[stable(feature = "rust1", since = "1.0.0")]
 pub struct TypeId {
@@ -227,4 +314,77 @@ impl TypeId {
             t: unsafe { intrinsics::type_id::<T>() },
         }
     }
+
+    /// Returns the `ErasedTypeId` of `T` with all of its lifetimes erased,
+    /// so that `&'a str` has the same identifier as `&'static str`.
+    ///
+    /// This is meant for diagnostics only. The identifier says nothing about
+    /// the lifetimes of a value, so it can't be compared with a `TypeId` or
+    /// be used to justify a downcast; use `TypeId::of` for that.
+    #[unstable(feature = "type_id_erased", issue = "0")]
+    pub fn of_erased<T: ?Sized>() -> ErasedTypeId {
+        ErasedTypeId {
+            t: unsafe { intrinsics::type_id_erased::<T>() },
+        }
+    }
+
+    /// Returns the identifier as an integer, e.g. to compare types across
+    /// dynamic libraries.
+    ///
+    /// The value is a hash of the type that is only stable for a given
+    /// compiler version and set of crates; it may change with any other
+    /// compiler, and should not be persisted.
+    #[unstable(feature = "type_id_u64", issue = "0")]
+    pub fn as_u64(&self) -> u64 {
+        self.t
+    }
+}
+
+/// An `ErasedTypeId` identifies a type with its lifetimes erased.
+///
+/// It is created by `TypeId::of_erased` for any type, including ones that
+/// borrow, and is meant for diagnostics only. Types that differ only in
+/// their lifetimes have the same `ErasedTypeId`.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Hash)]
+#[unstable(feature = "type_id_erased", issue = "0")]
+pub struct ErasedTypeId {
+    t: u64,
+}
+
+/// Returns the name of a type as a string slice, for diagnostics.
+///
+/// The exact contents of the name, such as whether paths are included, are
+/// not specified and may change between compiler versions.
+///
+/// # Examples
+///
+/// ```
+/// #![feature(type_name)]
+///
+/// use std::any::type_name;
+///
+/// assert!(type_name::<Option<i32>>().ends_with("Option<i32>"));
+/// ```
+#[unstable(feature = "type_name", issue = "0")]
+pub fn type_name<T: ?Sized>() -> &'static str {
+    unsafe { intrinsics::type_name::<T>() }
+}
+
+/// Returns the name of the type of the pointed-to value, like `type_name`.
+///
+/// This allows naming types that cannot be written out, like closures.
+///
+/// # Examples
+///
+/// ```
+/// #![feature(type_name)]
+///
+/// use std::any::type_name_of_val;
+///
+/// assert_eq!(type_name_of_val(&1.0f32), "f32");
+/// assert_eq!(type_name_of_val("foo"), "str");
+/// ```
+#[unstable(feature = "type_name", issue = "0")]
+pub fn type_name_of_val<T: ?Sized>(_val: &T) -> &'static str {
+    type_name::<T>()
 }
@@ -27,8 +27,11 @@ use default::Default;
 use fmt;
 use hash::{Hash, self};
 use iter::IntoIterator;
-use marker::{Copy, Sized, Unsize};
-use option::Option;
+use marker::{Copy, PhantomData, Sized, Unsize};
+use mem;
+use ops::{ChangeOutputType, ControlFlow, Drop, FnMut, FromResidual, Residual, Try};
+use option::Option::{self, Some};
+use ptr;
 use slice::{Iter, IterMut, SliceExt};
 
 /// Utility trait implemented only on arrays of fixed size
@@ -62,6 +65,71 @@ unsafe impl<T, A: Unsize<[T]>> FixedSizeArray<T> for A {
     }
 }
 
+/// Creates an array by calling a fallible function with the index of each
+/// element in order, and returns the first failure if there is one.
+///
+/// The function returns a `Try` type like `Result<T, E>`, and `try_from_fn`
+/// returns the same kind of type wrapped around the array. The elements
+/// created before a failure are dropped.
+///
+/// # Examples
+///
+/// ```
+/// #![feature(fixed_size_array, try_trait_v2)]
+///
+/// use std::array;
+///
+/// let a: Result<[u8; 3], _> = array::try_from_fn(|i| ["1", "2", "3"][i].parse::<u8>());
+/// assert_eq!(a, Ok([1, 2, 3]));
+///
+/// let b: Option<[u8; 4]> = array::try_from_fn(|i| 250u8.checked_add(2 * i as u8));
+/// assert_eq!(b, None);
+/// ```
+pub fn try_from_fn<A, T, F, R>(mut f: F) -> ChangeOutputType<R, A> where
+    A: FixedSizeArray<T>,
+    F: FnMut(usize) -> R,
+    R: Try<Output = T>,
+    <R as Try>::Residual: Residual<A>,
+{
+    // Drops the initialized prefix of the array if `f` fails or panics. The
+    // rest of the array is uninitialized, so it is never dropped or moved
+    // out as a whole until every element is written.
+    struct Guard<A: FixedSizeArray<T>, T> {
+        array: A,
+        initialized: usize,
+        marker: PhantomData<T>,
+    }
+
+    impl<A: FixedSizeArray<T>, T> Drop for Guard<A, T> {
+        fn drop(&mut self) {
+            for x in &mut self.array.as_mut_slice()[..self.initialized] {
+                unsafe { ptr::drop_in_place(x) }
+            }
+        }
+    }
+
+    let mut guard: Guard<A, T> = Guard {
+        array: unsafe { mem::uninitialized() },
+        initialized: 0,
+        marker: PhantomData,
+    };
+    let len = guard.array.as_slice().len();
+    while guard.initialized < len {
+        let i = guard.initialized;
+        match f(i).branch() {
+            ControlFlow::Continue(x) => unsafe {
+                ptr::write(&mut guard.array.as_mut_slice()[i], x)
+            },
+            ControlFlow::Break(r) => return FromResidual::from_residual(r),
+        }
+        guard.initialized += 1;
+    }
+    // the array is fully initialized, so move it out and skip the guard's drop
+    let array = unsafe { ptr::read(&guard.array) };
+    mem::forget(guard);
+    Try::from_output(array)
+}
+
 macro_rules! __impl_slice_eq1 {
     ($Lhs: ty, $Rhs: ty) => {
         __impl_slice_eq1! { $Lhs, $Rhs, Sized }
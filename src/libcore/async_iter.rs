@@ -0,0 +1,170 @@
+// Copyright 2016 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Composable asynchronous iteration.
+//!
+//! An [`AsyncIterator`] is to [`Iterator`] what a `Future` is to a plain
+//! value: asking it for the next item may have to wait, in which case it
+//! returns `Poll::Pending` and wakes the current task once an item (or the
+//! end of the iteration) is available.
+//!
+//! [`AsyncIterator`]: trait.AsyncIterator.html
+//! [`Iterator`]: ../iter/trait.Iterator.html
+
+#![unstable(feature = "async_iterator", issue = "0")]
+
+use iter::{IntoIterator, Iterator};
+use marker::{PhantomData, Sized};
+use option::Option::{self, Some, None};
+use task::{Context, Poll};
+
+/// An interface for dealing with asynchronous iterators.
+///
+/// This is the main async iterator trait. It works like `Iterator`, except
+/// that `poll_next` can return `Poll::Pending` when the next item isn't
+/// available yet. `Poll::Ready(None)` marks the end of the iteration, after
+/// which `poll_next` should not be called again.
+pub trait AsyncIterator {
+    /// The type of items yielded by the async iterator.
+    type Item;
+
+    /// Attempts to pull out the next value of this async iterator,
+    /// registering the current task for wakeup if the value is not yet
+    /// available, and returning `Poll::Ready(None)` if the async iterator
+    /// is exhausted.
+    fn poll_next(&mut self, cx: &mut Context) -> Poll<Option<Self::Item>>;
+
+    /// Returns the bounds on the remaining length of the async iterator.
+    ///
+    /// This has the same meaning and the same default as
+    /// `Iterator::size_hint`.
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, None)
+    }
+}
+
+impl<'a, I: ?Sized + AsyncIterator> AsyncIterator for &'a mut I {
+    type Item = I::Item;
+
+    fn poll_next(&mut self, cx: &mut Context) -> Poll<Option<I::Item>> {
+        (**self).poll_next(cx)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (**self).size_hint()
+    }
+}
+
+/// Conversion into an `AsyncIterator`.
+///
+/// This is the counterpart of `IntoIterator`, and the anchor for any
+/// future language support for iterating asynchronously over a value.
+/// Every `AsyncIterator` converts into itself.
+pub trait IntoAsyncIterator {
+    /// The type of the item yielded by the async iterator.
+    type Item;
+
+    /// The type of the resulting async iterator.
+    type IntoAsyncIter: AsyncIterator<Item = Self::Item>;
+
+    /// Converts `self` into an async iterator.
+    fn into_async_iter(self) -> Self::IntoAsyncIter;
+}
+
+impl<I: AsyncIterator> IntoAsyncIterator for I {
+    type Item = I::Item;
+    type IntoAsyncIter = I;
+
+    #[inline]
+    fn into_async_iter(self) -> I {
+        self
+    }
+}
+
+/// An async iterator that was created from an iterator.
+///
+/// This `struct` is created by the [`from_iter`] function. See its
+/// documentation for more.
+///
+/// [`from_iter`]: fn.from_iter.html
+#[derive(Clone, Debug)]
+#[must_use = "async iterators do nothing unless polled"]
+pub struct FromIter<I> {
+    iter: I,
+}
+
+/// Converts an iterator into an async iterator that is always ready with
+/// its next item.
+///
+/// # Examples
+///
+/// ```
+/// #![feature(async_iterator, futures_api, noop_waker)]
+///
+/// use std::async_iter::{self, AsyncIterator};
+/// use std::task::{Context, Poll, Waker};
+///
+/// let waker = Waker::noop();
+/// let mut cx = Context::from_waker(&waker);
+///
+/// let mut iter = async_iter::from_iter(vec![1, 2]);
+/// assert_eq!(iter.poll_next(&mut cx), Poll::Ready(Some(1)));
+/// assert_eq!(iter.poll_next(&mut cx), Poll::Ready(Some(2)));
+/// assert_eq!(iter.poll_next(&mut cx), Poll::Ready(None));
+/// ```
+pub fn from_iter<I: IntoIterator>(iter: I) -> FromIter<I::IntoIter> {
+    FromIter { iter: iter.into_iter() }
+}
+
+impl<I: Iterator> AsyncIterator for FromIter<I> {
+    type Item = I::Item;
+
+    #[inline]
+    fn poll_next(&mut self, _cx: &mut Context) -> Poll<Option<I::Item>> {
+        Poll::Ready(self.iter.next())
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+/// An async iterator that yields nothing.
+///
+/// This `struct` is created by the [`empty`] function. See its
+/// documentation for more.
+///
+/// [`empty`]: fn.empty.html
+#[derive(Clone, Debug)]
+#[must_use = "async iterators do nothing unless polled"]
+pub struct Empty<T> {
+    _marker: PhantomData<T>,
+}
+
+/// Creates an async iterator that is immediately exhausted.
+pub fn empty<T>() -> Empty<T> {
+    Empty { _marker: PhantomData }
+}
+
+impl<T> AsyncIterator for Empty<T> {
+    type Item = T;
+
+    #[inline]
+    fn poll_next(&mut self, _cx: &mut Context) -> Poll<Option<T>> {
+        Poll::Ready(None)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, Some(0))
+    }
+}
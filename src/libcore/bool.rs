@@ -0,0 +1,94 @@
+// Copyright 2016 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Inherent methods for the `bool` primitive type.
+
+use option::Option::{self, Some, None};
+use ops::FnOnce;
+use result::Result::{self, Ok, Err};
+
+#[cfg(not(stage0))]
+#[lang = "bool"]
+impl bool {
+    /// Returns `Some(t)` if the `bool` is `true`, or `None` otherwise.
+    ///
+    /// The argument is evaluated eagerly; use [`then`](#method.then) to only
+    /// compute it when the `bool` is `true`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(bool_to_option)]
+    ///
+    /// assert_eq!(false.then_some(0), None);
+    /// assert_eq!(true.then_some(0), Some(0));
+    /// ```
+    #[unstable(feature = "bool_to_option", issue = "0")]
+    #[inline]
+    pub fn then_some<T>(self, t: T) -> Option<T> {
+        if self { Some(t) } else { None }
+    }
+
+    /// Returns `Some(f())` if the `bool` is `true`, or `None` otherwise.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(bool_to_option)]
+    ///
+    /// assert_eq!(false.then(|| 0), None);
+    /// assert_eq!(true.then(|| 0), Some(0));
+    /// ```
+    #[unstable(feature = "bool_to_option", issue = "0")]
+    #[inline]
+    pub fn then<T, F: FnOnce() -> T>(self, f: F) -> Option<T> {
+        if self { Some(f()) } else { None }
+    }
+
+    /// Returns `Ok(())` if the `bool` is `true`, or `Err(err)` otherwise.
+    ///
+    /// This allows a condition to be checked with the `?` operator.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(bool_to_option)]
+    ///
+    /// fn check_len(s: &str) -> Result<(), &'static str> {
+    ///     (s.len() < 8).ok_or("too long")
+    /// }
+    ///
+    /// assert_eq!(check_len("short"), Ok(()));
+    /// assert_eq!(check_len("far too long"), Err("too long"));
+    /// ```
+    #[unstable(feature = "bool_to_option", issue = "0")]
+    #[inline]
+    pub fn ok_or<E>(self, err: E) -> Result<(), E> {
+        if self { Ok(()) } else { Err(err) }
+    }
+
+    /// Returns `Ok(())` if the `bool` is `true`, or `Err(f())` otherwise.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(bool_to_option)]
+    ///
+    /// let limit = 3;
+    /// assert_eq!((2 < limit).ok_or_else(|| format!("{} is too big", 2)), Ok(()));
+    /// assert_eq!((5 < limit).ok_or_else(|| format!("{} is too big", 5)),
+    ///            Err("5 is too big".to_string()));
+    /// ```
+    #[unstable(feature = "bool_to_option", issue = "0")]
+    #[inline]
+    pub fn ok_or_else<E, F: FnOnce() -> E>(self, f: F) -> Result<(), E> {
+        if self { Ok(()) } else { Err(f()) }
+    }
+}
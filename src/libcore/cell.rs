@@ -147,10 +147,14 @@
 use clone::Clone;
 use cmp::{PartialEq, Eq, PartialOrd, Ord, Ordering};
 use default::Default;
+use fmt;
 use marker::{Copy, Send, Sync, Sized, Unsize};
+use mem;
 use ops::{Deref, DerefMut, Drop, FnOnce, CoerceUnsized};
 use option::Option;
 use option::Option::{None, Some};
+use result::Result;
+use result::Result::{Ok, Err};
 
 /// A mutable memory location that admits only `Copy` data.
 ///
@@ -244,6 +248,83 @@ impl<T:Copy> Cell<T> {
             &mut *self.value.get()
         }
     }
+
+    /// Updates the contained value using a function and returns the new
+    /// value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(cell_update)]
+    ///
+    /// use std::cell::Cell;
+    ///
+    /// let c = Cell::new(5);
+    /// let new = c.update(|x| x + 1);
+    ///
+    /// assert_eq!(new, 6);
+    /// assert_eq!(c.get(), 6);
+    /// ```
+    #[inline]
+    #[unstable(feature = "cell_update", issue = "0")]
+    pub fn update<F>(&self, f: F) -> T where F: FnOnce(T) -> T {
+        let new = f(self.get());
+        self.set(new);
+        new
+    }
+
+    /// Returns a `&Cell<T>` from a `&mut T`.
+    ///
+    /// Since the mutable borrow guarantees that there is no other reference
+    /// to the value, it may be shared as a `Cell` for as long as that
+    /// borrow lasts.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(as_cell)]
+    ///
+    /// use std::cell::Cell;
+    ///
+    /// let mut x = 1;
+    /// {
+    ///     let c = Cell::from_mut(&mut x);
+    ///     let c2 = c;
+    ///     c2.set(c.get() + 1);
+    /// }
+    /// assert_eq!(x, 2);
+    /// ```
+    #[inline]
+    #[unstable(feature = "as_cell", issue = "0")]
+    pub fn from_mut(t: &mut T) -> &Cell<T> {
+        // `Cell<T>` and `UnsafeCell<T>` both consist of a single `T`.
+        unsafe { &*(t as *mut T as *const Cell<T>) }
+    }
+}
+
+macro_rules! array_of_cells_impls {
+    ($($N:expr)+) => {
+        $(
+            impl<T: Copy> Cell<[T; $N]> {
+                /// Returns a `&[Cell<T>; N]` from a `&Cell<[T; N]>`, to
+                /// access the elements of the array individually.
+                #[inline]
+                #[unstable(feature = "as_cell", issue = "0")]
+                pub fn as_array_of_cells(&self) -> &[Cell<T>; $N] {
+                    // An array of `Cell<T>` has the same layout as an
+                    // array of `T`, and the cells only ever hand out copies.
+                    unsafe { &*(self as *const Cell<[T; $N]> as *const [Cell<T>; $N]) }
+                }
+            }
+        )+
+    }
+}
+
+array_of_cells_impls! {
+     0  1  2  3  4  5  6  7  8  9
+    10 11 12 13 14 15 16 17 18 19
+    20 21 22 23 24 25 26 27 28 29
+    30 31 32
 }
 
 #[stable(feature = "rust1", since = "1.0.0")]
@@ -321,6 +402,7 @@ impl<T:Ord + Copy> Ord for Cell<T> {
 #[stable(feature = "rust1", since = "1.0.0")]
 pub struct RefCell<T: ?Sized> {
     borrow: Cell<BorrowFlag>,
+    borrowed_at: Cell<Option<&'static Location>>,
     value: UnsafeCell<T>,
 }
 
@@ -336,12 +418,71 @@ pub enum BorrowState {
     Unused,
 }
 
+/// An error returned by [`RefCell::try_borrow`](struct.RefCell.html#method.try_borrow).
+///
+/// If the outstanding mutable borrow was taken with `borrow_mut_at!`, its
+/// `Display` output includes the source location of that borrow.
+#[unstable(feature = "try_borrow", issue = "0")]
+pub struct BorrowError {
+    borrowed_at: Option<&'static Location>,
+}
+
+#[unstable(feature = "try_borrow", issue = "0")]
+impl fmt::Debug for BorrowError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("BorrowError").finish()
+    }
+}
+
+#[unstable(feature = "try_borrow", issue = "0")]
+impl fmt::Display for BorrowError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.borrowed_at {
+            Some(&(file, line)) => write!(f, "already mutably borrowed at {}:{}", file, line),
+            None => fmt::Display::fmt("already mutably borrowed", f),
+        }
+    }
+}
+
+/// An error returned by [`RefCell::try_borrow_mut`](struct.RefCell.html#method.try_borrow_mut).
+///
+/// If the outstanding borrow was taken with `borrow_at!` or `borrow_mut_at!`,
+/// its `Display` output includes the source location of that borrow.
+#[unstable(feature = "try_borrow", issue = "0")]
+pub struct BorrowMutError {
+    borrowed_at: Option<&'static Location>,
+}
+
+#[unstable(feature = "try_borrow", issue = "0")]
+impl fmt::Debug for BorrowMutError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("BorrowMutError").finish()
+    }
+}
+
+#[unstable(feature = "try_borrow", issue = "0")]
+impl fmt::Display for BorrowMutError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.borrowed_at {
+            Some(&(file, line)) => write!(f, "already borrowed at {}:{}", file, line),
+            None => fmt::Display::fmt("already borrowed", f),
+        }
+    }
+}
+
 // Values [1, MAX-1] represent the number of `Ref` active
 // (will not outgrow its range since `usize` is the size of the address space)
 type BorrowFlag = usize;
 const UNUSED: BorrowFlag = 0;
 const WRITING: BorrowFlag = !0;
 
+// A file name and line number, as given by `file!()` and `line!()`.
+//
+// A `RefCell` keeps a reference to the location of the borrow that was taken
+// while it was unused, so that it only grows by a pointer. Only borrows taken
+// with `borrow_at!` and `borrow_mut_at!` have a location.
+type Location = (&'static str, u32);
+
 impl<T> RefCell<T> {
     /// Creates a new `RefCell` containing `value`.
     ///
@@ -358,6 +499,7 @@ impl<T> RefCell<T> {
         RefCell {
             value: UnsafeCell::new(value),
             borrow: Cell::new(UNUSED),
+            borrowed_at: Cell::new(None),
         }
     }
 
@@ -381,6 +523,89 @@ impl<T> RefCell<T> {
         debug_assert!(self.borrow.get() == UNUSED);
         unsafe { self.value.into_inner() }
     }
+
+    /// Replaces the wrapped value with a new one, returning the old value,
+    /// without deinitializing either one.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value is currently borrowed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(refcell_replace)]
+    ///
+    /// use std::cell::RefCell;
+    ///
+    /// let c = RefCell::new(5);
+    /// let old = c.replace(6);
+    ///
+    /// assert_eq!(old, 5);
+    /// assert_eq!(c.into_inner(), 6);
+    /// ```
+    #[unstable(feature = "refcell_replace", issue = "0")]
+    #[inline]
+    pub fn replace(&self, t: T) -> T {
+        mem::replace(&mut *self.borrow_mut(), t)
+    }
+
+    /// Replaces the wrapped value with a new one computed from `f`, returning
+    /// the old value, without deinitializing either one.
+    ///
+    /// The value stays mutably borrowed while `f` runs.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value is currently borrowed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(refcell_replace)]
+    ///
+    /// use std::cell::RefCell;
+    ///
+    /// let c = RefCell::new(5);
+    /// let old = c.replace_with(|&mut old| old + 1);
+    ///
+    /// assert_eq!(old, 5);
+    /// assert_eq!(c.into_inner(), 6);
+    /// ```
+    #[unstable(feature = "refcell_replace", issue = "0")]
+    #[inline]
+    pub fn replace_with<F: FnOnce(&mut T) -> T>(&self, f: F) -> T {
+        let mut borrow = self.borrow_mut();
+        let new = f(&mut *borrow);
+        mem::replace(&mut *borrow, new)
+    }
+}
+
+impl<T: Default> RefCell<T> {
+    /// Takes the wrapped value, leaving `Default::default()` in its place.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value is currently borrowed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(refcell_replace)]
+    ///
+    /// use std::cell::RefCell;
+    ///
+    /// let c = RefCell::new(vec![1, 2]);
+    /// let v = c.take();
+    ///
+    /// assert_eq!(v, [1, 2]);
+    /// assert!(c.into_inner().is_empty());
+    /// ```
+    #[unstable(feature = "refcell_replace", issue = "0")]
+    #[inline]
+    pub fn take(&self) -> T {
+        self.replace(Default::default())
+    }
 }
 
 impl<T: ?Sized> RefCell<T> {
@@ -436,12 +661,71 @@ impl<T: ?Sized> RefCell<T> {
     #[stable(feature = "rust1", since = "1.0.0")]
     #[inline]
     pub fn borrow(&self) -> Ref<T> {
+        match self.borrow_from(None) {
+            Ok(r) => r,
+            Err(e) => panic!("RefCell<T> {}", e),
+        }
+    }
+
+    /// Immutably borrows the wrapped value, returning an error if the value is
+    /// currently mutably borrowed.
+    ///
+    /// The borrow lasts until the returned `Ref` exits scope. Multiple
+    /// immutable borrows can be taken out at the same time.
+    ///
+    /// This is the non-panicking variant of [`borrow`](#method.borrow).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(try_borrow)]
+    ///
+    /// use std::cell::RefCell;
+    ///
+    /// let c = RefCell::new(5);
+    ///
+    /// {
+    ///     let m = c.borrow_mut();
+    ///     assert!(c.try_borrow().is_err());
+    /// }
+    ///
+    /// {
+    ///     let m = c.borrow();
+    ///     assert!(c.try_borrow().is_ok());
+    /// }
+    /// ```
+    #[unstable(feature = "try_borrow", issue = "0")]
+    #[inline]
+    pub fn try_borrow(&self) -> Result<Ref<T>, BorrowError> {
+        self.borrow_from(None)
+    }
+
+    /// Like `try_borrow`, but records `at`, a file name and line number, as
+    /// the location of the borrow, so that errors caused by it can report it.
+    ///
+    /// This is what `borrow_at!` calls, which passes the location of its own
+    /// invocation.
+    #[unstable(feature = "refcell_borrow_location", issue = "0")]
+    #[inline]
+    pub fn try_borrow_at(&self, at: &'static (&'static str, u32))
+                         -> Result<Ref<T>, BorrowError> {
+        self.borrow_from(Some(at))
+    }
+
+    #[inline]
+    fn borrow_from(&self, at: Option<&'static Location>) -> Result<Ref<T>, BorrowError> {
+        let unused = self.borrow.get() == UNUSED;
         match BorrowRef::new(&self.borrow) {
-            Some(b) => Ref {
-                value: unsafe { &*self.value.get() },
-                borrow: b,
-            },
-            None => panic!("RefCell<T> already mutably borrowed"),
+            Some(b) => {
+                if unused {
+                    self.borrowed_at.set(at);
+                }
+                Ok(Ref {
+                    value: unsafe { &*self.value.get() },
+                    borrow: b,
+                })
+            }
+            None => Err(BorrowError { borrowed_at: self.borrowed_at.get() }),
         }
     }
 
@@ -484,12 +768,66 @@ impl<T: ?Sized> RefCell<T> {
     #[stable(feature = "rust1", since = "1.0.0")]
     #[inline]
     pub fn borrow_mut(&self) -> RefMut<T> {
+        match self.borrow_mut_from(None) {
+            Ok(r) => r,
+            Err(e) => panic!("RefCell<T> {}", e),
+        }
+    }
+
+    /// Mutably borrows the wrapped value, returning an error if the value is
+    /// currently borrowed.
+    ///
+    /// The borrow lasts until the returned `RefMut` exits scope. The value
+    /// cannot be borrowed while this borrow is active.
+    ///
+    /// This is the non-panicking variant of [`borrow_mut`](#method.borrow_mut).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(try_borrow)]
+    ///
+    /// use std::cell::RefCell;
+    ///
+    /// let c = RefCell::new(5);
+    ///
+    /// {
+    ///     let m = c.borrow();
+    ///     assert!(c.try_borrow_mut().is_err());
+    /// }
+    ///
+    /// assert!(c.try_borrow_mut().is_ok());
+    /// ```
+    #[unstable(feature = "try_borrow", issue = "0")]
+    #[inline]
+    pub fn try_borrow_mut(&self) -> Result<RefMut<T>, BorrowMutError> {
+        self.borrow_mut_from(None)
+    }
+
+    /// Like `try_borrow_mut`, but records `at`, a file name and line number,
+    /// as the location of the borrow, so that errors caused by it can report
+    /// it.
+    ///
+    /// This is what `borrow_mut_at!` calls, which passes the location of its
+    /// own invocation.
+    #[unstable(feature = "refcell_borrow_location", issue = "0")]
+    #[inline]
+    pub fn try_borrow_mut_at(&self, at: &'static (&'static str, u32))
+                             -> Result<RefMut<T>, BorrowMutError> {
+        self.borrow_mut_from(Some(at))
+    }
+
+    #[inline]
+    fn borrow_mut_from(&self, at: Option<&'static Location>) -> Result<RefMut<T>, BorrowMutError> {
         match BorrowRefMut::new(&self.borrow) {
-            Some(b) => RefMut {
-                value: unsafe { &mut *self.value.get() },
-                borrow: b,
-            },
-            None => panic!("RefCell<T> already borrowed"),
+            Some(b) => {
+                self.borrowed_at.set(at);
+                Ok(RefMut {
+                    value: unsafe { &mut *self.value.get() },
+                    borrow: b,
+                })
+            }
+            None => Err(BorrowMutError { borrowed_at: self.borrowed_at.get() }),
         }
     }
 
@@ -889,3 +1227,145 @@ impl<T: Default> Default for UnsafeCell<T> {
         UnsafeCell::new(Default::default())
     }
 }
+
+/// A value which is computed on first access.
+///
+/// This is the single-threaded counterpart of `std::sync::LazyLock`. The
+/// first dereference runs the function given to `new`, and every later one
+/// returns the stored result.
+///
+/// If the function panics, the `LazyCell` is poisoned, and any further
+/// attempt to get at the value panics as well. So does getting at the value
+/// from within the function.
+///
+/// As with `LazyLock`, these are associated functions rather than methods, so
+/// that they don't shadow methods of `T`.
+///
+/// # Examples
+///
+/// ```
+/// #![feature(lazy_cell)]
+///
+/// use std::cell::LazyCell;
+///
+/// let lazy = LazyCell::new(|| {
+///     println!("initializing");
+///     92
+/// });
+/// assert_eq!(LazyCell::get(&lazy), None);
+/// assert_eq!(*lazy, 92);
+/// assert_eq!(LazyCell::get(&lazy), Some(&92));
+/// ```
+#[unstable(feature = "lazy_cell", issue = "0")]
+pub struct LazyCell<T, F = fn() -> T> {
+    state: UnsafeCell<LazyState<T, F>>,
+}
+
+enum LazyState<T, F> {
+    Uninit(F),
+    Init(T),
+    Poisoned,
+}
+
+impl<T, F> LazyCell<T, F> {
+    /// Creates a `LazyCell` which computes its value with `f`.
+    #[unstable(feature = "lazy_cell", issue = "0")]
+    pub const fn new(f: F) -> LazyCell<T, F> {
+        LazyCell { state: UnsafeCell::new(LazyState::Uninit(f)) }
+    }
+
+    /// Returns the value, or `None` if it hasn't been computed yet.
+    #[unstable(feature = "lazy_cell", issue = "0")]
+    pub fn get(this: &LazyCell<T, F>) -> Option<&T> {
+        match unsafe { &*this.state.get() } {
+            &LazyState::Init(ref value) => Some(value),
+            _ => None,
+        }
+    }
+
+    /// Returns a mutable reference to the value, or `None` if it hasn't been
+    /// computed yet.
+    #[unstable(feature = "lazy_cell", issue = "0")]
+    pub fn get_mut(this: &mut LazyCell<T, F>) -> Option<&mut T> {
+        match unsafe { &mut *this.state.get() } {
+            &mut LazyState::Init(ref mut value) => Some(value),
+            _ => None,
+        }
+    }
+
+    /// Consumes the `LazyCell`, returning the value if it has been computed,
+    /// and otherwise the function that would have computed it.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the `LazyCell` is poisoned.
+    #[unstable(feature = "lazy_cell", issue = "0")]
+    pub fn into_inner(this: LazyCell<T, F>) -> Result<T, F> {
+        match unsafe { this.state.into_inner() } {
+            LazyState::Init(value) => Ok(value),
+            LazyState::Uninit(f) => Err(f),
+            LazyState::Poisoned => panic!(LAZY_CELL_POISONED),
+        }
+    }
+}
+
+impl<T, F: FnOnce() -> T> LazyCell<T, F> {
+    /// Computes the value if that hasn't been done yet, and returns it.
+    ///
+    /// This is what dereferencing a `LazyCell` does.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the `LazyCell` is poisoned, and propagates the panic of the
+    /// function if it is the one that poisons it.
+    #[unstable(feature = "lazy_cell", issue = "0")]
+    pub fn force(this: &LazyCell<T, F>) -> &T {
+        if let Some(value) = LazyCell::get(this) {
+            return value
+        }
+        // Poison the cell while `f` runs, which stops it from being forced
+        // again from within `f`, and keeps it poisoned if `f` panics.
+        let state = unsafe { &mut *this.state.get() };
+        let f = match mem::replace(state, LazyState::Poisoned) {
+            LazyState::Uninit(f) => f,
+            _ => panic!(LAZY_CELL_POISONED),
+        };
+        let value = f();
+        let state = unsafe { &mut *this.state.get() };
+        *state = LazyState::Init(value);
+        LazyCell::get(this).unwrap()
+    }
+
+    /// Computes the value if that hasn't been done yet, and returns a mutable
+    /// reference to it.
+    ///
+    /// # Panics
+    ///
+    /// Panics like `force`.
+    #[unstable(feature = "lazy_cell", issue = "0")]
+    pub fn force_mut(this: &mut LazyCell<T, F>) -> &mut T {
+        LazyCell::force(this);
+        LazyCell::get_mut(this).unwrap()
+    }
+}
+
+const LAZY_CELL_POISONED: &'static str = "LazyCell instance has previously been poisoned";
+
+#[unstable(feature = "lazy_cell", issue = "0")]
+impl<T, F: FnOnce() -> T> Deref for LazyCell<T, F> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        LazyCell::force(self)
+    }
+}
+
+#[unstable(feature = "lazy_cell", issue = "0")]
+impl<T: fmt::Debug, F> fmt::Debug for LazyCell<T, F> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match LazyCell::get(self) {
+            Some(value) => write!(f, "LazyCell {{ value: {:?} }}", value),
+            None => write!(f, "LazyCell {{ <uninit> }}"),
+        }
+    }
+}
@@ -271,6 +271,8 @@ pub trait CharExt {
     fn encode_utf8(self) -> EncodeUtf8;
     #[unstable(feature = "unicode", issue = "27784")]
     fn encode_utf16(self) -> EncodeUtf16;
+    #[unstable(feature = "encode_utf16_into", issue = "0")]
+    fn encode_utf16_checked(self, dst: &mut [u16]) -> Option<&mut [u16]>;
 }
 
 #[stable(feature = "core", since = "1.6.0")]
@@ -389,6 +391,23 @@ impl CharExt for char {
         };
         EncodeUtf16 { buf: buf, pos: pos }
     }
+
+    #[inline]
+    fn encode_utf16_checked(self, dst: &mut [u16]) -> Option<&mut [u16]> {
+        let len = self.len_utf16();
+        if dst.len() < len {
+            return None;
+        }
+        let code = self as u32;
+        if len == 1 {
+            dst[0] = code as u16;
+        } else {
+            let code = code - 0x1_0000;
+            dst[0] = 0xD800 | ((code >> 10) as u16);
+            dst[1] = 0xDC00 | ((code as u16) & 0x3FF);
+        }
+        Some(&mut dst[..len])
+    }
 }
 
 /// Returns an iterator that yields the hexadecimal Unicode escape of a
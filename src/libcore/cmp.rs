@@ -569,6 +569,80 @@ pub fn max<T: Ord>(v1: T, v2: T) -> T {
     if v2 >= v1 { v2 } else { v1 }
 }
 
+/// Compare two values and return them sorted, minimum first.
+///
+/// Returns `[v1, v2]` if the comparison determines them to be equal, in
+/// agreement with `min` and `max`.
+///
+/// # Examples
+///
+/// ```
+/// #![feature(cmp_minmax)]
+///
+/// use std::cmp;
+///
+/// assert_eq!(cmp::minmax(1, 2), [1, 2]);
+/// assert_eq!(cmp::minmax(2, 1), [1, 2]);
+///
+/// let sorted = cmp::minmax(42, 17);
+/// assert_eq!(sorted[0], 17);
+/// assert_eq!(sorted[1], 42);
+/// ```
+#[inline]
+#[unstable(feature = "cmp_minmax", issue = "0")]
+pub fn minmax<T: Ord>(v1: T, v2: T) -> [T; 2] {
+    if v2 < v1 { [v2, v1] } else { [v1, v2] }
+}
+
+/// Compare two values with respect to the specified comparison function and
+/// return them sorted, minimum first.
+///
+/// Returns `[v1, v2]` if the comparison determines them to be equal.
+///
+/// # Examples
+///
+/// ```
+/// #![feature(cmp_minmax)]
+///
+/// use std::cmp;
+///
+/// assert_eq!(cmp::minmax_by(-2, 1, |x: &i32, y: &i32| x.abs().cmp(&y.abs())), [1, -2]);
+/// assert_eq!(cmp::minmax_by(-2, 2, |x: &i32, y: &i32| x.abs().cmp(&y.abs())), [-2, 2]);
+/// ```
+#[inline]
+#[unstable(feature = "cmp_minmax", issue = "0")]
+pub fn minmax_by<T, F>(v1: T, v2: T, compare: F) -> [T; 2]
+    where F: FnOnce(&T, &T) -> Ordering
+{
+    match compare(&v1, &v2) {
+        Ordering::Less | Ordering::Equal => [v1, v2],
+        Ordering::Greater => [v2, v1],
+    }
+}
+
+/// Return the two values sorted by the key computed by the specified
+/// function, minimum first.
+///
+/// Returns `[v1, v2]` if the keys are equal.
+///
+/// # Examples
+///
+/// ```
+/// #![feature(cmp_minmax)]
+///
+/// use std::cmp;
+///
+/// assert_eq!(cmp::minmax_by_key(-2, 1, |x: &i32| x.abs()), [1, -2]);
+/// assert_eq!(cmp::minmax_by_key(-2, 2, |x: &i32| x.abs()), [-2, 2]);
+/// ```
+#[inline]
+#[unstable(feature = "cmp_minmax", issue = "0")]
+pub fn minmax_by_key<T, F, K>(v1: T, v2: T, mut f: F) -> [T; 2]
+    where F: FnMut(&T) -> K, K: Ord
+{
+    if f(&v2) < f(&v1) { [v2, v1] } else { [v1, v2] }
+}
+
 // Implementation of PartialEq, Eq, PartialOrd and Ord for primitive types
 mod impls {
     use cmp::{PartialOrd, Ord, PartialEq, Eq, Ordering};
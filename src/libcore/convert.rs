@@ -40,6 +40,9 @@
 
 #![stable(feature = "rust1", since = "1.0.0")]
 
+use clone::Clone;
+use cmp::{PartialEq, Eq};
+use fmt;
 use marker::Sized;
 use result::Result;
 
@@ -278,3 +281,43 @@ impl AsRef<str> for str {
         self
     }
 }
+
+/// A type with no values, for conversions that cannot fail.
+///
+/// Since no value of this type can exist, `Result<T, Infallible>` is always
+/// `Ok` and `Result<Infallible, E>` is always `Err`. This is how the `Try`
+/// implementations in `ops` spell out the residual of a short-circuiting
+/// `?`, which carries only the error.
+#[unstable(feature = "convert_infallible", issue = "0")]
+pub enum Infallible {}
+
+#[unstable(feature = "convert_infallible", issue = "0")]
+impl Clone for Infallible {
+    fn clone(&self) -> Infallible {
+        match *self {}
+    }
+}
+
+#[unstable(feature = "convert_infallible", issue = "0")]
+impl fmt::Debug for Infallible {
+    fn fmt(&self, _: &mut fmt::Formatter) -> fmt::Result {
+        match *self {}
+    }
+}
+
+#[unstable(feature = "convert_infallible", issue = "0")]
+impl fmt::Display for Infallible {
+    fn fmt(&self, _: &mut fmt::Formatter) -> fmt::Result {
+        match *self {}
+    }
+}
+
+#[unstable(feature = "convert_infallible", issue = "0")]
+impl PartialEq for Infallible {
+    fn eq(&self, _: &Infallible) -> bool {
+        match *self {}
+    }
+}
+
+#[unstable(feature = "convert_infallible", issue = "0")]
+impl Eq for Infallible {}
@@ -17,7 +17,8 @@ use prelude::v1::*;
 use cell::{UnsafeCell, Cell, RefCell, Ref, RefMut, BorrowState};
 use marker::PhantomData;
 use mem;
-use num::flt2dec;
+use num::{flt2dec, Float, NanPayload, f16, f128};
+use num::dec2flt::rawfp::RawFloat;
 use ops::Deref;
 use result;
 use slice;
@@ -772,6 +773,69 @@ pub trait UpperExp {
     fn fmt(&self, &mut Formatter) -> Result;
 }
 
+/// Format trait for the `g` character.
+///
+/// The `LowerGeneral` trait should format its output like `%g` of C, in either
+/// decimal or scientific notation depending on the magnitude, with a lower-case
+/// `e`.
+///
+/// For floats, the precision is the number of significant digits, and the
+/// scientific notation is used when the decimal exponent is less than -4 or
+/// not less than the precision. Trailing zeroes are removed unless the `#`
+/// flag is given. Without a precision, the shortest digits that read back as
+/// the same number are printed, and the scientific notation is used when the
+/// exponent is less than -4 or at least 16.
+///
+/// For more information on formatters, see [the module-level documentation][module].
+///
+/// [module]: ../../std/fmt/index.html
+///
+/// # Examples
+///
+/// Basic usage with `f64`:
+///
+/// ```
+/// #![feature(fmt_general)]
+///
+/// assert_eq!(format!("{:g}", 1234.5), "1234.5");
+/// assert_eq!(format!("{:g}", 1e-7), "1e-7");
+/// assert_eq!(format!("{:.3g}", 1234.5), "1.23e3");
+/// assert_eq!(format!("{:.3g}", 0.5), "0.5");
+/// assert_eq!(format!("{:#.3g}", 0.5), "0.500");
+/// ```
+#[unstable(feature = "fmt_general", issue = "0")]
+pub trait LowerGeneral {
+    /// Formats the value using the given formatter.
+    #[unstable(feature = "fmt_general", issue = "0")]
+    fn fmt(&self, &mut Formatter) -> Result;
+}
+
+/// Format trait for the `G` character.
+///
+/// The `UpperGeneral` trait should format its output like `LowerGeneral`,
+/// except that the scientific notation uses an upper-case `E`.
+///
+/// For more information on formatters, see [the module-level documentation][module].
+///
+/// [module]: ../../std/fmt/index.html
+///
+/// # Examples
+///
+/// Basic usage with `f64`:
+///
+/// ```
+/// #![feature(fmt_general)]
+///
+/// assert_eq!(format!("{:G}", 1e100), "1E100");
+/// assert_eq!(format!("{:G}", 0.001), "0.001");
+/// ```
+#[unstable(feature = "fmt_general", issue = "0")]
+pub trait UpperGeneral {
+    /// Formats the value using the given formatter.
+    #[unstable(feature = "fmt_general", issue = "0")]
+    fn fmt(&self, &mut Formatter) -> Result;
+}
+
 /// The `write` function takes an output stream, a precompiled format string,
 /// and a list of arguments. The arguments will be formatted according to the
 /// specified format string into the output stream provided.
@@ -1358,6 +1422,7 @@ macro_rules! fmt_refs {
 }
 
 fmt_refs! { Debug, Display, Octal, Binary, LowerHex, UpperHex, LowerExp, UpperExp }
+fmt_refs! { LowerGeneral, UpperGeneral }
 
 #[stable(feature = "rust1", since = "1.0.0")]
 impl Debug for bool {
@@ -1521,6 +1586,62 @@ fn float_to_exponential_common<T>(fmt: &mut Formatter, num: &T, upper: bool) ->
     fmt.pad_formatted_parts(&formatted)
 }
 
+// Common code of floating point LowerGeneral and UpperGeneral.
+fn float_to_general_common<T>(fmt: &mut Formatter, num: &T, upper: bool) -> Result
+    where T: flt2dec::DecodableFloat
+{
+    let force_sign = fmt.sign_plus();
+    let sign = match force_sign {
+        false => flt2dec::Sign::Minus,
+        true  => flt2dec::Sign::MinusPlus,
+    };
+
+    let mut buf = [0; 1024]; // enough for f32 and f64
+    let mut parts = [flt2dec::Part::Zero(0); 16];
+    let formatted = if let Some(precision) = fmt.precision {
+        // a precision of 0 is treated as 1, as in C
+        let ndigits = if precision == 0 { 1 } else { precision };
+        flt2dec::to_exact_general_str(flt2dec::strategy::grisu::format_exact, *num, sign,
+                                      ndigits, fmt.alternate(), upper, &mut buf, &mut parts)
+    } else {
+        flt2dec::to_shortest_exp_str(flt2dec::strategy::grisu::format_shortest, *num, sign,
+                                     (-4, 16), upper, &mut buf, &mut parts)
+    };
+    fmt.pad_formatted_parts(&formatted)
+}
+
+// Debug of NaNs through `NanPayload`, which shows their sign and payload in the syntax that
+// `FromStr` accepts, like `-NaN(0x1f)`.
+fn float_nan_payload<T>(fmt: &mut Formatter, num: &T) -> Result
+    where T: flt2dec::DecodableFloat + Float
+{
+    let negative = num.integer_decode2().2 < 0;
+    let sign: &'static [u8] = match (negative, fmt.sign_plus()) {
+        (true, _) => b"-",
+        (false, true) => b"+",
+        (false, false) => b"",
+    };
+
+    let mut payload = Float::payload(*num).unwrap_or(0);
+    if payload == 0 {
+        return fmt.pad_formatted_parts(&flt2dec::Formatted {
+            sign: sign,
+            parts: &[flt2dec::Part::Copy(b"NaN")],
+        });
+    }
+    let mut buf = [0; 16];
+    let mut start = buf.len();
+    while payload != 0 {
+        start -= 1;
+        buf[start] = b"0123456789abcdef"[(payload & 0xf) as usize];
+        payload >>= 4;
+    }
+    let parts = [flt2dec::Part::Copy(b"NaN(0x"),
+                 flt2dec::Part::Copy(&buf[start..]),
+                 flt2dec::Part::Copy(b")")];
+    fmt.pad_formatted_parts(&flt2dec::Formatted { sign: sign, parts: &parts })
+}
+
 macro_rules! floating { ($ty:ident) => {
 
     #[stable(feature = "rust1", since = "1.0.0")]
@@ -1530,6 +1651,17 @@ macro_rules! floating { ($ty:ident) => {
         }
     }
 
+    #[unstable(feature = "float_nan_payload", issue = "0")]
+    impl Debug for NanPayload<$ty> {
+        fn fmt(&self, fmt: &mut Formatter) -> Result {
+            if self.0.is_nan() {
+                float_nan_payload(fmt, &self.0)
+            } else {
+                float_to_decimal_common(fmt, &self.0, true)
+            }
+        }
+    }
+
     #[stable(feature = "rust1", since = "1.0.0")]
     impl Display for $ty {
         fn fmt(&self, fmt: &mut Formatter) -> Result {
@@ -1550,9 +1682,90 @@ macro_rules! floating { ($ty:ident) => {
             float_to_exponential_common(fmt, self, true)
         }
     }
+
+    #[unstable(feature = "fmt_general", issue = "0")]
+    impl LowerGeneral for $ty {
+        fn fmt(&self, fmt: &mut Formatter) -> Result {
+            float_to_general_common(fmt, self, false)
+        }
+    }
+
+    #[unstable(feature = "fmt_general", issue = "0")]
+    impl UpperGeneral for $ty {
+        fn fmt(&self, fmt: &mut Formatter) -> Result {
+            float_to_general_common(fmt, self, true)
+        }
+    }
 } }
 floating! { f32 }
 floating! { f64 }
+floating! { f16 }
+
+// `f128` generates its digits with its own bignums, and needs a larger buffer for the exact
+// modes than the other floats.
+fn f128_to_decimal(fmt: &mut Formatter, num: f128, negative_zero: bool) -> Result {
+    let force_sign = fmt.sign_plus();
+    let sign = match (force_sign, negative_zero) {
+        (false, false) => flt2dec::Sign::Minus,
+        (false, true)  => flt2dec::Sign::MinusRaw,
+        (true,  false) => flt2dec::Sign::MinusPlus,
+        (true,  true)  => flt2dec::Sign::MinusPlusRaw,
+    };
+
+    let mut buf = [0; flt2dec::wide::MAX_BUF_LEN];
+    let mut parts = [flt2dec::Part::Zero(0); 16];
+    let formatted = if let Some(precision) = fmt.precision {
+        flt2dec::wide::to_exact_fixed_str(num, sign, precision, false, &mut buf, &mut parts)
+    } else {
+        flt2dec::wide::to_shortest_str(num, sign, 0, false, &mut buf, &mut parts)
+    };
+    fmt.pad_formatted_parts(&formatted)
+}
+
+fn f128_to_exponential(fmt: &mut Formatter, num: f128, upper: bool) -> Result {
+    let force_sign = fmt.sign_plus();
+    let sign = match force_sign {
+        false => flt2dec::Sign::Minus,
+        true  => flt2dec::Sign::MinusPlus,
+    };
+
+    let mut buf = [0; flt2dec::wide::MAX_BUF_LEN];
+    let mut parts = [flt2dec::Part::Zero(0); 16];
+    let formatted = if let Some(precision) = fmt.precision {
+        flt2dec::wide::to_exact_exp_str(num, sign, precision + 1, upper, &mut buf, &mut parts)
+    } else {
+        flt2dec::wide::to_shortest_exp_str(num, sign, (0, 0), upper, &mut buf, &mut parts)
+    };
+    fmt.pad_formatted_parts(&formatted)
+}
+
+#[unstable(feature = "f128", issue = "0")]
+impl Debug for f128 {
+    fn fmt(&self, fmt: &mut Formatter) -> Result {
+        f128_to_decimal(fmt, *self, true)
+    }
+}
+
+#[unstable(feature = "f128", issue = "0")]
+impl Display for f128 {
+    fn fmt(&self, fmt: &mut Formatter) -> Result {
+        f128_to_decimal(fmt, *self, false)
+    }
+}
+
+#[unstable(feature = "f128", issue = "0")]
+impl LowerExp for f128 {
+    fn fmt(&self, fmt: &mut Formatter) -> Result {
+        f128_to_exponential(fmt, *self, false)
+    }
+}
+
+#[unstable(feature = "f128", issue = "0")]
+impl UpperExp for f128 {
+    fn fmt(&self, fmt: &mut Formatter) -> Result {
+        f128_to_exponential(fmt, *self, true)
+    }
+}
 
 // Implementation of Display/Debug for various core types
 
@@ -16,17 +16,20 @@
 
 use prelude::v1::*;
 
+use convert::From;
 use fmt;
-use num::Zero;
+use i128::i128;
 use ops::{Div, Rem, Sub};
 use str;
 use slice;
 use ptr;
 use mem;
+use u128::u128;
 
 #[doc(hidden)]
-trait Int: Zero + PartialEq + PartialOrd + Div<Output=Self> + Rem<Output=Self> +
+trait Int: PartialEq + PartialOrd + Div<Output=Self> + Rem<Output=Self> +
            Sub<Output=Self> + Copy {
+    fn zero() -> Self;
     fn from_u8(u: u8) -> Self;
     fn to_u8(&self) -> u8;
     fn to_u16(&self) -> u16;
@@ -36,6 +39,7 @@ trait Int: Zero + PartialEq + PartialOrd + Div<Output=Self> + Rem<Output=Self> +
 
 macro_rules! doit {
     ($($t:ident)*) => ($(impl Int for $t {
+        fn zero() -> $t { 0 }
         fn from_u8(u: u8) -> $t { u as $t }
         fn to_u8(&self) -> u8 { *self as u8 }
         fn to_u16(&self) -> u16 { *self as u16 }
@@ -45,6 +49,19 @@ macro_rules! doit {
 }
 doit! { i8 i16 i32 i64 isize u8 u16 u32 u64 usize }
 
+// `u128` and `i128` are not primitives, so they can't be cast with `as`.
+macro_rules! doit_wide {
+    ($($t:ident)*) => ($(impl Int for $t {
+        fn zero() -> $t { $t::from(0u8) }
+        fn from_u8(u: u8) -> $t { $t::from(u) }
+        fn to_u8(&self) -> u8 { self.to_parts().1 as u8 }
+        fn to_u16(&self) -> u16 { self.to_parts().1 as u16 }
+        fn to_u32(&self) -> u32 { self.to_parts().1 as u32 }
+        fn to_u64(&self) -> u64 { self.to_parts().1 }
+    })*)
+}
+doit_wide! { i128 u128 }
+
 /// A type that represents a specific radix
 #[doc(hidden)]
 trait GenericRadix {
@@ -61,11 +78,11 @@ trait GenericRadix {
 
     /// Format an integer using the radix using a formatter.
     fn fmt_int<T: Int>(&self, mut x: T, f: &mut fmt::Formatter) -> fmt::Result {
-        // The radix can be as low as 2, so we need a buffer of at least 64
-        // characters for a base 2 number.
+        // The radix can be as low as 2, so we need a buffer of at least 128
+        // characters for a base 2 `u128`.
         let zero = T::zero();
         let is_nonnegative = x >= zero;
-        let mut buf = [0; 64];
+        let mut buf = [0; 128];
         let mut curr = buf.len();
         let base = T::from_u8(self.base());
         if is_nonnegative {
@@ -185,6 +202,45 @@ integer! { i16, u16 }
 integer! { i32, u32 }
 integer! { i64, u64 }
 
+// The radix traits format the bits of an `i128` as a `u128`, like they do
+// for the primitives with the `as` cast in `int_base!`.
+macro_rules! wide_int_base {
+    ($Trait:ident for $T:ident, $to_u128:expr, $Radix:ident) => {
+        #[unstable(feature = "i128", issue = "0")]
+        impl fmt::$Trait for $T {
+            fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                $Radix.fmt_int($to_u128(*self), f)
+            }
+        }
+    }
+}
+
+macro_rules! wide_integer {
+    ($($Int:ident, $to_u128:expr;)*) => ($(
+        wide_int_base! { Binary   for $Int, $to_u128, Binary }
+        wide_int_base! { Octal    for $Int, $to_u128, Octal }
+        wide_int_base! { LowerHex for $Int, $to_u128, LowerHex }
+        wide_int_base! { UpperHex for $Int, $to_u128, UpperHex }
+
+        // Decimal is the only radix in which the signed numbers are
+        // formatted with a sign, so it takes them as they are.
+        #[unstable(feature = "i128", issue = "0")]
+        impl fmt::Display for $Int {
+            fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                Decimal.fmt_int(*self, f)
+            }
+        }
+
+        #[unstable(feature = "i128", issue = "0")]
+        impl fmt::Debug for $Int {
+            fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                fmt::Display::fmt(self, f)
+            }
+        }
+    )*)
+}
+wide_integer! { i128, i128::as_u128; u128, u128::from; }
+
 const DEC_DIGITS_LUT: &'static[u8] =
     b"0001020304050607080910111213141516171819\
       2021222324252627282930313233343536373839\
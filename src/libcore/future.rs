@@ -0,0 +1,188 @@
+// Copyright 2016 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Asynchronous values.
+
+#![unstable(feature = "futures_api", issue = "0")]
+
+use convert::Infallible;
+use marker::Sized;
+use ops::{ControlFlow, FromResidual, Try};
+use option::Option::{self, Some};
+use task::{Context, Poll};
+
+/// A future represents an asynchronous computation.
+///
+/// A future is a value that might not have finished computing yet. It is
+/// driven by an executor calling `poll` until it returns `Poll::Ready`;
+/// while it can't make progress, it returns `Poll::Pending` and arranges
+/// for the waker of the given [`Context`](../task/struct.Context.html) to
+/// be woken when it can.
+///
+/// Once a future has returned `Ready`, it should not be polled again, and
+/// may panic or misbehave if it is.
+///
+/// # Examples
+///
+/// ```
+/// #![feature(futures_api)]
+///
+/// use std::future::Future;
+/// use std::task::{self, Context, Poll};
+///
+/// // A future that is ready on its second poll.
+/// struct YieldOnce(bool);
+///
+/// impl Future for YieldOnce {
+///     type Output = &'static str;
+///
+///     fn poll(&mut self, cx: &mut Context) -> Poll<&'static str> {
+///         if self.0 {
+///             return Poll::Ready("done")
+///         }
+///         self.0 = true;
+///         cx.waker().wake_by_ref();
+///         Poll::Pending
+///     }
+/// }
+///
+/// assert_eq!(task::block_on(YieldOnce(false)), "done");
+/// ```
+pub trait Future {
+    /// The type of value produced on completion.
+    type Output;
+
+    /// Attempts to resolve the future to a final value, registering the
+    /// current task for wakeup if the value is not yet available.
+    fn poll(&mut self, cx: &mut Context) -> Poll<Self::Output>;
+}
+
+impl<'a, F: ?Sized + Future> Future for &'a mut F {
+    type Output = F::Output;
+
+    fn poll(&mut self, cx: &mut Context) -> Poll<F::Output> {
+        (**self).poll(cx)
+    }
+}
+
+/// A `Poll` that is settled already: a future that is immediately ready
+/// with a value, or never ready at all.
+///
+/// This `struct` is created by the [`ready`](fn.ready.html) function, which
+/// makes a future out of a value, and by
+/// [`Poll::ready`](../task/enum.Poll.html#method.ready), which makes a
+/// `Poll` that `?` can be applied to. It is also exported as
+/// `task::Ready`.
+#[unstable(feature = "future_readiness_fns", issue = "0")]
+#[derive(Clone, Debug)]
+#[must_use = "futures do nothing unless polled"]
+pub struct Ready<T>(Option<Poll<T>>);
+
+#[unstable(feature = "future_readiness_fns", issue = "0")]
+impl<T> Ready<T> {
+    /// Consumes the `Ready`, returning the wrapped value.
+    ///
+    /// # Panics
+    ///
+    /// Will panic if this `Ready` is pending, or was already polled to
+    /// completion.
+    pub fn into_inner(self) -> T {
+        match self.into_poll() {
+            Poll::Ready(t) => t,
+            Poll::Pending => panic!("called `into_inner()` on a pending `Ready`"),
+        }
+    }
+
+    /// Creates a `Ready` that is ready if `poll` is, and pending otherwise.
+    #[unstable(feature = "poll_ready", issue = "0")]
+    #[inline]
+    pub fn from_poll(poll: Poll<T>) -> Ready<T> {
+        Ready(Some(poll))
+    }
+
+    /// Returns the inner `Poll`.
+    ///
+    /// # Panics
+    ///
+    /// Will panic if this `Ready` was already polled to completion.
+    #[unstable(feature = "poll_ready", issue = "0")]
+    #[inline]
+    pub fn into_poll(self) -> Poll<T> {
+        self.0.expect("called `into_poll()` on `Ready` after completion")
+    }
+}
+
+#[unstable(feature = "future_readiness_fns", issue = "0")]
+impl<T> Future for Ready<T> {
+    type Output = T;
+
+    #[inline]
+    fn poll(&mut self, _cx: &mut Context) -> Poll<T> {
+        match self.0.take().expect("`Ready` polled after completion") {
+            Poll::Ready(t) => Poll::Ready(t),
+            Poll::Pending => {
+                self.0 = Some(Poll::Pending);
+                Poll::Pending
+            }
+        }
+    }
+}
+
+#[unstable(feature = "poll_ready", issue = "0")]
+impl<T> Try for Ready<T> {
+    type Output = T;
+    type Residual = Ready<Infallible>;
+
+    #[inline]
+    fn from_output(output: T) -> Ready<T> {
+        Ready(Some(Poll::Ready(output)))
+    }
+
+    #[inline]
+    fn branch(self) -> ControlFlow<Ready<Infallible>, T> {
+        match self.into_poll() {
+            Poll::Ready(v) => ControlFlow::Continue(v),
+            Poll::Pending => ControlFlow::Break(Ready(Some(Poll::Pending))),
+        }
+    }
+}
+
+#[unstable(feature = "poll_ready", issue = "0")]
+impl<T> FromResidual<Ready<Infallible>> for Ready<T> {
+    #[inline]
+    fn from_residual(_residual: Ready<Infallible>) -> Ready<T> {
+        Ready(Some(Poll::Pending))
+    }
+}
+
+#[unstable(feature = "poll_ready", issue = "0")]
+impl<T> FromResidual<Ready<Infallible>> for Poll<T> {
+    #[inline]
+    fn from_residual(_residual: Ready<Infallible>) -> Poll<T> {
+        Poll::Pending
+    }
+}
+
+/// Creates a future that is immediately ready with a value.
+///
+/// # Examples
+///
+/// ```
+/// #![feature(futures_api, future_readiness_fns)]
+///
+/// use std::future;
+/// use std::task;
+///
+/// assert_eq!(task::block_on(future::ready(1)), 1);
+/// ```
+#[unstable(feature = "future_readiness_fns", issue = "0")]
+pub fn ready<T>(t: T) -> Ready<T> {
+    Ready(Some(Poll::Ready(t)))
+}
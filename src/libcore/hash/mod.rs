@@ -236,6 +236,36 @@ pub trait BuildHasher {
     /// Creates a new hasher.
     #[stable(since = "1.7.0", feature = "build_hasher")]
     fn build_hasher(&self) -> Self::Hasher;
+
+    /// Calculates the hash of a single value.
+    ///
+    /// This is a shorthand for building a hasher, feeding `x` to it and
+    /// finishing it. `HashMap` and `HashSet` hash their keys with this too,
+    /// but then set the highest bit of the hash, which they use to tell
+    /// full buckets from empty ones. So with the collection's `hasher()`,
+    /// this gives the hash of a key in the collection apart from that bit.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(build_hasher_simple_hash_one)]
+    ///
+    /// use std::collections::hash_map::RandomState;
+    /// use std::hash::{BuildHasher, Hash, Hasher};
+    ///
+    /// let s = RandomState::new();
+    /// let x = (1, "a");
+    ///
+    /// let mut hasher = s.build_hasher();
+    /// x.hash(&mut hasher);
+    /// assert_eq!(s.hash_one(&x), hasher.finish());
+    /// ```
+    #[unstable(feature = "build_hasher_simple_hash_one", issue = "0")]
+    fn hash_one<T: Hash>(&self, x: T) -> u64 where Self: Sized {
+        let mut hasher = self.build_hasher();
+        x.hash(&mut hasher);
+        hasher.finish()
+    }
 }
 
 /// A structure which implements `BuildHasher` for all `Hasher` types which also
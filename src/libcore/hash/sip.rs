@@ -18,6 +18,14 @@ use ptr;
 /// An implementation of SipHash 1-3.
 ///
 /// See: https://131002.net/siphash/
+///
+/// This is the variant with fewer rounds. Like
+/// [`SipHasher24`](struct.SipHasher24.html), it is exactly the function of
+/// the reference implementation, so its output is fully specified by its
+/// keys and the bytes written to it.
+///
+/// `HashMap`'s default `RandomState` currently uses this variant, but that
+/// is not guaranteed: it may switch to another algorithm at any time.
 #[unstable(feature = "sip_hash_13", issue = "29754")]
 #[derive(Debug, Clone, Default)]
 pub struct SipHasher13 {
@@ -27,6 +35,31 @@ pub struct SipHasher13 {
 /// An implementation of SipHash 2-4.
 ///
 /// See: https://131002.net/siphash/
+///
+/// Unlike the hashers built by `HashMap`'s default `RandomState`, whose
+/// algorithm may change at any time, this is exactly the SipHash-2-4
+/// function of the reference implementation: for two given keys and a
+/// given sequence of bytes passed to `write`, `finish` returns the same
+/// value on every platform and in every release. This makes it suitable for
+/// hashes that outlive the process, such as checksums or consistent
+/// bucketing.
+///
+/// Note that this only covers the bytes written. The `Hash` impls of
+/// integers write them in native byte order, and those of `usize` and
+/// `isize` in native width, so for a portable result give integers an
+/// explicit width and byte order before writing their bytes.
+///
+/// # Examples
+///
+/// ```
+/// #![feature(sip_hash_13)]
+///
+/// use std::hash::{Hasher, SipHasher24};
+///
+/// let mut hasher = SipHasher24::new_with_keys(0x0706050403020100, 0x0f0e0d0c0b0a0908);
+/// hasher.write(&[0, 1, 2, 3, 4, 5, 6, 7]);
+/// assert_eq!(hasher.finish(), 0x93f5f5799a932462);
+/// ```
 #[unstable(feature = "sip_hash_13", issue = "29754")]
 #[derive(Debug, Clone, Default)]
 pub struct SipHasher24 {
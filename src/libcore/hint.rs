@@ -0,0 +1,199 @@
+// Copyright 2016 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Hints to the compiler that affect how code should be emitted or
+//! optimized.
+//!
+//! None of these change what a correct program computes, with the exception
+//! of [`assert_unchecked`](fn.assert_unchecked.html), whose condition being
+//! false is undefined behavior.
+
+#![unstable(feature = "hint", issue = "0")]
+
+use intrinsics;
+
+/// An identity function that the optimizer can not see through.
+///
+/// The value is treated as if it were used in some arbitrary way, so code
+/// computing it can not be removed or constant-folded away. This is mostly
+/// useful in benchmarks, to keep the code being measured from being
+/// optimized out.
+///
+/// This is a best-effort hint: it is a no-op on targets which do not
+/// support inline assembly.
+///
+/// # Examples
+///
+/// ```
+/// #![feature(hint)]
+///
+/// use std::hint::black_box;
+///
+/// fn sum(n: u64) -> u64 {
+///     (0..n).fold(0, |a, b| a + b)
+/// }
+///
+/// // Without `black_box`, the whole call could be computed at compile
+/// // time, and the result thrown away.
+/// black_box(sum(black_box(1000)));
+/// ```
+#[cfg(not(any(all(target_os = "nacl", target_arch = "le32"),
+              target_arch = "asmjs")))]
+#[inline]
+pub fn black_box<T>(dummy: T) -> T {
+    // we need to "use" the argument in some way LLVM can't
+    // introspect.
+    unsafe { asm!("" : : "r"(&dummy)) }
+    dummy
+}
+
+/// An identity function that the optimizer can not see through.
+#[cfg(any(all(target_os = "nacl", target_arch = "le32"),
+          target_arch = "asmjs"))]
+#[inline(never)]
+pub fn black_box<T>(dummy: T) -> T {
+    dummy
+}
+
+/// Informs the optimizer that a condition is always true.
+///
+/// This lets the optimizer remove checks which it could not prove
+/// redundant on its own, such as bounds checks. In builds with debug
+/// assertions enabled, the condition is checked.
+///
+/// # Safety
+///
+/// `cond` must be `true`. Calling this with a `false` condition is
+/// undefined behavior.
+///
+/// # Examples
+///
+/// ```
+/// #![feature(hint)]
+///
+/// use std::hint;
+///
+/// fn first_even(v: &[u32]) -> u32 {
+///     // Callers guarantee that `v` starts with an even number.
+///     unsafe { hint::assert_unchecked(!v.is_empty() && v[0] % 2 == 0) }
+///     v[0]
+/// }
+///
+/// assert_eq!(first_even(&[4, 7]), 4);
+/// ```
+#[inline(always)]
+pub unsafe fn assert_unchecked(cond: bool) {
+    debug_assert!(cond, "hint::assert_unchecked must never be called when the condition is false");
+    intrinsics::assume(cond)
+}
+
+/// Hints to the compiler that `b` is likely to be true, so that the code
+/// for that case should be laid out as the fast path. Returns `b`.
+///
+/// This only has an effect when used directly as the condition of an `if`
+/// or `while`.
+///
+/// # Examples
+///
+/// ```
+/// #![feature(hint)]
+///
+/// use std::hint::likely;
+///
+/// fn checked_div(a: u32, b: u32) -> Option<u32> {
+///     if likely(b != 0) { Some(a / b) } else { None }
+/// }
+///
+/// assert_eq!(checked_div(6, 3), Some(2));
+/// ```
+#[cfg(not(stage0))]
+#[inline(always)]
+pub fn likely(b: bool) -> bool {
+    unsafe { intrinsics::likely(b) }
+}
+
+/// Hints to the compiler that `b` is likely to be true. Returns `b`.
+#[cfg(stage0)]
+#[inline(always)]
+pub fn likely(b: bool) -> bool {
+    b
+}
+
+/// Hints to the compiler that `b` is likely to be false, so that the code
+/// for that case should be laid out as the fast path. Returns `b`.
+///
+/// This only has an effect when used directly as the condition of an `if`
+/// or `while`.
+#[cfg(not(stage0))]
+#[inline(always)]
+pub fn unlikely(b: bool) -> bool {
+    unsafe { intrinsics::unlikely(b) }
+}
+
+/// Hints to the compiler that `b` is likely to be false. Returns `b`.
+#[cfg(stage0)]
+#[inline(always)]
+pub fn unlikely(b: bool) -> bool {
+    b
+}
+
+/// Tells the processor that the current thread is busy-waiting in a spin
+/// loop, for another thread to change something in memory.
+///
+/// On x86 and x86-64 this is the `pause` instruction, and on AArch64
+/// `yield`, which use less power while spinning, give a hyperthread that
+/// shares the core more of it, and keep the loop from slowing down the other
+/// thread's write once it comes. It is a no-op on other targets.
+///
+/// This does not give up the thread's timeslice, which `thread::yield_now`
+/// does, so it is only for waits that are expected to be very short. See
+/// `sync::Backoff` in the standard library for spinning, then yielding, and
+/// then blocking.
+///
+/// # Examples
+///
+/// ```
+/// #![feature(hint)]
+///
+/// use std::hint;
+/// use std::sync::Arc;
+/// use std::sync::atomic::{AtomicBool, Ordering};
+/// use std::thread;
+///
+/// let ready = Arc::new(AtomicBool::new(false));
+/// let setter = {
+///     let ready = ready.clone();
+///     thread::spawn(move || ready.store(true, Ordering::Release))
+/// };
+/// while !ready.load(Ordering::Acquire) {
+///     hint::spin_loop();
+/// }
+/// setter.join().unwrap();
+/// ```
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+#[inline]
+pub fn spin_loop() {
+    unsafe { asm!("pause" : : : "memory" : "volatile") }
+}
+
+/// Tells the processor that the current thread is busy-waiting in a spin
+/// loop.
+#[cfg(target_arch = "aarch64")]
+#[inline]
+pub fn spin_loop() {
+    unsafe { asm!("yield" : : : "memory" : "volatile") }
+}
+
+/// Tells the processor that the current thread is busy-waiting in a spin
+/// loop.
+#[cfg(not(any(target_arch = "x86", target_arch = "x86_64", target_arch = "aarch64")))]
+#[inline]
+pub fn spin_loop() {
+}
@@ -197,6 +197,20 @@ extern "rust-intrinsic" {
     /// own, or if it does not enable any significant optimizations.
     pub fn assume(b: bool);
 
+    /// Hints to the compiler that branch condition is likely to be true.
+    /// Returns the value passed to it.
+    ///
+    /// Any use other than with `if` statements will probably not have an effect.
+    #[cfg(not(stage0))]
+    pub fn likely(b: bool) -> bool;
+
+    /// Hints to the compiler that branch condition is likely to be false.
+    /// Returns the value passed to it.
+    ///
+    /// Any use other than with `if` statements will probably not have an effect.
+    #[cfg(not(stage0))]
+    pub fn unlikely(b: bool) -> bool;
+
     /// Executes a breakpoint trap, for inspection by a debugger.
     pub fn breakpoint();
 
@@ -245,6 +259,11 @@ extern "rust-intrinsic" {
     /// crate it is invoked in.
     pub fn type_id<T: ?Sized + 'static>() -> u64;
 
+    /// Gets an identifier for the specified type like `type_id`, but for any
+    /// type. Lifetimes are erased, so `&'a T` has the same identifier as
+    /// `&'static T`.
+    pub fn type_id_erased<T: ?Sized>() -> u64;
+
     /// Creates a value initialized to so that its drop flag,
     /// if any, says that it has been dropped.
     ///
@@ -11,16 +11,16 @@
 use clone::Clone;
 use cmp::{Ord, PartialOrd, PartialEq, Ordering};
 use default::Default;
-use ops::FnMut;
+use ops::{ChangeOutputType, ControlFlow, FnMut, FromResidual, Residual, Try};
 use option::Option::{self, Some, None};
 use marker::Sized;
 
 use super::{Chain, Cycle, Cloned, Enumerate, Filter, FilterMap, FlatMap, Fuse};
 use super::{Inspect, Map, Peekable, Scan, Skip, SkipWhile, Take, TakeWhile, Rev};
-use super::{Zip, Sum, Product};
+use super::{Zip, Sum, Product, CheckedSum, CheckedProduct};
 use super::ChainState;
 use super::{DoubleEndedIterator, ExactSizeIterator, Extend, FromIterator};
-use super::{IntoIterator, ZipImpl};
+use super::{IntoIterator, MinMaxResult, ZipImpl};
 
 fn _assert_is_object_safe(_: &Iterator<Item=()>) {}
 
@@ -1237,9 +1237,9 @@ pub trait Iterator {
 
         for x in self {
             if f(&x) {
-                left.extend(Some(x))
+                left.extend_one(x)
             } else {
-                right.extend(Some(x))
+                right.extend_one(x)
             }
         }
 
@@ -1320,6 +1320,106 @@ pub trait Iterator {
         accum
     }
 
+    /// An iterator method that applies a function as long as it returns
+    /// successfully, producing a single, final value.
+    ///
+    /// `try_fold()` is like [`fold()`](#method.fold), except that the closure
+    /// returns a type implementing [`Try`](../ops/trait.Try.html), such as
+    /// `Option`, `Result` or `ControlFlow`. As soon as the closure
+    /// short-circuits, so does `try_fold()`, returning that residual without
+    /// consuming the rest of the iterator. Otherwise the final accumulator is
+    /// returned, wrapped in the same type.
+    ///
+    /// Since it takes `self` by mutable reference, the iterator can still be
+    /// used afterwards to look at the elements following the one that
+    /// stopped the fold.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// #![feature(try_trait_v2)]
+    ///
+    /// let a = [1, 2, 3];
+    ///
+    /// // the checked sum of all of the elements of the array
+    /// let sum = a.iter().try_fold(0i8, |acc, &x| acc.checked_add(x));
+    /// assert_eq!(sum, Some(6));
+    /// ```
+    ///
+    /// Short-circuiting:
+    ///
+    /// ```
+    /// #![feature(try_trait_v2)]
+    ///
+    /// let a = [10, 20, 30, 100, 40, 50];
+    /// let mut it = a.iter();
+    ///
+    /// // This sum overflows when adding the 100 element
+    /// let sum = it.try_fold(0i8, |acc, &x| acc.checked_add(x));
+    /// assert_eq!(sum, None);
+    ///
+    /// // Because it short-circuited, the remaining elements are still
+    /// // available through the iterator.
+    /// assert_eq!(it.len(), 2);
+    /// assert_eq!(it.next(), Some(&40));
+    /// ```
+    #[inline]
+    #[unstable(feature = "try_trait_v2", issue = "0")]
+    fn try_fold<B, F, R>(&mut self, init: B, mut f: F) -> R where
+        Self: Sized,
+        F: FnMut(B, Self::Item) -> R,
+        R: Try<Output = B> + FromResidual<<R as Try>::Residual>,
+    {
+        let mut accum = init;
+        while let Some(x) = self.next() {
+            // Written out rather than using `?`, which only goes through
+            // `Try` once the compiler building this crate lowers it so.
+            accum = match f(accum, x).branch() {
+                ControlFlow::Continue(c) => c,
+                ControlFlow::Break(r) => return R::from_residual(r),
+            };
+        }
+        R::from_output(accum)
+    }
+
+    /// An iterator method that applies a fallible function to each item in
+    /// the iterator, stopping at the first error and returning that error.
+    ///
+    /// This can also be thought of as the fallible form of a `for` loop, or
+    /// of `all()` and `any()` for closures that want to return something
+    /// other than a `bool`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(try_trait_v2)]
+    ///
+    /// use std::ops::ControlFlow;
+    ///
+    /// let r = (2..100).try_for_each(|x| {
+    ///     if 323 % x == 0 {
+    ///         return ControlFlow::Break(x)
+    ///     }
+    ///     ControlFlow::Continue(())
+    /// });
+    /// assert_eq!(r, ControlFlow::Break(17));
+    ///
+    /// let data = ["1", "2", "x"];
+    /// let r: Result<(), _> = data.iter().try_for_each(|s| s.parse::<i32>().map(|_| ()));
+    /// assert!(r.is_err());
+    /// ```
+    #[inline]
+    #[unstable(feature = "try_trait_v2", issue = "0")]
+    fn try_for_each<F, R>(&mut self, mut f: F) -> R where
+        Self: Sized,
+        F: FnMut(Self::Item) -> R,
+        R: Try<Output = ()> + FromResidual<<R as Try>::Residual>,
+    {
+        self.try_fold((), move |(), x| f(x))
+    }
+
     /// Tests if every element of the iterator matches a predicate.
     ///
     /// `all()` takes a closure that returns `true` or `false`. It applies
@@ -1472,6 +1572,46 @@ pub trait Iterator {
         None
     }
 
+    /// Applies a fallible predicate to the elements of the iterator, and
+    /// returns the first one for which it returns `true`, or the first
+    /// failure.
+    ///
+    /// This is like `find()`, except that the closure returns a `Try` type
+    /// like `Result<bool, E>` or `Option<bool>`, and `try_find()` returns the
+    /// same kind of type wrapped around an `Option<Self::Item>`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(try_trait_v2)]
+    ///
+    /// let a = ["1", "2", "lol", "NaN", "5"];
+    ///
+    /// let r = a.iter().try_find(|s| s.parse::<i32>().map(|x| x == 2));
+    /// assert_eq!(r, Ok(Some(&"2")));
+    ///
+    /// // stops at the first string that fails to parse
+    /// let r = a.iter().try_find(|s| s.parse::<i32>().map(|x| x == 5));
+    /// assert!(r.is_err());
+    /// ```
+    #[inline]
+    #[unstable(feature = "try_trait_v2", issue = "0")]
+    fn try_find<F, R>(&mut self, mut f: F) -> ChangeOutputType<R, Option<Self::Item>> where
+        Self: Sized,
+        F: FnMut(&Self::Item) -> R,
+        R: Try<Output = bool>,
+        <R as Try>::Residual: Residual<Option<Self::Item>>,
+    {
+        while let Some(x) = self.next() {
+            match f(&x).branch() {
+                ControlFlow::Continue(false) => {}
+                ControlFlow::Continue(true) => return Try::from_output(Some(x)),
+                ControlFlow::Break(r) => return FromResidual::from_residual(r),
+            }
+        }
+        Try::from_output(None)
+    }
+
     /// Searches for an element in an iterator, returning its index.
     ///
     /// `position()` takes a closure that returns `true` or `false`. It applies
@@ -1639,6 +1779,81 @@ pub trait Iterator {
             .map(|(_, x)| x)
     }
 
+    /// Returns both the minimum and the maximum element of an iterator.
+    ///
+    /// This takes about 1.5 comparisons per element, and goes through the
+    /// iterator only once. In agreement with [`min()`](#method.min) and
+    /// [`max()`](#method.max), the minimum is the first of several equally
+    /// minimum elements and the maximum the last of several equally maximum
+    /// ones.
+    ///
+    /// The result distinguishes iterators without any elements, those with
+    /// exactly one element, and those with more, in which case the minimum
+    /// and maximum are two different elements.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// #![feature(iter_minmax)]
+    ///
+    /// use std::iter::MinMaxResult::{NoElements, OneElement, MinMax};
+    ///
+    /// let a: [i32; 0] = [];
+    /// assert_eq!(a.iter().minmax(), NoElements);
+    ///
+    /// let a = [1];
+    /// assert_eq!(a.iter().minmax(), OneElement(&1));
+    ///
+    /// let a = [1, 2, 3, 4, 5];
+    /// assert_eq!(a.iter().minmax(), MinMax(&1, &5));
+    ///
+    /// let a = [1, 1, 1];
+    /// assert_eq!(a.iter().minmax(), MinMax(&1, &1));
+    /// ```
+    #[unstable(feature = "iter_minmax", issue = "0")]
+    fn minmax(mut self) -> MinMaxResult<Self::Item> where Self: Sized, Self::Item: Ord
+    {
+        let (mut min, mut max) = match self.next() {
+            None => return MinMaxResult::NoElements,
+            Some(x) => match self.next() {
+                None => return MinMaxResult::OneElement(x),
+                Some(y) => if y < x { (y, x) } else { (x, y) },
+            },
+        };
+
+        // Compare the elements pairwise with each other first, so that only
+        // the smaller one has to be compared with `min` and only the larger
+        // one with `max`.
+        loop {
+            let first = match self.next() {
+                None => break,
+                Some(x) => x,
+            };
+            let second = match self.next() {
+                None => {
+                    if first < min {
+                        min = first;
+                    } else if first >= max {
+                        max = first;
+                    }
+                    break
+                }
+                Some(x) => x,
+            };
+            if first <= second {
+                if first < min { min = first }
+                if second >= max { max = second }
+            } else {
+                if second < min { min = second }
+                if first >= max { max = first }
+            }
+        }
+
+        MinMaxResult::MinMax(min, max)
+    }
+
     /// Returns the element that gives the maximum value from the
     /// specified function.
     ///
@@ -1748,9 +1963,13 @@ pub trait Iterator {
         let mut ts: FromA = Default::default();
         let mut us: FromB = Default::default();
 
+        let (lower, _) = self.size_hint();
+        ts.extend_reserve(lower);
+        us.extend_reserve(lower);
+
         for (t, u) in self {
-            ts.extend(Some(t));
-            us.extend(Some(u));
+            ts.extend_one(t);
+            us.extend_one(u);
         }
 
         (ts, us)
@@ -1822,6 +2041,7 @@ pub trait Iterator {
     ///
     /// When calling `sum` and a primitive integer type is being returned, this
     /// method will panic if the computation overflows.
+    /// `checked_sum` returns `None` instead.
     ///
     /// # Examples
     ///
@@ -1849,6 +2069,7 @@ pub trait Iterator {
     ///
     /// When calling `product` and a primitive integer type is being returned,
     /// this method will panic if the computation overflows.
+    /// `checked_product` returns `None` instead.
     ///
     /// # Examples
     ///
@@ -1868,6 +2089,59 @@ pub trait Iterator {
         Product::product(self)
     }
 
+    /// Sums the elements of an iterator, or returns `None` if the sum
+    /// overflows.
+    ///
+    /// Unlike `sum`, this never panics, and stops consuming the iterator at
+    /// the first element that makes the sum overflow. To wrap or saturate on
+    /// overflow instead, sum `Wrapping` or `Saturating` elements.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(iter_checked_arith, saturating_int)]
+    /// use std::num::{Saturating, Wrapping};
+    ///
+    /// let a = [200u8, 50, 10];
+    /// assert_eq!(a[..2].iter().checked_sum::<u8>(), Some(250));
+    /// assert_eq!(a.iter().checked_sum::<u8>(), None);
+    ///
+    /// assert_eq!(a.iter().map(|&x| Wrapping(x)).sum::<Wrapping<u8>>(), Wrapping(4));
+    /// assert_eq!(a.iter().map(|&x| Saturating(x)).sum::<Saturating<u8>>(), Saturating(255));
+    /// ```
+    #[unstable(feature = "iter_checked_arith", issue = "0")]
+    fn checked_sum<S>(self) -> Option<S>
+        where Self: Sized,
+              S: CheckedSum<Self::Item>,
+    {
+        CheckedSum::checked_sum(self)
+    }
+
+    /// Multiplies the elements of an iterator, or returns `None` if the
+    /// product overflows.
+    ///
+    /// Unlike `product`, this never panics, and stops consuming the iterator
+    /// at the first element that makes the product overflow.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(iter_checked_arith)]
+    /// fn factorial(n: u32) -> Option<u32> {
+    ///     (1..).take_while(|&i| i <= n).checked_product()
+    /// }
+    /// assert_eq!(factorial(5), Some(120));
+    /// assert_eq!(factorial(12), Some(479001600));
+    /// assert_eq!(factorial(13), None);
+    /// ```
+    #[unstable(feature = "iter_checked_arith", issue = "0")]
+    fn checked_product<P>(self) -> Option<P>
+        where Self: Sized,
+              P: CheckedProduct<Self::Item>,
+    {
+        CheckedProduct::checked_product(self)
+    }
+
     /// Lexicographically compares the elements of this `Iterator` with those
     /// of another.
     #[stable(feature = "iter_order", since = "1.5.0")]
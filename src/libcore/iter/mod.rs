@@ -325,11 +325,15 @@ pub use self::sources::{Repeat, repeat};
 pub use self::sources::{Empty, empty};
 #[stable(feature = "iter_once", since = "1.2.0")]
 pub use self::sources::{Once, once};
+#[unstable(feature = "iter_from_coroutine", issue = "0")]
+pub use self::sources::{FromCoroutine, from_coroutine};
 
 #[stable(feature = "rust1", since = "1.0.0")]
 pub use self::traits::{FromIterator, IntoIterator, DoubleEndedIterator, Extend};
 #[stable(feature = "rust1", since = "1.0.0")]
 pub use self::traits::{ExactSizeIterator, Sum, Product};
+#[unstable(feature = "iter_checked_arith", issue = "0")]
+pub use self::traits::{CheckedSum, CheckedProduct};
 
 mod iterator;
 mod range;
@@ -1821,3 +1825,52 @@ impl<I: DoubleEndedIterator, F> DoubleEndedIterator for Inspect<I, F>
 #[stable(feature = "rust1", since = "1.0.0")]
 impl<I: ExactSizeIterator, F> ExactSizeIterator for Inspect<I, F>
     where F: FnMut(&I::Item) {}
+
+/// The result of [`Iterator::minmax()`].
+///
+/// [`Iterator::minmax()`]: trait.Iterator.html#method.minmax
+#[derive(Clone, PartialEq, Eq, Debug)]
+#[unstable(feature = "iter_minmax", issue = "0")]
+pub enum MinMaxResult<T> {
+    /// The iterator was empty.
+    #[unstable(feature = "iter_minmax", issue = "0")]
+    NoElements,
+
+    /// The iterator had exactly one element, which is both the minimum and
+    /// the maximum.
+    #[unstable(feature = "iter_minmax", issue = "0")]
+    OneElement(T),
+
+    /// The iterator had more than one element. The minimum comes first and
+    /// the maximum second; they may be equal, but are never the same element.
+    #[unstable(feature = "iter_minmax", issue = "0")]
+    MinMax(T, T),
+}
+
+impl<T: Clone> MinMaxResult<T> {
+    /// Converts the result to an `Option` of the minimum and maximum,
+    /// cloning the only element if there was just one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(iter_minmax)]
+    ///
+    /// use std::iter::MinMaxResult::{self, NoElements, OneElement, MinMax};
+    ///
+    /// let r: MinMaxResult<i32> = NoElements;
+    /// assert_eq!(r.into_option(), None);
+    /// let r = OneElement(1);
+    /// assert_eq!(r.into_option(), Some((1, 1)));
+    /// let r = MinMax(1, 2);
+    /// assert_eq!(r.into_option(), Some((1, 2)));
+    /// ```
+    #[unstable(feature = "iter_minmax", issue = "0")]
+    pub fn into_option(self) -> Option<(T, T)> {
+        match self {
+            MinMaxResult::NoElements => None,
+            MinMaxResult::OneElement(x) => Some((x.clone(), x)),
+            MinMaxResult::MinMax(min, max) => Some((min, max)),
+        }
+    }
+}
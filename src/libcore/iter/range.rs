@@ -8,6 +8,7 @@
 // option. This file may not be copied, modified, or distributed
 // except according to those terms.
 
+use char;
 use clone::Clone;
 use cmp::PartialOrd;
 use mem;
@@ -53,6 +54,10 @@ pub trait Step: PartialOrd + Sized {
 
     /// Subtracts one to this step, returning the result
     fn sub_one(&self) -> Self;
+
+    /// Adds `n` to this step, which is the same as calling `add_one` `n`
+    /// times, returning `None` on overflow.
+    fn add_usize(&self, n: usize) -> Option<Self>;
 }
 
 macro_rules! step_impl_unsigned {
@@ -112,6 +117,14 @@ macro_rules! step_impl_unsigned {
             fn steps_between_by_one(start: &Self, end: &Self) -> Option<usize> {
                 Self::steps_between(start, end, &1)
             }
+
+            #[inline]
+            #[allow(trivial_numeric_casts)]
+            fn add_usize(&self, n: usize) -> Option<Self> {
+                // Note: We assume $t <= usize here
+                let room = (<$t>::max_value() - *self) as usize;
+                if n > room { None } else { Some(*self + n as $t) }
+            }
         }
     )*)
 }
@@ -183,6 +196,18 @@ macro_rules! step_impl_signed {
             fn steps_between_by_one(start: &Self, end: &Self) -> Option<usize> {
                 Self::steps_between(start, end, &1)
             }
+
+            #[inline]
+            #[allow(trivial_numeric_casts)]
+            fn add_usize(&self, n: usize) -> Option<Self> {
+                // Note: We assume $t <= isize here, as in `steps_between`
+                let room = (<$t>::max_value() as isize).wrapping_sub(*self as isize) as usize;
+                if n > room {
+                    None
+                } else {
+                    Some((*self as isize).wrapping_add(n as isize) as $t)
+                }
+            }
         }
     )*)
 }
@@ -232,6 +257,12 @@ macro_rules! step_impl_no_between {
             fn steps_between_by_one(start: &Self, end: &Self) -> Option<usize> {
                 Self::steps_between(start, end, &1)
             }
+
+            #[inline]
+            fn add_usize(&self, n: usize) -> Option<Self> {
+                // usize is narrower than these types, so the cast is exact
+                (*self).checked_add(n as $t)
+            }
         }
     )*)
 }
@@ -247,6 +278,92 @@ step_impl_signed!(i64);
 #[cfg(not(target_pointer_width = "64"))]
 step_impl_no_between!(u64 i64);
 
+// Chars step over the surrogate code points, which leaves a gap between
+// `'\u{D7FF}'` and `'\u{E000}'`. Counting the scalar values below a char
+// closes it, and makes all the arithmetic that of integers.
+const SURROGATES: u32 = 0xE000 - 0xD800;
+const CHAR_COUNT: u32 = 0x110000 - SURROGATES;
+
+fn char_to_index(c: char) -> u32 {
+    let c = c as u32;
+    if c >= 0xE000 { c - SURROGATES } else { c }
+}
+
+fn index_to_char(i: u32) -> Option<char> {
+    if i >= CHAR_COUNT {
+        return None;
+    }
+    let c = if i >= 0xD800 { i + SURROGATES } else { i };
+    // Safe, as `c` is below 0x110000 and outside of the gap.
+    Some(unsafe { char::from_u32_unchecked(c) })
+}
+
+/// Steps over the Unicode scalar values in order. A step `by` a char moves
+/// by as many scalar values as its code point, so `'\u{2}'` steps to every
+/// other char.
+#[unstable(feature = "step_trait",
+           reason = "likely to be replaced by finer-grained traits",
+           issue = "27741")]
+impl Step for char {
+    #[inline]
+    fn step(&self, by: &char) -> Option<char> {
+        self.add_usize(*by as usize)
+    }
+
+    #[inline]
+    fn steps_between(start: &char, end: &char, by: &char) -> Option<usize> {
+        let by = *by as u32;
+        if by == 0 { return None; }
+        if *start < *end {
+            let diff = char_to_index(*end) - char_to_index(*start);
+            Some(((diff - 1) / by + 1) as usize)
+        } else {
+            Some(0)
+        }
+    }
+
+    #[inline]
+    fn is_negative(&self) -> bool {
+        false
+    }
+
+    #[inline]
+    fn replace_one(&mut self) -> Self {
+        mem::replace(self, '\0')
+    }
+
+    #[inline]
+    fn replace_zero(&mut self) -> Self {
+        mem::replace(self, '\u{1}')
+    }
+
+    #[inline]
+    fn add_one(&self) -> Self {
+        self.add_usize(1).expect("overflow in `Step::add_one` for char")
+    }
+
+    #[inline]
+    fn sub_one(&self) -> Self {
+        match char_to_index(*self).checked_sub(1) {
+            Some(i) => index_to_char(i).unwrap(),
+            None => panic!("overflow in `Step::sub_one` for char"),
+        }
+    }
+
+    #[inline]
+    fn steps_between_by_one(start: &Self, end: &Self) -> Option<usize> {
+        Self::steps_between(start, end, &'\u{1}')
+    }
+
+    #[inline]
+    fn add_usize(&self, n: usize) -> Option<Self> {
+        if n >= CHAR_COUNT as usize {
+            return None;
+        }
+        index_to_char(char_to_index(*self) + n as u32)
+    }
+}
+
 /// An adapter for stepping range iterators by a custom amount.
 ///
 /// The resulting iterator handles overflow by stopping. The `A`
@@ -516,12 +633,34 @@ impl<A: Step> Iterator for ops::Range<A> where
             None => (0, None)
         }
     }
+
+    #[inline]
+    fn nth(&mut self, n: usize) -> Option<A> {
+        if let Some(plus_n) = self.start.add_usize(n) {
+            if plus_n < self.end {
+                self.start = plus_n.add_one();
+                return Some(plus_n);
+            }
+        }
+        // Either way, the range runs out before the `n`th element. Adding
+        // zero copies the end without needing `Clone`.
+        if self.start < self.end {
+            self.start = self.end.add_usize(0).unwrap();
+        }
+        None
+    }
 }
 
 // Ranges of u64 and i64 are excluded because they cannot guarantee having
 // a length <= usize::MAX, which is required by ExactSizeIterator.
 range_exact_iter_impl!(usize u8 u16 u32 isize i8 i16 i32);
 
+// There are fewer than 2^21 chars, so their ranges are fine too. Only the
+// inclusive ones iterate, as `&char` can't be added like the ranges above
+// require.
+#[unstable(feature = "inclusive_range", reason = "recently added, follows RFC", issue = "28237")]
+impl ExactSizeIterator for ops::RangeInclusive<char> { }
+
 #[stable(feature = "rust1", since = "1.0.0")]
 impl<A: Step + Clone> DoubleEndedIterator for ops::Range<A> where
     for<'a> &'a A: Add<&'a A, Output = A>,
@@ -550,12 +689,28 @@ impl<A: Step> Iterator for ops::RangeFrom<A> where
         mem::swap(&mut n, &mut self.start);
         Some(n)
     }
+
+    #[inline]
+    fn nth(&mut self, n: usize) -> Option<A> {
+        match self.start.add_usize(n) {
+            Some(plus_n) => {
+                self.start = plus_n.add_one();
+                Some(plus_n)
+            }
+            None => {
+                // The start overflows on the way, so step one at a time to
+                // overflow exactly like `next` does.
+                for _ in 0..n {
+                    self.next();
+                }
+                self.next()
+            }
+        }
+    }
 }
 
 #[unstable(feature = "inclusive_range", reason = "recently added, follows RFC", issue = "28237")]
-impl<A: Step> Iterator for ops::RangeInclusive<A> where
-    for<'a> &'a A: Add<&'a A, Output = A>
-{
+impl<A: Step> Iterator for ops::RangeInclusive<A> {
     type Item = A;
 
     #[inline]
@@ -598,6 +753,39 @@ impl<A: Step> Iterator for ops::RangeInclusive<A> where
         n
     }
 
+    #[inline]
+    fn nth(&mut self, n: usize) -> Option<A> {
+        use ops::RangeInclusive::*;
+
+        // as in `next`, the borrows of `start` and `end` end before `self`
+        // may be replaced
+        let exhausted_at = match *self {
+            Empty { .. } => return None,
+
+            NonEmpty { ref mut start, ref mut end } => {
+                match start.add_usize(n) {
+                    Some(plus_n) => {
+                        if plus_n <= *end {
+                            *start = plus_n;
+                            None
+                        } else {
+                            Some(end.replace_one())
+                        }
+                    }
+                    None => Some(end.replace_one()),
+                }
+            }
+        };
+
+        match exhausted_at {
+            Some(end) => {
+                *self = Empty { at: end };
+                None
+            }
+            None => self.next(),
+        }
+    }
+
     #[inline]
     fn size_hint(&self) -> (usize, Option<usize>) {
         use ops::RangeInclusive::*;
@@ -615,10 +803,7 @@ impl<A: Step> Iterator for ops::RangeInclusive<A> where
 }
 
 #[unstable(feature = "inclusive_range", reason = "recently added, follows RFC", issue = "28237")]
-impl<A: Step> DoubleEndedIterator for ops::RangeInclusive<A> where
-    for<'a> &'a A: Add<&'a A, Output = A>,
-    for<'a> &'a A: Sub<&'a A, Output = A>
-{
+impl<A: Step> DoubleEndedIterator for ops::RangeInclusive<A> {
     #[inline]
     fn next_back(&mut self) -> Option<A> {
         use ops::RangeInclusive::*;
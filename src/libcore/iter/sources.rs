@@ -12,7 +12,9 @@ use clone::Clone;
 use default::Default;
 use fmt;
 use marker;
+use ops::{Coroutine, CoroutineState, DerefMut};
 use option::Option::{self, Some, None};
+use pin::Pin;
 use usize;
 
 use super::{DoubleEndedIterator, IntoIterator, Iterator, ExactSizeIterator};
@@ -268,3 +270,77 @@ impl<T> ExactSizeIterator for Once<T> {
 pub fn once<T>(value: T) -> Once<T> {
     Once { inner: Some(value).into_iter() }
 }
+
+/// An iterator over the values yielded by a coroutine.
+///
+/// This `struct` is created by the [`from_coroutine()`] function. See its
+/// documentation for more.
+///
+/// [`from_coroutine()`]: fn.from_coroutine.html
+#[derive(Debug)]
+#[unstable(feature = "iter_from_coroutine", issue = "0")]
+pub struct FromCoroutine<P> {
+    coroutine: Pin<P>,
+    done: bool,
+}
+
+#[unstable(feature = "iter_from_coroutine", issue = "0")]
+impl<P: DerefMut> Iterator for FromCoroutine<P> where P::Target: Coroutine<Return = ()> {
+    type Item = <P::Target as Coroutine>::Yield;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None
+        }
+        match Coroutine::resume(self.coroutine.as_mut(), ()) {
+            CoroutineState::Yielded(item) => Some(item),
+            CoroutineState::Complete(()) => {
+                self.done = true;
+                None
+            }
+        }
+    }
+}
+
+/// Creates an iterator over the values yielded by a pinned coroutine.
+///
+/// The coroutine is resumed with `()` each time the iterator is advanced,
+/// and the iterator ends when the coroutine completes; it is never resumed
+/// after that. Taking the coroutine already pinned is what makes this safe
+/// for coroutines which are not `Unpin`: pin it on the heap with a pinned
+/// `Box`, or on the stack with [`pin!`](../macro.pin.html).
+///
+/// # Examples
+///
+/// ```
+/// #![feature(coroutine_trait, iter_from_coroutine, pin)]
+///
+/// use std::iter;
+/// use std::ops::{Coroutine, CoroutineState};
+/// use std::pin::Pin;
+///
+/// struct Countdown(u32);
+///
+/// impl Coroutine for Countdown {
+///     type Yield = u32;
+///     type Return = ();
+///
+///     fn resume(mut this: Pin<&mut Self>, _: ()) -> CoroutineState<u32, ()> {
+///         if this.0 == 0 {
+///             return CoroutineState::Complete(())
+///         }
+///         this.0 -= 1;
+///         CoroutineState::Yielded(this.0 + 1)
+///     }
+/// }
+///
+/// let countdown = pin!(Countdown(3));
+/// let values: Vec<_> = iter::from_coroutine(countdown).collect();
+/// assert_eq!(values, [3, 2, 1]);
+/// ```
+#[unstable(feature = "iter_from_coroutine", issue = "0")]
+pub fn from_coroutine<P: DerefMut>(coroutine: Pin<P>) -> FromCoroutine<P>
+    where P::Target: Coroutine<Return = ()>
+{
+    FromCoroutine { coroutine: coroutine, done: false }
+}
@@ -10,6 +10,7 @@
 
 use option::Option::{self, Some};
 use marker::Sized;
+use num::{Saturating, Wrapping};
 
 use super::Iterator;
 
@@ -348,6 +349,44 @@ pub trait Extend<A> {
     /// ```
     #[stable(feature = "rust1", since = "1.0.0")]
     fn extend<T: IntoIterator<Item=A>>(&mut self, iter: T);
+
+    /// Extends a collection with exactly one element.
+    ///
+    /// This is the same as `extend(Some(item))`, which is how it is
+    /// implemented by default, but collections can usually add a single
+    /// element without going through an iterator.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(extend_one)]
+    /// let mut v = vec![1, 2];
+    /// v.extend_one(3);
+    /// assert_eq!(v, [1, 2, 3]);
+    /// ```
+    #[unstable(feature = "extend_one", issue = "0")]
+    fn extend_one(&mut self, item: A) {
+        self.extend(Some(item));
+    }
+
+    /// Reserves capacity in a collection for the given number of additional
+    /// elements, ahead of calls to `extend_one`.
+    ///
+    /// The default implementation does nothing, as do the implementations of
+    /// collections which allocate each element separately.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(extend_one)]
+    /// let mut v: Vec<i32> = Vec::new();
+    /// Extend::<i32>::extend_reserve(&mut v, 10);
+    /// assert!(v.capacity() >= 10);
+    /// ```
+    #[unstable(feature = "extend_one", issue = "0")]
+    fn extend_reserve(&mut self, additional: usize) {
+        let _ = additional;
+    }
 }
 
 /// An iterator able to yield elements from both ends.
@@ -557,6 +596,32 @@ pub trait Product<A = Self>: Sized {
     fn product<I: Iterator<Item=A>>(iter: I) -> Self;
 }
 
+/// Trait to represent types that can be created by summing up an iterator,
+/// unless the sum overflows.
+///
+/// This trait is used to implement the `checked_sum` method on iterators.
+/// Like `Sum` this trait should rarely be called directly and instead
+/// interacted with through `Iterator::checked_sum`.
+#[unstable(feature = "iter_checked_arith", issue = "0")]
+pub trait CheckedSum<A = Self>: Sized {
+    /// Method which takes an iterator and generates `Self` from the elements by
+    /// "summing up" the items, or returns `None` if the sum overflows.
+    fn checked_sum<I: Iterator<Item=A>>(iter: I) -> Option<Self>;
+}
+
+/// Trait to represent types that can be created by multiplying elements of an
+/// iterator, unless the product overflows.
+///
+/// This trait is used to implement the `checked_product` method on iterators.
+/// Like `Product` this trait should rarely be called directly and instead
+/// interacted with through `Iterator::checked_product`.
+#[unstable(feature = "iter_checked_arith", issue = "0")]
+pub trait CheckedProduct<A = Self>: Sized {
+    /// Method which takes an iterator and generates `Self` from the elements by
+    /// multiplying the items, or returns `None` if the product overflows.
+    fn checked_product<I: Iterator<Item=A>>(iter: I) -> Option<Self>;
+}
+
 macro_rules! integer_sum_product {
     ($($a:ident)*) => ($(
         #[unstable(feature = "iter_arith_traits", issue = "34529")]
@@ -597,6 +662,94 @@ macro_rules! integer_sum_product {
     )*)
 }
 
+macro_rules! integer_checked_sum_product {
+    ($($a:ident)*) => ($(
+        #[unstable(feature = "iter_checked_arith", issue = "0")]
+        impl CheckedSum for $a {
+            fn checked_sum<I: Iterator<Item=$a>>(mut iter: I) -> Option<$a> {
+                iter.try_fold(0, |a: $a, b| a.checked_add(b))
+            }
+        }
+
+        #[unstable(feature = "iter_checked_arith", issue = "0")]
+        impl CheckedProduct for $a {
+            fn checked_product<I: Iterator<Item=$a>>(mut iter: I) -> Option<$a> {
+                iter.try_fold(1, |a: $a, b| a.checked_mul(b))
+            }
+        }
+
+        #[unstable(feature = "iter_checked_arith", issue = "0")]
+        impl<'a> CheckedSum<&'a $a> for $a {
+            fn checked_sum<I: Iterator<Item=&'a $a>>(iter: I) -> Option<$a> {
+                CheckedSum::checked_sum(iter.cloned())
+            }
+        }
+
+        #[unstable(feature = "iter_checked_arith", issue = "0")]
+        impl<'a> CheckedProduct<&'a $a> for $a {
+            fn checked_product<I: Iterator<Item=&'a $a>>(iter: I) -> Option<$a> {
+                CheckedProduct::checked_product(iter.cloned())
+            }
+        }
+
+        #[unstable(feature = "iter_arith_traits", issue = "34529")]
+        impl Sum for Wrapping<$a> {
+            fn sum<I: Iterator<Item=Wrapping<$a>>>(iter: I) -> Wrapping<$a> {
+                iter.fold(Wrapping(0), |a, b| a + b)
+            }
+        }
+
+        #[unstable(feature = "iter_arith_traits", issue = "34529")]
+        impl Product for Wrapping<$a> {
+            fn product<I: Iterator<Item=Wrapping<$a>>>(iter: I) -> Wrapping<$a> {
+                iter.fold(Wrapping(1), |a, b| a * b)
+            }
+        }
+
+        #[unstable(feature = "iter_arith_traits", issue = "34529")]
+        impl<'a> Sum<&'a Wrapping<$a>> for Wrapping<$a> {
+            fn sum<I: Iterator<Item=&'a Wrapping<$a>>>(iter: I) -> Wrapping<$a> {
+                iter.fold(Wrapping(0), |a, b| a + *b)
+            }
+        }
+
+        #[unstable(feature = "iter_arith_traits", issue = "34529")]
+        impl<'a> Product<&'a Wrapping<$a>> for Wrapping<$a> {
+            fn product<I: Iterator<Item=&'a Wrapping<$a>>>(iter: I) -> Wrapping<$a> {
+                iter.fold(Wrapping(1), |a, b| a * *b)
+            }
+        }
+
+        #[unstable(feature = "saturating_int", issue = "0")]
+        impl Sum for Saturating<$a> {
+            fn sum<I: Iterator<Item=Saturating<$a>>>(iter: I) -> Saturating<$a> {
+                iter.fold(Saturating(0), |a, b| a + b)
+            }
+        }
+
+        #[unstable(feature = "saturating_int", issue = "0")]
+        impl Product for Saturating<$a> {
+            fn product<I: Iterator<Item=Saturating<$a>>>(iter: I) -> Saturating<$a> {
+                iter.fold(Saturating(1), |a, b| a * b)
+            }
+        }
+
+        #[unstable(feature = "saturating_int", issue = "0")]
+        impl<'a> Sum<&'a Saturating<$a>> for Saturating<$a> {
+            fn sum<I: Iterator<Item=&'a Saturating<$a>>>(iter: I) -> Saturating<$a> {
+                iter.fold(Saturating(0), |a, b| a + *b)
+            }
+        }
+
+        #[unstable(feature = "saturating_int", issue = "0")]
+        impl<'a> Product<&'a Saturating<$a>> for Saturating<$a> {
+            fn product<I: Iterator<Item=&'a Saturating<$a>>>(iter: I) -> Saturating<$a> {
+                iter.fold(Saturating(1), |a, b| a * *b)
+            }
+        }
+    )*)
+}
+
 macro_rules! float_sum_product {
     ($($a:ident)*) => ($(
         #[unstable(feature = "iter_arith_traits", issue = "34529")]
@@ -630,4 +783,5 @@ macro_rules! float_sum_product {
 }
 
 integer_sum_product! { i8 i16 i32 i64 isize u8 u16 u32 u64 usize }
+integer_checked_sum_product! { i8 i16 i32 i64 isize u8 u16 u32 u64 usize }
 float_sum_product! { f32 f64 }
@@ -63,6 +63,7 @@
 
 #![feature(allow_internal_unstable)]
 #![feature(asm)]
+#![feature(associated_consts)]
 #![feature(associated_type_defaults)]
 #![feature(cfg_target_feature)]
 #![feature(concat_idents)]
@@ -105,15 +106,19 @@ mod uint_macros;
 #[path = "num/i16.rs"]  pub mod i16;
 #[path = "num/i32.rs"]  pub mod i32;
 #[path = "num/i64.rs"]  pub mod i64;
+#[path = "num/i128.rs"] pub mod i128;
 
 #[path = "num/usize.rs"] pub mod usize;
 #[path = "num/u8.rs"]   pub mod u8;
 #[path = "num/u16.rs"]  pub mod u16;
 #[path = "num/u32.rs"]  pub mod u32;
 #[path = "num/u64.rs"]  pub mod u64;
+#[path = "num/u128.rs"] pub mod u128;
 
 #[path = "num/f32.rs"]   pub mod f32;
 #[path = "num/f64.rs"]   pub mod f64;
+#[path = "num/f16.rs"]   pub mod f16;
+#[path = "num/f128.rs"]  pub mod f128;
 
 #[macro_use]
 pub mod num;
@@ -143,20 +148,28 @@ pub mod borrow;
 
 pub mod any;
 pub mod array;
+pub mod async_iter;
 pub mod sync;
 pub mod cell;
 pub mod char;
 pub mod panicking;
 pub mod iter;
 pub mod option;
+pub mod pin;
+pub mod range;
 pub mod raw;
 pub mod result;
 
 pub mod slice;
 pub mod str;
 pub mod hash;
+pub mod hint;
 pub mod fmt;
+pub mod future;
+pub mod simd;
+pub mod task;
 
 // note: does not need to be public
 mod iter_private;
 mod tuple;
+mod bool;
@@ -211,6 +211,152 @@ macro_rules! try {
     })
 }
 
+/// Extracts the successful type of a `Poll<T>`.
+///
+/// This macro bakes in propagation of `Pending` signals by returning early:
+/// it evaluates to the value inside a `Poll::Ready`, or returns
+/// `Poll::Pending` from the enclosing function.
+///
+/// # Examples
+///
+/// ```
+/// #![feature(futures_api)]
+///
+/// use std::future::Future;
+/// use std::task::{Context, Poll};
+///
+/// // Doubles the output of another future.
+/// struct Double<F>(F);
+///
+/// impl<F: Future<Output = u32>> Future for Double<F> {
+///     type Output = u32;
+///
+///     fn poll(&mut self, cx: &mut Context) -> Poll<u32> {
+///         let n = ready!(self.0.poll(cx));
+///         Poll::Ready(n * 2)
+///     }
+/// }
+/// # fn main() {}
+/// ```
+///
+/// The `ready!` call expands to:
+///
+/// ```
+/// # #![feature(futures_api)]
+/// # use std::future::Future;
+/// # use std::task::{Context, Poll};
+/// # struct Double<F>(F);
+/// # impl<F: Future<Output = u32>> Future for Double<F> {
+/// #     type Output = u32;
+/// #     fn poll(&mut self, cx: &mut Context) -> Poll<u32> {
+/// let n = match self.0.poll(cx) {
+///     Poll::Ready(t) => t,
+///     Poll::Pending => return Poll::Pending,
+/// };
+/// #         Poll::Ready(n * 2)
+/// #     }
+/// # }
+/// # fn main() {}
+/// ```
+#[macro_export]
+#[unstable(feature = "futures_api", issue = "0")]
+macro_rules! ready {
+    ($e:expr) => (match $e {
+        $crate::task::Poll::Ready(t) => t,
+        $crate::task::Poll::Pending => return $crate::task::Poll::Pending,
+    })
+}
+
+/// Pins a value on the stack.
+///
+/// The value is moved into the local scope and a `Pin<&mut T>` to it is
+/// returned; since the value itself can no longer be named, it can never
+/// be moved again, which is what makes this safe for types that are not
+/// `Unpin`.
+///
+/// # Examples
+///
+/// ```
+/// #![feature(pin)]
+///
+/// use std::marker::PhantomPinned;
+/// use std::pin::Pin;
+///
+/// struct Unmovable {
+///     value: u32,
+///     _pinned: PhantomPinned,
+/// }
+///
+/// fn read(pinned: Pin<&mut Unmovable>) -> u32 {
+///     pinned.value
+/// }
+///
+/// let mut pinned = pin!(Unmovable { value: 3, _pinned: PhantomPinned });
+/// assert_eq!(read(pinned.as_mut()), 3);
+/// assert_eq!(read(pinned), 3);
+/// ```
+#[macro_export]
+#[unstable(feature = "pin", issue = "0")]
+#[allow_internal_unstable]
+macro_rules! pin {
+    ($value:expr) => ($crate::pin::Pin { __pointer: &mut { $value } })
+}
+
+/// Immutably borrows a `RefCell`, recording where the borrow was taken.
+///
+/// This is `RefCell::borrow`, except that a failed mutable borrow while this
+/// one is outstanding reports the file and line of the `borrow_at!`
+/// invocation.
+///
+/// # Panics
+///
+/// Panics if the value is currently mutably borrowed.
+///
+/// # Examples
+///
+/// ```
+/// #![feature(refcell_borrow_location, try_borrow)]
+///
+/// use std::cell::RefCell;
+///
+/// let c = RefCell::new(5);
+/// let b = borrow_at!(c);
+/// let err = c.try_borrow_mut().unwrap_err();
+/// assert!(err.to_string().starts_with("already borrowed at "));
+/// ```
+#[macro_export]
+#[unstable(feature = "refcell_borrow_location", issue = "0")]
+macro_rules! borrow_at {
+    ($cell:expr) => ({
+        static AT: (&'static str, u32) = (file!(), line!());
+        match $cell.try_borrow_at(&AT) {
+            Ok(r) => r,
+            Err(e) => panic!("RefCell<T> {}", e),
+        }
+    })
+}
+
+/// Mutably borrows a `RefCell`, recording where the borrow was taken.
+///
+/// This is `RefCell::borrow_mut`, except that a failed borrow while this one
+/// is outstanding reports the file and line of the `borrow_mut_at!`
+/// invocation.
+///
+/// # Panics
+///
+/// Panics if the value is currently borrowed.
+#[macro_export]
+#[unstable(feature = "refcell_borrow_location", issue = "0")]
+macro_rules! borrow_mut_at {
+    ($cell:expr) => ({
+        static AT: (&'static str, u32) = (file!(), line!());
+        match $cell.try_borrow_mut_at(&AT) {
+            Ok(r) => r,
+            Err(e) => panic!("RefCell<T> {}", e),
+        }
+    })
+}
+
 /// Use the `format!` syntax to write data into a buffer.
 ///
 /// This macro is typically used with a buffer of `&mut `[`Write`][write].
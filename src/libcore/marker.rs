@@ -462,3 +462,38 @@ pub trait Reflect {}
            reason = "requires RFC and more experience",
            issue = "27749")]
 impl Reflect for .. { }
+
+/// Types that can be safely moved after being pinned.
+///
+/// `Unpin` is implemented for all types that do not opt out of it, and
+/// cancels the guarantees of [`Pin`](../pin/struct.Pin.html): a
+/// `Pin<&mut T>` for a `T: Unpin` can be freely turned back into a
+/// `&mut T`. A type that relies on never being moved once pinned opts out
+/// by containing a [`PhantomPinned`](struct.PhantomPinned.html).
+#[unstable(feature = "pin", issue = "0")]
+pub trait Unpin {}
+
+#[unstable(feature = "pin", issue = "0")]
+impl Unpin for .. { }
+
+// Moving a pointer never moves its pointee, so pointers are `Unpin` even
+// when what they point to isn't.
+#[unstable(feature = "pin", issue = "0")]
+impl<'a, T: ?Sized + 'a> Unpin for &'a T {}
+#[unstable(feature = "pin", issue = "0")]
+impl<'a, T: ?Sized + 'a> Unpin for &'a mut T {}
+#[unstable(feature = "pin", issue = "0")]
+impl<T: ?Sized> Unpin for *const T {}
+#[unstable(feature = "pin", issue = "0")]
+impl<T: ?Sized> Unpin for *mut T {}
+
+/// A marker type which does not implement `Unpin`.
+///
+/// If a type contains a `PhantomPinned`, it will not implement `Unpin` by
+/// default.
+#[unstable(feature = "pin", issue = "0")]
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct PhantomPinned;
+
+#[unstable(feature = "pin", issue = "0")]
+impl !Unpin for PhantomPinned {}
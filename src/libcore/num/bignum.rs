@@ -13,18 +13,56 @@
 //! This is designed to avoid the heap allocation at expense of stack memory.
 //! The most used bignum type, `Big32x40`, is limited by 32 × 40 = 1,280 bits
 //! and will take at most 160 bytes of stack memory. This is more than enough
-//! for round-tripping all possible finite `f64` values.
+//! for formatting all possible finite `f64` values.
 //!
 //! In principle it is possible to have multiple bignum types for different
-//! inputs, but we don't do so to avoid the code bloat. Each bignum is still
-//! tracked for the actual usages, so it normally doesn't matter.
-
-// This module is only for dec2flt and flt2dec, and only public because of libcoretest.
-// It is not intended to ever be stabilized.
-#![doc(hidden)]
-#![unstable(feature = "core_private_bignum",
-            reason = "internal routines only exposed for testing",
-            issue = "0")]
+//! inputs, but we don't do so to avoid the code bloat, except for a few:
+//! parsing has to look at up to 768 significant digits to round correctly,
+//! so it gets `Big32x200`, which takes 800 bytes, and the exponent range of
+//! `f128` is sixteen times as wide, so formatting it gets `Big32x580`, which
+//! takes 2,320 bytes, and parsing it `Big32x1210`, which takes 4,840 bytes.
+//! Each bignum is still tracked for the actual usages, so it normally
+//! doesn't matter.
+//!
+//! These are the bignums that `dec2flt` and `flt2dec` use, and they are
+//! public for other exact conversions between decimal and binary that can't
+//! allocate. They are unsigned, and they only have the operations that those
+//! conversions need: addition, subtraction, multiplication and division by a
+//! digit or by another bignum, multiplication by powers of two and five,
+//! shifts, and comparison.
+//!
+//! # Capacity
+//!
+//! A bignum never grows: `Big32x40` holds integers below `2^1280`,
+//! `Big32x200` integers below `2^6400`, `Big32x580` integers below
+//! `2^18560`, and `Big32x1210` integers below `2^38720`. Every operation
+//! whose result doesn't fit, or that would make it negative, panics instead
+//! of wrapping around, so the caller has to bound its values beforehand and
+//! pick a large enough type.
+//! The same goes for `mul_pow2` when the digits in use, which after a
+//! subtraction may include leading zeros, would be shifted past the last one.
+//!
+//! # Examples
+//!
+//! ```
+//! #![feature(bignum)]
+//! use std::num::bignum::Big32x40 as Big;
+//!
+//! // 10^30 is 5^30 * 2^30
+//! let mut x = Big::from_small(1);
+//! x.mul_pow5(30).mul_pow2(30);
+//! assert_eq!(x.bit_length(), 100);
+//!
+//! let mut y = x.clone();
+//! y.div_pow2(30);
+//! assert_eq!(y, *Big::from_small(1).mul_pow5(30));
+//! assert!(x > y);
+//!
+//! let (_, rem) = x.div_rem_small(7);
+//! assert_eq!(rem, 1);
+//! ```
+
+#![unstable(feature = "bignum", issue = "0")]
 #![macro_use]
 
 use prelude::v1::*;
@@ -118,10 +156,11 @@ macro_rules! define_bignum {
         /// Thus this is intentionally not `Copy`.
         ///
         /// All operations available to bignums panic in the case of over/underflows.
-        /// The caller is responsible to use large enough bignum types.
+        /// The caller is responsible to use large enough bignum types; see the
+        /// [module documentation](index.html#capacity) for their limits.
         pub struct $name {
             /// One plus the offset to the maximum "digit" in use.
-            /// This does not decrease, so be aware of the computation order.
+            /// Only `div_pow2` decreases this, so be aware of the computation order.
             /// `base[size..]` should be zero.
             size: usize,
             /// Digits. `[a, b, c, ...]` represents `a + b*2^W + c*2^(2W) + ...`
@@ -219,6 +258,7 @@ macro_rules! define_bignum {
                 self
             }
 
+            /// Adds a digit-sized `other` to itself and returns its own mutable reference.
             pub fn add_small(&mut self, other: $ty) -> &mut $name {
                 use num::bignum::FullOps;
 
@@ -315,6 +355,46 @@ macro_rules! define_bignum {
                 self
             }
 
+            /// Divides itself by `2^bits`, discarding the remainder, and returns its own
+            /// mutable reference.
+            pub fn div_pow2(&mut self, bits: usize) -> &mut $name {
+                use mem;
+
+                let digitbits = mem::size_of::<$ty>() * 8;
+                let digits = bits / digitbits;
+                let bits = bits % digitbits;
+
+                let sz = self.size;
+                if digits >= sz {
+                    for i in 0..sz {
+                        self.base[i] = 0;
+                    }
+                    self.size = 1;
+                    return self;
+                }
+
+                // shift by `digits * digitbits` bits
+                let last = sz - digits;
+                for i in 0..last {
+                    self.base[i] = self.base[i+digits];
+                }
+                for i in last..sz {
+                    self.base[i] = 0;
+                }
+
+                // shift by `bits` bits
+                if bits > 0 {
+                    for i in 0..last-1 {
+                        self.base[i] = (self.base[i] >> bits) |
+                                       (self.base[i+1] << (digitbits - bits));
+                    }
+                    self.base[last-1] >>= bits;
+                }
+
+                self.size = last;
+                self
+            }
+
             /// Multiplies itself by `5^e` and returns its own mutable reference.
             pub fn mul_pow5(&mut self, mut e: usize) -> &mut $name {
                 use mem;
@@ -486,13 +566,22 @@ macro_rules! define_bignum {
     )
 }
 
-/// The digit type for `Big32x40`.
+/// The digit type for `Big32x40`, `Big32x200`, `Big32x580` and `Big32x1210`.
 pub type Digit32 = u32;
 
 define_bignum!(Big32x40: type=Digit32, n=40);
 
+define_bignum!(Big32x200: type=Digit32, n=200);
+
+define_bignum!(Big32x580: type=Digit32, n=580);
+
+define_bignum!(Big32x1210: type=Digit32, n=1210);
+
 // this one is used for testing only.
 #[doc(hidden)]
+#[unstable(feature = "core_private_bignum",
+           reason = "internal routines only exposed for testing",
+           issue = "0")]
 pub mod tests {
     use prelude::v1::*;
     define_bignum!(Big8x3: type=u8, n=3);
@@ -0,0 +1,197 @@
+// Copyright 2016 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Conversions from integers to floats that do not round silently.
+//!
+//! A cast like `u64 as f64` rounds to the nearest float whenever the integer
+//! has more significant bits than the float's significand. [`FromInt`] either
+//! refuses to round, or rounds in an explicitly given direction.
+//!
+//! [`FromInt`]: trait.FromInt.html
+//!
+//! # Examples
+//!
+//! ```
+//! #![feature(float_from_int, float_rounding_mode)]
+//!
+//! use std::num::RoundingMode;
+//!
+//! assert_eq!(f64::try_from_int_exact(1u64 << 53), Ok(9007199254740992.0));
+//! assert!(f64::try_from_int_exact((1u64 << 53) + 1).is_err());
+//! assert_eq!(f64::from_int_round((1u64 << 53) + 1, RoundingMode::TowardPositive),
+//!            9007199254740994.0);
+//! ```
+
+#![unstable(feature = "float_from_int",
+            reason = "recently added",
+            issue = "0")]
+
+use fmt;
+use i128::i128;
+use num::{Float, RoundingMode};
+use result::Result::{self, Ok, Err};
+use u128::u128;
+
+/// The error type returned when an integer is not exactly representable as a
+/// float.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct InexactIntError(());
+
+impl InexactIntError {
+    #[unstable(feature = "int_error_internals",
+               reason = "available through Error trait and this method should \
+                         not be exposed publicly",
+               issue = "0")]
+    #[doc(hidden)]
+    pub fn __description(&self) -> &str {
+        "integer not exactly representable as a float"
+    }
+}
+
+impl fmt::Display for InexactIntError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.__description().fmt(f)
+    }
+}
+
+/// Conversion from the integer type `I`, with control over rounding.
+pub trait FromInt<I>: Sized {
+    /// Converts `i` if it is exactly representable, and returns an error
+    /// otherwise.
+    fn try_from_int_exact(i: I) -> Result<Self, InexactIntError>;
+
+    /// Converts `i`, rounding in the direction given by `mode`.
+    ///
+    /// With `RoundingMode::TiesToEven` this is the same as an `as` cast.
+    fn from_int_round(i: I, mode: RoundingMode) -> Self;
+}
+
+/// Rounds `(-1)^neg * m` to a float with `sig_bits` significant bits.
+/// Returns the rounded significand and exponent, and whether it is exact.
+fn round(neg: bool, m: u64, sig_bits: u32, mode: RoundingMode) -> (u64, i32, bool) {
+    let bits = 64 - m.leading_zeros();
+    if bits <= sig_bits {
+        return (m, 0, true);
+    }
+    let shift = bits - sig_bits;
+    let q = m >> shift;
+    let rem = m & ((1 << shift) - 1);
+    let half = 1 << (shift - 1);
+    let up = match mode {
+        RoundingMode::TiesToEven => rem > half || (rem == half && q & 1 == 1),
+        RoundingMode::TiesToAway => rem >= half,
+        RoundingMode::TowardZero => false,
+        RoundingMode::TowardPositive => rem != 0 && !neg,
+        RoundingMode::TowardNegative => rem != 0 && neg,
+        RoundingMode::ToOdd => rem != 0 && q & 1 == 0,
+    };
+    // `q + 1` may be `2^sig_bits`, which is still exact
+    (if up { q + 1 } else { q }, shift as i32, rem == 0)
+}
+
+/// Like `round`, for a 128-bit `m`.
+fn round_wide(neg: bool, m: u128, sig_bits: u32, mode: RoundingMode) -> (u64, i32, bool) {
+    let extra = 64 - m.to_parts().0.leading_zeros();
+    if extra == 0 {
+        return round(neg, m.to_parts().1, sig_bits, mode);
+    }
+    // Round the top 64 bits instead, with any nonzero bits below them kept
+    // in the lowest one. That bit is always rounded off, so this rounds the
+    // same way in every mode.
+    let top = (m >> extra).to_parts().1;
+    let sticky = m << (128 - extra) != u128::from(0u8);
+    let (q, e, exact) = round(neg, top | sticky as u64, sig_bits, mode);
+    (q, e + extra as i32, exact)
+}
+
+macro_rules! from_int_impl {
+    ($float:ident, $sig_bits:expr, unsigned $($int:ident)*) => ($(
+        impl FromInt<$int> for $float {
+            fn try_from_int_exact(i: $int) -> Result<$float, InexactIntError> {
+                match round(false, i as u64, $sig_bits, RoundingMode::TowardZero) {
+                    (_, _, false) => Err(InexactIntError(())),
+                    _ => Ok(i as $float),
+                }
+            }
+
+            fn from_int_round(i: $int, mode: RoundingMode) -> $float {
+                let (q, e, _) = round(false, i as u64, $sig_bits, mode);
+                Float::ldexp(q as $float, e)
+            }
+        }
+    )*);
+    ($float:ident, $sig_bits:expr, $($int:ident)*) => ($(
+        impl FromInt<$int> for $float {
+            fn try_from_int_exact(i: $int) -> Result<$float, InexactIntError> {
+                match round(i < 0, (i as i64).wrapping_abs() as u64, $sig_bits,
+                            RoundingMode::TowardZero) {
+                    (_, _, false) => Err(InexactIntError(())),
+                    _ => Ok(i as $float),
+                }
+            }
+
+            fn from_int_round(i: $int, mode: RoundingMode) -> $float {
+                let neg = i < 0;
+                let (q, e, _) = round(neg, (i as i64).wrapping_abs() as u64, $sig_bits, mode);
+                let x = Float::ldexp(q as $float, e);
+                if neg { -x } else { x }
+            }
+        }
+    )*);
+}
+
+// `u128` and `i128` can't be cast with `as`, so they are always converted
+// through their rounded significand.
+macro_rules! from_wide_int_impl {
+    ($($float:ident $sig_bits:expr;)*) => ($(
+        impl FromInt<u128> for $float {
+            fn try_from_int_exact(i: u128) -> Result<$float, InexactIntError> {
+                match round_wide(false, i, $sig_bits, RoundingMode::TowardZero) {
+                    (q, e, true) => Ok(Float::ldexp(q as $float, e)),
+                    _ => Err(InexactIntError(())),
+                }
+            }
+
+            fn from_int_round(i: u128, mode: RoundingMode) -> $float {
+                let (q, e, _) = round_wide(false, i, $sig_bits, mode);
+                Float::ldexp(q as $float, e)
+            }
+        }
+
+        impl FromInt<i128> for $float {
+            fn try_from_int_exact(i: i128) -> Result<$float, InexactIntError> {
+                let neg = i.is_negative();
+                match round_wide(neg, i.unsigned_abs(), $sig_bits, RoundingMode::TowardZero) {
+                    (q, e, true) => {
+                        let x = Float::ldexp(q as $float, e);
+                        Ok(if neg { -x } else { x })
+                    }
+                    _ => Err(InexactIntError(())),
+                }
+            }
+
+            fn from_int_round(i: i128, mode: RoundingMode) -> $float {
+                let neg = i.is_negative();
+                let (q, e, _) = round_wide(neg, i.unsigned_abs(), $sig_bits, mode);
+                let x = Float::ldexp(q as $float, e);
+                if neg { -x } else { x }
+            }
+        }
+    )*)
+}
+
+from_int_impl!(f32, 24, i8 i16 i32 i64 isize);
+from_int_impl!(f32, 24, unsigned u8 u16 u32 u64 usize);
+from_int_impl!(f64, 53, i8 i16 i32 i64 isize);
+from_int_impl!(f64, 53, unsigned u8 u16 u32 u64 usize);
+from_wide_int_impl! {
+    f32 24;
+    f64 53;
+}
@@ -12,8 +12,10 @@
 
 use prelude::v1::*;
 use cmp::min;
-use cmp::Ordering::{Less, Equal, Greater};
+use cmp::Ordering::{self, Less, Equal, Greater};
+use num::FpCategory;
 use num::diy_float::Fp;
+use num::flt2dec::Rounding;
 use num::dec2flt::table;
 use num::dec2flt::rawfp::{self, Unpacked, RawFloat, fp_to_float, next_float, prev_float};
 use num::dec2flt::num::{self, Big};
@@ -424,3 +426,63 @@ fn round_by_remainder<T: RawFloat>(v: Big, r: Big, q: u64, z: T) -> T {
         next_float(z)
     }
 }
+
+/// Compare `f * 10^e` with `sig * 2^k`, through the same ratio as Algorithm R.
+pub fn compare_with_float(f: &Big, e: i16, sig: u64, k: i16) -> Ordering {
+    let mut x = f.clone();
+    let mut y = Big::from_u64(sig);
+    make_ratio(&mut x, &mut y, e, k);
+    x.cmp(&y)
+}
+
+/// Round a positive value as `rounding` says, given `z`, the value rounded to the nearest and
+/// ties to even, and `cmp`, where `cmp(sig, k)` compares the value with `sig * 2^k`.
+///
+/// All the algorithms above round to the nearest. Rather than teaching each of them the other
+/// roundings, this corrects their result: the value is either `z` itself or lies between `z` and
+/// one of its neighbours, so one exact comparison tells which two floats it lies between, and
+/// only a possible tie for `Rounding::NearestUp` takes a second one.
+pub fn round_directed<T, F>(z: T, rounding: Rounding, cmp: F) -> T
+    where T: RawFloat, F: Fn(u64, i16) -> Ordering
+{
+    let order = match z.classify2() {
+        // Overflow and underflow: the value is finite, and not zero.
+        FpCategory::Infinite => Less,
+        FpCategory::Zero => Greater,
+        _ => {
+            let Unpacked { sig, k } = z.unpack();
+            cmp(sig, k)
+        }
+    };
+    let (down, up) = match order {
+        Equal => return z,
+        // The bits of the float below a positive one (or infinity) are one less.
+        Less => (T::from_bits2(z.to_bits2() - 1), z),
+        Greater => (z, next_float(z)),
+    };
+    match rounding {
+        Rounding::NearestEven => z,
+        // Only a tie that rounded down to even rounds up instead.
+        Rounding::NearestUp if order == Greater => {
+            let (sig, k) = midpoint(down, up);
+            if cmp(sig, k) == Equal { up } else { z }
+        }
+        Rounding::NearestUp => z,
+        Rounding::Down => down,
+        Rounding::Up => up,
+        Rounding::Odd => if down.to_bits2() & 1 == 1 { down } else { up },
+    }
+}
+
+/// The point halfway between neighbouring floats `down < up`, as `sig * 2^k`. Infinity stands for
+/// `T::MAX` plus one ULP, which is the boundary for overflow when rounding to the nearest.
+pub fn midpoint<T: RawFloat>(down: T, up: T) -> (u64, i16) {
+    let Unpacked { sig, k } = down.unpack();
+    if up.classify2() == FpCategory::Infinite {
+        return (2 * sig + 1, k - 1);
+    }
+    // The exponents differ by at most one, where `up` is the smallest float of a binade.
+    let up = up.unpack();
+    let k_min = min(k, up.k);
+    ((sig << (k - k_min)) + (up.sig << (up.k - k_min)), k_min - 1)
+}
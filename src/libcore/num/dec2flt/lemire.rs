@@ -0,0 +1,115 @@
+// Copyright 2016 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! The Eisel-Lemire algorithm, for converting `w * 10^q` with a 64-bit `w`.
+//!
+//! It multiplies `w` by a 128-bit approximation of `5^q`, of which the upper 64 bits are almost
+//! always enough to determine the correctly rounded significand: the product is only ambiguous
+//! if the bits below those that are kept are all ones, because the truncated part of the
+//! approximation might then carry into them. In that case the lower 64 bits of the
+//! approximation are multiplied in as well, and if that still doesn't settle it, the caller
+//! has to fall back to the bignum algorithms. This happens for a vanishingly small fraction of
+//! all inputs.
+//!
+//! See Daniel Lemire, "Number Parsing at a Gigabyte per Second", Software: Practice and
+//! Experience 51 (8), 2021.
+
+use prelude::v1::*;
+use u64;
+use num::quad::wide_mul as mul;
+use num::dec2flt::rawfp::RawFloat;
+use num::dec2flt::table;
+
+/// Compute the correctly rounded float of `w * 10^q`, or `None` if that can't be determined
+/// without knowing more digits of `5^q`.
+pub fn compute_float<T: RawFloat>(q: i64, mut w: u64) -> Option<T> {
+    let sig_bits = T::explicit_sig_bits() as i32;
+    if w == 0 || q < T::smallest_power_of_ten() as i64 {
+        return Some(T::zero2());
+    } else if q > T::largest_power_of_ten() as i64 {
+        return Some(T::infinity2());
+    }
+    // Normalize `w` so that the product has its highest bit in one of the top two positions.
+    let lz = w.leading_zeros();
+    w <<= lz;
+    let (hi, lo) = product_approx(q, w, sig_bits as u32 + 3);
+    if lo == u64::MAX {
+        // The rest of `5^q`, which the table doesn't have, could carry into the bits we keep.
+        // That can't happen when `5^q` fits into 128 bits (`q <= 55`), or when `5^-q` fits into
+        // 64 bits (`q >= -27`), so only outside of these we don't know.
+        if q < -27 || q > 55 {
+            return None;
+        }
+    }
+
+    // Keep one bit more than the significand has, for rounding.
+    let upper_bit = (hi >> 63) as i32;
+    let shift = upper_bit + 64 - sig_bits - 3;
+    let mut mantissa = hi >> shift;
+    let mut power2 = power(q as i32) + upper_bit - lz as i32 + T::max_exp() as i32;
+    if power2 <= 0 {
+        // The result is subnormal, or zero: shift the significand to the smallest exponent.
+        if -power2 + 1 >= 64 {
+            return Some(T::zero2());
+        }
+        mantissa >>= -power2 + 1;
+        mantissa += mantissa & 1;
+        mantissa >>= 1;
+        // Rounding up may carry into the smallest normal exponent, which is then encoded by
+        // the hidden bit alone.
+        return Some(T::from_bits2(mantissa));
+    }
+    // The product is exactly halfway between two floats. This can only happen for exponents
+    // where `5^q` is exact in 128 bits and the product has no bits below those that are kept
+    // but the rounding bit. Then it rounds to even, instead of up.
+    if lo <= 1 && q >= T::min_exponent_round_to_even() as i64 &&
+       q <= T::max_exponent_round_to_even() as i64 && mantissa & 3 == 1 &&
+       (mantissa << shift) == hi {
+        mantissa &= !1;
+    }
+    mantissa += mantissa & 1;
+    mantissa >>= 1;
+    if mantissa >= 2 << sig_bits {
+        // Rounding up carried into the next binade.
+        mantissa = 1 << sig_bits;
+        power2 += 1;
+    }
+    mantissa &= !(1 << sig_bits);
+    if power2 >= T::max_encoded_exp() as i32 {
+        return Some(T::infinity2());
+    }
+    Some(T::from_bits2(mantissa | (power2 as u64) << sig_bits))
+}
+
+/// Approximates `floor(log2(10^q)) + 63`, which is exact in the range of exponents of the table,
+/// with a fixed-point multiplication by `log2(10)`.
+fn power(q: i32) -> i32 {
+    (q.wrapping_mul(152170 + 65536) >> 16) + 63
+}
+
+/// Multiplies `w` by the approximation of `5^q`, returning the upper 128 bits of the product.
+///
+/// Only the upper `precision` bits of the upper word are needed, so the lower words of the
+/// approximation are only multiplied in when the bits below them are all ones, when a carry
+/// could change them.
+fn product_approx(q: i64, w: u64, precision: u32) -> (u64, u64) {
+    let mask = u64::MAX >> precision;
+    let index = (q - table::SMALLEST_POWER_OF_FIVE as i64) as usize;
+    let (hi5, lo5) = table::POWERS_OF_FIVE_128[index];
+    let (mut hi, mut lo) = mul(w, hi5);
+    if hi & mask == mask {
+        let (carry, _) = mul(w, lo5);
+        lo = lo.wrapping_add(carry);
+        if carry > lo {
+            hi += 1;
+        }
+    }
+    (hi, lo)
+}
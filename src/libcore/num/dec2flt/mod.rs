@@ -41,6 +41,17 @@
 //! simple but very slow algorithm that involved computing `f * 10^e` fully and doing an iterative
 //! search for the best approximation.
 //!
+//! Right after the fastest of these special cases, the Eisel-Lemire algorithm (see the `lemire`
+//! module) converts the leading 19 digits with one or two 64 by 64 bit multiplications. It
+//! settles virtually all inputs, so the rest of the chain only runs for the few where it can't
+//! tell which way to round.
+//!
+//! The bignums that the rest of the chain uses have a fixed size, so at most `MAX_SIG_DIGITS`
+//! significant digits of `f` go into them, followed by a single `1` that stands in for any
+//! digits after those. Neither a float nor the point halfway between two of them has that many
+//! significant digits, so the truncated decimal lies between the same two of them as the input,
+//! and rounds the same way, however long the input is.
+//!
 //! Primarily, this module and its children implement the algorithms described in:
 //! "How to Read Floating Point Numbers Accurately" by William D. Clinger,
 //! available online: http://citeseerx.ist.psu.edu/viewdoc/summary?doi=10.1.1.45.4152
@@ -93,28 +104,41 @@
             issue = "0")]
 
 use prelude::v1::*;
-use fmt;
+use {cmp, fmt, i32};
+use num::{FpCategory, RoundingMode, f16, f128};
+use num::flt2dec::Rounding;
 use str::FromStr;
 
-use self::parse::{parse_decimal, Decimal, Sign, ParseResult};
-use self::num::digits_to_big;
+use self::parse::{parse_decimal, hex_digit_value, Decimal, Hexadecimal, Sign, ParseResult};
+use self::num::{digits_to_big, Big};
 use self::rawfp::RawFloat;
 
+pub use self::options::{dec2flt_with, ParseFloatOptions};
+pub use self::radix::dec2flt_radix;
+
 mod algorithm;
+mod lemire;
+mod options;
+mod radix;
 mod table;
 mod num;
+mod wide;
 // These two have their own tests.
 pub mod rawfp;
 pub mod parse;
 
+/// The most significant digits that go into a bignum. The longest decimal expansion of an `f64`,
+/// or of the point halfway between two of them, has 767.
+const MAX_SIG_DIGITS: usize = 768;
+
 macro_rules! from_str_float_impl {
     ($t:ty) => {
         #[stable(feature = "rust1", since = "1.0.0")]
         impl FromStr for $t {
             type Err = ParseFloatError;
 
-            /// Converts a string in base 10 to a float.
-            /// Accepts an optional decimal exponent.
+            /// Converts a string in base 10 or 16 to a float.
+            /// Accepts an optional decimal or binary exponent.
             ///
             /// This function accepts strings such as
             ///
@@ -125,7 +149,11 @@ macro_rules! from_str_float_impl {
             /// * '.' (understood as 0)
             /// * '5.'
             /// * '.5', or, equivalently,  '0.5'
-            /// * 'inf', '-inf', 'NaN'
+            /// * '0x1.8p-3', or equivalently, '0X1.8P-3' (hexadecimal digits with a
+            ///   binary exponent, as accepted by C99 `strtod`)
+            /// * 'inf', '-inf', 'NaN', '-NaN'
+            /// * 'NaN(0x1f)', or equivalently, 'nan(0x1f)' or 'NaN(31)' (a quiet NaN with
+            ///   the payload 31, as accepted by C99 `strtod`)
             ///
             /// Leading and trailing whitespace represent an error.
             ///
@@ -140,13 +168,75 @@ macro_rules! from_str_float_impl {
             /// number represented by `src`.
             #[inline]
             fn from_str(src: &str) -> Result<Self, ParseFloatError> {
-                dec2flt(src)
+                dec2flt(src.as_bytes())
             }
         }
     }
 }
 from_str_float_impl!(f32);
 from_str_float_impl!(f64);
+from_str_float_impl!(f16);
+
+impl FromStr for f128 {
+    type Err = ParseFloatError;
+
+    /// Converts a string in base 10 or 16 to an `f128`, with the same syntax
+    /// and the same correct rounding as for `f64`.
+    #[inline]
+    fn from_str(src: &str) -> Result<f128, ParseFloatError> {
+        wide::dec2flt_wide(src.as_bytes())
+    }
+}
+
+/// Parses a float of any `RawFloat` type, with the syntax and the correct rounding of `FromStr`
+/// for `f64`.
+///
+/// This is what `FromStr` for `f32`, `f64` and `f16` does, so that types of other crates which
+/// implement `RawFloat` can implement `FromStr` in the same way.
+///
+/// # Examples
+///
+/// ```
+/// #![feature(raw_float)]
+/// use std::num;
+///
+/// assert_eq!(num::parse_float::<f32>("0.1"), Ok(0.1f32));
+/// assert_eq!(num::parse_float::<f64>("-0x1.8p1"), Ok(-3.0));
+/// assert!(num::parse_float::<f64>("1e").is_err());
+/// ```
+#[unstable(feature = "raw_float", issue = "0")]
+pub fn parse_float<T: RawFloat>(src: &str) -> Result<T, ParseFloatError> {
+    dec2flt(src.as_bytes())
+}
+
+/// Parses a float of any `RawFloat` type from ASCII bytes, like `parse_float` does from a
+/// string, for implementing `Float::from_ascii`.
+#[unstable(feature = "raw_float", issue = "0")]
+pub fn parse_float_ascii<T: RawFloat>(src: &[u8]) -> Result<T, ParseFloatError> {
+    dec2flt(src)
+}
+
+/// Parses a float of any `RawFloat` type like `parse_float`, but fails if the value of the
+/// string isn't exactly representable, for implementing `Float::from_str_exact`.
+#[unstable(feature = "raw_float", issue = "0")]
+pub fn parse_float_exact<T: RawFloat>(src: &str) -> Result<T, ParseFloatError> {
+    dec2flt_exact(src.as_bytes())
+}
+
+/// Parses the longest prefix of a string that is a float of any `RawFloat` type, and returns it
+/// with the number of bytes it takes up, for implementing `Float::from_str_partial`.
+#[unstable(feature = "raw_float", issue = "0")]
+pub fn parse_float_partial<T: RawFloat>(src: &str) -> Result<(T, usize), ParseFloatError> {
+    dec2flt_partial(src.as_bytes())
+}
+
+/// Parses a float of any `RawFloat` type in the syntax given by `options`, for implementing
+/// `Float::from_str_with`.
+#[unstable(feature = "raw_float", issue = "0")]
+pub fn parse_float_with<T: RawFloat>(src: &str, options: &ParseFloatOptions)
+                                     -> Result<T, ParseFloatError> {
+    dec2flt_with(src, options)
+}
 
 /// An error which can be returned when parsing a float.
 ///
@@ -159,16 +249,72 @@ from_str_float_impl!(f64);
 #[derive(Debug, Clone, PartialEq)]
 #[stable(feature = "rust1", since = "1.0.0")]
 pub struct ParseFloatError {
-    kind: FloatErrorKind
+    kind: FloatErrorKind,
+    offset: Option<usize>,
 }
 
-#[derive(Debug, Clone, PartialEq)]
-enum FloatErrorKind {
+/// The reasons why parsing a float can fail.
+///
+/// This is returned by [`ParseFloatError::kind`].
+///
+/// [`ParseFloatError::kind`]: struct.ParseFloatError.html#method.kind
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[unstable(feature = "float_error_kind", issue = "0")]
+pub enum FloatErrorKind {
+    /// The string is empty.
     Empty,
-    Invalid,
+    /// A character that cannot be part of the number where it is, like the
+    /// `x` in `1x`.
+    InvalidDigit,
+    /// The number has no digits, like `-` or `e5`.
+    MissingDigits,
+    /// The number ends in an `e`, or in a `p` for hexadecimal floats, without
+    /// any digits after it, like `1e+`.
+    ExponentMissingDigits,
+    /// The number is not exactly representable, when parsing it without
+    /// rounding.
+    Inexact,
+    #[doc(hidden)]
+    __Nonexhaustive,
 }
 
 impl ParseFloatError {
+    /// Returns the reason why parsing failed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(float_error_kind)]
+    /// use std::num::FloatErrorKind;
+    ///
+    /// let err = "1.5e".parse::<f64>().unwrap_err();
+    /// assert_eq!(err.kind(), FloatErrorKind::ExponentMissingDigits);
+    /// ```
+    #[unstable(feature = "float_error_kind", issue = "0")]
+    pub fn kind(&self) -> FloatErrorKind {
+        self.kind
+    }
+
+    /// Returns the offset in bytes of the first character where the string
+    /// stops being a valid float, or `None` if the error is not about a
+    /// particular character, like for an empty string or an inexact number.
+    ///
+    /// The offset is at the end of the string if it ended too early.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(float_error_kind)]
+    ///
+    /// assert_eq!("-1.5x".parse::<f64>().unwrap_err().offset(), Some(4));
+    /// assert_eq!("1.5e".parse::<f64>().unwrap_err().offset(), Some(4));
+    /// assert_eq!("".parse::<f64>().unwrap_err().offset(), None);
+    /// ```
+    #[unstable(feature = "float_error_kind", issue = "0")]
+    pub fn offset(&self) -> Option<usize> {
+        self.offset
+    }
+
     #[unstable(feature = "int_error_internals",
                reason = "available through Error trait and this method should \
                          not be exposed publicly",
@@ -177,7 +323,13 @@ impl ParseFloatError {
     pub fn __description(&self) -> &str {
         match self.kind {
             FloatErrorKind::Empty => "cannot parse float from empty string",
-            FloatErrorKind::Invalid => "invalid float literal",
+            // These all used to be the same error, whose message is kept as it was. `kind`
+            // tells them apart.
+            FloatErrorKind::InvalidDigit |
+            FloatErrorKind::MissingDigits |
+            FloatErrorKind::ExponentMissingDigits => "invalid float literal",
+            FloatErrorKind::Inexact => "float literal is not exactly representable",
+            FloatErrorKind::__Nonexhaustive => unreachable!(),
         }
     }
 }
@@ -190,16 +342,28 @@ impl fmt::Display for ParseFloatError {
 }
 
 fn pfe_empty() -> ParseFloatError {
-    ParseFloatError { kind: FloatErrorKind::Empty }
+    ParseFloatError { kind: FloatErrorKind::Empty, offset: None }
+}
+
+fn pfe_invalid(kind: FloatErrorKind, offset: usize) -> ParseFloatError {
+    ParseFloatError { kind: kind, offset: Some(offset) }
 }
 
-fn pfe_invalid() -> ParseFloatError {
-    ParseFloatError { kind: FloatErrorKind::Invalid }
+fn pfe_inexact() -> ParseFloatError {
+    ParseFloatError { kind: FloatErrorKind::Inexact, offset: None }
+}
+
+/// The length of the sign that `extract_sign` split off `s`.
+fn sign_len(s: &[u8]) -> usize {
+    match s[0] {
+        b'+' | b'-' => 1,
+        _ => 0,
+    }
 }
 
 /// Split decimal string into sign and the rest, without inspecting or validating the rest.
-fn extract_sign(s: &str) -> (Sign, &str) {
-    match s.as_bytes()[0] {
+fn extract_sign(s: &[u8]) -> (Sign, &[u8]) {
+    match s[0] {
         b'+' => (Sign::Positive, &s[1..]),
         b'-' => (Sign::Negative, &s[1..]),
         // If the string is invalid, we never use the sign, so we don't need to validate here.
@@ -207,20 +371,99 @@ fn extract_sign(s: &str) -> (Sign, &str) {
     }
 }
 
+/// Convert the longest prefix of `s` that is a number, and return it with its length in bytes.
+pub fn dec2flt_partial<T: RawFloat>(s: &[u8]) -> Result<(T, usize), ParseFloatError> {
+    if s.is_empty() {
+        return Err(pfe_empty())
+    }
+    let sign_len = sign_len(s);
+    let len = match parse::prefix_len(&s[sign_len..]) {
+        0 => {
+            // Without any number at the start, the whole string fails right there, so report
+            // the same error for it. Only a few quirks like `.e5` are numbers as a whole but
+            // have no prefix that is one.
+            return Err(match dec2flt::<T>(s) {
+                Err(e) => e,
+                Ok(_) => pfe_invalid(FloatErrorKind::MissingDigits, sign_len),
+            });
+        }
+        len => sign_len + len,
+    };
+    dec2flt(&s[..len]).map(|flt| (flt, len))
+}
+
 /// Convert a decimal string into a floating point number.
-fn dec2flt<T: RawFloat>(s: &str) -> Result<T, ParseFloatError> {
+///
+/// This works on the bytes of the string, since the syntax is all ASCII anyway, so that it can
+/// take byte slices that were never checked to be UTF-8 as well.
+pub fn dec2flt<T: RawFloat>(s: &[u8]) -> Result<T, ParseFloatError> {
     if s.is_empty() {
         return Err(pfe_empty())
     }
+    let sign_len = sign_len(s);
     let (sign, s) = extract_sign(s);
     let flt = match parse_decimal(s) {
-        ParseResult::Valid(decimal) => convert(decimal)?,
+        ParseResult::Valid(decimal) => convert(decimal),
+        ParseResult::ValidHex(hex) => convert_hex::<T>(&hex).0,
         ParseResult::ShortcutToInf => T::infinity2(),
         ParseResult::ShortcutToZero => T::zero2(),
-        ParseResult::Invalid => match s {
-            "inf" => T::infinity2(),
-            "NaN" => T::nan2(),
-            _ => { return Err(pfe_invalid()); }
+        ParseResult::Invalid(kind, offset) => {
+            if s == b"inf" {
+                T::infinity2()
+            } else if let Some(nan) = parse_nan_exactly(s) {
+                nan
+            } else {
+                return Err(pfe_invalid(kind, sign_len + offset));
+            }
+        }
+    };
+
+    match sign {
+        Sign::Positive => Ok(flt),
+        Sign::Negative => Ok(-flt),
+    }
+}
+
+/// Convert a decimal string into a floating point number, failing if the value of the string
+/// isn't exactly representable.
+///
+/// `inf` and `NaN` are accepted as they are.
+pub fn dec2flt_exact<T: RawFloat>(s: &[u8]) -> Result<T, ParseFloatError> {
+    if s.is_empty() {
+        return Err(pfe_empty())
+    }
+    let sign_len = sign_len(s);
+    let (sign, s) = extract_sign(s);
+    let flt = match parse_decimal(s) {
+        ParseResult::Valid(mut decimal) => {
+            simplify(&mut decimal);
+            let flt = convert(Decimal::new(decimal.integral, decimal.fractional, decimal.exp));
+            if !is_exact(&decimal, flt) {
+                return Err(pfe_inexact());
+            }
+            flt
+        }
+        ParseResult::ValidHex(hex) => match convert_hex(&hex) {
+            (flt, true) => flt,
+            (_, false) => { return Err(pfe_inexact()); }
+        },
+        // The parser takes these shortcuts for huge exponents without looking at the digits, so
+        // the value is only exact if it's zero.
+        ParseResult::ShortcutToInf | ParseResult::ShortcutToZero => {
+            let mut digits = s.iter().take_while(|&&c| c != b'e' && c != b'E');
+            if digits.any(|&c| b'1' <= c && c <= b'9') {
+                return Err(pfe_inexact());
+            }
+            T::zero2()
+        }
+        ParseResult::Invalid(kind, offset) => {
+            if s == b"inf" {
+                T::infinity2()
+            } else if let Some(nan) = parse_nan_exactly(s) {
+                nan
+            } else {
+                return Err(pfe_invalid(kind, sign_len + offset));
+            }
         }
     };
 
@@ -230,25 +473,178 @@ fn dec2flt<T: RawFloat>(s: &str) -> Result<T, ParseFloatError> {
     }
 }
 
+/// Convert a decimal string into a floating point number, rounding as `mode` says.
+///
+/// The conversion itself rounds to the nearest, and `algorithm::round_directed` corrects it with
+/// exact comparisons, so that the result is correctly rounded in every mode.
+pub fn dec2flt_rounded<T: RawFloat>(s: &[u8], mode: RoundingMode) -> Result<T, ParseFloatError> {
+    if mode == RoundingMode::TiesToEven {
+        return dec2flt(s);
+    }
+    if s.is_empty() {
+        return Err(pfe_empty())
+    }
+    let sign_len = sign_len(s);
+    let (sign, s) = extract_sign(s);
+    let rounding = match sign {
+        Sign::Positive => Rounding::new(mode, false),
+        Sign::Negative => Rounding::new(mode, true),
+    };
+    let flt = match parse_decimal(s) {
+        ParseResult::Valid(mut decimal) => {
+            simplify(&mut decimal);
+            if decimal.integral.is_empty() && decimal.fractional.is_empty() {
+                T::zero2()
+            } else if let Some(z) = trivial_cases(&decimal) {
+                // The value is far from `T::MAX` and from half the smallest subnormal, so the
+                // only comparison that can happen, with the latter, comes out less.
+                algorithm::round_directed(z, rounding, |_, _| cmp::Ordering::Less)
+            } else {
+                let z = convert(Decimal::new(decimal.integral, decimal.fractional, decimal.exp));
+                // The truncated digits lie between the same two floats as the value, and the
+                // bound on the size of the bignums in `convert` also covers comparing with `z`
+                // and its neighbours.
+                let (f, _, e) = truncated_digits_to_big(&decimal);
+                let e = e as i16;
+                algorithm::round_directed(z, rounding, |sig, k| {
+                    algorithm::compare_with_float(&f, e, sig, k)
+                })
+            }
+        }
+        ParseResult::ValidHex(hex) => {
+            let (mantissa, e, sticky) = hex_mantissa(&hex);
+            if mantissa == 0 {
+                T::zero2()
+            } else {
+                let (z, _) = convert_hex::<T>(&hex);
+                // As in `convert_hex`, the sticky bit is far enough below the last bit of `z` to
+                // stand for all the digits after the first 16 significant ones.
+                algorithm::round_directed(z, rounding, |sig, k| {
+                    // `2 * mantissa + sticky` and `sig` both have at most 65 bits, so a shift
+                    // beyond that decides the comparison on its own.
+                    let shift = e - 1 - k as i64;
+                    if shift > 65 {
+                        return cmp::Ordering::Greater;
+                    } else if shift < -65 {
+                        return cmp::Ordering::Less;
+                    }
+                    let mut x = Big::from_u64(mantissa);
+                    x.mul_pow2(1).add_small(sticky as u32);
+                    let mut y = Big::from_u64(sig);
+                    if shift >= 0 {
+                        x.mul_pow2(shift as usize);
+                    } else {
+                        y.mul_pow2(-shift as usize);
+                    }
+                    x.cmp(&y)
+                })
+            }
+        }
+        // As in `dec2flt_exact`, the value is zero unless the digits say otherwise, and then it's
+        // as far from any float as in `trivial_cases`.
+        ParseResult::ShortcutToInf | ParseResult::ShortcutToZero => {
+            let mut digits = s.iter().take_while(|&&c| c != b'e' && c != b'E');
+            if !digits.any(|&c| b'1' <= c && c <= b'9') {
+                T::zero2()
+            } else {
+                let z = match parse_decimal(s) {
+                    ParseResult::ShortcutToInf => T::infinity2(),
+                    _ => T::zero2(),
+                };
+                algorithm::round_directed(z, rounding, |_, _| cmp::Ordering::Less)
+            }
+        }
+        ParseResult::Invalid(kind, offset) => {
+            if s == b"inf" {
+                T::infinity2()
+            } else if let Some(nan) = parse_nan_exactly(s) {
+                nan
+            } else {
+                return Err(pfe_invalid(kind, sign_len + offset));
+            }
+        }
+    };
+
+    match sign {
+        Sign::Positive => Ok(flt),
+        Sign::Negative => Ok(-flt),
+    }
+}
+
+/// Converts `s` if all of it is a NaN, with a payload that fits into `T`.
+fn parse_nan_exactly<T: RawFloat>(s: &[u8]) -> Option<T> {
+    match parse::parse_nan(s) {
+        Some((payload, len)) if len == s.len() && payload >> (T::explicit_sig_bits() - 1) == 0 => {
+            Some(T::nan_with_payload2(payload))
+        }
+        _ => None,
+    }
+}
+
+/// Whether `x`, the conversion of the simplified `decimal`, is exactly equal to it.
+///
+/// Rather than comparing the two, this checks whether the decimal is representable at all: if
+/// it is, the correctly rounded conversion is the decimal itself. The decimal `f * 10^e` is
+/// `f * 5^e * 2^e`, which is only an integer times a power of two if `5^-e` divides `f` when
+/// `e < 0`. Writing it as `q * 2^s` with `q` odd, it is representable if `q` fits in the
+/// significand and `2^s` is no smaller than the smallest subnormal.
+fn is_exact<T: RawFloat>(decimal: &Decimal, x: T) -> bool {
+    if decimal.integral.is_empty() && decimal.fractional.is_empty() {
+        return true;
+    }
+    // Overflow and underflow. These also cover everything trivial_cases() catches, which may
+    // have too many digits for a bignum.
+    match x.classify2() {
+        FpCategory::Infinite | FpCategory::Zero => return false,
+        _ => {}
+    }
+    // No float has as many significant digits, and the bignum might not hold them.
+    if decimal.integral.len() + decimal.fractional.len() > MAX_SIG_DIGITS {
+        return false;
+    }
+    let e = decimal.exp - decimal.fractional.len() as i64;
+    let mut f = digits_to_big(decimal.integral, decimal.fractional);
+    if e >= 0 {
+        // 5^e alone needs more bits than the significand has.
+        if e >= T::ceil_log5_of_max_sig() as i64 {
+            return false;
+        }
+        f.mul_pow5(e as usize);
+    } else {
+        for _ in 0..-e {
+            if f.div_rem_small(5).1 != 0 {
+                return false;
+            }
+        }
+    }
+    let bits = f.bit_length();
+    let trailing_zeros = (0..bits).take_while(|&i| f.get_bit(i) == 0).count();
+    let q_bits = bits - trailing_zeros;
+    let s = e + trailing_zeros as i64;
+    q_bits <= T::sig_bits() as usize && s >= T::min_exp_int() as i64
+}
+
 /// The main workhorse for the decimal-to-float conversion: Orchestrate all the preprocessing
 /// and figure out which algorithm should do the actual conversion.
-fn convert<T: RawFloat>(mut decimal: Decimal) -> Result<T, ParseFloatError> {
+fn convert<T: RawFloat>(mut decimal: Decimal) -> T {
     simplify(&mut decimal);
     if let Some(x) = trivial_cases(&decimal) {
-        return Ok(x);
+        return x;
     }
     // Remove/shift out the decimal point.
     let e = decimal.exp - decimal.fractional.len() as i64;
     if let Some(x) = algorithm::fast_path(decimal.integral, decimal.fractional, e) {
-        return Ok(x);
+        return x;
     }
-    // Big32x40 is limited to 1280 bits, which translates to about 385 decimal digits.
-    // If we exceed this, we'll crash, so we error out before getting too close (within 10^10).
-    let upper_bound = bound_intermediate_digits(&decimal, e);
-    if upper_bound > 375 {
-        return Err(pfe_invalid());
+    if let Some(x) = eisel_lemire(&decimal, e) {
+        return x;
     }
-    let f = digits_to_big(decimal.integral, decimal.fractional);
+    let (f, f_len, e) = truncated_digits_to_big(&decimal);
+    // Big32x200 is limited to 6400 bits, which translates to about 1926 decimal digits. With at
+    // most `MAX_SIG_DIGITS + 1` digits and the cutoffs in `trivial_cases`, the bound is at most
+    // 769 + (769 + 326) + 17 = 1881 digits, which leaves a margin of 10^45.
+    let upper_bound = bound_intermediate_digits(f_len, e);
+    debug_assert!(upper_bound <= 1881);
 
     // Now the exponent certainly fits in 16 bit, which is used throughout the main algorithms.
     let e = e as i16;
@@ -257,9 +653,102 @@ fn convert<T: RawFloat>(mut decimal: Decimal) -> Result<T, ParseFloatError> {
     let exponent_in_range = table::MIN_E <= e && e <= table::MAX_E;
     let value_in_range = upper_bound <= T::max_normal_digits() as u64;
     if exponent_in_range && value_in_range {
-        Ok(algorithm::bellerophon(&f, e))
+        algorithm::bellerophon(&f, e)
+    } else {
+        algorithm::algorithm_m(&f, e)
+    }
+}
+
+/// Convert the digits of the simplified `decimal` into a bignum `f`, and return it with the
+/// number of its decimal digits and the exponent `e` of the decimal `f * 10^e`.
+///
+/// Only the first `MAX_SIG_DIGITS` digits go into `f`. If there are more, `f` gets a `1` digit
+/// after those, which puts `f * 10^e` strictly between the truncated value and the next one up,
+/// just as the value itself: `simplify` strips trailing zeros, so the cut off digits aren't all
+/// zero.
+fn truncated_digits_to_big(decimal: &Decimal) -> (Big, usize, i64) {
+    let (integral, fractional) = (decimal.integral, decimal.fractional);
+    let e = decimal.exp - fractional.len() as i64;
+    let f_len = integral.len() + fractional.len();
+    if f_len <= MAX_SIG_DIGITS {
+        return (digits_to_big(integral, fractional), f_len, e);
+    }
+    let mut f = if integral.len() >= MAX_SIG_DIGITS {
+        digits_to_big(&integral[..MAX_SIG_DIGITS], &[])
     } else {
-        Ok(algorithm::algorithm_m(&f, e))
+        digits_to_big(integral, &fractional[..MAX_SIG_DIGITS - integral.len()])
+    };
+    f.mul_small(10).add_small(1);
+    (f, MAX_SIG_DIGITS + 1, e + (f_len - MAX_SIG_DIGITS) as i64 - 1)
+}
+
+/// Convert a hexadecimal float, and tell whether the result is exact.
+///
+/// The digits map directly to bits, so the first 16 significant ones make up a 64-bit mantissa
+/// for `RawFloat::from_exp_mantissa2`, which rounds it only once. Any further digits only matter
+/// for rounding, and it's enough to know whether they are all zero: if not, they go into a
+/// sticky bit at the bottom, which is at least 8 bits below the last one that's kept.
+fn convert_hex<T: RawFloat>(hex: &Hexadecimal) -> (T, bool) {
+    let (mantissa, e, sticky) = hex_mantissa(hex);
+    if mantissa == 0 {
+        return (T::zero2(), true);
+    }
+
+    let exp = cmp::max(cmp::min(e, i32::MAX as i64), i32::MIN as i64) as i32;
+    let x = T::from_exp_mantissa2(exp, mantissa | sticky as u64);
+    // As in `is_exact`, the value is `q * 2^s` with `q` odd.
+    let trailing_zeros = mantissa.trailing_zeros();
+    let q_bits = 64 - mantissa.leading_zeros() - trailing_zeros;
+    let s = e + trailing_zeros as i64;
+    let exact = !sticky && x.classify2() != FpCategory::Infinite &&
+                q_bits <= T::sig_bits() as u32 && s >= T::min_exp_int() as i64;
+    (x, exact)
+}
+
+/// The first 16 significant digits of a hexadecimal float as a mantissa, the exponent that goes
+/// with it, and whether any of the digits after them are not zero.
+fn hex_mantissa(hex: &Hexadecimal) -> (u64, i64, bool) {
+    let mut mantissa = 0u64;
+    let mut sig_digits = 0;
+    let mut sticky = false;
+    // Neither the length of the input nor the saturated exponent come close to overflowing.
+    let mut e = hex.exp - 4 * hex.fractional.len() as i64;
+    for &c in hex.integral.iter().chain(hex.fractional) {
+        let d = hex_digit_value(c).unwrap() as u64;
+        if sig_digits < 16 {
+            mantissa = mantissa << 4 | d;
+            if mantissa != 0 {
+                sig_digits += 1;
+            }
+        } else {
+            e += 4;
+            sticky |= d != 0;
+        }
+    }
+    (mantissa, e, sticky)
+}
+
+/// Try Eisel-Lemire on the leading 19 significant digits, which fit into a `u64`.
+///
+/// If any digits are cut off, the value lies strictly between the truncated `w * 10^q` and
+/// `(w + 1) * 10^q`, so it's only known to round like them if both round to the same float.
+/// Otherwise, or if Eisel-Lemire itself can't tell, the bignum algorithms have to decide.
+fn eisel_lemire<T: RawFloat>(decimal: &Decimal, e: i64) -> Option<T> {
+    let mut w = 0u64;
+    let mut digits = decimal.integral.iter().chain(decimal.fractional);
+    for &c in digits.by_ref().take(19) {
+        w = w * 10 + (c - b'0') as u64;
+    }
+    // `simplify` strips trailing zeros, so any digits that are left over are not all zero.
+    let rest = digits.count();
+    let q = e + rest as i64;
+    match lemire::compute_float::<T>(q, w) {
+        Some(x) if rest == 0 => Some(x),
+        Some(x) => match lemire::compute_float::<T>(q, w + 1) {
+            Some(y) if y.to_bits2() == x.to_bits2() => Some(x),
+            _ => None,
+        },
+        None => None,
     }
 }
 
@@ -293,11 +782,11 @@ fn simplify(decimal: &mut Decimal) {
 }
 
 /// Quick and dirty upper bound on the size (log10) of the largest value that Algorithm R and
-/// Algorithm M will compute while working on the given decimal.
-fn bound_intermediate_digits(decimal: &Decimal, e: i64) -> u64 {
+/// Algorithm M will compute while working on `f * 10^e`, where `f` has `f_len` digits.
+fn bound_intermediate_digits(f_len: usize, e: i64) -> u64 {
     // We don't need to worry too much about overflow here thanks to trivial_cases() and the
     // parser, which filter out the most extreme inputs for us.
-    let f_len: u64 = decimal.integral.len() as u64 + decimal.fractional.len() as u64;
+    let f_len = f_len as u64;
     if e >= 0 {
         // In the case e >= 0, both algorithms compute about `f * 10^e`. Algorithm R proceeds to
         // do some complicated calculations with this but we can ignore that for the upper bound
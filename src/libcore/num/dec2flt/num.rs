@@ -14,8 +14,9 @@
 
 use prelude::v1::*;
 use cmp::Ordering::{self, Less, Equal, Greater};
+use num::swar;
 
-pub use num::bignum::Big32x40 as Big;
+pub use num::bignum::Big32x200 as Big;
 
 /// Test whether truncating all bits less significant than `ones_place` introduces
 /// a relative error less, equal, or greater than 0.5 ULP.
@@ -60,10 +61,19 @@ pub fn from_str_unchecked<'a, T>(bytes: T) -> u64 where T : IntoIterator<Item=&'
 /// Like `from_str_unchecked`, this function relies on the parser to weed out non-digits.
 pub fn digits_to_big(integral: &[u8], fractional: &[u8]) -> Big {
     let mut f = Big::from_small(0);
-    for &c in integral.iter().chain(fractional) {
-        let n = (c - b'0') as u32;
-        f.mul_small(10);
-        f.add_small(n);
+    for &part in &[integral, fractional] {
+        // eight digits at a time, and then the rest one by one
+        let mut digits = part;
+        while let Some(n) = swar::eight_digits(digits) {
+            f.mul_small(100_000_000);
+            f.add_small(n);
+            digits = &digits[8..];
+        }
+        for &c in digits {
+            let n = (c - b'0') as u32;
+            f.mul_small(10);
+            f.add_small(n);
+        }
     }
     f
 }
@@ -0,0 +1,346 @@
+// Copyright 2016 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Parsing decimal strings in a configurable syntax.
+//!
+//! Rewriting such a string into the syntax of `FromStr` would need an allocation, so instead the
+//! significant digits are copied into a buffer on the stack, and go into `convert` as an integer
+//! with an exponent. Leading zeros are skipped, and digits after the first `MAX_SIG_DIGITS` are
+//! dropped but still counted in the exponent. Like `convert` does for long inputs, a single `1`
+//! after the kept digits stands in for the dropped ones unless they are all zero, which doesn't
+//! change the result.
+
+use prelude::v1::*;
+
+use str::Chars;
+
+use super::{convert, pfe_empty, pfe_invalid, FloatErrorKind, ParseFloatError, MAX_SIG_DIGITS};
+use super::FloatErrorKind::{InvalidDigit, MissingDigits, ExponentMissingDigits};
+use super::parse::{Decimal, Sign};
+use super::rawfp::RawFloat;
+
+/// The size of the buffer: the significant digits that are kept, and one for the rest.
+const MAX_DIGITS: usize = MAX_SIG_DIGITS + 1;
+
+/// Exponents are saturated at this, which is far beyond the range of any float.
+const EXP_LIMIT: i64 = 1 << 40;
+
+static NO_GROUPING: [char; 0] = [];
+static DEFAULT_INFINITY: [&'static str; 1] = ["inf"];
+static DEFAULT_NAN: [&'static str; 1] = ["NaN"];
+static LENIENT_INFINITY: [&'static str; 2] = ["inf", "infinity"];
+static LENIENT_NAN: [&'static str; 1] = ["nan"];
+
+/// Options for parsing floats in a syntax other than that of `FromStr`, so
+/// that numbers written for other locales or by other programs don't have to
+/// be rewritten first.
+///
+/// The syntax is an optional sign, then either one of the spellings of
+/// infinity or NaN, or decimal digits with an optional decimal point and an
+/// optional exponent after an `e` or `E`. Grouping characters may appear
+/// between the digits in front of the decimal point, and if enabled,
+/// underscores between any two digits. Hexadecimal floats are not accepted.
+///
+/// The defaults are those of `FromStr`, with `.` as the decimal point, no
+/// grouping, no underscores, and the spellings `inf` and `NaN`.
+/// `lenient_specials` adds the spellings of C, Python and JavaScript.
+///
+/// # Examples
+///
+/// ```
+/// #![feature(float_parse_options)]
+/// use std::num::ParseFloatOptions;
+///
+/// let mut german = ParseFloatOptions::new();
+/// german.decimal_point(',').grouping(&['.']);
+///
+/// assert_eq!(f64::from_str_with("1.234,5", &german), Ok(1234.5));
+/// assert!(f64::from_str_with("1,234.5", &german).is_err());
+/// ```
+#[derive(Copy, Clone, Debug)]
+#[unstable(feature = "float_parse_options", issue = "0")]
+pub struct ParseFloatOptions<'a> {
+    decimal_point: char,
+    grouping: &'a [char],
+    underscores: bool,
+    infinity: &'a [&'a str],
+    nan: &'a [&'a str],
+    lenient_specials: bool,
+}
+
+impl<'a> ParseFloatOptions<'a> {
+    /// Creates options for the syntax of `FromStr`.
+    #[unstable(feature = "float_parse_options", issue = "0")]
+    pub fn new() -> ParseFloatOptions<'a> {
+        ParseFloatOptions {
+            decimal_point: '.',
+            grouping: &NO_GROUPING,
+            underscores: false,
+            infinity: &DEFAULT_INFINITY,
+            nan: &DEFAULT_NAN,
+            lenient_specials: false,
+        }
+    }
+
+    /// Sets the character that separates the integral and the fractional
+    /// digits.
+    #[unstable(feature = "float_parse_options", issue = "0")]
+    pub fn decimal_point(&mut self, decimal_point: char) -> &mut ParseFloatOptions<'a> {
+        self.decimal_point = decimal_point;
+        self
+    }
+
+    /// Sets the characters that may separate groups of digits in front of
+    /// the decimal point, like the `,` in `1,000`.
+    ///
+    /// A grouping character must be between two digits, but the groups may
+    /// have any size. The decimal point is never taken for a grouping
+    /// character.
+    #[unstable(feature = "float_parse_options", issue = "0")]
+    pub fn grouping(&mut self, grouping: &'a [char]) -> &mut ParseFloatOptions<'a> {
+        self.grouping = grouping;
+        self
+    }
+
+    /// Sets whether underscores may separate digits, as they can in float
+    /// literals like `1_000.000_1e1_0`.
+    ///
+    /// Unlike in literals, each underscore must be between two digits, so
+    /// that `1__0`, `1_` and `_1` are still invalid.
+    #[unstable(feature = "float_parse_options", issue = "0")]
+    pub fn underscores(&mut self, underscores: bool) -> &mut ParseFloatOptions<'a> {
+        self.underscores = underscores;
+        self
+    }
+
+    /// Sets the accepted spellings of infinity. They are compared exactly,
+    /// so different cases have to be listed separately.
+    #[unstable(feature = "float_parse_options", issue = "0")]
+    pub fn infinity(&mut self, spellings: &'a [&'a str]) -> &mut ParseFloatOptions<'a> {
+        self.infinity = spellings;
+        self
+    }
+
+    /// Sets the accepted spellings of NaN, like `infinity` does for infinity.
+    #[unstable(feature = "float_parse_options", issue = "0")]
+    pub fn nan(&mut self, spellings: &'a [&'a str]) -> &mut ParseFloatOptions<'a> {
+        self.nan = spellings;
+        self
+    }
+
+    /// Sets whether `inf`, `infinity` and `nan` are accepted in any case, in
+    /// addition to the spellings of `infinity` and `nan`, like `strtod` and
+    /// JSON5 do. This covers the `Infinity` of JavaScript, the `INF` of C and
+    /// the `nan` of Python.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(float_parse_options)]
+    /// use std::f64;
+    /// use std::num::ParseFloatOptions;
+    ///
+    /// let mut lenient = ParseFloatOptions::new();
+    /// lenient.lenient_specials(true);
+    ///
+    /// assert_eq!(f64::from_str_with("+Infinity", &lenient), Ok(f64::INFINITY));
+    /// assert_eq!(f64::from_str_with("-INF", &lenient), Ok(f64::NEG_INFINITY));
+    /// assert!(f64::from_str_with("nan", &lenient).unwrap().is_nan());
+    /// assert!(f64::from_str_with("infinit", &lenient).is_err());
+    /// ```
+    #[unstable(feature = "float_parse_options", issue = "0")]
+    pub fn lenient_specials(&mut self, lenient: bool) -> &mut ParseFloatOptions<'a> {
+        self.lenient_specials = lenient;
+        self
+    }
+}
+
+#[unstable(feature = "float_parse_options", issue = "0")]
+impl<'a> Default for ParseFloatOptions<'a> {
+    fn default() -> ParseFloatOptions<'a> {
+        ParseFloatOptions::new()
+    }
+}
+
+/// Convert a string in the syntax given by `options` into a floating point number.
+pub fn dec2flt_with<T: RawFloat>(s: &str, options: &ParseFloatOptions)
+                                 -> Result<T, ParseFloatError> {
+    if s.is_empty() {
+        return Err(pfe_empty());
+    }
+    let (sign, sign_len) = match s.as_bytes()[0] {
+        b'+' => (Sign::Positive, 1),
+        b'-' => (Sign::Negative, 1),
+        _ => (Sign::Positive, 0),
+    };
+    let s = &s[sign_len..];
+    let flt = if is_special(s, options.infinity, options, &LENIENT_INFINITY) {
+        T::infinity2()
+    } else if is_special(s, options.nan, options, &LENIENT_NAN) {
+        T::nan2()
+    } else {
+        let mut digits = [0; MAX_DIGITS];
+        match scan(s, options, &mut digits) {
+            Ok((len, exp)) => convert(Decimal::new(&digits[..len], b"", exp)),
+            Err((kind, offset)) => { return Err(pfe_invalid(kind, sign_len + offset)); }
+        }
+    };
+
+    match sign {
+        Sign::Positive => Ok(flt),
+        Sign::Negative => Ok(-flt),
+    }
+}
+
+/// Copies the significant digits of `s` into `digits`, and returns how many there are and the
+/// exponent that goes with them as an integer, or what is wrong with `s` and where.
+///
+/// The errors are the same that `parse_decimal` gives for the same mistakes.
+fn scan(s: &str, options: &ParseFloatOptions, digits: &mut [u8; MAX_DIGITS])
+        -> Result<(usize, i64), (FloatErrorKind, usize)> {
+    let mut len = 0;
+    let mut exp = 0i64;
+    let mut dropped_nonzero = false;
+    let mut seen_digit = false;
+    let mut chars = s.chars();
+    let mut c = chars.next();
+
+    // The integral part, where each dropped digit makes the rest ten times larger.
+    loop {
+        match c {
+            Some(d @ '0'...'9') => {
+                if len < MAX_SIG_DIGITS {
+                    if len > 0 || d != '0' {
+                        digits[len] = d as u8;
+                        len += 1;
+                    }
+                } else {
+                    exp += 1;
+                    dropped_nonzero |= d != '0';
+                }
+                seen_digit = true;
+                c = chars.next();
+            }
+            Some(g) if seen_digit && g != options.decimal_point &&
+                       (options.grouping.contains(&g) || is_underscore(g, options)) => {
+                let at = offset(s, &chars, c);
+                c = chars.next();
+                match c {
+                    Some('0'...'9') => {}
+                    _ => return Err((InvalidDigit, at)), // No digit after the grouping character
+                }
+            }
+            _ => break,
+        }
+    }
+
+    // The fractional part, where each digit that is kept, or skipped as a leading zero, makes the
+    // rest ten times smaller.
+    if c == Some(options.decimal_point) {
+        c = chars.next();
+        let mut seen_frac_digit = false;
+        loop {
+            match c {
+                Some(d @ '0'...'9') => {
+                    if len < MAX_SIG_DIGITS {
+                        if len > 0 || d != '0' {
+                            digits[len] = d as u8;
+                            len += 1;
+                        }
+                        exp -= 1;
+                    } else {
+                        dropped_nonzero |= d != '0';
+                    }
+                    seen_digit = true;
+                    seen_frac_digit = true;
+                    c = chars.next();
+                }
+                Some(u) if seen_frac_digit && is_underscore(u, options) => {
+                    let at = offset(s, &chars, c);
+                    c = chars.next();
+                    match c {
+                        Some('0'...'9') => {}
+                        _ => return Err((InvalidDigit, at)), // No digit after the underscore
+                    }
+                }
+                _ => break,
+            }
+        }
+    }
+    if dropped_nonzero {
+        digits[len] = b'1';
+        len += 1;
+        exp -= 1;
+    }
+    if !seen_digit {
+        return match c {
+            None | Some('e') | Some('E') => Err((MissingDigits, offset(s, &chars, c))),
+            _ => Err((InvalidDigit, offset(s, &chars, c))),
+        };
+    }
+
+    match c {
+        None => {}
+        Some('e') | Some('E') => {
+            let rest = chars.as_str().as_bytes();
+            let (negative, rest) = match rest.first() {
+                Some(&b'-') => (true, &rest[1..]),
+                Some(&b'+') => (false, &rest[1..]),
+                _ => (false, rest),
+            };
+            let at = |i: usize| s.len() - rest.len() + i;
+            if rest.first().map_or(true, |&b| !is_digit(b)) {
+                return Err((ExponentMissingDigits, at(0))); // Empty exponent
+            }
+            let mut abs_exp = 0i64;
+            for (i, &b) in rest.iter().enumerate() {
+                if is_underscore(b as char, options) && i > 0 && i + 1 < rest.len() &&
+                   is_digit(rest[i - 1]) && is_digit(rest[i + 1]) {
+                    continue;
+                }
+                if !is_digit(b) {
+                    return Err((InvalidDigit, at(i))); // Trailing junk after exponent
+                }
+                if abs_exp < EXP_LIMIT {
+                    abs_exp = abs_exp * 10 + (b - b'0') as i64;
+                }
+            }
+            exp += if negative { -abs_exp } else { abs_exp };
+        }
+        _ => return Err((InvalidDigit, offset(s, &chars, c))), // Trailing junk
+    }
+    Ok((len, exp))
+}
+
+/// The offset in `s` of `c`, which was just taken from `chars`.
+fn offset(s: &str, chars: &Chars, c: Option<char>) -> usize {
+    s.len() - chars.as_str().len() - c.map_or(0, |c| c.len_utf8())
+}
+
+/// Whether `s` is one of `spellings`, or with lenient specials, one of the lowercase `lenient`
+/// spellings in any case.
+fn is_special(s: &str, spellings: &[&str], options: &ParseFloatOptions, lenient: &[&str]) -> bool {
+    spellings.iter().any(|&spelling| spelling == s) ||
+    options.lenient_specials && lenient.iter().any(|&spelling| eq_ignore_case(s, spelling))
+}
+
+/// Whether `s` is the same as the ASCII `lowercase` but for the case of its letters.
+fn eq_ignore_case(s: &str, lowercase: &str) -> bool {
+    s.len() == lowercase.len() &&
+    s.bytes().zip(lowercase.bytes()).all(|(a, b)| a == b || a.wrapping_add(b'a' - b'A') == b)
+}
+
+fn is_underscore(c: char, options: &ParseFloatOptions) -> bool {
+    c == '_' && options.underscores
+}
+
+fn is_digit(b: u8) -> bool {
+    b >= b'0' && b <= b'9'
+}
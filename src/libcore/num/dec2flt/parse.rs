@@ -13,7 +13,14 @@
 //! `(digits | digits? '.'? digits?) (('e' | 'E') ('+' | '-')? digits)?`
 //!
 //! In other words, standard floating-point syntax, with two exceptions: No sign, and no
-//! handling of "inf" and "NaN". These are handled by the driver function (super::dec2flt).
+//! handling of "inf" and "NaN". These are handled by the driver function (super::dec2flt), with
+//! the help of `parse_nan` for NaN payloads.
+//!
+//! Hexadecimal floats as accepted by C99 `strtod` are recognized as well:
+//!
+//! `('0x' | '0X') (hexdigits | hexdigits? '.'? hexdigits?) (('p' | 'P') ('+' | '-')? digits)?`
+//!
+//! where at least one hexadecimal digit is required and the exponent is a power of two.
 //!
 //! Although recognizing valid inputs is relatively easy, this module also has to reject the
 //! countless invalid variations, never panic, and perform numerous checks that the other
@@ -22,7 +29,10 @@
 //! So, be careful when modifying anything, and double-check with the other modules.
 use prelude::v1::*;
 use super::num;
-use self::ParseResult::{Valid, ShortcutToInf, ShortcutToZero, Invalid};
+use num::swar;
+use super::FloatErrorKind;
+use super::FloatErrorKind::{InvalidDigit, MissingDigits, ExponentMissingDigits};
+use self::ParseResult::{Valid, ValidHex, ShortcutToInf, ShortcutToZero, Invalid};
 
 #[derive(Debug)]
 pub enum Sign {
@@ -45,71 +55,248 @@ impl<'a> Decimal<'a> {
     }
 }
 
+#[derive(Debug, PartialEq, Eq)]
+/// The interesting parts of a hexadecimal string, without the `0x` prefix.
+pub struct Hexadecimal<'a> {
+    pub integral: &'a [u8],
+    pub fractional: &'a [u8],
+    /// The binary exponent, saturated to `+/- HEX_EXP_LIMIT`.
+    pub exp: i64,
+}
+
+/// Larger binary exponents make the value zero or infinity no matter how many digits there are
+/// in front of them, short of a terabyte of them.
+pub const HEX_EXP_LIMIT: i64 = 1 << 40;
+
+impl<'a> Hexadecimal<'a> {
+    pub fn new(integral: &'a [u8], fractional: &'a [u8], exp: i64) -> Hexadecimal<'a> {
+        Hexadecimal { integral: integral, fractional: fractional, exp: exp }
+    }
+}
+
 #[derive(Debug, PartialEq, Eq)]
 pub enum ParseResult<'a> {
     Valid(Decimal<'a>),
+    ValidHex(Hexadecimal<'a>),
     ShortcutToInf,
     ShortcutToZero,
-    Invalid,
+    /// What is wrong, and the offset in bytes of the first character where that is so.
+    Invalid(FloatErrorKind, usize),
 }
 
 /// Check if the input string is a valid floating point number and if so, locate the integral
 /// part, the fractional part, and the exponent in it. Does not handle signs.
-pub fn parse_decimal(s: &str) -> ParseResult {
+pub fn parse_decimal(s: &[u8]) -> ParseResult {
     if s.is_empty() {
-        return Invalid;
+        return Invalid(MissingDigits, 0);
     }
+    // The offset of what is left of the input.
+    let len = s.len();
+    let at = |rest: &[u8]| len - rest.len();
 
-    let s = s.as_bytes();
+    if s.len() >= 2 && s[0] == b'0' && (s[1] == b'x' || s[1] == b'X') {
+        return parse_hex(len, &s[2..]);
+    }
     let (integral, s) = eat_digits(s);
 
     match s.first() {
         None => Valid(Decimal::new(integral, b"", 0)),
         Some(&b'e') | Some(&b'E') => {
             if integral.is_empty() {
-                return Invalid; // No digits before 'e'
+                return Invalid(MissingDigits, 0); // No digits before 'e'
             }
 
-            parse_exp(integral, b"", &s[1..])
+            parse_exp(len, integral, b"", &s[1..])
         }
         Some(&b'.') => {
             let (fractional, s) = eat_digits(&s[1..]);
             if integral.is_empty() && fractional.is_empty() && s.is_empty() {
-                return Invalid;
+                return Invalid(MissingDigits, at(s));
             }
 
             match s.first() {
                 None => Valid(Decimal::new(integral, fractional, 0)),
-                Some(&b'e') | Some(&b'E') => parse_exp(integral, fractional, &s[1..]),
-                _ => Invalid, // Trailing junk after fractional part
+                Some(&b'e') | Some(&b'E') => parse_exp(len, integral, fractional, &s[1..]),
+                _ => Invalid(InvalidDigit, at(s)), // Trailing junk after fractional part
             }
         }
-        _ => Invalid, // Trailing junk after first digit string
+        _ => Invalid(InvalidDigit, at(s)), // Trailing junk after first digit string
     }
 }
 
-/// Carve off decimal digits up to the first non-digit character.
-fn eat_digits(s: &[u8]) -> (&[u8], &[u8]) {
+/// The hexadecimal counterpart of `parse_decimal`, for the input after the `0x` prefix. `len` is
+/// the length of the whole input, to compute offsets from.
+fn parse_hex(len: usize, s: &[u8]) -> ParseResult {
+    let at = |rest: &[u8]| len - rest.len();
+    let (integral, s) = eat_hex_digits(s);
+    let (fractional, s) = match s.first() {
+        Some(&b'.') => eat_hex_digits(&s[1..]),
+        _ => (&b""[..], s),
+    };
+    if integral.is_empty() && fractional.is_empty() {
+        return Invalid(MissingDigits, at(s)); // No digits at all, `0x.` or `0xp1`
+    }
+
+    let exp = match s.first() {
+        None => 0,
+        Some(&b'p') | Some(&b'P') => {
+            let (negative, rest) = match s.get(1) {
+                Some(&b'-') => (true, &s[2..]),
+                Some(&b'+') => (false, &s[2..]),
+                _ => (false, &s[1..]),
+            };
+            let (number, trailing) = eat_digits(rest);
+            if number.is_empty() {
+                return Invalid(ExponentMissingDigits, at(rest)); // Empty exponent
+            }
+            if !trailing.is_empty() {
+                return Invalid(InvalidDigit, at(trailing)); // Trailing junk after exponent
+            }
+            // Unlike the decimal exponent, this cannot shortcut to zero or infinity on its own,
+            // since the digits in front of it may all be zero. Saturating it is just as good.
+            let mut abs_exp = 0i64;
+            for &c in number {
+                abs_exp = abs_exp * 10 + (c - b'0') as i64;
+                if abs_exp > HEX_EXP_LIMIT {
+                    abs_exp = HEX_EXP_LIMIT;
+                    break;
+                }
+            }
+            if negative { -abs_exp } else { abs_exp }
+        }
+        _ => return Invalid(InvalidDigit, at(s)), // Trailing junk after the digits
+    };
+    ValidHex(Hexadecimal::new(integral, fractional, exp))
+}
+
+/// Returns the length of the longest prefix of `s` that is a number without a sign, or zero if
+/// there is none. An `e` or `p` that is not followed by exponent digits is not part of it, and
+/// neither is an `x` that is not followed by hexadecimal digits.
+pub fn prefix_len(s: &[u8]) -> usize {
+    if s.starts_with(b"inf") {
+        return 3;
+    }
+    if let Some((_, len)) = parse_nan(s) {
+        return len;
+    }
+    if s.len() >= 2 && s[0] == b'0' && (s[1] == b'x' || s[1] == b'X') {
+        let (integral, rest) = eat_hex_digits(&s[2..]);
+        let (fractional, rest) = match rest.first() {
+            Some(&b'.') => eat_hex_digits(&rest[1..]),
+            _ => (&b""[..], rest),
+        };
+        if !integral.is_empty() || !fractional.is_empty() {
+            return s.len() - rest.len() + exp_len(rest, b'p');
+        }
+        // Otherwise only the `0` is a number.
+    }
+    let (integral, rest) = eat_digits(s);
+    let (fractional, rest) = match rest.first() {
+        Some(&b'.') => eat_digits(&rest[1..]),
+        _ => (&b""[..], rest),
+    };
+    if integral.is_empty() && fractional.is_empty() {
+        return 0;
+    }
+    s.len() - rest.len() + exp_len(rest, b'e')
+}
+
+/// Returns the length of the exponent at the start of `s`, or zero if there is no complete
+/// exponent. `e` is the lowercase letter introducing it, the uppercase one is accepted as well.
+fn exp_len(s: &[u8], e: u8) -> usize {
+    match s.first() {
+        Some(&c) if c | 0x20 == e => {}
+        _ => return 0,
+    }
+    let rest = match s.get(1) {
+        Some(&b'-') | Some(&b'+') => &s[2..],
+        _ => &s[1..],
+    };
+    let (number, _) = eat_digits(rest);
+    if number.is_empty() { 0 } else { s.len() - rest.len() + number.len() }
+}
+
+/// Returns the value of a hexadecimal digit.
+pub fn hex_digit_value(c: u8) -> Option<u8> {
+    match c {
+        b'0'...b'9' => Some(c - b'0'),
+        b'a'...b'f' => Some(c - b'a' + 10),
+        b'A'...b'F' => Some(c - b'A' + 10),
+        _ => None,
+    }
+}
+
+/// Parses a NaN at the start of `s`, which is either `NaN`, or `NaN` or `nan` followed by a
+/// payload in parentheses as in C, like `nan(0x1f)`. The payload is hexadecimal after `0x` and
+/// decimal otherwise. Returns the payload, which is zero without one, and the length of the NaN.
+pub fn parse_nan(s: &[u8]) -> Option<(u64, usize)> {
+    let lower = s.starts_with(b"nan");
+    if !lower && !s.starts_with(b"NaN") {
+        return None;
+    }
+    match parse_nan_payload(&s[3..]) {
+        Some((payload, len)) => Some((payload, 3 + len)),
+        // The lowercase spelling of C is only accepted with a payload.
+        None if lower => None,
+        None => Some((0, 3)),
+    }
+}
+
+/// Parses a parenthesized NaN payload at the start of `s`, and returns it with its length.
+fn parse_nan_payload(s: &[u8]) -> Option<(u64, usize)> {
+    let (radix, start) = if s.starts_with(b"(0x") || s.starts_with(b"(0X") {
+        (16, 3)
+    } else if s.starts_with(b"(") {
+        (10, 1)
+    } else {
+        return None;
+    };
+    let mut payload = 0u64;
+    for (i, &c) in s[start..].iter().enumerate() {
+        let digit = match hex_digit_value(c) {
+            Some(d) if d < radix => d,
+            _ if c == b')' && i > 0 => return Some((payload, start + i + 1)),
+            _ => return None,
+        };
+        let next = payload.checked_mul(radix as u64).and_then(|p| p.checked_add(digit as u64));
+        payload = match next {
+            Some(p) => p,
+            None => return None, // Too large for any float
+        };
+    }
+    None // No closing parenthesis
+}
+
+/// Carve off hexadecimal digits up to the first non-digit character.
+fn eat_hex_digits(s: &[u8]) -> (&[u8], &[u8]) {
     let mut i = 0;
-    while i < s.len() && b'0' <= s[i] && s[i] <= b'9' {
+    while i < s.len() && hex_digit_value(s[i]).is_some() {
         i += 1;
     }
     (&s[..i], &s[i..])
 }
 
-/// Exponent extraction and error checking.
-fn parse_exp<'a>(integral: &'a [u8], fractional: &'a [u8], rest: &'a [u8]) -> ParseResult<'a> {
+/// Carve off decimal digits up to the first non-digit character.
+fn eat_digits(s: &[u8]) -> (&[u8], &[u8]) {
+    let i = swar::digit_len(s);
+    (&s[..i], &s[i..])
+}
+
+/// Exponent extraction and error checking. `len` is the length of the whole input, to compute
+/// offsets from.
+fn parse_exp<'a>(len: usize, integral: &'a [u8], fractional: &'a [u8], rest: &'a [u8])
+                 -> ParseResult<'a> {
     let (sign, rest) = match rest.first() {
         Some(&b'-') => (Sign::Negative, &rest[1..]),
         Some(&b'+') => (Sign::Positive, &rest[1..]),
         _ => (Sign::Positive, rest),
     };
     let (mut number, trailing) = eat_digits(rest);
-    if !trailing.is_empty() {
-        return Invalid; // Trailing junk after exponent
-    }
     if number.is_empty() {
-        return Invalid; // Empty exponent
+        return Invalid(ExponentMissingDigits, len - rest.len()); // Empty exponent
+    }
+    if !trailing.is_empty() {
+        return Invalid(InvalidDigit, len - trailing.len()); // Trailing junk after exponent
     }
     // At this point, we certainly have a valid string of digits. It may be too long to put into
     // an `i64`, but if it's that huge, the input is certainly zero or infinity. Since each zero
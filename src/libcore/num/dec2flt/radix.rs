@@ -0,0 +1,237 @@
+// Copyright 2016 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Converting strings of digits in any radix from 2 to 36 into floats.
+//!
+//! There is no exponent, so the value is `0.d1 d2 ... dn * r^p` for the significant digits `d`
+//! in radix `r`, up to a point. The first `K` digits, for a `K` such that `r^(K-1) >= 2^64`,
+//! make up a bignum `f`, and dividing `f * r^(p-K)` by a power of two gives a 64-bit mantissa
+//! and a sticky bit for `RawFloat::from_exp_mantissa2`, which rounds it only once. That's
+//! correct as it is if there are no further digits.
+//!
+//! If there are, the value lies strictly between `f * r^(p-K)` and `(f + 1) * r^(p-K)`, which
+//! are too close together for two points halfway between floats to fit between them, so the
+//! result is either right or one off. Comparing the value with the points halfway to the
+//! neighbours of the result tells, and those comparisons go through all the digits, one at a
+//! time: unlike in radix 10, the points halfway between floats have no finite expansion in an
+//! odd radix, so no number of digits is always enough.
+
+use prelude::v1::*;
+use cmp;
+use cmp::Ordering::{self, Less, Equal, Greater};
+use num::{FloatErrorKind, FpCategory};
+use super::{ParseFloatError, pfe_empty, pfe_invalid, sign_len, extract_sign};
+use super::algorithm::midpoint;
+use super::num::{get_bits, to_u64, Big};
+use super::parse::Sign;
+use super::rawfp::RawFloat;
+
+/// Convert a string of digits in radix `radix`, with an optional sign and an optional point,
+/// into a floating point number.
+///
+/// # Panics
+///
+/// Panics if `radix` is not in the range from 2 to 36.
+pub fn dec2flt_radix<T: RawFloat>(s: &[u8], radix: u32) -> Result<T, ParseFloatError> {
+    assert!(radix >= 2 && radix <= 36,
+           "from_str_radix_float: must lie in the range `[2, 36]` - found {}",
+           radix);
+    if s.is_empty() {
+        return Err(pfe_empty())
+    }
+    let sign_len = sign_len(s);
+    let (sign, s) = extract_sign(s);
+    let (integral, fractional) = match parse_digits(s, radix) {
+        Ok(digits) => digits,
+        Err((kind, offset)) => return Err(pfe_invalid(kind, sign_len + offset)),
+    };
+    let flt: T = convert(integral, fractional, radix);
+
+    match sign {
+        Sign::Positive => Ok(flt),
+        Sign::Negative => Ok(-flt),
+    }
+}
+
+/// Split `s` into the digits before and after the point, or tell what is wrong with it and
+/// where.
+fn parse_digits(s: &[u8], radix: u32) -> Result<(&[u8], &[u8]), (FloatErrorKind, usize)> {
+    let digit_len = |s: &[u8]| s.iter().take_while(|&&c| (c as char).to_digit(radix).is_some())
+                                       .count();
+    let (integral, rest) = s.split_at(digit_len(s));
+    let (fractional, rest) = match rest.first() {
+        Some(&b'.') => {
+            let rest = &rest[1..];
+            rest.split_at(digit_len(rest))
+        }
+        _ => (&b""[..], rest),
+    };
+    let offset = s.len() - rest.len();
+    if !rest.is_empty() {
+        Err((FloatErrorKind::InvalidDigit, offset))
+    } else if integral.is_empty() && fractional.is_empty() {
+        Err((FloatErrorKind::MissingDigits, offset))
+    } else {
+        Ok((integral, fractional))
+    }
+}
+
+fn digit_value(c: u8, radix: u32) -> u32 {
+    (c as char).to_digit(radix).unwrap()
+}
+
+/// Convert the digits of a positive number into the nearest float.
+fn convert<T: RawFloat>(integral: &[u8], fractional: &[u8], radix: u32) -> T {
+    // Strip the zeros that don't make a difference, and then those in front of the first
+    // significant digit, whose place `p` is one more than the number of integral digits.
+    let integral = &integral[integral.iter().take_while(|&&c| c == b'0').count()..];
+    let fractional = &fractional[..fractional.len() -
+                                   fractional.iter().rev().take_while(|&&c| c == b'0').count()];
+    let leading_zeros = if integral.is_empty() {
+        fractional.iter().take_while(|&&c| c == b'0').count()
+    } else {
+        0
+    };
+    let sig_fractional = &fractional[leading_zeros..];
+    let n = integral.len() + sig_fractional.len();
+    if n == 0 {
+        return T::zero2();
+    }
+    let p = integral.len() as i64 - leading_zeros as i64;
+
+    // With `2^lo <= r`, the value is at least `r^(p-1) >= 2^(lo*(p-1))` for `p >= 1`, and less
+    // than `r^p <= 2^(lo*p)` for `p <= 0`. Beyond `T::MAX` and below half the smallest subnormal,
+    // the bignums would get too large.
+    let lo = 31 - radix.leading_zeros();
+    if lo as i64 * (p - 1) > T::max_exp() as i64 {
+        return T::infinity2();
+    } else if lo as i64 * p < T::min_exp_int() as i64 - 1 {
+        return T::zero2();
+    }
+
+    let k = cmp::min(n, 64 / lo as usize + 2);
+    let mut num = Big::from_small(0);
+    let mut truncated = false;
+    for (i, &c) in integral.iter().chain(sig_fractional).enumerate() {
+        if i < k {
+            num.mul_small(radix).add_small(digit_value(c, radix));
+        } else if c != b'0' {
+            truncated = true;
+            break;
+        }
+    }
+
+    // `num / den` is the value of the first `k` digits, and scaling it to between `2^61` and
+    // `2^63` leaves room below the last bit of any `RawFloat` for the sticky bit.
+    let e = p - k as i64;
+    let mut den = Big::from_small(1);
+    for _ in 0..e.abs() {
+        if e > 0 {
+            num.mul_small(radix);
+        } else {
+            den.mul_small(radix);
+        }
+    }
+    let s = 62 + den.bit_length() as i64 - num.bit_length() as i64;
+    if s >= 0 {
+        num.mul_pow2(s as usize);
+    } else {
+        den.mul_pow2(-s as usize);
+    }
+    let mut q = Big::from_small(0);
+    let mut rem = Big::from_small(0);
+    num.div_rem(&den, &mut q, &mut rem);
+    let sticky = truncated || !rem.is_zero();
+    let z = T::from_exp_mantissa2(-s as i32, to_u64(&q) | sticky as u64);
+    if !truncated {
+        return z;
+    }
+
+    // `z` is the nearest float to some number strictly between the truncated value and the
+    // next one up, and so is the nearest to the value itself unless that is on the other side
+    // of one of the points halfway to the neighbours of `z`, or exactly on it.
+    let compare = |down: T, up: T| {
+        let (sig, k) = midpoint(down, up);
+        compare_with_float(integral, fractional, radix, sig, k)
+    };
+    let even = |x: T, y: T| if x.to_bits2() & 1 == 0 { x } else { y };
+    if z.to_bits2() != 0 {
+        let down = T::from_bits2(z.to_bits2() - 1);
+        match compare(down, z) {
+            Less => return down,
+            Equal => return even(down, z),
+            Greater => {}
+        }
+    }
+    if z.classify2() != FpCategory::Infinite {
+        let up = T::from_bits2(z.to_bits2() + 1);
+        match compare(z, up) {
+            Greater => return up,
+            Equal => return even(z, up),
+            Less => {}
+        }
+    }
+    z
+}
+
+/// Compare the number with the digits `integral` (without leading zeros) and `fractional`
+/// (without trailing zeros) in radix `radix` with `sig * 2^k`, which is below `2^1100`.
+fn compare_with_float(integral: &[u8], fractional: &[u8], radix: u32, sig: u64, k: i16)
+                      -> Ordering {
+    // Split `sig * 2^k` into an integer part and a fraction `frac / 2^s`.
+    let mut int = Big::from_u64(sig);
+    let s = if k >= 0 {
+        int.mul_pow2(k as usize);
+        0
+    } else {
+        -k as usize
+    };
+    let mut frac = int.clone();
+    int.div_pow2(s);
+    let mut whole = int.clone();
+    whole.mul_pow2(s);
+    frac.sub(&whole);
+
+    // The digits of the integer part in radix `radix`, the least significant first.
+    let mut int_digits = [0u8; 1100];
+    let mut int_len = 0;
+    while !int.is_zero() {
+        let (_, d) = int.div_rem_small(radix);
+        int_digits[int_len] = d as u8;
+        int_len += 1;
+    }
+    if integral.len() != int_len {
+        return integral.len().cmp(&int_len);
+    }
+    for (&c, &d) in integral.iter().zip(int_digits[..int_len].iter().rev()) {
+        match digit_value(c, radix).cmp(&(d as u32)) {
+            Equal => {}
+            order => return order,
+        }
+    }
+
+    // The digits of the fraction come out of the top of `frac` as it's multiplied by the radix.
+    for &c in fractional {
+        if frac.is_zero() {
+            // There is a non-zero digit left, the last one.
+            return Greater;
+        }
+        frac.mul_small(radix);
+        let d = get_bits(&frac, s, s + 6) as u32;
+        let mut top = Big::from_small(d);
+        top.mul_pow2(s);
+        frac.sub(&top);
+        match digit_value(c, radix).cmp(&d) {
+            Equal => {}
+            order => return order,
+        }
+    }
+    if frac.is_zero() { Equal } else { Less }
+}
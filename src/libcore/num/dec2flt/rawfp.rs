@@ -28,68 +28,154 @@
 //! take the universally-correct slow path (Algorithm M) for very small and very large numbers.
 //! That algorithm needs only next_float() which does handle subnormals and zeros.
 use prelude::v1::*;
-use u32;
 use cmp::Ordering::{Less, Equal, Greater};
 use ops::{Mul, Div, Neg};
 use fmt::{Debug, LowerExp};
-use mem::transmute;
 use num::diy_float::Fp;
-use num::FpCategory::{Infinite, Zero, Subnormal, Normal, Nan};
-use num::Float;
+use num::FpCategory::{self, Infinite, Zero, Subnormal, Normal, Nan};
+use num::{Float, f16};
 use num::dec2flt::num::{self, Big};
 use num::dec2flt::table;
 
-#[derive(Copy, Clone, Debug)]
-pub struct Unpacked {
-    pub sig: u64,
-    pub k: i16,
-}
-
-impl Unpacked {
-    pub fn new(sig: u64, k: i16) -> Self {
-        Unpacked { sig: sig, k: k }
-    }
-}
+pub use num::fp::Unpacked;
 
-/// A helper trait to avoid duplicating basically all the conversion code for `f32` and `f64`.
+/// The encoding of a binary floating point type, through which `dec2flt` parses floats of that
+/// type with correct rounding.
+///
+/// It is implemented for `f32`, `f64` and `f16`, and other crates can implement it for their own
+/// types to get the same parsing, with the fast path, Eisel-Lemire, Bellerophon and Algorithm M,
+/// from `num::parse_float` and its siblings. See the parent module's doc comment for why this
+/// has to be generic rather than going through `f64`.
+///
+/// # Implementing `RawFloat`
+///
+/// The type has to be laid out like the IEEE 754 binary interchange formats, in at most 64 bits:
+/// a sign bit on top, then `exp_bits()` bits of biased exponent, then the `sig_bits() - 1` bits
+/// of the significand without the hidden bit. An encoded exponent of zero is for zeros and
+/// subnormals, and one of all ones is for infinities and NaNs. `bfloat16` and the binary
+/// formats of other standards fit this, but posits and decimal floats don't.
 ///
-/// See the parent module's doc comment for why this is necessary.
+/// Beyond that, the algorithms rely on a few properties that the compiler can't check:
 ///
-/// Should **never ever** be implemented for other types or be used outside the dec2flt module.
-/// Inherits from `Float` because there is some overlap, but all the reused methods are trivial.
-/// The "methods" (pseudo-constants) with default implementation should not be overriden.
-pub trait RawFloat : Float + Copy + Debug + LowerExp
+/// * `from_int`, `short_fast_pow10`, and `*` and `/` on their results, are correctly rounded
+///   to the nearest and ties to even, like IEEE 754 arithmetic is;
+/// * the decimal exponent range given by `smallest_power_of_ten()` and `largest_power_of_ten()`
+///   is within that of `f64`, which is as far as the tables of powers go;
+/// * the pseudo-constants are exact for the format, the way they are for the implementations
+///   for `f32`, `f64` and `f16` in this module, which show how to derive them.
+///
+/// The "methods" (pseudo-constants) with default implementations are derived from `exp_bits()`
+/// and `sig_bits()` and should not be overridden. So are the methods that decode and encode
+/// values, which only rely on the layout above, and through which `RawFloat` is independent of
+/// `Float`: an implementation only needs the bits of the type, its arithmetic and the constants.
+#[unstable(feature = "raw_float", issue = "0")]
+pub trait RawFloat : Copy + PartialEq + Debug + LowerExp
                     + Mul<Output=Self> + Div<Output=Self> + Neg<Output=Self>
 {
-    // suffix of "2" because Float::infinity is deprecated
-    #[allow(deprecated)]
+    /// Get the raw binary representation of the float, in the low bits of the `u64`. The suffix
+    /// of "2" is to not clash with `Float::to_bits`.
+    fn to_bits2(self) -> u64;
+
+    /// Transmute the raw binary representation into a float. Panics if `bits` has more bits than
+    /// the float. The suffix of "2" is to not clash with `Float::from_bits`.
+    fn from_bits2(bits: u64) -> Self;
+
+    /// Positive infinity. The suffix of "2" is because `Float::infinity` is deprecated.
     fn infinity2() -> Self {
-        Float::infinity()
+        Self::from_bits2((Self::max_encoded_exp() as u64) << Self::explicit_sig_bits())
     }
 
-    // suffix of "2" because Float::nan is deprecated
-    #[allow(deprecated)]
+    /// A quiet NaN. The suffix of "2" is because `Float::nan` is deprecated.
     fn nan2() -> Self {
-        Float::nan()
+        Self::nan_with_payload2(0)
     }
 
-    // suffix of "2" because Float::zero is deprecated
-    fn zero2() -> Self;
+    /// A positive quiet NaN with the given payload, which has to fit below the quiet bit.
+    fn nan_with_payload2(payload: u64) -> Self {
+        let quiet = 1 << (Self::explicit_sig_bits() - 1);
+        assert!(payload < quiet, "NaN payload {:#x} does not fit", payload);
+        Self::from_bits2(Self::infinity2().to_bits2() | quiet | payload)
+    }
 
-    // suffix of "2" because Float::integer_decode is deprecated
-    #[allow(deprecated)]
-    fn integer_decode2(self) -> (u64, i16, i8) {
-        Float::integer_decode(self)
+    /// Positive zero. The suffix of "2" is because `Float::zero` is deprecated.
+    fn zero2() -> Self {
+        Self::from_bits2(0)
+    }
+
+    /// The category of the float, like `Float::classify`.
+    fn classify2(self) -> FpCategory {
+        let sig_mask = (1 << Self::explicit_sig_bits()) - 1;
+        let exp_mask = (Self::max_encoded_exp() as u64) << Self::explicit_sig_bits();
+        let bits = self.to_bits2();
+        match (bits & sig_mask, bits & exp_mask) {
+            (0, 0) => Zero,
+            (_, 0) => Subnormal,
+            (0, e) if e == exp_mask => Infinite,
+            (_, e) if e == exp_mask => Nan,
+            _ => Normal,
+        }
     }
 
-    /// Get the raw binary representation of the float.
-    fn transmute(self) -> u64;
+    /// Whether the sign bit is set, like `Float::is_sign_negative`.
+    fn is_sign_negative2(self) -> bool {
+        self.to_bits2() >> (Self::exp_bits() + Self::explicit_sig_bits()) & 1 == 1
+    }
 
-    /// Transmute the raw binary representation into a float.
-    fn from_bits(bits: u64) -> Self;
+    /// The significand, exponent and sign of a finite float, like `Float::integer_decode`, which
+    /// is deprecated and the reason for the suffix of "2".
+    fn integer_decode2(self) -> (u64, i16, i8) {
+        let bits = self.to_bits2();
+        let sign = if self.is_sign_negative2() { -1 } else { 1 };
+        let sig = bits & ((1 << Self::explicit_sig_bits()) - 1);
+        let exp = (bits >> Self::explicit_sig_bits()) as i16 & Self::max_encoded_exp();
+        // Like the exponent, a subnormal significand is scaled to that of the smallest normal.
+        if exp == 0 {
+            (sig << 1, Self::min_exp_int() - 1, sign)
+        } else {
+            (sig | Self::min_sig(), exp - Self::max_exp() - Self::explicit_sig_bits() as i16, sign)
+        }
+    }
 
     /// Decode the float.
-    fn unpack(self) -> Unpacked;
+    fn unpack(self) -> Unpacked {
+        let (sig, exp, _sig) = self.integer_decode2();
+        Unpacked::new(sig, exp)
+    }
+
+    /// Returns `mantissa * 2^exp`, rounded to the nearest and ties to even, like
+    /// `Float::from_exp_mantissa`. The result overflows to infinity and underflows to subnormals
+    /// or zero.
+    fn from_exp_mantissa2(exp: i32, mantissa: u64) -> Self {
+        if mantissa == 0 {
+            return Self::zero2();
+        }
+        // normalize to `m * 2^e` with a 64-bit `m`, and thus `2^(e + 63) <= value`.
+        let lz = mantissa.leading_zeros();
+        let m = mantissa << lz;
+        let e = exp as i64 - lz as i64 + 63;
+        let (max_exp, min_exp) = (Self::max_exp() as i64, Self::min_exp() as i64);
+        if e > max_exp {
+            return Self::infinity2();
+        }
+        // subnormals have the exponent of the smallest normal, with fewer bits
+        // in the significand.
+        let extra = if e < min_exp { min_exp - e } else { 0 };
+        let shift = (64 - Self::sig_bits() as i64 + extra) as u32;
+        let biased = if e < min_exp { 0 } else { (e - min_exp) as u64 };
+        if shift > 64 {
+            // less than half the smallest subnormal
+            return Self::zero2();
+        }
+        let (q, rem, half) = if shift == 64 {
+            (0, m, 1 << 63)
+        } else {
+            (m >> shift, m & ((1 << shift) - 1), 1 << (shift - 1))
+        };
+        let q = if rem > half || (rem == half && q & 1 == 1) { q + 1 } else { q };
+        // `q` includes the hidden bit for normal numbers, which carries into the
+        // exponent field, as does rounding up to the next binade or to infinity.
+        Self::from_bits2((biased << Self::explicit_sig_bits()) + q)
+    }
 
     /// Cast from a small integer that can be represented exactly.  Panic if the integer can't be
     /// represented, the other code in this module makes sure to never let that happen.
@@ -103,11 +189,12 @@ pub trait RawFloat : Float + Copy + Debug + LowerExp
     // A possible workaround is having a `FloatInfo` struct for all the constants, but so far
     // the methods aren't painful enough to rewrite.
 
-    /// What the name says. It's easier to hard code than juggling intrinsics and
+    /// What the name says: `ceil(log5(2^sig_bits()))`, the first power of ten whose power of five
+    /// doesn't fit the significand. It's easier to hard code than juggling intrinsics and
     /// hoping LLVM constant folds it.
     fn ceil_log5_of_max_sig() -> i16;
 
-    // A conservative bound on the decimal digits of inputs that can't produce overflow or zero or
+    /// A conservative bound on the decimal digits of inputs that can't produce overflow or zero or
     /// subnormals. Probably the decimal exponent of the maximum normal value, hence the name.
     fn max_normal_digits() -> usize;
 
@@ -119,6 +206,17 @@ pub trait RawFloat : Float + Copy + Debug + LowerExp
     /// is certainly rounded to zero.
     fn zero_cutoff() -> i64;
 
+    /// Below and above these decimal exponents, `w * 10^q` is certainly rounded to zero or to
+    /// infinity for any `w` that fits into a `u64`. Eisel-Lemire relies on this to stay within
+    /// its table of powers of five.
+    fn smallest_power_of_ten() -> i16;
+    fn largest_power_of_ten() -> i16;
+
+    /// Only within these decimal exponents can `w * 10^q` be exactly halfway between two floats,
+    /// which is when Eisel-Lemire has to round ties to even itself.
+    fn min_exponent_round_to_even() -> i16;
+    fn max_exponent_round_to_even() -> i16;
+
     /// The number of bits in the exponent.
     fn exp_bits() -> u8;
 
@@ -167,8 +265,12 @@ pub trait RawFloat : Float + Copy + Debug + LowerExp
 }
 
 impl RawFloat for f32 {
-    fn zero2() -> Self {
-        0.0
+    fn to_bits2(self) -> u64 {
+        Float::to_bits(self)
+    }
+
+    fn from_bits2(bits: u64) -> Self {
+        Float::from_bits(bits)
     }
 
     fn sig_bits() -> u8 {
@@ -183,21 +285,6 @@ impl RawFloat for f32 {
         11
     }
 
-    fn transmute(self) -> u64 {
-        let bits: u32 = unsafe { transmute(self) };
-        bits as u64
-    }
-
-    fn from_bits(bits: u64) -> f32 {
-        assert!(bits < u32::MAX as u64, "f32::from_bits: too many bits");
-        unsafe { transmute(bits as u32) }
-    }
-
-    fn unpack(self) -> Unpacked {
-        let (sig, exp, _sig) = self.integer_decode2();
-        Unpacked::new(sig, exp)
-    }
-
     fn from_int(x: u64) -> f32 {
         // rkruppe is uncertain whether `as` rounds correctly on all platforms.
         debug_assert!(x as f32 == fp_to_float(Fp { f: x, e: 0 }));
@@ -219,12 +306,32 @@ impl RawFloat for f32 {
     fn zero_cutoff() -> i64 {
         -48
     }
+
+    fn smallest_power_of_ten() -> i16 {
+        -65
+    }
+
+    fn largest_power_of_ten() -> i16 {
+        38
+    }
+
+    fn min_exponent_round_to_even() -> i16 {
+        -17
+    }
+
+    fn max_exponent_round_to_even() -> i16 {
+        10
+    }
 }
 
 
 impl RawFloat for f64 {
-    fn zero2() -> Self {
-        0.0
+    fn to_bits2(self) -> u64 {
+        Float::to_bits(self)
+    }
+
+    fn from_bits2(bits: u64) -> Self {
+        Float::from_bits(bits)
     }
 
     fn sig_bits() -> u8 {
@@ -239,20 +346,6 @@ impl RawFloat for f64 {
         23
     }
 
-    fn transmute(self) -> u64 {
-        let bits: u64 = unsafe { transmute(self) };
-        bits
-    }
-
-    fn from_bits(bits: u64) -> f64 {
-        unsafe { transmute(bits) }
-    }
-
-    fn unpack(self) -> Unpacked {
-        let (sig, exp, _sig) = self.integer_decode2();
-        Unpacked::new(sig, exp)
-    }
-
     fn from_int(x: u64) -> f64 {
         // rkruppe is uncertain whether `as` rounds correctly on all platforms.
         debug_assert!(x as f64 == fp_to_float(Fp { f: x, e: 0 }));
@@ -275,6 +368,85 @@ impl RawFloat for f64 {
         -326
     }
 
+    fn smallest_power_of_ten() -> i16 {
+        -342
+    }
+
+    fn largest_power_of_ten() -> i16 {
+        308
+    }
+
+    fn min_exponent_round_to_even() -> i16 {
+        -4
+    }
+
+    fn max_exponent_round_to_even() -> i16 {
+        23
+    }
+
+}
+
+// `f16` arithmetic is done in `f32` and rounded once, which is all the fast path needs: there,
+// products and quotients of small integers and powers of ten are exact in or correctly rounded
+// to `f32`, and rounding them again to `f16` is innocuous.
+impl RawFloat for f16 {
+    fn to_bits2(self) -> u64 {
+        Float::to_bits(self)
+    }
+
+    fn from_bits2(bits: u64) -> Self {
+        Float::from_bits(bits)
+    }
+
+    fn sig_bits() -> u8 {
+        11
+    }
+
+    fn exp_bits() -> u8 {
+        5
+    }
+
+    fn ceil_log5_of_max_sig() -> i16 {
+        5
+    }
+
+    fn from_int(x: u64) -> f16 {
+        assert!(x <= Self::max_sig(), "f16::from_int: {} is not exact", x);
+        f16::from_f32(x as f32)
+    }
+
+    fn short_fast_pow10(e: usize) -> Self {
+        // up to 10^4, the powers of ten are exact in `f16`
+        f16::from_f32(table::F32_SHORT_POWERS[e])
+    }
+
+    fn max_normal_digits() -> usize {
+        4
+    }
+
+    fn inf_cutoff() -> i64 {
+        6
+    }
+
+    fn zero_cutoff() -> i64 {
+        -10
+    }
+
+    fn smallest_power_of_ten() -> i16 {
+        -27
+    }
+
+    fn largest_power_of_ten() -> i16 {
+        4
+    }
+
+    fn min_exponent_round_to_even() -> i16 {
+        -22
+    }
+
+    fn max_exponent_round_to_even() -> i16 {
+        5
+    }
 }
 
 /// Convert an Fp to the closest f64. Only handles number that fit into a normalized f64.
@@ -323,14 +495,14 @@ pub fn encode_normal<T: RawFloat>(x: Unpacked) -> T {
         "encode_normal: exponent out of range");
     // Leave sign bit at 0 ("+"), our numbers are all positive
     let bits = (k_enc as u64) << T::explicit_sig_bits() | sig_enc;
-    T::from_bits(bits)
+    T::from_bits2(bits)
 }
 
 /// Construct the subnormal. A mantissa of 0 is allowed and constructs zero.
 pub fn encode_subnormal<T: RawFloat>(significand: u64) -> T {
     assert!(significand < T::min_sig(), "encode_subnormal: not actually subnormal");
     // Encoded exponent is 0, the sign bit is 0, so we just have to reinterpret the bits.
-    T::from_bits(significand)
+    T::from_bits2(significand)
 }
 
 /// Approximate a bignum with an Fp. Rounds within 0.5 ULP with half-to-even.
@@ -357,7 +529,7 @@ pub fn big_to_fp(f: &Big) -> Fp {
 /// Find the largest floating point number strictly smaller than the argument.
 /// Does not handle subnormals, zero, or exponent underflow.
 pub fn prev_float<T: RawFloat>(x: T) -> T {
-    match x.classify() {
+    match x.classify2() {
         Infinite => panic!("prev_float: argument is infinite"),
         Nan => panic!("prev_float: argument is NaN"),
         Subnormal => panic!("prev_float: argument is subnormal"),
@@ -378,7 +550,7 @@ pub fn prev_float<T: RawFloat>(x: T) -> T {
 // Unlike most code in this module, this function does handle zero, subnormals, and infinities.
 // However, like all other code here, it does not deal with NaN and negative numbers.
 pub fn next_float<T: RawFloat>(x: T) -> T {
-    match x.classify() {
+    match x.classify2() {
         Nan => panic!("next_float: argument is NaN"),
         Infinite => T::infinity2(),
         // This seems too good to be true, but it works.
@@ -390,8 +562,8 @@ pub fn next_float<T: RawFloat>(x: T) -> T {
         // too is exactly what we want!
         // Finally, f64::MAX + 1 = 7eff...f + 1 = 7ff0...0 = f64::INFINITY.
         Zero | Subnormal | Normal => {
-            let bits: u64 = x.transmute();
-            T::from_bits(bits + 1)
+            let bits = x.to_bits2();
+            T::from_bits2(bits + 1)
         }
     }
 }
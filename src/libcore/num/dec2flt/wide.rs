@@ -0,0 +1,217 @@
+// Copyright 2016 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Converting decimal strings into `f128`, whose significand doesn't fit `RawFloat`.
+//!
+//! Short decimals take a fast path, like `algorithm::fast_path`: with up to 34 digits and an
+//! exponent of at most 48, both `f` and `10^e` are exact as `f128`s, and are accumulated in a
+//! `u128`. Otherwise the decimal `f * 10^e` is `f * 5^e * 2^e`, so the leading 117 bits of the
+//! quotient of `f` and `5^-e` (or of `f * 5^e` and one) are computed by long division, and
+//! `quad::round` rounds them with a sticky bit for the remainder, which makes the result
+//! correctly rounded.
+//!
+//! As for the other floats, only the first `MAX_DIGITS` significant digits go into `f`,
+//! followed by a `1` that stands in for any digits after those, so decimals of any length
+//! parse. Bignums of 38720 bits are then large enough for every decimal that doesn't trivially
+//! overflow or underflow.
+
+use prelude::v1::*;
+use {cmp, i32};
+use f128::{INFINITY, NAN};
+use num::f128;
+use num::bignum::Big32x1210 as Big;
+use num::quad::{self, SIGN};
+use u128::u128;
+use super::{ParseFloatError, pfe_empty, pfe_invalid};
+use super::{sign_len, extract_sign, simplify};
+use super::parse::{self, parse_decimal, hex_digit_value, Decimal, Hexadecimal, Sign, ParseResult};
+
+/// The most significant digits that go into `f`. The longest decimal expansion of an `f128`,
+/// or of the point halfway between two of them, has at most 11564.
+const MAX_DIGITS: usize = 11564;
+
+/// Convert a decimal string into an `f128`, like `dec2flt` does for the other floats.
+pub fn dec2flt_wide(s: &[u8]) -> Result<f128, ParseFloatError> {
+    if s.is_empty() {
+        return Err(pfe_empty())
+    }
+    let sign_len = sign_len(s);
+    let (sign, s) = extract_sign(s);
+    let (hi, lo) = match parse_decimal(s) {
+        ParseResult::Valid(decimal) => convert(decimal),
+        ParseResult::ValidHex(hex) => convert_hex(&hex),
+        ParseResult::ShortcutToInf => INFINITY.to_bits(),
+        ParseResult::ShortcutToZero => (0, 0),
+        ParseResult::Invalid(kind, offset) => {
+            if s == b"inf" {
+                INFINITY.to_bits()
+            } else if let Some((payload, len)) = parse::parse_nan(s) {
+                if len != s.len() {
+                    return Err(pfe_invalid(kind, sign_len + offset));
+                }
+                // any `u64` fits into the 111 bits of payload
+                (NAN.to_bits().0, payload)
+            } else {
+                return Err(pfe_invalid(kind, sign_len + offset));
+            }
+        }
+    };
+
+    match sign {
+        Sign::Positive => Ok(f128::from_bits(hi, lo)),
+        Sign::Negative => Ok(f128::from_bits(hi ^ SIGN, lo)),
+    }
+}
+
+/// Convert a string of ASCII digits into a bignum, like `num::digits_to_big`.
+fn digits_to_big(integral: &[u8], fractional: &[u8]) -> Big {
+    let mut f = Big::from_small(0);
+    for &c in integral.iter().chain(fractional) {
+        f.mul_small(10);
+        f.add_small((c - b'0') as u32);
+    }
+    f
+}
+
+/// Convert the digits of the simplified `decimal` into a bignum `f`, and return it with the
+/// exponent `e` of the decimal `f * 10^e`, like `truncated_digits_to_big` does for the other
+/// floats.
+///
+/// Only the first `MAX_DIGITS` digits go into `f`. If there are more, `f` gets a `1` digit
+/// after those, which puts `f * 10^e` strictly between the truncated value and the next one up,
+/// just as the value itself.
+fn truncated_digits_to_big(decimal: &Decimal) -> (Big, i64) {
+    let (integral, fractional) = (decimal.integral, decimal.fractional);
+    let e = decimal.exp - fractional.len() as i64;
+    let f_len = integral.len() + fractional.len();
+    if f_len <= MAX_DIGITS {
+        return (digits_to_big(integral, fractional), e);
+    }
+    let mut f = if integral.len() >= MAX_DIGITS {
+        digits_to_big(&integral[..MAX_DIGITS], &[])
+    } else {
+        digits_to_big(integral, &fractional[..MAX_DIGITS - integral.len()])
+    };
+    f.mul_small(10).add_small(1);
+    (f, e + (f_len - MAX_DIGITS) as i64 - 1)
+}
+
+/// Convert a decimal into the bits of the nearest `f128`.
+fn convert(mut decimal: Decimal) -> (u64, u64) {
+    simplify(&mut decimal);
+    let f_len = decimal.integral.len() + decimal.fractional.len();
+    if f_len == 0 {
+        return (0, 0);
+    }
+    // As in `trivial_cases`, this is about `ceil(log10(value))`: `10^4933` is beyond `MAX`,
+    // and `10^-4966` is less than half the smallest subnormal.
+    let max_place = decimal.exp + decimal.integral.len() as i64;
+    if max_place > 4933 {
+        return INFINITY.to_bits();
+    } else if max_place < -4965 {
+        return (0, 0);
+    }
+
+    if let Some(bits) = fast_path(&decimal) {
+        return bits;
+    }
+
+    // With at most `MAX_DIGITS + 1` digits in `f`, `e` is at least `-4965 - 11565`, so `5^-e`
+    // takes up to 38382 bits, and the division up to 118 more.
+    let (mut num, e) = truncated_digits_to_big(&decimal);
+    let mut den = Big::from_small(1);
+    if e >= 0 {
+        num.mul_pow5(e as usize);
+    } else {
+        den.mul_pow5(-e as usize);
+    }
+    // Scale `num / den` to between `2^115` and `2^117`, and the result by `2^-k` to make up
+    // for it.
+    let k = 116 + den.bit_length() as i64 - num.bit_length() as i64;
+    if k >= 0 {
+        num.mul_pow2(k as usize);
+    } else {
+        den.mul_pow2(-k as usize);
+    }
+
+    // One quotient bit at a time, from bit 116 down, by comparing `num` with `den * 2^116` and
+    // doubling `num` instead of halving the divisor.
+    den.mul_pow2(116);
+    let (mut hi, mut lo) = (0, 0);
+    for _ in 0..117 {
+        let (h, l) = quad::shl(hi, lo, 1);
+        hi = h;
+        lo = l;
+        if num >= den {
+            num.sub(&den);
+            lo |= 1;
+        }
+        num.mul_pow2(1);
+    }
+    let sticky = !num.is_zero() as u64;
+    quad::round(false, (e - k) as i32, hi, lo | sticky)
+}
+
+/// Convert a short decimal with a single correctly rounded multiplication or division.
+///
+/// Any 34 digits are less than `10^34 < 2^113`, and `10^48` is `5^48 < 2^112` times a power of
+/// two, so both operands fit the significand of an `f128` without rounding.
+fn fast_path(decimal: &Decimal) -> Option<(u64, u64)> {
+    let (integral, fractional) = (decimal.integral, decimal.fractional);
+    if integral.len() + fractional.len() > 34 {
+        return None;
+    }
+    let e = decimal.exp - fractional.len() as i64;
+    if e.abs() > 48 {
+        return None;
+    }
+
+    let ten = u128::from(10u8);
+    let mut f = u128::from(0u8);
+    for &c in integral.iter().chain(fractional) {
+        f = f * ten + u128::from(c - b'0');
+    }
+    let (fhi, flo) = f.to_parts();
+    let (phi, plo) = u128::from(5u8).pow(e.abs() as u32).to_parts();
+    let f = quad::round(false, 0, fhi, flo);
+    let pow10 = quad::round(false, e.abs() as i32, phi, plo);
+    if e >= 0 {
+        Some(quad::mul(f, pow10))
+    } else {
+        Some(quad::div(f, pow10))
+    }
+}
+
+/// Convert a hexadecimal float into the bits of the nearest `f128`.
+///
+/// Like `convert_hex`, this keeps the first 30 significant digits, which is at least 117 bits
+/// and at most 120, and a sticky bit for any non-zero digits after them.
+fn convert_hex(hex: &Hexadecimal) -> (u64, u64) {
+    let (mut hi, mut lo) = (0u64, 0u64);
+    let mut sig_digits = 0;
+    let mut sticky = false;
+    let mut e = hex.exp - 4 * hex.fractional.len() as i64;
+    for &c in hex.integral.iter().chain(hex.fractional) {
+        let d = hex_digit_value(c).unwrap() as u64;
+        if sig_digits < 30 {
+            let (h, l) = quad::shl(hi, lo, 4);
+            hi = h;
+            lo = l | d;
+            if hi != 0 || lo != 0 {
+                sig_digits += 1;
+            }
+        } else {
+            e += 4;
+            sticky |= d != 0;
+        }
+    }
+    let exp = cmp::max(cmp::min(e, i32::MAX as i64), i32::MIN as i64) as i32;
+    quad::round(false, exp, hi, lo | sticky as u64)
+}
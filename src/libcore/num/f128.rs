@@ -0,0 +1,315 @@
+// Copyright 2016 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Operations and constants for 128-bits floats (`f128` type)
+//!
+//! Like `f16`, `f128` is not a primitive type: it is the IEEE 754 binary128
+//! format stored in two `u64`s, with arithmetic done in software. Addition,
+//! subtraction, multiplication and division are correctly rounded, to the
+//! nearest and ties to even, and so are parsing and the conversions to `f32`
+//! and `f64`. Conversions from `f32` and `f64` are exact.
+//!
+//! Its 113-bit significand does not fit the `u64` mantissas of `Float`, so
+//! `f128` has its own versions of the basic methods instead, and parses and
+//! formats with bignums that are large enough for its exponent range.
+//!
+//! # Examples
+//!
+//! ```
+//! #![feature(f128)]
+//!
+//! use std::num::f128;
+//!
+//! let x: f128 = "0.1".parse().unwrap();
+//! assert_eq!(x.to_string(), "0.1");
+//! assert!(x != f128::from_f64(0.1));
+//! assert_eq!(x.to_f64(), 0.1);
+//! ```
+
+#![unstable(feature = "f128", issue = "0")]
+
+use cmp::{PartialEq, PartialOrd, Ordering};
+use convert::From;
+use default::Default;
+use mem;
+use num::Float;
+use num::quad::{self, SIGN, EXP_MASK, MAN_MASK};
+use ops::{Add, Sub, Mul, Div, Neg};
+use ops::{AddAssign, SubAssign, MulAssign, DivAssign};
+use option::Option;
+use num::FpCategory as Fp;
+
+/// A 128-bit floating point number, in the IEEE 754 binary128 format.
+///
+/// See the [module documentation](../f128/index.html) for how arithmetic and
+/// conversions round.
+#[allow(non_camel_case_types)]
+#[derive(Copy, Clone)]
+pub struct f128 {
+    hi: u64,
+    lo: u64,
+}
+
+/// The radix or base of the internal representation of `f128`.
+pub const RADIX: u32 = 2;
+
+/// Number of significant digits in base 2.
+pub const MANTISSA_DIGITS: u32 = 113;
+/// Approximate number of significant digits in base 10.
+pub const DIGITS: u32 = 33;
+
+/// Difference between `1.0` and the next largest representable number.
+pub const EPSILON: f128 = f128 { hi: 0x3f8f_0000_0000_0000, lo: 0 };
+
+/// Smallest finite `f128` value.
+pub const MIN: f128 = f128 { hi: 0xfffe_ffff_ffff_ffff, lo: 0xffff_ffff_ffff_ffff };
+/// Smallest positive normal `f128` value.
+pub const MIN_POSITIVE: f128 = f128 { hi: 0x0001_0000_0000_0000, lo: 0 };
+/// Largest finite `f128` value.
+pub const MAX: f128 = f128 { hi: 0x7ffe_ffff_ffff_ffff, lo: 0xffff_ffff_ffff_ffff };
+
+/// One greater than the minimum possible normal power of 2 exponent.
+pub const MIN_EXP: i32 = -16381;
+/// Maximum possible power of 2 exponent.
+pub const MAX_EXP: i32 = 16384;
+
+/// Minimum possible normal power of 10 exponent.
+pub const MIN_10_EXP: i32 = -4931;
+/// Maximum possible power of 10 exponent.
+pub const MAX_10_EXP: i32 = 4932;
+
+/// Not a Number (NaN).
+pub const NAN: f128 = f128 { hi: 0x7fff_8000_0000_0000, lo: 0 };
+/// Infinity (∞).
+pub const INFINITY: f128 = f128 { hi: 0x7fff_0000_0000_0000, lo: 0 };
+/// Negative infinity (-∞).
+pub const NEG_INFINITY: f128 = f128 { hi: 0xffff_0000_0000_0000, lo: 0 };
+
+/// Maximum number of significant decimal digits printed by the formatting
+/// traits without a precision, which print the shortest digits that read
+/// back as the same `f128`.
+pub const MAX_SIG_DIGITS: usize = 36;
+
+impl f128 {
+    /// Creates an `f128` from the high and low halves of its IEEE 754
+    /// binary128 representation.
+    #[inline]
+    pub fn from_bits(hi: u64, lo: u64) -> f128 {
+        f128 { hi: hi, lo: lo }
+    }
+
+    /// Returns the high and low halves of the IEEE 754 binary128
+    /// representation.
+    #[inline]
+    pub fn to_bits(self) -> (u64, u64) {
+        (self.hi, self.lo)
+    }
+
+    /// Converts an `f32`, which is exact.
+    pub fn from_f32(x: f32) -> f128 {
+        f128::from_f64(x as f64)
+    }
+
+    /// Converts an `f64`, which is exact.
+    ///
+    /// NaNs keep their sign and payload.
+    pub fn from_f64(x: f64) -> f128 {
+        let bits: u64 = unsafe { mem::transmute(x) };
+        let sign = bits & SIGN;
+        if !x.is_finite() {
+            // the 52 stored bits become the top of the 112 stored bits
+            let man = bits & ((1 << 52) - 1);
+            f128::from_bits(sign | EXP_MASK | man >> 4, man << 60)
+        } else {
+            let (e, m) = x.to_exp_mantissa();
+            let (hi, lo) = quad::round(false, e, 0, m);
+            f128::from_bits(sign | hi, lo)
+        }
+    }
+
+    /// Converts to an `f32`, rounding to the nearest and ties to even.
+    ///
+    /// This rounds only once, so it may differ from converting to `f64`
+    /// first.
+    pub fn to_f32(self) -> f32 {
+        if self.is_nan() {
+            let bits = (self.hi >> 32) as u32 & 0x8000_0000 | 0x7fc0_0000 |
+                       (self.hi >> 25) as u32 & 0x003f_ffff;
+            unsafe { mem::transmute(bits) }
+        } else {
+            self.to_float()
+        }
+    }
+
+    /// Converts to an `f64`, rounding to the nearest and ties to even.
+    ///
+    /// Values beyond `f64::MAX` round to infinity, and NaNs keep their sign
+    /// and the upper bits of their payload.
+    pub fn to_f64(self) -> f64 {
+        if self.is_nan() {
+            let man = (self.hi & MAN_MASK) << 4 | self.lo >> 60;
+            let bits = self.hi & SIGN | 0x7ff8_0000_0000_0000 | man & 0x0007_ffff_ffff_ffff;
+            unsafe { mem::transmute(bits) }
+        } else {
+            self.to_float()
+        }
+    }
+
+    /// Converts a value that is not NaN to `f32` or `f64`.
+    fn to_float<T: Float + Neg<Output = T>>(self) -> T {
+        let x: T = if self.is_infinite() {
+            Float::infinity()
+        } else if quad::is_zero(self.to_bits()) {
+            Float::zero()
+        } else {
+            // Reduce the mantissa to 64 bits, keeping a sticky bit that is far below the bits
+            // of `T`, so that `from_exp_mantissa` rounds only once.
+            let (_, e, hi, lo) = quad::unpack(self.to_bits());
+            let lz = quad::leading_zeros(hi, lo);
+            let (hi, lo) = quad::shl(hi, lo, lz);
+            Float::from_exp_mantissa(e + 64 - lz as i32, hi | (lo != 0) as u64)
+        };
+        if self.is_sign_negative() { -x } else { x }
+    }
+
+    /// Returns `true` if this value is NaN.
+    #[inline]
+    pub fn is_nan(self) -> bool {
+        quad::is_nan(self.to_bits())
+    }
+
+    /// Returns `true` if this value is positive or negative infinity.
+    #[inline]
+    pub fn is_infinite(self) -> bool {
+        quad::is_infinite(self.to_bits())
+    }
+
+    /// Returns `true` if this number is neither infinite nor NaN.
+    #[inline]
+    pub fn is_finite(self) -> bool {
+        self.hi & EXP_MASK != EXP_MASK
+    }
+
+    /// Returns `true` if the number is neither zero, infinite, subnormal or NaN.
+    #[inline]
+    pub fn is_normal(self) -> bool {
+        self.classify() == Fp::Normal
+    }
+
+    /// Returns the floating point category of the number.
+    pub fn classify(self) -> Fp {
+        match (self.hi & MAN_MASK | self.lo, self.hi & EXP_MASK) {
+            (0, 0)        => Fp::Zero,
+            (_, 0)        => Fp::Subnormal,
+            (0, EXP_MASK) => Fp::Infinite,
+            (_, EXP_MASK) => Fp::Nan,
+            _             => Fp::Normal,
+        }
+    }
+
+    /// Computes the absolute value of `self`.
+    #[inline]
+    pub fn abs(self) -> f128 {
+        f128::from_bits(self.hi & !SIGN, self.lo)
+    }
+
+    /// Returns a number composed of the magnitude of `self` and the sign of
+    /// `sign`.
+    #[inline]
+    pub fn copysign(self, sign: f128) -> f128 {
+        f128::from_bits(self.hi & !SIGN | sign.hi & SIGN, self.lo)
+    }
+
+    /// Returns `true` if `self`'s sign bit is clear, including for `+0.0`,
+    /// positive infinity and NaNs with a positive sign.
+    #[inline]
+    pub fn is_sign_positive(self) -> bool {
+        self.hi & SIGN == 0
+    }
+
+    /// Returns `true` if `self`'s sign bit is set, including for `-0.0`,
+    /// negative infinity and NaNs with a negative sign.
+    #[inline]
+    pub fn is_sign_negative(self) -> bool {
+        self.hi & SIGN != 0
+    }
+}
+
+impl From<f32> for f128 {
+    #[inline]
+    fn from(x: f32) -> f128 {
+        f128::from_f32(x)
+    }
+}
+
+impl From<f64> for f128 {
+    #[inline]
+    fn from(x: f64) -> f128 {
+        f128::from_f64(x)
+    }
+}
+
+impl Default for f128 {
+    #[inline]
+    fn default() -> f128 {
+        f128::from_bits(0, 0)
+    }
+}
+
+impl PartialEq for f128 {
+    #[inline]
+    fn eq(&self, other: &f128) -> bool {
+        quad::partial_cmp(self.to_bits(), other.to_bits()) == Some(Ordering::Equal)
+    }
+}
+
+impl PartialOrd for f128 {
+    #[inline]
+    fn partial_cmp(&self, other: &f128) -> Option<Ordering> {
+        quad::partial_cmp(self.to_bits(), other.to_bits())
+    }
+}
+
+impl Neg for f128 {
+    type Output = f128;
+
+    #[inline]
+    fn neg(self) -> f128 {
+        f128::from_bits(self.hi ^ SIGN, self.lo)
+    }
+}
+
+macro_rules! f128_op_impl {
+    ($($trait_:ident $method:ident $assign_trait:ident $assign_method:ident $op:tt)*) => ($(
+        impl $trait_ for f128 {
+            type Output = f128;
+
+            #[inline]
+            fn $method(self, other: f128) -> f128 {
+                let (hi, lo) = quad::$method(self.to_bits(), other.to_bits());
+                f128::from_bits(hi, lo)
+            }
+        }
+
+        impl $assign_trait for f128 {
+            #[inline]
+            fn $assign_method(&mut self, other: f128) {
+                *self = *self $op other;
+            }
+        }
+    )*)
+}
+
+f128_op_impl! {
+    Add add AddAssign add_assign +
+    Sub sub SubAssign sub_assign -
+    Mul mul MulAssign mul_assign *
+    Div div DivAssign div_assign /
+}
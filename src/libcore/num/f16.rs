@@ -0,0 +1,590 @@
+// Copyright 2016 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Operations and constants for 16-bits floats (`f16` type)
+//!
+//! Unlike `f32` and `f64`, `f16` is not a primitive type: it is the IEEE 754
+//! binary16 format stored in a `u16`. Arithmetic is carried out in `f32` and
+//! rounded back, which is correctly rounded because `f32` has more than twice
+//! as many significand bits. Conversions from `f32` and `f64` round to the
+//! nearest, ties to even, and conversions to them are exact.
+//!
+//! # Examples
+//!
+//! ```
+//! #![feature(f16)]
+//!
+//! use std::num::f16;
+//!
+//! let x: f16 = "65504".parse().unwrap();
+//! assert_eq!(x, std::f16::MAX);
+//! // the shortest digits that read back as `MAX`
+//! assert_eq!(x.to_string(), "65500");
+//! assert_eq!(f16::from_f32(0.1).to_f32(), 0.0999755859375);
+//! ```
+
+#![unstable(feature = "f16", issue = "0")]
+
+use cmp::{PartialEq, PartialOrd, Ord, Ordering};
+use convert::From;
+use default::Default;
+use i32;
+use mem;
+use num::{Float, RoundingMode, TotalOrdF32};
+use num::dec2flt::{self, ParseFloatError, ParseFloatOptions};
+use num::flt2dec;
+use ops::{Add, Sub, Mul, Div, Rem, Neg};
+use ops::{AddAssign, SubAssign, MulAssign, DivAssign, RemAssign};
+use option::Option::{self, Some, None};
+use result::Result;
+use str::StrExt;
+use num::FpCategory as Fp;
+
+/// A 16-bit floating point number, in the IEEE 754 binary16 format.
+///
+/// See the [module documentation](../f16/index.html) for how arithmetic and
+/// conversions round.
+#[allow(non_camel_case_types)]
+#[derive(Copy, Clone)]
+pub struct f16(u16);
+
+/// The radix or base of the internal representation of `f16`.
+pub const RADIX: u32 = 2;
+
+/// Number of significant digits in base 2.
+pub const MANTISSA_DIGITS: u32 = 11;
+/// Approximate number of significant digits in base 10.
+pub const DIGITS: u32 = 3;
+
+/// Difference between `1.0` and the next largest representable number.
+pub const EPSILON: f16 = f16(0x1400);
+
+/// Smallest finite `f16` value.
+pub const MIN: f16 = f16(0xfbff);
+/// Smallest positive normal `f16` value.
+pub const MIN_POSITIVE: f16 = f16(0x0400);
+/// Largest finite `f16` value, `65504`.
+pub const MAX: f16 = f16(0x7bff);
+
+/// One greater than the minimum possible normal power of 2 exponent.
+pub const MIN_EXP: i32 = -13;
+/// Maximum possible power of 2 exponent.
+pub const MAX_EXP: i32 = 16;
+
+/// Minimum possible normal power of 10 exponent.
+pub const MIN_10_EXP: i32 = -4;
+/// Maximum possible power of 10 exponent.
+pub const MAX_10_EXP: i32 = 4;
+
+/// Not a Number (NaN).
+pub const NAN: f16 = f16(QUIET_NAN_BITS);
+/// Infinity (∞).
+pub const INFINITY: f16 = f16(0x7c00);
+/// Negative infinity (-∞).
+pub const NEG_INFINITY: f16 = f16(0xfc00);
+
+/// Maximum number of significant decimal digits printed by the formatting
+/// traits without a precision, which print the shortest digits that read
+/// back as the same `f16`.
+pub const MAX_SIG_DIGITS: usize = 5;
+/// Maximum length in bytes of an `f16` formatted with `Display` or `Debug`
+/// without a precision or width, including the sign.
+///
+/// The longest are the small numbers, like `-0.00000006` with 8 fractional
+/// digits, while `MAX` has 5 integral digits.
+pub const MAX_DISPLAY_LEN: usize = 1 + 2 + 8;
+/// Maximum length in bytes of an `f16` formatted with `LowerExp` or
+/// `UpperExp` without a precision or width, like `-1.0014e-4`.
+pub const MAX_EXP_DISPLAY_LEN: usize = 1 + MAX_SIG_DIGITS + 1 + 3;
+
+const SIGN_MASK: u16 = 0x8000;
+const EXP_MASK: u16 = 0x7c00;
+const MAN_MASK: u16 = 0x03ff;
+
+// The bits of the positive quiet NaN without a payload, and of the payload
+// below the quiet bit.
+const QUIET_NAN_BITS: u16 = 0x7e00;
+const PAYLOAD_MASK: u16 = 0x01ff;
+
+impl f16 {
+    /// Creates an `f16` from its IEEE 754 binary16 representation.
+    #[inline]
+    pub fn from_bits(bits: u16) -> f16 {
+        f16(bits)
+    }
+
+    /// Returns the IEEE 754 binary16 representation.
+    #[inline]
+    pub fn to_bits(self) -> u16 {
+        self.0
+    }
+
+    /// Converts an `f32`, rounding to the nearest and ties to even.
+    ///
+    /// Values beyond `MAX` round to infinity, and NaNs keep their sign and
+    /// the upper bits of their payload.
+    pub fn from_f32(x: f32) -> f16 {
+        let bits: u32 = unsafe { mem::transmute(x) };
+        let sign = (bits >> 16) as u16 & SIGN_MASK;
+        if x.is_nan() {
+            f16(sign | QUIET_NAN_BITS | (bits >> 13) as u16 & PAYLOAD_MASK)
+        } else if x.is_infinite() {
+            f16(sign | INFINITY.0)
+        } else {
+            let (e, m) = x.to_exp_mantissa();
+            f16(sign | <f16 as Float>::from_exp_mantissa(e, m).0)
+        }
+    }
+
+    /// Converts an `f64`, rounding to the nearest and ties to even.
+    ///
+    /// This rounds only once, so it may differ from converting to `f32`
+    /// first.
+    pub fn from_f64(x: f64) -> f16 {
+        let bits: u64 = unsafe { mem::transmute(x) };
+        let sign = (bits >> 48) as u16 & SIGN_MASK;
+        if x.is_nan() {
+            f16(sign | QUIET_NAN_BITS | (bits >> 42) as u16 & PAYLOAD_MASK)
+        } else if x.is_infinite() {
+            f16(sign | INFINITY.0)
+        } else {
+            let (e, m) = x.to_exp_mantissa();
+            f16(sign | <f16 as Float>::from_exp_mantissa(e, m).0)
+        }
+    }
+
+    /// Converts to an `f32`, which is exact.
+    pub fn to_f32(self) -> f32 {
+        let neg = self.0 & SIGN_MASK != 0;
+        if self.0 & EXP_MASK == EXP_MASK {
+            let bits = (neg as u32) << 31 | 0x7f800000 | ((self.0 & MAN_MASK) as u32) << 13;
+            return unsafe { mem::transmute(bits) };
+        }
+        let (e, m) = self.to_exp_mantissa();
+        let x: f32 = Float::from_exp_mantissa(e, m);
+        if neg { -x } else { x }
+    }
+
+    /// Converts to an `f64`, which is exact.
+    pub fn to_f64(self) -> f64 {
+        self.to_f32() as f64
+    }
+}
+
+impl From<f16> for f32 {
+    #[inline]
+    fn from(x: f16) -> f32 {
+        x.to_f32()
+    }
+}
+
+impl From<f16> for f64 {
+    #[inline]
+    fn from(x: f16) -> f64 {
+        x.to_f64()
+    }
+}
+
+impl Default for f16 {
+    #[inline]
+    fn default() -> f16 {
+        f16(0)
+    }
+}
+
+impl PartialEq for f16 {
+    #[inline]
+    fn eq(&self, other: &f16) -> bool {
+        self.to_f32() == other.to_f32()
+    }
+}
+
+impl PartialOrd for f16 {
+    #[inline]
+    fn partial_cmp(&self, other: &f16) -> Option<Ordering> {
+        self.to_f32().partial_cmp(&other.to_f32())
+    }
+}
+
+impl Neg for f16 {
+    type Output = f16;
+
+    #[inline]
+    fn neg(self) -> f16 {
+        f16(self.0 ^ SIGN_MASK)
+    }
+}
+
+macro_rules! f16_op_impl {
+    ($($trait_:ident $method:ident $assign_trait:ident $assign_method:ident $op:tt)*) => ($(
+        impl $trait_ for f16 {
+            type Output = f16;
+
+            #[inline]
+            fn $method(self, other: f16) -> f16 {
+                f16::from_f32(self.to_f32() $op other.to_f32())
+            }
+        }
+
+        impl $assign_trait for f16 {
+            #[inline]
+            fn $assign_method(&mut self, other: f16) {
+                *self = *self $op other;
+            }
+        }
+    )*)
+}
+
+f16_op_impl! {
+    Add add AddAssign add_assign +
+    Sub sub SubAssign sub_assign -
+    Mul mul MulAssign mul_assign *
+    Div div DivAssign div_assign /
+    Rem rem RemAssign rem_assign %
+}
+
+#[unstable(feature = "core_float",
+           reason = "stable interface is via `impl f{32,64}` in later crates",
+           issue = "32110")]
+impl Float for f16 {
+    #[inline]
+    fn nan() -> f16 { NAN }
+
+    #[inline]
+    fn infinity() -> f16 { INFINITY }
+
+    #[inline]
+    fn neg_infinity() -> f16 { NEG_INFINITY }
+
+    #[inline]
+    fn zero() -> f16 { f16(0) }
+
+    #[inline]
+    fn neg_zero() -> f16 { f16(SIGN_MASK) }
+
+    #[inline]
+    fn one() -> f16 { f16(0x3c00) }
+
+    /// Returns `true` if the number is NaN.
+    #[inline]
+    fn is_nan(self) -> bool { self.0 & !SIGN_MASK > EXP_MASK }
+
+    /// Returns `true` if the number is infinite.
+    #[inline]
+    fn is_infinite(self) -> bool { self.0 & !SIGN_MASK == EXP_MASK }
+
+    /// Returns `true` if the number is neither infinite or NaN.
+    #[inline]
+    fn is_finite(self) -> bool { self.0 & EXP_MASK != EXP_MASK }
+
+    /// Returns `true` if the number is neither zero, infinite, subnormal or NaN.
+    #[inline]
+    fn is_normal(self) -> bool {
+        self.classify() == Fp::Normal
+    }
+
+    /// Returns the floating point category of the number.
+    fn classify(self) -> Fp {
+        match (self.0 & MAN_MASK, self.0 & EXP_MASK) {
+            (0, 0)        => Fp::Zero,
+            (_, 0)        => Fp::Subnormal,
+            (0, EXP_MASK) => Fp::Infinite,
+            (_, EXP_MASK) => Fp::Nan,
+            _             => Fp::Normal,
+        }
+    }
+
+    /// Returns the mantissa, exponent and sign as integers.
+    fn integer_decode(self) -> (u64, i16, i8) {
+        let sign: i8 = if self.0 & SIGN_MASK == 0 { 1 } else { -1 };
+        let mut exponent: i16 = ((self.0 >> 10) & 0x1f) as i16;
+        let mantissa = if exponent == 0 {
+            (self.0 & MAN_MASK) << 1
+        } else {
+            (self.0 & MAN_MASK) | 0x400
+        };
+        // Exponent bias + mantissa shift
+        exponent -= 15 + 10;
+        (mantissa as u64, exponent, sign)
+    }
+
+    /// Computes the absolute value of `self`.
+    #[inline]
+    fn abs(self) -> f16 { f16(self.0 & !SIGN_MASK) }
+
+    /// Returns a number that represents the sign of `self`.
+    #[inline]
+    fn signum(self) -> f16 {
+        if self.is_nan() { NAN } else { f16(0x3c00).copysign(self) }
+    }
+
+    /// Returns `true` if `self` is positive, including `+0.0` and
+    /// `Float::infinity()`.
+    #[inline]
+    fn is_sign_positive(self) -> bool { self.to_f32().is_sign_positive() }
+
+    /// Returns `true` if `self` is negative, including `-0.0` and
+    /// `Float::neg_infinity()`.
+    #[inline]
+    fn is_sign_negative(self) -> bool { self.to_f32().is_sign_negative() }
+
+    /// Returns the reciprocal (multiplicative inverse) of the number.
+    #[inline]
+    fn recip(self) -> f16 { f16::from_f32(self.to_f32().recip()) }
+
+    #[inline]
+    fn powi(self, n: i32) -> f16 { f16::from_f32(self.to_f32().powi(n)) }
+
+    /// Converts to degrees, assuming the number is in radians.
+    #[inline]
+    fn to_degrees(self) -> f16 { f16::from_f32(self.to_f32().to_degrees()) }
+
+    /// Converts to radians, assuming the number is in degrees.
+    #[inline]
+    fn to_radians(self) -> f16 { f16::from_f32(self.to_f32().to_radians()) }
+
+    #[inline]
+    fn from_str_exact(src: &str) -> Result<f16, ParseFloatError> {
+        dec2flt::dec2flt_exact(src.as_bytes())
+    }
+
+    #[inline]
+    fn from_str_rounded(src: &str, mode: RoundingMode) -> Result<f16, ParseFloatError> {
+        dec2flt::dec2flt_rounded(src.as_bytes(), mode)
+    }
+
+    #[inline]
+    fn from_str_radix(src: &str, radix: u32) -> Result<f16, ParseFloatError> {
+        dec2flt::dec2flt_radix(src.as_bytes(), radix)
+    }
+
+    #[inline]
+    fn from_str_partial(src: &str) -> Result<(f16, usize), ParseFloatError> {
+        dec2flt::dec2flt_partial(src.as_bytes())
+    }
+
+    #[inline]
+    fn from_ascii(src: &[u8]) -> Result<f16, ParseFloatError> {
+        dec2flt::dec2flt(src)
+    }
+
+    #[inline]
+    fn from_str_with(src: &str, options: &ParseFloatOptions) -> Result<f16, ParseFloatError> {
+        dec2flt::dec2flt_with(src, options)
+    }
+
+    // The integral and fractional parts of an `f16` are `f16`s again, so
+    // computing them in `f32` is exact.
+
+    /// Returns the integer part of a number.
+    #[inline]
+    fn trunc(self) -> f16 { f16::from_f32(self.to_f32().trunc()) }
+
+    /// Returns the largest integer less than or equal to a number.
+    #[inline]
+    fn floor(self) -> f16 { f16::from_f32(self.to_f32().floor()) }
+
+    /// Returns the smallest integer greater than or equal to a number.
+    #[inline]
+    fn ceil(self) -> f16 { f16::from_f32(self.to_f32().ceil()) }
+
+    /// Returns the nearest integer to a number, rounding half-way cases away
+    /// from `0.0`.
+    #[inline]
+    fn round(self) -> f16 { f16::from_f32(self.to_f32().round()) }
+
+    /// Returns the nearest integer to a number, rounding half-way cases to
+    /// the even integer.
+    #[inline]
+    fn round_ties_even(self) -> f16 { f16::from_f32(self.to_f32().round_ties_even()) }
+
+    /// Returns the fractional part of a number.
+    #[inline]
+    fn fract(self) -> f16 { f16::from_f32(self.to_f32().fract()) }
+
+    /// Returns a number with the magnitude of `self` and the sign of `sign`.
+    #[inline]
+    fn copysign(self, sign: f16) -> f16 {
+        f16(self.0 & !SIGN_MASK | sign.0 & SIGN_MASK)
+    }
+
+    #[inline]
+    fn to_bits(self) -> u64 {
+        self.0 as u64
+    }
+
+    #[inline]
+    fn from_bits(bits: u64) -> f16 {
+        assert!(bits <= 0xffff, "f16::from_bits: too many bits");
+        f16(bits as u16)
+    }
+
+    /// Returns a positive quiet NaN with the given payload.
+    fn nan_with_payload(payload: u64) -> f16 {
+        assert!(payload <= PAYLOAD_MASK as u64,
+                "NaN payload {:#x} does not fit in 9 bits", payload);
+        f16(QUIET_NAN_BITS | payload as u16)
+    }
+
+    /// Returns the significand of a NaN without the quiet bit.
+    fn payload(self) -> Option<u64> {
+        if self.is_nan() {
+            Some((self.0 & PAYLOAD_MASK) as u64)
+        } else {
+            None
+        }
+    }
+
+    /// Writes the shortest `Display` form into `buf`.
+    fn write_shortest(self, buf: &mut [u8]) -> Option<&str> {
+        flt2dec::write_shortest_str(self, buf)
+    }
+
+    /// Writes the `Display` form with `precision` fractional digits into `buf`.
+    fn write_fixed(self, buf: &mut [u8], precision: usize) -> Option<&str> {
+        flt2dec::write_fixed_str(self, precision, buf)
+    }
+
+    /// Restricts a value to the interval `[min, max]`.
+    #[inline]
+    fn clamp(self, min: f16, max: f16) -> f16 {
+        assert!(min <= max, "min > max, or either was NaN. min = {:?}, max = {:?}", min, max);
+        if self < min {
+            min
+        } else if self > max {
+            max
+        } else {
+            self
+        }
+    }
+
+    /// Restricts a value to the interval `[min, max]` in the total order.
+    #[inline]
+    fn clamp_total(self, min: f16, max: f16) -> f16 {
+        // the conversion to `f32` preserves the total order
+        let (x, lo, hi) = (TotalOrdF32(self.to_f32()), TotalOrdF32(min.to_f32()),
+                           TotalOrdF32(max.to_f32()));
+        assert!(lo <= hi, "min > max in the total order. min = {:?}, max = {:?}", min, max);
+        if x < lo {
+            min
+        } else if x > hi {
+            max
+        } else {
+            self
+        }
+    }
+
+    /// Compares in the `totalOrder` of IEEE 754.
+    #[inline]
+    fn total_cmp(&self, other: &f16) -> Ordering {
+        // Flipping all but the sign bit of negative numbers reverses their
+        // order, so that the encodings then compare as signed integers.
+        let mut left = self.0 as i16;
+        let mut right = other.0 as i16;
+        left ^= (((left >> 15) as u16) >> 1) as i16;
+        right ^= (((right >> 15) as u16) >> 1) as i16;
+        left.cmp(&right)
+    }
+
+    /// Returns `(exp, mantissa)` such that `abs(self) = mantissa * 2^exp`.
+    ///
+    /// Normal numbers have an 11-bit mantissa, while subnormals and zeroes have
+    /// the smallest exponent. Infinities and NaNs decode like normal numbers with
+    /// the exponent field saturated, as `integer_decode` does.
+    fn to_exp_mantissa(self) -> (i32, u64) {
+        let biased = ((self.0 >> 10) & 0x1f) as i32;
+        let frac = (self.0 & MAN_MASK) as u64;
+        if biased == 0 {
+            (-24, frac)
+        } else {
+            (biased - 15 - 10, frac | (1 << 10))
+        }
+    }
+
+    /// Returns `mantissa * 2^exp`, rounded to the nearest and ties to even.
+    ///
+    /// The result overflows to infinity and underflows to subnormals or zero.
+    fn from_exp_mantissa(exp: i32, mantissa: u64) -> f16 {
+        if mantissa == 0 {
+            return f16(0);
+        }
+        // normalize to `m * 2^e` with a 64-bit `m`, and thus `2^(e + 63) <= value`.
+        let lz = mantissa.leading_zeros();
+        let m = mantissa << lz;
+        let e = exp as i64 - lz as i64 + 63;
+        if e > 15 {
+            return INFINITY;
+        }
+        // subnormals have the exponent of the smallest normal, with fewer bits
+        // in the significand.
+        let shift = 53 + if e < -14 { (-14 - e) as u32 } else { 0 };
+        let biased = if e < -14 { 0 } else { (e + 14) as u64 };
+        if shift > 64 {
+            // less than half the smallest subnormal
+            return f16(0);
+        }
+        let (q, rem, half) = if shift == 64 {
+            (0, m, 1 << 63)
+        } else {
+            (m >> shift, m & ((1 << shift) - 1), 1 << (shift - 1))
+        };
+        let q = if rem > half || (rem == half && q & 1 == 1) { q + 1 } else { q };
+        // `q` includes the hidden bit for normal numbers, which carries into the
+        // exponent field, as does rounding up to the next binade or to infinity.
+        f16(((biased << 10) + q) as u16)
+    }
+
+    /// Returns `self * 2^exp`, rounded to the nearest and ties to even.
+    fn ldexp(self, exp: i32) -> f16 {
+        if self.0 & !SIGN_MASK == 0 || !self.is_finite() {
+            return self;
+        }
+        let (e, m) = self.to_exp_mantissa();
+        let x: f16 = Float::from_exp_mantissa(e.saturating_add(exp), m);
+        x.copysign(self)
+    }
+
+    /// Returns `floor(log2(abs(self)))`.
+    ///
+    /// Zero and NaN give `i32::MIN`, while infinities give `i32::MAX`.
+    fn ilogb(self) -> i32 {
+        if self.0 & !SIGN_MASK == 0 || self.is_nan() {
+            i32::MIN
+        } else if self.is_infinite() {
+            i32::MAX
+        } else {
+            let (e, m) = self.to_exp_mantissa();
+            e + 63 - m.leading_zeros() as i32
+        }
+    }
+
+    /// Returns `self * a + b`, rounded only once.
+    fn mul_add(self, a: f16, b: f16) -> f16 {
+        use num::fma;
+
+        // As for `f32`, the special cases don't depend on the product being
+        // rounded, and the others are rounded once by `from_exp_mantissa`.
+        let zero = |x: f16| x.0 & !SIGN_MASK == 0;
+        if !self.is_finite() || !a.is_finite() || b.is_nan() || zero(self) || zero(a) {
+            return self * a + b;
+        } else if b.is_infinite() {
+            return b;
+        } else if zero(b) {
+            return self * a;
+        }
+        let decode = |x: f16| {
+            let (e, m) = x.to_exp_mantissa();
+            (x.0 & SIGN_MASK != 0, e, m)
+        };
+        let (neg, e, m) = fma::mul_add(decode(self), decode(a), decode(b));
+        let x: f16 = Float::from_exp_mantissa(e, m);
+        if neg { -x } else { x }
+    }
+}
@@ -15,9 +15,17 @@
 
 #![stable(feature = "rust1", since = "1.0.0")]
 
+use cmp::{Ord, Ordering};
+use i32;
+use u32;
 use intrinsics;
 use mem;
-use num::Float;
+use num::{Float, RoundingMode, TotalOrdF32};
+use num::dec2flt::{self, ParseFloatError, ParseFloatOptions};
+use num::flt2dec;
+use option::Option::{self, Some, None};
+use result::Result;
+use str::StrExt;
 use num::FpCategory as Fp;
 
 /// The radix or base of the internal representation of `f32`.
@@ -69,6 +77,23 @@ pub const INFINITY: f32 = 1.0_f32/0.0_f32;
 #[stable(feature = "rust1", since = "1.0.0")]
 pub const NEG_INFINITY: f32 = -1.0_f32/0.0_f32;
 
+/// Maximum number of significant decimal digits printed by the formatting
+/// traits without a precision, which print the shortest digits that read
+/// back as the same `f32`.
+#[unstable(feature = "float_display_len", issue = "0")]
+pub const MAX_SIG_DIGITS: usize = 9;
+/// Maximum length in bytes of an `f32` formatted with `Display` or `Debug`
+/// without a precision or width, including the sign.
+///
+/// The longest are the subnormals, like `-0.000…0001` with 45 fractional
+/// digits, while `MAX` has 39 integral digits.
+#[unstable(feature = "float_display_len", issue = "0")]
+pub const MAX_DISPLAY_LEN: usize = 1 + 2 + 45;
+/// Maximum length in bytes of an `f32` formatted with `LowerExp` or
+/// `UpperExp` without a precision or width, like `-1.13885106e-35`.
+#[unstable(feature = "float_display_len", issue = "0")]
+pub const MAX_EXP_DISPLAY_LEN: usize = 1 + MAX_SIG_DIGITS + 1 + 4;
+
 /// Basic mathematical constants.
 #[stable(feature = "rust1", since = "1.0.0")]
 pub mod consts {
@@ -139,6 +164,11 @@ pub mod consts {
     pub const LN_10: f32 = 2.30258509299404568401799145468436421_f32;
 }
 
+// The bits of the positive quiet NaN without a payload, and of the payload
+// below the quiet bit.
+const QUIET_NAN_BITS: u32 = 0x7fc00000;
+const PAYLOAD_MASK: u32 = 0x003fffff;
+
 #[unstable(feature = "core_float",
            reason = "stable interface is via `impl f{32,64}` in later crates",
            issue = "32110")]
@@ -269,4 +299,303 @@ impl Float for f32 {
         let value: f32 = consts::PI;
         self * (value / 180.0f32)
     }
+
+    #[inline]
+    fn from_str_exact(src: &str) -> Result<f32, ParseFloatError> {
+        dec2flt::dec2flt_exact(src.as_bytes())
+    }
+
+    #[inline]
+    fn from_str_rounded(src: &str, mode: RoundingMode) -> Result<f32, ParseFloatError> {
+        dec2flt::dec2flt_rounded(src.as_bytes(), mode)
+    }
+
+    #[inline]
+    fn from_str_radix(src: &str, radix: u32) -> Result<f32, ParseFloatError> {
+        dec2flt::dec2flt_radix(src.as_bytes(), radix)
+    }
+
+    #[inline]
+    fn from_str_partial(src: &str) -> Result<(f32, usize), ParseFloatError> {
+        dec2flt::dec2flt_partial(src.as_bytes())
+    }
+
+    #[inline]
+    fn from_ascii(src: &[u8]) -> Result<f32, ParseFloatError> {
+        dec2flt::dec2flt(src)
+    }
+
+    #[inline]
+    fn from_str_with(src: &str, options: &ParseFloatOptions) -> Result<f32, ParseFloatError> {
+        dec2flt::dec2flt_with(src, options)
+    }
+
+    /// Returns the integer part of a number.
+    ///
+    /// This clears the fraction bits below the binary point, so it doesn't
+    /// need `libm`.
+    fn trunc(self) -> f32 {
+        const SIGN_MASK: u32 = 0x80000000;
+        const MAN_MASK: u32 = 0x007fffff;
+
+        let bits: u32 = unsafe { mem::transmute(self) };
+        let exp = ((bits >> 23) & 0xff) as i32 - 127;
+        if exp >= 23 {
+            // already integral, infinite or NaN
+            self
+        } else if exp < 0 {
+            unsafe { mem::transmute(bits & SIGN_MASK) }
+        } else {
+            unsafe { mem::transmute(bits & !(MAN_MASK >> exp)) }
+        }
+    }
+
+    /// Returns the largest integer less than or equal to a number.
+    #[inline]
+    fn floor(self) -> f32 {
+        let t = self.trunc();
+        if self < t { t - 1.0 } else { t }
+    }
+
+    /// Returns the smallest integer greater than or equal to a number.
+    #[inline]
+    fn ceil(self) -> f32 {
+        let t = self.trunc();
+        if self > t { t + 1.0 } else { t }
+    }
+
+    /// Returns the nearest integer to a number, rounding half-way cases away
+    /// from `0.0`.
+    fn round(self) -> f32 {
+        // the fractional part is exact, so unlike `(self + 0.5).floor()` this
+        // doesn't round up the largest number below one half.
+        let t = self.trunc();
+        let frac = self - t;
+        if frac >= 0.5 {
+            t + 1.0
+        } else if frac <= -0.5 {
+            t - 1.0
+        } else {
+            t
+        }
+    }
+
+    /// Returns the nearest integer to a number, rounding half-way cases to
+    /// the even integer.
+    fn round_ties_even(self) -> f32 {
+        let t = self.trunc();
+        let frac = self - t;
+        // `t` is odd when halving it leaves a fraction; both are exact.
+        let odd = (t / 2.0).trunc() * 2.0 != t;
+        if frac > 0.5 || (frac == 0.5 && odd) {
+            t + 1.0
+        } else if frac < -0.5 || (frac == -0.5 && odd) {
+            t - 1.0
+        } else {
+            t
+        }
+    }
+
+    /// Returns the fractional part of a number.
+    #[inline]
+    fn fract(self) -> f32 { self - self.trunc() }
+
+    /// Returns a number with the magnitude of `self` and the sign of `sign`.
+    #[inline]
+    fn copysign(self, sign: f32) -> f32 {
+        unsafe { intrinsics::copysignf32(self, sign) }
+    }
+
+    /// Returns the IEEE 754 binary32 encoding of `self`.
+    #[inline]
+    fn to_bits(self) -> u64 {
+        let bits: u32 = unsafe { mem::transmute(self) };
+        bits as u64
+    }
+
+    /// Returns the float with the binary32 encoding `bits`.
+    #[inline]
+    fn from_bits(bits: u64) -> f32 {
+        assert!(bits <= u32::MAX as u64, "f32::from_bits: too many bits");
+        unsafe { mem::transmute(bits as u32) }
+    }
+
+    /// Returns a positive quiet NaN with the given payload.
+    fn nan_with_payload(payload: u64) -> f32 {
+        assert!(payload <= PAYLOAD_MASK as u64,
+                "NaN payload {:#x} does not fit in 22 bits", payload);
+        unsafe { mem::transmute(QUIET_NAN_BITS | payload as u32) }
+    }
+
+    /// Returns the significand of a NaN without the quiet bit.
+    fn payload(self) -> Option<u64> {
+        if self.is_nan() {
+            let bits: u32 = unsafe { mem::transmute(self) };
+            Some((bits & PAYLOAD_MASK) as u64)
+        } else {
+            None
+        }
+    }
+
+    /// Writes the shortest `Display` form into `buf`.
+    fn write_shortest(self, buf: &mut [u8]) -> Option<&str> {
+        flt2dec::write_shortest_str(self, buf)
+    }
+
+    /// Writes the `Display` form with `precision` fractional digits into `buf`.
+    fn write_fixed(self, buf: &mut [u8], precision: usize) -> Option<&str> {
+        flt2dec::write_fixed_str(self, precision, buf)
+    }
+
+    /// Restricts a value to the interval `[min, max]`.
+    #[inline]
+    fn clamp(self, min: f32, max: f32) -> f32 {
+        assert!(min <= max, "min > max, or either was NaN. min = {:?}, max = {:?}", min, max);
+        if self < min {
+            min
+        } else if self > max {
+            max
+        } else {
+            self
+        }
+    }
+
+    /// Restricts a value to the interval `[min, max]` in the total order.
+    #[inline]
+    fn clamp_total(self, min: f32, max: f32) -> f32 {
+        let (x, lo, hi) = (TotalOrdF32(self), TotalOrdF32(min), TotalOrdF32(max));
+        assert!(lo <= hi, "min > max in the total order. min = {:?}, max = {:?}", min, max);
+        if x < lo {
+            min
+        } else if x > hi {
+            max
+        } else {
+            self
+        }
+    }
+
+    /// Compares in the `totalOrder` of IEEE 754.
+    #[inline]
+    fn total_cmp(&self, other: &f32) -> Ordering {
+        // Flipping all but the sign bit of negative numbers reverses their
+        // order, so that the encodings then compare as signed integers.
+        let mut left = Float::to_bits(*self) as u32 as i32;
+        let mut right = Float::to_bits(*other) as u32 as i32;
+        left ^= (((left >> 31) as u32) >> 1) as i32;
+        right ^= (((right >> 31) as u32) >> 1) as i32;
+        left.cmp(&right)
+    }
+
+    /// Returns `(exp, mantissa)` such that `abs(self) = mantissa * 2^exp`.
+    ///
+    /// Normal numbers have a 24-bit mantissa, while subnormals and zeroes have
+    /// the smallest exponent. Infinities and NaNs decode like normal numbers with
+    /// the exponent field saturated, as `integer_decode` does.
+    fn to_exp_mantissa(self) -> (i32, u64) {
+        const MAN_MASK: u32 = 0x007fffff;
+
+        let bits: u32 = unsafe { mem::transmute(self) };
+        let biased = ((bits >> 23) & 0xff) as i32;
+        let frac = (bits & MAN_MASK) as u64;
+        if biased == 0 {
+            (-149, frac)
+        } else {
+            (biased - 127 - 23, frac | (1 << 23))
+        }
+    }
+
+    /// Returns `mantissa * 2^exp`, rounded to the nearest and ties to even.
+    ///
+    /// The result overflows to infinity and underflows to subnormals or zero.
+    fn from_exp_mantissa(exp: i32, mantissa: u64) -> f32 {
+        if mantissa == 0 {
+            return 0.0;
+        }
+        // normalize to `m * 2^e` with a 64-bit `m`, and thus `2^(e + 63) <= value`.
+        let lz = mantissa.leading_zeros();
+        let m = mantissa << lz;
+        let e = exp as i64 - lz as i64 + 63;
+        if e > 127 {
+            return INFINITY;
+        }
+        // subnormals have the exponent of the smallest normal, with fewer bits
+        // in the significand.
+        let shift = 40 + if e < -126 { (-126 - e) as u32 } else { 0 };
+        let biased = if e < -126 { 0 } else { (e + 126) as u64 };
+        if shift > 64 {
+            // less than half the smallest subnormal
+            return 0.0;
+        }
+        let (q, rem, half) = if shift == 64 {
+            (0, m, 1 << 63)
+        } else {
+            (m >> shift, m & ((1 << shift) - 1), 1 << (shift - 1))
+        };
+        let q = if rem > half || (rem == half && q & 1 == 1) { q + 1 } else { q };
+        // `q` includes the hidden bit for normal numbers, which carries into the
+        // exponent field, as does rounding up to the next binade or to infinity.
+        let bits = ((biased << 23) + q) as u32;
+        unsafe { mem::transmute(bits) }
+    }
+
+    /// Returns `self * 2^exp`, rounded to the nearest and ties to even.
+    fn ldexp(self, exp: i32) -> f32 {
+        if self == 0.0 || !self.is_finite() {
+            return self;
+        }
+        let (e, m) = self.to_exp_mantissa();
+        let x: f32 = Float::from_exp_mantissa(e.saturating_add(exp), m);
+        if self < 0.0 { -x } else { x }
+    }
+
+    /// Returns `floor(log2(abs(self)))`.
+    ///
+    /// Zero and NaN give `i32::MIN`, while infinities give `i32::MAX`.
+    fn ilogb(self) -> i32 {
+        if self == 0.0 || self.is_nan() {
+            i32::MIN
+        } else if self.is_infinite() {
+            i32::MAX
+        } else {
+            let (e, m) = self.to_exp_mantissa();
+            e + 63 - m.leading_zeros() as i32
+        }
+    }
+
+    /// Returns `self * a + b`, rounded only once.
+    ///
+    /// Targets with fused multiply-add instructions use them, while the others
+    /// fall back to a software implementation.
+    #[cfg(any(target_arch = "aarch64", target_feature = "fma",
+              all(target_arch = "arm", target_feature = "vfp4")))]
+    #[inline]
+    fn mul_add(self, a: f32, b: f32) -> f32 {
+        unsafe { intrinsics::fmaf32(self, a, b) }
+    }
+
+    /// Returns `self * a + b`, rounded only once.
+    #[cfg(not(any(target_arch = "aarch64", target_feature = "fma",
+                  all(target_arch = "arm", target_feature = "vfp4"))))]
+    fn mul_add(self, a: f32, b: f32) -> f32 {
+        use num::fma;
+
+        // Unless all the operands are finite and non-zero, the product is exact
+        // or the sum does not depend on it being rounded.
+        if !self.is_finite() || !a.is_finite() || b.is_nan() {
+            return self * a + b;
+        } else if b.is_infinite() {
+            return b;
+        } else if self == 0.0 || a == 0.0 {
+            return self * a + b;
+        } else if b == 0.0 {
+            return self * a;
+        }
+        let decode = |x: f32| {
+            let (e, m) = x.to_exp_mantissa();
+            (x < 0.0, e, m)
+        };
+        let (neg, e, m) = fma::mul_add(decode(self), decode(a), decode(b));
+        let x: f32 = Float::from_exp_mantissa(e, m);
+        if neg { -x } else { x }
+    }
 }
@@ -0,0 +1,292 @@
+// Copyright 2016 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Querying the floating-point environment of the current thread.
+//!
+//! This reads the IEEE 754 exception flags, which the hardware sets as a side
+//! effect of float arithmetic and which stay set until cleared, and the
+//! flush-to-zero and denormals-are-zero modes, which make the hardware treat
+//! subnormal results and inputs as zero.
+//!
+//! The environment is read from the hardware on x86 and x86_64 (the x87
+//! status word and, with SSE, the MXCSR register) and on AArch64 (the FPSR
+//! and FPCR registers). Everywhere else no flags are ever reported, the modes
+//! are reported as off, and `is_supported` returns `false`.
+//!
+//! The compiler doesn't know that float arithmetic touches this state, and
+//! may evaluate constant expressions at compile time or move arithmetic
+//! across these calls. Computations whose flags matter should go through
+//! `hint::black_box` and an opaque function boundary.
+//!
+//! # Examples
+//!
+//! ```
+//! #![feature(float_env, hint)]
+//!
+//! use std::hint::black_box;
+//! use std::num::fenv::{self, INEXACT};
+//!
+//! fenv::clear_exceptions();
+//! let third = black_box(1.0f64) / black_box(3.0);
+//! black_box(third);
+//! if fenv::is_supported() {
+//!     assert!(fenv::test_exceptions().contains(INEXACT));
+//! }
+//! ```
+
+#![unstable(feature = "float_env",
+            reason = "recently added, the set of supported targets may grow",
+            issue = "0")]
+
+use ops::{BitAnd, BitOr, Sub};
+
+/// A set of IEEE 754 floating-point exception flags.
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Default, Debug)]
+pub struct FpExceptions(u8);
+
+/// An operation had no meaningful result, like `0.0 / 0.0` or `(-1.0).sqrt()`.
+pub const INVALID: FpExceptions = FpExceptions(0x01);
+/// An operation had a subnormal operand. Only reported on x86 and x86_64, and
+/// on AArch64 when the input was flushed to zero.
+pub const DENORMAL: FpExceptions = FpExceptions(0x02);
+/// A finite non-zero number was divided by zero.
+pub const DIVIDE_BY_ZERO: FpExceptions = FpExceptions(0x04);
+/// A result was too large in magnitude to be represented, and was rounded to
+/// infinity or to the largest finite number.
+pub const OVERFLOW: FpExceptions = FpExceptions(0x08);
+/// A result was tiny (below the smallest normal number) and inexact.
+pub const UNDERFLOW: FpExceptions = FpExceptions(0x10);
+/// A result had to be rounded.
+pub const INEXACT: FpExceptions = FpExceptions(0x20);
+
+impl FpExceptions {
+    /// Returns the empty set of flags.
+    #[inline]
+    pub fn empty() -> FpExceptions {
+        FpExceptions(0)
+    }
+
+    /// Returns the set of all flags.
+    #[inline]
+    pub fn all() -> FpExceptions {
+        FpExceptions(0x3f)
+    }
+
+    /// Returns `true` if no flags are set.
+    #[inline]
+    pub fn is_empty(self) -> bool {
+        self.0 == 0
+    }
+
+    /// Returns `true` if all the flags of `other` are set in `self`.
+    #[inline]
+    pub fn contains(self, other: FpExceptions) -> bool {
+        self.0 & other.0 == other.0
+    }
+}
+
+impl BitOr for FpExceptions {
+    type Output = FpExceptions;
+
+    #[inline]
+    fn bitor(self, other: FpExceptions) -> FpExceptions {
+        FpExceptions(self.0 | other.0)
+    }
+}
+
+impl BitAnd for FpExceptions {
+    type Output = FpExceptions;
+
+    #[inline]
+    fn bitand(self, other: FpExceptions) -> FpExceptions {
+        FpExceptions(self.0 & other.0)
+    }
+}
+
+impl Sub for FpExceptions {
+    type Output = FpExceptions;
+
+    #[inline]
+    fn sub(self, other: FpExceptions) -> FpExceptions {
+        FpExceptions(self.0 & !other.0)
+    }
+}
+
+/// Returns whether the floating-point environment can be read on this target.
+///
+/// When this is `false` the other functions in this module are no-ops.
+#[inline]
+pub fn is_supported() -> bool {
+    imp::SUPPORTED
+}
+
+/// Returns the exception flags raised on the current thread since they were
+/// last cleared.
+#[inline]
+pub fn test_exceptions() -> FpExceptions {
+    FpExceptions(imp::test_exceptions())
+}
+
+/// Clears all the exception flags of the current thread.
+#[inline]
+pub fn clear_exceptions() {
+    imp::clear_exceptions()
+}
+
+/// Returns whether the current thread is in flush-to-zero mode, where
+/// results that would be subnormal are replaced with zero.
+#[inline]
+pub fn flush_to_zero() -> bool {
+    imp::flush_to_zero()
+}
+
+/// Returns whether the current thread is in denormals-are-zero mode, where
+/// subnormal operands are treated as zero.
+///
+/// On AArch64 a single mode controls both this and `flush_to_zero`.
+#[inline]
+pub fn denormals_are_zero() -> bool {
+    imp::denormals_are_zero()
+}
+
+/// Returns whether `f32` and `f64` arithmetic is evaluated at extended
+/// precision, so that results may be rounded twice.
+///
+/// This is the case on x86 without SSE2, where the x87 FPU is used. The
+/// float parsing code changes the precision of the x87 FPU around its fast
+/// path to stay correctly rounded.
+#[inline]
+pub fn extended_precision() -> bool {
+    cfg!(all(target_arch = "x86", not(target_feature = "sse2")))
+}
+
+// The exception flags of the x87 status word and of MXCSR are in bits 0 to 5,
+// in the same order as `FpExceptions`.
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+mod imp {
+    use cell::UnsafeCell;
+
+    pub const SUPPORTED: bool = true;
+
+    const FLAGS: u32 = 0x3f;
+    const MXCSR_DAZ: u32 = 1 << 6;
+    const MXCSR_FTZ: u32 = 1 << 15;
+
+    // The stores below write through the pointer they are given, so it has to
+    // come from an `UnsafeCell` rather than from a shared reference.
+    #[cfg(target_feature = "sse")]
+    fn mxcsr() -> u32 {
+        let csr = UnsafeCell::new(0u32);
+        unsafe { asm!("stmxcsr $0" : "=*m" (csr.get()) ::: "volatile") }
+        csr.into_inner()
+    }
+
+    #[cfg(target_feature = "sse")]
+    fn set_mxcsr(csr: u32) {
+        unsafe { asm!("ldmxcsr $0" :: "m" (csr) :: "volatile") }
+    }
+
+    // Reading MXCSR without SSE is an illegal instruction.
+    #[cfg(not(target_feature = "sse"))]
+    fn mxcsr() -> u32 { 0 }
+
+    #[cfg(not(target_feature = "sse"))]
+    fn set_mxcsr(_csr: u32) { }
+
+    fn x87_status_word() -> u16 {
+        let sw = UnsafeCell::new(0u16);
+        unsafe { asm!("fnstsw $0" : "=*m" (sw.get()) ::: "volatile") }
+        sw.into_inner()
+    }
+
+    pub fn test_exceptions() -> u8 {
+        ((x87_status_word() as u32 | mxcsr()) & FLAGS) as u8
+    }
+
+    pub fn clear_exceptions() {
+        unsafe { asm!("fnclex" :::: "volatile") }
+        set_mxcsr(mxcsr() & !FLAGS)
+    }
+
+    pub fn flush_to_zero() -> bool {
+        mxcsr() & MXCSR_FTZ != 0
+    }
+
+    pub fn denormals_are_zero() -> bool {
+        mxcsr() & MXCSR_DAZ != 0
+    }
+}
+
+#[cfg(target_arch = "aarch64")]
+mod imp {
+    pub const SUPPORTED: bool = true;
+
+    const FPCR_FZ: u64 = 1 << 24;
+
+    // The exception flags of FPSR, and the `FpExceptions` flag of each.
+    const FPSR_FLAGS: [(u64, u8); 6] = [
+        (1 << 0, 0x01), // IOC, invalid operation
+        (1 << 7, 0x02), // IDC, input denormal
+        (1 << 1, 0x04), // DZC, divide by zero
+        (1 << 2, 0x08), // OFC, overflow
+        (1 << 3, 0x10), // UFC, underflow
+        (1 << 4, 0x20), // IXC, inexact
+    ];
+    const FPSR_MASK: u64 = 0x9f;
+
+    fn fpsr() -> u64 {
+        let fpsr: u64;
+        unsafe { asm!("mrs $0, fpsr" : "=r" (fpsr) ::: "volatile") }
+        fpsr
+    }
+
+    fn fpcr() -> u64 {
+        let fpcr: u64;
+        unsafe { asm!("mrs $0, fpcr" : "=r" (fpcr) ::: "volatile") }
+        fpcr
+    }
+
+    pub fn test_exceptions() -> u8 {
+        let fpsr = fpsr();
+        let mut flags = 0;
+        for &(bit, flag) in &FPSR_FLAGS {
+            if fpsr & bit != 0 {
+                flags |= flag;
+            }
+        }
+        flags
+    }
+
+    pub fn clear_exceptions() {
+        let fpsr = fpsr() & !FPSR_MASK;
+        unsafe { asm!("msr fpsr, $0" :: "r" (fpsr) :: "volatile") }
+    }
+
+    pub fn flush_to_zero() -> bool {
+        fpcr() & FPCR_FZ != 0
+    }
+
+    pub fn denormals_are_zero() -> bool {
+        fpcr() & FPCR_FZ != 0
+    }
+}
+
+#[cfg(not(any(target_arch = "x86", target_arch = "x86_64", target_arch = "aarch64")))]
+mod imp {
+    pub const SUPPORTED: bool = false;
+
+    pub fn test_exceptions() -> u8 { 0 }
+
+    pub fn clear_exceptions() { }
+
+    pub fn flush_to_zero() -> bool { false }
+
+    pub fn denormals_are_zero() -> bool { false }
+}
@@ -13,6 +13,7 @@
 use prelude::v1::*;
 
 use {f32, f64};
+use num::f16;
 use num::FpCategory;
 use num::dec2flt::rawfp::RawFloat;
 
@@ -66,12 +67,16 @@ impl DecodableFloat for f64 {
     fn min_pos_norm_value() -> Self { f64::MIN_POSITIVE }
 }
 
+impl DecodableFloat for f16 {
+    fn min_pos_norm_value() -> Self { ::f16::MIN_POSITIVE }
+}
+
 /// Returns a sign (true when negative) and `FullDecoded` value
 /// from given floating point number.
 pub fn decode<T: DecodableFloat>(v: T) -> (/*negative?*/ bool, FullDecoded) {
     let (mant, exp, sign) = v.integer_decode2();
     let even = (mant & 1) == 0;
-    let decoded = match v.classify() {
+    let decoded = match v.classify2() {
         FpCategory::Nan => FullDecoded::Nan,
         FpCategory::Infinite => FullDecoded::Infinite,
         FpCategory::Zero => FullDecoded::Zero,
@@ -132,10 +132,13 @@ functions.
 
 use prelude::v1::*;
 use i16;
+use str;
+use num::RoundingMode;
 pub use self::decoder::{decode, DecodableFloat, FullDecoded, Decoded};
 
 pub mod estimator;
 pub mod decoder;
+pub mod wide;
 
 /// Digit-generation algorithms.
 pub mod strategy {
@@ -171,6 +174,40 @@ pub fn round_up(d: &mut [u8], n: usize) -> Option<u8> {
     }
 }
 
+/// The rounding of the last digit in the exact mode.
+///
+/// Unlike `RoundingMode` this only concerns the magnitude of the number, as
+/// the digit-generation algorithms never see the sign.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum Rounding {
+    /// Round to the nearest, and ties to the even digit.
+    NearestEven,
+    /// Round to the nearest, and ties up.
+    NearestUp,
+    /// Round down (i.e. truncate the remaining digits).
+    Down,
+    /// Round up unless all the remaining digits are zero.
+    Up,
+    /// Round to the odd digit unless all the remaining digits are zero.
+    Odd,
+}
+
+impl Rounding {
+    /// Returns the rounding of the magnitude which rounds a number with the given sign as `mode`.
+    pub fn new(mode: RoundingMode, negative: bool) -> Rounding {
+        match (mode, negative) {
+            (RoundingMode::TiesToEven, _) => Rounding::NearestEven,
+            (RoundingMode::TiesToAway, _) => Rounding::NearestUp,
+            (RoundingMode::TowardZero, _) |
+            (RoundingMode::TowardPositive, true) |
+            (RoundingMode::TowardNegative, false) => Rounding::Down,
+            (RoundingMode::TowardPositive, false) |
+            (RoundingMode::TowardNegative, true) => Rounding::Up,
+            (RoundingMode::ToOdd, _) => Rounding::Odd,
+        }
+    }
+}
+
 /// Formatted parts.
 #[derive(Copy, Clone, PartialEq, Eq, Debug)]
 pub enum Part<'a> {
@@ -588,6 +625,80 @@ pub fn to_exact_exp_str<'a, T, F>(mut format_exact: F, v: T,
     }
 }
 
+/// Formats given floating point number like `%g` of C, with exactly given number of
+/// significant digits. The result is stored to the supplied parts array while utilizing
+/// given byte buffer as a scratch. `upper` is used to determine the case of the exponent
+/// prefix (`e` or `E`). The first part to be rendered is always a `Part::Sign` (which can
+/// be an empty string if no sign is rendered).
+///
+/// The decimal form is used when the decimal exponent `x` of the rounded number satisfies
+/// `-4 <= x < ndigits`, and the exponential form otherwise. Trailing zeroes are removed
+/// unless `keep_zeroes` is true.
+///
+/// `format_exact` should be the underlying digit-generation function.
+/// You probably would want `strategy::grisu::format_exact` for this.
+///
+/// The byte buffer should be at least `ndigits` bytes long unless `ndigits` is
+/// so large that only the fixed number of digits will be ever written.
+/// (The tipping point for `f64` is about 800, so 1000 bytes should be enough.)
+/// There should be at least 6 parts available, due to the worst case like
+/// `[1][.][2345][0000][e-][67]` with `keep_zeroes`. The sign is not one of them.
+pub fn to_exact_general_str<'a, T, F>(mut format_exact: F, v: T,
+                                      sign: Sign, ndigits: usize, keep_zeroes: bool, upper: bool,
+                                      buf: &'a mut [u8], parts: &'a mut [Part<'a>]) -> Formatted<'a>
+        where T: DecodableFloat, F: FnMut(&Decoded, &mut [u8], i16) -> (usize, i16) {
+    assert!(parts.len() >= 6);
+    assert!(ndigits > 0);
+
+    let (negative, full_decoded) = decode(v);
+    let sign = determine_sign(sign, &full_decoded, negative);
+    match full_decoded {
+        FullDecoded::Nan => {
+            parts[0] = Part::Copy(b"NaN");
+            Formatted { sign: sign, parts: &parts[..1] }
+        }
+        FullDecoded::Infinite => {
+            parts[0] = Part::Copy(b"inf");
+            Formatted { sign: sign, parts: &parts[..1] }
+        }
+        FullDecoded::Zero => {
+            if keep_zeroes && ndigits > 1 { // [0.][0000]
+                parts[0] = Part::Copy(b"0.");
+                parts[1] = Part::Zero(ndigits - 1);
+                Formatted { sign: sign, parts: &parts[..2] }
+            } else {
+                parts[0] = Part::Copy(b"0");
+                Formatted { sign: sign, parts: &parts[..1] }
+            }
+        }
+        FullDecoded::Finite(ref decoded) => {
+            let maxlen = estimate_max_buf_len(decoded.exp);
+            assert!(buf.len() >= ndigits || buf.len() >= maxlen);
+
+            let trunc = if ndigits < maxlen { ndigits } else { maxlen };
+            let (mut len, exp) = format_exact(decoded, &mut buf[..trunc], i16::MIN);
+            if !keep_zeroes {
+                while buf[len - 1] == b'0' {
+                    len -= 1;
+                }
+            }
+
+            // the number is `0.<...buf...> * 10^exp`, i.e. `x = exp - 1`
+            let vis_exp = exp as i32 - 1;
+            let parts = if -4 <= vis_exp && vis_exp < ndigits as i32 {
+                // `ndigits - 1 - x` fractional digits, which is non-negative here
+                let frac_digits = ndigits as i32 - exp as i32;
+                let frac_digits = if keep_zeroes { frac_digits as usize } else { 0 };
+                digits_to_dec_str(&buf[..len], exp, frac_digits, parts)
+            } else {
+                let min_ndigits = if keep_zeroes { ndigits } else { 0 };
+                digits_to_exp_str(&buf[..len], exp, min_ndigits, upper, parts)
+            };
+            Formatted { sign: sign, parts: parts }
+        }
+    }
+}
+
 /// Formats given floating point number into the decimal form with exactly
 /// given number of fractional digits. The result is stored to the supplied parts
 /// array while utilizing given byte buffer as a scratch. `upper` is currently
@@ -604,12 +715,26 @@ pub fn to_exact_exp_str<'a, T, F>(mut format_exact: F, v: T,
 /// There should be at least 5 parts available, due to the worst case like
 /// `[+][0.][0000][45][0000]` with `frac_digits = 10`.
 pub fn to_exact_fixed_str<'a, T, F>(mut format_exact: F, v: T,
-                                    sign: Sign, frac_digits: usize, _upper: bool,
+                                    sign: Sign, frac_digits: usize, upper: bool,
                                     buf: &'a mut [u8], parts: &'a mut [Part<'a>]) -> Formatted<'a>
         where T: DecodableFloat, F: FnMut(&Decoded, &mut [u8], i16) -> (usize, i16) {
+    to_exact_fixed_str_rounded(|d, buf, limit, _| format_exact(d, buf, limit), v,
+                               sign, frac_digits, RoundingMode::TiesToEven, upper, buf, parts)
+}
+
+/// Same as `to_exact_fixed_str`, but rounds the last fractional digit with given `mode`.
+///
+/// `format_exact` should be the underlying digit-generation function taking a `Rounding`.
+/// You probably would want `strategy::grisu::format_exact_rounded` for this.
+pub fn to_exact_fixed_str_rounded<'a, T, F>(mut format_exact: F, v: T,
+                                            sign: Sign, frac_digits: usize, mode: RoundingMode,
+                                            _upper: bool, buf: &'a mut [u8],
+                                            parts: &'a mut [Part<'a>]) -> Formatted<'a>
+        where T: DecodableFloat, F: FnMut(&Decoded, &mut [u8], i16, Rounding) -> (usize, i16) {
     assert!(parts.len() >= 4);
 
     let (negative, full_decoded) = decode(v);
+    let rounding = Rounding::new(mode, negative);
     let sign = determine_sign(sign, &full_decoded, negative);
     match full_decoded {
         FullDecoded::Nan => {
@@ -638,7 +763,7 @@ pub fn to_exact_fixed_str<'a, T, F>(mut format_exact: F, v: T,
             // `format_exact` will end rendering digits much earlier in this case,
             // because we are strictly limited by `maxlen`.
             let limit = if frac_digits < 0x8000 { -(frac_digits as i16) } else { i16::MIN };
-            let (len, exp) = format_exact(decoded, &mut buf[..maxlen], limit);
+            let (len, exp) = format_exact(decoded, &mut buf[..maxlen], limit, rounding);
             if exp <= limit {
                 // the restriction couldn't been met, so this should render like zero no matter
                 // `exp` was. this does not include the case that the restriction has been met
@@ -660,3 +785,31 @@ pub fn to_exact_fixed_str<'a, T, F>(mut format_exact: F, v: T,
     }
 }
 
+
+/// Writes `v` into `out` the way `Display` formats it without a precision, i.e. the
+/// shortest digits that read back as `v`. Returns the written string, or `None` if `out`
+/// is too short; `MAX_DISPLAY_LEN` bytes of `f32` or `f64` are always enough.
+pub fn write_shortest_str<T: DecodableFloat>(v: T, out: &mut [u8]) -> Option<&str> {
+    let mut buf = [0; MAX_SIG_DIGITS];
+    let mut parts = [Part::Zero(0); 4];
+    let formatted = to_shortest_str(strategy::grisu::format_shortest, v, Sign::Minus, 0, false,
+                                    &mut buf, &mut parts);
+    match formatted.write(out) {
+        Some(len) => Some(unsafe { str::from_utf8_unchecked(&out[..len]) }),
+        None => None,
+    }
+}
+
+/// Writes `v` into `out` the way `Display` formats it with `frac_digits` digits after the
+/// decimal point. Returns the written string, or `None` if `out` is too short.
+pub fn write_fixed_str<T: DecodableFloat>(v: T, frac_digits: usize,
+                                          out: &mut [u8]) -> Option<&str> {
+    let mut buf = [0; 1024]; // enough for f32 and f64
+    let mut parts = [Part::Zero(0); 5];
+    let formatted = to_exact_fixed_str(strategy::grisu::format_exact, v, Sign::Minus,
+                                       frac_digits, false, &mut buf, &mut parts);
+    match formatted.write(out) {
+        Some(len) => Some(unsafe { str::from_utf8_unchecked(&out[..len]) }),
+        None => None,
+    }
+}
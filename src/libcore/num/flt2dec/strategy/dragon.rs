@@ -19,7 +19,7 @@ use prelude::v1::*;
 
 use cmp::Ordering;
 
-use num::flt2dec::{Decoded, MAX_SIG_DIGITS, round_up};
+use num::flt2dec::{Decoded, MAX_SIG_DIGITS, Rounding, round_up};
 use num::flt2dec::estimator::estimate_scaling_factor;
 use num::bignum::Digit32 as Digit;
 use num::bignum::Big32x40 as Big;
@@ -228,6 +228,21 @@ pub fn format_shortest(d: &Decoded, buf: &mut [u8]) -> (/*#digits*/ usize, /*exp
 
 /// The exact and fixed mode implementation for Dragon.
 pub fn format_exact(d: &Decoded, buf: &mut [u8], limit: i16) -> (/*#digits*/ usize, /*exp*/ i16) {
+    // an exact half with an empty buffer has always been rounded up here, so keep doing that.
+    format_exact_impl(d, buf, limit, Rounding::NearestEven, true)
+}
+
+/// The exact and fixed mode implementation for Dragon, rounding the last digit with `rounding`.
+///
+/// Unlike `format_exact`, `Rounding::NearestEven` rounds an exact half with an empty buffer
+/// to zero, which is even.
+pub fn format_exact_rounded(d: &Decoded, buf: &mut [u8], limit: i16,
+                            rounding: Rounding) -> (/*#digits*/ usize, /*exp*/ i16) {
+    format_exact_impl(d, buf, limit, rounding, false)
+}
+
+fn format_exact_impl(d: &Decoded, buf: &mut [u8], limit: i16, rounding: Rounding,
+                     empty_is_odd: bool) -> (/*#digits*/ usize, /*exp*/ i16) {
     assert!(d.mant > 0);
     assert!(d.minus > 0);
     assert!(d.plus > 0);
@@ -257,7 +272,14 @@ pub fn format_exact(d: &Decoded, buf: &mut [u8], limit: i16) -> (/*#digits*/ usi
     // in order to keep the fixed-size bignum, we actually use `mant + floor(plus) >= scale`.
     // we are not actually modifying `scale`, since we can skip the initial multiplication instead.
     // again with the shortest algorithm, `d[0]` can be zero but will be eventually rounded up.
-    if *div_2pow10(&mut scale.clone(), buf.len()).add(&mant) >= scale {
+    // this is only true when rounding to the nearest; otherwise we fix up when `mant >= scale`
+    // and leave any carry to the final rounding.
+    let fixup = match rounding {
+        Rounding::NearestEven | Rounding::NearestUp =>
+            *div_2pow10(&mut scale.clone(), buf.len()).add(&mant) >= scale,
+        Rounding::Down | Rounding::Up | Rounding::Odd => mant >= scale,
+    };
+    if fixup {
         // equivalent to scaling `scale` by 10
         k += 1;
     } else {
@@ -308,16 +330,31 @@ pub fn format_exact(d: &Decoded, buf: &mut [u8], limit: i16) -> (/*#digits*/ usi
     // rounding up if we stop in the middle of digits
     // if the following digits are exactly 5000..., check the prior digit and try to
     // round to even (i.e. avoid rounding up when the prior digit is even).
-    let order = mant.cmp(scale.mul_small(5));
-    if order == Ordering::Greater || (order == Ordering::Equal &&
-                                      (len == 0 || buf[len-1] & 1 == 1)) {
+    // an empty buffer stands for zero, which is even unless `empty_is_odd` says otherwise.
+    let round = match rounding {
+        Rounding::NearestEven => {
+            let order = mant.cmp(scale.mul_small(5));
+            order == Ordering::Greater || (order == Ordering::Equal &&
+                                           (if len == 0 { empty_is_odd }
+                                            else { buf[len-1] & 1 == 1 }))
+        }
+        Rounding::NearestUp => mant >= *scale.mul_small(5),
+        Rounding::Down => false,
+        Rounding::Up => !mant.is_zero(),
+        // an even digit rounds up without a carry, and an empty buffer like for `Up`.
+        Rounding::Odd => !mant.is_zero() && (len == 0 || buf[len-1] & 1 == 0),
+    };
+    if round {
         // if rounding up changes the length, the exponent should also change.
         // but we've been requested a fixed number of digits, so do not alter the buffer...
         if let Some(c) = round_up(buf, len) {
             // ...unless we've been requested the fixed precision instead.
             // we also need to check that, if the original buffer was empty,
             // the additional digit can only be added when `k == limit` (edge case).
-            k += 1;
+            // when rounding up regardless of the magnitude, the empty buffer instead rounds up
+            // to `10^limit` however small `k` was.
+            let away = rounding == Rounding::Up || rounding == Rounding::Odd;
+            k = if away && k < limit { limit + 1 } else { k + 1 };
             if k > limit && len < buf.len() {
                 buf[len] = c;
                 len += 1;
@@ -19,7 +19,7 @@ Rust adaptation of Grisu3 algorithm described in [1]. It uses about
 use prelude::v1::*;
 
 use num::diy_float::Fp;
-use num::flt2dec::{Decoded, MAX_SIG_DIGITS, round_up};
+use num::flt2dec::{Decoded, MAX_SIG_DIGITS, Rounding, round_up};
 
 
 // see the comments in `format_shortest_opt` for the rationale.
@@ -694,3 +694,19 @@ pub fn format_exact(d: &Decoded, buf: &mut [u8], limit: i16) -> (/*#digits*/ usi
         None => fallback(d, buf, limit),
     }
 }
+
+/// The exact and fixed mode implementation for Grisu with Dragon fallback, rounding the last
+/// digit with `rounding`.
+///
+/// Grisu never decides the ties, so it is only used when rounding to the nearest.
+pub fn format_exact_rounded(d: &Decoded, buf: &mut [u8], limit: i16,
+                            rounding: Rounding) -> (/*#digits*/ usize, /*exp*/ i16) {
+    use num::flt2dec::strategy::dragon::format_exact_rounded as fallback;
+    match rounding {
+        Rounding::NearestEven | Rounding::NearestUp => match format_exact_opt(d, buf, limit) {
+            Some(ret) => ret,
+            None => fallback(d, buf, limit, rounding),
+        },
+        Rounding::Down | Rounding::Up | Rounding::Odd => fallback(d, buf, limit, rounding),
+    }
+}
@@ -0,0 +1,481 @@
+// Copyright 2016 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+/*!
+Formatting of `f128`, whose 113-bit significand doesn't fit `Decoded`.
+
+There is no Grisu here, since its 64-bit `Fp` cannot hold the significand either.
+The digits come from the Dragon algorithm in `strategy::dragon` on `Big32x580`,
+and are then rendered like the other floats by the same wrappers.
+*/
+
+use prelude::v1::*;
+
+use cmp::Ordering;
+use i16;
+
+use num::f128;
+use num::FpCategory;
+use num::bignum::Digit32 as Digit;
+use num::bignum::Big32x580 as Big;
+use num::quad;
+use num::flt2dec::{self, Sign, Part, Formatted, round_up};
+use num::flt2dec::{digits_to_dec_str, digits_to_exp_str};
+use num::flt2dec::estimator::estimate_scaling_factor;
+
+/// The minimum size of buffer necessary for the shortest mode, like `flt2dec::MAX_SIG_DIGITS`.
+pub const MAX_SIG_DIGITS: usize = 36;
+
+/// The size of buffer that is always enough for the exact and fixed modes,
+/// `estimate_max_buf_len` of the smallest exponent.
+pub const MAX_BUF_LEN: usize = 12408;
+
+static POW10: [Digit; 10] = [1, 10, 100, 1000, 10000, 100000,
+                             1000000, 10000000, 100000000, 1000000000];
+static TWOPOW10: [Digit; 10] = [2, 20, 200, 2000, 20000, 200000,
+                                2000000, 20000000, 200000000, 2000000000];
+
+/// Decoded unsigned finite value, like `flt2dec::Decoded` but with a mantissa
+/// of up to 115 bits as `(hi, lo)`.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Decoded {
+    /// The scaled mantissa.
+    pub mant: (u64, u64),
+    /// The lower error range.
+    pub minus: u64,
+    /// The upper error range.
+    pub plus: u64,
+    /// The shared exponent in base 2.
+    pub exp: i16,
+    /// True when the error range is inclusive.
+    pub inclusive: bool,
+}
+
+/// Decoded unsigned value.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum FullDecoded {
+    /// Not-a-number.
+    Nan,
+    /// Infinities, either positive or negative.
+    Infinite,
+    /// Zero, either positive or negative.
+    Zero,
+    /// Finite numbers with further decoded fields.
+    Finite(Decoded),
+}
+
+/// Returns a sign (true when negative) and `FullDecoded` value
+/// from given `f128`, like `flt2dec::decode`.
+pub fn decode(v: f128) -> (/*negative?*/ bool, FullDecoded) {
+    let (neg, exp, hi, lo) = quad::unpack(v.to_bits());
+    let even = lo & 1 == 0;
+    let decoded = match v.classify() {
+        FpCategory::Nan => FullDecoded::Nan,
+        FpCategory::Infinite => FullDecoded::Infinite,
+        FpCategory::Zero => FullDecoded::Zero,
+        FpCategory::Normal if (hi, lo) == (1 << 48, 0) && exp > quad::MIN_EXP_INT => {
+            // neighbors: (maxmant, exp - 1) -- (minnormmant, exp) -- (minnormmant + 1, exp)
+            FullDecoded::Finite(Decoded { mant: quad::shl(hi, lo, 2), minus: 1, plus: 2,
+                                          exp: (exp - 2) as i16, inclusive: even })
+        }
+        _ => {
+            // neighbors: (mant - 1, exp) -- (mant, exp) -- (mant + 1, exp)
+            // this includes the subnormals, which share the smallest exponent.
+            FullDecoded::Finite(Decoded { mant: quad::shl(hi, lo, 1), minus: 1, plus: 1,
+                                          exp: (exp - 1) as i16, inclusive: even })
+        }
+    };
+    (neg, decoded)
+}
+
+fn to_big(x: (u64, u64)) -> Big {
+    let mut big = Big::from_u64(x.0);
+    big.mul_pow2(64).add(&Big::from_u64(x.1));
+    big
+}
+
+/// Estimates `k_0` like `estimate_scaling_factor`, which only looks at the bit length
+/// of `mant`. The top 64 bits have the same one once the lower bits are made sticky.
+fn estimate_wide_scaling_factor(mant: (u64, u64), exp: i16) -> i16 {
+    let lz = quad::leading_zeros(mant.0, mant.1);
+    let (top, rest) = quad::shl(mant.0, mant.1, lz);
+    estimate_scaling_factor(top | (rest != 0) as u64, exp + 64 - lz as i16)
+}
+
+fn mul_pow10(x: &mut Big, n: usize) -> &mut Big {
+    x.mul_pow5(n).mul_pow2(n)
+}
+
+fn div_2pow10(x: &mut Big, mut n: usize) -> &mut Big {
+    let largest = POW10.len() - 1;
+    while n > largest {
+        x.div_rem_small(POW10[largest]);
+        n -= largest;
+    }
+    x.div_rem_small(TWOPOW10[n]);
+    x
+}
+
+// only usable when `x < 16 * scale`; `scaleN` should be `scale.mul_small(N)`
+fn div_rem_upto_16<'a>(x: &'a mut Big, scale: &Big,
+                       scale2: &Big, scale4: &Big, scale8: &Big) -> (u8, &'a mut Big) {
+    let mut d = 0;
+    if *x >= *scale8 { x.sub(scale8); d += 8; }
+    if *x >= *scale4 { x.sub(scale4); d += 4; }
+    if *x >= *scale2 { x.sub(scale2); d += 2; }
+    if *x >= *scale  { x.sub(scale);  d += 1; }
+    debug_assert!(*x < *scale);
+    (d, x)
+}
+
+/// The shortest mode implementation, translated from `strategy::dragon::format_shortest`.
+pub fn format_shortest(d: &Decoded, buf: &mut [u8]) -> (/*#digits*/ usize, /*exp*/ i16) {
+    assert!(d.mant != (0, 0));
+    assert!(d.minus > 0);
+    assert!(d.plus > 0);
+    assert!(buf.len() >= MAX_SIG_DIGITS);
+
+    // `a.cmp(&b) < rounding` is `if d.inclusive {a <= b} else {a < b}`
+    let rounding = if d.inclusive {Ordering::Greater} else {Ordering::Equal};
+
+    // estimate `k_0` from original inputs satisfying `10^(k_0-1) < high <= 10^(k_0+1)`.
+    let lo = d.mant.1.wrapping_add(d.plus);
+    let high = (d.mant.0 + (lo < d.plus) as u64, lo);
+    let mut k = estimate_wide_scaling_factor(high, d.exp);
+
+    // `v = mant / scale`, `low = (mant - minus) / scale`, `high = (mant + plus) / scale`
+    let mut mant = to_big(d.mant);
+    let mut minus = Big::from_u64(d.minus);
+    let mut plus = Big::from_u64(d.plus);
+    let mut scale = Big::from_small(1);
+    if d.exp < 0 {
+        scale.mul_pow2(-d.exp as usize);
+    } else {
+        mant.mul_pow2(d.exp as usize);
+        minus.mul_pow2(d.exp as usize);
+        plus.mul_pow2(d.exp as usize);
+    }
+
+    // divide `mant` by `10^k`. now `scale / 10 < mant + plus <= scale * 10`.
+    if k >= 0 {
+        mul_pow10(&mut scale, k as usize);
+    } else {
+        mul_pow10(&mut mant, -k as usize);
+        mul_pow10(&mut minus, -k as usize);
+        mul_pow10(&mut plus, -k as usize);
+    }
+
+    // fixup when `mant + plus > scale` (or `>=`), as in Dragon.
+    // now `scale < mant + plus <= scale * 10` and we are ready to generate digits.
+    if scale.cmp(mant.clone().add(&plus)) < rounding {
+        k += 1;
+    } else {
+        mant.mul_small(10);
+        minus.mul_small(10);
+        plus.mul_small(10);
+    }
+
+    let mut scale2 = scale.clone(); scale2.mul_pow2(1);
+    let mut scale4 = scale.clone(); scale4.mul_pow2(2);
+    let mut scale8 = scale.clone(); scale8.mul_pow2(3);
+
+    let mut down;
+    let mut up;
+    let mut i = 0;
+    loop {
+        // generate one digit: `d[n] = floor(mant / scale) < 10`.
+        let (d, _) = div_rem_upto_16(&mut mant, &scale, &scale2, &scale4, &scale8);
+        debug_assert!(d < 10);
+        buf[i] = b'0' + d;
+        i += 1;
+
+        // stop and round `down` when `mant < minus` (or `<=`), and `up` when
+        // `scale < mant + plus` (or `<=`). keep generating otherwise.
+        down = mant.cmp(&minus) < rounding;
+        up = scale.cmp(mant.clone().add(&plus)) < rounding;
+        if down || up { break; }
+
+        mant.mul_small(10);
+        minus.mul_small(10);
+        plus.mul_small(10);
+    }
+
+    if up && (!down || *mant.mul_pow2(1) >= scale) {
+        if let Some(c) = round_up(buf, i) {
+            buf[i] = c;
+            i += 1;
+            k += 1;
+        }
+    }
+
+    (i, k)
+}
+
+/// The exact and fixed mode implementation, translated from
+/// `strategy::dragon::format_exact`.
+pub fn format_exact(d: &Decoded, buf: &mut [u8], limit: i16) -> (/*#digits*/ usize, /*exp*/ i16) {
+    assert!(d.mant != (0, 0));
+
+    // estimate `k_0` from original inputs satisfying `10^(k_0-1) < v <= 10^(k_0+1)`.
+    let mut k = estimate_wide_scaling_factor(d.mant, d.exp);
+
+    // `v = mant / scale`.
+    let mut mant = to_big(d.mant);
+    let mut scale = Big::from_small(1);
+    if d.exp < 0 {
+        scale.mul_pow2(-d.exp as usize);
+    } else {
+        mant.mul_pow2(d.exp as usize);
+    }
+
+    // divide `mant` by `10^k`. now `scale / 10 < mant <= scale * 10`.
+    if k >= 0 {
+        mul_pow10(&mut scale, k as usize);
+    } else {
+        mul_pow10(&mut mant, -k as usize);
+    }
+
+    // fixup when `mant + floor(plus) >= scale`, where `plus / scale = 10^-buf.len() / 2`.
+    if *div_2pow10(&mut scale.clone(), buf.len()).add(&mant) >= scale {
+        k += 1;
+    } else {
+        mant.mul_small(10);
+    }
+
+    // shorten the buffer for the last-digit limitation, as in Dragon.
+    let mut len = if k < limit {
+        0
+    } else if ((k as i32 - limit as i32) as usize) < buf.len() {
+        (k - limit) as usize
+    } else {
+        buf.len()
+    };
+
+    if len > 0 {
+        let mut scale2 = scale.clone(); scale2.mul_pow2(1);
+        let mut scale4 = scale.clone(); scale4.mul_pow2(2);
+        let mut scale8 = scale.clone(); scale8.mul_pow2(3);
+
+        for i in 0..len {
+            if mant.is_zero() { // following digits are all zeroes, we stop here
+                for c in &mut buf[i..len] { *c = b'0'; }
+                return (len, k);
+            }
+
+            let (d, _) = div_rem_upto_16(&mut mant, &scale, &scale2, &scale4, &scale8);
+            debug_assert!(d < 10);
+            buf[i] = b'0' + d;
+            mant.mul_small(10);
+        }
+    }
+
+    // round to the nearest, and ties to even.
+    let order = mant.cmp(scale.mul_small(5));
+    if order == Ordering::Greater || (order == Ordering::Equal &&
+                                      len > 0 && buf[len-1] & 1 == 1) {
+        if let Some(c) = round_up(buf, len) {
+            k += 1;
+            if k > limit && len < buf.len() {
+                buf[len] = c;
+                len += 1;
+            }
+        }
+    }
+
+    (len, k)
+}
+
+/// Returns rather crude approximation (upper bound) for the maximum buffer size
+/// calculated from the given decoded exponent, like `flt2dec::estimate_max_buf_len`
+/// but for mantissas of up to 115 bits: `36 + (1 + exp * log_10 x)`.
+fn estimate_max_buf_len(exp: i16) -> usize {
+    36 + ((if exp < 0 { -12 } else { 5 } * exp as i32) as usize >> 4)
+}
+
+/// The finite numbers are signed like the infinities.
+fn determine_sign(sign: Sign, decoded: &FullDecoded, negative: bool) -> &'static [u8] {
+    let category = match *decoded {
+        FullDecoded::Nan => flt2dec::FullDecoded::Nan,
+        FullDecoded::Zero => flt2dec::FullDecoded::Zero,
+        FullDecoded::Infinite | FullDecoded::Finite(_) => flt2dec::FullDecoded::Infinite,
+    };
+    flt2dec::determine_sign(sign, &category, negative)
+}
+
+/// Formats an `f128` like `flt2dec::to_shortest_str` does with `format_shortest`.
+pub fn to_shortest_str<'a>(v: f128, sign: Sign, frac_digits: usize, _upper: bool,
+                           buf: &'a mut [u8], parts: &'a mut [Part<'a>]) -> Formatted<'a> {
+    assert!(parts.len() >= 4);
+    assert!(buf.len() >= MAX_SIG_DIGITS);
+
+    let (negative, full_decoded) = decode(v);
+    let sign = determine_sign(sign, &full_decoded, negative);
+    match full_decoded {
+        FullDecoded::Nan => {
+            parts[0] = Part::Copy(b"NaN");
+            Formatted { sign: sign, parts: &parts[..1] }
+        }
+        FullDecoded::Infinite => {
+            parts[0] = Part::Copy(b"inf");
+            Formatted { sign: sign, parts: &parts[..1] }
+        }
+        FullDecoded::Zero => {
+            if frac_digits > 0 { // [0.][0000]
+                parts[0] = Part::Copy(b"0.");
+                parts[1] = Part::Zero(frac_digits);
+                Formatted { sign: sign, parts: &parts[..2] }
+            } else {
+                parts[0] = Part::Copy(b"0");
+                Formatted { sign: sign, parts: &parts[..1] }
+            }
+        }
+        FullDecoded::Finite(ref decoded) => {
+            let (len, exp) = format_shortest(decoded, buf);
+            Formatted { sign: sign,
+                        parts: digits_to_dec_str(&buf[..len], exp, frac_digits, parts) }
+        }
+    }
+}
+
+/// Formats an `f128` like `flt2dec::to_shortest_exp_str` does with `format_shortest`.
+pub fn to_shortest_exp_str<'a>(v: f128, sign: Sign, dec_bounds: (i16, i16), upper: bool,
+                               buf: &'a mut [u8], parts: &'a mut [Part<'a>]) -> Formatted<'a> {
+    assert!(parts.len() >= 6);
+    assert!(buf.len() >= MAX_SIG_DIGITS);
+    assert!(dec_bounds.0 <= dec_bounds.1);
+
+    let (negative, full_decoded) = decode(v);
+    let sign = determine_sign(sign, &full_decoded, negative);
+    match full_decoded {
+        FullDecoded::Nan => {
+            parts[0] = Part::Copy(b"NaN");
+            Formatted { sign: sign, parts: &parts[..1] }
+        }
+        FullDecoded::Infinite => {
+            parts[0] = Part::Copy(b"inf");
+            Formatted { sign: sign, parts: &parts[..1] }
+        }
+        FullDecoded::Zero => {
+            parts[0] = if dec_bounds.0 <= 0 && 0 < dec_bounds.1 {
+                Part::Copy(b"0")
+            } else {
+                Part::Copy(if upper { b"0E0" } else { b"0e0" })
+            };
+            Formatted { sign: sign, parts: &parts[..1] }
+        }
+        FullDecoded::Finite(ref decoded) => {
+            let (len, exp) = format_shortest(decoded, buf);
+            let vis_exp = exp as i32 - 1;
+            let parts = if dec_bounds.0 as i32 <= vis_exp && vis_exp < dec_bounds.1 as i32 {
+                digits_to_dec_str(&buf[..len], exp, 0, parts)
+            } else {
+                digits_to_exp_str(&buf[..len], exp, 0, upper, parts)
+            };
+            Formatted { sign: sign, parts: parts }
+        }
+    }
+}
+
+/// Formats an `f128` like `flt2dec::to_exact_exp_str` does with `format_exact`.
+///
+/// The byte buffer should be at least `ndigits` bytes long unless `ndigits` is
+/// so large that only the fixed number of digits will be ever written, which
+/// `MAX_BUF_LEN` bytes always are.
+pub fn to_exact_exp_str<'a>(v: f128, sign: Sign, ndigits: usize, upper: bool,
+                            buf: &'a mut [u8], parts: &'a mut [Part<'a>]) -> Formatted<'a> {
+    assert!(parts.len() >= 6);
+    assert!(ndigits > 0);
+
+    let (negative, full_decoded) = decode(v);
+    let sign = determine_sign(sign, &full_decoded, negative);
+    match full_decoded {
+        FullDecoded::Nan => {
+            parts[0] = Part::Copy(b"NaN");
+            Formatted { sign: sign, parts: &parts[..1] }
+        }
+        FullDecoded::Infinite => {
+            parts[0] = Part::Copy(b"inf");
+            Formatted { sign: sign, parts: &parts[..1] }
+        }
+        FullDecoded::Zero => {
+            if ndigits > 1 { // [0.][0000][e0]
+                parts[0] = Part::Copy(b"0.");
+                parts[1] = Part::Zero(ndigits - 1);
+                parts[2] = Part::Copy(if upper { b"E0" } else { b"e0" });
+                Formatted { sign: sign, parts: &parts[..3] }
+            } else {
+                parts[0] = Part::Copy(if upper { b"0E0" } else { b"0e0" });
+                Formatted { sign: sign, parts: &parts[..1] }
+            }
+        }
+        FullDecoded::Finite(ref decoded) => {
+            let maxlen = estimate_max_buf_len(decoded.exp);
+            assert!(buf.len() >= ndigits || buf.len() >= maxlen);
+
+            let trunc = if ndigits < maxlen { ndigits } else { maxlen };
+            let (len, exp) = format_exact(decoded, &mut buf[..trunc], i16::MIN);
+            Formatted { sign: sign,
+                        parts: digits_to_exp_str(&buf[..len], exp, ndigits, upper, parts) }
+        }
+    }
+}
+
+/// Formats an `f128` like `flt2dec::to_exact_fixed_str` does with `format_exact`.
+///
+/// The byte buffer should be at least `MAX_BUF_LEN` bytes long.
+pub fn to_exact_fixed_str<'a>(v: f128, sign: Sign, frac_digits: usize, _upper: bool,
+                              buf: &'a mut [u8], parts: &'a mut [Part<'a>]) -> Formatted<'a> {
+    assert!(parts.len() >= 4);
+
+    let (negative, full_decoded) = decode(v);
+    let sign = determine_sign(sign, &full_decoded, negative);
+    match full_decoded {
+        FullDecoded::Nan => {
+            parts[0] = Part::Copy(b"NaN");
+            Formatted { sign: sign, parts: &parts[..1] }
+        }
+        FullDecoded::Infinite => {
+            parts[0] = Part::Copy(b"inf");
+            Formatted { sign: sign, parts: &parts[..1] }
+        }
+        FullDecoded::Zero => {
+            if frac_digits > 0 { // [0.][0000]
+                parts[0] = Part::Copy(b"0.");
+                parts[1] = Part::Zero(frac_digits);
+                Formatted { sign: sign, parts: &parts[..2] }
+            } else {
+                parts[0] = Part::Copy(b"0");
+                Formatted { sign: sign, parts: &parts[..1] }
+            }
+        }
+        FullDecoded::Finite(ref decoded) => {
+            let maxlen = estimate_max_buf_len(decoded.exp);
+            assert!(buf.len() >= maxlen);
+
+            let limit = if frac_digits < 0x8000 { -(frac_digits as i16) } else { i16::MIN };
+            let (len, exp) = format_exact(decoded, &mut buf[..maxlen], limit);
+            if exp <= limit {
+                // the restriction couldn't been met, so this should render like zero.
+                debug_assert_eq!(len, 0);
+                if frac_digits > 0 { // [0.][0000]
+                    parts[0] = Part::Copy(b"0.");
+                    parts[1] = Part::Zero(frac_digits);
+                    Formatted { sign: sign, parts: &parts[..2] }
+                } else {
+                    parts[0] = Part::Copy(b"0");
+                    Formatted { sign: sign, parts: &parts[..1] }
+                }
+            } else {
+                Formatted { sign: sign,
+                            parts: digits_to_dec_str(&buf[..len], exp, frac_digits, parts) }
+            }
+        }
+    }
+}
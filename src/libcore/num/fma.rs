@@ -0,0 +1,66 @@
+// Copyright 2016 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Fused multiply-add in software, for targets without FMA instructions.
+//!
+//! The product of two mantissas of at most 53 bits is exact in 128 bits, and so is its sum
+//! with a third mantissa as long as the bits shifted out of the smaller operand are kept
+//! as a sticky bit. The result is then reduced to 64 bits, again keeping a sticky bit, and
+//! `Float::from_exp_mantissa` rounds it only once.
+
+use num::quad::{leading_zeros, shl, shr_jam, wide_mul};
+
+/// An unsigned 128-bit integer as `(hi, lo)`, times `2^e`, with a sign.
+#[derive(Copy, Clone)]
+struct Wide {
+    neg: bool,
+    e: i32,
+    hi: u64,
+    lo: u64,
+}
+
+/// Shifts a non-zero value so that its highest bit is bit 125, leaving room for a carry.
+fn normalize(neg: bool, e: i32, hi: u64, lo: u64) -> Wide {
+    let s = leading_zeros(hi, lo) - 2;
+    let (hi, lo) = shl(hi, lo, s);
+    Wide { neg: neg, e: e - s as i32, hi: hi, lo: lo }
+}
+
+/// Returns `a * b + c`, where each operand is a sign and the `(exp, mantissa)` pair of
+/// `Float::to_exp_mantissa`, with non-zero mantissas of at most 53 bits.
+///
+/// The result is a sign and an `(exp, mantissa)` pair for `Float::from_exp_mantissa`, with
+/// the lowest bit of the mantissa set if any lower bits are non-zero. An exact zero is
+/// positive, as it is when rounding to nearest.
+pub fn mul_add(a: (bool, i32, u64), b: (bool, i32, u64), c: (bool, i32, u64))
+               -> (bool, i32, u64) {
+    let (hi, lo) = wide_mul(a.2, b.2);
+    let p = normalize(a.0 != b.0, a.1 + b.1, hi, lo);
+    let c = normalize(c.0, c.1, 0, c.2);
+
+    // Both operands have at least 20 zero bits at the bottom, so `x` does not lose any
+    // bits, and the sticky bit of `y` can never make a tie out of an inexact sum.
+    let (x, y) = if (p.e, p.hi, p.lo) >= (c.e, c.hi, c.lo) { (p, c) } else { (c, p) };
+    let (yhi, ylo) = shr_jam(y.hi, y.lo, (x.e - y.e) as u32);
+    let (hi, lo) = if x.neg == y.neg {
+        let lo = x.lo.wrapping_add(ylo);
+        (x.hi + yhi + (lo < x.lo) as u64, lo)
+    } else {
+        let lo = x.lo.wrapping_sub(ylo);
+        (x.hi - yhi - (x.lo < ylo) as u64, lo)
+    };
+    if hi == 0 && lo == 0 {
+        return (false, 0, 0);
+    }
+
+    let s = leading_zeros(hi, lo);
+    let (hi, lo) = shl(hi, lo, s);
+    (x.neg, x.e + 64 - s as i32, hi | (lo != 0) as u64)
+}
@@ -0,0 +1,151 @@
+// Copyright 2016 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Decomposition of positive floats into an integer significand and a binary exponent.
+//!
+//! A positive finite `f32` or `f64` is represented here as `sig * 2^k`, with the hidden bit of
+//! normal numbers made explicit. For normal numbers, `sig` has exactly as many bits as the
+//! type's significand (24 for `f32` and 53 for `f64`), so every normal value has exactly one
+//! such representation. This is the representation used internally by the decimal-to-float
+//! conversion routines.
+//!
+//! None of the functions here deal with the sign: negative numbers and NaN are rejected.
+
+#![unstable(feature = "float_decomposition",
+            reason = "recently added, the names and representation may change",
+            issue = "0")]
+
+use num::dec2flt::rawfp::{self, RawFloat};
+use num::FpCategory::{Infinite, Nan, Normal, Subnormal, Zero};
+
+/// A positive float decomposed into `sig * 2^k`.
+///
+/// For normal numbers `sig` is in the range `2^(p-1)..2^p` where `p` is the number of bits in
+/// the significand including the hidden bit. For subnormal numbers and zero, `sig` is below that
+/// range and `k` is the exponent of the smallest subnormal.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Unpacked {
+    /// The integer significand.
+    pub sig: u64,
+    /// The binary exponent.
+    pub k: i16,
+}
+
+impl Unpacked {
+    /// Creates a new `Unpacked` from its parts, without any validation.
+    pub fn new(sig: u64, k: i16) -> Self {
+        Unpacked { sig: sig, k: k }
+    }
+}
+
+/// Decomposes `x` into `sig * 2^k`.
+///
+/// # Panics
+///
+/// Panics if `x` is negative, infinite, or NaN. Negative zero is treated like zero.
+///
+/// # Examples
+///
+/// ```
+/// #![feature(float_decomposition)]
+/// use std::num::fp::{self, Unpacked};
+///
+/// assert_eq!(fp::unpack(1.0f64), Unpacked::new(1 << 52, -52));
+/// assert_eq!(fp::unpack(0.75f32), Unpacked::new(3 << 22, -24));
+/// ```
+pub fn unpack<T: RawFloat>(x: T) -> Unpacked {
+    match x.classify2() {
+        Normal | Subnormal if !x.is_sign_negative2() => x.unpack(),
+        Zero => x.unpack(),
+        _ => panic!("fp::unpack: argument is negative, infinite, or NaN"),
+    }
+}
+
+/// Constructs the normal float `x.sig * 2^x.k`. This is the inverse of `unpack` for normal
+/// numbers.
+///
+/// # Panics
+///
+/// Panics if `x.sig` doesn't have exactly as many bits as the significand of `T`, or if `x.k`
+/// is outside the exponent range of normal numbers.
+///
+/// # Examples
+///
+/// ```
+/// #![feature(float_decomposition)]
+/// use std::num::fp::{self, Unpacked};
+///
+/// let x: f64 = fp::encode_normal(Unpacked::new(3 << 51, -52));
+/// assert_eq!(x, 1.5);
+/// ```
+pub fn encode_normal<T: RawFloat>(x: Unpacked) -> T {
+    assert!(T::min_sig() <= x.sig && x.sig <= T::max_sig(),
+            "fp::encode_normal: significand not normalized");
+    assert!(T::min_exp_int() <= x.k && x.k <= T::max_exp_int(),
+            "fp::encode_normal: exponent out of range");
+    rawfp::encode_normal(x)
+}
+
+/// Returns the smallest float strictly greater than `x`.
+///
+/// This handles zero and subnormals, and saturates at infinity: the successor of the largest
+/// finite number and of infinity is infinity.
+///
+/// # Panics
+///
+/// Panics if `x` is negative or NaN.
+///
+/// # Examples
+///
+/// ```
+/// #![feature(float_decomposition)]
+/// use std::f64;
+/// use std::num::fp;
+///
+/// assert_eq!(fp::next_float(1.0f64), 1.0 + f64::EPSILON);
+/// assert_eq!(fp::next_float(0.0f64), 5e-324);
+/// assert_eq!(fp::next_float(f64::MAX), f64::INFINITY);
+/// ```
+pub fn next_float<T: RawFloat>(x: T) -> T {
+    assert!(!x.is_sign_negative2() || x == T::zero2(), "fp::next_float: argument is negative");
+    // Negative zero is the only negative number let through, treat it like zero.
+    rawfp::next_float(if x == T::zero2() { T::zero2() } else { x })
+}
+
+/// Returns the largest float strictly smaller than `x`.
+///
+/// This is the inverse of `next_float` for positive numbers: it handles subnormals, and the
+/// predecessor of infinity is the largest finite number.
+///
+/// # Panics
+///
+/// Panics if `x` is zero, negative, or NaN.
+///
+/// # Examples
+///
+/// ```
+/// #![feature(float_decomposition)]
+/// use std::f64;
+/// use std::num::fp;
+///
+/// assert_eq!(fp::prev_float(1.0f64), 1.0 - f64::EPSILON / 2.0);
+/// assert_eq!(fp::prev_float(f64::INFINITY), f64::MAX);
+/// assert_eq!(fp::prev_float(5e-324f64), 0.0);
+/// ```
+pub fn prev_float<T: RawFloat>(x: T) -> T {
+    match x.classify2() {
+        Nan => panic!("fp::prev_float: argument is NaN"),
+        Zero => panic!("fp::prev_float: argument is zero"),
+        _ if x.is_sign_negative2() => panic!("fp::prev_float: argument is negative"),
+        // As in `next_float`, stepping the encoding does the right thing at every boundary:
+        // from normal to subnormal numbers and from infinity to the largest finite number.
+        Infinite | Subnormal | Normal => T::from_bits2(x.to_bits2() - 1),
+    }
+}
@@ -0,0 +1,750 @@
+// Copyright 2016 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Operations and constants for signed 128-bits integers (`i128` type)
+//!
+//! Like `u128`, `i128` is not a primitive type: it is stored in two's
+//! complement in an `i64` and a `u64`, with arithmetic done in software, and
+//! the conversions go through `From`, `from_parts`, `to_parts` and
+//! `as_u128`. As for `u128`, the arithmetic operators panic on overflow only
+//! when overflow checks are enabled in the crate using them.
+//!
+//! # Examples
+//!
+//! ```
+//! #![feature(i128)]
+//!
+//! use std::num::i128;
+//!
+//! let x = i128::from(i64::min_value()) * i128::from(4u8);
+//! assert_eq!(x.to_parts(), (-2, 0));
+//! assert_eq!(x.to_string(), "-36893488147419103232");
+//! assert_eq!(format!("{:x}", x), "fffffffffffffffe0000000000000000");
+//! ```
+
+#![unstable(feature = "i128", issue = "0")]
+
+use cmp::{PartialOrd, Ord, Ordering};
+use convert::From;
+use hash::{Hash, Hasher};
+use i64;
+use num::quad;
+use ops::{Add, Sub, Mul, Div, Rem, Neg, Not, BitAnd, BitOr, BitXor, Shl, Shr};
+use ops::{AddAssign, SubAssign, MulAssign, DivAssign, RemAssign};
+use ops::{BitAndAssign, BitOrAssign, BitXorAssign, ShlAssign, ShrAssign};
+use option::Option::{self, Some, None};
+use u128::u128;
+
+/// A signed 128-bit integer.
+///
+/// See the [module documentation](../i128/index.html) for how it differs
+/// from the primitive integer types.
+#[allow(non_camel_case_types)]
+#[derive(Copy, Clone, PartialEq, Eq, Default)]
+#[repr(C)]
+pub struct i128 {
+    #[cfg(target_endian = "big")]
+    hi: i64,
+    lo: u64,
+    #[cfg(target_endian = "little")]
+    hi: i64,
+}
+
+/// The smallest value that can be represented by this integer type.
+pub const MIN: i128 = i128::min_value();
+/// The largest value that can be represented by this integer type.
+pub const MAX: i128 = i128::max_value();
+
+impl i128 {
+    /// Creates an `i128` from its high 64 bits, which hold the sign, and its
+    /// low 64 bits.
+    #[inline]
+    pub const fn from_parts(hi: i64, lo: u64) -> i128 {
+        i128 { hi: hi, lo: lo }
+    }
+
+    /// Returns the high 64 bits, which hold the sign, and the low 64 bits.
+    #[inline]
+    pub fn to_parts(self) -> (i64, u64) {
+        (self.hi, self.lo)
+    }
+
+    /// Returns the smallest value that can be represented by this integer
+    /// type.
+    #[inline]
+    pub const fn min_value() -> i128 {
+        i128 { hi: i64::MIN, lo: 0 }
+    }
+
+    /// Returns the largest value that can be represented by this integer
+    /// type.
+    #[inline]
+    pub const fn max_value() -> i128 {
+        i128 { hi: i64::MAX, lo: !0 }
+    }
+
+    /// Reinterprets the bits as a `u128`, like an `as` cast between
+    /// primitive integers of the same width.
+    #[inline]
+    pub fn as_u128(self) -> u128 {
+        u128::from_parts(self.hi as u64, self.lo)
+    }
+
+    /// Returns the number of ones in the binary representation of `self`.
+    #[inline]
+    pub fn count_ones(self) -> u32 {
+        self.as_u128().count_ones()
+    }
+
+    /// Returns the number of zeros in the binary representation of `self`.
+    #[inline]
+    pub fn count_zeros(self) -> u32 {
+        self.as_u128().count_zeros()
+    }
+
+    /// Returns the number of leading zeros in the binary representation of
+    /// `self`.
+    #[inline]
+    pub fn leading_zeros(self) -> u32 {
+        self.as_u128().leading_zeros()
+    }
+
+    /// Returns the number of trailing zeros in the binary representation of
+    /// `self`.
+    #[inline]
+    pub fn trailing_zeros(self) -> u32 {
+        self.as_u128().trailing_zeros()
+    }
+
+    /// Shifts the bits to the left by `n`, wrapping the truncated bits
+    /// around to the end.
+    #[inline]
+    pub fn rotate_left(self, n: u32) -> i128 {
+        self.as_u128().rotate_left(n).as_i128()
+    }
+
+    /// Shifts the bits to the right by `n`, wrapping the truncated bits
+    /// around to the beginning.
+    #[inline]
+    pub fn rotate_right(self, n: u32) -> i128 {
+        self.as_u128().rotate_right(n).as_i128()
+    }
+
+    /// Reverses the byte order of `self`.
+    #[inline]
+    pub fn swap_bytes(self) -> i128 {
+        self.as_u128().swap_bytes().as_i128()
+    }
+
+    /// Converts `x` from big endian to the target's endianness.
+    #[inline]
+    pub fn from_be(x: i128) -> i128 {
+        u128::from_be(x.as_u128()).as_i128()
+    }
+
+    /// Converts `x` from little endian to the target's endianness.
+    #[inline]
+    pub fn from_le(x: i128) -> i128 {
+        u128::from_le(x.as_u128()).as_i128()
+    }
+
+    /// Converts `self` to big endian from the target's endianness.
+    #[inline]
+    pub fn to_be(self) -> i128 {
+        self.as_u128().to_be().as_i128()
+    }
+
+    /// Converts `self` to little endian from the target's endianness.
+    #[inline]
+    pub fn to_le(self) -> i128 {
+        self.as_u128().to_le().as_i128()
+    }
+
+    /// Checked integer addition. Returns `None` if overflow occurred.
+    #[inline]
+    pub fn checked_add(self, other: i128) -> Option<i128> {
+        checked(self.overflowing_add(other))
+    }
+
+    /// Checked integer subtraction. Returns `None` if overflow occurred.
+    #[inline]
+    pub fn checked_sub(self, other: i128) -> Option<i128> {
+        checked(self.overflowing_sub(other))
+    }
+
+    /// Checked integer multiplication. Returns `None` if overflow occurred.
+    #[inline]
+    pub fn checked_mul(self, other: i128) -> Option<i128> {
+        checked(self.overflowing_mul(other))
+    }
+
+    /// Checked integer division. Returns `None` if `other == 0` or the
+    /// division results in overflow.
+    #[inline]
+    pub fn checked_div(self, other: i128) -> Option<i128> {
+        if other == i128::from(0u8) {
+            None
+        } else {
+            checked(self.overflowing_div(other))
+        }
+    }
+
+    /// Checked integer remainder. Returns `None` if `other == 0` or the
+    /// division results in overflow.
+    #[inline]
+    pub fn checked_rem(self, other: i128) -> Option<i128> {
+        if other == i128::from(0u8) {
+            None
+        } else {
+            checked(self.overflowing_rem(other))
+        }
+    }
+
+    /// Checked negation. Returns `None` if `self == MIN`.
+    #[inline]
+    pub fn checked_neg(self) -> Option<i128> {
+        checked(self.overflowing_neg())
+    }
+
+    /// Checked shift left. Returns `None` if `rhs` is larger than or equal
+    /// to 128.
+    #[inline]
+    pub fn checked_shl(self, rhs: u32) -> Option<i128> {
+        checked(self.overflowing_shl(rhs))
+    }
+
+    /// Checked shift right. Returns `None` if `rhs` is larger than or equal
+    /// to 128.
+    #[inline]
+    pub fn checked_shr(self, rhs: u32) -> Option<i128> {
+        checked(self.overflowing_shr(rhs))
+    }
+
+    /// Saturating integer addition. Computes `self + other`, saturating at
+    /// the numeric bounds instead of overflowing.
+    #[inline]
+    pub fn saturating_add(self, other: i128) -> i128 {
+        // the sum can only overflow away from zero, in the direction of
+        // both operands
+        match self.checked_add(other) {
+            Some(x) => x,
+            None if self.is_negative() => MIN,
+            None => MAX,
+        }
+    }
+
+    /// Saturating integer subtraction. Computes `self - other`, saturating
+    /// at the numeric bounds instead of overflowing.
+    #[inline]
+    pub fn saturating_sub(self, other: i128) -> i128 {
+        match self.checked_sub(other) {
+            Some(x) => x,
+            None if self.is_negative() => MIN,
+            None => MAX,
+        }
+    }
+
+    /// Saturating integer multiplication. Computes `self * other`,
+    /// saturating at the numeric bounds instead of overflowing.
+    #[inline]
+    pub fn saturating_mul(self, other: i128) -> i128 {
+        match self.checked_mul(other) {
+            Some(x) => x,
+            None if self.is_negative() != other.is_negative() => MIN,
+            None => MAX,
+        }
+    }
+
+    /// Wrapping (modular) addition. Computes `self + rhs`, wrapping around
+    /// at the boundary of the type.
+    #[inline]
+    pub fn wrapping_add(self, rhs: i128) -> i128 {
+        self.as_u128().wrapping_add(rhs.as_u128()).as_i128()
+    }
+
+    /// Wrapping (modular) subtraction. Computes `self - rhs`, wrapping
+    /// around at the boundary of the type.
+    #[inline]
+    pub fn wrapping_sub(self, rhs: i128) -> i128 {
+        self.as_u128().wrapping_sub(rhs.as_u128()).as_i128()
+    }
+
+    /// Wrapping (modular) multiplication. Computes `self * rhs`, wrapping
+    /// around at the boundary of the type.
+    #[inline]
+    pub fn wrapping_mul(self, rhs: i128) -> i128 {
+        self.as_u128().wrapping_mul(rhs.as_u128()).as_i128()
+    }
+
+    /// Wrapping (modular) division. Computes `self / rhs`, wrapping around
+    /// at the boundary of the type.
+    ///
+    /// The only case where such wrapping can occur is when one divides
+    /// `MIN / -1`, which is equivalent to `-MIN`, a positive value that is
+    /// too large to represent in the type. In such a case, this function
+    /// returns `MIN` itself.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if `rhs` is 0.
+    #[inline]
+    pub fn wrapping_div(self, rhs: i128) -> i128 {
+        self.overflowing_div(rhs).0
+    }
+
+    /// Wrapping (modular) remainder. Computes `self % rhs`, wrapping around
+    /// at the boundary of the type.
+    ///
+    /// Such wrap-around never actually occurs mathematically;
+    /// implementation artifacts make `x % y` invalid for `MIN / -1`. In
+    /// such a case, this function returns `0`.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if `rhs` is 0.
+    #[inline]
+    pub fn wrapping_rem(self, rhs: i128) -> i128 {
+        self.overflowing_rem(rhs).0
+    }
+
+    /// Wrapping (modular) negation. Computes `-self`, wrapping around at
+    /// the boundary of the type.
+    ///
+    /// The only case where such wrapping can occur is when one negates
+    /// `MIN`, which is too large to represent as a positive value. In such
+    /// a case, this function returns `MIN` itself.
+    #[inline]
+    pub fn wrapping_neg(self) -> i128 {
+        self.as_u128().wrapping_neg().as_i128()
+    }
+
+    /// Panic-free bitwise shift-left; yields `self << (rhs % 128)`.
+    #[inline]
+    pub fn wrapping_shl(self, rhs: u32) -> i128 {
+        self.as_u128().wrapping_shl(rhs).as_i128()
+    }
+
+    /// Panic-free bitwise shift-right; yields `self >> (rhs % 128)`, which
+    /// fills the vacated bits with copies of the sign bit.
+    pub fn wrapping_shr(self, rhs: u32) -> i128 {
+        let s = rhs & 127;
+        if s == 0 {
+            self
+        } else if s < 64 {
+            i128::from_parts(self.hi >> s, self.lo >> s | (self.hi as u64) << (64 - s))
+        } else {
+            i128::from_parts(self.hi >> 63, (self.hi >> (s - 64)) as u64)
+        }
+    }
+
+    /// Calculates `self + rhs`. Returns a tuple of the addition along with
+    /// a boolean indicating whether an arithmetic overflow would occur.
+    #[inline]
+    pub fn overflowing_add(self, rhs: i128) -> (i128, bool) {
+        // only the sum of two numbers of the same sign can overflow, and
+        // then it has the other sign
+        let sum = self.wrapping_add(rhs);
+        let overflow = self.is_negative() == rhs.is_negative() &&
+                       sum.is_negative() != self.is_negative();
+        (sum, overflow)
+    }
+
+    /// Calculates `self - rhs`. Returns a tuple of the subtraction along
+    /// with a boolean indicating whether an arithmetic overflow would occur.
+    #[inline]
+    pub fn overflowing_sub(self, rhs: i128) -> (i128, bool) {
+        let diff = self.wrapping_sub(rhs);
+        let overflow = self.is_negative() != rhs.is_negative() &&
+                       diff.is_negative() != self.is_negative();
+        (diff, overflow)
+    }
+
+    /// Calculates the multiplication of `self` and `rhs`. Returns a tuple
+    /// of the multiplication along with a boolean indicating whether an
+    /// arithmetic overflow would occur.
+    pub fn overflowing_mul(self, rhs: i128) -> (i128, bool) {
+        // The product of the magnitudes fits if it is below `2^127`, or is
+        // equal to it for a negative product, which is then `MIN`.
+        let (mag, overflow) = self.unsigned_abs().overflowing_mul(rhs.unsigned_abs());
+        let limit = MIN.as_u128();
+        let overflow = overflow || mag > limit ||
+                       (mag == limit && self.is_negative() == rhs.is_negative());
+        (self.wrapping_mul(rhs), overflow)
+    }
+
+    /// Calculates the divisor when `self` is divided by `rhs`. Returns a
+    /// tuple of the divisor along with a boolean indicating whether an
+    /// arithmetic overflow would occur. If an overflow would occur then
+    /// `self` is returned.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if `rhs` is 0.
+    #[inline]
+    pub fn overflowing_div(self, rhs: i128) -> (i128, bool) {
+        if self == MIN && rhs == i128::from(-1i8) {
+            (self, true)
+        } else {
+            (self / rhs, false)
+        }
+    }
+
+    /// Calculates the remainder when `self` is divided by `rhs`. Returns a
+    /// tuple of the remainder after dividing along with a boolean
+    /// indicating whether an arithmetic overflow would occur. If an
+    /// overflow would occur then 0 is returned.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if `rhs` is 0.
+    #[inline]
+    pub fn overflowing_rem(self, rhs: i128) -> (i128, bool) {
+        if self == MIN && rhs == i128::from(-1i8) {
+            (i128::from(0u8), true)
+        } else {
+            (self % rhs, false)
+        }
+    }
+
+    /// Negates `self`, overflowing if this is equal to the minimum value.
+    ///
+    /// Returns a tuple of the negated version of `self` along with a
+    /// boolean indicating whether an overflow happened. If `self` is the
+    /// minimum value, then the minimum value will be returned again and
+    /// `true` will be returned for an overflow happening.
+    #[inline]
+    pub fn overflowing_neg(self) -> (i128, bool) {
+        (self.wrapping_neg(), self == MIN)
+    }
+
+    /// Shifts `self` left by `rhs` bits. Returns a tuple of the shifted
+    /// version of `self`, with `rhs` masked to 128, along with a boolean
+    /// indicating whether the shift value was larger than or equal to 128.
+    #[inline]
+    pub fn overflowing_shl(self, rhs: u32) -> (i128, bool) {
+        (self.wrapping_shl(rhs), rhs >= 128)
+    }
+
+    /// Shifts `self` right by `rhs` bits. Returns a tuple of the shifted
+    /// version of `self`, with `rhs` masked to 128, along with a boolean
+    /// indicating whether the shift value was larger than or equal to 128.
+    #[inline]
+    pub fn overflowing_shr(self, rhs: u32) -> (i128, bool) {
+        (self.wrapping_shr(rhs), rhs >= 128)
+    }
+
+    /// Raises `self` to the power of `exp`, using exponentiation by
+    /// squaring.
+    ///
+    /// # Panics
+    ///
+    /// Overflows like the arithmetic operators, panicking when debug
+    /// assertions are enabled.
+    pub fn pow(self, mut exp: u32) -> i128 {
+        let mut base = self;
+        let mut acc = i128::from(1u8);
+        while exp > 1 {
+            if (exp & 1) == 1 {
+                acc = acc * base;
+            }
+            exp /= 2;
+            base = base * base;
+        }
+        // Like for the primitives, the base is not squared again after the
+        // last bit of the exponent, which could overflow needlessly.
+        if exp == 1 {
+            acc = acc * base;
+        }
+        acc
+    }
+
+    /// Computes the absolute value of `self`.
+    ///
+    /// # Panics
+    ///
+    /// The absolute value of `MIN` cannot be represented as an `i128`, and
+    /// attempting to calculate it overflows like the arithmetic operators,
+    /// panicking when overflow checks are enabled and returning `MIN`
+    /// otherwise.
+    #[inline]
+    #[rustc_inherit_overflow_checks]
+    pub fn abs(self) -> i128 {
+        if self.is_negative() { -self } else { self }
+    }
+
+    /// Returns a number representing sign of `self`.
+    ///
+    /// - `0` if the number is zero
+    /// - `1` if the number is positive
+    /// - `-1` if the number is negative
+    #[inline]
+    pub fn signum(self) -> i128 {
+        if self.is_positive() {
+            i128::from(1u8)
+        } else if self.is_negative() {
+            i128::from(-1i8)
+        } else {
+            self
+        }
+    }
+
+    /// Returns a number with the magnitude of `self` and the sign of
+    /// `sign`.
+    ///
+    /// As integers have no negative zero, a `sign` of zero counts as
+    /// positive, and a `self` of zero is returned as it is.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `self` is `MIN` and `sign` is not negative, like the
+    /// arithmetic operators.
+    #[inline]
+    pub fn copysign(self, sign: i128) -> i128 {
+        if self.is_negative() == sign.is_negative() { self } else { -self }
+    }
+
+    /// Returns `true` if `self` is positive and `false` if the number
+    /// is zero or negative.
+    #[inline]
+    pub fn is_positive(self) -> bool {
+        self.hi > 0 || (self.hi == 0 && self.lo != 0)
+    }
+
+    /// Returns `true` if `self` is negative and `false` if the number
+    /// is zero or positive.
+    #[inline]
+    pub fn is_negative(self) -> bool {
+        self.hi < 0
+    }
+
+    /// Returns the absolute value of `self` as a `u128`, which can hold
+    /// that of `MIN` too.
+    #[inline]
+    pub fn unsigned_abs(self) -> u128 {
+        if self.is_negative() { self.wrapping_neg().as_u128() } else { self.as_u128() }
+    }
+
+    /// Returns the quotient and the remainder of `self / other`, rounded
+    /// towards zero, for a non-zero `other` and without overflow.
+    fn div_rem(self, other: i128) -> (i128, i128) {
+        let (a, b) = (self.unsigned_abs().to_parts(), other.unsigned_abs().to_parts());
+        let ((qhi, qlo), (rhi, rlo)) = quad::div_rem(a, b);
+        let (q, r) = (u128::from_parts(qhi, qlo).as_i128(), u128::from_parts(rhi, rlo).as_i128());
+        // the remainder has the sign of the dividend
+        let q = if self.is_negative() != other.is_negative() { q.wrapping_neg() } else { q };
+        let r = if self.is_negative() { r.wrapping_neg() } else { r };
+        (q, r)
+    }
+}
+
+fn checked((x, overflow): (i128, bool)) -> Option<i128> {
+    if overflow { None } else { Some(x) }
+}
+
+macro_rules! from_unsigned_impl {
+    ($($t:ty)*) => ($(
+        impl From<$t> for i128 {
+            #[inline]
+            fn from(x: $t) -> i128 {
+                i128::from_parts(0, x as u64)
+            }
+        }
+    )*)
+}
+
+macro_rules! from_signed_impl {
+    ($($t:ty)*) => ($(
+        impl From<$t> for i128 {
+            #[inline]
+            fn from(x: $t) -> i128 {
+                i128::from_parts((x as i64) >> 63, x as i64 as u64)
+            }
+        }
+    )*)
+}
+
+from_unsigned_impl! { u8 u16 u32 u64 usize }
+from_signed_impl! { i8 i16 i32 i64 isize }
+
+impl PartialOrd for i128 {
+    #[inline]
+    fn partial_cmp(&self, other: &i128) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for i128 {
+    #[inline]
+    fn cmp(&self, other: &i128) -> Ordering {
+        self.to_parts().cmp(&other.to_parts())
+    }
+}
+
+// The derived `Hash` would feed the fields in their declaration order, which
+// depends on the endianness.
+impl Hash for i128 {
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.to_parts().hash(state)
+    }
+}
+
+// Overflow is reported through a primitive operation, like for `u128`.
+macro_rules! i128_op_impl {
+    ($($trait_:ident $method:ident $assign_trait:ident $assign_method:ident
+       $overflowing:ident |$o:ident| $report:expr;)*) => ($(
+        impl $trait_ for i128 {
+            type Output = i128;
+
+            #[inline]
+            #[rustc_inherit_overflow_checks]
+            fn $method(self, other: i128) -> i128 {
+                let (x, overflow) = self.$overflowing(other);
+                let $o = overflow as u8;
+                let _ = $report;
+                x
+            }
+        }
+
+        impl $assign_trait for i128 {
+            #[inline]
+            fn $assign_method(&mut self, other: i128) {
+                *self = $trait_::$method(*self, other);
+            }
+        }
+    )*)
+}
+
+i128_op_impl! {
+    Add add AddAssign add_assign overflowing_add |o| o * 0xff + o;
+    Sub sub SubAssign sub_assign overflowing_sub |o| 0 - o;
+    Mul mul MulAssign mul_assign overflowing_mul |o| (o * 0x10) * (o * 0x10);
+}
+
+impl Div for i128 {
+    type Output = i128;
+
+    #[inline]
+    fn div(self, other: i128) -> i128 {
+        if other == i128::from(0u8) {
+            panic!("attempt to divide by zero");
+        } else if self == MIN && other == i128::from(-1i8) {
+            panic!("attempt to divide with overflow");
+        }
+        self.div_rem(other).0
+    }
+}
+
+impl DivAssign for i128 {
+    #[inline]
+    fn div_assign(&mut self, other: i128) {
+        *self = *self / other;
+    }
+}
+
+impl Rem for i128 {
+    type Output = i128;
+
+    #[inline]
+    fn rem(self, other: i128) -> i128 {
+        if other == i128::from(0u8) {
+            panic!("attempt to calculate the remainder with a divisor of zero");
+        } else if self == MIN && other == i128::from(-1i8) {
+            panic!("attempt to calculate the remainder with overflow");
+        }
+        self.div_rem(other).1
+    }
+}
+
+impl RemAssign for i128 {
+    #[inline]
+    fn rem_assign(&mut self, other: i128) {
+        *self = *self % other;
+    }
+}
+
+impl Neg for i128 {
+    type Output = i128;
+
+    #[inline]
+    #[rustc_inherit_overflow_checks]
+    fn neg(self) -> i128 {
+        let (x, overflow) = self.overflowing_neg();
+        // a primitive negation can't inherit the overflow checks, so this
+        // reports it by subtracting instead
+        let _ = 0 - overflow as u8;
+        x
+    }
+}
+
+macro_rules! i128_shift_impl {
+    ($($trait_:ident $method:ident $assign_trait:ident $assign_method:ident
+       $overflowing:ident |$o:ident| $report:expr;)*) => ($(
+        impl $trait_<u32> for i128 {
+            type Output = i128;
+
+            #[inline]
+            #[rustc_inherit_overflow_checks]
+            fn $method(self, rhs: u32) -> i128 {
+                let (x, overflow) = self.$overflowing(rhs);
+                let $o = overflow as u8;
+                let _ = $report;
+                x
+            }
+        }
+
+        impl $assign_trait<u32> for i128 {
+            #[inline]
+            fn $assign_method(&mut self, rhs: u32) {
+                *self = $trait_::$method(*self, rhs);
+            }
+        }
+    )*)
+}
+
+i128_shift_impl! {
+    Shl shl ShlAssign shl_assign overflowing_shl |o| o << (o * 8);
+    Shr shr ShrAssign shr_assign overflowing_shr |o| o >> (o * 8);
+}
+
+macro_rules! i128_bit_impl {
+    ($($trait_:ident $method:ident $assign_trait:ident $assign_method:ident $op:tt)*) => ($(
+        impl $trait_ for i128 {
+            type Output = i128;
+
+            #[inline]
+            fn $method(self, other: i128) -> i128 {
+                i128::from_parts(self.hi $op other.hi, self.lo $op other.lo)
+            }
+        }
+
+        impl $assign_trait for i128 {
+            #[inline]
+            fn $assign_method(&mut self, other: i128) {
+                *self = *self $op other;
+            }
+        }
+    )*)
+}
+
+i128_bit_impl! {
+    BitAnd bitand BitAndAssign bitand_assign &
+    BitOr bitor BitOrAssign bitor_assign |
+    BitXor bitxor BitXorAssign bitxor_assign ^
+}
+
+impl Not for i128 {
+    type Output = i128;
+
+    #[inline]
+    fn not(self) -> i128 {
+        i128::from_parts(!self.hi, !self.lo)
+    }
+}
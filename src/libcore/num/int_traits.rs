@@ -0,0 +1,355 @@
+// Copyright 2016 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Traits abstracting over the primitive integer types.
+
+use clone::Clone;
+use cmp::{Eq, Ord};
+use convert::{AsMut, AsRef};
+use default::Default;
+use fmt;
+use hash::Hash;
+use i128::i128;
+use marker::Copy;
+use mem;
+use ops::{Add, Sub, Mul, Div, Rem, Neg, Not, BitAnd, BitOr, BitXor, Shl, Shr};
+use ops::{AddAssign, SubAssign, MulAssign, DivAssign, RemAssign};
+use ops::{BitAndAssign, BitOrAssign, BitXorAssign, ShlAssign, ShrAssign};
+use option::Option;
+use str::FromStr;
+use u128::u128;
+
+use super::ParseIntError;
+
+/// A primitive integer type.
+///
+/// This trait is implemented for all of the primitive integer types, as well
+/// as `u128` and `i128`, and provides the operations they have in common, so
+/// that algorithms which don't care about the width or signedness of an
+/// integer can be written once. Every method behaves exactly like the
+/// inherent method of the same name.
+///
+/// # Examples
+///
+/// ```
+/// #![feature(associated_consts, int_traits)]
+///
+/// use std::num::PrimInt;
+///
+/// // Appends the values to `out` in little-endian byte order.
+/// fn write_le<T: PrimInt>(values: &[T], out: &mut Vec<u8>) {
+///     for &v in values {
+///         out.extend_from_slice(v.to_le_bytes().as_ref());
+///     }
+/// }
+///
+/// fn count_ones<T: PrimInt>(values: &[T]) -> u32 {
+///     values.iter().map(|v| v.count_ones()).sum()
+/// }
+///
+/// let mut buf = Vec::new();
+/// write_le(&[1u16, 0x0302], &mut buf);
+/// write_le(&[-1i8], &mut buf);
+/// assert_eq!(buf, [1, 0, 2, 3, 0xff]);
+///
+/// assert_eq!(count_ones(&[3u64, u64::max_value()]), 66);
+/// assert_eq!(<u32 as PrimInt>::BITS, 32);
+/// ```
+#[unstable(feature = "int_traits", issue = "0")]
+pub trait PrimInt: Copy + Clone + Default + Eq + Ord + Hash
+    + fmt::Debug + fmt::Display + fmt::Binary + fmt::Octal + fmt::LowerHex + fmt::UpperHex
+    + FromStr<Err = ParseIntError>
+    + Add<Output = Self> + Sub<Output = Self> + Mul<Output = Self>
+    + Div<Output = Self> + Rem<Output = Self>
+    + Not<Output = Self> + BitAnd<Output = Self> + BitOr<Output = Self>
+    + BitXor<Output = Self> + Shl<u32, Output = Self> + Shr<u32, Output = Self>
+    + AddAssign + SubAssign + MulAssign + DivAssign + RemAssign
+    + BitAndAssign + BitOrAssign + BitXorAssign + ShlAssign<u32> + ShrAssign<u32>
+{
+    /// A byte array as large as the type, as used by `to_le_bytes` and
+    /// friends.
+    type Bytes: Copy + Default + AsRef<[u8]> + AsMut<[u8]>;
+
+    /// The size of the type in bits.
+    const BITS: u32;
+    /// The smallest value that can be represented by the type.
+    const MIN: Self;
+    /// The largest value that can be represented by the type.
+    const MAX: Self;
+    /// The value `0`.
+    const ZERO: Self;
+    /// The value `1`.
+    const ONE: Self;
+
+    /// Returns the number of ones in the binary representation of `self`.
+    fn count_ones(self) -> u32;
+    /// Returns the number of zeros in the binary representation of `self`.
+    fn count_zeros(self) -> u32;
+    /// Returns the number of leading zeros in the binary representation of
+    /// `self`.
+    fn leading_zeros(self) -> u32;
+    /// Returns the number of trailing zeros in the binary representation of
+    /// `self`.
+    fn trailing_zeros(self) -> u32;
+    /// Shifts the bits to the left by `n`, wrapping the truncated bits
+    /// around to the end.
+    fn rotate_left(self, n: u32) -> Self;
+    /// Shifts the bits to the right by `n`, wrapping the truncated bits
+    /// around to the beginning.
+    fn rotate_right(self, n: u32) -> Self;
+    /// Reverses the byte order of `self`.
+    fn swap_bytes(self) -> Self;
+    /// Converts `x` from big endian to the target's endianness.
+    fn from_be(x: Self) -> Self;
+    /// Converts `x` from little endian to the target's endianness.
+    fn from_le(x: Self) -> Self;
+    /// Converts `self` to big endian from the target's endianness.
+    fn to_be(self) -> Self;
+    /// Converts `self` to little endian from the target's endianness.
+    fn to_le(self) -> Self;
+    /// Returns the memory representation of `self` in little-endian byte
+    /// order.
+    fn to_le_bytes(self) -> Self::Bytes;
+    /// Returns the memory representation of `self` in big-endian byte
+    /// order.
+    fn to_be_bytes(self) -> Self::Bytes;
+    /// Creates a value from its memory representation in little-endian
+    /// byte order.
+    fn from_le_bytes(bytes: Self::Bytes) -> Self;
+    /// Creates a value from its memory representation in big-endian byte
+    /// order.
+    fn from_be_bytes(bytes: Self::Bytes) -> Self;
+    /// Raises `self` to the power of `exp`.
+    fn pow(self, exp: u32) -> Self;
+
+    /// Checked addition. Returns `None` if overflow occurred.
+    fn checked_add(self, other: Self) -> Option<Self>;
+    /// Checked subtraction. Returns `None` if overflow occurred.
+    fn checked_sub(self, other: Self) -> Option<Self>;
+    /// Checked multiplication. Returns `None` if overflow occurred.
+    fn checked_mul(self, other: Self) -> Option<Self>;
+    /// Checked division. Returns `None` if `other == 0` or the division
+    /// results in overflow.
+    fn checked_div(self, other: Self) -> Option<Self>;
+    /// Checked remainder. Returns `None` if `other == 0` or the division
+    /// results in overflow.
+    fn checked_rem(self, other: Self) -> Option<Self>;
+    /// Checked negation. Returns `None` unless `self == 0` for unsigned
+    /// types, or if `self == MIN` for signed types.
+    fn checked_neg(self) -> Option<Self>;
+    /// Checked shift left. Returns `None` if `rhs` is larger than or equal
+    /// to the number of bits in `self`.
+    fn checked_shl(self, rhs: u32) -> Option<Self>;
+    /// Checked shift right. Returns `None` if `rhs` is larger than or equal
+    /// to the number of bits in `self`.
+    fn checked_shr(self, rhs: u32) -> Option<Self>;
+    /// Saturating addition, saturating at the numeric bounds.
+    fn saturating_add(self, other: Self) -> Self;
+    /// Saturating subtraction, saturating at the numeric bounds.
+    fn saturating_sub(self, other: Self) -> Self;
+    /// Wrapping (modular) addition.
+    fn wrapping_add(self, rhs: Self) -> Self;
+    /// Wrapping (modular) subtraction.
+    fn wrapping_sub(self, rhs: Self) -> Self;
+    /// Wrapping (modular) multiplication.
+    fn wrapping_mul(self, rhs: Self) -> Self;
+    /// Wrapping (modular) negation.
+    fn wrapping_neg(self) -> Self;
+    /// Panic-free bitwise shift-left, masking `rhs` to the type's width.
+    fn wrapping_shl(self, rhs: u32) -> Self;
+    /// Panic-free bitwise shift-right, masking `rhs` to the type's width.
+    fn wrapping_shr(self, rhs: u32) -> Self;
+    /// Calculates `self + rhs`, returning the wrapped result and whether an
+    /// overflow occurred.
+    fn overflowing_add(self, rhs: Self) -> (Self, bool);
+    /// Calculates `self - rhs`, returning the wrapped result and whether an
+    /// overflow occurred.
+    fn overflowing_sub(self, rhs: Self) -> (Self, bool);
+    /// Calculates `self * rhs`, returning the wrapped result and whether an
+    /// overflow occurred.
+    fn overflowing_mul(self, rhs: Self) -> (Self, bool);
+}
+
+/// A primitive signed integer type.
+#[unstable(feature = "int_traits", issue = "0")]
+pub trait Signed: PrimInt + Neg<Output = Self> {
+    /// The unsigned integer type of the same width.
+    type Unsigned: Unsigned<Signed = Self>;
+
+    /// Computes the absolute value of `self`.
+    fn abs(self) -> Self;
+    /// Returns `-1`, `0` or `1` depending on the sign of `self`.
+    fn signum(self) -> Self;
+    /// Returns a number with the magnitude of `self` and the sign of `sign`,
+    /// where zero counts as positive.
+    fn copysign(self, sign: Self) -> Self;
+    /// Returns `true` if `self` is greater than zero.
+    fn is_positive(self) -> bool;
+    /// Returns `true` if `self` is less than zero.
+    fn is_negative(self) -> bool;
+    /// Reinterprets the bits of `self` as the unsigned type of the same
+    /// width.
+    fn to_unsigned(self) -> Self::Unsigned;
+}
+
+/// A primitive unsigned integer type.
+#[unstable(feature = "int_traits", issue = "0")]
+pub trait Unsigned: PrimInt {
+    /// The signed integer type of the same width.
+    type Signed: Signed<Unsigned = Self>;
+
+    /// Returns `true` if `self` is a power of two.
+    fn is_power_of_two(self) -> bool;
+    /// Returns the smallest power of two greater than or equal to `self`.
+    /// Unspecified behavior on overflow.
+    fn next_power_of_two(self) -> Self;
+    /// Returns the smallest power of two greater than or equal to `self`,
+    /// or `None` if it is greater than `MAX`.
+    fn checked_next_power_of_two(self) -> Option<Self>;
+    /// Reinterprets the bits of `self` as the signed type of the same width.
+    fn to_signed(self) -> Self::Signed;
+}
+
+macro_rules! prim_int_impl {
+    (@impl $t:ident, $bytes:expr, $zero:expr, $one:expr) => (
+        #[unstable(feature = "int_traits", issue = "0")]
+        impl PrimInt for $t {
+            type Bytes = [u8; $bytes];
+
+            const BITS: u32 = $bytes * 8;
+            const MIN: $t = ::$t::MIN;
+            const MAX: $t = ::$t::MAX;
+            const ZERO: $t = $zero;
+            const ONE: $t = $one;
+
+            #[inline] fn count_ones(self) -> u32 { self.count_ones() }
+            #[inline] fn count_zeros(self) -> u32 { self.count_zeros() }
+            #[inline] fn leading_zeros(self) -> u32 { self.leading_zeros() }
+            #[inline] fn trailing_zeros(self) -> u32 { self.trailing_zeros() }
+            #[inline] fn rotate_left(self, n: u32) -> $t { self.rotate_left(n) }
+            #[inline] fn rotate_right(self, n: u32) -> $t { self.rotate_right(n) }
+            #[inline] fn swap_bytes(self) -> $t { self.swap_bytes() }
+            #[inline] fn from_be(x: $t) -> $t { $t::from_be(x) }
+            #[inline] fn from_le(x: $t) -> $t { $t::from_le(x) }
+            #[inline] fn to_be(self) -> $t { self.to_be() }
+            #[inline] fn to_le(self) -> $t { self.to_le() }
+            #[inline]
+            fn to_le_bytes(self) -> [u8; $bytes] {
+                unsafe { mem::transmute(self.to_le()) }
+            }
+            #[inline]
+            fn to_be_bytes(self) -> [u8; $bytes] {
+                unsafe { mem::transmute(self.to_be()) }
+            }
+            #[inline]
+            fn from_le_bytes(bytes: [u8; $bytes]) -> $t {
+                $t::from_le(unsafe { mem::transmute(bytes) })
+            }
+            #[inline]
+            fn from_be_bytes(bytes: [u8; $bytes]) -> $t {
+                $t::from_be(unsafe { mem::transmute(bytes) })
+            }
+            #[inline] fn pow(self, exp: u32) -> $t { self.pow(exp) }
+
+            #[inline] fn checked_add(self, other: $t) -> Option<$t> { self.checked_add(other) }
+            #[inline] fn checked_sub(self, other: $t) -> Option<$t> { self.checked_sub(other) }
+            #[inline] fn checked_mul(self, other: $t) -> Option<$t> { self.checked_mul(other) }
+            #[inline] fn checked_div(self, other: $t) -> Option<$t> { self.checked_div(other) }
+            #[inline] fn checked_rem(self, other: $t) -> Option<$t> { self.checked_rem(other) }
+            #[inline] fn checked_neg(self) -> Option<$t> { self.checked_neg() }
+            #[inline] fn checked_shl(self, rhs: u32) -> Option<$t> { self.checked_shl(rhs) }
+            #[inline] fn checked_shr(self, rhs: u32) -> Option<$t> { self.checked_shr(rhs) }
+            #[inline] fn saturating_add(self, other: $t) -> $t { self.saturating_add(other) }
+            #[inline] fn saturating_sub(self, other: $t) -> $t { self.saturating_sub(other) }
+            #[inline] fn wrapping_add(self, rhs: $t) -> $t { self.wrapping_add(rhs) }
+            #[inline] fn wrapping_sub(self, rhs: $t) -> $t { self.wrapping_sub(rhs) }
+            #[inline] fn wrapping_mul(self, rhs: $t) -> $t { self.wrapping_mul(rhs) }
+            #[inline] fn wrapping_neg(self) -> $t { self.wrapping_neg() }
+            #[inline] fn wrapping_shl(self, rhs: u32) -> $t { self.wrapping_shl(rhs) }
+            #[inline] fn wrapping_shr(self, rhs: u32) -> $t { self.wrapping_shr(rhs) }
+            #[inline]
+            fn overflowing_add(self, rhs: $t) -> ($t, bool) { self.overflowing_add(rhs) }
+            #[inline]
+            fn overflowing_sub(self, rhs: $t) -> ($t, bool) { self.overflowing_sub(rhs) }
+            #[inline]
+            fn overflowing_mul(self, rhs: $t) -> ($t, bool) { self.overflowing_mul(rhs) }
+        }
+    );
+    ($($t:ident, $bytes:expr;)*) => ($(
+        prim_int_impl! { @impl $t, $bytes, 0, 1 }
+    )*)
+}
+
+macro_rules! signed_unsigned_impl {
+    ($($i:ident, $u:ident;)*) => ($(
+        #[unstable(feature = "int_traits", issue = "0")]
+        impl Signed for $i {
+            type Unsigned = $u;
+
+            #[inline] fn abs(self) -> $i { self.abs() }
+            #[inline] fn signum(self) -> $i { self.signum() }
+            #[inline] fn copysign(self, sign: $i) -> $i { self.copysign(sign) }
+            #[inline] fn is_positive(self) -> bool { self.is_positive() }
+            #[inline] fn is_negative(self) -> bool { self.is_negative() }
+            #[inline] fn to_unsigned(self) -> $u { self as $u }
+        }
+
+        #[unstable(feature = "int_traits", issue = "0")]
+        impl Unsigned for $u {
+            type Signed = $i;
+
+            #[inline] fn is_power_of_two(self) -> bool { self.is_power_of_two() }
+            #[inline] fn next_power_of_two(self) -> $u { self.next_power_of_two() }
+            #[inline]
+            fn checked_next_power_of_two(self) -> Option<$u> {
+                self.checked_next_power_of_two()
+            }
+            #[inline] fn to_signed(self) -> $i { self as $i }
+        }
+    )*)
+}
+
+prim_int_impl! { u8, 1; u16, 2; u32, 4; u64, 8; i8, 1; i16, 2; i32, 4; i64, 8; }
+#[cfg(target_pointer_width = "16")]
+prim_int_impl! { usize, 2; isize, 2; }
+#[cfg(target_pointer_width = "32")]
+prim_int_impl! { usize, 4; isize, 4; }
+#[cfg(target_pointer_width = "64")]
+prim_int_impl! { usize, 8; isize, 8; }
+
+signed_unsigned_impl! { i8, u8; i16, u16; i32, u32; i64, u64; isize, usize; }
+
+prim_int_impl! { @impl u128, 16, u128::from_parts(0, 0), u128::from_parts(0, 1) }
+prim_int_impl! { @impl i128, 16, i128::from_parts(0, 0), i128::from_parts(0, 1) }
+
+#[unstable(feature = "int_traits", issue = "0")]
+impl Signed for i128 {
+    type Unsigned = u128;
+
+    #[inline] fn abs(self) -> i128 { self.abs() }
+    #[inline] fn signum(self) -> i128 { self.signum() }
+    #[inline] fn copysign(self, sign: i128) -> i128 { self.copysign(sign) }
+    #[inline] fn is_positive(self) -> bool { self.is_positive() }
+    #[inline] fn is_negative(self) -> bool { self.is_negative() }
+    #[inline] fn to_unsigned(self) -> u128 { self.as_u128() }
+}
+
+#[unstable(feature = "int_traits", issue = "0")]
+impl Unsigned for u128 {
+    type Signed = i128;
+
+    #[inline] fn is_power_of_two(self) -> bool { self.is_power_of_two() }
+    #[inline] fn next_power_of_two(self) -> u128 { self.next_power_of_two() }
+    #[inline]
+    fn checked_next_power_of_two(self) -> Option<u128> {
+        self.checked_next_power_of_two()
+    }
+    #[inline] fn to_signed(self) -> i128 { self.as_i128() }
+}
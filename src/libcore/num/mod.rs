@@ -13,10 +13,11 @@
 #![stable(feature = "rust1", since = "1.0.0")]
 
 use char::CharExt;
-use cmp::PartialOrd;
+use cmp::{Ordering, PartialOrd};
 use convert::{From, TryFrom};
 use fmt;
 use intrinsics;
+use iter::Iterator;
 use marker::{Copy, Sized};
 use mem::size_of;
 use option::Option::{self, Some, None};
@@ -93,7 +94,87 @@ impl<T: fmt::UpperHex> fmt::UpperHex for Wrapping<T> {
     }
 }
 
+/// Provides intentionally-saturated arithmetic on `T`.
+///
+/// Where `Wrapping<T>` wraps around on overflow, the standard arithmetic
+/// operations on `Saturating<T>` stop at the numeric bounds of `T`
+/// instead, like the `saturating_add` family of methods.
+///
+/// # Examples
+///
+/// ```
+/// #![feature(saturating_int)]
+/// use std::num::Saturating;
+///
+/// let max = Saturating(u32::max_value());
+/// let one = Saturating(1u32);
+///
+/// assert_eq!(u32::max_value(), (max + one).0);
+/// assert_eq!(0, (one - max).0);
+/// ```
+#[unstable(feature = "saturating_int", issue = "0")]
+#[derive(PartialEq, Eq, PartialOrd, Ord, Clone, Copy, Default, Hash)]
+pub struct Saturating<T>(#[unstable(feature = "saturating_int", issue = "0")] pub T);
+
+#[unstable(feature = "saturating_int", issue = "0")]
+impl<T: fmt::Debug> fmt::Debug for Saturating<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+#[unstable(feature = "saturating_int", issue = "0")]
+impl<T: fmt::Display> fmt::Display for Saturating<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+/// Formats a float like `Debug`, except that NaNs show their sign and
+/// payload.
+///
+/// `Debug` prints every NaN as `NaN`. Through `NanPayload`, a NaN is printed
+/// in the syntax that `FromStr` accepts, like `-NaN(0x1f)`, so that parsing
+/// the output gives back the same NaN. Other values are printed as `Debug`
+/// prints them.
+///
+/// # Examples
+///
+/// ```
+/// #![feature(float_nan_payload)]
+/// use std::f64;
+/// use std::num::NanPayload;
+///
+/// let x = -f64::nan_with_payload(0x1f);
+/// assert_eq!(format!("{:?}", x), "NaN");
+/// assert_eq!(format!("{:?}", NanPayload(x)), "-NaN(0x1f)");
+/// assert_eq!(format!("{:?}", NanPayload(1.5f64)), "1.5");
+/// ```
+#[unstable(feature = "float_nan_payload", issue = "0")]
+#[derive(PartialEq, PartialOrd, Clone, Copy, Default)]
+pub struct NanPayload<T>(#[unstable(feature = "float_nan_payload", issue = "0")] pub T);
+
 mod wrapping;
+mod saturating;
+mod total_ord;
+mod int_traits;
+#[cfg(not(any(target_arch = "aarch64", target_feature = "fma",
+              all(target_arch = "arm", target_feature = "vfp4"))))]
+mod fma;
+mod swar;
+
+#[unstable(feature = "float_total_ord", issue = "0")]
+pub use self::total_ord::{TotalOrdF32, TotalOrdF64};
+#[unstable(feature = "f16", issue = "0")]
+pub use f16::f16;
+#[unstable(feature = "f128", issue = "0")]
+pub use f128::f128;
+#[unstable(feature = "i128", issue = "0")]
+pub use u128::u128;
+#[unstable(feature = "i128", issue = "0")]
+pub use i128::i128;
+#[unstable(feature = "int_traits", issue = "0")]
+pub use self::int_traits::{PrimInt, Signed, Unsigned};
 
 // All these modules are technically private and only exposed for libcoretest:
 pub mod flt2dec;
@@ -101,6 +182,13 @@ pub mod dec2flt;
 pub mod bignum;
 pub mod diy_float;
 
+// This one is shared with the `f128`, `u128` and `i128` modules outside of `num`:
+pub mod quad;
+
+pub mod fp;
+pub mod fenv;
+pub mod convert;
+
 /// Types that have a "zero" value.
 ///
 /// This trait is intended for use in conjunction with `Add`, as an identity:
@@ -225,7 +313,120 @@ macro_rules! int_impl {
         /// ```
         #[stable(feature = "rust1", since = "1.0.0")]
         pub fn from_str_radix(src: &str, radix: u32) -> Result<Self, ParseIntError> {
-            from_str_radix(src, radix)
+            from_ascii_radix(src.as_bytes(), radix, false)
+        }
+
+        /// Converts ASCII bytes in base 10 to an integer, like `from_str`
+        /// does from a string.
+        ///
+        /// This saves going through `str::from_utf8` first when parsing
+        /// from a byte buffer.
+        ///
+        /// # Examples
+        ///
+        /// Basic usage:
+        ///
+        /// ```
+        /// #![feature(num_from_ascii)]
+        ///
+        /// assert_eq!(i32::from_ascii(b"-12"), Ok(-12));
+        /// assert!(i32::from_ascii(b"12\xff").is_err());
+        /// ```
+        #[unstable(feature = "num_from_ascii", issue = "0")]
+        pub fn from_ascii(src: &[u8]) -> Result<Self, ParseIntError> {
+            from_ascii_radix(src, 10, false)
+        }
+
+        /// Converts ASCII bytes in a given base to an integer, like
+        /// `from_str_radix` does from a string.
+        ///
+        /// # Panics
+        ///
+        /// This function panics if `radix` is not in the range from 2 to 36.
+        ///
+        /// # Examples
+        ///
+        /// Basic usage:
+        ///
+        /// ```
+        /// #![feature(num_from_ascii)]
+        ///
+        /// assert_eq!(i32::from_ascii_radix(b"A", 16), Ok(10));
+        /// ```
+        #[unstable(feature = "num_from_ascii", issue = "0")]
+        pub fn from_ascii_radix(src: &[u8], radix: u32) -> Result<Self, ParseIntError> {
+            from_ascii_radix(src, radix, false)
+        }
+
+        /// Converts a string slice in a given base to an integer, like
+        /// `from_str_radix`, but also accepts underscores between digits, as
+        /// in integer literals.
+        ///
+        /// Unlike in literals, each underscore must be between two digits.
+        ///
+        /// # Panics
+        ///
+        /// This function panics if `radix` is not in the range from 2 to 36.
+        ///
+        /// # Examples
+        ///
+        /// Basic usage:
+        ///
+        /// ```
+        /// #![feature(parse_underscores)]
+        ///
+        /// assert_eq!(i32::from_str_radix_lenient("1_000_000", 10), Ok(1000000));
+        /// assert_eq!(i32::from_str_radix_lenient("ff_ff", 16), Ok(0xffff));
+        /// assert!(i32::from_str_radix_lenient("1__000", 10).is_err());
+        /// assert!(i32::from_str_radix_lenient("_1", 10).is_err());
+        /// ```
+        #[unstable(feature = "parse_underscores", issue = "0")]
+        pub fn from_str_radix_lenient(src: &str, radix: u32) -> Result<Self, ParseIntError> {
+            from_ascii_radix(src.as_bytes(), radix, true)
+        }
+
+        /// Parses the longest prefix of `src` that is an integer in base 10,
+        /// and returns it with the number of bytes it takes up.
+        ///
+        /// The rest of the string is not looked at, so a parser can continue
+        /// right after the number. An error is returned if there is no such
+        /// prefix, or if its value does not fit into the type.
+        ///
+        /// # Examples
+        ///
+        /// Basic usage:
+        ///
+        /// ```
+        /// #![feature(from_str_partial)]
+        ///
+        /// assert_eq!(i32::from_str_partial("12, 34"), Ok((12, 2)));
+        /// assert!(i32::from_str_partial("x12").is_err());
+        /// ```
+        #[unstable(feature = "from_str_partial", issue = "0")]
+        pub fn from_str_partial(src: &str) -> Result<(Self, usize), ParseIntError> {
+            from_str_radix_partial(src, 10)
+        }
+
+        /// Parses the longest prefix of `src` that is an integer in the given
+        /// base, like `from_str_partial`.
+        ///
+        /// # Panics
+        ///
+        /// This function panics if `radix` is not in the range from 2 to 36.
+        ///
+        /// # Examples
+        ///
+        /// Basic usage:
+        ///
+        /// ```
+        /// #![feature(from_str_partial)]
+        ///
+        /// assert_eq!(i32::from_str_radix_partial("-ff;", 16), Ok((-255, 3)));
+        /// ```
+        #[unstable(feature = "from_str_partial", issue = "0")]
+        pub fn from_str_radix_partial(src: &str, radix: u32)
+                                      -> Result<(Self, usize), ParseIntError> {
+            from_str_radix_partial(src, radix)
         }
 
         /// Returns the number of ones in the binary representation of `self`.
@@ -1163,6 +1364,46 @@ macro_rules! int_impl {
             }
         }
 
+        /// Returns a number with the magnitude of `self` and the sign of
+        /// `sign`.
+        ///
+        /// As integers have no negative zero, a `sign` of zero counts as
+        /// positive, and a `self` of zero is returned as it is. This matches
+        /// `copysign` for floats on everything but `-0.0`.
+        ///
+        /// # Overflow behavior
+        ///
+        /// Like `abs`, the magnitude of `i32::min_value()` cannot be
+        /// represented as a positive `i32`. Giving it a non-negative `sign`
+        /// will cause an overflow, which panics in debug mode and returns
+        /// `i32::min_value()` in optimized code.
+        ///
+        /// # Examples
+        ///
+        /// Basic usage:
+        ///
+        /// ```
+        /// #![feature(copysign)]
+        ///
+        /// assert_eq!(10i8.copysign(-3), -10);
+        /// assert_eq!((-10i8).copysign(3), 10);
+        /// assert_eq!((-10i8).copysign(0), 10);
+        /// assert_eq!(i8::min_value().copysign(-1), i8::min_value());
+        /// ```
+        #[unstable(feature = "copysign", issue = "0")]
+        #[inline]
+        #[rustc_inherit_overflow_checks]
+        pub fn copysign(self, sign: Self) -> Self {
+            if self.is_negative() == sign.is_negative() {
+                self
+            } else {
+                // Note that the #[inline] above means that the overflow
+                // semantics of this negation depend on the crate we're being
+                // inlined into.
+                -self
+            }
+        }
+
         /// Returns `true` if `self` is positive and `false` if the number
         /// is zero or negative.
         ///
@@ -1299,7 +1540,120 @@ macro_rules! uint_impl {
         /// ```
         #[stable(feature = "rust1", since = "1.0.0")]
         pub fn from_str_radix(src: &str, radix: u32) -> Result<Self, ParseIntError> {
-            from_str_radix(src, radix)
+            from_ascii_radix(src.as_bytes(), radix, false)
+        }
+
+        /// Converts ASCII bytes in base 10 to an integer, like `from_str`
+        /// does from a string.
+        ///
+        /// This saves going through `str::from_utf8` first when parsing
+        /// from a byte buffer.
+        ///
+        /// # Examples
+        ///
+        /// Basic usage:
+        ///
+        /// ```
+        /// #![feature(num_from_ascii)]
+        ///
+        /// assert_eq!(u32::from_ascii(b"12"), Ok(12));
+        /// assert!(u32::from_ascii(b"12\xff").is_err());
+        /// ```
+        #[unstable(feature = "num_from_ascii", issue = "0")]
+        pub fn from_ascii(src: &[u8]) -> Result<Self, ParseIntError> {
+            from_ascii_radix(src, 10, false)
+        }
+
+        /// Converts ASCII bytes in a given base to an integer, like
+        /// `from_str_radix` does from a string.
+        ///
+        /// # Panics
+        ///
+        /// This function panics if `radix` is not in the range from 2 to 36.
+        ///
+        /// # Examples
+        ///
+        /// Basic usage:
+        ///
+        /// ```
+        /// #![feature(num_from_ascii)]
+        ///
+        /// assert_eq!(u32::from_ascii_radix(b"A", 16), Ok(10));
+        /// ```
+        #[unstable(feature = "num_from_ascii", issue = "0")]
+        pub fn from_ascii_radix(src: &[u8], radix: u32) -> Result<Self, ParseIntError> {
+            from_ascii_radix(src, radix, false)
+        }
+
+        /// Converts a string slice in a given base to an integer, like
+        /// `from_str_radix`, but also accepts underscores between digits, as
+        /// in integer literals.
+        ///
+        /// Unlike in literals, each underscore must be between two digits.
+        ///
+        /// # Panics
+        ///
+        /// This function panics if `radix` is not in the range from 2 to 36.
+        ///
+        /// # Examples
+        ///
+        /// Basic usage:
+        ///
+        /// ```
+        /// #![feature(parse_underscores)]
+        ///
+        /// assert_eq!(u32::from_str_radix_lenient("1_000_000", 10), Ok(1000000));
+        /// assert_eq!(u32::from_str_radix_lenient("ff_ff", 16), Ok(0xffff));
+        /// assert!(u32::from_str_radix_lenient("1__000", 10).is_err());
+        /// assert!(u32::from_str_radix_lenient("_1", 10).is_err());
+        /// ```
+        #[unstable(feature = "parse_underscores", issue = "0")]
+        pub fn from_str_radix_lenient(src: &str, radix: u32) -> Result<Self, ParseIntError> {
+            from_ascii_radix(src.as_bytes(), radix, true)
+        }
+
+        /// Parses the longest prefix of `src` that is an integer in base 10,
+        /// and returns it with the number of bytes it takes up.
+        ///
+        /// The rest of the string is not looked at, so a parser can continue
+        /// right after the number. An error is returned if there is no such
+        /// prefix, or if its value does not fit into the type.
+        ///
+        /// # Examples
+        ///
+        /// Basic usage:
+        ///
+        /// ```
+        /// #![feature(from_str_partial)]
+        ///
+        /// assert_eq!(u32::from_str_partial("12, 34"), Ok((12, 2)));
+        /// assert!(u32::from_str_partial("x12").is_err());
+        /// ```
+        #[unstable(feature = "from_str_partial", issue = "0")]
+        pub fn from_str_partial(src: &str) -> Result<(Self, usize), ParseIntError> {
+            from_str_radix_partial(src, 10)
+        }
+
+        /// Parses the longest prefix of `src` that is an integer in the given
+        /// base, like `from_str_partial`.
+        ///
+        /// # Panics
+        ///
+        /// This function panics if `radix` is not in the range from 2 to 36.
+        ///
+        /// # Examples
+        ///
+        /// Basic usage:
+        ///
+        /// ```
+        /// #![feature(from_str_partial)]
+        ///
+        /// assert_eq!(u32::from_str_radix_partial("+ff;", 16), Ok((255, 3)));
+        /// ```
+        #[unstable(feature = "from_str_partial", issue = "0")]
+        pub fn from_str_radix_partial(src: &str, radix: u32)
+                                      -> Result<(Self, usize), ParseIntError> {
+            from_str_radix_partial(src, radix)
         }
 
         /// Returns the number of ones in the binary representation of `self`.
@@ -2332,6 +2686,37 @@ pub enum FpCategory {
     Normal,
 }
 
+/// A rounding-direction attribute, deciding which of the two nearest
+/// representable values an inexact result is rounded to.
+///
+/// The names follow IEEE 754.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+#[unstable(feature = "float_rounding_mode", issue = "0")]
+pub enum RoundingMode {
+    /// Round to the nearest value; ties go to the one with an even last digit.
+    TiesToEven,
+
+    /// Round to the nearest value; ties go to the one with the larger magnitude.
+    TiesToAway,
+
+    /// Round toward zero, i.e. truncate.
+    TowardZero,
+
+    /// Round toward positive infinity.
+    TowardPositive,
+
+    /// Round toward negative infinity.
+    TowardNegative,
+
+    /// Round to the neighbour with an odd last digit, unless the value is
+    /// exact.
+    ///
+    /// This keeps enough information to round again: a result rounded to odd
+    /// with at least two more digits than needed rounds to the nearest like
+    /// the exact value would.
+    ToOdd,
+}
+
 /// A built-in floating point number.
 #[doc(hidden)]
 #[unstable(feature = "core_float",
@@ -2443,6 +2828,122 @@ pub trait Float: Sized {
     /// Convert degrees to radians.
     #[stable(feature = "deg_rad_conversions", since="1.7.0")]
     fn to_radians(self) -> Self;
+
+    /// Parses a decimal string like `FromStr`, but fails if the value of the
+    /// string isn't exactly representable.
+    #[unstable(feature = "float_from_str_exact", issue = "0")]
+    fn from_str_exact(src: &str) -> Result<Self, ParseFloatError>;
+
+    /// Parses a decimal string like `FromStr`, but rounds its value as
+    /// `mode` says rather than to the nearest.
+    #[unstable(feature = "float_from_str_rounded", issue = "0")]
+    fn from_str_rounded(src: &str, mode: RoundingMode) -> Result<Self, ParseFloatError>;
+
+    /// Parses a string of digits in the given radix, with an optional sign
+    /// and an optional point, into the nearest float.
+    #[unstable(feature = "float_from_str_radix", issue = "0")]
+    fn from_str_radix(src: &str, radix: u32) -> Result<Self, ParseFloatError>;
+
+    /// Parses the longest prefix of a string that is a float, and returns it
+    /// with the number of bytes it takes up.
+    #[unstable(feature = "from_str_partial", issue = "0")]
+    fn from_str_partial(src: &str) -> Result<(Self, usize), ParseFloatError>;
+
+    /// Parses a float from ASCII bytes, like `FromStr` does from a string.
+    #[unstable(feature = "num_from_ascii", issue = "0")]
+    fn from_ascii(src: &[u8]) -> Result<Self, ParseFloatError>;
+
+    /// Parses a decimal string in the syntax given by `options`.
+    #[unstable(feature = "float_parse_options", issue = "0")]
+    fn from_str_with(src: &str, options: &ParseFloatOptions) -> Result<Self, ParseFloatError>;
+
+    /// Returns the largest integer less than or equal to a number.
+    #[unstable(feature = "core_float_rounding", issue = "0")]
+    fn floor(self) -> Self;
+    /// Returns the smallest integer greater than or equal to a number.
+    #[unstable(feature = "core_float_rounding", issue = "0")]
+    fn ceil(self) -> Self;
+    /// Returns the integer part of a number.
+    #[unstable(feature = "core_float_rounding", issue = "0")]
+    fn trunc(self) -> Self;
+    /// Returns the nearest integer to a number, rounding half-way cases away
+    /// from `0.0`.
+    #[unstable(feature = "core_float_rounding", issue = "0")]
+    fn round(self) -> Self;
+    /// Returns the nearest integer to a number, rounding half-way cases to
+    /// the even integer.
+    #[unstable(feature = "round_ties_even", issue = "0")]
+    fn round_ties_even(self) -> Self;
+    /// Returns the fractional part of a number.
+    #[unstable(feature = "core_float_rounding", issue = "0")]
+    fn fract(self) -> Self;
+
+    /// Returns a number with the magnitude of `self` and the sign of `sign`,
+    /// including the sign of zeroes and NaNs.
+    #[unstable(feature = "copysign", issue = "0")]
+    fn copysign(self, sign: Self) -> Self;
+
+    /// Returns the IEEE 754 encoding of `self`, in the low bits of the `u64`
+    /// for types narrower than 64 bits.
+    #[unstable(feature = "float_bits", issue = "0")]
+    fn to_bits(self) -> u64;
+    /// Returns the float with the IEEE 754 encoding `bits`, panicking if
+    /// `bits` has more bits than the type.
+    #[unstable(feature = "float_bits", issue = "0")]
+    fn from_bits(bits: u64) -> Self;
+
+    /// Returns a positive quiet NaN with the given payload in the low bits
+    /// of its significand, panicking if the payload does not fit below the
+    /// quiet bit.
+    #[unstable(feature = "float_nan_payload", issue = "0")]
+    fn nan_with_payload(payload: u64) -> Self;
+    /// Returns the payload of a NaN, which is its significand without the
+    /// quiet bit, or `None` if `self` is not NaN.
+    #[unstable(feature = "float_nan_payload", issue = "0")]
+    fn payload(self) -> Option<u64>;
+    /// Writes `self` into `buf` as `Display` does without a precision, with
+    /// the shortest digits that read back as `self`. Returns the written
+    /// string, or `None` if `buf` is too short.
+    #[unstable(feature = "float_write_buf", issue = "0")]
+    fn write_shortest(self, buf: &mut [u8]) -> Option<&str>;
+    /// Writes `self` into `buf` as `Display` does with a precision of
+    /// `precision`. Returns the written string, or `None` if `buf` is too
+    /// short.
+    #[unstable(feature = "float_write_buf", issue = "0")]
+    fn write_fixed(self, buf: &mut [u8], precision: usize) -> Option<&str>;
+    /// Restricts a value to the interval `[min, max]`, panicking if
+    /// `min > max` or either bound is NaN.
+    #[unstable(feature = "float_clamp", issue = "0")]
+    fn clamp(self, min: Self, max: Self) -> Self;
+    /// Restricts a value to the interval `[min, max]` in the total order of
+    /// the `TotalOrd` wrappers, panicking if `min > max` in that order.
+    #[unstable(feature = "float_clamp", issue = "0")]
+    fn clamp_total(self, min: Self, max: Self) -> Self;
+    /// Compares `self` and `other` in the `totalOrder` of IEEE 754, which
+    /// orders the encodings rather than the values: negative NaNs, `-inf`,
+    /// the negative numbers, `-0.0`, `0.0`, the positive numbers, `inf` and
+    /// then positive NaNs, with NaNs ordered by their payload.
+    #[unstable(feature = "total_cmp", issue = "0")]
+    fn total_cmp(&self, other: &Self) -> Ordering;
+
+    /// Returns `(exp, mantissa)` such that `abs(self) = mantissa * 2^exp`,
+    /// with the hidden bit of normal numbers made explicit.
+    #[unstable(feature = "float_exp_mantissa", issue = "0")]
+    fn to_exp_mantissa(self) -> (i32, u64);
+    /// Returns the correctly rounded value of `mantissa * 2^exp`.
+    #[unstable(feature = "float_exp_mantissa", issue = "0")]
+    fn from_exp_mantissa(exp: i32, mantissa: u64) -> Self;
+    /// Returns the correctly rounded value of `self * 2^exp`.
+    #[unstable(feature = "float_exp_mantissa", issue = "0")]
+    fn ldexp(self, exp: i32) -> Self;
+    /// Returns the exponent of the highest set bit of `abs(self)`, i.e.
+    /// `floor(log2(abs(self)))`.
+    #[unstable(feature = "float_exp_mantissa", issue = "0")]
+    fn ilogb(self) -> i32;
+
+    /// Returns `self * a + b`, rounded only once.
+    #[unstable(feature = "core_float_mul_add", issue = "0")]
+    fn mul_add(self, a: Self, b: Self) -> Self;
 }
 
 macro_rules! from_str_radix_int_impl {
@@ -2451,13 +2952,90 @@ macro_rules! from_str_radix_int_impl {
         impl FromStr for $t {
             type Err = ParseIntError;
             fn from_str(src: &str) -> Result<Self, ParseIntError> {
-                from_str_radix(src, 10)
+                from_ascii_radix(src.as_bytes(), 10, false)
             }
         }
     )*}
 }
 from_str_radix_int_impl! { isize i8 i16 i32 i64 usize u8 u16 u32 u64 }
 
+// `u128` and `i128` are not primitives, so their parsing is implemented here, where
+// `from_ascii_radix` and the fields of `ParseIntError` are visible.
+macro_rules! from_str_radix_wide_impl {
+    ($($t:ident)*) => {$(
+        impl $t {
+            /// Converts a string slice in a given base to an integer.
+            ///
+            /// Leading and trailing whitespace represent an error.
+            ///
+            /// # Panics
+            ///
+            /// This function panics if `radix` is not in the range from 2 to 36.
+            #[unstable(feature = "i128", issue = "0")]
+            pub fn from_str_radix(src: &str, radix: u32) -> Result<$t, ParseIntError> {
+                from_ascii_radix(src.as_bytes(), radix, false)
+            }
+
+            /// Converts ASCII bytes in base 10 to an integer, like `from_str`
+            /// does from a string.
+            #[unstable(feature = "num_from_ascii", issue = "0")]
+            pub fn from_ascii(src: &[u8]) -> Result<$t, ParseIntError> {
+                from_ascii_radix(src, 10, false)
+            }
+
+            /// Converts ASCII bytes in a given base to an integer, like
+            /// `from_str_radix` does from a string.
+            ///
+            /// # Panics
+            ///
+            /// This function panics if `radix` is not in the range from 2 to 36.
+            #[unstable(feature = "num_from_ascii", issue = "0")]
+            pub fn from_ascii_radix(src: &[u8], radix: u32) -> Result<$t, ParseIntError> {
+                from_ascii_radix(src, radix, false)
+            }
+
+            /// Converts a string slice in a given base to an integer, like
+            /// `from_str_radix`, but also accepts underscores between digits.
+            ///
+            /// # Panics
+            ///
+            /// This function panics if `radix` is not in the range from 2 to 36.
+            #[unstable(feature = "parse_underscores", issue = "0")]
+            pub fn from_str_radix_lenient(src: &str, radix: u32) -> Result<$t, ParseIntError> {
+                from_ascii_radix(src.as_bytes(), radix, true)
+            }
+
+            /// Parses the longest prefix of `src` that is an integer in base
+            /// 10, and returns it with the number of bytes it takes up.
+            #[unstable(feature = "from_str_partial", issue = "0")]
+            pub fn from_str_partial(src: &str) -> Result<($t, usize), ParseIntError> {
+                from_str_radix_partial(src, 10)
+            }
+
+            /// Parses the longest prefix of `src` that is an integer in the
+            /// given base, like `from_str_partial`.
+            ///
+            /// # Panics
+            ///
+            /// This function panics if `radix` is not in the range from 2 to 36.
+            #[unstable(feature = "from_str_partial", issue = "0")]
+            pub fn from_str_radix_partial(src: &str, radix: u32)
+                                          -> Result<($t, usize), ParseIntError> {
+                from_str_radix_partial(src, radix)
+            }
+        }
+
+        #[unstable(feature = "i128", issue = "0")]
+        impl FromStr for $t {
+            type Err = ParseIntError;
+            fn from_str(src: &str) -> Result<Self, ParseIntError> {
+                from_ascii_radix(src.as_bytes(), 10, false)
+            }
+        }
+    )*}
+}
+from_str_radix_wide_impl! { u128 i128 }
+
 /// The error type returned when a checked integral type conversion fails.
 #[unstable(feature = "try_from", issue = "33417")]
 #[derive(Debug, Copy, Clone)]
@@ -2577,8 +3155,26 @@ macro_rules! doit {
 }
 doit! { i8 i16 i32 i64 isize u8 u16 u32 u64 usize }
 
-fn from_str_radix<T: FromStrRadixHelper>(src: &str, radix: u32)
-                                         -> Result<T, ParseIntError> {
+macro_rules! doit_wide {
+    ($($t:ident)*) => ($(impl FromStrRadixHelper for $t {
+        fn min_value() -> Self { $t::min_value() }
+        fn max_value() -> Self { $t::max_value() }
+        fn from_u32(u: u32) -> Self { $t::from(u) }
+        fn checked_mul(&self, other: u32) -> Option<Self> {
+            $t::checked_mul(*self, $t::from(other))
+        }
+        fn checked_sub(&self, other: u32) -> Option<Self> {
+            $t::checked_sub(*self, $t::from(other))
+        }
+        fn checked_add(&self, other: u32) -> Option<Self> {
+            $t::checked_add(*self, $t::from(other))
+        }
+    })*)
+}
+doit_wide! { u128 i128 }
+
+fn from_ascii_radix<T: FromStrRadixHelper>(src: &[u8], radix: u32, underscores: bool)
+                                           -> Result<T, ParseIntError> {
     use self::IntErrorKind::*;
     use self::ParseIntError as PIE;
 
@@ -2592,11 +3188,10 @@ fn from_str_radix<T: FromStrRadixHelper>(src: &str, radix: u32)
 
     let is_signed_ty = T::from_u32(0) > T::min_value();
 
-    // all valid digits are ascii, so we will just iterate over the utf8 bytes
+    // all valid digits are ascii, so we will just iterate over the bytes
     // and cast them to chars. .to_digit() will safely return None for anything
-    // other than a valid ascii digit for the given radix, including the first-byte
-    // of multi-byte sequences
-    let src = src.as_bytes();
+    // other than a valid ascii digit for the given radix, including the bytes
+    // of multi-byte sequences in utf8 input
 
     let (is_positive, digits) = match src[0] {
         b'+' => (true, &src[1..]),
@@ -2608,10 +3203,23 @@ fn from_str_radix<T: FromStrRadixHelper>(src: &str, radix: u32)
         return Err(PIE { kind: Empty });
     }
 
+    // an underscore is skipped if it is allowed and between two digits
+    let is_separator = |i: usize| {
+        underscores && digits[i] == b'_' && i > 0 && i + 1 < digits.len() &&
+        (digits[i - 1] as char).is_digit(radix) && (digits[i + 1] as char).is_digit(radix)
+    };
+
+    if radix == 10 && !underscores && size_of::<T>() >= 4 {
+        return from_ascii_decimal(is_positive, digits);
+    }
+
     let mut result = T::from_u32(0);
     if is_positive {
         // The number is positive
-        for &c in digits {
+        for (i, &c) in digits.iter().enumerate() {
+            if is_separator(i) {
+                continue;
+            }
             let x = match (c as char).to_digit(radix) {
                 Some(x) => x,
                 None => return Err(PIE { kind: InvalidDigit }),
@@ -2627,7 +3235,10 @@ fn from_str_radix<T: FromStrRadixHelper>(src: &str, radix: u32)
         }
     } else {
         // The number is negative
-        for &c in digits {
+        for (i, &c) in digits.iter().enumerate() {
+            if is_separator(i) {
+                continue;
+            }
             let x = match (c as char).to_digit(radix) {
                 Some(x) => x,
                 None => return Err(PIE { kind: InvalidDigit }),
@@ -2645,6 +3256,63 @@ fn from_str_radix<T: FromStrRadixHelper>(src: &str, radix: u32)
     Ok(result)
 }
 
+/// The base 10 case of `from_ascii_radix` for types of at least 32 bits, which can multiply by
+/// `10^8` and so take the digits eight at a time where they are all valid.
+fn from_ascii_decimal<T: FromStrRadixHelper>(is_positive: bool, mut digits: &[u8])
+                                             -> Result<T, ParseIntError> {
+    use self::IntErrorKind::*;
+    use self::ParseIntError as PIE;
+
+    let mut result = T::from_u32(0);
+    while !digits.is_empty() {
+        let (x, scale, len) = match swar::eight_digits(digits) {
+            Some(x) => (x, 100_000_000, 8),
+            None => match (digits[0] as char).to_digit(10) {
+                Some(x) => (x, 10, 1),
+                None => return Err(PIE { kind: InvalidDigit }),
+            },
+        };
+        let next = if is_positive {
+            result.checked_mul(scale).and_then(|result| result.checked_add(x))
+        } else {
+            result.checked_mul(scale).and_then(|result| result.checked_sub(x))
+        };
+        result = match next {
+            Some(result) => result,
+            None if is_positive => return Err(PIE { kind: Overflow }),
+            None => return Err(PIE { kind: Underflow }),
+        };
+        digits = &digits[len..];
+    }
+    Ok(result)
+}
+
+fn from_str_radix_partial<T: FromStrRadixHelper>(src: &str, radix: u32)
+                                                 -> Result<(T, usize), ParseIntError> {
+    assert!(radix >= 2 && radix <= 36,
+           "from_str_radix_int: must lie in the range `[2, 36]` - found {}",
+           radix);
+
+    let is_signed_ty = T::from_u32(0) > T::min_value();
+    let src = src.as_bytes();
+    let sign_len = match src.first() {
+        Some(&b'+') => 1,
+        Some(&b'-') if is_signed_ty => 1,
+        _ => 0,
+    };
+    let mut len = sign_len;
+    if radix == 10 {
+        len += swar::digit_len(&src[sign_len..]);
+    }
+    while len < src.len() && (src[len] as char).to_digit(radix).is_some() {
+        len += 1;
+    }
+    if len == sign_len && len < src.len() {
+        return Err(ParseIntError { kind: IntErrorKind::InvalidDigit });
+    }
+    from_ascii_radix(&src[..len], radix, false).map(|x| (x, len))
+}
+
 /// An error which can be returned when parsing an integer.
 ///
 /// This error is used as the error type for the `from_str_radix()` functions
@@ -2688,6 +3356,16 @@ impl fmt::Display for ParseIntError {
 
 #[stable(feature = "rust1", since = "1.0.0")]
 pub use num::dec2flt::ParseFloatError;
+#[unstable(feature = "float_parse_options", issue = "0")]
+pub use num::dec2flt::ParseFloatOptions;
+#[unstable(feature = "float_error_kind", issue = "0")]
+pub use num::dec2flt::FloatErrorKind;
+#[unstable(feature = "raw_float", issue = "0")]
+pub use num::dec2flt::rawfp::RawFloat;
+#[unstable(feature = "raw_float", issue = "0")]
+pub use num::dec2flt::{parse_float, parse_float_ascii, parse_float_exact, parse_float_partial};
+#[unstable(feature = "raw_float", issue = "0")]
+pub use num::dec2flt::parse_float_with;
 
 // Conversion traits for primitive integer and float types
 // Conversions T -> T are covered by a blanket impl and therefore excluded
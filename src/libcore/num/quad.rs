@@ -0,0 +1,346 @@
+// Copyright 2016 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Software arithmetic on the bits of `f128`, and on 128-bit integers as `(hi, lo)` pairs.
+//!
+//! Every operation first computes its result exactly, or with 115 or more significant bits and
+//! the lowest one set if any bits were lost below it. `round` then rounds that to the nearest
+//! `f128`, ties to even, which makes the operation correctly rounded.
+
+#![doc(hidden)]
+#![unstable(feature = "core_quad",
+            reason = "internal routines only exposed for 128-bit numbers",
+            issue = "0")]
+
+use cmp::Ordering;
+use option::Option::{self, Some, None};
+
+pub const SIGN: u64 = 1 << 63;
+pub const EXP_MASK: u64 = 0x7fff << 48;
+pub const MAN_MASK: u64 = (1 << 48) - 1;
+pub const QUIET: u64 = 1 << 47;
+
+/// The exponent of the subnormals, whose significand is the 112 stored bits.
+pub const MIN_EXP_INT: i32 = -16494;
+
+pub fn leading_zeros(hi: u64, lo: u64) -> u32 {
+    if hi != 0 { hi.leading_zeros() } else { 64 + lo.leading_zeros() }
+}
+
+/// Shifts left by `s < 128`.
+pub fn shl(hi: u64, lo: u64, s: u32) -> (u64, u64) {
+    if s == 0 {
+        (hi, lo)
+    } else if s < 64 {
+        (hi << s | lo >> (64 - s), lo << s)
+    } else {
+        (lo << (s - 64), 0)
+    }
+}
+
+/// Shifts right by `s < 128`.
+pub fn shr(hi: u64, lo: u64, s: u32) -> (u64, u64) {
+    if s == 0 {
+        (hi, lo)
+    } else if s < 64 {
+        (hi >> s, lo >> s | hi << (64 - s))
+    } else {
+        (0, hi >> (s - 64))
+    }
+}
+
+/// Shifts right by any amount, setting the lowest bit if any non-zero bits were shifted out.
+pub fn shr_jam(hi: u64, lo: u64, s: u32) -> (u64, u64) {
+    let (hi, lo, lost) = if s == 0 {
+        (hi, lo, 0)
+    } else if s < 64 {
+        (hi >> s, lo >> s | hi << (64 - s), lo << (64 - s))
+    } else if s == 64 {
+        (0, hi, lo)
+    } else if s < 128 {
+        (0, hi >> (s - 64), lo | hi << (128 - s))
+    } else {
+        (0, 0, hi | lo)
+    };
+    (hi, lo | (lost != 0) as u64)
+}
+
+/// Returns the full 128-bit product of `a` and `b`, as `(hi, lo)`.
+pub fn wide_mul(a: u64, b: u64) -> (u64, u64) {
+    const MASK: u64 = 0xffffffff;
+    let (a1, a0) = (a >> 32, a & MASK);
+    let (b1, b0) = (b >> 32, b & MASK);
+    let p00 = a0 * b0;
+    let p01 = a0 * b1;
+    let p10 = a1 * b0;
+    let p11 = a1 * b1;
+    let mid = (p00 >> 32) + (p01 & MASK) + (p10 & MASK);
+    (p11 + (p01 >> 32) + (p10 >> 32) + (mid >> 32), mid << 32 | p00 & MASK)
+}
+
+/// Returns the quotient and the remainder of `a / b`, for a non-zero `b`.
+pub fn div_rem(a: (u64, u64), b: (u64, u64)) -> ((u64, u64), (u64, u64)) {
+    if b.0 == 0 && b.1 >> 32 == 0 {
+        // Short division of the four 32-bit digits of `a` by the single digit of `b`, which
+        // keeps every partial dividend below `2^64`.
+        let d = b.1;
+        let digits = [a.0 >> 32, a.0 & 0xffffffff, a.1 >> 32, a.1 & 0xffffffff];
+        let mut qs = [0; 4];
+        let mut rem = 0;
+        for (q, &digit) in qs.iter_mut().zip(&digits) {
+            let cur = rem << 32 | digit;
+            *q = cur / d;
+            rem = cur % d;
+        }
+        return ((qs[0] << 32 | qs[1], qs[2] << 32 | qs[3]), (0, rem));
+    }
+    if a < b {
+        return ((0, 0), a);
+    }
+
+    // Binary long division, one bit of the quotient at a time, with the divisor starting out
+    // aligned with the top bit of `a`.
+    let shift = leading_zeros(b.0, b.1) - leading_zeros(a.0, a.1);
+    let mut d = shl(b.0, b.1, shift);
+    let mut r = a;
+    let mut q = (0, 0);
+    for _ in 0..shift + 1 {
+        q = shl(q.0, q.1, 1);
+        if r >= d {
+            r = (r.0 - d.0 - (r.1 < d.1) as u64, r.1.wrapping_sub(d.1));
+            q.1 |= 1;
+        }
+        d = shr(d.0, d.1, 1);
+    }
+    (q, r)
+}
+
+/// Returns `a + b + carry` and the carry out of it.
+fn adc(a: u64, b: u64, carry: bool) -> (u64, bool) {
+    let (s, c1) = a.overflowing_add(b);
+    let (s, c2) = s.overflowing_add(carry as u64);
+    (s, c1 || c2)
+}
+
+pub fn is_nan(x: (u64, u64)) -> bool {
+    let hi = x.0 & !SIGN;
+    hi > EXP_MASK || (hi == EXP_MASK && x.1 != 0)
+}
+
+pub fn is_infinite(x: (u64, u64)) -> bool {
+    x.0 & !SIGN == EXP_MASK && x.1 == 0
+}
+
+pub fn is_zero(x: (u64, u64)) -> bool {
+    x.0 & !SIGN == 0 && x.1 == 0
+}
+
+/// Returns the quiet version of the NaN `x`.
+fn quiet(x: (u64, u64)) -> (u64, u64) {
+    (x.0 | QUIET, x.1)
+}
+
+/// Decodes finite bits into a sign and an `(exp, mantissa)` pair like
+/// `Float::to_exp_mantissa`: the magnitude is `mantissa * 2^exp`, with the implicit bit of
+/// normal numbers included in the mantissa.
+pub fn unpack(x: (u64, u64)) -> (bool, i32, u64, u64) {
+    let neg = x.0 & SIGN != 0;
+    let biased = ((x.0 & EXP_MASK) >> 48) as i32;
+    let man = x.0 & MAN_MASK;
+    if biased == 0 {
+        (neg, MIN_EXP_INT, man, x.1)
+    } else {
+        (neg, MIN_EXP_INT - 1 + biased, man | 1 << 48, x.1)
+    }
+}
+
+/// Rounds `(hi, lo) * 2^exp` with the given sign to the bits of the nearest `f128`, ties to
+/// even, overflowing to infinity and underflowing to zero.
+///
+/// The mantissa may have its lowest bit set for any lost bits below it, as long as it then
+/// has at least 115 significant bits, so that the sticky bit stays below the rounding bit.
+pub fn round(neg: bool, exp: i32, hi: u64, lo: u64) -> (u64, u64) {
+    let sign = if neg { SIGN } else { 0 };
+    if hi == 0 && lo == 0 {
+        return (sign, 0);
+    }
+
+    // Normalize to a 128-bit mantissa whose top bit is worth `2^e`.
+    let lz = leading_zeros(hi, lo);
+    let (hi, lo) = shl(hi, lo, lz);
+    let e = exp as i64 + 127 - lz as i64;
+    if e > 16383 {
+        return (sign | EXP_MASK, 0);
+    }
+    // Keep 113 bits, or fewer for the subnormals, whose last bit is worth `2^MIN_EXP_INT`.
+    let (biased, shift) = if e >= -16382 {
+        ((e + 16382) as u64, 15)
+    } else {
+        (0, 15 + (-16382 - e) as u32)
+    };
+
+    // Bit 1 is the rounding bit and bit 0 is sticky for all the lower ones.
+    let (hi, lo) = shr_jam(hi, lo, shift - 2);
+    let grs = lo & 3;
+    let (mut hi, mut lo) = (hi >> 2, lo >> 2 | hi << 62);
+    if grs > 2 || (grs == 2 && lo & 1 == 1) {
+        lo = lo.wrapping_add(1);
+        hi += (lo == 0) as u64;
+    }
+    // The implicit bit adds one to the biased exponent, and so does a carry out of it.
+    (sign | ((biased << 48) + hi), lo)
+}
+
+/// Shifts a non-zero finite value so that its highest bit is bit 125, leaving room for a carry.
+fn normalize(x: (u64, u64)) -> (bool, i32, u64, u64) {
+    let (neg, e, hi, lo) = unpack(x);
+    let s = leading_zeros(hi, lo) - 2;
+    let (hi, lo) = shl(hi, lo, s);
+    (neg, e - s as i32, hi, lo)
+}
+
+pub fn add(a: (u64, u64), b: (u64, u64)) -> (u64, u64) {
+    if is_nan(a) {
+        return quiet(a);
+    } else if is_nan(b) {
+        return quiet(b);
+    } else if is_infinite(a) {
+        return if is_infinite(b) && a.0 != b.0 { (EXP_MASK | QUIET, 0) } else { a };
+    } else if is_infinite(b) {
+        return b;
+    } else if is_zero(a) && is_zero(b) {
+        // only the sum of two negative zeros is negative
+        return (a.0 & b.0, 0);
+    } else if is_zero(a) {
+        return b;
+    } else if is_zero(b) {
+        return a;
+    }
+
+    // Both mantissas have 13 zero bits at the bottom, so `x` does not lose any bits, and the
+    // sticky bit of `y` can never make a tie out of an inexact sum.
+    let (a, b) = (normalize(a), normalize(b));
+    let (x, y) = if (a.1, a.2, a.3) >= (b.1, b.2, b.3) { (a, b) } else { (b, a) };
+    let (yhi, ylo) = shr_jam(y.2, y.3, (x.1 - y.1) as u32);
+    let (hi, lo) = if x.0 == y.0 {
+        let lo = x.3.wrapping_add(ylo);
+        (x.2 + yhi + (lo < x.3) as u64, lo)
+    } else {
+        let lo = x.3.wrapping_sub(ylo);
+        (x.2 - yhi - (x.3 < ylo) as u64, lo)
+    };
+    // an exact zero is positive, as it is when rounding to nearest
+    round(x.0 && (hi != 0 || lo != 0), x.1, hi, lo)
+}
+
+pub fn sub(a: (u64, u64), b: (u64, u64)) -> (u64, u64) {
+    if is_nan(b) {
+        return add(a, b);
+    }
+    add(a, (b.0 ^ SIGN, b.1))
+}
+
+pub fn mul(a: (u64, u64), b: (u64, u64)) -> (u64, u64) {
+    let sign = (a.0 ^ b.0) & SIGN;
+    if is_nan(a) {
+        return quiet(a);
+    } else if is_nan(b) {
+        return quiet(b);
+    } else if is_infinite(a) || is_infinite(b) {
+        return if is_zero(a) || is_zero(b) { (EXP_MASK | QUIET, 0) } else { (sign | EXP_MASK, 0) };
+    } else if is_zero(a) || is_zero(b) {
+        return (sign, 0);
+    }
+
+    let (_, ae, ahi, alo) = unpack(a);
+    let (_, be, bhi, blo) = unpack(b);
+    let (sa, sb) = (leading_zeros(ahi, alo), leading_zeros(bhi, blo));
+    let (ahi, alo) = shl(ahi, alo, sa);
+    let (bhi, blo) = shl(bhi, blo, sb);
+
+    // the 256-bit product `w3:w2:w1:w0`, of which the top 128 bits are more than enough
+    let (p0hi, p0lo) = wide_mul(alo, blo);
+    let (p1hi, p1lo) = wide_mul(alo, bhi);
+    let (p2hi, p2lo) = wide_mul(ahi, blo);
+    let (p3hi, p3lo) = wide_mul(ahi, bhi);
+    let (w1, c1) = adc(p0hi, p1lo, false);
+    let (w1, c2) = adc(w1, p2lo, false);
+    let (w2, c3) = adc(p1hi, p2hi, c1);
+    let (w2, c4) = adc(w2, p3lo, c2);
+    let w3 = p3hi + c3 as u64 + c4 as u64;
+    let sticky = (w1 | p0lo != 0) as u64;
+    round(sign != 0, ae - sa as i32 + be - sb as i32 + 128, w3, w2 | sticky)
+}
+
+pub fn div(a: (u64, u64), b: (u64, u64)) -> (u64, u64) {
+    let sign = (a.0 ^ b.0) & SIGN;
+    if is_nan(a) {
+        return quiet(a);
+    } else if is_nan(b) {
+        return quiet(b);
+    } else if is_infinite(a) {
+        return if is_infinite(b) { (EXP_MASK | QUIET, 0) } else { (sign | EXP_MASK, 0) };
+    } else if is_infinite(b) {
+        return (sign, 0);
+    } else if is_zero(b) {
+        return if is_zero(a) { (EXP_MASK | QUIET, 0) } else { (sign | EXP_MASK, 0) };
+    } else if is_zero(a) {
+        return (sign, 0);
+    }
+
+    // Normalize both to 113 bits, and make the dividend no smaller than the divisor.
+    let (_, ae, ahi, alo) = unpack(a);
+    let (_, be, bhi, blo) = unpack(b);
+    let (sa, sb) = (leading_zeros(ahi, alo) - 15, leading_zeros(bhi, blo) - 15);
+    let (mut rhi, mut rlo) = shl(ahi, alo, sa);
+    let (dhi, dlo) = shl(bhi, blo, sb);
+    let mut e = ae - sa as i32 - (be - sb as i32);
+    if (rhi, rlo) < (dhi, dlo) {
+        let (hi, lo) = shl(rhi, rlo, 1);
+        rhi = hi;
+        rlo = lo;
+        e -= 1;
+    }
+
+    // 116 bits of the quotient, one at a time, and a sticky bit for the remainder. The
+    // remainder stays below twice the divisor, so it never needs more than 114 bits.
+    let (mut qhi, mut qlo) = (0, 0);
+    for _ in 0..116 {
+        let (hi, lo) = shl(qhi, qlo, 1);
+        qhi = hi;
+        qlo = lo;
+        if (rhi, rlo) >= (dhi, dlo) {
+            let lo = rlo.wrapping_sub(dlo);
+            rhi = rhi - dhi - (rlo < dlo) as u64;
+            rlo = lo;
+            qlo |= 1;
+        }
+        let (hi, lo) = shl(rhi, rlo, 1);
+        rhi = hi;
+        rlo = lo;
+    }
+    let sticky = (rhi | rlo != 0) as u64;
+    round(sign != 0, e - 115, qhi, qlo | sticky)
+}
+
+/// Compares like the primitive floats do: NaN is unordered, and the zeros are equal.
+pub fn partial_cmp(a: (u64, u64), b: (u64, u64)) -> Option<Ordering> {
+    if is_nan(a) || is_nan(b) {
+        return None;
+    } else if is_zero(a) && is_zero(b) {
+        return Some(Ordering::Equal);
+    }
+    let (an, bn) = (a.0 & SIGN != 0, b.0 & SIGN != 0);
+    if an != bn {
+        return Some(if an { Ordering::Less } else { Ordering::Greater });
+    }
+    let order = (a.0 & !SIGN, a.1).cmp(&(b.0 & !SIGN, b.1));
+    Some(if an { order.reverse() } else { order })
+}
@@ -0,0 +1,73 @@
+// Copyright 2016 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use super::Saturating;
+
+use ops::*;
+
+macro_rules! saturating_impl {
+    ($($t:ty)*) => ($(
+        #[unstable(feature = "saturating_int", issue = "0")]
+        impl Add for Saturating<$t> {
+            type Output = Saturating<$t>;
+
+            #[inline(always)]
+            fn add(self, other: Saturating<$t>) -> Saturating<$t> {
+                Saturating(self.0.saturating_add(other.0))
+            }
+        }
+
+        #[unstable(feature = "saturating_int", issue = "0")]
+        impl AddAssign for Saturating<$t> {
+            #[inline(always)]
+            fn add_assign(&mut self, other: Saturating<$t>) {
+                *self = *self + other;
+            }
+        }
+
+        #[unstable(feature = "saturating_int", issue = "0")]
+        impl Sub for Saturating<$t> {
+            type Output = Saturating<$t>;
+
+            #[inline(always)]
+            fn sub(self, other: Saturating<$t>) -> Saturating<$t> {
+                Saturating(self.0.saturating_sub(other.0))
+            }
+        }
+
+        #[unstable(feature = "saturating_int", issue = "0")]
+        impl SubAssign for Saturating<$t> {
+            #[inline(always)]
+            fn sub_assign(&mut self, other: Saturating<$t>) {
+                *self = *self - other;
+            }
+        }
+
+        #[unstable(feature = "saturating_int", issue = "0")]
+        impl Mul for Saturating<$t> {
+            type Output = Saturating<$t>;
+
+            #[inline(always)]
+            fn mul(self, other: Saturating<$t>) -> Saturating<$t> {
+                Saturating(self.0.saturating_mul(other.0))
+            }
+        }
+
+        #[unstable(feature = "saturating_int", issue = "0")]
+        impl MulAssign for Saturating<$t> {
+            #[inline(always)]
+            fn mul_assign(&mut self, other: Saturating<$t>) {
+                *self = *self * other;
+            }
+        }
+    )*)
+}
+
+saturating_impl! { usize u8 u16 u32 u64 isize i8 i16 i32 i64 }
@@ -0,0 +1,78 @@
+// Copyright 2016 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Scanning and converting ASCII decimal digits eight at a time, as the bytes of a `u64`.
+//!
+//! This is "SIMD within a register": the checks and the arithmetic work on all eight bytes at
+//! once and need nothing beyond 64-bit integers, so they are the same on every target. Wider
+//! vector registers would do more at a time, but `core` has no way to detect them at runtime,
+//! and realistic numbers have few enough digits that eight at a time covers most of them.
+
+use option::Option::{self, Some, None};
+
+const ASCII_ZEROS: u64 = 0x3030_3030_3030_3030;
+const HIGH_NIBBLES: u64 = 0xf0f0_f0f0_f0f0_f0f0;
+
+/// Loads the first eight bytes of `s`, the first one in the lowest byte.
+#[inline]
+fn load(s: &[u8]) -> u64 {
+    let mut v = 0;
+    for (i, &c) in s[..8].iter().enumerate() {
+        v |= (c as u64) << (8 * i);
+    }
+    v
+}
+
+/// Tests whether all eight bytes of `v` are ASCII decimal digits.
+#[inline]
+fn is_eight_digits(v: u64) -> bool {
+    // The high nibble of a digit is 3, and adding 6 to its low nibble doesn't carry into it.
+    (v & HIGH_NIBBLES) | ((v.wrapping_add(0x0606_0606_0606_0606) & HIGH_NIBBLES) >> 4) ==
+        0x3333_3333_3333_3333
+}
+
+/// Converts eight ASCII decimal digits, loaded by `load`, into their value.
+#[inline]
+fn parse_eight_digits(v: u64) -> u32 {
+    // Combine neighbouring digits into numbers of two, then four, then eight digits.
+    let v = v - ASCII_ZEROS;
+    let v = v.wrapping_mul(10) + (v >> 8);
+    let pairs = v & 0x0000_00ff_0000_00ff;
+    let next_pairs = (v >> 16) & 0x0000_00ff_0000_00ff;
+    let v = pairs.wrapping_mul(100 + (1_000_000 << 32))
+                 .wrapping_add(next_pairs.wrapping_mul(1 + (10_000 << 32)));
+    (v >> 32) as u32
+}
+
+/// Returns the value of the first eight bytes of `s` if they are all decimal digits.
+#[inline]
+pub fn eight_digits(s: &[u8]) -> Option<u32> {
+    if s.len() < 8 {
+        return None;
+    }
+    let v = load(s);
+    if is_eight_digits(v) {
+        Some(parse_eight_digits(v))
+    } else {
+        None
+    }
+}
+
+/// Counts the decimal digits at the start of `s`.
+pub fn digit_len(s: &[u8]) -> usize {
+    let mut i = 0;
+    while s.len() - i >= 8 && is_eight_digits(load(&s[i..])) {
+        i += 8;
+    }
+    while i < s.len() && b'0' <= s[i] && s[i] <= b'9' {
+        i += 1;
+    }
+    i
+}
@@ -0,0 +1,173 @@
+// Copyright 2016 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Totally ordered wrappers around the float types.
+
+use cmp::{Ordering, PartialEq, Eq, PartialOrd, Ord};
+use convert::From;
+use fmt;
+use hash::{Hash, Hasher};
+use mem;
+use ops::Deref;
+use option::Option::{self, Some};
+
+macro_rules! total_ord_impl {
+    ($(#[$attr:meta])* struct $name:ident($t:ident, $bits:ident);
+     canonical_nan = $nan:expr) => {
+        $(#[$attr])*
+        #[unstable(feature = "float_total_ord", issue = "0")]
+        #[derive(Copy, Clone, Default)]
+        pub struct $name(#[unstable(feature = "float_total_ord", issue = "0")] pub $t);
+
+        impl $name {
+            // Maps the value to an integer with the same ordering, after
+            // merging all NaNs into one and -0.0 into +0.0. Flipping all
+            // bits of negative numbers reverses their order, and setting
+            // the sign bit of positive numbers puts them above negatives.
+            #[inline]
+            fn key(self) -> $bits {
+                let x = if self.0 != self.0 {
+                    $nan
+                } else if self.0 == 0.0 {
+                    0.0
+                } else {
+                    self.0
+                };
+                let bits: $bits = unsafe { mem::transmute(x) };
+                let sign = 1 << (mem::size_of::<$bits>() * 8 - 1);
+                if bits & sign != 0 { !bits } else { bits | sign }
+            }
+        }
+
+        #[unstable(feature = "float_total_ord", issue = "0")]
+        impl PartialEq for $name {
+            #[inline]
+            fn eq(&self, other: &$name) -> bool {
+                self.key() == other.key()
+            }
+        }
+
+        #[unstable(feature = "float_total_ord", issue = "0")]
+        impl Eq for $name {}
+
+        #[unstable(feature = "float_total_ord", issue = "0")]
+        impl PartialOrd for $name {
+            #[inline]
+            fn partial_cmp(&self, other: &$name) -> Option<Ordering> {
+                Some(self.cmp(other))
+            }
+        }
+
+        #[unstable(feature = "float_total_ord", issue = "0")]
+        impl Ord for $name {
+            #[inline]
+            fn cmp(&self, other: &$name) -> Ordering {
+                self.key().cmp(&other.key())
+            }
+        }
+
+        #[unstable(feature = "float_total_ord", issue = "0")]
+        impl Hash for $name {
+            #[inline]
+            fn hash<H: Hasher>(&self, state: &mut H) {
+                self.key().hash(state)
+            }
+        }
+
+        #[unstable(feature = "float_total_ord", issue = "0")]
+        impl Deref for $name {
+            type Target = $t;
+
+            #[inline]
+            fn deref(&self) -> &$t {
+                &self.0
+            }
+        }
+
+        #[unstable(feature = "float_total_ord", issue = "0")]
+        impl From<$t> for $name {
+            #[inline]
+            fn from(x: $t) -> $name {
+                $name(x)
+            }
+        }
+
+        #[unstable(feature = "float_total_ord", issue = "0")]
+        impl From<$name> for $t {
+            #[inline]
+            fn from(x: $name) -> $t {
+                x.0
+            }
+        }
+
+        #[unstable(feature = "float_total_ord", issue = "0")]
+        impl fmt::Debug for $name {
+            fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                fmt::Debug::fmt(&self.0, f)
+            }
+        }
+
+        #[unstable(feature = "float_total_ord", issue = "0")]
+        impl fmt::Display for $name {
+            fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                fmt::Display::fmt(&self.0, f)
+            }
+        }
+    }
+}
+
+total_ord_impl! {
+    /// An `f32` with a total order, so that it can be used as the key of a
+    /// `BTreeMap` or a `HashMap`.
+    ///
+    /// `Eq`, `Ord` and `Hash` agree with each other and treat all NaNs as
+    /// equal to each other and greater than every other value, including
+    /// positive infinity, and `-0.0` as equal to `0.0`. Every other value
+    /// is ordered as by `<`. The wrapped value is left untouched: only the
+    /// comparisons canonicalize it.
+    ///
+    /// See [`TotalOrdF64`](struct.TotalOrdF64.html) for an example.
+    struct TotalOrdF32(f32, u32);
+    canonical_nan = ::f32::NAN
+}
+
+total_ord_impl! {
+    /// An `f64` with a total order, so that it can be used as the key of a
+    /// `BTreeMap` or a `HashMap`.
+    ///
+    /// `Eq`, `Ord` and `Hash` agree with each other and treat all NaNs as
+    /// equal to each other and greater than every other value, including
+    /// positive infinity, and `-0.0` as equal to `0.0`. Every other value
+    /// is ordered as by `<`. The wrapped value is left untouched: only the
+    /// comparisons canonicalize it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(float_total_ord)]
+    ///
+    /// use std::collections::BTreeMap;
+    /// use std::f64;
+    /// use std::num::TotalOrdF64;
+    ///
+    /// let mut counts = BTreeMap::new();
+    /// for &x in &[1.5, f64::NAN, -0.0, 0.0, -f64::NAN, 1.5] {
+    ///     *counts.entry(TotalOrdF64(x)).or_insert(0) += 1;
+    /// }
+    ///
+    /// assert_eq!(counts.len(), 3);
+    /// assert_eq!(counts[&TotalOrdF64(0.0)], 2);
+    /// assert_eq!(counts[&TotalOrdF64(1.5)], 2);
+    /// assert_eq!(counts[&TotalOrdF64(f64::NAN)], 2);
+    /// assert!(counts.keys().last().unwrap().is_nan());
+    /// ```
+    struct TotalOrdF64(f64, u64);
+    canonical_nan = ::f64::NAN
+}
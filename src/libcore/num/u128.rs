@@ -0,0 +1,631 @@
+// Copyright 2016 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Operations and constants for unsigned 128-bits integers (`u128` type)
+//!
+//! Like `f128`, `u128` is not a primitive type: it is stored in two `u64`s,
+//! in the same byte order as a primitive would be, with arithmetic done in
+//! software. It has the methods of the primitive integer types, and since it
+//! can't be cast with `as`, conversions go through `From`, `from_parts`,
+//! `to_parts` and `as_i128` instead.
+//!
+//! Like those of the primitives, the arithmetic operators panic on overflow
+//! when overflow checks are enabled in the crate using them, as they are with
+//! debug assertions, and wrap around otherwise. Division by zero always
+//! panics.
+//!
+//! # Examples
+//!
+//! ```
+//! #![feature(i128)]
+//!
+//! use std::num::u128;
+//!
+//! let x = u128::from(u64::max_value()) * u128::from(3u8);
+//! assert_eq!(x.to_parts(), (2, u64::max_value() - 2));
+//! assert_eq!(x.to_string(), "55340232221128654845");
+//! assert_eq!("55340232221128654845".parse(), Ok(x));
+//! ```
+
+#![unstable(feature = "i128", issue = "0")]
+
+use cmp::{PartialOrd, Ord, Ordering};
+use convert::From;
+use hash::{Hash, Hasher};
+use i128::i128;
+use num::quad;
+use ops::{Add, Sub, Mul, Div, Rem, Not, BitAnd, BitOr, BitXor, Shl, Shr};
+use ops::{AddAssign, SubAssign, MulAssign, DivAssign, RemAssign};
+use ops::{BitAndAssign, BitOrAssign, BitXorAssign, ShlAssign, ShrAssign};
+use option::Option::{self, Some, None};
+
+/// An unsigned 128-bit integer.
+///
+/// See the [module documentation](../u128/index.html) for how it differs
+/// from the primitive integer types.
+#[allow(non_camel_case_types)]
+#[derive(Copy, Clone, PartialEq, Eq, Default)]
+#[repr(C)]
+pub struct u128 {
+    #[cfg(target_endian = "big")]
+    hi: u64,
+    lo: u64,
+    #[cfg(target_endian = "little")]
+    hi: u64,
+}
+
+/// The smallest value that can be represented by this integer type.
+pub const MIN: u128 = u128::min_value();
+/// The largest value that can be represented by this integer type.
+pub const MAX: u128 = u128::max_value();
+
+impl u128 {
+    /// Creates a `u128` from its high and low 64 bits.
+    #[inline]
+    pub const fn from_parts(hi: u64, lo: u64) -> u128 {
+        u128 { hi: hi, lo: lo }
+    }
+
+    /// Returns the high and low 64 bits.
+    #[inline]
+    pub fn to_parts(self) -> (u64, u64) {
+        (self.hi, self.lo)
+    }
+
+    /// Returns the smallest value that can be represented by this integer
+    /// type.
+    #[inline]
+    pub const fn min_value() -> u128 {
+        u128 { hi: 0, lo: 0 }
+    }
+
+    /// Returns the largest value that can be represented by this integer
+    /// type.
+    #[inline]
+    pub const fn max_value() -> u128 {
+        u128 { hi: !0, lo: !0 }
+    }
+
+    /// Reinterprets the bits as an `i128`, like an `as` cast between
+    /// primitive integers of the same width.
+    #[inline]
+    pub fn as_i128(self) -> i128 {
+        i128::from_parts(self.hi as i64, self.lo)
+    }
+
+    /// Returns the number of ones in the binary representation of `self`.
+    #[inline]
+    pub fn count_ones(self) -> u32 {
+        self.hi.count_ones() + self.lo.count_ones()
+    }
+
+    /// Returns the number of zeros in the binary representation of `self`.
+    #[inline]
+    pub fn count_zeros(self) -> u32 {
+        128 - self.count_ones()
+    }
+
+    /// Returns the number of leading zeros in the binary representation of
+    /// `self`.
+    #[inline]
+    pub fn leading_zeros(self) -> u32 {
+        quad::leading_zeros(self.hi, self.lo)
+    }
+
+    /// Returns the number of trailing zeros in the binary representation of
+    /// `self`.
+    #[inline]
+    pub fn trailing_zeros(self) -> u32 {
+        if self.lo != 0 { self.lo.trailing_zeros() } else { 64 + self.hi.trailing_zeros() }
+    }
+
+    /// Shifts the bits to the left by `n`, wrapping the truncated bits
+    /// around to the end.
+    pub fn rotate_left(self, n: u32) -> u128 {
+        let n = n % 128;
+        if n == 0 {
+            self
+        } else {
+            self.wrapping_shl(n) | self.wrapping_shr(128 - n)
+        }
+    }
+
+    /// Shifts the bits to the right by `n`, wrapping the truncated bits
+    /// around to the beginning.
+    pub fn rotate_right(self, n: u32) -> u128 {
+        self.rotate_left(128 - n % 128)
+    }
+
+    /// Reverses the byte order of `self`.
+    #[inline]
+    pub fn swap_bytes(self) -> u128 {
+        u128::from_parts(self.lo.swap_bytes(), self.hi.swap_bytes())
+    }
+
+    /// Converts `x` from big endian to the target's endianness.
+    #[inline]
+    pub fn from_be(x: u128) -> u128 {
+        if cfg!(target_endian = "big") { x } else { x.swap_bytes() }
+    }
+
+    /// Converts `x` from little endian to the target's endianness.
+    #[inline]
+    pub fn from_le(x: u128) -> u128 {
+        if cfg!(target_endian = "little") { x } else { x.swap_bytes() }
+    }
+
+    /// Converts `self` to big endian from the target's endianness.
+    #[inline]
+    pub fn to_be(self) -> u128 {
+        u128::from_be(self)
+    }
+
+    /// Converts `self` to little endian from the target's endianness.
+    #[inline]
+    pub fn to_le(self) -> u128 {
+        u128::from_le(self)
+    }
+
+    /// Checked integer addition. Returns `None` if overflow occurred.
+    #[inline]
+    pub fn checked_add(self, other: u128) -> Option<u128> {
+        checked(self.overflowing_add(other))
+    }
+
+    /// Checked integer subtraction. Returns `None` if overflow occurred.
+    #[inline]
+    pub fn checked_sub(self, other: u128) -> Option<u128> {
+        checked(self.overflowing_sub(other))
+    }
+
+    /// Checked integer multiplication. Returns `None` if overflow occurred.
+    #[inline]
+    pub fn checked_mul(self, other: u128) -> Option<u128> {
+        checked(self.overflowing_mul(other))
+    }
+
+    /// Checked integer division. Returns `None` if `other == 0`.
+    #[inline]
+    pub fn checked_div(self, other: u128) -> Option<u128> {
+        if other == MIN { None } else { Some(self.div_rem(other).0) }
+    }
+
+    /// Checked integer remainder. Returns `None` if `other == 0`.
+    #[inline]
+    pub fn checked_rem(self, other: u128) -> Option<u128> {
+        if other == MIN { None } else { Some(self.div_rem(other).1) }
+    }
+
+    /// Checked negation. Returns `None` unless `self == 0`.
+    #[inline]
+    pub fn checked_neg(self) -> Option<u128> {
+        checked(self.overflowing_neg())
+    }
+
+    /// Checked shift left. Returns `None` if `rhs` is larger than or equal
+    /// to 128.
+    #[inline]
+    pub fn checked_shl(self, rhs: u32) -> Option<u128> {
+        checked(self.overflowing_shl(rhs))
+    }
+
+    /// Checked shift right. Returns `None` if `rhs` is larger than or equal
+    /// to 128.
+    #[inline]
+    pub fn checked_shr(self, rhs: u32) -> Option<u128> {
+        checked(self.overflowing_shr(rhs))
+    }
+
+    /// Saturating integer addition. Computes `self + other`, saturating at
+    /// the numeric bounds instead of overflowing.
+    #[inline]
+    pub fn saturating_add(self, other: u128) -> u128 {
+        self.checked_add(other).unwrap_or(MAX)
+    }
+
+    /// Saturating integer subtraction. Computes `self - other`, saturating
+    /// at the numeric bounds instead of overflowing.
+    #[inline]
+    pub fn saturating_sub(self, other: u128) -> u128 {
+        self.checked_sub(other).unwrap_or(MIN)
+    }
+
+    /// Saturating integer multiplication. Computes `self * other`,
+    /// saturating at the numeric bounds instead of overflowing.
+    #[inline]
+    pub fn saturating_mul(self, other: u128) -> u128 {
+        self.checked_mul(other).unwrap_or(MAX)
+    }
+
+    /// Wrapping (modular) addition. Computes `self + rhs`, wrapping around
+    /// at the boundary of the type.
+    #[inline]
+    pub fn wrapping_add(self, rhs: u128) -> u128 {
+        self.overflowing_add(rhs).0
+    }
+
+    /// Wrapping (modular) subtraction. Computes `self - rhs`, wrapping
+    /// around at the boundary of the type.
+    #[inline]
+    pub fn wrapping_sub(self, rhs: u128) -> u128 {
+        self.overflowing_sub(rhs).0
+    }
+
+    /// Wrapping (modular) multiplication. Computes `self * rhs`, wrapping
+    /// around at the boundary of the type.
+    #[inline]
+    pub fn wrapping_mul(self, rhs: u128) -> u128 {
+        self.overflowing_mul(rhs).0
+    }
+
+    /// Wrapping (modular) division. Computes `self / rhs`, which can never
+    /// overflow for unsigned types.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if `rhs` is 0.
+    #[inline]
+    pub fn wrapping_div(self, rhs: u128) -> u128 {
+        self / rhs
+    }
+
+    /// Wrapping (modular) remainder. Computes `self % rhs`, which can never
+    /// overflow for unsigned types.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if `rhs` is 0.
+    #[inline]
+    pub fn wrapping_rem(self, rhs: u128) -> u128 {
+        self % rhs
+    }
+
+    /// Wrapping (modular) negation. Computes `-self`, wrapping around at
+    /// the boundary of the type.
+    #[inline]
+    pub fn wrapping_neg(self) -> u128 {
+        self.overflowing_neg().0
+    }
+
+    /// Panic-free bitwise shift-left; yields `self << (rhs % 128)`.
+    #[inline]
+    pub fn wrapping_shl(self, rhs: u32) -> u128 {
+        let (hi, lo) = quad::shl(self.hi, self.lo, rhs & 127);
+        u128::from_parts(hi, lo)
+    }
+
+    /// Panic-free bitwise shift-right; yields `self >> (rhs % 128)`.
+    #[inline]
+    pub fn wrapping_shr(self, rhs: u32) -> u128 {
+        let (hi, lo) = quad::shr(self.hi, self.lo, rhs & 127);
+        u128::from_parts(hi, lo)
+    }
+
+    /// Calculates `self + rhs`. Returns a tuple of the addition along with
+    /// a boolean indicating whether an arithmetic overflow would occur.
+    #[inline]
+    pub fn overflowing_add(self, rhs: u128) -> (u128, bool) {
+        let (lo, carry) = self.lo.overflowing_add(rhs.lo);
+        let (hi, o1) = self.hi.overflowing_add(rhs.hi);
+        let (hi, o2) = hi.overflowing_add(carry as u64);
+        (u128::from_parts(hi, lo), o1 || o2)
+    }
+
+    /// Calculates `self - rhs`. Returns a tuple of the subtraction along
+    /// with a boolean indicating whether an arithmetic overflow would occur.
+    #[inline]
+    pub fn overflowing_sub(self, rhs: u128) -> (u128, bool) {
+        let (lo, borrow) = self.lo.overflowing_sub(rhs.lo);
+        let (hi, o1) = self.hi.overflowing_sub(rhs.hi);
+        let (hi, o2) = hi.overflowing_sub(borrow as u64);
+        (u128::from_parts(hi, lo), o1 || o2)
+    }
+
+    /// Calculates the multiplication of `self` and `rhs`. Returns a tuple
+    /// of the multiplication along with a boolean indicating whether an
+    /// arithmetic overflow would occur.
+    pub fn overflowing_mul(self, rhs: u128) -> (u128, bool) {
+        // The product of the high halves, and the high halves of the cross
+        // products, only ever add to the bits above the lowest 128.
+        let (hi, lo) = quad::wide_mul(self.lo, rhs.lo);
+        let (c1hi, c1lo) = quad::wide_mul(self.hi, rhs.lo);
+        let (c2hi, c2lo) = quad::wide_mul(self.lo, rhs.hi);
+        let (hi, o1) = hi.overflowing_add(c1lo);
+        let (hi, o2) = hi.overflowing_add(c2lo);
+        let overflow = (self.hi != 0 && rhs.hi != 0) || c1hi != 0 || c2hi != 0 || o1 || o2;
+        (u128::from_parts(hi, lo), overflow)
+    }
+
+    /// Calculates the divisor when `self` is divided by `rhs`. Returns a
+    /// tuple of the divisor along with a boolean indicating whether an
+    /// arithmetic overflow would occur, which never happens for unsigned
+    /// types.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if `rhs` is 0.
+    #[inline]
+    pub fn overflowing_div(self, rhs: u128) -> (u128, bool) {
+        (self / rhs, false)
+    }
+
+    /// Calculates the remainder when `self` is divided by `rhs`. Returns a
+    /// tuple of the remainder along with a boolean indicating whether an
+    /// arithmetic overflow would occur, which never happens for unsigned
+    /// types.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if `rhs` is 0.
+    #[inline]
+    pub fn overflowing_rem(self, rhs: u128) -> (u128, bool) {
+        (self % rhs, false)
+    }
+
+    /// Negates `self` in a wrapping fashion. Returns `!self + 1` along with
+    /// a boolean indicating whether `self` was non-zero, which is when the
+    /// negation overflows.
+    #[inline]
+    pub fn overflowing_neg(self) -> (u128, bool) {
+        ((!self).overflowing_add(u128::from(1u8)).0, self != MIN)
+    }
+
+    /// Shifts `self` left by `rhs` bits. Returns a tuple of the shifted
+    /// version of `self`, with `rhs` masked to 128, along with a boolean
+    /// indicating whether the shift value was larger than or equal to 128.
+    #[inline]
+    pub fn overflowing_shl(self, rhs: u32) -> (u128, bool) {
+        (self.wrapping_shl(rhs), rhs >= 128)
+    }
+
+    /// Shifts `self` right by `rhs` bits. Returns a tuple of the shifted
+    /// version of `self`, with `rhs` masked to 128, along with a boolean
+    /// indicating whether the shift value was larger than or equal to 128.
+    #[inline]
+    pub fn overflowing_shr(self, rhs: u32) -> (u128, bool) {
+        (self.wrapping_shr(rhs), rhs >= 128)
+    }
+
+    /// Raises `self` to the power of `exp`, using exponentiation by
+    /// squaring.
+    ///
+    /// # Panics
+    ///
+    /// Overflows like the arithmetic operators, panicking when debug
+    /// assertions are enabled.
+    pub fn pow(self, mut exp: u32) -> u128 {
+        let mut base = self;
+        let mut acc = u128::from(1u8);
+        while exp > 1 {
+            if (exp & 1) == 1 {
+                acc = acc * base;
+            }
+            exp /= 2;
+            base = base * base;
+        }
+        // Like for the primitives, the base is not squared again after the
+        // last bit of the exponent, which could overflow needlessly.
+        if exp == 1 {
+            acc = acc * base;
+        }
+        acc
+    }
+
+    /// Returns `true` if and only if `self == 2^k` for some `k`.
+    #[inline]
+    pub fn is_power_of_two(self) -> bool {
+        self.count_ones() == 1
+    }
+
+    /// Returns the smallest power of two greater than or equal to `self`.
+    /// Unspecified behavior on overflow.
+    #[inline]
+    pub fn next_power_of_two(self) -> u128 {
+        self.checked_next_power_of_two().unwrap_or(MIN)
+    }
+
+    /// Returns the smallest power of two greater than or equal to `self`.
+    /// If the next power of two is greater than the type's maximum value,
+    /// `None` is returned, otherwise the power of two is wrapped in `Some`.
+    pub fn checked_next_power_of_two(self) -> Option<u128> {
+        let one = u128::from(1u8);
+        if self <= one {
+            return Some(one);
+        }
+        let bits = 128 - (self - one).leading_zeros();
+        one.checked_shl(bits)
+    }
+
+    /// Returns the quotient and the remainder of `self / other`, for a
+    /// non-zero `other`.
+    fn div_rem(self, other: u128) -> (u128, u128) {
+        let ((qhi, qlo), (rhi, rlo)) = quad::div_rem(self.to_parts(), other.to_parts());
+        (u128::from_parts(qhi, qlo), u128::from_parts(rhi, rlo))
+    }
+}
+
+fn checked((x, overflow): (u128, bool)) -> Option<u128> {
+    if overflow { None } else { Some(x) }
+}
+
+macro_rules! from_impl {
+    ($($t:ty)*) => ($(
+        impl From<$t> for u128 {
+            #[inline]
+            fn from(x: $t) -> u128 {
+                u128::from_parts(0, x as u64)
+            }
+        }
+    )*)
+}
+
+from_impl! { u8 u16 u32 u64 usize }
+
+impl PartialOrd for u128 {
+    #[inline]
+    fn partial_cmp(&self, other: &u128) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for u128 {
+    #[inline]
+    fn cmp(&self, other: &u128) -> Ordering {
+        self.to_parts().cmp(&other.to_parts())
+    }
+}
+
+// The derived `Hash` would feed the fields in their declaration order, which
+// depends on the endianness.
+impl Hash for u128 {
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.to_parts().hash(state)
+    }
+}
+
+// The operators wrap, and then report an overflow by doing a primitive
+// operation on `u8` that overflows exactly when they did. The operators
+// inherit the overflow checks of the crate they are used in, so the primitive
+// operation panics, with its own message, only when that crate checks for
+// overflow, like it would for the primitive integers.
+macro_rules! u128_op_impl {
+    ($($trait_:ident $method:ident $assign_trait:ident $assign_method:ident
+       $overflowing:ident |$o:ident| $report:expr;)*) => ($(
+        impl $trait_ for u128 {
+            type Output = u128;
+
+            #[inline]
+            #[rustc_inherit_overflow_checks]
+            fn $method(self, other: u128) -> u128 {
+                let (x, overflow) = self.$overflowing(other);
+                let $o = overflow as u8;
+                let _ = $report;
+                x
+            }
+        }
+
+        impl $assign_trait for u128 {
+            #[inline]
+            fn $assign_method(&mut self, other: u128) {
+                *self = $trait_::$method(*self, other);
+            }
+        }
+    )*)
+}
+
+u128_op_impl! {
+    Add add AddAssign add_assign overflowing_add |o| o * 0xff + o;
+    Sub sub SubAssign sub_assign overflowing_sub |o| 0 - o;
+    Mul mul MulAssign mul_assign overflowing_mul |o| (o * 0x10) * (o * 0x10);
+}
+
+impl Div for u128 {
+    type Output = u128;
+
+    #[inline]
+    fn div(self, other: u128) -> u128 {
+        if other == MIN {
+            panic!("attempt to divide by zero");
+        }
+        self.div_rem(other).0
+    }
+}
+
+impl DivAssign for u128 {
+    #[inline]
+    fn div_assign(&mut self, other: u128) {
+        *self = *self / other;
+    }
+}
+
+impl Rem for u128 {
+    type Output = u128;
+
+    #[inline]
+    fn rem(self, other: u128) -> u128 {
+        if other == MIN {
+            panic!("attempt to calculate the remainder with a divisor of zero");
+        }
+        self.div_rem(other).1
+    }
+}
+
+impl RemAssign for u128 {
+    #[inline]
+    fn rem_assign(&mut self, other: u128) {
+        *self = *self % other;
+    }
+}
+
+macro_rules! u128_shift_impl {
+    ($($trait_:ident $method:ident $assign_trait:ident $assign_method:ident
+       $overflowing:ident |$o:ident| $report:expr;)*) => ($(
+        impl $trait_<u32> for u128 {
+            type Output = u128;
+
+            #[inline]
+            #[rustc_inherit_overflow_checks]
+            fn $method(self, rhs: u32) -> u128 {
+                let (x, overflow) = self.$overflowing(rhs);
+                let $o = overflow as u8;
+                let _ = $report;
+                x
+            }
+        }
+
+        impl $assign_trait<u32> for u128 {
+            #[inline]
+            fn $assign_method(&mut self, rhs: u32) {
+                *self = $trait_::$method(*self, rhs);
+            }
+        }
+    )*)
+}
+
+u128_shift_impl! {
+    Shl shl ShlAssign shl_assign overflowing_shl |o| o << (o * 8);
+    Shr shr ShrAssign shr_assign overflowing_shr |o| o >> (o * 8);
+}
+
+macro_rules! u128_bit_impl {
+    ($($trait_:ident $method:ident $assign_trait:ident $assign_method:ident $op:tt)*) => ($(
+        impl $trait_ for u128 {
+            type Output = u128;
+
+            #[inline]
+            fn $method(self, other: u128) -> u128 {
+                u128::from_parts(self.hi $op other.hi, self.lo $op other.lo)
+            }
+        }
+
+        impl $assign_trait for u128 {
+            #[inline]
+            fn $assign_method(&mut self, other: u128) {
+                *self = *self $op other;
+            }
+        }
+    )*)
+}
+
+u128_bit_impl! {
+    BitAnd bitand BitAndAssign bitand_assign &
+    BitOr bitor BitOrAssign bitor_assign |
+    BitXor bitxor BitXorAssign bitxor_assign ^
+}
+
+impl Not for u128 {
+    type Output = u128;
+
+    #[inline]
+    fn not(self) -> u128 {
+        u128::from_parts(!self.hi, !self.lo)
+    }
+}
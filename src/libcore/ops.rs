@@ -68,8 +68,12 @@
 #![stable(feature = "rust1", since = "1.0.0")]
 
 use cmp::PartialOrd;
+use convert::{From, Infallible};
 use fmt;
-use marker::{Sized, Unsize};
+use marker::{Sized, Unpin, Unsize};
+use option::Option::{self, Some, None};
+use pin::Pin;
+use result::Result::{self, Ok, Err};
 
 /// The `Drop` trait is used to run some code when a value goes out of scope.
 /// This is sometimes called a 'destructor'.
@@ -2145,3 +2149,422 @@ pub trait BoxPlace<Data: ?Sized> : Place<Data> {
     /// Creates a globally fresh place.
     fn make_place() -> Self;
 }
+
+/// Used to tell an operation whether it should exit early or go on as usual.
+///
+/// This is the result of [`Try::branch`](trait.Try.html#tymethod.branch),
+/// and is also useful on its own wherever a search, visitor or traversal
+/// wants to stop as soon as it has found what it was looking for.
+///
+/// # Examples
+///
+/// Stopping a traversal at the first negative number:
+///
+/// ```
+/// #![feature(try_trait_v2)]
+///
+/// use std::ops::ControlFlow;
+///
+/// let r = [2, 4, -1, 8].iter().try_for_each(|&x| {
+///     if x < 0 { ControlFlow::Break(x) } else { ControlFlow::Continue(()) }
+/// });
+/// assert_eq!(r, ControlFlow::Break(-1));
+/// ```
+#[unstable(feature = "try_trait_v2", issue = "0")]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ControlFlow<B, C = ()> {
+    /// Move on to the next phase of the operation as normal.
+    #[unstable(feature = "try_trait_v2", issue = "0")]
+    Continue(C),
+    /// Exit the operation without running subsequent phases.
+    #[unstable(feature = "try_trait_v2", issue = "0")]
+    Break(B),
+}
+
+impl<B, C> ControlFlow<B, C> {
+    /// Returns `true` if this is a `Break` variant.
+    #[unstable(feature = "try_trait_v2", issue = "0")]
+    #[inline]
+    pub fn is_break(&self) -> bool {
+        match *self {
+            ControlFlow::Break(_) => true,
+            ControlFlow::Continue(_) => false,
+        }
+    }
+
+    /// Returns `true` if this is a `Continue` variant.
+    #[unstable(feature = "try_trait_v2", issue = "0")]
+    #[inline]
+    pub fn is_continue(&self) -> bool {
+        !self.is_break()
+    }
+
+    /// Converts the `ControlFlow` into an `Option` which is `Some` if it
+    /// was a `Break`.
+    #[unstable(feature = "try_trait_v2", issue = "0")]
+    #[inline]
+    pub fn break_value(self) -> Option<B> {
+        match self {
+            ControlFlow::Break(b) => Some(b),
+            ControlFlow::Continue(_) => None,
+        }
+    }
+
+    /// Converts the `ControlFlow` into an `Option` which is `Some` if it
+    /// was a `Continue`.
+    #[unstable(feature = "try_trait_v2", issue = "0")]
+    #[inline]
+    pub fn continue_value(self) -> Option<C> {
+        match self {
+            ControlFlow::Continue(c) => Some(c),
+            ControlFlow::Break(_) => None,
+        }
+    }
+}
+
+/// The `?` operator and `Iterator::try_fold`.
+///
+/// A type implementing `Try` is split into an `Output`, which `expr?`
+/// evaluates to, and a `Residual`, which is handed to
+/// [`FromResidual`](trait.FromResidual.html) to build the early return
+/// value of the enclosing function. `expr?` effectively desugars to:
+///
+/// ```rust,ignore
+/// match Try::branch(expr) {
+///     ControlFlow::Continue(val) => val,
+///     ControlFlow::Break(residual) => return FromResidual::from_residual(residual),
+/// }
+/// ```
+///
+/// The residual should only carry what is needed to short-circuit, which
+/// is why `Result<T, E>` uses `Result<Infallible, E>` rather than `E`: this
+/// keeps the `?` of a `Result` from being accepted in a function returning
+/// an unrelated type that happens to implement `FromResidual<E>`.
+///
+/// # Examples
+///
+/// ```
+/// #![feature(try_trait_v2, question_mark)]
+///
+/// use std::ops::{ControlFlow, FromResidual, Try};
+///
+/// #[derive(Debug, PartialEq)]
+/// enum Lookup { Found(u32), Missing }
+///
+/// impl Try for Lookup {
+///     type Output = u32;
+///     type Residual = Lookup;
+///
+///     fn from_output(output: u32) -> Lookup {
+///         Lookup::Found(output)
+///     }
+///
+///     fn branch(self) -> ControlFlow<Lookup, u32> {
+///         match self {
+///             Lookup::Found(x) => ControlFlow::Continue(x),
+///             Lookup::Missing => ControlFlow::Break(Lookup::Missing),
+///         }
+///     }
+/// }
+///
+/// impl FromResidual<Lookup> for Lookup {
+///     fn from_residual(residual: Lookup) -> Lookup {
+///         residual
+///     }
+/// }
+///
+/// fn sum(a: Lookup, b: Lookup) -> Lookup {
+///     Lookup::Found(a? + b?)
+/// }
+///
+/// assert_eq!(sum(Lookup::Found(1), Lookup::Found(2)), Lookup::Found(3));
+/// assert_eq!(sum(Lookup::Found(1), Lookup::Missing), Lookup::Missing);
+/// ```
+#[unstable(feature = "try_trait_v2", issue = "0")]
+pub trait Try {
+    /// The type of the value produced by `?` when *not* short-circuiting.
+    #[unstable(feature = "try_trait_v2", issue = "0")]
+    type Output;
+
+    /// The type of the value passed to `FromResidual::from_residual` when
+    /// short-circuiting.
+    #[unstable(feature = "try_trait_v2", issue = "0")]
+    type Residual;
+
+    /// Constructs the type from its `Output` type.
+    #[unstable(feature = "try_trait_v2", issue = "0")]
+    fn from_output(output: Self::Output) -> Self;
+
+    /// Decides whether `?` should produce a value or short-circuit.
+    #[unstable(feature = "try_trait_v2", issue = "0")]
+    fn branch(self) -> ControlFlow<Self::Residual, Self::Output>;
+}
+
+/// Constructs a type from the residual of a short-circuiting `?`.
+///
+/// This is what decides which types `?` can be used on in a function
+/// returning `Self`, and how their residuals are converted.
+#[unstable(feature = "try_trait_v2", issue = "0")]
+pub trait FromResidual<R> {
+    /// Constructs the type from a compatible `Residual` type.
+    #[unstable(feature = "try_trait_v2", issue = "0")]
+    fn from_residual(residual: R) -> Self;
+}
+
+/// Allows finding the `Try` type with a different `Output` but the same kind
+/// of residual, e.g. `Result<U, E>` from the residual of a `Result<T, E>`.
+///
+/// This is what generic fallible helpers like `Iterator::try_find` use to
+/// return the same kind of `Try` type that their closure returns, wrapped
+/// around a different value.
+///
+/// # Examples
+///
+/// ```
+/// #![feature(try_trait_v2)]
+///
+/// use std::ops::{ChangeOutputType, ControlFlow, FromResidual, Residual, Try};
+///
+/// // Applies `f` to both halves of a pair, stopping at the first failure.
+/// fn try_both<T, U, F, R>(pair: (T, T), mut f: F) -> ChangeOutputType<R, (U, U)>
+///     where F: FnMut(T) -> R,
+///           R: Try<Output = U>,
+///           <R as Try>::Residual: Residual<(U, U)>,
+/// {
+///     let a = match f(pair.0).branch() {
+///         ControlFlow::Continue(a) => a,
+///         ControlFlow::Break(r) => return FromResidual::from_residual(r),
+///     };
+///     let b = match f(pair.1).branch() {
+///         ControlFlow::Continue(b) => b,
+///         ControlFlow::Break(r) => return FromResidual::from_residual(r),
+///     };
+///     Try::from_output((a, b))
+/// }
+///
+/// assert_eq!(try_both(("1", "2"), |s| s.parse::<i32>()), Ok((1, 2)));
+/// assert!(try_both(("1", "x"), |s| s.parse::<i32>()).is_err());
+/// assert_eq!(try_both((4u8, 200), |x| x.checked_add(50)), None);
+/// ```
+#[unstable(feature = "try_trait_v2", issue = "0")]
+pub trait Residual<O> {
+    /// The `Try` type with this residual and an `Output` of `O`.
+    #[unstable(feature = "try_trait_v2", issue = "0")]
+    type TryType: Try<Output = O, Residual = Self> + FromResidual<Self>;
+}
+
+/// The `Try` type that has the same residual as `T`, but an `Output` of `V`.
+#[unstable(feature = "try_trait_v2", issue = "0")]
+pub type ChangeOutputType<T, V> = <<T as Try>::Residual as Residual<V>>::TryType;
+
+#[unstable(feature = "try_trait_v2", issue = "0")]
+impl<B, C> Residual<C> for ControlFlow<B, Infallible> {
+    type TryType = ControlFlow<B, C>;
+}
+
+#[unstable(feature = "try_trait_v2", issue = "0")]
+impl<T> Residual<T> for Option<Infallible> {
+    type TryType = Option<T>;
+}
+
+#[unstable(feature = "try_trait_v2", issue = "0")]
+impl<T, E> Residual<T> for Result<Infallible, E> {
+    type TryType = Result<T, E>;
+}
+
+#[unstable(feature = "try_trait_v2", issue = "0")]
+impl<B, C> Try for ControlFlow<B, C> {
+    type Output = C;
+    type Residual = ControlFlow<B, Infallible>;
+
+    #[inline]
+    fn from_output(output: C) -> ControlFlow<B, C> {
+        ControlFlow::Continue(output)
+    }
+
+    #[inline]
+    fn branch(self) -> ControlFlow<ControlFlow<B, Infallible>, C> {
+        match self {
+            ControlFlow::Continue(c) => ControlFlow::Continue(c),
+            ControlFlow::Break(b) => ControlFlow::Break(ControlFlow::Break(b)),
+        }
+    }
+}
+
+#[unstable(feature = "try_trait_v2", issue = "0")]
+impl<B, C> FromResidual<ControlFlow<B, Infallible>> for ControlFlow<B, C> {
+    #[inline]
+    fn from_residual(residual: ControlFlow<B, Infallible>) -> ControlFlow<B, C> {
+        match residual {
+            ControlFlow::Break(b) => ControlFlow::Break(b),
+            ControlFlow::Continue(never) => match never {},
+        }
+    }
+}
+
+#[unstable(feature = "try_trait_v2", issue = "0")]
+impl<T> Try for Option<T> {
+    type Output = T;
+    type Residual = Option<Infallible>;
+
+    #[inline]
+    fn from_output(output: T) -> Option<T> {
+        Some(output)
+    }
+
+    #[inline]
+    fn branch(self) -> ControlFlow<Option<Infallible>, T> {
+        match self {
+            Some(v) => ControlFlow::Continue(v),
+            None => ControlFlow::Break(None),
+        }
+    }
+}
+
+#[unstable(feature = "try_trait_v2", issue = "0")]
+impl<T> FromResidual<Option<Infallible>> for Option<T> {
+    #[inline]
+    fn from_residual(residual: Option<Infallible>) -> Option<T> {
+        match residual {
+            None => None,
+            Some(never) => match never {},
+        }
+    }
+}
+
+#[unstable(feature = "try_trait_v2", issue = "0")]
+impl<T, E> Try for Result<T, E> {
+    type Output = T;
+    type Residual = Result<Infallible, E>;
+
+    #[inline]
+    fn from_output(output: T) -> Result<T, E> {
+        Ok(output)
+    }
+
+    #[inline]
+    fn branch(self) -> ControlFlow<Result<Infallible, E>, T> {
+        match self {
+            Ok(v) => ControlFlow::Continue(v),
+            Err(e) => ControlFlow::Break(Err(e)),
+        }
+    }
+}
+
+#[unstable(feature = "try_trait_v2", issue = "0")]
+impl<T, E, F: From<E>> FromResidual<Result<Infallible, E>> for Result<T, F> {
+    #[inline]
+    fn from_residual(residual: Result<Infallible, E>) -> Result<T, F> {
+        match residual {
+            Err(e) => Err(From::from(e)),
+            Ok(never) => match never {},
+        }
+    }
+}
+
+/// The result of a coroutine resumption.
+///
+/// This enum is returned from the `Coroutine::resume` method and indicates
+/// the possible return values of a coroutine.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[unstable(feature = "coroutine_trait", issue = "0")]
+pub enum CoroutineState<Y, R> {
+    /// The coroutine suspended with a value.
+    ///
+    /// The coroutine can be resumed again to continue its execution.
+    Yielded(Y),
+
+    /// The coroutine completed with a return value.
+    ///
+    /// It is unspecified what happens when a completed coroutine is resumed
+    /// again; implementations typically panic.
+    Complete(R),
+}
+
+/// A resumable computation, such as a state machine that yields values
+/// while it runs and produces a final value once it is done.
+///
+/// Each call to `resume` passes in a value of type `R` and continues the
+/// computation until it either yields a `Yield` value or completes with a
+/// `Return` value. Coroutines are pinned while they are resumed, so an
+/// implementation may keep references into its own state across
+/// suspensions.
+///
+/// `resume` takes the pinned coroutine as an explicit argument, so it is
+/// called as `Coroutine::resume(coroutine.as_mut(), arg)`.
+///
+/// # Examples
+///
+/// ```
+/// #![feature(coroutine_trait, pin)]
+///
+/// use std::ops::{Coroutine, CoroutineState};
+/// use std::pin::Pin;
+///
+/// // Sums the values it is resumed with, yielding each partial sum, and
+/// // completes once the sum reaches 10.
+/// struct Accumulate(u32);
+///
+/// impl Coroutine<u32> for Accumulate {
+///     type Yield = u32;
+///     type Return = &'static str;
+///
+///     fn resume(mut this: Pin<&mut Self>, arg: u32)
+///               -> CoroutineState<u32, &'static str> {
+///         this.0 += arg;
+///         if this.0 >= 10 {
+///             CoroutineState::Complete("done")
+///         } else {
+///             CoroutineState::Yielded(this.0)
+///         }
+///     }
+/// }
+///
+/// let mut acc = Accumulate(0);
+/// let mut acc = Pin::new(&mut acc);
+/// assert_eq!(Coroutine::resume(acc.as_mut(), 4), CoroutineState::Yielded(4));
+/// assert_eq!(Coroutine::resume(acc.as_mut(), 4), CoroutineState::Yielded(8));
+/// assert_eq!(Coroutine::resume(acc.as_mut(), 4), CoroutineState::Complete("done"));
+/// ```
+#[unstable(feature = "coroutine_trait", issue = "0")]
+pub trait Coroutine<R = ()> {
+    /// The type of value this coroutine yields.
+    #[unstable(feature = "coroutine_trait", issue = "0")]
+    type Yield;
+
+    /// The type of value this coroutine returns once it completes.
+    #[unstable(feature = "coroutine_trait", issue = "0")]
+    type Return;
+
+    /// Resumes the execution of this coroutine with `arg`.
+    ///
+    /// Returns `Yielded` if the coroutine suspended and can be resumed
+    /// again, or `Complete` if it has finished.
+    ///
+    /// # Panics
+    ///
+    /// This function may panic if it is called after the `Complete`
+    /// variant has been returned previously.
+    #[unstable(feature = "coroutine_trait", issue = "0")]
+    fn resume(this: Pin<&mut Self>, arg: R) -> CoroutineState<Self::Yield, Self::Return>;
+}
+
+#[unstable(feature = "coroutine_trait", issue = "0")]
+impl<'a, C: ?Sized + Coroutine<R> + Unpin, R> Coroutine<R> for &'a mut C {
+    type Yield = C::Yield;
+    type Return = C::Return;
+
+    fn resume(this: Pin<&mut &'a mut C>, arg: R) -> CoroutineState<C::Yield, C::Return> {
+        C::resume(Pin::new(&mut **this.get_mut()), arg)
+    }
+}
+
+#[unstable(feature = "coroutine_trait", issue = "0")]
+impl<'a, C: ?Sized + Coroutine<R>, R> Coroutine<R> for Pin<&'a mut C> {
+    type Yield = C::Yield;
+    type Return = C::Return;
+
+    fn resume(this: Pin<&mut Pin<&'a mut C>>, arg: R) -> CoroutineState<C::Yield, C::Return> {
+        C::resume(this.as_deref_mut(), arg)
+    }
+}
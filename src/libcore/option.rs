@@ -146,7 +146,7 @@ use default::Default;
 use iter::ExactSizeIterator;
 use iter::{Iterator, DoubleEndedIterator, FromIterator, IntoIterator};
 use mem;
-use ops::FnOnce;
+use ops::{ChangeOutputType, ControlFlow, FnOnce, FromResidual, Residual, Try};
 use result::Result::{Ok, Err};
 use result::Result;
 
@@ -387,6 +387,40 @@ impl<T> Option<T> {
         }
     }
 
+    /// Maps an `Option<T>` to `Option<U>` by applying a fallible function to
+    /// a contained value, and returns the failure if it fails.
+    ///
+    /// The function returns a `Try` type like `Result<U, E>`, and `try_map`
+    /// returns the same kind of type wrapped around the `Option<U>`, so that
+    /// `None` is mapped to a success.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(try_trait_v2)]
+    ///
+    /// assert_eq!(Some("5").try_map(|s| s.parse::<i32>()), Ok(Some(5)));
+    /// assert_eq!(None::<&str>.try_map(|s| s.parse::<i32>()), Ok(None));
+    /// assert!(Some("x").try_map(|s| s.parse::<i32>()).is_err());
+    ///
+    /// assert_eq!(Some(200u8).try_map(|x| x.checked_add(100)), None);
+    /// ```
+    #[inline]
+    #[unstable(feature = "try_trait_v2", issue = "0")]
+    pub fn try_map<U, F, R>(self, f: F) -> ChangeOutputType<R, Option<U>> where
+        F: FnOnce(T) -> R,
+        R: Try<Output = U>,
+        <R as Try>::Residual: Residual<Option<U>>,
+    {
+        match self {
+            Some(x) => match f(x).branch() {
+                ControlFlow::Continue(u) => Try::from_output(Some(u)),
+                ControlFlow::Break(r) => FromResidual::from_residual(r),
+            },
+            None => Try::from_output(None),
+        }
+    }
+
     /// Applies a function to the contained value (if any),
     /// or returns a `default` (if not).
     ///
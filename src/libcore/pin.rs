@@ -0,0 +1,219 @@
+// Copyright 2016 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Types that pin data to its location in memory.
+//!
+//! A [`Pin<P>`] wraps a pointer `P` and guarantees that the value it points
+//! to will not be moved again until it is dropped, unless the value
+//! implements [`Unpin`]. This is what allows self-referential values, such
+//! as the state of a suspended computation, to be handed out by pointer.
+//!
+//! Values on the heap can be pinned by pinning their owning pointer; values
+//! on the stack are pinned with the [`pin!`] macro, which takes ownership of
+//! the value so that it can no longer be reached unpinned.
+//!
+//! [`Pin<P>`]: struct.Pin.html
+//! [`Unpin`]: ../marker/trait.Unpin.html
+//! [`pin!`]: ../macro.pin.html
+
+#![unstable(feature = "pin", issue = "0")]
+
+use fmt;
+use marker::{Sized, Unpin};
+use ops::{Deref, DerefMut};
+
+/// A pinned pointer.
+///
+/// This is a wrapper around a kind of pointer which makes that pointer
+/// "pin" its value in place, preventing the value referenced by that
+/// pointer from being moved unless it implements `Unpin`.
+///
+/// See the [module documentation](index.html) for more.
+#[derive(Copy, Clone, Hash, Eq, PartialEq, Ord, PartialOrd)]
+pub struct Pin<P> {
+    // Only public so that `pin!` can construct a `Pin` without `unsafe`;
+    // it must not be used anywhere else.
+    #[unstable(feature = "unsafe_pin_internals", issue = "0")]
+    #[doc(hidden)]
+    pub __pointer: P,
+}
+
+impl<P: Deref> Pin<P> where P::Target: Unpin {
+    /// Constructs a new `Pin<P>` around a pointer to some data of a type
+    /// that implements `Unpin`.
+    ///
+    /// Unlike `new_unchecked`, this is safe, because the pointee does not
+    /// rely on the pinning guarantees.
+    #[inline]
+    pub fn new(pointer: P) -> Pin<P> {
+        Pin { __pointer: pointer }
+    }
+
+    /// Unwraps this `Pin<P>`, returning the underlying pointer.
+    #[inline]
+    pub fn into_inner(pin: Pin<P>) -> P {
+        pin.__pointer
+    }
+}
+
+impl<P: Deref> Pin<P> {
+    /// Constructs a new `Pin<P>` around a pointer to some data of a type
+    /// that may or may not implement `Unpin`.
+    ///
+    /// # Safety
+    ///
+    /// The caller must guarantee that the data pointed to by `pointer` is
+    /// never moved again, even after the `Pin` is gone, until it is
+    /// dropped. In particular, `P`'s `Deref` and `DerefMut` impls must not
+    /// move out of the pointee.
+    #[inline]
+    pub unsafe fn new_unchecked(pointer: P) -> Pin<P> {
+        Pin { __pointer: pointer }
+    }
+
+    /// Gets a pinned shared reference from this pinned pointer.
+    #[inline]
+    pub fn as_ref(&self) -> Pin<&P::Target> {
+        Pin { __pointer: &*self.__pointer }
+    }
+
+    /// Unwraps this `Pin<P>`, returning the underlying pointer.
+    ///
+    /// # Safety
+    ///
+    /// The caller must keep upholding the pinning guarantees through the
+    /// returned pointer.
+    #[inline]
+    pub unsafe fn into_inner_unchecked(pin: Pin<P>) -> P {
+        pin.__pointer
+    }
+}
+
+impl<P: DerefMut> Pin<P> {
+    /// Gets a pinned mutable reference from this pinned pointer.
+    ///
+    /// This is useful to call a method taking a `Pin<&mut T>` several times
+    /// through the same pinned pointer.
+    #[inline]
+    pub fn as_mut(&mut self) -> Pin<&mut P::Target> {
+        Pin { __pointer: &mut *self.__pointer }
+    }
+
+    /// Assigns a new value to the memory behind the pinned pointer.
+    ///
+    /// The old value is dropped in place, which does not violate the
+    /// pinning guarantees.
+    #[inline]
+    pub fn set(&mut self, value: P::Target) where P::Target: Sized {
+        *self.__pointer = value;
+    }
+}
+
+impl<'a, T: ?Sized> Pin<&'a T> {
+    /// Gets a shared reference to the pinned value.
+    ///
+    /// This is safe, because a `&T` does not allow moving out of the value.
+    #[inline]
+    pub fn get_ref(self) -> &'a T {
+        self.__pointer
+    }
+}
+
+impl<'a, T: ?Sized> Pin<&'a mut T> {
+    /// Converts this `Pin<&mut T>` into a `Pin<&T>`.
+    #[inline]
+    pub fn into_ref(self) -> Pin<&'a T> {
+        Pin { __pointer: self.__pointer }
+    }
+
+    /// Gets a mutable reference to the pinned value, which implements
+    /// `Unpin`.
+    #[inline]
+    pub fn get_mut(self) -> &'a mut T where T: Unpin {
+        self.__pointer
+    }
+
+    /// Gets a mutable reference to the pinned value.
+    ///
+    /// # Safety
+    ///
+    /// The caller must not move the value out of the returned reference.
+    #[inline]
+    pub unsafe fn get_unchecked_mut(self) -> &'a mut T {
+        self.__pointer
+    }
+}
+
+impl<T: ?Sized> Pin<&'static T> {
+    /// Gets a pinning reference from a `'static` reference.
+    ///
+    /// This is safe, because the value is borrowed for the whole program
+    /// and can never be moved.
+    #[inline]
+    pub fn static_ref(r: &'static T) -> Pin<&'static T> {
+        Pin { __pointer: r }
+    }
+}
+
+impl<T: ?Sized> Pin<&'static mut T> {
+    /// Gets a pinning mutable reference from a `'static` mutable reference.
+    ///
+    /// This is safe, because the value is borrowed for the whole program
+    /// and can never be moved.
+    #[inline]
+    pub fn static_mut(r: &'static mut T) -> Pin<&'static mut T> {
+        Pin { __pointer: r }
+    }
+}
+
+impl<'a, P: DerefMut> Pin<&'a mut Pin<P>> {
+    /// Gets a pinned mutable reference to the value behind a pinned
+    /// mutable reference to a pinned pointer.
+    ///
+    /// This is a generic way to go from `Pin<&mut Pin<Box<T>>>`, or any
+    /// other nested pinned pointer, to `Pin<&mut T>`, and is safe because
+    /// the inner `Pin` already guarantees that its pointee never moves.
+    #[inline]
+    pub fn as_deref_mut(self) -> Pin<&'a mut P::Target> {
+        Pin { __pointer: &mut *self.__pointer.__pointer }
+    }
+}
+
+impl<P: Deref> Deref for Pin<P> {
+    type Target = P::Target;
+
+    fn deref(&self) -> &P::Target {
+        &*self.__pointer
+    }
+}
+
+impl<P: DerefMut> DerefMut for Pin<P> where P::Target: Unpin {
+    fn deref_mut(&mut self) -> &mut P::Target {
+        &mut *self.__pointer
+    }
+}
+
+impl<P: fmt::Debug> fmt::Debug for Pin<P> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Debug::fmt(&self.__pointer, f)
+    }
+}
+
+impl<P: fmt::Display> fmt::Display for Pin<P> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Display::fmt(&self.__pointer, f)
+    }
+}
+
+impl<P: fmt::Pointer> fmt::Pointer for Pin<P> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Pointer::fmt(&self.__pointer, f)
+    }
+}
@@ -0,0 +1,359 @@
+// Copyright 2016 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Copyable range types.
+//!
+//! The range types in [`ops`](../ops/index.html), which the `a..b` syntax
+//! produces, are iterators themselves. That is why they can't be `Copy`:
+//! copying an iterator by accident and advancing the copy is a common bug.
+//! It also makes them awkward to store in structs and to take by value in
+//! APIs that only want the bounds.
+//!
+//! The types in this module hold the same bounds, but are `Copy` and only
+//! implement `IntoIterator`. They convert to and from their counterparts
+//! in `ops` with `From`, and can index slices and strings and be passed to
+//! `slice::get` just like them, so an API can take
+//! `R: Into<range::Range<usize>>` to accept either.
+//!
+//! The ranges that are not iterators, `..`, `..b` and `...b`, are the same
+//! types as in `ops`, and are re-exported here so that this module has all
+//! of them.
+//!
+//! # Examples
+//!
+//! ```
+//! #![feature(new_range_api)]
+//!
+//! use std::range::Range;
+//!
+//! #[derive(Clone, Copy)]
+//! struct Token {
+//!     kind: u8,
+//!     span: Range<usize>,
+//! }
+//!
+//! let source = "let x";
+//! let token = Token { kind: 0, span: (4..5).into() };
+//! let copy = token;
+//! assert_eq!(&source[token.span], "x");
+//! assert_eq!(copy.span.into_iter().collect::<Vec<_>>(), [4]);
+//! ```
+
+#![unstable(feature = "new_range_api", issue = "0")]
+
+use clone::Clone;
+use cmp::PartialOrd;
+use convert::From;
+use fmt;
+use iter::{Iterator, IntoIterator, DoubleEndedIterator, ExactSizeIterator, Step};
+use ops::{self, Add, Sub};
+use option::Option::{self, Some, None};
+
+#[unstable(feature = "new_range_api", issue = "0")]
+pub use ops::{RangeFull, RangeTo, RangeToInclusive};
+
+/// A (half-open) range bounded inclusively below and exclusively above:
+/// `{ x | start <= x < end }`.
+///
+/// This is the copyable counterpart of [`ops::Range`], and converts to and
+/// from it with `From`.
+///
+/// [`ops::Range`]: ../ops/struct.Range.html
+#[derive(Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct Range<Idx> {
+    /// The lower bound of the range (inclusive).
+    pub start: Idx,
+    /// The upper bound of the range (exclusive).
+    pub end: Idx,
+}
+
+impl<Idx: fmt::Debug> fmt::Debug for Range<Idx> {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        write!(fmt, "{:?}..{:?}", self.start, self.end)
+    }
+}
+
+impl<Idx: PartialOrd<Idx>> Range<Idx> {
+    /// Returns `true` if `item` is contained in the range.
+    pub fn contains(&self, item: Idx) -> bool {
+        (self.start <= item) && (item < self.end)
+    }
+
+    /// Returns `true` if the range contains no items.
+    pub fn is_empty(&self) -> bool {
+        !(self.start < self.end)
+    }
+}
+
+impl<A: Step> Range<A> where for<'a> &'a A: Add<&'a A, Output = A> {
+    /// Creates an iterator over the elements within this range.
+    ///
+    /// Shorthand for `.clone().into_iter()`.
+    pub fn iter(&self) -> IterRange<A> where A: Clone {
+        self.clone().into_iter()
+    }
+}
+
+impl<Idx> From<ops::Range<Idx>> for Range<Idx> {
+    fn from(range: ops::Range<Idx>) -> Range<Idx> {
+        Range { start: range.start, end: range.end }
+    }
+}
+
+impl<Idx> From<Range<Idx>> for ops::Range<Idx> {
+    fn from(range: Range<Idx>) -> ops::Range<Idx> {
+        range.start..range.end
+    }
+}
+
+impl<A: Step> IntoIterator for Range<A> where for<'a> &'a A: Add<&'a A, Output = A> {
+    type Item = A;
+    type IntoIter = IterRange<A>;
+
+    fn into_iter(self) -> IterRange<A> {
+        IterRange(self.into())
+    }
+}
+
+/// An iterator over a [`Range`](struct.Range.html).
+#[derive(Clone, Debug)]
+pub struct IterRange<A>(ops::Range<A>);
+
+impl<A> IterRange<A> {
+    /// Returns the remainder of the range being iterated over.
+    pub fn remainder(self) -> Range<A> {
+        self.0.into()
+    }
+}
+
+impl<A: Step> Iterator for IterRange<A> where for<'a> &'a A: Add<&'a A, Output = A> {
+    type Item = A;
+
+    #[inline]
+    fn next(&mut self) -> Option<A> {
+        self.0.next()
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.0.size_hint()
+    }
+
+    #[inline]
+    fn nth(&mut self, n: usize) -> Option<A> {
+        self.0.nth(n)
+    }
+}
+
+impl<A: Step + Clone> DoubleEndedIterator for IterRange<A> where
+    for<'a> &'a A: Add<&'a A, Output = A>,
+    for<'a> &'a A: Sub<&'a A, Output = A>
+{
+    #[inline]
+    fn next_back(&mut self) -> Option<A> {
+        self.0.next_back()
+    }
+}
+
+/// A range only bounded inclusively below: `{ x | start <= x }`.
+///
+/// This is the copyable counterpart of [`ops::RangeFrom`], and converts to
+/// and from it with `From`.
+///
+/// [`ops::RangeFrom`]: ../ops/struct.RangeFrom.html
+#[derive(Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct RangeFrom<Idx> {
+    /// The lower bound of the range (inclusive).
+    pub start: Idx,
+}
+
+impl<Idx: fmt::Debug> fmt::Debug for RangeFrom<Idx> {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        write!(fmt, "{:?}..", self.start)
+    }
+}
+
+impl<Idx: PartialOrd<Idx>> RangeFrom<Idx> {
+    /// Returns `true` if `item` is contained in the range.
+    pub fn contains(&self, item: Idx) -> bool {
+        self.start <= item
+    }
+}
+
+impl<A: Step> RangeFrom<A> where for<'a> &'a A: Add<&'a A, Output = A> {
+    /// Creates an iterator over the elements within this range.
+    ///
+    /// Shorthand for `.clone().into_iter()`.
+    pub fn iter(&self) -> IterRangeFrom<A> where A: Clone {
+        self.clone().into_iter()
+    }
+}
+
+impl<Idx> From<ops::RangeFrom<Idx>> for RangeFrom<Idx> {
+    fn from(range: ops::RangeFrom<Idx>) -> RangeFrom<Idx> {
+        RangeFrom { start: range.start }
+    }
+}
+
+impl<Idx> From<RangeFrom<Idx>> for ops::RangeFrom<Idx> {
+    fn from(range: RangeFrom<Idx>) -> ops::RangeFrom<Idx> {
+        range.start..
+    }
+}
+
+impl<A: Step> IntoIterator for RangeFrom<A> where for<'a> &'a A: Add<&'a A, Output = A> {
+    type Item = A;
+    type IntoIter = IterRangeFrom<A>;
+
+    fn into_iter(self) -> IterRangeFrom<A> {
+        IterRangeFrom(self.into())
+    }
+}
+
+/// An iterator over a [`RangeFrom`](struct.RangeFrom.html).
+#[derive(Clone, Debug)]
+pub struct IterRangeFrom<A>(ops::RangeFrom<A>);
+
+impl<A> IterRangeFrom<A> {
+    /// Returns the remainder of the range being iterated over.
+    pub fn remainder(self) -> RangeFrom<A> {
+        self.0.into()
+    }
+}
+
+impl<A: Step> Iterator for IterRangeFrom<A> where for<'a> &'a A: Add<&'a A, Output = A> {
+    type Item = A;
+
+    #[inline]
+    fn next(&mut self) -> Option<A> {
+        self.0.next()
+    }
+
+    #[inline]
+    fn nth(&mut self, n: usize) -> Option<A> {
+        self.0.nth(n)
+    }
+}
+
+/// A range bounded inclusively below and above: `{ x | start <= x <= end }`.
+///
+/// This is the copyable counterpart of [`ops::RangeInclusive`]. Unlike it,
+/// it always holds both bounds, and is empty when `start > end`. It
+/// converts into an `ops::RangeInclusive` with `From`; the other direction
+/// is not provided, because an exhausted `ops::RangeInclusive` no longer
+/// has both of its bounds.
+///
+/// [`ops::RangeInclusive`]: ../ops/enum.RangeInclusive.html
+#[derive(Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct RangeInclusive<Idx> {
+    /// The lower bound of the range (inclusive).
+    pub start: Idx,
+    /// The upper bound of the range (inclusive).
+    pub end: Idx,
+}
+
+impl<Idx: fmt::Debug> fmt::Debug for RangeInclusive<Idx> {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        write!(fmt, "{:?}...{:?}", self.start, self.end)
+    }
+}
+
+impl<Idx: PartialOrd<Idx>> RangeInclusive<Idx> {
+    /// Returns `true` if `item` is contained in the range.
+    pub fn contains(&self, item: Idx) -> bool {
+        (self.start <= item) && (item <= self.end)
+    }
+
+    /// Returns `true` if the range contains no items.
+    pub fn is_empty(&self) -> bool {
+        !(self.start <= self.end)
+    }
+}
+
+impl<A: Step> RangeInclusive<A> {
+    /// Creates an iterator over the elements within this range.
+    ///
+    /// Shorthand for `.clone().into_iter()`.
+    pub fn iter(&self) -> IterRangeInclusive<A> where A: Clone {
+        self.clone().into_iter()
+    }
+}
+
+impl<Idx> From<RangeInclusive<Idx>> for ops::RangeInclusive<Idx> {
+    fn from(range: RangeInclusive<Idx>) -> ops::RangeInclusive<Idx> {
+        ops::RangeInclusive::NonEmpty { start: range.start, end: range.end }
+    }
+}
+
+impl<A: Step> IntoIterator for RangeInclusive<A> {
+    type Item = A;
+    type IntoIter = IterRangeInclusive<A>;
+
+    fn into_iter(self) -> IterRangeInclusive<A> {
+        IterRangeInclusive(self.into())
+    }
+}
+
+/// An iterator over a [`RangeInclusive`](struct.RangeInclusive.html).
+#[derive(Clone, Debug)]
+pub struct IterRangeInclusive<A>(ops::RangeInclusive<A>);
+
+impl<A> IterRangeInclusive<A> {
+    /// Returns the remainder of the range being iterated over, or `None`
+    /// if the iterator is exhausted.
+    pub fn remainder(self) -> Option<RangeInclusive<A>> {
+        match self.0 {
+            ops::RangeInclusive::NonEmpty { start, end } => {
+                Some(RangeInclusive { start: start, end: end })
+            }
+            ops::RangeInclusive::Empty { .. } => None,
+        }
+    }
+}
+
+impl<A: Step> Iterator for IterRangeInclusive<A> {
+    type Item = A;
+
+    #[inline]
+    fn next(&mut self) -> Option<A> {
+        self.0.next()
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.0.size_hint()
+    }
+
+    #[inline]
+    fn nth(&mut self, n: usize) -> Option<A> {
+        self.0.nth(n)
+    }
+}
+
+impl<A: Step> DoubleEndedIterator for IterRangeInclusive<A> {
+    #[inline]
+    fn next_back(&mut self) -> Option<A> {
+        self.0.next_back()
+    }
+}
+
+macro_rules! range_exact_iter_impl {
+    ($($t:ty)*) => ($(
+        impl ExactSizeIterator for IterRange<$t> { }
+        impl ExactSizeIterator for IterRangeInclusive<$t> { }
+    )*)
+}
+
+// Like their `ops` counterparts, ranges of u64 and i64 are excluded because
+// they cannot guarantee having a length <= usize::MAX, and only inclusive
+// ranges of chars iterate.
+range_exact_iter_impl!(usize u8 u16 u32 isize i8 i16 i32);
+
+impl ExactSizeIterator for IterRangeInclusive<char> { }
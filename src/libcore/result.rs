@@ -986,3 +986,131 @@ impl<A, E, V: FromIterator<A>> FromIterator<Result<A, E>> for Result<V, E> {
         }
     }
 }
+
+/// The successes collected from an iterator of `Result`s or `Option`s up to
+/// its first failure, together with that failure.
+///
+/// Collecting into a `Result<V, E>` discards the values that were already
+/// collected when an `Err` turns up. Collecting into a `Partial<V, E>` stops
+/// at the same point, but keeps them, and records the position of the `Err`
+/// in the input. For an iterator of `Option`s, the error is `()`.
+///
+/// # Examples
+///
+/// ```
+/// #![feature(collect_partial)]
+///
+/// use std::result::Partial;
+///
+/// let lines = ["1", "2", "x", "4"];
+/// let p: Partial<Vec<i32>, _> = lines.iter().map(|s| s.parse::<i32>()).collect();
+/// assert_eq!(p.values(), &[1, 2]);
+/// assert_eq!(p.error().map(|(i, _)| i), Some(2));
+///
+/// let p: Partial<Vec<i32>, ()> = vec![Some(1), None, Some(3)].into_iter().collect();
+/// assert_eq!(p.into_parts(), (vec![1], Some((1, ()))));
+/// ```
+#[derive(Clone, PartialEq, Eq, Debug)]
+#[unstable(feature = "collect_partial", issue = "0")]
+pub struct Partial<V, E> {
+    values: V,
+    error: Option<(usize, E)>,
+}
+
+impl<V, E> Partial<V, E> {
+    /// Returns the values collected before the first error.
+    #[unstable(feature = "collect_partial", issue = "0")]
+    pub fn values(&self) -> &V {
+        &self.values
+    }
+
+    /// Returns the first error and its index in the input, or `None` if the
+    /// whole input was collected.
+    #[unstable(feature = "collect_partial", issue = "0")]
+    pub fn error(&self) -> Option<(usize, &E)> {
+        match self.error {
+            Some((i, ref e)) => Some((i, e)),
+            None => None,
+        }
+    }
+
+    /// Returns `true` if there was no error.
+    #[unstable(feature = "collect_partial", issue = "0")]
+    pub fn is_complete(&self) -> bool {
+        self.error.is_none()
+    }
+
+    /// Converts into the collected values and the first error with its index.
+    #[unstable(feature = "collect_partial", issue = "0")]
+    pub fn into_parts(self) -> (V, Option<(usize, E)>) {
+        (self.values, self.error)
+    }
+
+    /// Converts into a `Result`, which is `Ok` only if there was no error.
+    #[unstable(feature = "collect_partial", issue = "0")]
+    pub fn into_result(self) -> Result<V, (usize, E)> {
+        match self.error {
+            Some(err) => Err(err),
+            None => Ok(self.values),
+        }
+    }
+}
+
+// Like the adapter of `Result::from_iter`, but also counts the elements.
+struct PartialAdapter<Iter, E> {
+    iter: Iter,
+    index: usize,
+    err: Option<(usize, E)>,
+}
+
+impl<T, E, Iter: Iterator<Item=Result<T, E>>> Iterator for PartialAdapter<Iter, E> {
+    type Item = T;
+
+    #[inline]
+    fn next(&mut self) -> Option<T> {
+        match self.iter.next() {
+            Some(Ok(value)) => {
+                self.index += 1;
+                Some(value)
+            }
+            Some(Err(err)) => {
+                self.err = Some((self.index, err));
+                None
+            }
+            None => None,
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, self.iter.size_hint().1)
+    }
+}
+
+fn collect_partial<V, T, E, I>(iter: I) -> Partial<V, E>
+    where V: FromIterator<T>, I: Iterator<Item=Result<T, E>>
+{
+    let mut adapter = PartialAdapter { iter: iter, index: 0, err: None };
+    let values: V = FromIterator::from_iter(adapter.by_ref());
+    Partial { values: values, error: adapter.err }
+}
+
+#[unstable(feature = "collect_partial", issue = "0")]
+impl<A, E, V: FromIterator<A>> FromIterator<Result<A, E>> for Partial<V, E> {
+    /// Takes each element in the `Iterator` until the first `Err`, and
+    /// collects the values of the `Ok`s before it.
+    #[inline]
+    fn from_iter<I: IntoIterator<Item=Result<A, E>>>(iter: I) -> Partial<V, E> {
+        collect_partial(iter.into_iter())
+    }
+}
+
+#[unstable(feature = "collect_partial", issue = "0")]
+impl<A, V: FromIterator<A>> FromIterator<Option<A>> for Partial<V, ()> {
+    /// Takes each element in the `Iterator` until the first `None`, and
+    /// collects the values of the `Some`s before it.
+    #[inline]
+    fn from_iter<I: IntoIterator<Item=Option<A>>>(iter: I) -> Partial<V, ()> {
+        collect_partial(iter.into_iter().map(|x| x.ok_or(())))
+    }
+}
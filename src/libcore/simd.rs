@@ -0,0 +1,696 @@
+// Copyright 2016 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Portable SIMD vector types.
+//!
+//! This module provides 128-bit vector types that work the same way on every
+//! platform. Operations on them are lowered through the generic `simd_*`
+//! platform intrinsics, so they compile to the native vector instructions
+//! where the target has them and to scalar code elsewhere, without any `cfg`
+//! on the caller's side.
+//!
+//! The vector types are named after their lane type and lane count, like
+//! `f32x4` for four `f32` lanes. Every vector type has a corresponding mask
+//! type, like `m32x4`, which is what lane-wise comparisons return and what
+//! `select` takes to choose lanes from two vectors.
+//!
+//! Integer arithmetic wraps around on overflow, in every lane. Shifts take
+//! the shift amount for every lane from the corresponding lane of the
+//! right-hand side, and like `wrapping_shl` only use its bits below the width
+//! of a lane.
+//!
+//! Code that is generic over the vector types is written against the `Simd`
+//! trait, which gives the lane type and count of each of them.
+//!
+//! # Examples
+//!
+//! ```
+//! #![feature(portable_simd)]
+//! use std::simd::f32x4;
+//!
+//! let a = f32x4::new(1.0, 2.0, 3.0, 4.0);
+//! let b = f32x4::splat(10.0);
+//! let c = a * b + a;
+//! assert_eq!(c.to_array(), [11.0, 22.0, 33.0, 44.0]);
+//!
+//! let mask = c.gt(f32x4::splat(25.0));
+//! assert!(mask.any() && !mask.all());
+//! assert_eq!(mask.select(a, b).to_array(), [10.0, 10.0, 3.0, 4.0]);
+//! ```
+
+#![unstable(feature = "portable_simd",
+            reason = "recently added, the set of types and operations may change",
+            issue = "0")]
+#![allow(non_camel_case_types)]
+
+use prelude::v1::*;
+
+use fmt;
+use mem;
+use ops::{Add, Sub, Mul, Div, Neg, Not, BitAnd, BitOr, BitXor, Shl, Shr};
+
+extern "platform-intrinsic" {
+    fn simd_add<T>(x: T, y: T) -> T;
+    fn simd_sub<T>(x: T, y: T) -> T;
+    fn simd_mul<T>(x: T, y: T) -> T;
+    fn simd_div<T>(x: T, y: T) -> T;
+    fn simd_shl<T>(x: T, y: T) -> T;
+    fn simd_shr<T>(x: T, y: T) -> T;
+    fn simd_and<T>(x: T, y: T) -> T;
+    fn simd_or<T>(x: T, y: T) -> T;
+    fn simd_xor<T>(x: T, y: T) -> T;
+
+    fn simd_eq<T, U>(x: T, y: T) -> U;
+    fn simd_ne<T, U>(x: T, y: T) -> U;
+    fn simd_lt<T, U>(x: T, y: T) -> U;
+    fn simd_le<T, U>(x: T, y: T) -> U;
+    fn simd_gt<T, U>(x: T, y: T) -> U;
+    fn simd_ge<T, U>(x: T, y: T) -> U;
+
+    fn simd_insert<T, E>(x: T, idx: u32, y: E) -> T;
+    fn simd_extract<T, E>(x: T, idx: u32) -> E;
+
+    fn simd_shuffle2<T, U>(x: T, y: T, idx: [u32; 2]) -> U;
+    fn simd_shuffle4<T, U>(x: T, y: T, idx: [u32; 4]) -> U;
+    fn simd_shuffle8<T, U>(x: T, y: T, idx: [u32; 8]) -> U;
+    fn simd_shuffle16<T, U>(x: T, y: T, idx: [u32; 16]) -> U;
+
+    fn simd_cast<T, U>(x: T) -> U;
+}
+
+/// A SIMD vector type.
+///
+/// This trait allows writing code that is generic over the vector types in
+/// this module. It is unsafe to implement because `select` relies on `Mask`
+/// having the same size and number of lanes as the vector type.
+pub unsafe trait Simd: Copy {
+    /// The type of a single lane.
+    type Elem: Copy;
+
+    /// The mask type with the same number and width of lanes.
+    type Mask: Copy;
+
+    /// Returns the number of lanes.
+    fn lanes() -> usize;
+}
+
+macro_rules! common_impls {
+    ($name:ident, $elem:ty, $lanes:expr, $shuffle:ident,
+     reverse: $reverse:expr, low: $low:expr, high: $high:expr) => {
+        impl $name {
+            /// Returns the number of lanes.
+            #[inline]
+            pub fn lanes() -> usize {
+                $lanes
+            }
+
+            /// Creates a vector from an array of lanes.
+            #[inline]
+            pub fn from_array(array: [$elem; $lanes]) -> $name {
+                unsafe { mem::transmute(array) }
+            }
+
+            /// Returns the lanes of `self` as an array.
+            #[inline]
+            pub fn to_array(self) -> [$elem; $lanes] {
+                unsafe { mem::transmute(self) }
+            }
+
+            /// Returns the value of lane `i`.
+            ///
+            /// # Panics
+            ///
+            /// Panics if `i` is not smaller than the number of lanes.
+            #[inline]
+            pub fn extract(self, i: usize) -> $elem {
+                $name::check_lane(i);
+                unsafe { simd_extract(self, i as u32) }
+            }
+
+            /// Returns a copy of `self` with lane `i` set to `x`.
+            ///
+            /// # Panics
+            ///
+            /// Panics if `i` is not smaller than the number of lanes.
+            #[inline]
+            pub fn replace(self, i: usize, x: $elem) -> $name {
+                $name::check_lane(i);
+                unsafe { simd_insert(self, i as u32, x) }
+            }
+
+            /// Returns a copy of `self` with the lanes in reverse order.
+            #[inline]
+            pub fn reverse(self) -> $name {
+                unsafe { $shuffle(self, self, $reverse) }
+            }
+
+            /// Interleaves the lanes of the low halves of `self` and `other`,
+            /// starting with the first lane of `self`.
+            #[inline]
+            pub fn interleave_low(self, other: $name) -> $name {
+                unsafe { $shuffle(self, other, $low) }
+            }
+
+            /// Interleaves the lanes of the high halves of `self` and `other`,
+            /// starting with the middle lane of `self`.
+            #[inline]
+            pub fn interleave_high(self, other: $name) -> $name {
+                unsafe { $shuffle(self, other, $high) }
+            }
+
+            #[inline]
+            fn check_lane(i: usize) {
+                assert!(i < $lanes, concat!(stringify!($name), ": lane index out of bounds"));
+            }
+        }
+
+        impl fmt::Debug for $name {
+            fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                let mut builder = f.debug_tuple(stringify!($name));
+                for lane in &self.to_array() {
+                    builder.field(lane);
+                }
+                builder.finish()
+            }
+        }
+
+        impl From<[$elem; $lanes]> for $name {
+            #[inline]
+            fn from(array: [$elem; $lanes]) -> $name {
+                $name::from_array(array)
+            }
+        }
+
+        impl From<$name> for [$elem; $lanes] {
+            #[inline]
+            fn from(v: $name) -> [$elem; $lanes] {
+                v.to_array()
+            }
+        }
+    }
+}
+
+macro_rules! bitwise_impls {
+    ($name:ident, $elem:ty, $lanes:expr) => {
+        impl BitAnd for $name {
+            type Output = $name;
+            #[inline]
+            fn bitand(self, other: $name) -> $name { unsafe { simd_and(self, other) } }
+        }
+
+        impl BitOr for $name {
+            type Output = $name;
+            #[inline]
+            fn bitor(self, other: $name) -> $name { unsafe { simd_or(self, other) } }
+        }
+
+        impl BitXor for $name {
+            type Output = $name;
+            #[inline]
+            fn bitxor(self, other: $name) -> $name { unsafe { simd_xor(self, other) } }
+        }
+
+        impl Not for $name {
+            type Output = $name;
+            #[inline]
+            fn not(self) -> $name { self ^ $name::from_array([!(0 as $elem); $lanes]) }
+        }
+    }
+}
+
+macro_rules! mask_type {
+    ($(#[$attr:meta])* pub struct $name:ident($($field:ty),+) : $elem:ty, $lanes:expr,
+     $shuffle:ident, reverse: $reverse:expr, low: $low:expr, high: $high:expr;
+     new($($x:ident),+)) => {
+        $(#[$attr])*
+        #[repr(simd)]
+        #[derive(Copy, Clone, PartialEq, Eq)]
+        pub struct $name($($field),+);
+
+        impl $name {
+            /// Creates a mask from the values of its lanes.
+            #[inline]
+            pub fn new($($x: bool),+) -> $name {
+                $name($(-($x as $elem)),+)
+            }
+
+            /// Creates a mask with all lanes set to `x`.
+            #[inline]
+            pub fn splat(x: bool) -> $name {
+                $name($({ let $x = -(x as $elem); $x }),+)
+            }
+
+            /// Returns whether all lanes are set.
+            #[inline]
+            pub fn all(self) -> bool {
+                self.to_array().iter().all(|&lane| lane != 0)
+            }
+
+            /// Returns whether any lane is set.
+            #[inline]
+            pub fn any(self) -> bool {
+                self.to_array().iter().any(|&lane| lane != 0)
+            }
+
+            /// Returns whether no lane is set.
+            #[inline]
+            pub fn none(self) -> bool {
+                !self.any()
+            }
+
+            /// Returns whether lane `i` is set.
+            ///
+            /// # Panics
+            ///
+            /// Panics if `i` is not smaller than the number of lanes.
+            #[inline]
+            pub fn test(self, i: usize) -> bool {
+                self.extract(i) != 0
+            }
+
+            /// Returns a copy of `self` with lane `i` set to `x`.
+            ///
+            /// # Panics
+            ///
+            /// Panics if `i` is not smaller than the number of lanes.
+            #[inline]
+            pub fn set(self, i: usize, x: bool) -> $name {
+                self.replace(i, -(x as $elem))
+            }
+
+            /// Chooses, for every lane, the lane of `a` if the lane of
+            /// `self` is set and the lane of `b` otherwise.
+            #[inline]
+            pub fn select<V: Simd<Mask = $name>>(self, a: V, b: V) -> V {
+                unsafe {
+                    let a: $name = mem::transmute_copy(&a);
+                    let b: $name = mem::transmute_copy(&b);
+                    mem::transmute_copy(&((self & a) | (!self & b)))
+                }
+            }
+        }
+
+        common_impls!($name, $elem, $lanes, $shuffle,
+                      reverse: $reverse, low: $low, high: $high);
+        bitwise_impls!($name, $elem, $lanes);
+
+        unsafe impl Simd for $name {
+            type Elem = $elem;
+            type Mask = $name;
+            fn lanes() -> usize { $lanes }
+        }
+    }
+}
+
+macro_rules! vector_type {
+    ($(#[$attr:meta])* pub struct $name:ident($($field:ty),+) : $elem:ty, $lanes:expr,
+     $mask:ident, $shuffle:ident, reverse: $reverse:expr, low: $low:expr, high: $high:expr;
+     new($($x:ident),+)) => {
+        $(#[$attr])*
+        #[repr(simd)]
+        #[derive(Copy, Clone, PartialEq, Default)]
+        pub struct $name($($field),+);
+
+        impl $name {
+            /// Creates a vector from the values of its lanes.
+            #[inline]
+            pub fn new($($x: $elem),+) -> $name {
+                $name($($x),+)
+            }
+
+            /// Creates a vector with all lanes set to `x`.
+            #[inline]
+            pub fn splat(x: $elem) -> $name {
+                $name($({ let $x = x; $x }),+)
+            }
+
+            /// Loads a vector from the first lanes of `slice`.
+            ///
+            /// # Panics
+            ///
+            /// Panics if `slice` is shorter than the number of lanes.
+            #[inline]
+            pub fn load(slice: &[$elem]) -> $name {
+                let mut array = [0 as $elem; $lanes];
+                array.copy_from_slice(&slice[..$lanes]);
+                $name::from_array(array)
+            }
+
+            /// Stores the lanes of `self` into the first elements of `slice`.
+            ///
+            /// # Panics
+            ///
+            /// Panics if `slice` is shorter than the number of lanes.
+            #[inline]
+            pub fn store(self, slice: &mut [$elem]) {
+                slice[..$lanes].copy_from_slice(&self.to_array());
+            }
+
+            /// Loads every lane from the element of `slice` at the
+            /// corresponding index in `indices`.
+            ///
+            /// # Panics
+            ///
+            /// Panics if any index is out of bounds.
+            #[inline]
+            pub fn gather(slice: &[$elem], indices: [usize; $lanes]) -> $name {
+                let mut array = [0 as $elem; $lanes];
+                for (lane, &i) in array.iter_mut().zip(&indices) {
+                    *lane = slice[i];
+                }
+                $name::from_array(array)
+            }
+
+            /// Like `gather`, but only loads the lanes that are set in
+            /// `mask`, taking the other lanes from `or` and ignoring their
+            /// indices.
+            ///
+            /// # Panics
+            ///
+            /// Panics if the index of any lane set in `mask` is out of bounds.
+            #[inline]
+            pub fn gather_select(slice: &[$elem], indices: [usize; $lanes],
+                                 mask: $mask, or: $name) -> $name {
+                let mut array = or.to_array();
+                for (j, (lane, &i)) in array.iter_mut().zip(&indices).enumerate() {
+                    if mask.test(j) {
+                        *lane = slice[i];
+                    }
+                }
+                $name::from_array(array)
+            }
+
+            /// Stores every lane into the element of `slice` at the
+            /// corresponding index in `indices`. If an index occurs more
+            /// than once, the highest lane with that index is stored last.
+            ///
+            /// # Panics
+            ///
+            /// Panics if any index is out of bounds.
+            #[inline]
+            pub fn scatter(self, slice: &mut [$elem], indices: [usize; $lanes]) {
+                for (&lane, &i) in self.to_array().iter().zip(&indices) {
+                    slice[i] = lane;
+                }
+            }
+
+            /// Like `scatter`, but only stores the lanes that are set in
+            /// `mask`, ignoring the indices of all other lanes.
+            ///
+            /// # Panics
+            ///
+            /// Panics if the index of any lane set in `mask` is out of bounds.
+            #[inline]
+            pub fn scatter_select(self, slice: &mut [$elem], indices: [usize; $lanes],
+                                  mask: $mask) {
+                for (j, (&lane, &i)) in self.to_array().iter().zip(&indices).enumerate() {
+                    if mask.test(j) {
+                        slice[i] = lane;
+                    }
+                }
+            }
+
+            /// Lane-wise `==`.
+            #[inline]
+            pub fn eq(self, other: $name) -> $mask { unsafe { simd_eq(self, other) } }
+
+            /// Lane-wise `!=`.
+            #[inline]
+            pub fn ne(self, other: $name) -> $mask { unsafe { simd_ne(self, other) } }
+
+            /// Lane-wise `<`.
+            #[inline]
+            pub fn lt(self, other: $name) -> $mask { unsafe { simd_lt(self, other) } }
+
+            /// Lane-wise `<=`.
+            #[inline]
+            pub fn le(self, other: $name) -> $mask { unsafe { simd_le(self, other) } }
+
+            /// Lane-wise `>`.
+            #[inline]
+            pub fn gt(self, other: $name) -> $mask { unsafe { simd_gt(self, other) } }
+
+            /// Lane-wise `>=`.
+            #[inline]
+            pub fn ge(self, other: $name) -> $mask { unsafe { simd_ge(self, other) } }
+
+            /// Lane-wise minimum. For floats, a lane is taken from `other`
+            /// unless it is greater than the lane of `self`, so NaN lanes in
+            /// `other` are propagated.
+            #[inline]
+            pub fn min(self, other: $name) -> $name {
+                self.lt(other).select(self, other)
+            }
+
+            /// Lane-wise maximum. For floats, a lane is taken from `other`
+            /// unless it is smaller than the lane of `self`, so NaN lanes in
+            /// `other` are propagated.
+            #[inline]
+            pub fn max(self, other: $name) -> $name {
+                self.gt(other).select(self, other)
+            }
+        }
+
+        common_impls!($name, $elem, $lanes, $shuffle,
+                      reverse: $reverse, low: $low, high: $high);
+
+        impl Add for $name {
+            type Output = $name;
+            #[inline]
+            fn add(self, other: $name) -> $name { unsafe { simd_add(self, other) } }
+        }
+
+        impl Sub for $name {
+            type Output = $name;
+            #[inline]
+            fn sub(self, other: $name) -> $name { unsafe { simd_sub(self, other) } }
+        }
+
+        impl Mul for $name {
+            type Output = $name;
+            #[inline]
+            fn mul(self, other: $name) -> $name { unsafe { simd_mul(self, other) } }
+        }
+
+        unsafe impl Simd for $name {
+            type Elem = $elem;
+            type Mask = $mask;
+            fn lanes() -> usize { $lanes }
+        }
+    }
+}
+
+macro_rules! int_vector_type {
+    ($(#[$attr:meta])* pub struct $name:ident($($field:ty),+) : $elem:ty, $lanes:expr,
+     $($rest:tt)*) => {
+        vector_type! {
+            $(#[$attr])* pub struct $name($($field),+) : $elem, $lanes, $($rest)*
+        }
+
+        bitwise_impls!($name, $elem, $lanes);
+
+        impl $name {
+            // Masks every lane of a shift amount to less than the lane width,
+            // as `wrapping_shl` does: the intrinsics are undefined for larger
+            // amounts.
+            #[inline]
+            fn shift_amount(other: $name) -> $name {
+                let bits = mem::size_of::<$elem>() * 8;
+                other & $name::splat((bits - 1) as $elem)
+            }
+        }
+
+        impl Shl for $name {
+            type Output = $name;
+            #[inline]
+            fn shl(self, other: $name) -> $name {
+                unsafe { simd_shl(self, $name::shift_amount(other)) }
+            }
+        }
+
+        impl Shr for $name {
+            type Output = $name;
+            #[inline]
+            fn shr(self, other: $name) -> $name {
+                unsafe { simd_shr(self, $name::shift_amount(other)) }
+            }
+        }
+    }
+}
+
+macro_rules! signed_neg_impl {
+    ($($name:ident)*) => ($(
+        impl Neg for $name {
+            type Output = $name;
+            #[inline]
+            fn neg(self) -> $name { $name::splat(0) - self }
+        }
+    )*)
+}
+
+macro_rules! float_vector_type {
+    ($(#[$attr:meta])* pub struct $name:ident($($field:ty),+) : $elem:ty, $lanes:expr,
+     $($rest:tt)*) => {
+        vector_type! {
+            $(#[$attr])* pub struct $name($($field),+) : $elem, $lanes, $($rest)*
+        }
+
+        impl Div for $name {
+            type Output = $name;
+            #[inline]
+            fn div(self, other: $name) -> $name { unsafe { simd_div(self, other) } }
+        }
+
+        impl Neg for $name {
+            type Output = $name;
+            #[inline]
+            fn neg(self) -> $name { $name::splat(-0.0) - self }
+        }
+    }
+}
+
+macro_rules! cast_impls {
+    ($name:ident: $($method:ident -> $to:ident),+) => {
+        impl $name {
+            $(
+                /// Converts every lane as if with `as`.
+                #[inline]
+                pub fn $method(self) -> $to { unsafe { simd_cast(self) } }
+            )+
+        }
+    }
+}
+
+const REVERSE2: [u32; 2] = [1, 0];
+const LOW2: [u32; 2] = [0, 2];
+const HIGH2: [u32; 2] = [1, 3];
+const REVERSE4: [u32; 4] = [3, 2, 1, 0];
+const LOW4: [u32; 4] = [0, 4, 1, 5];
+const HIGH4: [u32; 4] = [2, 6, 3, 7];
+const REVERSE8: [u32; 8] = [7, 6, 5, 4, 3, 2, 1, 0];
+const LOW8: [u32; 8] = [0, 8, 1, 9, 2, 10, 3, 11];
+const HIGH8: [u32; 8] = [4, 12, 5, 13, 6, 14, 7, 15];
+const REVERSE16: [u32; 16] = [15, 14, 13, 12, 11, 10, 9, 8, 7, 6, 5, 4, 3, 2, 1, 0];
+const LOW16: [u32; 16] = [0, 16, 1, 17, 2, 18, 3, 19, 4, 20, 5, 21, 6, 22, 7, 23];
+const HIGH16: [u32; 16] = [8, 24, 9, 25, 10, 26, 11, 27, 12, 28, 13, 29, 14, 30, 15, 31];
+
+mask_type! {
+    /// A mask for vectors of two 64-bit lanes.
+    pub struct m64x2(i64, i64) : i64, 2, simd_shuffle2,
+        reverse: REVERSE2, low: LOW2, high: HIGH2;
+    new(x0, x1)
+}
+
+mask_type! {
+    /// A mask for vectors of four 32-bit lanes.
+    pub struct m32x4(i32, i32, i32, i32) : i32, 4, simd_shuffle4,
+        reverse: REVERSE4, low: LOW4, high: HIGH4;
+    new(x0, x1, x2, x3)
+}
+
+mask_type! {
+    /// A mask for vectors of eight 16-bit lanes.
+    pub struct m16x8(i16, i16, i16, i16, i16, i16, i16, i16) : i16, 8, simd_shuffle8,
+        reverse: REVERSE8, low: LOW8, high: HIGH8;
+    new(x0, x1, x2, x3, x4, x5, x6, x7)
+}
+
+mask_type! {
+    /// A mask for vectors of sixteen 8-bit lanes.
+    pub struct m8x16(i8, i8, i8, i8, i8, i8, i8, i8,
+                     i8, i8, i8, i8, i8, i8, i8, i8) : i8, 16, simd_shuffle16,
+        reverse: REVERSE16, low: LOW16, high: HIGH16;
+    new(x0, x1, x2, x3, x4, x5, x6, x7, x8, x9, x10, x11, x12, x13, x14, x15)
+}
+
+float_vector_type! {
+    /// A vector of two `f64` lanes.
+    pub struct f64x2(f64, f64) : f64, 2, m64x2, simd_shuffle2,
+        reverse: REVERSE2, low: LOW2, high: HIGH2;
+    new(x0, x1)
+}
+
+int_vector_type! {
+    /// A vector of two `i64` lanes.
+    pub struct i64x2(i64, i64) : i64, 2, m64x2, simd_shuffle2,
+        reverse: REVERSE2, low: LOW2, high: HIGH2;
+    new(x0, x1)
+}
+
+int_vector_type! {
+    /// A vector of two `u64` lanes.
+    pub struct u64x2(u64, u64) : u64, 2, m64x2, simd_shuffle2,
+        reverse: REVERSE2, low: LOW2, high: HIGH2;
+    new(x0, x1)
+}
+
+float_vector_type! {
+    /// A vector of four `f32` lanes.
+    pub struct f32x4(f32, f32, f32, f32) : f32, 4, m32x4, simd_shuffle4,
+        reverse: REVERSE4, low: LOW4, high: HIGH4;
+    new(x0, x1, x2, x3)
+}
+
+int_vector_type! {
+    /// A vector of four `i32` lanes.
+    pub struct i32x4(i32, i32, i32, i32) : i32, 4, m32x4, simd_shuffle4,
+        reverse: REVERSE4, low: LOW4, high: HIGH4;
+    new(x0, x1, x2, x3)
+}
+
+int_vector_type! {
+    /// A vector of four `u32` lanes.
+    pub struct u32x4(u32, u32, u32, u32) : u32, 4, m32x4, simd_shuffle4,
+        reverse: REVERSE4, low: LOW4, high: HIGH4;
+    new(x0, x1, x2, x3)
+}
+
+int_vector_type! {
+    /// A vector of eight `i16` lanes.
+    pub struct i16x8(i16, i16, i16, i16, i16, i16, i16, i16) : i16, 8, m16x8, simd_shuffle8,
+        reverse: REVERSE8, low: LOW8, high: HIGH8;
+    new(x0, x1, x2, x3, x4, x5, x6, x7)
+}
+
+int_vector_type! {
+    /// A vector of eight `u16` lanes.
+    pub struct u16x8(u16, u16, u16, u16, u16, u16, u16, u16) : u16, 8, m16x8, simd_shuffle8,
+        reverse: REVERSE8, low: LOW8, high: HIGH8;
+    new(x0, x1, x2, x3, x4, x5, x6, x7)
+}
+
+int_vector_type! {
+    /// A vector of sixteen `i8` lanes.
+    pub struct i8x16(i8, i8, i8, i8, i8, i8, i8, i8,
+                     i8, i8, i8, i8, i8, i8, i8, i8) : i8, 16, m8x16, simd_shuffle16,
+        reverse: REVERSE16, low: LOW16, high: HIGH16;
+    new(x0, x1, x2, x3, x4, x5, x6, x7, x8, x9, x10, x11, x12, x13, x14, x15)
+}
+
+int_vector_type! {
+    /// A vector of sixteen `u8` lanes.
+    pub struct u8x16(u8, u8, u8, u8, u8, u8, u8, u8,
+                     u8, u8, u8, u8, u8, u8, u8, u8) : u8, 16, m8x16, simd_shuffle16,
+        reverse: REVERSE16, low: LOW16, high: HIGH16;
+    new(x0, x1, x2, x3, x4, x5, x6, x7, x8, x9, x10, x11, x12, x13, x14, x15)
+}
+
+signed_neg_impl! { i64x2 i32x4 i16x8 i8x16 }
+
+cast_impls!(f64x2: as_i64x2 -> i64x2, as_u64x2 -> u64x2);
+cast_impls!(i64x2: as_f64x2 -> f64x2, as_u64x2 -> u64x2);
+cast_impls!(u64x2: as_f64x2 -> f64x2, as_i64x2 -> i64x2);
+cast_impls!(f32x4: as_i32x4 -> i32x4, as_u32x4 -> u32x4);
+cast_impls!(i32x4: as_f32x4 -> f32x4, as_u32x4 -> u32x4);
+cast_impls!(u32x4: as_f32x4 -> f32x4, as_i32x4 -> i32x4);
+cast_impls!(i16x8: as_u16x8 -> u16x8);
+cast_impls!(u16x8: as_i16x8 -> i16x8);
+cast_impls!(i8x16: as_u8x16 -> u8x16);
+cast_impls!(u8x16: as_i8x16 -> i8x16);
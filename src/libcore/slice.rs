@@ -43,6 +43,7 @@ use intrinsics::assume;
 use iter::*;
 use ops::{FnMut, self};
 use ops::RangeFull;
+use range;
 use option::Option;
 use option::Option::{None, Some};
 use result::Result;
@@ -111,6 +112,12 @@ pub trait SliceExt {
     fn binary_search_by_key<B, F>(&self, b: &B, f: F) -> Result<usize, usize>
         where F: FnMut(&Self::Item) -> B,
               B: Ord;
+    #[unstable(feature = "slice_binary_search_from", issue = "0")]
+    fn binary_search_from(&self, hint: usize, x: &Self::Item) -> Result<usize, usize>
+        where Self::Item: Ord;
+    #[unstable(feature = "slice_binary_search_from", issue = "0")]
+    fn binary_search_from_by<F>(&self, hint: usize, f: F) -> Result<usize, usize>
+        where F: FnMut(&Self::Item) -> Ordering;
     #[stable(feature = "core", since = "1.6.0")]
     fn len(&self) -> usize;
     #[stable(feature = "core", since = "1.6.0")]
@@ -162,6 +169,26 @@ pub trait SliceExt {
     fn clone_from_slice(&mut self, src: &[Self::Item]) where Self::Item: Clone;
     #[stable(feature = "copy_from_slice", since = "1.9.0")]
     fn copy_from_slice(&mut self, src: &[Self::Item]) where Self::Item: Copy;
+    #[unstable(feature = "slice_try_copy", issue = "0")]
+    fn try_clone_from_slice(&mut self, src: &[Self::Item]) -> Result<(), LengthMismatchError>
+        where Self::Item: Clone;
+    #[unstable(feature = "slice_try_copy", issue = "0")]
+    fn try_copy_from_slice(&mut self, src: &[Self::Item]) -> Result<(), LengthMismatchError>
+        where Self::Item: Copy;
+    #[unstable(feature = "slice_copy_within", issue = "0")]
+    fn copy_within(&mut self, src: ops::Range<usize>, dest: usize) where Self::Item: Copy;
+    #[unstable(feature = "slice_get_slice", issue = "0")]
+    fn get_slice<I>(&self, index: I) -> Option<&[Self::Item]>
+        where I: SliceIndex<Self::Item>;
+    #[unstable(feature = "slice_get_slice", issue = "0")]
+    fn get_slice_mut<I>(&mut self, index: I) -> Option<&mut [Self::Item]>
+        where I: SliceIndex<Self::Item>;
+    #[unstable(feature = "slice_get_slice", issue = "0")]
+    unsafe fn get_slice_unchecked<I>(&self, index: I) -> &[Self::Item]
+        where I: SliceIndex<Self::Item>;
+    #[unstable(feature = "slice_get_slice", issue = "0")]
+    unsafe fn get_slice_unchecked_mut<I>(&mut self, index: I) -> &mut [Self::Item]
+        where I: SliceIndex<Self::Item>;
 }
 
 // Use macros to be generic over const/mut
@@ -491,8 +518,9 @@ impl<T> SliceExt for [T] {
 
     #[inline]
     fn clone_from_slice(&mut self, src: &[T]) where T: Clone {
-        assert!(self.len() == src.len(),
-                "destination and source slices have different lengths");
+        if self.len() != src.len() {
+            slice_len_mismatch_fail(self.len(), src.len());
+        }
         // NOTE: We need to explicitly slice them to the same length
         // for bounds checking to be elided, and the optimizer will
         // generate memcpy for simple cases (for example T = u8).
@@ -505,14 +533,77 @@ impl<T> SliceExt for [T] {
 
     #[inline]
     fn copy_from_slice(&mut self, src: &[T]) where T: Copy {
-        assert!(self.len() == src.len(),
-                "destination and source slices have different lengths");
+        if self.len() != src.len() {
+            slice_len_mismatch_fail(self.len(), src.len());
+        }
         unsafe {
             ptr::copy_nonoverlapping(
                 src.as_ptr(), self.as_mut_ptr(), self.len());
         }
     }
 
+    #[inline]
+    fn try_clone_from_slice(&mut self, src: &[T]) -> Result<(), LengthMismatchError>
+        where T: Clone
+    {
+        if self.len() != src.len() {
+            return Err(LengthMismatchError { dst_len: self.len(), src_len: src.len() });
+        }
+        self.clone_from_slice(src);
+        Ok(())
+    }
+
+    #[inline]
+    fn try_copy_from_slice(&mut self, src: &[T]) -> Result<(), LengthMismatchError>
+        where T: Copy
+    {
+        if self.len() != src.len() {
+            return Err(LengthMismatchError { dst_len: self.len(), src_len: src.len() });
+        }
+        self.copy_from_slice(src);
+        Ok(())
+    }
+
+    fn copy_within(&mut self, src: ops::Range<usize>, dest: usize) where T: Copy {
+        let len = self.len();
+        if src.start > src.end {
+            copy_within_order_fail(src.start, src.end);
+        }
+        if src.end > len {
+            copy_within_src_fail(src.start, src.end, len);
+        }
+        let count = src.end - src.start;
+        if dest > len - count {
+            copy_within_dest_fail(dest, count, len);
+        }
+        // The ranges may overlap, so this is a `memmove`.
+        unsafe {
+            ptr::copy(self.as_ptr().offset(src.start as isize),
+                      self.as_mut_ptr().offset(dest as isize),
+                      count);
+        }
+    }
+
+    #[inline]
+    fn get_slice<I>(&self, index: I) -> Option<&[T]> where I: SliceIndex<T> {
+        index.get(self)
+    }
+
+    #[inline]
+    fn get_slice_mut<I>(&mut self, index: I) -> Option<&mut [T]> where I: SliceIndex<T> {
+        index.get_mut(self)
+    }
+
+    #[inline]
+    unsafe fn get_slice_unchecked<I>(&self, index: I) -> &[T] where I: SliceIndex<T> {
+        index.get_unchecked(self)
+    }
+
+    #[inline]
+    unsafe fn get_slice_unchecked_mut<I>(&mut self, index: I) -> &mut [T] where I: SliceIndex<T> {
+        index.get_unchecked_mut(self)
+    }
+
     #[inline]
     fn binary_search_by_key<B, F>(&self, b: &B, mut f: F) -> Result<usize, usize>
         where F: FnMut(&Self::Item) -> B,
@@ -520,6 +611,67 @@ impl<T> SliceExt for [T] {
     {
         self.binary_search_by(|k| f(k).cmp(b))
     }
+
+    fn binary_search_from(&self, hint: usize, x: &T) -> Result<usize, usize> where T: Ord {
+        self.binary_search_from_by(hint, |p| p.cmp(x))
+    }
+
+    fn binary_search_from_by<F>(&self, hint: usize, mut f: F) -> Result<usize, usize> where
+        F: FnMut(&T) -> Ordering
+    {
+        let len = self.len();
+        let hint = cmp::min(hint, len);
+
+        // Gallop away from the hint with doubling steps until the target is
+        // bracketed by `lo..hi`: everything before `lo` compares `Less` and
+        // everything from `hi` on compares `Greater`.
+        let forward = if hint < len {
+            match f(&self[hint]) {
+                Less => true,
+                Greater => false,
+                Equal => return Ok(hint),
+            }
+        } else {
+            false
+        };
+        let (mut lo, mut hi) = if forward { (hint + 1, len) } else { (0, hint) };
+        let mut step = 1;
+        if forward {
+            while step <= len - lo {
+                let probe = lo + step - 1;
+                match f(&self[probe]) {
+                    Less => {
+                        lo = probe + 1;
+                        step *= 2;
+                    }
+                    Greater => {
+                        hi = probe;
+                        break;
+                    }
+                    Equal => return Ok(probe),
+                }
+            }
+        } else {
+            while step <= hi {
+                let probe = hi - step;
+                match f(&self[probe]) {
+                    Greater => {
+                        hi = probe;
+                        step *= 2;
+                    }
+                    Less => {
+                        lo = probe + 1;
+                        break;
+                    }
+                    Equal => return Ok(probe),
+                }
+            }
+        }
+        match self[lo..hi].binary_search_by(f) {
+            Ok(i) => Ok(lo + i),
+            Err(i) => Err(lo + i),
+        }
+    }
 }
 
 #[stable(feature = "rust1", since = "1.0.0")]
@@ -555,6 +707,33 @@ fn slice_index_order_fail(index: usize, end: usize) -> ! {
     panic!("slice index starts at {} but ends at {}", index, end);
 }
 
+#[inline(never)]
+#[cold]
+fn slice_len_mismatch_fail(dst_len: usize, src_len: usize) -> ! {
+    panic!("destination and source slices have different lengths \
+            (destination has {} elements, source has {})", dst_len, src_len);
+}
+
+#[inline(never)]
+#[cold]
+fn copy_within_order_fail(start: usize, end: usize) -> ! {
+    panic!("copy_within source range starts at {} but ends at {}", start, end);
+}
+
+#[inline(never)]
+#[cold]
+fn copy_within_src_fail(start: usize, end: usize, len: usize) -> ! {
+    panic!("copy_within source range {}..{} out of range for slice of length {}",
+           start, end, len);
+}
+
+#[inline(never)]
+#[cold]
+fn copy_within_dest_fail(dest: usize, count: usize, len: usize) -> ! {
+    panic!("copy_within destination range {}..{} out of range for slice of length {}",
+           dest, dest.saturating_add(count), len);
+}
+
 
 /// Implements slicing with syntax `&self[begin .. end]`.
 ///
@@ -757,6 +936,248 @@ impl<T> ops::IndexMut<ops::RangeToInclusive<usize>> for [T] {
     }
 }
 
+// The copyable ranges of `core::range` index like their `ops` counterparts.
+
+#[unstable(feature = "new_range_api", issue = "0")]
+#[rustc_on_unimplemented = "slice indices are of type `usize`"]
+impl<T> ops::Index<range::Range<usize>> for [T] {
+    type Output = [T];
+
+    #[inline]
+    fn index(&self, index: range::Range<usize>) -> &[T] {
+        self.index(index.start .. index.end)
+    }
+}
+#[unstable(feature = "new_range_api", issue = "0")]
+#[rustc_on_unimplemented = "slice indices are of type `usize`"]
+impl<T> ops::Index<range::RangeFrom<usize>> for [T] {
+    type Output = [T];
+
+    #[inline]
+    fn index(&self, index: range::RangeFrom<usize>) -> &[T] {
+        self.index(index.start ..)
+    }
+}
+#[unstable(feature = "new_range_api", issue = "0")]
+#[rustc_on_unimplemented = "slice indices are of type `usize`"]
+impl<T> ops::Index<range::RangeInclusive<usize>> for [T] {
+    type Output = [T];
+
+    #[inline]
+    fn index(&self, index: range::RangeInclusive<usize>) -> &[T] {
+        // empty like `start..start`, which `start...end` can't express
+        if index.is_empty() {
+            self.index(index.start .. index.start)
+        } else {
+            self.index(index.start ... index.end)
+        }
+    }
+}
+
+#[unstable(feature = "new_range_api", issue = "0")]
+#[rustc_on_unimplemented = "slice indices are of type `usize`"]
+impl<T> ops::IndexMut<range::Range<usize>> for [T] {
+    #[inline]
+    fn index_mut(&mut self, index: range::Range<usize>) -> &mut [T] {
+        self.index_mut(index.start .. index.end)
+    }
+}
+#[unstable(feature = "new_range_api", issue = "0")]
+#[rustc_on_unimplemented = "slice indices are of type `usize`"]
+impl<T> ops::IndexMut<range::RangeFrom<usize>> for [T] {
+    #[inline]
+    fn index_mut(&mut self, index: range::RangeFrom<usize>) -> &mut [T] {
+        self.index_mut(index.start ..)
+    }
+}
+#[unstable(feature = "new_range_api", issue = "0")]
+#[rustc_on_unimplemented = "slice indices are of type `usize`"]
+impl<T> ops::IndexMut<range::RangeInclusive<usize>> for [T] {
+    #[inline]
+    fn index_mut(&mut self, index: range::RangeInclusive<usize>) -> &mut [T] {
+        if index.is_empty() {
+            self.index_mut(index.start .. index.start)
+        } else {
+            self.index_mut(index.start ... index.end)
+        }
+    }
+}
+
+/// The ranges of `usize` that `get_slice` and its variants accept, from
+/// `ops` and from `range`.
+#[unstable(feature = "slice_get_slice", issue = "0")]
+#[rustc_on_unimplemented = "slices are sliced by ranges of `usize`"]
+pub trait SliceIndex<T> {
+    /// Returns the subslice in this range, if in bounds.
+    fn get(self, slice: &[T]) -> Option<&[T]>;
+
+    /// Returns the mutable subslice in this range, if in bounds.
+    fn get_mut(self, slice: &mut [T]) -> Option<&mut [T]>;
+
+    /// Returns the subslice in this range, without bounds checking.
+    unsafe fn get_unchecked(self, slice: &[T]) -> &[T];
+
+    /// Returns the mutable subslice in this range, without bounds checking.
+    unsafe fn get_unchecked_mut(self, slice: &mut [T]) -> &mut [T];
+}
+
+#[unstable(feature = "slice_get_slice", issue = "0")]
+impl<T> SliceIndex<T> for ops::Range<usize> {
+    #[inline]
+    fn get(self, slice: &[T]) -> Option<&[T]> {
+        if self.start > self.end || self.end > slice.len() {
+            None
+        } else {
+            unsafe { Some(self.get_unchecked(slice)) }
+        }
+    }
+
+    #[inline]
+    fn get_mut(self, slice: &mut [T]) -> Option<&mut [T]> {
+        if self.start > self.end || self.end > slice.len() {
+            None
+        } else {
+            unsafe { Some(self.get_unchecked_mut(slice)) }
+        }
+    }
+
+    #[inline]
+    unsafe fn get_unchecked(self, slice: &[T]) -> &[T] {
+        from_raw_parts(slice.as_ptr().offset(self.start as isize), self.end - self.start)
+    }
+
+    #[inline]
+    unsafe fn get_unchecked_mut(self, slice: &mut [T]) -> &mut [T] {
+        from_raw_parts_mut(slice.as_mut_ptr().offset(self.start as isize), self.end - self.start)
+    }
+}
+
+// The other ranges forward to `ops::Range`, like their `Index` impls do.
+macro_rules! slice_index_impl {
+    ($($range:ty, |$r:ident, $slice:ident| $to_range:expr;)*) => ($(
+        #[unstable(feature = "slice_get_slice", issue = "0")]
+        impl<T> SliceIndex<T> for $range {
+            #[inline]
+            fn get(self, $slice: &[T]) -> Option<&[T]> {
+                let $r = self;
+                $to_range.get($slice)
+            }
+
+            #[inline]
+            fn get_mut(self, $slice: &mut [T]) -> Option<&mut [T]> {
+                let $r = self;
+                $to_range.get_mut($slice)
+            }
+
+            #[inline]
+            unsafe fn get_unchecked(self, $slice: &[T]) -> &[T] {
+                let $r = self;
+                $to_range.get_unchecked($slice)
+            }
+
+            #[inline]
+            unsafe fn get_unchecked_mut(self, $slice: &mut [T]) -> &mut [T] {
+                let $r = self;
+                $to_range.get_unchecked_mut($slice)
+            }
+        }
+    )*)
+}
+
+slice_index_impl! {
+    ops::RangeTo<usize>, |r, slice| (0..r.end);
+    ops::RangeFrom<usize>, |r, slice| (r.start..slice.len());
+    RangeFull, |_r, slice| (0..slice.len());
+    range::Range<usize>, |r, slice| (r.start..r.end);
+    range::RangeFrom<usize>, |r, slice| (r.start..slice.len());
+}
+
+#[unstable(feature = "slice_get_slice", issue = "0")]
+impl<T> SliceIndex<T> for ops::RangeInclusive<usize> {
+    #[inline]
+    fn get(self, slice: &[T]) -> Option<&[T]> {
+        match self {
+            ops::RangeInclusive::Empty { .. } => Some(&[]),
+            ops::RangeInclusive::NonEmpty { end, .. } if end == usize::max_value() => None,
+            ops::RangeInclusive::NonEmpty { start, end } => (start..end + 1).get(slice),
+        }
+    }
+
+    #[inline]
+    fn get_mut(self, slice: &mut [T]) -> Option<&mut [T]> {
+        match self {
+            ops::RangeInclusive::Empty { .. } => Some(&mut []),
+            ops::RangeInclusive::NonEmpty { end, .. } if end == usize::max_value() => None,
+            ops::RangeInclusive::NonEmpty { start, end } => (start..end + 1).get_mut(slice),
+        }
+    }
+
+    #[inline]
+    unsafe fn get_unchecked(self, slice: &[T]) -> &[T] {
+        match self {
+            ops::RangeInclusive::Empty { .. } => &[],
+            ops::RangeInclusive::NonEmpty { start, end } => (start..end + 1).get_unchecked(slice),
+        }
+    }
+
+    #[inline]
+    unsafe fn get_unchecked_mut(self, slice: &mut [T]) -> &mut [T] {
+        match self {
+            ops::RangeInclusive::Empty { .. } => &mut [],
+            ops::RangeInclusive::NonEmpty { start, end } => {
+                (start..end + 1).get_unchecked_mut(slice)
+            }
+        }
+    }
+}
+
+// This range is inclusive of a bound that may be `usize::MAX`, so it goes
+// through `ops::RangeInclusive`, whose `get` checks for that.
+slice_index_impl! {
+    ops::RangeToInclusive<usize>, |r, slice| (0...r.end);
+}
+
+// An empty `range::RangeInclusive` selects nothing at its start, like
+// `start..start` does.
+#[unstable(feature = "slice_get_slice", issue = "0")]
+impl<T> SliceIndex<T> for range::RangeInclusive<usize> {
+    #[inline]
+    fn get(self, slice: &[T]) -> Option<&[T]> {
+        if self.is_empty() {
+            (self.start..self.start).get(slice)
+        } else {
+            (self.start...self.end).get(slice)
+        }
+    }
+
+    #[inline]
+    fn get_mut(self, slice: &mut [T]) -> Option<&mut [T]> {
+        if self.is_empty() {
+            (self.start..self.start).get_mut(slice)
+        } else {
+            (self.start...self.end).get_mut(slice)
+        }
+    }
+
+    #[inline]
+    unsafe fn get_unchecked(self, slice: &[T]) -> &[T] {
+        if self.is_empty() {
+            (self.start..self.start).get_unchecked(slice)
+        } else {
+            (self.start...self.end).get_unchecked(slice)
+        }
+    }
+
+    #[inline]
+    unsafe fn get_unchecked_mut(self, slice: &mut [T]) -> &mut [T] {
+        if self.is_empty() {
+            (self.start..self.start).get_unchecked_mut(slice)
+        } else {
+            (self.start...self.end).get_unchecked_mut(slice)
+        }
+    }
+}
+
 ////////////////////////////////////////////////////////////////////////////////
 // Common traits
 ////////////////////////////////////////////////////////////////////////////////
@@ -1686,6 +2107,39 @@ impl<'a, T> DoubleEndedIterator for ChunksMut<'a, T> {
 #[stable(feature = "rust1", since = "1.0.0")]
 impl<'a, T> ExactSizeIterator for ChunksMut<'a, T> {}
 
+/// The error returned by `try_copy_from_slice` and `try_clone_from_slice`
+/// when the destination and source slices have different lengths.
+///
+/// Nothing has been copied when this error is returned.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[unstable(feature = "slice_try_copy", issue = "0")]
+pub struct LengthMismatchError {
+    dst_len: usize,
+    src_len: usize,
+}
+
+impl LengthMismatchError {
+    /// Returns the length of the destination slice.
+    #[unstable(feature = "slice_try_copy", issue = "0")]
+    pub fn dst_len(&self) -> usize {
+        self.dst_len
+    }
+
+    /// Returns the length of the source slice.
+    #[unstable(feature = "slice_try_copy", issue = "0")]
+    pub fn src_len(&self) -> usize {
+        self.src_len
+    }
+}
+
+#[unstable(feature = "slice_try_copy", issue = "0")]
+impl fmt::Display for LengthMismatchError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "destination and source slices have different lengths \
+                   (destination has {} elements, source has {})", self.dst_len, self.src_len)
+    }
+}
+
 //
 // Free functions
 //
@@ -17,7 +17,7 @@
 use self::pattern::Pattern;
 use self::pattern::{Searcher, ReverseSearcher, DoubleEndedSearcher};
 
-use char;
+use char::{self, CharExt};
 use clone::Clone;
 use convert::AsRef;
 use default::Default;
@@ -33,6 +33,10 @@ use slice::{self, SliceExt};
 
 pub mod pattern;
 
+#[unstable(feature = "str_internals", issue = "0")]
+#[doc(hidden)]
+pub mod memchr;
+
 /// A trait to abstract the idea of creating a new instance of a type from a
 /// string.
 ///
@@ -339,8 +343,10 @@ fn utf8_acc_cont_byte(ch: u32, byte: u8) -> u32 { (ch << 6) | (byte & CONT_MASK)
 
 /// Checks whether the byte is a UTF-8 continuation byte (i.e. starts with the
 /// bits `10`).
+#[unstable(feature = "str_internals", issue = "0")]
+#[doc(hidden)]
 #[inline]
-fn utf8_is_cont_byte(byte: u8) -> bool { (byte & !CONT_MASK) == TAG_CONT_U8 }
+pub fn utf8_is_cont_byte(byte: u8) -> bool { (byte & !CONT_MASK) == TAG_CONT_U8 }
 
 #[inline]
 fn unwrap_or_0(opt: Option<&u8>) -> u8 {
@@ -1305,6 +1311,7 @@ mod traits {
     use option::Option;
     use option::Option::Some;
     use ops;
+    use range;
     use str::{StrExt, eq_slice};
 
     #[stable(feature = "rust1", since = "1.0.0")]
@@ -1577,6 +1584,65 @@ mod traits {
             self.index_mut(0...index.end)
         }
     }
+
+    #[unstable(feature = "new_range_api", issue = "0")]
+    impl ops::Index<range::Range<usize>> for str {
+        type Output = str;
+
+        #[inline]
+        fn index(&self, index: range::Range<usize>) -> &str {
+            self.index(index.start .. index.end)
+        }
+    }
+    #[unstable(feature = "new_range_api", issue = "0")]
+    impl ops::Index<range::RangeFrom<usize>> for str {
+        type Output = str;
+
+        #[inline]
+        fn index(&self, index: range::RangeFrom<usize>) -> &str {
+            self.index(index.start ..)
+        }
+    }
+    #[unstable(feature = "new_range_api", issue = "0")]
+    impl ops::Index<range::RangeInclusive<usize>> for str {
+        type Output = str;
+
+        #[inline]
+        fn index(&self, index: range::RangeInclusive<usize>) -> &str {
+            // empty like `start..start`, which `start...end` can't express
+            if index.is_empty() {
+                self.index(index.start .. index.start)
+            } else {
+                self.index(index.start ... index.end)
+            }
+        }
+    }
+
+    #[unstable(feature = "new_range_api", issue = "0")]
+    impl ops::IndexMut<range::Range<usize>> for str {
+        #[inline]
+        fn index_mut(&mut self, index: range::Range<usize>) -> &mut str {
+            self.index_mut(index.start .. index.end)
+        }
+    }
+    #[unstable(feature = "new_range_api", issue = "0")]
+    impl ops::IndexMut<range::RangeFrom<usize>> for str {
+        #[inline]
+        fn index_mut(&mut self, index: range::RangeFrom<usize>) -> &mut str {
+            self.index_mut(index.start ..)
+        }
+    }
+    #[unstable(feature = "new_range_api", issue = "0")]
+    impl ops::IndexMut<range::RangeInclusive<usize>> for str {
+        #[inline]
+        fn index_mut(&mut self, index: range::RangeInclusive<usize>) -> &mut str {
+            if index.is_empty() {
+                self.index_mut(index.start .. index.start)
+            } else {
+                self.index_mut(index.start ... index.end)
+            }
+        }
+    }
 }
 
 /// Methods for string slices
@@ -1647,6 +1713,14 @@ pub trait StrExt {
         where P::Searcher: ReverseSearcher<'a>;
     #[stable(feature = "is_char_boundary", since = "1.9.0")]
     fn is_char_boundary(&self, index: usize) -> bool;
+    #[unstable(feature = "str_char_index", issue = "0")]
+    fn byte_index_of_char(&self, n: usize) -> Option<usize>;
+    #[unstable(feature = "str_char_index", issue = "0")]
+    fn char_index_of_byte(&self, index: usize) -> Option<usize>;
+    #[unstable(feature = "encode_utf16_into", issue = "0")]
+    fn encoded_utf16_len(&self) -> usize;
+    #[unstable(feature = "encode_utf16_into", issue = "0")]
+    fn encode_utf16_into(&self, dst: &mut [u16]) -> Option<usize>;
     #[stable(feature = "core", since = "1.6.0")]
     fn as_bytes(&self) -> &[u8];
     #[stable(feature = "core", since = "1.6.0")]
@@ -1887,6 +1961,47 @@ impl StrExt for str {
         }
     }
 
+    fn byte_index_of_char(&self, n: usize) -> Option<usize> {
+        // Every char starts with exactly one byte that isn't a continuation byte.
+        let mut chars = 0;
+        for (i, &b) in self.as_bytes().iter().enumerate() {
+            if !utf8_is_cont_byte(b) {
+                if chars == n {
+                    return Some(i);
+                }
+                chars += 1;
+            }
+        }
+        if chars == n { Some(self.len()) } else { None }
+    }
+
+    fn char_index_of_byte(&self, index: usize) -> Option<usize> {
+        if !self.is_char_boundary(index) {
+            return None;
+        }
+        Some(self.as_bytes()[..index].iter().filter(|&&b| !utf8_is_cont_byte(b)).count())
+    }
+
+    fn encoded_utf16_len(&self) -> usize {
+        // Every char takes one unit, except those of four bytes, which start with `0xF0` or more
+        // in UTF-8 and are the ones outside the BMP, which take two.
+        self.as_bytes().iter().fold(0, |len, &b| {
+            len + !utf8_is_cont_byte(b) as usize + (b >= 0xF0) as usize
+        })
+    }
+
+    fn encode_utf16_into(&self, dst: &mut [u16]) -> Option<usize> {
+        if dst.len() < self.encoded_utf16_len() {
+            return None;
+        }
+        let mut len = 0;
+        for c in self.chars() {
+            // there is room for every char, after the check above
+            len += c.encode_utf16_checked(&mut dst[len..]).unwrap().len();
+        }
+        Some(len)
+    }
+
     #[inline]
     fn as_bytes(&self) -> &[u8] {
         unsafe { mem::transmute(self) }
@@ -416,22 +416,165 @@ macro_rules! searcher_methods {
 /////////////////////////////////////////////////////////////////////////////
 
 /// Associated type for `<char as Pattern<'a>>::Searcher`.
+///
+/// Unlike the searchers for other char patterns, this one looks for the
+/// last byte of the needle's UTF-8 encoding with `memchr` or `memrchr`, and
+/// only then compares the bytes in front of it, in both directions.
 #[derive(Clone, Debug)]
-pub struct CharSearcher<'a>(<CharEqPattern<char> as Pattern<'a>>::Searcher);
+pub struct CharSearcher<'a> {
+    haystack: &'a str,
+    // invariant: `finger` and `finger_back` are at char boundaries of
+    // `haystack`, except for the middle of `next_match` and
+    // `next_match_back`, which leave them at boundaries again.
+    /// The byte index of the forward search.
+    finger: usize,
+    /// The byte index of the reverse search.
+    finger_back: usize,
+    needle: char,
+    /// The number of bytes in the UTF-8 encoding of `needle`.
+    utf8_size: usize,
+    /// The UTF-8 encoding of `needle`, in its first `utf8_size` bytes.
+    utf8_encoded: [u8; 4],
+}
+
+impl<'a> CharSearcher<'a> {
+    #[inline]
+    fn last_byte(&self) -> u8 {
+        self.utf8_encoded[self.utf8_size - 1]
+    }
+
+    #[inline]
+    fn is_needle_at(&self, start: usize) -> bool {
+        &self.haystack.as_bytes()[start..start + self.utf8_size] ==
+            &self.utf8_encoded[..self.utf8_size]
+    }
+}
 
 unsafe impl<'a> Searcher<'a> for CharSearcher<'a> {
-    searcher_methods!(forward);
+    #[inline]
+    fn haystack(&self) -> &'a str {
+        self.haystack
+    }
+
+    #[inline]
+    fn next(&mut self) -> SearchStep {
+        let old_finger = self.finger;
+        let slice = unsafe { self.haystack.slice_unchecked(old_finger, self.finger_back) };
+        let mut iter = slice.chars();
+        // Compare lengths of the internal byte slice iterator
+        // to find length of current char
+        let old_len = iter.iter.len();
+        if let Some(c) = iter.next() {
+            self.finger += old_len - iter.iter.len();
+            if c == self.needle {
+                SearchStep::Match(old_finger, self.finger)
+            } else {
+                SearchStep::Reject(old_finger, self.finger)
+            }
+        } else {
+            SearchStep::Done
+        }
+    }
+
+    #[inline]
+    fn next_match(&mut self) -> Option<(usize, usize)> {
+        let last_byte = self.last_byte();
+        loop {
+            let bytes = &self.haystack.as_bytes()[self.finger..self.finger_back];
+            match super::memchr::memchr(last_byte, bytes) {
+                Some(index) => {
+                    // The found byte ends the needle if it is there at all. When it isn't, the
+                    // finger may now be inside a char, which is fine since only a match or the
+                    // end of the haystack makes us return, and both are at char boundaries.
+                    // Stepping past just the byte, rather than to the next boundary, is needed
+                    // for needles with repeated bytes like U+A041, `EA 81 81`.
+                    self.finger += index + 1;
+                    if self.finger >= self.utf8_size {
+                        let start = self.finger - self.utf8_size;
+                        if self.is_needle_at(start) {
+                            return Some((start, self.finger));
+                        }
+                    }
+                }
+                None => {
+                    self.finger = self.finger_back;
+                    return None;
+                }
+            }
+        }
+    }
 }
 
 unsafe impl<'a> ReverseSearcher<'a> for CharSearcher<'a> {
-    searcher_methods!(reverse);
+    #[inline]
+    fn next_back(&mut self) -> SearchStep {
+        let old_finger = self.finger_back;
+        let slice = unsafe { self.haystack.slice_unchecked(self.finger, old_finger) };
+        let mut iter = slice.chars();
+        let old_len = iter.iter.len();
+        if let Some(c) = iter.next_back() {
+            self.finger_back -= old_len - iter.iter.len();
+            if c == self.needle {
+                SearchStep::Match(self.finger_back, old_finger)
+            } else {
+                SearchStep::Reject(self.finger_back, old_finger)
+            }
+        } else {
+            SearchStep::Done
+        }
+    }
+
+    #[inline]
+    fn next_match_back(&mut self) -> Option<(usize, usize)> {
+        let last_byte = self.last_byte();
+        loop {
+            let bytes = &self.haystack.as_bytes()[self.finger..self.finger_back];
+            match super::memchr::memrchr(last_byte, bytes) {
+                Some(index) => {
+                    let index = self.finger + index;
+                    if index + 1 >= self.utf8_size {
+                        let start = index + 1 - self.utf8_size;
+                        if self.is_needle_at(start) {
+                            self.finger_back = start;
+                            return Some((start, index + 1));
+                        }
+                    }
+                    // The byte may have been the end of a different char or a byte in the
+                    // middle of one, so the search goes on right in front of it, like in
+                    // `next_match`. Searching backwards, bytes in the needle repeating its
+                    // last byte cannot make us skip a match.
+                    self.finger_back = index;
+                }
+                None => {
+                    self.finger_back = self.finger;
+                    return None;
+                }
+            }
+        }
+    }
 }
 
 impl<'a> DoubleEndedSearcher<'a> for CharSearcher<'a> {}
 
 /// Searches for chars that are equal to a given char
 impl<'a> Pattern<'a> for char {
-    pattern_methods!(CharSearcher<'a>, CharEqPattern, CharSearcher);
+    type Searcher = CharSearcher<'a>;
+
+    #[inline]
+    fn into_searcher(self, haystack: &'a str) -> CharSearcher<'a> {
+        let encoded = self.encode_utf8();
+        let bytes = encoded.as_slice();
+        let mut utf8_encoded = [0; 4];
+        utf8_encoded[..bytes.len()].copy_from_slice(bytes);
+        CharSearcher {
+            haystack: haystack,
+            finger: 0,
+            finger_back: haystack.len(),
+            needle: self,
+            utf8_size: bytes.len(),
+            utf8_encoded: utf8_encoded,
+        }
+    }
 }
 
 /////////////////////////////////////////////////////////////////////////////
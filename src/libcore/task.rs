@@ -0,0 +1,486 @@
+// Copyright 2016 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Types and traits for working with asynchronous tasks.
+//!
+//! A task is polled until it is `Ready`. When a task can't make progress,
+//! it returns `Pending` after arranging for the [`Waker`] found in its
+//! [`Context`] to be woken once it can; the executor then knows to poll the
+//! task again.
+//!
+//! A `Waker` is a [`RawWaker`]: a data pointer plus a [`RawWakerVTable`]
+//! telling how to clone, wake and drop it. Executors that don't need
+//! anything fancier can use [`Waker::noop`], or build a waker from an
+//! `Arc` with `std::task::Wake`.
+//!
+//! [`Waker`]: struct.Waker.html
+//! [`Context`]: struct.Context.html
+//! [`RawWaker`]: struct.RawWaker.html
+//! [`RawWakerVTable`]: struct.RawWakerVTable.html
+//! [`Waker::noop`]: struct.Waker.html#method.noop
+
+#![unstable(feature = "futures_api", issue = "0")]
+
+use clone::Clone;
+use convert::{AsRef, From};
+use fmt;
+use marker::{PhantomData, Send, Sync};
+use mem;
+use ops::{Drop, FnOnce};
+use option::Option::{self, Some, None};
+use result::Result::{self, Ok, Err};
+
+#[unstable(feature = "poll_ready", issue = "0")]
+pub use future::Ready;
+
+/// Indicates whether a value is available or if the current task has been
+/// scheduled to receive a wakeup instead.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Poll<T> {
+    /// Represents that a value is immediately ready.
+    Ready(T),
+
+    /// Represents that a value is not ready yet.
+    ///
+    /// When a function returns `Pending`, it must also ensure that the
+    /// current task is scheduled to be awoken when progress can be made.
+    Pending,
+}
+
+impl<T> Poll<T> {
+    /// Changes the ready value of this `Poll` with the closure provided.
+    pub fn map<U, F>(self, f: F) -> Poll<U> where F: FnOnce(T) -> U {
+        match self {
+            Poll::Ready(t) => Poll::Ready(f(t)),
+            Poll::Pending => Poll::Pending,
+        }
+    }
+
+    /// Returns `true` if this is `Poll::Ready`.
+    #[inline]
+    pub fn is_ready(&self) -> bool {
+        match *self {
+            Poll::Ready(_) => true,
+            Poll::Pending => false,
+        }
+    }
+
+    /// Returns `true` if this is `Poll::Pending`.
+    #[inline]
+    pub fn is_pending(&self) -> bool {
+        !self.is_ready()
+    }
+
+    /// Extracts the successful type of a `Poll<T>`, propagating `Pending`.
+    ///
+    /// Applying `?` to the returned [`Ready`](../future/struct.Ready.html) evaluates
+    /// to the value of a `Poll::Ready`, or returns `Poll::Pending` from the
+    /// enclosing function, which must itself return a `Poll`. This is the
+    /// operator form of the [`ready!`](../macro.ready.html) macro.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(futures_api, poll_ready, question_mark)]
+    ///
+    /// use std::task::Poll;
+    ///
+    /// fn add_one(poll: Poll<i32>) -> Poll<i32> {
+    ///     let x = poll.ready()?;
+    ///     Poll::Ready(x + 1)
+    /// }
+    ///
+    /// assert_eq!(add_one(Poll::Ready(1)), Poll::Ready(2));
+    /// assert_eq!(add_one(Poll::Pending), Poll::Pending);
+    /// ```
+    #[unstable(feature = "poll_ready", issue = "0")]
+    #[inline]
+    pub fn ready(self) -> Ready<T> {
+        Ready::from_poll(self)
+    }
+}
+
+impl<T, E> Poll<Result<T, E>> {
+    /// Changes the success value of this `Poll` with the closure provided.
+    pub fn map_ok<U, F>(self, f: F) -> Poll<Result<U, E>> where F: FnOnce(T) -> U {
+        match self {
+            Poll::Ready(Ok(t)) => Poll::Ready(Ok(f(t))),
+            Poll::Ready(Err(e)) => Poll::Ready(Err(e)),
+            Poll::Pending => Poll::Pending,
+        }
+    }
+
+    /// Changes the error value of this `Poll` with the closure provided.
+    pub fn map_err<U, F>(self, f: F) -> Poll<Result<T, U>> where F: FnOnce(E) -> U {
+        match self {
+            Poll::Ready(Ok(t)) => Poll::Ready(Ok(t)),
+            Poll::Ready(Err(e)) => Poll::Ready(Err(f(e))),
+            Poll::Pending => Poll::Pending,
+        }
+    }
+}
+
+impl<T> From<T> for Poll<T> {
+    fn from(t: T) -> Poll<T> {
+        Poll::Ready(t)
+    }
+}
+
+/// A `RawWaker` allows the implementor of a task executor to create a
+/// [`Waker`](struct.Waker.html) which provides customized wakeup behavior.
+///
+/// It consists of a data pointer and a [virtual function pointer
+/// table](struct.RawWakerVTable.html) that customizes the behavior of the
+/// `RawWaker`.
+#[derive(PartialEq, Debug)]
+pub struct RawWaker {
+    data: *const (),
+    vtable: &'static RawWakerVTable,
+}
+
+impl RawWaker {
+    /// Creates a new `RawWaker` from the provided `data` pointer and
+    /// `vtable`.
+    ///
+    /// The `data` pointer can be used to store arbitrary data as required
+    /// by the executor, such as a type-erased pointer to an `Arc` that is
+    /// associated with the task. It is passed as the first parameter to all
+    /// functions of the `vtable`.
+    #[inline]
+    pub const fn new(data: *const (), vtable: &'static RawWakerVTable) -> RawWaker {
+        RawWaker {
+            data: data,
+            vtable: vtable,
+        }
+    }
+}
+
+/// A virtual function pointer table that specifies the behavior of a
+/// [`RawWaker`](struct.RawWaker.html).
+///
+/// The pointer passed to all functions inside the vtable is the `data`
+/// pointer of the enclosing `RawWaker`. The functions must be thread safe
+/// when used for a [`Waker`](struct.Waker.html), which is `Send` and
+/// `Sync`; a [`LocalWaker`](struct.LocalWaker.html) is never shared between
+/// threads.
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct RawWakerVTable {
+    clone: unsafe fn(*const ()) -> RawWaker,
+    wake: unsafe fn(*const ()),
+    wake_by_ref: unsafe fn(*const ()),
+    drop: unsafe fn(*const ()),
+}
+
+impl RawWakerVTable {
+    /// Creates a new `RawWakerVTable` from the provided `clone`, `wake`,
+    /// `wake_by_ref` and `drop` functions.
+    ///
+    /// * `clone` is called when the waker gets cloned, and must return a
+    ///   `RawWaker` for a waker that wakes the same task.
+    /// * `wake` is called when `wake` is called on the waker, and must also
+    ///   release the resources associated with it.
+    /// * `wake_by_ref` is called when `wake_by_ref` is called on the waker,
+    ///   and must leave it usable.
+    /// * `drop` is called when the waker gets dropped.
+    #[inline]
+    pub const fn new(clone: unsafe fn(*const ()) -> RawWaker,
+                     wake: unsafe fn(*const ()),
+                     wake_by_ref: unsafe fn(*const ()),
+                     drop: unsafe fn(*const ())) -> RawWakerVTable {
+        RawWakerVTable {
+            clone: clone,
+            wake: wake,
+            wake_by_ref: wake_by_ref,
+            drop: drop,
+        }
+    }
+}
+
+unsafe fn noop_clone(_: *const ()) -> RawWaker {
+    RawWaker::new(0 as *const (), &NOOP_VTABLE)
+}
+
+unsafe fn noop(_: *const ()) {}
+
+static NOOP_VTABLE: RawWakerVTable = RawWakerVTable {
+    clone: noop_clone,
+    wake: noop,
+    wake_by_ref: noop,
+    drop: noop,
+};
+
+/// A `Waker` is a handle for waking up a task by notifying its executor that
+/// it is ready to be run.
+///
+/// `Waker` implements `Clone`, `Send` and `Sync`, so it can be handed to
+/// whatever will eventually make the task ready, possibly on another thread.
+#[repr(C)]
+pub struct Waker {
+    waker: RawWaker,
+}
+
+unsafe impl Send for Waker {}
+unsafe impl Sync for Waker {}
+
+impl Waker {
+    /// Creates a new `Waker` from a `RawWaker`.
+    ///
+    /// The behavior of the returned `Waker` is undefined if the contract
+    /// defined in `RawWaker`'s and `RawWakerVTable`'s documentation is not
+    /// upheld, including the thread safety of the vtable functions.
+    #[inline]
+    pub unsafe fn from_raw(waker: RawWaker) -> Waker {
+        Waker { waker: waker }
+    }
+
+    /// Creates a `Waker` that does nothing when woken.
+    ///
+    /// This is useful for polling something once and for tests, where
+    /// nothing has to happen when the task becomes ready. Creating,
+    /// cloning and dropping it doesn't allocate.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(futures_api, noop_waker)]
+    ///
+    /// use std::task::{Context, Waker};
+    ///
+    /// let waker = Waker::noop();
+    /// let cx = Context::from_waker(&waker);
+    /// cx.waker().wake_by_ref();
+    /// ```
+    #[inline]
+    #[unstable(feature = "noop_waker", issue = "0")]
+    pub fn noop() -> Waker {
+        Waker { waker: RawWaker::new(0 as *const (), &NOOP_VTABLE) }
+    }
+
+    /// Wakes up the task associated with this `Waker`, consuming it.
+    #[inline]
+    pub fn wake(self) {
+        let wake = self.waker.vtable.wake;
+        let data = self.waker.data;
+        // `wake` takes care of releasing the resources, so `drop` must not
+        // be called as well.
+        mem::forget(self);
+        unsafe { wake(data) };
+    }
+
+    /// Wakes up the task associated with this `Waker` without consuming it.
+    #[inline]
+    pub fn wake_by_ref(&self) {
+        unsafe { (self.waker.vtable.wake_by_ref)(self.waker.data) }
+    }
+
+    /// Returns `true` if this `Waker` and another one would awake the same
+    /// task.
+    ///
+    /// This works on a best-effort basis, comparing the data pointers and
+    /// vtables, and may return `false` for wakers that would wake the same
+    /// task. It is mostly used to avoid replacing a stored waker by an
+    /// equivalent clone.
+    #[inline]
+    pub fn will_wake(&self, other: &Waker) -> bool {
+        self.waker == other.waker
+    }
+
+    /// Returns a reference to the underlying `RawWaker`.
+    #[inline]
+    pub fn as_raw(&self) -> &RawWaker {
+        &self.waker
+    }
+}
+
+impl Clone for Waker {
+    #[inline]
+    fn clone(&self) -> Waker {
+        Waker { waker: unsafe { (self.waker.vtable.clone)(self.waker.data) } }
+    }
+}
+
+impl Drop for Waker {
+    #[inline]
+    fn drop(&mut self) {
+        unsafe { (self.waker.vtable.drop)(self.waker.data) }
+    }
+}
+
+impl fmt::Debug for Waker {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Waker")
+            .field("data", &self.waker.data)
+            .field("vtable", &(self.waker.vtable as *const RawWakerVTable))
+            .finish()
+    }
+}
+
+impl AsRef<LocalWaker> for Waker {
+    fn as_ref(&self) -> &LocalWaker {
+        // Both are `repr(C)` structs whose only non-zero-sized field is the
+        // `RawWaker`, which comes first, so they have the same layout. A
+        // waker that is safe to use from any thread is in particular safe to
+        // use from this one.
+        unsafe { &*(self as *const Waker as *const LocalWaker) }
+    }
+}
+
+/// A `LocalWaker` is a handle for waking up a task that never leaves the
+/// thread it was created on.
+///
+/// Unlike a [`Waker`](struct.Waker.html), it is neither `Send` nor `Sync`,
+/// so the functions of its vtable don't need to be thread safe, which lets
+/// single-threaded executors use `Rc` and `Cell` for their bookkeeping. A
+/// task finds it through [`Context::local_waker`]; every `Waker` can be used
+/// as a `LocalWaker`.
+///
+/// [`Context::local_waker`]: struct.Context.html#method.local_waker
+#[repr(C)]
+pub struct LocalWaker {
+    waker: RawWaker,
+    _marker: PhantomData<*const ()>,
+}
+
+impl LocalWaker {
+    /// Creates a new `LocalWaker` from a `RawWaker`.
+    ///
+    /// The behavior of the returned `LocalWaker` is undefined if the
+    /// contract defined in `RawWaker`'s and `RawWakerVTable`'s
+    /// documentation is not upheld.
+    #[inline]
+    #[unstable(feature = "local_waker", issue = "0")]
+    pub unsafe fn from_raw(waker: RawWaker) -> LocalWaker {
+        LocalWaker { waker: waker, _marker: PhantomData }
+    }
+
+    /// Creates a `LocalWaker` that does nothing when woken.
+    #[inline]
+    #[unstable(feature = "local_waker", issue = "0")]
+    pub fn noop() -> LocalWaker {
+        unsafe { LocalWaker::from_raw(RawWaker::new(0 as *const (), &NOOP_VTABLE)) }
+    }
+
+    /// Wakes up the task associated with this `LocalWaker`, consuming it.
+    #[inline]
+    #[unstable(feature = "local_waker", issue = "0")]
+    pub fn wake(self) {
+        let wake = self.waker.vtable.wake;
+        let data = self.waker.data;
+        mem::forget(self);
+        unsafe { wake(data) };
+    }
+
+    /// Wakes up the task associated with this `LocalWaker` without consuming
+    /// it.
+    #[inline]
+    #[unstable(feature = "local_waker", issue = "0")]
+    pub fn wake_by_ref(&self) {
+        unsafe { (self.waker.vtable.wake_by_ref)(self.waker.data) }
+    }
+
+    /// Returns `true` if this `LocalWaker` and another one would awake the
+    /// same task, on the same best-effort basis as `Waker::will_wake`.
+    #[inline]
+    #[unstable(feature = "local_waker", issue = "0")]
+    pub fn will_wake(&self, other: &LocalWaker) -> bool {
+        self.waker == other.waker
+    }
+
+    /// Returns a reference to the underlying `RawWaker`.
+    #[inline]
+    #[unstable(feature = "local_waker", issue = "0")]
+    pub fn as_raw(&self) -> &RawWaker {
+        &self.waker
+    }
+}
+
+#[unstable(feature = "local_waker", issue = "0")]
+impl Clone for LocalWaker {
+    #[inline]
+    fn clone(&self) -> LocalWaker {
+        unsafe { LocalWaker::from_raw((self.waker.vtable.clone)(self.waker.data)) }
+    }
+}
+
+#[unstable(feature = "local_waker", issue = "0")]
+impl Drop for LocalWaker {
+    #[inline]
+    fn drop(&mut self) {
+        unsafe { (self.waker.vtable.drop)(self.waker.data) }
+    }
+}
+
+#[unstable(feature = "local_waker", issue = "0")]
+impl fmt::Debug for LocalWaker {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("LocalWaker")
+            .field("data", &self.waker.data)
+            .field("vtable", &(self.waker.vtable as *const RawWakerVTable))
+            .finish()
+    }
+}
+
+/// The context of an asynchronous task.
+///
+/// Currently, `Context` only serves to provide access to a `Waker` that can
+/// be used to wake the current task from any thread, and a `LocalWaker`
+/// for wakeups from the thread the task is being polled on.
+pub struct Context<'a> {
+    waker: &'a Waker,
+    local_waker: Option<&'a LocalWaker>,
+    // Make `Context` invariant over `'a`, so that it can't be shortened to
+    // a lifetime the wakers didn't outlive.
+    _marker: PhantomData<fn(&'a ()) -> &'a ()>,
+}
+
+impl<'a> Context<'a> {
+    /// Creates a new `Context` from a `&Waker`, which is also used as the
+    /// `LocalWaker`.
+    #[inline]
+    pub fn from_waker(waker: &'a Waker) -> Context<'a> {
+        Context { waker: waker, local_waker: None, _marker: PhantomData }
+    }
+
+    /// Creates a new `Context` from a `&Waker` and a separate `&LocalWaker`,
+    /// for executors that can wake a task more cheaply from the thread it
+    /// runs on.
+    #[inline]
+    #[unstable(feature = "local_waker", issue = "0")]
+    pub fn with_local_waker(waker: &'a Waker, local_waker: &'a LocalWaker) -> Context<'a> {
+        Context { waker: waker, local_waker: Some(local_waker), _marker: PhantomData }
+    }
+
+    /// Returns a reference to the `Waker` for the current task.
+    #[inline]
+    pub fn waker(&self) -> &'a Waker {
+        self.waker
+    }
+
+    /// Returns a reference to the `LocalWaker` for the current task.
+    ///
+    /// This is the waker given to `with_local_waker`, or else the `Waker`
+    /// itself.
+    #[inline]
+    #[unstable(feature = "local_waker", issue = "0")]
+    pub fn local_waker(&self) -> &'a LocalWaker {
+        match self.local_waker {
+            Some(local_waker) => local_waker,
+            None => self.waker.as_ref(),
+        }
+    }
+}
+
+impl<'a> fmt::Debug for Context<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Context")
+            .field("waker", &self.waker)
+            .finish()
+    }
+}
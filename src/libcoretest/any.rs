@@ -125,6 +125,45 @@ fn any_unsized() {
     is_any::<[i32]>();
 }
 
+#[test]
+fn any_send_sync_downcast() {
+    let mut x = 5usize;
+    {
+        let a = &mut x as &mut (Any + Send + Sync);
+        assert!(a.is::<usize>());
+        assert!(a.downcast_ref::<u32>().is_none());
+        *a.downcast_mut::<usize>().unwrap() = 6;
+        assert_eq!(format!("{:?}", a), "Any");
+    }
+    assert_eq!(x, 6);
+}
+
+#[test]
+fn any_downcast_unchecked() {
+    let mut x = Test;
+    unsafe {
+        assert_eq!(*(&x as &Any).downcast_ref_unchecked::<Test>(), Test);
+        assert_eq!(*(&mut x as &mut (Any + Send)).downcast_mut_unchecked::<Test>(), Test);
+        assert_eq!(*(&x as &(Any + Send + Sync)).downcast_ref_unchecked::<Test>(), Test);
+    }
+}
+
+#[test]
+fn type_id_erased() {
+    fn id_of_val<T: ?Sized>(_: &T) -> ErasedTypeId { TypeId::of_erased::<T>() }
+    let s = String::from("foo");
+    assert_eq!(id_of_val(&&*s), TypeId::of_erased::<&'static str>());
+    assert!(id_of_val(&&*s) != TypeId::of_erased::<&'static [u8]>());
+}
+
+#[test]
+fn type_names() {
+    assert_eq!(type_name::<u8>(), "u8");
+    assert_eq!(type_name::<[u16]>(), "[u16]");
+    assert_eq!(type_name_of_val(&(1i64, true)), "(i64, bool)");
+    assert!(type_name_of_val(&Test).ends_with("Test"));
+}
+
 #[bench]
 fn bench_downcast_ref(b: &mut Bencher) {
     b.iter(|| {
@@ -7,7 +7,8 @@
 // <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
 // option. This file may not be copied, modified, or distributed
 // except according to those terms.
-use core::array::FixedSizeArray;
+use core::array::{self, FixedSizeArray};
+use core::cell::Cell;
 
 #[test]
 fn fixed_size_array() {
@@ -26,3 +27,25 @@ fn fixed_size_array() {
     assert_eq!(FixedSizeArray::as_mut_slice(&mut empty_array).len(), 0);
     assert_eq!(FixedSizeArray::as_mut_slice(&mut empty_zero_sized).len(), 0);
 }
+
+#[test]
+fn try_from_fn() {
+    let a: Result<[usize; 4], ()> = array::try_from_fn(|i| Ok(i * i));
+    assert_eq!(a, Ok([0, 1, 4, 9]));
+    let e: Option<[(); 0]> = array::try_from_fn(|_| -> Option<()> { panic!() });
+    assert_eq!(e, Some([]));
+
+    // the elements created before the failure are dropped, and no others
+    struct Counted<'a>(&'a Cell<usize>);
+    impl<'a> Drop for Counted<'a> {
+        fn drop(&mut self) {
+            self.0.set(self.0.get() + 1);
+        }
+    }
+    let drops = Cell::new(0);
+    let r: Result<[Counted; 8], usize> = array::try_from_fn(|i| {
+        if i < 5 { Ok(Counted(&drops)) } else { Err(i) }
+    });
+    assert_eq!(r.err(), Some(5));
+    assert_eq!(drops.get(), 5);
+}
@@ -0,0 +1,74 @@
+// Copyright 2016 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use core::async_iter::{self, AsyncIterator, IntoAsyncIterator};
+use core::task::{Context, Poll, Waker};
+
+// Yields `Pending` before every item, waking the task immediately.
+struct Alternate {
+    next: u32,
+    ready: bool,
+}
+
+impl AsyncIterator for Alternate {
+    type Item = u32;
+
+    fn poll_next(&mut self, cx: &mut Context) -> Poll<Option<u32>> {
+        if !self.ready {
+            self.ready = true;
+            cx.waker().wake_by_ref();
+            return Poll::Pending;
+        }
+        self.ready = false;
+        self.next += 1;
+        Poll::Ready(if self.next <= 2 { Some(self.next) } else { None })
+    }
+}
+
+fn collect<I: AsyncIterator<Item = u32>>(mut iter: I) -> Vec<u32> {
+    let waker = Waker::noop();
+    let mut cx = Context::from_waker(&waker);
+    let mut items = Vec::new();
+    loop {
+        match iter.poll_next(&mut cx) {
+            Poll::Ready(Some(x)) => items.push(x),
+            Poll::Ready(None) => return items,
+            Poll::Pending => {}
+        }
+    }
+}
+
+#[test]
+fn from_iter() {
+    let iter = async_iter::from_iter(1..4);
+    assert_eq!(iter.size_hint(), (3, Some(3)));
+    assert_eq!(collect(iter), [1, 2, 3]);
+}
+
+#[test]
+fn empty() {
+    let iter = async_iter::empty::<u32>();
+    assert_eq!(iter.size_hint(), (0, Some(0)));
+    assert!(collect(iter).is_empty());
+}
+
+#[test]
+fn by_mut_ref() {
+    let mut iter = Alternate { next: 0, ready: false };
+    assert_eq!(iter.size_hint(), (0, None));
+    assert_eq!(collect(&mut iter), [1, 2]);
+    assert_eq!(iter.next, 3);
+}
+
+#[test]
+fn into_async_iter() {
+    let iter = async_iter::from_iter(vec![5, 6]).into_async_iter();
+    assert_eq!(collect(iter), [5, 6]);
+}
@@ -0,0 +1,39 @@
+// Copyright 2016 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#[test]
+fn test_then_some() {
+    assert_eq!(true.then_some(1), Some(1));
+    assert_eq!(false.then_some(1), None);
+}
+
+#[test]
+fn test_then() {
+    let mut calls = 0;
+    assert_eq!(false.then(|| { calls += 1; 1 }), None);
+    assert_eq!(calls, 0);
+    assert_eq!(true.then(|| { calls += 1; 1 }), Some(1));
+    assert_eq!(calls, 1);
+}
+
+#[test]
+fn test_ok_or() {
+    assert_eq!(true.ok_or("error"), Ok(()));
+    assert_eq!(false.ok_or("error"), Err("error"));
+}
+
+#[test]
+fn test_ok_or_else() {
+    let mut calls = 0;
+    assert_eq!(true.ok_or_else(|| { calls += 1; "error" }), Ok(()));
+    assert_eq!(calls, 0);
+    assert_eq!(false.ok_or_else(|| { calls += 1; "error" }), Err("error"));
+    assert_eq!(calls, 1);
+}
@@ -11,6 +11,7 @@
 use core::cell::*;
 use core::default::Default;
 use std::mem::drop;
+use std::panic;
 
 #[test]
 fn smoketest_cell() {
@@ -200,6 +201,31 @@ fn cell_default() {
     assert_eq!(0, cell.get());
 }
 
+#[test]
+fn cell_update() {
+    let c = Cell::new(10);
+    assert_eq!(c.update(|x| x + 5), 15);
+    assert_eq!(c.get(), 15);
+    assert_eq!(c.update(|x| x / 3), 5);
+    assert_eq!(c.get(), 5);
+}
+
+#[test]
+fn cell_from_mut() {
+    let mut x = [1, 2, 3];
+    {
+        let c = Cell::from_mut(&mut x);
+        let cells = c.as_array_of_cells();
+        cells[0].set(cells[2].get());
+        cells[2].update(|x| x * 10);
+        assert_eq!(c.get(), [3, 2, 30]);
+    }
+    assert_eq!(x, [3, 2, 30]);
+
+    let empty: Cell<[u8; 0]> = Cell::new([]);
+    assert_eq!(empty.as_array_of_cells().len(), 0);
+}
+
 #[test]
 fn refcell_default() {
     let cell: RefCell<u64> = Default::default();
@@ -249,3 +275,111 @@ fn refcell_ref_coercion() {
 }
 
 
+
+#[test]
+fn try_borrow() {
+    let x = RefCell::new(0);
+    {
+        let _b1 = x.try_borrow().unwrap();
+        let _b2 = x.try_borrow().unwrap();
+        assert!(x.try_borrow_mut().is_err());
+    }
+    {
+        let _m = x.try_borrow_mut().unwrap();
+        assert!(x.try_borrow().is_err());
+        assert!(x.try_borrow_mut().is_err());
+    }
+    assert!(x.try_borrow_mut().is_ok());
+}
+
+#[test]
+fn borrow_error_display() {
+    let x = RefCell::new(0);
+    let _m = x.borrow_mut();
+    assert_eq!(x.try_borrow().unwrap_err().to_string(), "already mutably borrowed");
+    assert_eq!(x.try_borrow_mut().unwrap_err().to_string(), "already borrowed");
+}
+
+#[test]
+fn borrow_error_location() {
+    let x = RefCell::new(0);
+    {
+        let _m = borrow_mut_at!(x); let line = line!();
+        let err = x.try_borrow().unwrap_err().to_string();
+        assert_eq!(err, format!("already mutably borrowed at {}:{}", file!(), line));
+    }
+    {
+        let _b1 = borrow_at!(x); let line = line!();
+        let _b2 = x.borrow();
+        let err = x.try_borrow_mut().unwrap_err().to_string();
+        assert_eq!(err, format!("already borrowed at {}:{}", file!(), line));
+    }
+    // A borrow without a location forgets the one of earlier borrows.
+    let _b = x.borrow();
+    assert_eq!(x.try_borrow_mut().unwrap_err().to_string(), "already borrowed");
+}
+
+#[test]
+#[should_panic(expected = "RefCell<T> already borrowed")]
+fn borrow_mut_at_borrowed() {
+    let x = RefCell::new(0);
+    let _b = x.borrow();
+    borrow_mut_at!(x);
+}
+
+#[test]
+fn refcell_replace() {
+    let x = RefCell::new(1);
+    assert_eq!(x.replace(2), 1);
+    assert_eq!(x.replace_with(|&mut old| old * 10), 2);
+    assert_eq!(*x.borrow(), 20);
+
+    let v = RefCell::new(vec![1, 2, 3]);
+    assert_eq!(v.take(), [1, 2, 3]);
+    assert!(v.borrow().is_empty());
+}
+
+#[test]
+#[should_panic]
+fn refcell_replace_borrowed() {
+    let x = RefCell::new(1);
+    let _b = x.borrow();
+    x.replace(2);
+}
+
+#[test]
+fn lazy_cell() {
+    let runs = Cell::new(0);
+    let lazy = LazyCell::new(|| {
+        runs.set(runs.get() + 1);
+        vec![1, 2]
+    });
+    assert_eq!(LazyCell::get(&lazy), None);
+    assert_eq!(format!("{:?}", lazy), "LazyCell { <uninit> }");
+    assert_eq!(lazy.len(), 2);
+    assert_eq!(*LazyCell::force(&lazy), [1, 2]);
+    assert_eq!(runs.get(), 1);
+    assert_eq!(format!("{:?}", lazy), "LazyCell { value: [1, 2] }");
+}
+
+#[test]
+fn lazy_cell_force_mut_and_into_inner() {
+    let mut lazy = LazyCell::new(|| 1);
+    assert_eq!(LazyCell::get_mut(&mut lazy), None);
+    *LazyCell::force_mut(&mut lazy) += 1;
+    *LazyCell::get_mut(&mut lazy).unwrap() += 1;
+    assert_eq!(LazyCell::into_inner(lazy).ok(), Some(3));
+
+    let lazy = LazyCell::new(|| 1);
+    assert_eq!(LazyCell::into_inner(lazy).err().map(|f| f()), Some(1));
+}
+
+#[test]
+fn lazy_cell_poisoned() {
+    let lazy: LazyCell<i32, _> = LazyCell::new(|| -> i32 { panic!("init") });
+    assert!(panic::catch_unwind(panic::AssertUnwindSafe(|| *lazy)).is_err());
+    let err = panic::catch_unwind(panic::AssertUnwindSafe(|| *lazy)).unwrap_err();
+    assert_eq!(err.downcast_ref::<&str>(),
+               Some(&"LazyCell instance has previously been poisoned"));
+    assert_eq!(LazyCell::get(&lazy), None);
+}
@@ -204,6 +204,18 @@ fn test_encode_utf16() {
     check('\u{1f4a9}', &[0xd83d, 0xdca9]);
 }
 
+#[test]
+fn test_encode_utf16_checked() {
+    let mut buf = [0; 3];
+    assert_eq!('x'.encode_utf16_checked(&mut buf).unwrap(), [0x0078]);
+    assert_eq!('\u{a66e}'.encode_utf16_checked(&mut buf[2..]).unwrap(), [0xa66e]);
+    assert_eq!('\u{1f4a9}'.encode_utf16_checked(&mut buf[1..]).unwrap(), [0xd83d, 0xdca9]);
+    assert_eq!(buf, [0x0078, 0xd83d, 0xdca9]);
+    assert_eq!('\u{1f4a9}'.encode_utf16_checked(&mut buf[2..]), None);
+    assert_eq!('x'.encode_utf16_checked(&mut []), None);
+    assert_eq!(buf, [0x0078, 0xd83d, 0xdca9]);
+}
+
 #[test]
 fn test_len_utf16() {
     assert!('x'.len_utf16() == 1);
@@ -28,6 +28,20 @@ fn test_mut_int_totalord() {
     assert_eq!((&mut 12).cmp(&&mut -5), Greater);
 }
 
+#[test]
+fn test_minmax() {
+    use core::cmp::{minmax, minmax_by, minmax_by_key};
+
+    assert_eq!(minmax(1, 2), [1, 2]);
+    assert_eq!(minmax(2, 1), [1, 2]);
+    assert_eq!(minmax_by(-2, 1, |x: &i32, y: &i32| x.abs().cmp(&y.abs())), [1, -2]);
+    assert_eq!(minmax_by_key(-2, 1, |x: &i32| x.abs()), [1, -2]);
+
+    // Equal values keep their order, like `min` and `max`.
+    assert_eq!(minmax_by_key((1, 'a'), (1, 'b'), |x| x.0), [(1, 'a'), (1, 'b')]);
+    assert_eq!(minmax_by((1, 'b'), (1, 'a'), |x, y| x.0.cmp(&y.0)), [(1, 'b'), (1, 'a')]);
+}
+
 #[test]
 fn test_ordering_reverse() {
     assert_eq!(Less.reverse(), Greater);
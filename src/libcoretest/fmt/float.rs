@@ -8,6 +8,9 @@
 // option. This file may not be copied, modified, or distributed
 // except according to those terms.
 
+use core::{cmp, f32, f64, mem};
+use core::num::{Float, NanPayload};
+
 #[test]
 fn test_format_float() {
     assert!("1" == format!("{:.0}", 1.0f64));
@@ -16,4 +19,129 @@ fn test_format_float() {
     assert!("9.8" == format!("{:.1}", 9.849f64));
     assert!("9.9" == format!("{:.1}", 9.851f64));
     assert!("1" == format!("{:.0}", 0.5f64));
+    assert!("2" == format!("{:.0}", 1.5f64));
+    assert!("2" == format!("{:.0}", 2.5f64));
+}
+
+#[test]
+fn test_format_general() {
+    assert_eq!(format!("{:g}", 1234.5f64), "1234.5");
+    assert_eq!(format!("{:g}", 1e-7f64), "1e-7");
+    assert_eq!(format!("{:g}", 0.0001f64), "0.0001");
+    assert_eq!(format!("{:g}", 1e15f64), "1000000000000000");
+    assert_eq!(format!("{:g}", 1e16f64), "1e16");
+    assert_eq!(format!("{:G}", -2.5e-10f32), "-2.5E-10");
+    assert_eq!(format!("{:g}", 0.0f64), "0");
+    assert_eq!(format!("{:g}", f64::NAN), "NaN");
+    assert_eq!(format!("{:+g}", f64::INFINITY), "+inf");
+
+    assert_eq!(format!("{:.3g}", 1234.5f64), "1.23e3");
+    assert_eq!(format!("{:.4G}", 1234.4f64), "1234");
+    assert_eq!(format!("{:.2g}", 9.96f64), "10");
+    assert_eq!(format!("{:.2g}", 99.6f64), "1e2");
+    assert_eq!(format!("{:.6g}", 0.5f64), "0.5");
+    assert_eq!(format!("{:.6g}", 0.0001f64), "0.0001");
+    assert_eq!(format!("{:.6g}", 0.00001f64), "1e-5");
+    assert_eq!(format!("{:.0g}", 123f32), "1e2");
+    assert_eq!(format!("{:.3g}", 0.0f64), "0");
+    assert_eq!(format!("{:>8.3g}", 3.14159f64), "    3.14");
+
+    assert_eq!(format!("{:#.6g}", 0.5f64), "0.500000");
+    assert_eq!(format!("{:#.5g}", 100.0f64), "100.00");
+    assert_eq!(format!("{:#.3g}", 1e-5f64), "1.00e-5");
+    assert_eq!(format!("{:#.3g}", 0.0f64), "0.00");
+}
+
+#[test]
+fn test_format_nan_payload() {
+    let x = <f64 as Float>::nan_with_payload(0x1f);
+    assert_eq!(format!("{:?}", x), "NaN");
+    assert_eq!(format!("{:#?}", -x), "NaN");
+    assert_eq!(format!("{:?}", NanPayload(x)), "NaN(0x1f)");
+    assert_eq!(format!("{:?}", NanPayload(-x)), "-NaN(0x1f)");
+    assert_eq!(format!("{:+?}", NanPayload(x)), "+NaN(0x1f)");
+    assert_eq!(format!("{:12?}", NanPayload(-x)), "  -NaN(0x1f)");
+    assert_eq!(format!("{:?}", NanPayload(-<f32 as Float>::nan_with_payload(0))), "-NaN");
+    assert_eq!(format!("{:?}", NanPayload(<f32 as Float>::nan_with_payload(0x3fffff))),
+               "NaN(0x3fffff)");
+    assert_eq!(format!("{:?}", NanPayload(1.5f64)), "1.5");
+    assert_eq!(format!("{:?}", NanPayload(-0.0f32)), "-0");
+}
+
+#[test]
+fn test_write_buf() {
+    let mut buf = [0; 8];
+    assert_eq!(1.5f64.write_shortest(&mut buf), Some("1.5"));
+    assert_eq!((-0.0f64).write_shortest(&mut buf), Some("0"));
+    assert_eq!(f32::NAN.write_shortest(&mut buf), Some("NaN"));
+    assert_eq!(12345678f32.write_shortest(&mut buf), Some("12345678"));
+    assert_eq!(123456789f32.write_shortest(&mut buf), None);
+    assert_eq!(0.1f64.write_fixed(&mut buf, 3), Some("0.100"));
+    assert_eq!((-9.99f64).write_fixed(&mut buf, 1), Some("-10.0"));
+    assert_eq!(1e5f32.write_fixed(&mut buf, 0), Some("100000"));
+    assert_eq!(1e5f32.write_fixed(&mut buf, 2), None);
+    assert_eq!(0.5f64.write_fixed(&mut buf[..0], 0), None);
+}
+
+// Checks that `x` and `-x` fit in the maximum lengths in all the formatting
+// modes, and that writing them into buffers of these lengths matches `format!`.
+// Returns the longest lengths of `{}` and `{:e}`.
+macro_rules! check_display_len {
+    ($float:ident, $x:expr) => ({
+        let x: $float = $x;
+        let mut lens = (0, 0);
+        let mut buf = [0; $float::MAX_DISPLAY_LEN];
+        for &x in &[x, -x] {
+            assert_eq!(x.write_shortest(&mut buf), Some(&*format!("{}", x)));
+            assert_eq!(x.write_fixed(&mut buf, 3), Some(&*format!("{:.3}", x)));
+            let display = format!("{}", x).len();
+            let exp = format!("{:e}", x).len();
+            assert!(display <= $float::MAX_DISPLAY_LEN, "{}", x);
+            assert!(format!("{:?}", x).len() <= $float::MAX_DISPLAY_LEN, "{:?}", x);
+            assert!(format!("{:+}", x).len() <= $float::MAX_DISPLAY_LEN, "{:+}", x);
+            assert!(exp <= $float::MAX_EXP_DISPLAY_LEN, "{:e}", x);
+            assert!(format!("{:+E}", x).len() <= $float::MAX_EXP_DISPLAY_LEN, "{:+E}", x);
+            lens = (cmp::max(lens.0, display), cmp::max(lens.1, exp));
+        }
+        lens
+    })
+}
+
+#[test]
+fn test_max_display_len_f64() {
+    assert_eq!(check_display_len!(f64, 5e-324), (f64::MAX_DISPLAY_LEN, 7));
+    assert_eq!(check_display_len!(f64, f64::MIN_POSITIVE).1, f64::MAX_EXP_DISPLAY_LEN);
+    assert_eq!(check_display_len!(f64, f64::MAX).0, 310);
+    check_display_len!(f64, f64::NAN);
+    check_display_len!(f64, f64::INFINITY);
+
+    // every exponent, with a few mantissas spread out over each binade
+    let mut seed = 0x2545f4914f6cdd1du64;
+    for exp in 0..0x7ff {
+        for _ in 0..8 {
+            seed ^= seed << 13;
+            seed ^= seed >> 7;
+            seed ^= seed << 17;
+            let bits = exp << 52 | seed >> 12;
+            check_display_len!(f64, unsafe { mem::transmute(bits) });
+        }
+    }
+}
+
+#[test]
+fn test_max_display_len_f32() {
+    assert_eq!(check_display_len!(f32, 1e-45), (f32::MAX_DISPLAY_LEN, 6));
+    assert_eq!(check_display_len!(f32, 1.13885106e-35).1, f32::MAX_EXP_DISPLAY_LEN);
+    assert_eq!(check_display_len!(f32, f32::MAX).0, 40);
+
+    let mut seed = 0x2545f491u32;
+    for exp in 0..0xff {
+        for _ in 0..64 {
+            seed ^= seed << 13;
+            seed ^= seed >> 17;
+            seed ^= seed << 5;
+            let bits = exp << 23 | seed >> 9;
+            check_display_len!(f32, unsafe { mem::transmute(bits) });
+        }
+    }
 }
@@ -0,0 +1,46 @@
+// Copyright 2016 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use core::future::{self, Future};
+use core::task::{Context, Poll, Waker};
+
+#[test]
+fn ready() {
+    let waker = Waker::noop();
+    let mut cx = Context::from_waker(&waker);
+    let mut fut = future::ready(5);
+    assert_eq!(fut.poll(&mut cx), Poll::Ready(5));
+
+    assert_eq!(future::ready("x").into_inner(), "x");
+}
+
+#[test]
+fn ready_from_poll() {
+    let waker = Waker::noop();
+    let mut cx = Context::from_waker(&waker);
+    let mut fut = Poll::Ready(5).ready();
+    assert_eq!(fut.poll(&mut cx), Poll::Ready(5));
+
+    let pending: Poll<i32> = Poll::Pending;
+    let mut fut = pending.ready();
+    assert_eq!(fut.poll(&mut cx), Poll::Pending);
+    assert_eq!(fut.poll(&mut cx), Poll::Pending);
+    assert_eq!(fut.into_poll(), Poll::Pending);
+}
+
+#[test]
+#[should_panic]
+fn ready_polled_twice() {
+    let waker = Waker::noop();
+    let mut cx = Context::from_waker(&waker);
+    let mut fut = future::ready(());
+    let _ = fut.poll(&mut cx);
+    let _ = fut.poll(&mut cx);
+}
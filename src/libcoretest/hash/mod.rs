@@ -10,7 +10,7 @@
 
 mod sip;
 
-use std::hash::{Hash, Hasher};
+use std::hash::{BuildHasher, BuildHasherDefault, Hash, Hasher};
 use std::default::Default;
 
 struct MyHasher {
@@ -111,3 +111,11 @@ fn test_custom_state() {
 
     assert_eq!(hash(&Custom { hash: 5 }), 5);
 }
+
+#[test]
+fn test_hash_one() {
+    let build = BuildHasherDefault::<MyHasher>::default();
+    assert_eq!(build.hash_one(5_u32), 5);
+    assert_eq!(build.hash_one(&Custom { hash: 1 }), 1);
+    assert_eq!(build.hash_one(&(1_u8, 2_u8)), 3);
+}
@@ -0,0 +1,27 @@
+// Copyright 2016 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use core::hint;
+
+#[test]
+fn test_black_box() {
+    assert_eq!(hint::black_box(3), 3);
+    let v = hint::black_box(vec![1, 2]);
+    assert_eq!(v, [1, 2]);
+}
+
+#[test]
+fn test_branch_hints() {
+    for &b in &[false, true] {
+        assert_eq!(hint::likely(b), b);
+        assert_eq!(hint::unlikely(b), b);
+    }
+    unsafe { hint::assert_unchecked(hint::black_box(1) == 1) }
+}
@@ -9,7 +9,8 @@
 // except according to those terms.
 
 use core::iter::*;
-use core::{i8, i16, isize};
+use core::num::{Saturating, Wrapping};
+use core::{char, i8, i16, isize};
 use core::usize;
 
 use test::Bencher;
@@ -554,6 +555,90 @@ fn test_iterator_product() {
     assert_eq!(v[..0].iter().cloned().product::<i32>(), 1);
 }
 
+// Records how the elements were added.
+#[derive(Default)]
+struct ExtendLog {
+    reserved: usize,
+    items: Vec<i32>,
+    extends: usize,
+}
+
+impl Extend<i32> for ExtendLog {
+    fn extend<I: IntoIterator<Item=i32>>(&mut self, iter: I) {
+        self.extends += 1;
+        self.items.extend(iter);
+    }
+
+    fn extend_one(&mut self, item: i32) {
+        self.items.push(item);
+    }
+
+    fn extend_reserve(&mut self, additional: usize) {
+        self.reserved += additional;
+    }
+}
+
+#[test]
+fn test_unzip_partition_extend_one() {
+    let (a, b): (ExtendLog, ExtendLog) = (0..5).map(|x| (x, -x)).unzip();
+    assert_eq!((a.reserved, a.extends), (5, 0));
+    assert_eq!((b.reserved, b.extends), (5, 0));
+    assert_eq!(a.items, [0, 1, 2, 3, 4]);
+    assert_eq!(b.items, [0, -1, -2, -3, -4]);
+
+    let (even, odd): (ExtendLog, ExtendLog) = (0..5).partition(|x| x % 2 == 0);
+    assert_eq!((even.extends, odd.extends), (0, 0));
+    assert_eq!(even.items, [0, 2, 4]);
+    assert_eq!(odd.items, [1, 3]);
+}
+
+#[test]
+fn test_iterator_checked_sum() {
+    let v: &[u8] = &[100, 100, 50, 10];
+    assert_eq!(v[..3].iter().checked_sum::<u8>(), Some(250));
+    assert_eq!(v.iter().checked_sum::<u8>(), None);
+    assert_eq!(v[..0].iter().cloned().checked_sum::<u8>(), Some(0));
+    assert_eq!([-128i8, 127, 1].iter().checked_sum::<i8>(), Some(0));
+    assert_eq!([-128i8, -1].iter().checked_sum::<i8>(), None);
+
+    // the iterator is not consumed past the overflow
+    let mut it = v.iter().cloned();
+    assert_eq!(it.by_ref().chain(v.iter().cloned()).checked_sum::<u8>(), None);
+    assert_eq!(it.next(), None);
+    let mut it = [200u8, 100, 1, 2].iter().cloned();
+    assert_eq!(it.by_ref().checked_sum::<u8>(), None);
+    assert_eq!(it.next(), Some(1));
+}
+
+#[test]
+fn test_iterator_checked_product() {
+    let v: &[i32] = &[0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10];
+    assert_eq!(v[1..5].iter().checked_product::<i32>(), Some(24));
+    assert_eq!(v[..0].iter().cloned().checked_product::<i32>(), Some(1));
+    assert_eq!((1..13).checked_product::<i32>(), Some(479001600));
+    assert_eq!((1..14).checked_product::<i32>(), None);
+    // an overflow is reported even if a later zero would make the product fit
+    assert_eq!((1..14).chain(0..1).checked_product::<i32>(), None);
+}
+
+#[test]
+fn test_iterator_sum_product_wrapping_saturating() {
+    let v: &[u8] = &[200, 50, 10];
+    assert_eq!(v.iter().map(|&x| Wrapping(x)).sum::<Wrapping<u8>>(), Wrapping(4));
+    assert_eq!(v.iter().map(|&x| Saturating(x)).sum::<Saturating<u8>>(), Saturating(255));
+    assert_eq!(v.iter().map(|&x| Wrapping(x)).product::<Wrapping<u8>>(), Wrapping(160));
+    assert_eq!(v.iter().map(|&x| Saturating(x)).product::<Saturating<u8>>(), Saturating(255));
+
+    let w = [Wrapping(i8::MAX), Wrapping(1)];
+    assert_eq!(w.iter().sum::<Wrapping<i8>>(), Wrapping(i8::MIN));
+    assert_eq!(w.iter().product::<Wrapping<i8>>(), Wrapping(i8::MAX));
+    let s = [Saturating(i8::MIN), Saturating(-1), Saturating(2)];
+    assert_eq!(s.iter().sum::<Saturating<i8>>(), Saturating(-126));
+    assert_eq!(s.iter().product::<Saturating<i8>>(), Saturating(i8::MAX));
+    assert_eq!(s[..0].iter().sum::<Saturating<i8>>(), Saturating(0));
+    assert_eq!(s[..0].iter().product::<Saturating<i8>>(), Saturating(1));
+}
+
 #[test]
 fn test_iterator_max() {
     let v: &[_] = &[0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10];
@@ -634,6 +719,86 @@ fn test_any() {
     assert!(!v[..0].iter().any(|_| panic!()));
 }
 
+#[test]
+fn test_try_fold() {
+    let v: &[i8] = &[10, 20, 30, 100, 40, 50];
+    assert_eq!(v[..3].iter().try_fold(0i8, |acc, &x| acc.checked_add(x)), Some(60));
+
+    let mut it = v.iter();
+    assert_eq!(it.try_fold(0i8, |acc, &x| acc.checked_add(x)), None);
+    assert_eq!(it.next(), Some(&40));
+
+    let r: Result<i32, &str> = ["1", "2", "3"].iter().try_fold(0, |acc, s| {
+        s.parse::<i32>().map(|x| acc + x).map_err(|_| "parse error")
+    });
+    assert_eq!(r, Ok(6));
+    assert_eq!((0..0).try_fold(7, |_, _| -> Option<i32> { panic!() }), Some(7));
+}
+
+#[test]
+fn test_try_for_each() {
+    use core::ops::ControlFlow;
+
+    let mut seen = Vec::new();
+    let r = (1..10).try_for_each(|x| {
+        seen.push(x);
+        if x * x > 10 { ControlFlow::Break(x) } else { ControlFlow::Continue(()) }
+    });
+    assert_eq!(r, ControlFlow::Break(4));
+    assert_eq!(seen, [1, 2, 3, 4]);
+
+    let r: ControlFlow<i32> = (1..4).try_for_each(|_| ControlFlow::Continue(()));
+    assert_eq!(r, ControlFlow::Continue(()));
+}
+
+#[test]
+fn test_try_find() {
+    let a = [1, 2, 3, -4, 5];
+    let mut it = a.iter();
+    let r: Result<_, i32> = it.try_find(|&&x| if x < 0 { Err(x) } else { Ok(x == 2) });
+    assert_eq!(r, Ok(Some(&2)));
+    let r: Result<_, i32> = it.try_find(|&&x| if x < 0 { Err(x) } else { Ok(x == 5) });
+    assert_eq!(r, Err(-4));
+    assert_eq!(it.next(), Some(&5));
+
+    assert_eq!((1..4).try_find(|&x| Some(x > 5)), Some(None));
+    assert_eq!((1..4).try_find(|&x| if x == 2 { None } else { Some(false) }), None);
+}
+
+#[test]
+fn test_from_coroutine() {
+    use core::marker::PhantomPinned;
+    use core::ops::{Coroutine, CoroutineState};
+    use core::pin::Pin;
+
+    struct Squares {
+        next: u32,
+        end: u32,
+        _pinned: PhantomPinned,
+    }
+
+    impl Coroutine for Squares {
+        type Yield = u32;
+        type Return = ();
+
+        fn resume(this: Pin<&mut Squares>, _: ()) -> CoroutineState<u32, ()> {
+            let this = unsafe { this.get_unchecked_mut() };
+            assert!(this.next <= this.end, "resumed after completion");
+            this.next += 1;
+            if this.next > this.end {
+                CoroutineState::Complete(())
+            } else {
+                CoroutineState::Yielded(this.next * this.next)
+            }
+        }
+    }
+
+    let squares = pin!(Squares { next: 0, end: 4, _pinned: PhantomPinned });
+    let mut it = from_coroutine(squares);
+    assert_eq!(it.by_ref().collect::<Vec<_>>(), [1, 4, 9, 16]);
+    assert_eq!(it.next(), None);
+}
+
 #[test]
 fn test_find() {
     let v: &[isize] = &[1, 3, 9, 27, 103, 14, 11];
@@ -670,6 +835,36 @@ fn test_min_by_key() {
     assert_eq!(*xs.iter().min_by_key(|x| x.abs()).unwrap(), 0);
 }
 
+#[test]
+fn test_minmax() {
+    use core::iter::MinMaxResult::{NoElements, OneElement, MinMax};
+
+    let xs: &[isize] = &[];
+    assert_eq!(xs.iter().minmax(), NoElements);
+    assert_eq!([1].iter().minmax(), OneElement(&1));
+    assert_eq!([2, 1].iter().minmax(), MinMax(&1, &2));
+    assert_eq!([3, 1, 2].iter().minmax(), MinMax(&1, &3));
+    assert_eq!([5, 2, 9, 1, 7, 3].iter().minmax(), MinMax(&1, &9));
+    assert_eq!([5, 2, 9, 1, 7, 3, 0].iter().minmax(), MinMax(&0, &9));
+    assert_eq!([5, 2, 9, 1, 7, 3, 10].iter().minmax(), MinMax(&1, &10));
+
+    assert_eq!(NoElements::<isize>.into_option(), None);
+    assert_eq!(OneElement(1).into_option(), Some((1, 1)));
+    assert_eq!(MinMax(1, 2).into_option(), Some((1, 2)));
+}
+
+#[test]
+fn test_minmax_stability() {
+    // Like `min` and `max`, `minmax` picks the first minimum and the last
+    // maximum, whatever the parity of their positions.
+    for len in 1..8 {
+        let xs = vec![0; len];
+        let (min, max) = xs.iter().minmax().into_option().unwrap();
+        assert!(min as *const _ == &xs[0] as *const _);
+        assert!(max as *const _ == &xs[len - 1] as *const _);
+    }
+}
+
 #[test]
 fn test_by_ref() {
     let mut xs = 0..10;
@@ -903,6 +1098,133 @@ fn test_range_step() {
     assert_eq!((isize::MIN..isize::MAX).step_by(1).size_hint(), (usize::MAX, Some(usize::MAX)));
 }
 
+#[test]
+fn test_range_nth() {
+    assert_eq!((10..15).nth(0), Some(10));
+    assert_eq!((10..15).nth(1), Some(11));
+    assert_eq!((10..15).nth(4), Some(14));
+    assert_eq!((10..15).nth(5), None);
+
+    let mut r = 10..20;
+    assert_eq!(r.nth(2), Some(12));
+    assert_eq!(r, 13..20);
+    assert_eq!(r.nth(2), Some(15));
+    assert_eq!(r, 16..20);
+    assert_eq!(r.nth(10), None);
+    assert_eq!(r, 20..20);
+    assert_eq!(r.next_back(), None);
+
+    let mut r = 5..2;
+    assert_eq!(r.nth(0), None);
+    assert_eq!(r, 5..2);
+    assert_eq!((0u8..).nth(254), Some(254));
+    let mut r = 250u8..;
+    assert_eq!(r.nth(4), Some(254));
+    assert_eq!(r, 255..);
+    assert_eq!((i8::MIN..i8::MAX).nth(usize::MAX), None);
+    assert_eq!((isize::MIN..isize::MAX).nth(usize::MAX - 1), Some(isize::MAX - 1));
+}
+
+#[test]
+fn test_step_add_usize() {
+    assert_eq!(Step::add_usize(&250u8, 5), Some(255));
+    assert_eq!(Step::add_usize(&250u8, 6), None);
+    assert_eq!(Step::add_usize(&250u8, usize::MAX), None);
+    assert_eq!(Step::add_usize(&i8::MIN, 255), Some(i8::MAX));
+    assert_eq!(Step::add_usize(&i8::MIN, 256), None);
+    assert_eq!(Step::add_usize(&-1i8, 1), Some(0));
+    assert_eq!(Step::add_usize(&isize::MIN, usize::MAX), Some(isize::MAX));
+    assert_eq!(Step::add_usize(&1isize, usize::MAX), None);
+    assert_eq!(Step::add_usize(&0usize, usize::MAX), Some(usize::MAX));
+}
+
+#[test]
+fn test_char_range() {
+    assert_eq!(('a'...'d').collect::<String>(), "abcd");
+    assert_eq!(('a'...'d').rev().collect::<String>(), "dcba");
+    assert_eq!(('a'...'e').len(), 5);
+    assert_eq!(('e'...'a').count(), 0);
+    assert_eq!(('\0'...'\0').collect::<Vec<_>>(), ['\0']);
+    assert_eq!((char::MAX...char::MAX).rev().collect::<Vec<_>>(), [char::MAX]);
+
+    // the surrogate gap is skipped in both directions
+    let gap = ['\u{D7FE}', '\u{D7FF}', '\u{E000}', '\u{E001}'];
+    assert_eq!(('\u{D7FE}'...'\u{E001}').collect::<Vec<_>>(), gap);
+    assert_eq!(('\u{D7FE}'...'\u{E001}').rev().collect::<Vec<_>>(),
+               gap.iter().rev().cloned().collect::<Vec<_>>());
+    assert_eq!(('\u{D7FF}'...'\u{E000}').len(), 2);
+    assert_eq!(('\u{D7FF}'...'\u{E000}').size_hint(), (2, Some(2)));
+    let mut r = '\u{D7FF}'...'\u{E000}';
+    assert_eq!(r.next_back(), Some('\u{E000}'));
+    assert_eq!(r.next_back(), Some('\u{D7FF}'));
+    assert_eq!(r.next_back(), None);
+
+    // the new range types iterate the same way
+    let r = ::core::range::RangeInclusive { start: '\u{D7FF}', end: '\u{E000}' };
+    assert_eq!(r.iter().len(), 2);
+    assert_eq!(r.into_iter().nth(1), Some('\u{E000}'));
+}
+
+#[test]
+fn test_char_range_exhaustive() {
+    let all = || (0..0x110000).filter_map(char::from_u32);
+    let count = 0x110000 - 0x800;
+    assert!(all().eq('\0'...char::MAX));
+    assert!(all().rev().eq(('\0'...char::MAX).rev()));
+    assert_eq!(('\0'...char::MAX).len(), count);
+    // every char is `nth` of the full range at its index
+    for (i, c) in all().enumerate() {
+        assert_eq!(('\0'...char::MAX).nth(i), Some(c));
+        assert_eq!((c...char::MAX).len(), count - i);
+        assert_eq!((c...char::MAX).nth(count - i - 1), Some(char::MAX));
+        let mut r = c...char::MAX;
+        assert_eq!(r.nth(count - i), None);
+        assert_eq!(r.len(), 0);
+    }
+}
+
+#[test]
+fn test_char_range_nth() {
+    let mut r = 'a'...char::MAX;
+    assert_eq!(r.nth(0xD800 - 0x61), Some('\u{E000}'));
+    assert_eq!(r.next(), Some('\u{E001}'));
+    assert_eq!(('\u{D7FF}'...'\u{E000}').nth(1), Some('\u{E000}'));
+    assert_eq!(('\u{D7FF}'...'\u{E000}').nth(2), None);
+    assert_eq!(('a'...'z').nth(usize::MAX), None);
+
+    let mut r = 'a'...'c';
+    assert_eq!(r.nth(3), None);
+    assert_eq!((r.next(), r.next_back(), r.len()), (None, None, 0));
+    let mut r = 'c'...'a';
+    assert_eq!(r.nth(0), None);
+    assert_eq!(r.next(), None);
+}
+
+#[test]
+fn test_char_range_step() {
+    let v = ('\u{D7FE}'..'\u{E002}').step_by('\u{2}').collect::<Vec<_>>();
+    assert_eq!(v, ['\u{D7FE}', '\u{E000}']);
+    assert_eq!(('\u{D7FE}'..'\u{E002}').step_by('\u{2}').size_hint(), (2, Some(2)));
+    assert_eq!(('\u{D7FE}'..'\u{E003}').step_by('\u{2}').size_hint(), (3, Some(3)));
+    let v = ('\u{D7FF}'...'\u{E001}').step_by('\u{2}').collect::<Vec<_>>();
+    assert_eq!(v, ['\u{D7FF}', '\u{E001}']);
+    // stepping beyond the last char stops
+    let big = '\u{100000}';
+    let v = ('\0'..char::MAX).step_by(big).collect::<Vec<_>>();
+    assert_eq!(v, ['\0', '\u{100800}']);
+    assert_eq!(('\0'..char::MAX).step_by(big).size_hint(), (2, Some(2)));
+    assert_eq!(('a'..'b').step_by('\0').size_hint(), (0, None));
+
+    assert_eq!(Step::steps_between(&'\u{D7FF}', &'\u{E000}', &'\u{1}'), Some(1));
+    assert_eq!(Step::steps_between_by_one(&'\0', &char::MAX), Some(0x110000 - 0x800 - 1));
+    assert_eq!(Step::steps_between_by_one(&'b', &'a'), Some(0));
+    assert_eq!(Step::add_usize(&'\u{D7FF}', 1), Some('\u{E000}'));
+    assert_eq!(Step::add_usize(&char::MAX, 1), None);
+    assert_eq!(Step::add_usize(&'\0', usize::MAX), None);
+    assert_eq!(Step::sub_one(&'\u{E000}'), '\u{D7FF}');
+    assert_eq!(Step::add_one(&'\u{D7FF}'), '\u{E000}');
+}
+
 #[test]
 fn test_repeat() {
     let mut it = repeat(42);
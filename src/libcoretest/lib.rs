@@ -10,27 +10,97 @@
 
 #![deny(warnings)]
 
+#![feature(any_downcast)]
+#![feature(as_cell)]
 #![feature(as_unsafe_cell)]
+#![feature(associated_consts)]
+#![feature(async_iterator)]
+#![feature(bignum)]
+#![feature(bool_to_option)]
 #![feature(borrow_state)]
 #![feature(box_syntax)]
+#![feature(build_hasher_simple_hash_one)]
 #![feature(cell_extras)]
+#![feature(cell_update)]
+#![feature(cmp_minmax)]
+#![feature(collect_partial)]
 #![feature(const_fn)]
+#![feature(copysign)]
+#![feature(coroutine_trait)]
+#![feature(core_float)]
+#![feature(core_float_mul_add)]
+#![feature(core_float_rounding)]
 #![feature(core_private_bignum)]
 #![feature(core_private_diy_float)]
 #![feature(dec2flt)]
+#![feature(encode_utf16_into)]
+#![feature(extend_one)]
+#![feature(f128)]
+#![feature(f16)]
 #![feature(fixed_size_array)]
+#![feature(hint)]
+#![feature(i128)]
+#![feature(inclusive_range)]
+#![feature(inclusive_range_syntax)]
+#![feature(int_traits)]
+#![feature(iter_checked_arith)]
+#![feature(iter_from_coroutine)]
+#![feature(iter_minmax)]
+#![feature(float_bits)]
+#![feature(float_clamp)]
+#![feature(float_decomposition)]
+#![feature(float_display_len)]
+#![feature(float_env)]
+#![feature(float_error_kind)]
+#![feature(float_exp_mantissa)]
+#![feature(float_from_int)]
+#![feature(float_from_str_exact)]
+#![feature(float_from_str_radix)]
+#![feature(float_from_str_rounded)]
+#![feature(float_nan_payload)]
+#![feature(float_parse_options)]
+#![feature(float_rounding_mode)]
+#![feature(float_total_ord)]
+#![feature(float_write_buf)]
 #![feature(flt2dec)]
+#![feature(fmt_general)]
+#![feature(from_str_partial)]
+#![feature(futures_api)]
+#![feature(future_readiness_fns)]
+#![feature(lazy_cell)]
 #![feature(libc)]
+#![feature(local_waker)]
+#![feature(new_range_api)]
+#![feature(noop_waker)]
 #![feature(nonzero)]
+#![feature(num_from_ascii)]
+#![feature(parse_underscores)]
+#![feature(question_mark)]
 #![feature(rand)]
+#![feature(pin)]
+#![feature(poll_ready)]
+#![feature(portable_simd)]
 #![feature(raw)]
+#![feature(raw_float)]
+#![feature(refcell_borrow_location)]
+#![feature(refcell_replace)]
+#![feature(round_ties_even)]
+#![feature(saturating_int)]
 #![feature(sip_hash_13)]
+#![feature(slice_get_slice)]
 #![feature(slice_patterns)]
 #![feature(step_by)]
+#![feature(step_trait)]
 #![feature(test)]
+#![feature(total_cmp)]
+#![feature(try_trait_v2)]
+#![feature(type_id_erased)]
+#![feature(type_id_u64)]
+#![feature(type_name)]
 #![feature(unboxed_closures)]
 #![feature(unicode)]
 #![feature(unique)]
+#![feature(try_borrow)]
 #![feature(try_from)]
 
 extern crate core;
@@ -41,13 +111,17 @@ extern crate rand;
 
 mod any;
 mod array;
+mod async_iter;
 mod atomic;
+mod bool;
 mod cell;
 mod char;
 mod clone;
 mod cmp;
 mod fmt;
+mod future;
 mod hash;
+mod hint;
 mod intrinsics;
 mod iter;
 mod mem;
@@ -55,8 +129,12 @@ mod nonzero;
 mod num;
 mod ops;
 mod option;
+mod pin;
 mod ptr;
+mod range;
 mod result;
+mod simd;
 mod slice;
 mod str;
+mod task;
 mod tuple;
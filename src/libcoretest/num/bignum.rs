@@ -102,6 +102,23 @@ fn test_mul_pow2() {
     assert_eq!(*Big::from_small(0).mul_pow2(23), Big::from_small(0));
 }
 
+#[test]
+fn test_div_pow2() {
+    assert_eq!(*Big::from_small(0x70).div_pow2(4), Big::from_small(0x7));
+    assert_eq!(*Big::from_small(0x7f).div_pow2(4), Big::from_small(0x7));
+    assert_eq!(*Big::from_u64(0x1fe).div_pow2(1), Big::from_small(0xff));
+    assert_eq!(*Big::from_u64(0xff000).div_pow2(12), Big::from_small(0xff));
+    assert_eq!(*Big::from_u64(0x918000).div_pow2(15), Big::from_u64(0x123));
+    assert_eq!(*Big::from_u64(0xabcdef).div_pow2(8), Big::from_u64(0xabcd));
+    assert_eq!(*Big::from_u64(0xabcdef).div_pow2(0), Big::from_u64(0xabcdef));
+    assert_eq!(*Big::from_u64(0xabcdef).div_pow2(23), Big::from_small(0x1));
+    assert_eq!(*Big::from_u64(0xabcdef).div_pow2(24), Big::from_small(0));
+    assert_eq!(*Big::from_u64(0xabcdef).div_pow2(100), Big::from_small(0));
+    // the result can be added to and shifted back as usual
+    assert_eq!(*Big::from_u64(0xabcdef).div_pow2(20).add_small(1).mul_pow2(20),
+               Big::from_u64(0xb00000));
+}
+
 #[test]
 #[should_panic]
 fn test_mul_pow2_overflow_1() {
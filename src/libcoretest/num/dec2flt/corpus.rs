@@ -0,0 +1,152 @@
+// Copyright 2016 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// Tests and benchmarks driven by external data files, such as the
+// parse-number-fxx-test-data collection. They only run if the environment
+// variable `RUST_DEC2FLT_CORPUS` is set to a list of files (separated like
+// `PATH`) so that regular test runs stay fast.
+//
+// Each non-empty line not starting with `#` holds whitespace-separated
+// fields: the expected `f32` bits and `f64` bits in hexadecimal, followed by
+// the decimal string. Any fields before these, such as the `f16` bits in the
+// parse-number-fxx format, are ignored.
+
+use std::env;
+use std::fs::File;
+use std::io::{BufRead, BufReader};
+use std::mem;
+use std::path::PathBuf;
+use test;
+
+const CORPUS_VAR: &'static str = "RUST_DEC2FLT_CORPUS";
+
+struct Case {
+    file: PathBuf,
+    line: usize,
+    input: String,
+    f32_bits: u32,
+    f64_bits: u64,
+}
+
+fn parse_line(line: &str) -> Option<(u32, u64, String)> {
+    let fields: Vec<&str> = line.split_whitespace().collect();
+    if fields.len() < 3 {
+        return None;
+    }
+    let n = fields.len();
+    let f32_bits = match u32::from_str_radix(fields[n - 3], 16) {
+        Ok(bits) => bits,
+        Err(_) => return None,
+    };
+    let f64_bits = match u64::from_str_radix(fields[n - 2], 16) {
+        Ok(bits) => bits,
+        Err(_) => return None,
+    };
+    Some((f32_bits, f64_bits, fields[n - 1].to_string()))
+}
+
+// Returns `None` if the corpus hasn't been requested.
+fn load() -> Option<Vec<Case>> {
+    let paths = match env::var_os(CORPUS_VAR) {
+        Some(paths) => paths,
+        None => return None,
+    };
+    let mut cases = Vec::new();
+    for file in env::split_paths(&paths) {
+        let reader = BufReader::new(File::open(&file).unwrap_or_else(|e| {
+            panic!("failed to open {}: {}", file.display(), e)
+        }));
+        for (i, line) in reader.lines().enumerate() {
+            let line = line.unwrap();
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let (f32_bits, f64_bits, input) = parse_line(line).unwrap_or_else(|| {
+                panic!("{}:{}: malformed line {:?}", file.display(), i + 1, line)
+            });
+            cases.push(Case {
+                file: file.clone(),
+                line: i + 1,
+                input: input,
+                f32_bits: f32_bits,
+                f64_bits: f64_bits,
+            });
+        }
+    }
+    Some(cases)
+}
+
+#[test]
+fn parse_line_formats() {
+    assert_eq!(parse_line("3F800000 3FF0000000000000 1"),
+               Some((0x3F800000, 0x3FF0000000000000, "1".to_string())));
+    assert_eq!(parse_line("3C00 3F800000 3FF0000000000000 1e0"),
+               Some((0x3F800000, 0x3FF0000000000000, "1e0".to_string())));
+    assert_eq!(parse_line("3FF0000000000000 1"), None);
+    assert_eq!(parse_line("x 3FF0000000000000 1"), None);
+}
+
+#[test]
+fn corpus() {
+    let cases = match load() {
+        Some(cases) => cases,
+        None => return,
+    };
+
+    let mut failures = Vec::new();
+    for case in &cases {
+        let f32_bits = case.input.parse::<f32>()
+                           .map(|x| unsafe { mem::transmute::<f32, u32>(x) });
+        let f64_bits = case.input.parse::<f64>()
+                           .map(|x| unsafe { mem::transmute::<f64, u64>(x) });
+        if f32_bits != Ok(case.f32_bits) || f64_bits != Ok(case.f64_bits) {
+            failures.push(format!("{}:{}: {:?}: expected {:08x} and {:016x}, \
+                                   got {:?} and {:?}",
+                                  case.file.display(), case.line, case.input,
+                                  case.f32_bits, case.f64_bits,
+                                  f32_bits.map(|b| format!("{:08x}", b)),
+                                  f64_bits.map(|b| format!("{:016x}", b))));
+        }
+    }
+    for failure in failures.iter().take(20) {
+        println!("{}", failure);
+    }
+    assert!(failures.is_empty(), "{} out of {} corpus values were parsed incorrectly",
+            failures.len(), cases.len());
+}
+
+#[bench]
+fn bench_corpus_f64(b: &mut test::Bencher) {
+    let cases = match load() {
+        Some(cases) => cases,
+        None => return,
+    };
+    b.bytes = cases.iter().map(|case| case.input.len() as u64).sum();
+    b.iter(|| {
+        for case in &cases {
+            test::black_box(case.input.parse::<f64>().ok());
+        }
+    });
+}
+
+#[bench]
+fn bench_corpus_f32(b: &mut test::Bencher) {
+    let cases = match load() {
+        Some(cases) => cases,
+        None => return,
+    };
+    b.bytes = cases.iter().map(|case| case.input.len() as u64).sum();
+    b.iter(|| {
+        for case in &cases {
+            test::black_box(case.input.parse::<f32>().ok());
+        }
+    });
+}
@@ -10,9 +10,14 @@
 
 #![allow(overflowing_literals)]
 
-use std::{i64, f32, f64};
+use std::{i64, f32, f64, fmt, iter, mem};
+use std::ops::{Div, Mul, Neg};
+use core::num::{self, Float, FpCategory, ParseFloatOptions, RawFloat, RoundingMode};
+use core::num::FloatErrorKind::*;
 use test;
+use num::prop::{check, Decimal};
 
+mod corpus;
 mod parse;
 mod rawfp;
 
@@ -87,6 +92,23 @@ fn zero() {
     test_literal!(1e-500);
 }
 
+#[test]
+fn halfway_cases() {
+    // Exact ties between two floats, which round to even.
+    assert_eq!("9007199254740993".parse(), Ok(9007199254740992.0f64));
+    assert_eq!("9007199254740995".parse(), Ok(9007199254740996.0f64));
+    assert_eq!("16777217".parse(), Ok(16777216.0f32));
+    assert_eq!("16777219".parse(), Ok(16777220.0f32));
+    // More digits than fit into 64 bits, just below and just above a tie.
+    assert_eq!("9007199254740992999999999999999999999".parse(), Ok(9007199254740992.0f64));
+    assert_eq!("9007199254740993000000000000000000001".parse(), Ok(9007199254740994.0f64));
+    assert_eq!("2.4703282292062328e-324".parse(), Ok(5e-324f64));
+    assert_eq!("2.4703282292062327e-324".parse(), Ok(0.0f64));
+    assert_eq!("7.0064923216240854e-46".parse(), Ok(1e-45f32));
+    assert_eq!("1.7976931348623158e308".parse(), Ok(f64::MAX));
+    assert_eq!("1.7976931348623159e308".parse(), Ok(f64::INFINITY));
+}
+
 #[test]
 fn fast_path_correct() {
     // This number triggers the fast path and is handled incorrectly when compiling on
@@ -118,6 +140,51 @@ fn nan() {
     assert!("NaN".parse::<f64>().unwrap().is_nan());
 }
 
+#[test]
+fn nan_payload() {
+    let payload64 = |s: &str| s.parse::<f64>().ok().and_then(Float::payload);
+    let payload32 = |s: &str| s.parse::<f32>().ok().and_then(Float::payload);
+    assert_eq!(payload64("NaN"), Some(0));
+    assert_eq!(payload64("NaN(0x1234)"), Some(0x1234));
+    assert_eq!(payload64("nan(0X1234)"), Some(0x1234));
+    assert_eq!(payload64("NaN(4660)"), Some(0x1234));
+    assert_eq!(payload64("+nan(0xabcDEF)"), Some(0xabcdef));
+    assert_eq!(payload64("NaN(0x7ffffffffffff)"), Some(0x7ffffffffffff));
+    assert_eq!(payload32("NaN(0x3fffff)"), Some(0x3fffff));
+    assert_eq!("-NaN".parse::<f64>().unwrap().integer_decode().2, -1);
+    assert_eq!("-NaN(0x5)".parse::<f32>().unwrap().integer_decode().2, -1);
+    assert_eq!("NaN(0x5)".parse::<f32>().unwrap().integer_decode().2, 1);
+
+    // Payloads that do not fit below the quiet bit, and other syntax errors.
+    for s in &["NaN(0x8000000000000)", "NaN(0x1ffffffffffffffff)", "nan", "NaN()", "NaN(0x)",
+               "NaN(12", "NaN(1a)", "NaN(-1)", "NaN(0x1))", "NaN (1)", "NAN(1)"] {
+        assert!(s.parse::<f64>().is_err(), "{:?} is a NaN", s);
+    }
+    assert!("NaN(0x400000)".parse::<f32>().is_err());
+    assert_eq!("NaN(0x400000)".parse::<f64>().ok().and_then(Float::payload), Some(0x400000));
+
+    let exact = <f64 as Float>::from_str_exact("NaN(0x1)").unwrap();
+    assert_eq!(exact.payload(), Some(1));
+    let (nan, len) = <f64 as Float>::from_str_partial("nan(0x1f)1").unwrap();
+    assert_eq!((nan.payload(), len), (Some(0x1f), 9));
+    let (nan, len) = <f64 as Float>::from_str_partial("NaN(0x1f").unwrap();
+    assert_eq!((nan.payload(), len), (Some(0), 3));
+
+    for &payload in &[0, 1, 0x1f, 0x400000, 0x7ffffffffffff] {
+        let x = <f64 as Float>::nan_with_payload(payload);
+        for &x in &[x, -x] {
+            let y = format!("{:#?}", x).parse::<f64>().unwrap();
+            assert_eq!(x.integer_decode(), y.integer_decode());
+        }
+    }
+}
+
+#[test]
+#[should_panic]
+fn nan_payload_too_large() {
+    <f32 as Float>::nan_with_payload(0x400000);
+}
+
 #[test]
 fn inf() {
     assert_eq!("inf".parse(), Ok(f64::INFINITY));
@@ -140,9 +207,634 @@ fn borderline_overflow() {
     for _ in 0..375 {
         s.push('3');
     }
-    // At the time of this writing, this returns Err(..), but this is a bug that should be fixed.
-    // It makes no sense to enshrine that in a test, the important part is that it doesn't panic.
-    let _ = s.parse::<f64>();
+    assert_eq!(s.parse::<f64>(), Ok(1.0 / 3.0));
+}
+
+#[test]
+fn long_decimals() {
+    let zeros = |n| iter::repeat('0').take(n).collect::<String>();
+    let threes: String = iter::repeat('3').take(10000).collect();
+    assert_eq!(format!("0.{}", threes).parse::<f64>(), Ok(1.0 / 3.0));
+    assert_eq!(format!("0.{}", threes).parse::<f32>(), Ok(1.0 / 3.0));
+    assert_eq!(format!("{}e-10000", threes).parse::<f64>(), Ok(1.0 / 3.0));
+    assert_eq!(format!("0.{}{}e1000", zeros(1000), threes).parse::<f64>(), Ok(1.0 / 3.0));
+    assert_eq!(format!("1{}", zeros(5000)).parse::<f64>(), Ok(f64::INFINITY));
+
+    // Halfway between one and the next float, where digits far beyond the ones that can make a
+    // difference still break the tie.
+    let half64 = "1.00000000000000011102230246251565404236316680908203125";
+    let half32 = "1.000000059604644775390625";
+    assert_eq!(format!("{}{}", half64, zeros(2000)).parse::<f64>(), Ok(1.0));
+    assert_eq!(format!("{}{}1", half64, zeros(2000)).parse::<f64>(), Ok(1.0 + f64::EPSILON));
+    assert_eq!(format!("{}{}1", half32, zeros(2000)).parse::<f32>(), Ok(1.0 + f32::EPSILON));
+    let half64_int = format!("{}{}1", half64.replace(".", ""), zeros(2000));
+    assert_eq!(format!("{}e-2054", half64_int).parse::<f64>(), Ok(1.0 + f64::EPSILON));
+
+    // The full expansion of the smallest subnormal has 751 significant digits.
+    let tiny = format!("{:.1074}", 5e-324);
+    assert_eq!(tiny.parse::<f64>(), Ok(5e-324));
+    assert_eq!(format!("{}{}1", tiny, zeros(100)).parse::<f64>(), Ok(5e-324));
+    assert_eq!(<f64 as Float>::from_str_exact(&format!("{}{}", tiny, zeros(100))), Ok(5e-324));
+    assert!(<f64 as Float>::from_str_exact(&format!("{}{}1", tiny, zeros(100))).is_err());
+    let rounded = <f64 as Float>::from_str_rounded(&format!("{}{}1", tiny, zeros(100)),
+                                                   RoundingMode::TowardPositive);
+    assert_eq!(rounded, Ok(1e-323));
+    let rounded = <f64 as Float>::from_str_rounded(&format!("0.{}", threes),
+                                                   RoundingMode::TowardPositive);
+    assert!(rounded.unwrap() > 1.0 / 3.0);
+
+    let default = ParseFloatOptions::new();
+    let with64 = |s: &str| <f64 as Float>::from_str_with(s, &default);
+    assert_eq!(with64(&format!("{}{}", half64, zeros(2000))), Ok(1.0));
+    assert_eq!(with64(&format!("{}{}1", half64, zeros(2000))), Ok(1.0 + f64::EPSILON));
+    assert_eq!(with64(&format!("{}e-2054", half64_int)), Ok(1.0 + f64::EPSILON));
+    assert_eq!(with64(&format!("0.{}", threes)), Ok(1.0 / 3.0));
+}
+
+#[test]
+fn exact() {
+    let exact64 = |s: &str| <f64 as Float>::from_str_exact(s);
+    let exact32 = |s: &str| <f32 as Float>::from_str_exact(s);
+    assert_eq!(exact64("0.5"), Ok(0.5));
+    assert_eq!(exact64("-2.5e-1"), Ok(-0.25));
+    assert_eq!(exact64("9007199254740992"), Ok(9007199254740992.0));
+    assert_eq!(exact64("1e22"), Ok(1e22));
+    assert_eq!(exact64("0e99999999999999999999"), Ok(0.0));
+    assert_eq!(exact32("16777216"), Ok(16777216.0));
+    assert_eq!(exact32("0.75"), Ok(0.75));
+    assert_eq!(exact64("inf"), Ok(f64::INFINITY));
+    assert!(exact32("NaN").unwrap().is_nan());
+
+    // The full decimal expansions of powers of two.
+    let tiny = format!("{:.60}", 1.0 / (1u64 << 60) as f64);
+    assert_eq!(exact64(&tiny), Ok(1.0 / (1u64 << 60) as f64));
+    assert_eq!(exact32(&tiny), Ok(1.0 / (1u64 << 60) as f32));
+    assert!(exact64(&format!("{}1", tiny)).is_err());
+}
+
+#[test]
+fn inexact() {
+    let exact64 = |s: &str| <f64 as Float>::from_str_exact(s);
+    let exact32 = |s: &str| <f32 as Float>::from_str_exact(s);
+    for s in &["0.1", "9007199254740993", "1e23", "1e400", "1e-400", "1e99999999999999999999"] {
+        assert!(exact64(s).is_err(), "{} is exact", s);
+        assert!(s.parse::<f64>().is_ok());
+    }
+    // 5^22 doesn't fit into 24 bits.
+    assert!(exact32("1e22").is_err());
+    assert!(exact32("16777217").is_err());
+    assert_eq!(exact64("0.1").unwrap_err().to_string(),
+               "float literal is not exactly representable");
+    assert_eq!(exact64("").unwrap_err(), "".parse::<f64>().unwrap_err());
+    assert_eq!(exact64("1x").unwrap_err(), "1x".parse::<f64>().unwrap_err());
+}
+
+#[test]
+fn hexadecimal() {
+    let parse64 = |s: &str| s.parse::<f64>();
+    let parse32 = |s: &str| s.parse::<f32>();
+    assert_eq!(parse64("0x1.8p-3"), Ok(0.1875));
+    assert_eq!(parse64("-0x1.8p-3"), Ok(-0.1875));
+    assert_eq!(parse64("0XFF.8"), Ok(255.5));
+    assert_eq!(parse64("0x.8"), Ok(0.5));
+    assert_eq!(parse64("+0x1P+4"), Ok(16.0));
+    assert_eq!(parse64("0x0.0000000000000000000000001p0"), Ok(1.0 / (1u64 << 50) as f64 /
+                                                              (1u64 << 50) as f64));
+    assert_eq!(parse64("0x1.fffffffffffffp1023"), Ok(f64::MAX));
+    assert_eq!(parse64("0x1p-1022"), Ok(f64::MIN_POSITIVE));
+    assert_eq!(parse64("-0x1p-1074"), Ok(-5e-324));
+    assert_eq!(parse64("0x1p1024"), Ok(f64::INFINITY));
+    assert_eq!(parse64("0x1p-99999999999999999999"), Ok(0.0));
+    assert_eq!(parse64("0x0p99999999999999999999"), Ok(0.0));
+    assert_eq!(parse32("0x1.fffffep127"), Ok(f32::MAX));
+    assert_eq!(parse32("0x1p128"), Ok(f32::INFINITY));
+    assert_eq!(parse32("0x1p-149"), Ok(2.0f32.powi(-149)));
+    assert!(parse64("0x").is_err());
+    assert!(parse64("0x1p").is_err());
+    assert!(parse64("0xinf").is_err());
+}
+
+#[test]
+fn hexadecimal_rounding() {
+    let parse64 = |s: &str| s.parse::<f64>().unwrap();
+    let parse32 = |s: &str| s.parse::<f32>().unwrap();
+    let ulp = f64::EPSILON;
+    // 1 + 2^-53 is a tie, which goes to even
+    assert_eq!(parse64("0x1.00000000000008"), 1.0);
+    assert_eq!(parse64("0x1.00000000000018"), 1.0 + 2.0 * ulp);
+    // digits beyond the first 16 significant ones still break the tie
+    assert_eq!(parse64("0x1.000000000000080000000000000"), 1.0);
+    assert_eq!(parse64("0x1.000000000000080000000000001"), 1.0 + ulp);
+    assert_eq!(parse64("0x0.0000001000000000000080000001p28"), 1.0 + ulp);
+    assert_eq!(parse64("0x1000000000000080000000000001p-108"), 1.0 + ulp);
+    // and so does rounding subnormals
+    assert_eq!(parse64("0x1p-1075"), 0.0);
+    assert_eq!(parse64("0x1.00000000000000000001p-1075"), 5e-324);
+    assert_eq!(parse64("0x1.8p-1075"), 5e-324);
+    assert_eq!(parse64("0x1.fffffffffffff8p1023"), f64::INFINITY);
+    assert_eq!(parse64("0x1.fffffffffffff7ffp1023"), f64::MAX);
+    assert_eq!(parse32("0x1.000001p0"), 1.0);
+    assert_eq!(parse32("0x1.000003p0"), 1.0 + 2.0 * f32::EPSILON);
+    assert_eq!(parse32("0x1.0000010000000000000001p0"), 1.0 + f32::EPSILON);
+}
+
+#[test]
+fn hexadecimal_exact() {
+    let exact64 = |s: &str| <f64 as Float>::from_str_exact(s);
+    let exact32 = |s: &str| <f32 as Float>::from_str_exact(s);
+    assert_eq!(exact64("0x1.8p-3"), Ok(0.1875));
+    assert_eq!(exact64("-0x1.fffffffffffffp1023"), Ok(-f64::MAX));
+    assert_eq!(exact64("0x1.00000000000000000000p0"), Ok(1.0));
+    assert_eq!(exact64("0x1p-1074"), Ok(5e-324));
+    assert_eq!(exact64("0x0p99999999999999999999"), Ok(0.0));
+    assert_eq!(exact32("0x1.fffffep127"), Ok(f32::MAX));
+    for s in &["0x1.00000000000008", "0x1.000000000000000000001", "0x1p-1075", "0x1.8p-1074",
+               "0x1p1024", "0x1p99999999999999999999"] {
+        assert!(exact64(s).is_err(), "{} is exact", s);
+    }
+    assert!(exact32("0x1.000001p0").is_err());
+    assert!(exact32("0x1p-150").is_err());
+}
+
+#[test]
+fn rounded() {
+    use core::num::RoundingMode::*;
+    let rounded64 = |s: &str, mode| <f64 as Float>::from_str_rounded(s, mode).unwrap();
+    let rounded32 = |s: &str, mode| <f32 as Float>::from_str_rounded(s, mode).unwrap();
+    let below_tenth = 0.09999999999999999;
+    assert_eq!(rounded64("0.1", TiesToEven), 0.1);
+    assert_eq!(rounded64("0.1", TiesToAway), 0.1);
+    assert_eq!(rounded64("0.1", TowardZero), below_tenth);
+    assert_eq!(rounded64("0.1", TowardPositive), 0.1);
+    assert_eq!(rounded64("0.1", TowardNegative), below_tenth);
+    assert_eq!(rounded64("-0.1", TowardPositive), -below_tenth);
+    assert_eq!(rounded64("-0.1", TowardNegative), -0.1);
+    assert_eq!(rounded64("0.3", TowardZero), 0.3);
+    assert_eq!(rounded64("0.3", TowardPositive), 0.30000000000000004);
+    assert_eq!(rounded32("0.1", TowardZero), 0.099999994);
+    assert_eq!(rounded32("0.1", TowardPositive), 0.1);
+
+    // The odd one of the two neighbours, whichever is nearer.
+    assert_eq!(rounded64("0.1", ToOdd), below_tenth);
+    assert_eq!(rounded64("0.3", ToOdd), 0.3);
+    assert_eq!(rounded64("9007199254740997", ToOdd), 9007199254740998.0);
+    assert_eq!(rounded64("9007199254740993", ToOdd), 9007199254740994.0);
+
+    // 2^53 + 1 is halfway between two floats.
+    assert_eq!(rounded64("9007199254740993", TiesToEven), 9007199254740992.0);
+    assert_eq!(rounded64("9007199254740993", TiesToAway), 9007199254740994.0);
+    assert_eq!(rounded64("-9007199254740993", TiesToAway), -9007199254740994.0);
+    assert_eq!(rounded64("9007199254740993", TowardZero), 9007199254740992.0);
+    assert_eq!(rounded64("9007199254740992.5", TiesToAway), 9007199254740992.0);
+    assert_eq!(rounded64("0x1.00000000000008p0", TiesToAway), 1.0 + f64::EPSILON);
+    assert_eq!(rounded64("0x1.00000000000008p0", TowardPositive), 1.0 + f64::EPSILON);
+    assert_eq!(rounded64("0x1.000000000000080000001p0", TowardZero), 1.0);
+
+    // Exact values don't change, whatever the mode.
+    for &mode in &[TiesToEven, TiesToAway, TowardZero, TowardPositive, TowardNegative, ToOdd] {
+        assert_eq!(rounded64("0.5", mode), 0.5);
+        assert_eq!(rounded64("-2.5e-1", mode), -0.25);
+        assert_eq!(rounded64("9007199254740992", mode), 9007199254740992.0);
+        assert_eq!(rounded64("0x1.fffffffffffffp1023", mode), f64::MAX);
+        assert_eq!(rounded64("0", mode), 0.0);
+        assert_eq!(rounded64("0e99999999999999999999", mode), 0.0);
+        assert_eq!(rounded64("0x0p-99999999999999999999", mode), 0.0);
+        assert_eq!(rounded64("inf", mode), f64::INFINITY);
+        assert!(rounded32("NaN", mode).is_nan());
+        assert!(rounded64("-0", mode).is_sign_negative());
+    }
+
+    // Overflow only goes to infinity when rounding away from zero or to the nearest.
+    assert_eq!(rounded64("1.8e308", TiesToEven), f64::INFINITY);
+    assert_eq!(rounded64("1.8e308", TowardZero), f64::MAX);
+    assert_eq!(rounded64("1e400", TowardZero), f64::MAX);
+    assert_eq!(rounded64("1e400", TowardPositive), f64::INFINITY);
+    assert_eq!(rounded64("1e400", ToOdd), f64::MAX);
+    assert_eq!(rounded64("-1e400", TowardPositive), -f64::MAX);
+    assert_eq!(rounded64("1e99999999999999999999", TowardNegative), f64::MAX);
+    assert_eq!(rounded64("0x1p1024", TowardZero), f64::MAX);
+
+    // And underflow only goes to zero when rounding toward it or to the nearest.
+    assert_eq!(rounded64("2e-324", TiesToEven), 0.0);
+    assert_eq!(rounded64("2e-324", TowardPositive), 5e-324);
+    assert_eq!(rounded64("1e-400", TowardPositive), 5e-324);
+    assert_eq!(rounded64("1e-400", TowardZero), 0.0);
+    assert_eq!(rounded64("1e-400", ToOdd), 5e-324);
+    assert_eq!(rounded64("-1e-400", TowardNegative), -5e-324);
+    assert_eq!(rounded64("1e-99999999999999999999", TowardPositive), 5e-324);
+    assert_eq!(rounded64("0x1p-1075", TiesToAway), 5e-324);
+    assert_eq!(rounded64("0x1p-99999999999999999999", TowardPositive), 5e-324);
+    assert_eq!(rounded32("1e-50", TowardPositive), 2.0f32.powi(-149));
+
+    assert_eq!(<f64 as Float>::from_str_rounded("1x", TowardZero).unwrap_err(),
+               "1x".parse::<f64>().unwrap_err());
+    assert!(<f64 as Float>::from_str_rounded("", TowardZero).is_err());
+}
+
+#[test]
+fn from_str_radix() {
+    let radix64 = |s: &str, radix| <f64 as Float>::from_str_radix(s, radix);
+    let radix32 = |s: &str, radix| <f32 as Float>::from_str_radix(s, radix);
+    let ones = |n| iter::repeat('1').take(n).collect::<String>();
+    let zeros = |n| iter::repeat('0').take(n).collect::<String>();
+    assert_eq!(radix64("101.101", 2), Ok(5.625));
+    assert_eq!(radix64("-fF.8", 16), Ok(-255.5));
+    assert_eq!(radix64("+0.1", 3), Ok(1.0 / 3.0));
+    assert_eq!(radix32("0.1", 3), Ok(1.0 / 3.0));
+    assert_eq!(radix64("z.z", 36), Ok(1295.0 / 36.0));
+    assert_eq!(radix64("123.456", 10), Ok(123.456));
+    assert_eq!(radix64("1e5", 16), Ok(485.0));
+    assert_eq!(radix64(".8", 16), Ok(0.5));
+    assert_eq!(radix64("7.", 8), Ok(7.0));
+    assert_eq!(radix64("-000.000", 5), Ok(-0.0));
+    assert_eq!(radix64(&format!("0.{}", ones(200)), 3), Ok(0.5));
+
+    // 2^53 + 1 is halfway between two floats, and 1 + 2^-53 has no finite expansion in radix 3.
+    let halfway = format!("1{}1", zeros(52));
+    assert_eq!(radix64(&halfway, 2), Ok(9007199254740992.0));
+    assert_eq!(radix64(&format!("{}.{}1", halfway, zeros(19)), 2), Ok(9007199254740994.0));
+    let below = concat!("1.000000000000000000000000000000000",
+                        "12112222022112101112020000010211200221202010122");
+    let above = concat!("1.000000000000000000000000000000000",
+                        "12112222022112101112020000010211200221202010200");
+    assert_eq!(radix64(below, 3), Ok(1.0));
+    assert_eq!(radix64(above, 3), Ok(1.0 + f64::EPSILON));
+
+    assert_eq!(radix64(&format!("{}{}", ones(53), zeros(971)), 2), Ok(f64::MAX));
+    assert_eq!(radix64(&ones(1024), 2), Ok(f64::INFINITY));
+    assert_eq!(radix64(&format!("-1{}", zeros(5000)), 36), Ok(f64::NEG_INFINITY));
+    assert_eq!(radix64(&format!("0.{}1", zeros(1073)), 2), Ok(5e-324));
+    assert_eq!(radix64(&format!("0.{}1", zeros(1074)), 2), Ok(0.0));
+    assert_eq!(radix64(&format!("0.{}1{}1", zeros(1074), zeros(50)), 2), Ok(5e-324));
+    assert_eq!(radix64(&format!("0.{}1", zeros(5000)), 36), Ok(0.0));
+
+    let error = |s: &str, radix| {
+        let err = radix64(s, radix).unwrap_err();
+        (err.kind(), err.offset())
+    };
+    assert_eq!(radix64("", 10).unwrap_err().kind(), Empty);
+    assert_eq!(error("-", 2), (MissingDigits, Some(1)));
+    assert_eq!(error(".", 16), (MissingDigits, Some(1)));
+    assert_eq!(error("12", 2), (InvalidDigit, Some(1)));
+    assert_eq!(error("-1.0.1", 10), (InvalidDigit, Some(4)));
+    assert_eq!(error("1e5", 10), (InvalidDigit, Some(1)));
+    assert_eq!(error("inf", 10), (InvalidDigit, Some(0)));
+    assert_eq!(error(" 1", 10), (InvalidDigit, Some(0)));
+}
+
+#[test]
+#[should_panic]
+fn from_str_radix_37() {
+    let _ = <f64 as Float>::from_str_radix("1", 37);
+}
+
+#[test]
+fn partial() {
+    let partial64 = |s: &str| <f64 as Float>::from_str_partial(s);
+    let partial32 = |s: &str| <f32 as Float>::from_str_partial(s);
+    assert_eq!(partial64("1.5"), Ok((1.5, 3)));
+    assert_eq!(partial64("1.5, 2"), Ok((1.5, 3)));
+    assert_eq!(partial64("-.25]"), Ok((-0.25, 4)));
+    assert_eq!(partial64("5."), Ok((5.0, 2)));
+    assert_eq!(partial64("5..1"), Ok((5.0, 2)));
+    assert_eq!(partial64("+1e3x"), Ok((1000.0, 4)));
+    assert_eq!(partial64("1E-3 "), Ok((0.001, 4)));
+    assert_eq!(partial32("0.1f"), Ok((0.1, 3)));
+    // An exponent without digits is not part of the number.
+    assert_eq!(partial64("2e"), Ok((2.0, 1)));
+    assert_eq!(partial64("2e+"), Ok((2.0, 1)));
+    assert_eq!(partial64("2.e-x"), Ok((2.0, 2)));
+    assert_eq!(partial64("2em"), Ok((2.0, 1)));
+    assert_eq!(partial64("1e400;"), Ok((f64::INFINITY, 5)));
+    assert_eq!(partial64("0e5."), Ok((0.0, 3)));
+    // Hexadecimal, where a lonely `0x` is just a zero.
+    assert_eq!(partial64("0x1.8p1)"), Ok((3.0, 7)));
+    assert_eq!(partial64("0x1.8pq"), Ok((1.5, 5)));
+    assert_eq!(partial64("-0XaG"), Ok((-10.0, 4)));
+    assert_eq!(partial64("0x.g"), Ok((0.0, 1)));
+    assert_eq!(partial64("0xg"), Ok((0.0, 1)));
+    assert_eq!(partial64("0x"), Ok((0.0, 1)));
+    // Infinity and NaN.
+    assert_eq!(partial64("inf"), Ok((f64::INFINITY, 3)));
+    assert_eq!(partial64("-infinity"), Ok((f64::NEG_INFINITY, 4)));
+    let (nan, len) = partial32("NaN1").unwrap();
+    assert!(nan.is_nan());
+    assert_eq!(len, 3);
+    // Multi-byte characters after the number.
+    assert_eq!(partial64("12€"), Ok((12.0, 2)));
+
+    for s in &["", ".", "-", "+.", "e5", ".e5", "x1", " 1", "+-1", "in", "nan", "€1"] {
+        assert!(partial64(s).is_err(), "{:?} has a prefix", s);
+    }
+    assert_eq!(partial64("").unwrap_err(), "".parse::<f64>().unwrap_err());
+    assert_eq!(partial64("x").unwrap_err(), "x".parse::<f64>().unwrap_err());
+}
+
+#[test]
+fn from_ascii() {
+    let ascii64 = |s: &[u8]| <f64 as Float>::from_ascii(s);
+    let ascii32 = |s: &[u8]| <f32 as Float>::from_ascii(s);
+    for s in &["0", "-1.5", "+.25e-3", "1e400", "0x1.8p-3", "inf", "-inf", "3.4028236e38",
+               "2.2250738585072011e-308"] {
+        assert_eq!(ascii64(s.as_bytes()), s.parse::<f64>());
+        assert_eq!(ascii32(s.as_bytes()), s.parse::<f32>());
+    }
+    assert!(ascii64(b"NaN").unwrap().is_nan());
+    for s in &["", ".", "1x", " 1", "1e", "--1", "in"] {
+        assert_eq!(ascii64(s.as_bytes()).unwrap_err(), s.parse::<f64>().unwrap_err());
+    }
+    // Bytes that aren't UTF-8 are just invalid characters.
+    assert!(ascii64(b"1\xff").is_err());
+    assert!(ascii64(b"\xc3\xa91").is_err());
+    assert!(ascii64(b"1.5\x80e3").is_err());
+}
+
+#[test]
+fn from_str_with() {
+    let with64 = |s: &str, o: &ParseFloatOptions| <f64 as Float>::from_str_with(s, o);
+    let with32 = |s: &str, o: &ParseFloatOptions| <f32 as Float>::from_str_with(s, o);
+    let default = ParseFloatOptions::new();
+    for s in &["0", "-1.5", "+.25e-3", "1.", "1e400", "1e-400", "inf", "-inf", "3.4028236e38",
+               "2.2250738585072011e-308", "0.1", "123456789012345678901234567890"] {
+        assert_eq!(with64(s, &default), s.parse::<f64>());
+        assert_eq!(with32(s, &default), s.parse::<f32>());
+    }
+    assert!(with64("NaN", &default).unwrap().is_nan());
+    for s in &["", ".", "1x", " 1", "1e", "--1", "in", "1,000"] {
+        assert_eq!(with64(s, &default).unwrap_err(), s.parse::<f64>().unwrap_err());
+    }
+    assert!(with64("0x1p3", &default).is_err());
+
+    let mut german = ParseFloatOptions::new();
+    german.decimal_point(',').grouping(&['.', '\'']);
+    assert_eq!(with64("1.234,5", &german), Ok(1234.5));
+    assert_eq!(with64("-1'234'567,25e1", &german), Ok(-12345672.5));
+    assert_eq!(with64("12.34.5", &german), Ok(12345.0));
+    assert_eq!(with32(",5", &german), Ok(0.5));
+    for s in &["1,234.5", ".1", "1.", "1..2", "1.,5", "1,5.0", "1.e3"] {
+        assert!(with64(s, &german).is_err(), "{:?}", s);
+    }
+    // A grouping character that is also the decimal point is just the decimal point.
+    let mut same = ParseFloatOptions::new();
+    same.grouping(&['.', ' ']);
+    assert_eq!(with64("1 000.5", &same), Ok(1000.5));
+    assert!(with64("1.000.5", &same).is_err());
+
+    let mut names = ParseFloatOptions::new();
+    names.infinity(&["∞", "Infinity"]).nan(&["nan", "NaN"]);
+    assert_eq!(with64("-∞", &names), Ok(f64::NEG_INFINITY));
+    assert_eq!(with32("+Infinity", &names), Ok(f32::INFINITY));
+    assert!(with64("-nan", &names).unwrap().is_nan());
+    assert!(with64("inf", &names).is_err());
+    assert!(with64("INFINITY", &names).is_err());
+
+    let mut lenient = ParseFloatOptions::new();
+    lenient.lenient_specials(true);
+    for s in &["inf", "+inf", "INF", "Inf", "infinity", "Infinity", "+INFINITY", "iNfInItY"] {
+        assert_eq!(with64(s, &lenient), Ok(f64::INFINITY), "{:?}", s);
+        assert_eq!(with32(&format!("-{}", s.trim_left_matches('+')), &lenient),
+                   Ok(f32::NEG_INFINITY));
+    }
+    for s in &["nan", "NaN", "NAN", "-nan", "+nAn"] {
+        assert!(with64(s, &lenient).unwrap().is_nan(), "{:?}", s);
+    }
+    for s in &["infinit", "infinityy", "in", "nana", "+-inf", "inf ", "ınf", "∞"] {
+        assert!(with64(s, &lenient).is_err(), "{:?}", s);
+    }
+    assert_eq!(with64("+1.5", &lenient), Ok(1.5));
+    // The lenient spellings come on top of the configured ones.
+    names.lenient_specials(true);
+    assert_eq!(with64("-∞", &names), Ok(f64::NEG_INFINITY));
+    assert_eq!(with64("INF", &names), Ok(f64::INFINITY));
+
+    // Digits beyond what is kept still count.
+    let zeros: String = iter::repeat('0').take(500).collect();
+    let ones: String = iter::repeat('1').take(500).collect();
+    assert_eq!(with64(&format!("1{}e-500", zeros), &default), Ok(1.0));
+    assert_eq!(with64(&format!("0.{}1e501", zeros), &default), Ok(1.0));
+    assert_eq!(with64(&format!("{}.5", ones), &default), Ok(f64::INFINITY));
+    assert_eq!(with64("1e99999999999999999999", &default), Ok(f64::INFINITY));
+    assert_eq!(with64("1e-99999999999999999999", &default), Ok(0.0));
+    assert_eq!(with64("0e99999999999999999999", &default), Ok(0.0));
+}
+
+#[test]
+fn underscores() {
+    let mut options = ParseFloatOptions::new();
+    options.underscores(true);
+    let lenient = |s: &str| <f64 as Float>::from_str_with(s, &options);
+    assert_eq!(lenient("1_000_000.5"), Ok(1000000.5));
+    assert_eq!(lenient("-1_0.2_5e1_0"), Ok(-10.25e10));
+    assert_eq!(lenient("0.000_1E-1_0"), Ok(0.0001e-10));
+    assert_eq!(lenient("1e+1_0"), Ok(1e10));
+    assert_eq!(lenient("1.5"), Ok(1.5));
+    for s in &["_1", "1_", "1__0", "1_.5", "1._5", "1.5_", "1_e5", "1e_5", "1e5_", "1e-_5",
+               "-_1", "_", "1_5_e1"] {
+        assert!(lenient(s).is_err(), "{:?}", s);
+    }
+    assert!(<f64 as Float>::from_str_with("1_000", &ParseFloatOptions::new()).is_err());
+    assert!("1_000.5".parse::<f64>().is_err());
+
+    // underscores and grouping together, with a decimal comma
+    let mut both = ParseFloatOptions::new();
+    both.decimal_point(',').grouping(&['.']).underscores(true);
+    assert_eq!(<f32 as Float>::from_str_with("1.000_000,2_5", &both), Ok(1000000.25));
+    assert!(<f32 as Float>::from_str_with("1._000", &both).is_err());
+}
+
+#[test]
+fn error_kind_and_offset() {
+    let err = |s: &str| {
+        let e = s.parse::<f64>().unwrap_err();
+        (e.kind(), e.offset())
+    };
+    assert_eq!(err(""), (Empty, None));
+    assert_eq!(err("+"), (MissingDigits, Some(1)));
+    assert_eq!(err("-e5"), (MissingDigits, Some(1)));
+    assert_eq!(err("1x"), (InvalidDigit, Some(1)));
+    assert_eq!(err("-12.5.1"), (InvalidDigit, Some(5)));
+    assert_eq!(err(" 1"), (InvalidDigit, Some(0)));
+    assert_eq!(err("1.5é"), (InvalidDigit, Some(3)));
+    assert_eq!(err("+1.5e"), (ExponentMissingDigits, Some(5)));
+    assert_eq!(err("1e+x"), (ExponentMissingDigits, Some(3)));
+    assert_eq!(err("1e5 "), (InvalidDigit, Some(3)));
+    assert_eq!(err("-0x1.8p"), (ExponentMissingDigits, Some(7)));
+    assert_eq!(err("0xg"), (MissingDigits, Some(2)));
+    assert_eq!(err("-infinity"), (InvalidDigit, Some(1)));
+    // The messages are the same as before there were kinds.
+    for s in &["--1", "+", "1e", "0x"] {
+        assert_eq!(s.parse::<f32>().unwrap_err().to_string(), "invalid float literal");
+    }
+    assert_eq!("".parse::<f32>().unwrap_err().to_string(),
+               "cannot parse float from empty string");
+
+    let exact = <f64 as Float>::from_str_exact("0.1").unwrap_err();
+    assert_eq!((exact.kind(), exact.offset()), (Inexact, None));
+    assert_eq!(<f64 as Float>::from_str_exact("1.0e").unwrap_err(),
+               "1.0e".parse::<f64>().unwrap_err());
+
+    // The partial parser fails where the string doesn't start with a number.
+    for s in &["", "+", "x", "-.x", "e5"] {
+        assert_eq!(<f64 as Float>::from_str_partial(s).unwrap_err(),
+                   s.parse::<f64>().unwrap_err());
+    }
+
+    // And with options, offsets are in bytes of the original string too.
+    let mut options = ParseFloatOptions::new();
+    options.decimal_point(',').grouping(&['\u{a0}']).infinity(&["∞"]);
+    let err_with = |s: &str| {
+        let e = <f64 as Float>::from_str_with(s, &options).unwrap_err();
+        (e.kind(), e.offset())
+    };
+    assert_eq!(err_with("-1\u{a0}000,5x"), (InvalidDigit, Some(9)));
+    assert_eq!(err_with("1\u{a0}\u{a0}0"), (InvalidDigit, Some(1)));
+    assert_eq!(err_with("1.5"), (InvalidDigit, Some(1)));
+    assert_eq!(err_with("-,e1"), (MissingDigits, Some(2)));
+    assert_eq!(err_with("∞∞"), (InvalidDigit, Some(0)));
+    assert_eq!(err_with("1,5e-"), (ExponentMissingDigits, Some(5)));
+    for s in &["", "+", ".", "1x", " 1", "1e", "1e+x", "1e5 ", "--1", "in", "e5"] {
+        assert_eq!(<f64 as Float>::from_str_with(s, &ParseFloatOptions::new()).unwrap_err(),
+                   s.parse::<f64>().unwrap_err());
+    }
+}
+
+#[test]
+fn parse_float_generic() {
+    use core::num::{self, f16};
+    for s in &["0", "-1.5", "+.25e-3", "1e400", "1e-400", "inf", "0x1.8p-3", "0.1",
+               "2.2250738585072011e-308", "123456789012345678901234567890", "", "1x", "1e"] {
+        assert_eq!(num::parse_float::<f64>(s), s.parse::<f64>());
+        assert_eq!(num::parse_float::<f32>(s), s.parse::<f32>());
+        assert_eq!(num::parse_float::<f16>(s), s.parse::<f16>());
+        assert_eq!(num::parse_float_ascii::<f64>(s.as_bytes()), s.parse::<f64>());
+        assert_eq!(num::parse_float_exact::<f32>(s), <f32 as Float>::from_str_exact(s));
+        assert_eq!(num::parse_float_partial::<f64>(s), <f64 as Float>::from_str_partial(s));
+        assert_eq!(num::parse_float_with::<f16>(s, &ParseFloatOptions::new()),
+                   <f16 as Float>::from_str_with(s, &ParseFloatOptions::new()));
+    }
+    assert!(num::parse_float::<f16>("NaN").unwrap().is_nan());
+}
+
+// A `bfloat16` of this crate rather than of `core`, which checks that `RawFloat` only takes the
+// encoding and arithmetic of a type to implement.
+#[derive(Copy, Clone, Debug, PartialEq)]
+struct Bf16(u16);
+
+impl Bf16 {
+    fn to_f32(self) -> f32 {
+        unsafe { mem::transmute((self.0 as u32) << 16) }
+    }
+
+    // Rounds to the nearest and ties to even. Products of two `Bf16`s are exact in `f32`, and
+    // quotients have enough bits there to be rounded again.
+    fn from_f32(x: f32) -> Bf16 {
+        let bits: u32 = unsafe { mem::transmute(x) };
+        if x.is_nan() {
+            return Bf16((bits >> 16) as u16 | 0x40);
+        }
+        Bf16(((bits + 0x7fff + (bits >> 16 & 1)) >> 16) as u16)
+    }
+}
+
+impl Mul for Bf16 {
+    type Output = Bf16;
+    fn mul(self, other: Bf16) -> Bf16 {
+        Bf16::from_f32(self.to_f32() * other.to_f32())
+    }
+}
+
+impl Div for Bf16 {
+    type Output = Bf16;
+    fn div(self, other: Bf16) -> Bf16 {
+        Bf16::from_f32(self.to_f32() / other.to_f32())
+    }
+}
+
+impl Neg for Bf16 {
+    type Output = Bf16;
+    fn neg(self) -> Bf16 {
+        Bf16(self.0 ^ 0x8000)
+    }
+}
+
+impl fmt::LowerExp for Bf16 {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::LowerExp::fmt(&self.to_f32(), f)
+    }
+}
+
+// The exponent range is that of `f32`, and so are the constants that only depend on it.
+impl RawFloat for Bf16 {
+    fn to_bits2(self) -> u64 { self.0 as u64 }
+    fn from_bits2(bits: u64) -> Bf16 {
+        assert!(bits <= 0xffff);
+        Bf16(bits as u16)
+    }
+    fn from_int(x: u64) -> Bf16 {
+        assert!(x <= Self::max_sig());
+        Bf16::from_f32(x as f32)
+    }
+    fn short_fast_pow10(e: usize) -> Bf16 { Bf16::from_f32([1.0, 10.0, 100.0, 1000.0][e]) }
+    fn ceil_log5_of_max_sig() -> i16 { 4 }
+    fn max_normal_digits() -> usize { 35 }
+    fn inf_cutoff() -> i64 { 40 }
+    fn zero_cutoff() -> i64 { -48 }
+    fn smallest_power_of_ten() -> i16 { -65 }
+    fn largest_power_of_ten() -> i16 { 38 }
+    fn min_exponent_round_to_even() -> i16 { -24 }
+    fn max_exponent_round_to_even() -> i16 { 3 }
+    fn exp_bits() -> u8 { 8 }
+    fn sig_bits() -> u8 { 8 }
+}
+
+#[test]
+fn parse_float_external() {
+    let parse = |s: &str| num::parse_float::<Bf16>(s).map(|x| x.0);
+    assert_eq!(parse("1"), Ok(0x3f80));
+    assert_eq!(parse("-2.5"), Ok(0xc020));
+    assert_eq!(parse("0.1"), Ok(0x3dcd));
+    assert_eq!(parse("3.140625"), Ok(0x4049));
+    // halfway between two floats, which rounds to even, and just above
+    assert_eq!(parse("1.00390625"), Ok(0x3f80));
+    assert_eq!(parse("1.01171875"), Ok(0x3f82));
+    assert_eq!(parse("1.00390625000000000000000001"), Ok(0x3f81));
+    assert_eq!(parse("338953138925153547590470800371487866880"), Ok(0x7f7f));
+    assert_eq!(parse("0x1.fep127"), Ok(0x7f7f));
+    assert_eq!(parse("1e39"), Ok(0x7f80));
+    assert_eq!(parse("9.2e-41"), Ok(0x0001));
+    assert_eq!(parse("1e-50"), Ok(0));
+    assert!(parse("1e").is_err());
+    assert_eq!(num::parse_float::<Bf16>("NaN").unwrap().classify2(), FpCategory::Nan);
+}
+
+#[test]
+fn prop_round_trip() {
+    // Whatever a decimal parses to, printing that value and parsing it again
+    // must not change it.
+    check("dec2flt_round_trip", 10_000, |d: &Decimal| {
+        let x: f64 = d.to_string().parse().unwrap();
+        let y: f32 = d.to_string().parse().unwrap();
+        (!x.is_finite() || (format!("{:?}", x).parse() == Ok(x) &&
+                            format!("{:e}", x).parse() == Ok(x))) &&
+        (!y.is_finite() || (format!("{:?}", y).parse() == Ok(y) &&
+                            format!("{:e}", y).parse() == Ok(y)))
+    });
+}
+
+#[test]
+fn prop_monotonic() {
+    // A larger decimal never parses to a smaller float, both for a neighbour
+    // with the same number of digits and for one with an extra digit.
+    check("dec2flt_monotonic", 10_000, |d: &Decimal| {
+        let s = d.to_string();
+        let next = Decimal { mantissa: d.mantissa + 1, exp: d.exp }.to_string();
+        let longer = Decimal { mantissa: d.mantissa * 10 + 1, exp: d.exp - 1 }.to_string();
+        let (x, y, z): (f64, f64, f64) = (s.parse().unwrap(), next.parse().unwrap(),
+                                          longer.parse().unwrap());
+        let (a, b, c): (f32, f32, f32) = (s.parse().unwrap(), next.parse().unwrap(),
+                                          longer.parse().unwrap());
+        x <= z && z <= y && a <= c && c <= b
+    });
 }
 
 #[bench]
@@ -9,14 +9,26 @@
 // except according to those terms.
 
 use std::iter;
-use core::num::dec2flt::parse::{Decimal, parse_decimal};
-use core::num::dec2flt::parse::ParseResult::{Valid, Invalid};
+use core::num::dec2flt::parse::{Decimal, Hexadecimal, parse_decimal, HEX_EXP_LIMIT};
+use core::num::dec2flt::parse::ParseResult::{Valid, ValidHex, Invalid};
+use core::num::FloatErrorKind::{InvalidDigit, MissingDigits, ExponentMissingDigits};
+
+fn is_invalid(s: &str) -> bool {
+    match parse_decimal(s.as_bytes()) {
+        Invalid(..) => true,
+        _ => false,
+    }
+}
 
 #[test]
 fn missing_pieces() {
-    let permutations = &[".e", "1e", "e4", "e", ".12e", "321.e", "32.12e+", "12.32e-"];
-    for &s in permutations {
-        assert_eq!(parse_decimal(s), Invalid);
+    let permutations = &[(".e", ExponentMissingDigits, 2), ("1e", ExponentMissingDigits, 2),
+                         ("e4", MissingDigits, 0), ("e", MissingDigits, 0), ("", MissingDigits, 0),
+                         (".", MissingDigits, 1), (".12e", ExponentMissingDigits, 4),
+                         ("321.e", ExponentMissingDigits, 5), ("32.12e+", ExponentMissingDigits, 7),
+                         ("12.32e-", ExponentMissingDigits, 7), ("1ex", ExponentMissingDigits, 2)];
+    for &(s, kind, offset) in permutations {
+        assert_eq!(parse_decimal(s.as_bytes()), Invalid(kind, offset));
     }
 }
 
@@ -30,7 +42,12 @@ fn invalid_chars() {
                 let mut input = String::new();
                 input.push_str(s);
                 input.insert(i, c);
-                assert!(parse_decimal(&input) == Invalid, "did not reject invalid {:?}", input);
+                // The error is always right at the inserted character, even if an exponent
+                // without digits is the reason.
+                match parse_decimal(input.as_bytes()) {
+                    Invalid(_, offset) => assert_eq!(offset, i, "wrong offset for {:?}", input),
+                    r => panic!("did not reject invalid {:?}: {:?}", input, r),
+                }
             }
         }
     }
@@ -38,15 +55,45 @@ fn invalid_chars() {
 
 #[test]
 fn valid() {
-    assert_eq!(parse_decimal("123.456e789"), Valid(Decimal::new(b"123", b"456", 789)));
-    assert_eq!(parse_decimal("123.456e+789"), Valid(Decimal::new(b"123", b"456", 789)));
-    assert_eq!(parse_decimal("123.456e-789"), Valid(Decimal::new(b"123", b"456", -789)));
-    assert_eq!(parse_decimal(".050"), Valid(Decimal::new(b"", b"050", 0)));
-    assert_eq!(parse_decimal("999"), Valid(Decimal::new(b"999", b"", 0)));
-    assert_eq!(parse_decimal("1.e300"), Valid(Decimal::new(b"1", b"", 300)));
-    assert_eq!(parse_decimal(".1e300"), Valid(Decimal::new(b"", b"1", 300)));
-    assert_eq!(parse_decimal("101e-33"), Valid(Decimal::new(b"101", b"", -33)));
+    assert_eq!(parse_decimal(b"123.456e789"), Valid(Decimal::new(b"123", b"456", 789)));
+    assert_eq!(parse_decimal(b"123.456e+789"), Valid(Decimal::new(b"123", b"456", 789)));
+    assert_eq!(parse_decimal(b"123.456e-789"), Valid(Decimal::new(b"123", b"456", -789)));
+    assert_eq!(parse_decimal(b".050"), Valid(Decimal::new(b"", b"050", 0)));
+    assert_eq!(parse_decimal(b"999"), Valid(Decimal::new(b"999", b"", 0)));
+    assert_eq!(parse_decimal(b"1.e300"), Valid(Decimal::new(b"1", b"", 300)));
+    assert_eq!(parse_decimal(b".1e300"), Valid(Decimal::new(b"", b"1", 300)));
+    assert_eq!(parse_decimal(b"101e-33"), Valid(Decimal::new(b"101", b"", -33)));
     let zeros: String = iter::repeat('0').take(25).collect();
     let s = format!("1.5e{}", zeros);
-    assert_eq!(parse_decimal(&s), Valid(Decimal::new(b"1", b"5", 0)));
+    assert_eq!(parse_decimal(s.as_bytes()), Valid(Decimal::new(b"1", b"5", 0)));
+}
+
+#[test]
+fn invalid_hex() {
+    let invalid = &["0x", "0X", "0x.", "0xp1", "0x.p1", "0x1p", "0x1p+", "0x1p-", "0x1pg", "0xg",
+                    "0x1p1.5", "0x1p0x1", "0x-1", "0x+1", "0x1.8.", "0x 1", "1x1", "00x1", "x1"];
+    for &s in invalid {
+        assert!(is_invalid(s), "did not reject invalid {:?}", s);
+    }
+    assert_eq!(parse_decimal(b"0x.p1"), Invalid(MissingDigits, 3));
+    assert_eq!(parse_decimal(b"0x1p+"), Invalid(ExponentMissingDigits, 5));
+    assert_eq!(parse_decimal(b"0x1pg"), Invalid(ExponentMissingDigits, 4));
+    assert_eq!(parse_decimal(b"0x1p1.5"), Invalid(InvalidDigit, 5));
+    assert_eq!(parse_decimal(b"0x1.8."), Invalid(InvalidDigit, 5));
+}
+
+#[test]
+fn valid_hex() {
+    assert_eq!(parse_decimal(b"0x1.8p-3"), ValidHex(Hexadecimal::new(b"1", b"8", -3)));
+    assert_eq!(parse_decimal(b"0XfF.A0P+10"), ValidHex(Hexadecimal::new(b"fF", b"A0", 10)));
+    assert_eq!(parse_decimal(b"0x.8"), ValidHex(Hexadecimal::new(b"", b"8", 0)));
+    assert_eq!(parse_decimal(b"0x10."), ValidHex(Hexadecimal::new(b"10", b"", 0)));
+    // 'e' is a digit and not an exponent
+    assert_eq!(parse_decimal(b"0x1.8e3"), ValidHex(Hexadecimal::new(b"1", b"8e3", 0)));
+    let nines: String = iter::repeat('9').take(30).collect();
+    let s = format!("0x1p-{}", nines);
+    assert_eq!(parse_decimal(s.as_bytes()), ValidHex(Hexadecimal::new(b"1", b"", -HEX_EXP_LIMIT)));
+    let zeros: String = iter::repeat('0').take(30).collect();
+    let s = format!("0x0p{}1", zeros);
+    assert_eq!(parse_decimal(s.as_bytes()), ValidHex(Hexadecimal::new(b"0", b"", 1)));
 }
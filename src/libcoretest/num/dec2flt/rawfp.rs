@@ -9,12 +9,12 @@
 // except according to those terms.
 
 use std::f64;
-use std::mem;
+use core::num::{Float, f16};
 use core::num::diy_float::Fp;
 use core::num::dec2flt::rawfp::{fp_to_float, prev_float, next_float, round_normal};
 
 fn integer_decode(f: f64) -> (u64, i16, i8) {
-    let bits: u64 = unsafe { mem::transmute(f) };
+    let bits = f.to_bits();
     let sign: i8 = if bits >> 63 == 0 { 1 } else { -1 };
     let mut exponent: i16 = ((bits >> 52) & 0x7ff) as i16;
     let mantissa = if exponent == 0 {
@@ -152,3 +152,27 @@ fn next_float_monotonic() {
     }
     assert!(x > 0.5);
 }
+
+#[test]
+fn float_bits_round_trip() {
+    assert_eq!(Float::to_bits(1.0f32), 0x3f80_0000);
+    assert_eq!(Float::to_bits(-0.0f64), 1 << 63);
+    assert_eq!(Float::to_bits(f16::from_bits(0x3c00)), 0x3c00);
+    for &x in &SOME_FLOATS {
+        assert_eq!(<f64 as Float>::from_bits(Float::to_bits(x)), x);
+        assert_eq!(Float::to_bits(x), x.to_bits());
+        let y = x as f32;
+        assert_eq!(<f32 as Float>::from_bits(Float::to_bits(y)), y);
+    }
+    // NaN payloads survive, even though NaNs don't compare equal.
+    let nan = <f32 as Float>::from_bits(0x7fc0_0123);
+    assert!(nan.is_nan());
+    assert_eq!(nan.to_bits(), 0x7fc0_0123);
+    assert_eq!(<f32 as Float>::from_bits(0xffff_ffff).to_bits(), !0);
+}
+
+#[test]
+#[should_panic]
+fn float_bits_too_wide() {
+    let _ = <f32 as Float>::from_bits(1 << 32);
+}
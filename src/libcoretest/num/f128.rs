@@ -0,0 +1,117 @@
+// Copyright 2016 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use core::f128::{MAX, MIN_POSITIVE, EPSILON, INFINITY, NEG_INFINITY, NAN};
+use core::num::f128;
+
+const ONE_TENTH: (u64, u64) = (0x3ffb_9999_9999_9999, 0x9999_9999_9999_999a);
+
+fn parse(s: &str) -> (u64, u64) {
+    s.parse::<f128>().unwrap().to_bits()
+}
+
+#[test]
+fn test_parse() {
+    assert_eq!(parse("0.1"), ONE_TENTH);
+    assert_eq!(parse("1"), (0x3fff_0000_0000_0000, 0));
+    assert_eq!(parse("-0"), (0x8000_0000_0000_0000, 0));
+    assert_eq!(parse("1.18973149535723176508575932662800702e4932"), MAX.to_bits());
+    assert_eq!(parse("1e4933"), INFINITY.to_bits());
+    assert_eq!(parse("3.3621031431120935062626778173217526e-4932"), MIN_POSITIVE.to_bits());
+    assert_eq!(parse("6e-4966"), (0, 1));
+    assert_eq!(parse("1e-5000"), (0, 0));
+    // just below and just above halfway between one and the next `f128`
+    assert_eq!(parse("1.000000000000000000000000000000000096296497219361792652798897129246365"),
+               (0x3fff_0000_0000_0000, 0));
+    assert_eq!(parse("1.000000000000000000000000000000000096296497219361792652798897129246366"),
+               (0x3fff_0000_0000_0000, 1));
+    assert_eq!(parse("0x1p-16494"), (0, 1));
+    assert_eq!(parse("inf"), INFINITY.to_bits());
+    assert!("NaN".parse::<f128>().unwrap().is_nan());
+    assert!("".parse::<f128>().is_err());
+}
+
+#[test]
+fn test_parse_fast_path() {
+    // `10^48` is exact, and the others are rounded once
+    assert_eq!(parse("1e48"), (0x409e_5e53_1a0a_1c87, 0x2bad_2ce1_6256_fe82));
+    assert_eq!(parse("1e-48"), (0x3f5f_7624_f8a7_62fd, 0x82b2_aac1_8030_b01b));
+    assert_eq!(parse("9999999999999999999999999999999999e48"),
+               (0x410f_5159_af80_4446, 0x2379_8810_65d4_1ad1));
+    assert_eq!(parse("0.0000000000000000000001234567890123456789"),
+               (0x3fb6_2a80_0d16_3332, 0xf0ce_65bc_7bc7_de97));
+}
+
+#[test]
+fn test_parse_long() {
+    // exactly halfway between one and the next `f128`
+    let half = concat!("1.000000000000000000000000000000000096296497219361792652798897129246365",
+                       "92690508241076940976199693977832794189453125");
+    let below = &half[..half.len() - 1];
+    let zeros: String = (0..12000).map(|_| "0").collect();
+    assert_eq!(parse(half), (0x3fff_0000_0000_0000, 0));
+    assert_eq!(parse(&format!("{}{}1", half, zeros)), (0x3fff_0000_0000_0000, 1));
+    assert_eq!(parse(&format!("{}4{}9", below, zeros)), (0x3fff_0000_0000_0000, 0));
+
+    let threes: String = (0..12000).map(|_| "3").collect();
+    let third = parse("0.3333333333333333333333333333333333333333");
+    assert_eq!(parse(&format!("0.{}", threes)), third);
+    assert_eq!(parse(&format!("{}e-12000", threes)), third);
+    assert_eq!(parse(&format!("{}e-16000", threes)),
+               parse("3.333333333333333333333333333333333333e-4001"));
+}
+
+#[test]
+fn test_display() {
+    assert_eq!(format!("{}", f128::from_bits(ONE_TENTH.0, ONE_TENTH.1)), "0.1");
+    assert_eq!(format!("{:e}", MAX), "1.189731495357231765085759326628007e4932");
+    assert_eq!(format!("{:.4e}", MAX), "1.1897e4932");
+    assert_eq!(format!("{:e}", EPSILON), "1.9259299443872358530559779425849273e-34");
+    assert_eq!(format!("{:e}", f128::from_bits(0, 1)), "6e-4966");
+    assert_eq!(format!("{:.40}", f128::from_bits(ONE_TENTH.0, ONE_TENTH.1)),
+               "0.1000000000000000000000000000000000048148");
+    assert_eq!(format!("{:?}", -f128::from_f64(0.0)), "-0");
+    assert_eq!(format!("{}", NEG_INFINITY), "-inf");
+    assert_eq!(format!("{:?}", NAN), "NaN");
+}
+
+#[test]
+fn test_conversions() {
+    let tenth = f128::from_bits(ONE_TENTH.0, ONE_TENTH.1);
+    assert_eq!(tenth.to_f64(), 0.1);
+    assert_eq!(tenth.to_f32(), 0.1);
+    assert_eq!(f128::from_f64(0.1).to_bits(), (0x3ffb_9999_9999_9999, 0xa000_0000_0000_0000));
+    assert!(f128::from_f64(0.1) != tenth);
+    assert_eq!(MAX.to_f64(), ::core::f64::INFINITY);
+    assert_eq!(MIN_POSITIVE.to_f64(), 0.0);
+    assert_eq!(f128::from(-1.5f32).to_f64(), -1.5);
+    assert_eq!(f128::from(::core::f64::MIN_POSITIVE).to_f64(), ::core::f64::MIN_POSITIVE);
+    assert!(f128::from_f64(::core::f64::NAN).is_nan());
+    assert!(NAN.to_f32().is_nan());
+}
+
+#[test]
+fn test_arithmetic() {
+    let one = f128::from_f64(1.0);
+    let three = f128::from_f64(3.0);
+    assert_eq!(one + EPSILON, f128::from_bits(0x3fff_0000_0000_0000, 1));
+    assert_eq!(one + EPSILON / f128::from_f64(2.0), one);
+    assert_eq!((one / three).to_bits(), (0x3ffd_5555_5555_5555, 0x5555_5555_5555_5555));
+    assert_eq!(f128::from_f64(0.5) * f128::from_f64(0.5), f128::from_f64(0.25));
+    assert_eq!(MAX + MAX, INFINITY);
+    assert!((INFINITY - INFINITY).is_nan());
+    assert!(NAN != NAN);
+    assert!(f128::from_f64(-0.0) == f128::from_f64(0.0));
+    assert!(MIN_POSITIVE > f128::from_bits(0x0000_ffff_ffff_ffff, 0xffff_ffff_ffff_ffff));
+    let mut x = one;
+    x *= three;
+    x -= one;
+    assert_eq!(x, f128::from_f64(2.0));
+}
@@ -0,0 +1,112 @@
+// Copyright 2016 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use core::f16::{MAX, MIN_POSITIVE, EPSILON, INFINITY, NEG_INFINITY, NAN};
+use core::num::{f16, Float};
+
+fn parse(s: &str) -> u16 {
+    s.parse::<f16>().unwrap().to_bits()
+}
+
+#[test]
+fn test_parse() {
+    assert_eq!(parse("65504"), MAX.to_bits());
+    assert_eq!(parse("65519.99"), MAX.to_bits());
+    // halfway to 65536, which rounds to the even significand of infinity
+    assert_eq!(parse("65520"), INFINITY.to_bits());
+    assert_eq!(parse("1e5"), INFINITY.to_bits());
+    assert_eq!(parse("-65504"), (-MAX).to_bits());
+    assert_eq!(parse("0.1"), 0x2e66);
+    assert_eq!(parse("1"), 0x3c00);
+    assert_eq!(parse("-0"), 0x8000);
+    assert_eq!(parse("0.00006103515625"), MIN_POSITIVE.to_bits());
+    assert_eq!(parse("6e-8"), 0x0001);
+    // half of the smallest subnormal, exactly and just above
+    assert_eq!(parse("0.0000000298023223876953125"), 0x0000);
+    assert_eq!(parse("0.0000000298023223876953126"), 0x0001);
+    // ties around 2048, where the spacing becomes 2
+    assert_eq!(parse("2049"), 0x6800);
+    assert_eq!(parse("2049.0001"), 0x6801);
+    assert_eq!(parse("2051"), 0x6802);
+    assert_eq!(parse("0x1.ffcp15"), MAX.to_bits());
+    assert_eq!(parse("inf"), INFINITY.to_bits());
+    assert!("NaN".parse::<f16>().unwrap().is_nan());
+    assert!("".parse::<f16>().is_err());
+}
+
+#[test]
+fn test_display() {
+    assert_eq!(format!("{}", MAX), "65500");
+    assert_eq!(format!("{:.1}", MAX), "65504.0");
+    assert_eq!(format!("{:e}", MAX), "6.55e4");
+    assert_eq!(format!("{}", EPSILON), "0.000977");
+    assert_eq!(format!("{}", f16::from_bits(1)), "0.00000006");
+    assert_eq!(format!("{}", f16::from_f32(0.1)), "0.1");
+    assert_eq!(format!("{:?}", -f16::from_f32(0.0)), "-0");
+    assert_eq!(format!("{}", NEG_INFINITY), "-inf");
+    assert_eq!(format!("{:?}", NAN), "NaN");
+}
+
+#[test]
+fn test_roundtrip() {
+    for bits in 0..0x10000u32 {
+        let x = f16::from_bits(bits as u16);
+        if x.is_nan() {
+            assert!(x.to_f32().is_nan() && f16::from_f32(x.to_f32()).is_nan());
+            continue;
+        }
+        assert_eq!(f16::from_f32(x.to_f32()).to_bits(), x.to_bits());
+        assert_eq!(f16::from_f64(x.to_f64()).to_bits(), x.to_bits());
+        let s = format!("{:?}", x);
+        assert_eq!(s.parse::<f16>().unwrap().to_bits(), x.to_bits(), "{}", s);
+        assert!(s.len() <= ::core::f16::MAX_DISPLAY_LEN, "{}", s);
+    }
+}
+
+#[test]
+fn test_conversions() {
+    assert_eq!(f16::from_f32(2049.0).to_bits(), 0x6800);
+    assert_eq!(f16::from_f32(2051.0).to_bits(), 0x6802);
+    assert_eq!(f16::from_f32(65519.0).to_bits(), MAX.to_bits());
+    assert_eq!(f16::from_f32(65520.0).to_bits(), INFINITY.to_bits());
+    assert_eq!(f16::from_f32(-1e10).to_bits(), NEG_INFINITY.to_bits());
+    assert_eq!(f16::from_f64(2.98023223876953125e-8).to_bits(), 0x0000);
+    assert_eq!(f16::from_f64(2.98023223876953126e-8).to_bits(), 0x0001);
+    assert_eq!(f16::from_f64(-1e-10).to_bits(), 0x8000);
+    // `f64` rounds once, while going through `f32` makes a tie
+    let x = 1.0 + 2f64.powi(-11) + 2f64.powi(-40);
+    assert_eq!(f16::from_f64(x).to_bits(), 0x3c01);
+    assert_eq!(f16::from_f32(x as f32).to_bits(), 0x3c00);
+    assert_eq!(f32::from(MAX), 65504.0);
+    assert_eq!(f64::from(f16::from_bits(1)), 2f64.powi(-24));
+    assert_eq!(f16::from_f32(Float::nan_with_payload(0x1ff << 13)).payload(), Some(0x1ff));
+}
+
+#[test]
+fn test_arithmetic() {
+    let one = f16::from_f32(1.0);
+    let three = f16::from_f32(3.0);
+    assert_eq!(one + EPSILON, f16::from_bits(0x3c01));
+    assert_eq!(one + EPSILON / f16::from_f32(2.0), one);
+    assert_eq!((one / three).to_bits(), 0x3555);
+    assert_eq!(three % f16::from_f32(2.0), one);
+    assert_eq!(MAX + MAX, INFINITY);
+    assert!(NAN != NAN);
+    assert!(f16::from_f32(-0.0) == f16::from_f32(0.0));
+    assert!(MIN_POSITIVE > f16::from_bits(0x03ff));
+    assert_eq!(Float::mul_add(three, three, -one), f16::from_f32(8.0));
+    assert_eq!(Float::ldexp(one, -24), f16::from_bits(1));
+    assert_eq!(Float::ilogb(MAX), 15);
+    assert_eq!(Float::floor(f16::from_f32(-2.5)), f16::from_f32(-3.0));
+    let mut x = one;
+    x *= three;
+    x -= one;
+    assert_eq!(x, f16::from_f32(2.0));
+}
@@ -0,0 +1,86 @@
+// Copyright 2016 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use core::f64;
+use core::hint::black_box;
+use core::num::fenv::{self, FpExceptions};
+use core::num::fenv::{INVALID, DENORMAL, DIVIDE_BY_ZERO, OVERFLOW, UNDERFLOW, INEXACT};
+
+// Returns the flags raised by `f` alone.
+fn flags_of<F: Fn() -> f64>(f: F) -> FpExceptions {
+    fenv::clear_exceptions();
+    black_box(f());
+    let flags = fenv::test_exceptions();
+    fenv::clear_exceptions();
+    flags
+}
+
+#[inline(never)]
+fn div(a: f64, b: f64) -> f64 {
+    black_box(a) / black_box(b)
+}
+
+#[inline(never)]
+fn mul(a: f64, b: f64) -> f64 {
+    black_box(a) * black_box(b)
+}
+
+#[test]
+fn test_exception_sets() {
+    let all = INVALID | DENORMAL | DIVIDE_BY_ZERO | OVERFLOW | UNDERFLOW | INEXACT;
+    assert_eq!(all, FpExceptions::all());
+    assert!(FpExceptions::empty().is_empty());
+    assert!(all.contains(OVERFLOW | INEXACT));
+    assert!(!INEXACT.contains(OVERFLOW | INEXACT));
+    assert_eq!((OVERFLOW | INEXACT) & INEXACT, INEXACT);
+    assert_eq!((OVERFLOW | INEXACT) - INEXACT, OVERFLOW);
+    assert_eq!(FpExceptions::default(), FpExceptions::empty());
+}
+
+#[test]
+fn test_exceptions_raised() {
+    if !fenv::is_supported() {
+        assert!(flags_of(|| div(1.0, 3.0)).is_empty());
+        return
+    }
+
+    assert!(flags_of(|| div(1.0, 2.0)).is_empty());
+    assert_eq!(flags_of(|| div(1.0, 3.0)), INEXACT);
+    assert_eq!(flags_of(|| div(0.0, 0.0)), INVALID);
+    assert_eq!(flags_of(|| div(1.0, 0.0)), DIVIDE_BY_ZERO);
+    assert!(flags_of(|| mul(f64::MAX, 2.0)).contains(OVERFLOW | INEXACT));
+    // Whether a flushed result is also inexact differs between targets.
+    assert!(flags_of(|| mul(f64::MIN_POSITIVE, 0.3)).contains(UNDERFLOW));
+}
+
+#[test]
+fn test_exceptions_cleared() {
+    black_box(div(1.0, 3.0));
+    fenv::clear_exceptions();
+    assert!(fenv::test_exceptions().is_empty());
+}
+
+#[test]
+fn test_flush_to_zero_matches_results() {
+    if !fenv::is_supported() {
+        assert!(!fenv::flush_to_zero());
+        assert!(!fenv::denormals_are_zero());
+        return
+    }
+
+    // The runtime may have turned flush-to-zero on, so only check that the
+    // reported mode agrees with what the hardware does.
+    let half = mul(f64::MIN_POSITIVE, 0.5);
+    if fenv::flush_to_zero() {
+        assert_eq!(half, 0.0);
+    } else {
+        assert!(half > 0.0 && half < f64::MIN_POSITIVE);
+    }
+}
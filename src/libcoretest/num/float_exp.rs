@@ -0,0 +1,125 @@
+// Copyright 2016 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use core::num::Float;
+use core::{f32, f64, i32, mem};
+use num::prop::{check, FiniteF32, FiniteF64};
+
+extern {
+    fn ldexp(x: f64, n: i32) -> f64;
+    fn ilogb(x: f64) -> i32;
+}
+
+fn bits64(x: f64) -> u64 { unsafe { mem::transmute(x) } }
+
+#[test]
+fn test_to_exp_mantissa() {
+    assert_eq!(Float::to_exp_mantissa(1.0f64), (-52, 1 << 52));
+    assert_eq!(Float::to_exp_mantissa(-1.0f64), (-52, 1 << 52));
+    assert_eq!(Float::to_exp_mantissa(0.0f64), (-1074, 0));
+    assert_eq!(Float::to_exp_mantissa(-0.0f64), (-1074, 0));
+    assert_eq!(Float::to_exp_mantissa(5e-324f64), (-1074, 1));
+    assert_eq!(Float::to_exp_mantissa(f64::MIN_POSITIVE), (-1074, 1 << 52));
+    assert_eq!(Float::to_exp_mantissa(f64::MAX), (971, (1 << 53) - 1));
+    assert_eq!(Float::to_exp_mantissa(f64::INFINITY), (972, 1 << 52));
+    assert_eq!(Float::to_exp_mantissa(1.0f32), (-23, 1 << 23));
+    assert_eq!(Float::to_exp_mantissa(f32::MIN_POSITIVE), (-149, 1 << 23));
+    assert_eq!(Float::to_exp_mantissa(f32::MAX), (104, (1 << 24) - 1));
+}
+
+#[test]
+fn test_from_exp_mantissa() {
+    let from64 = |e: i32, m: u64| -> f64 { Float::from_exp_mantissa(e, m) };
+    let from32 = |e: i32, m: u64| -> f32 { Float::from_exp_mantissa(e, m) };
+    assert_eq!(from64(0, 0), 0.0);
+    assert_eq!(from64(i32::MAX, 0), 0.0);
+    assert_eq!(from64(0, 1), 1.0);
+    assert_eq!(from64(-64, 1 << 63), 0.5);
+    assert_eq!(from64(971, (1 << 53) - 1), f64::MAX);
+    assert_eq!(from64(972, 1), 2.0f64.powi(972));
+    assert_eq!(from64(1024, 1), f64::INFINITY);
+    assert_eq!(from64(i32::MAX, u64::max_value()), f64::INFINITY);
+    // rounding the 64-bit mantissa to 53 bits: ties go to even
+    assert_eq!(from64(0, (1 << 53) + 1), 9007199254740992.0);
+    assert_eq!(from64(0, (1 << 53) + 3), 9007199254740996.0);
+    assert_eq!(from64(0, u64::max_value()), 18446744073709551616.0);
+    // rounding up to infinity
+    assert_eq!(from64(960, u64::max_value()), f64::INFINITY);
+    assert_eq!(from64(960, u64::max_value() >> 11 << 11), f64::MAX);
+    // subnormals and underflow
+    assert_eq!(from64(-1074, 1), 5e-324);
+    assert_eq!(from64(-1075, 1), 0.0);
+    assert_eq!(from64(-1075, 2), 5e-324);
+    assert_eq!(from64(-1075, 3), 1e-323);
+    assert_eq!(from64(-1076, 3), 5e-324);
+    assert_eq!(from64(-1200, u64::max_value()), 0.0);
+    assert_eq!(from64(i32::MIN, u64::max_value()), 0.0);
+    assert_eq!(from64(-1075, (1 << 53) - 1), f64::MIN_POSITIVE);
+    assert_eq!(from32(-149, 1), 2.0f32.powi(-149));
+    assert_eq!(from32(-150, 1), 0.0);
+    assert_eq!(from32(104, (1 << 24) - 1), f32::MAX);
+    assert_eq!(from32(104, 1 << 24), f32::INFINITY);
+    assert_eq!(from32(0, (1 << 24) + 1), 16777216.0);
+}
+
+#[test]
+fn test_ldexp_ilogb() {
+    assert_eq!(Float::ldexp(3.0f64, 2), 12.0);
+    assert_eq!(Float::ldexp(-3.0f64, -2), -0.75);
+    assert_eq!(Float::ldexp(1.0f64, -1074), 5e-324);
+    assert_eq!(Float::ldexp(1.0f64, -1075), 0.0);
+    assert_eq!(Float::ldexp(1.5f64, -1074), 1e-323);
+    assert_eq!(Float::ldexp(5e-324f64, 1074), 1.0);
+    assert_eq!(Float::ldexp(1.0f64, i32::MAX), f64::INFINITY);
+    assert_eq!(Float::ldexp(-1.0f64, i32::MAX), f64::NEG_INFINITY);
+    assert_eq!(Float::ldexp(f64::MAX, i32::MIN), 0.0);
+    assert!(Float::ldexp(-1.0f64, i32::MIN).is_sign_negative());
+    assert!(Float::ldexp(-0.0f64, 5).is_sign_negative());
+    assert!(Float::ldexp(f64::NAN, 5).is_nan());
+    assert_eq!(Float::ldexp(f32::MIN_POSITIVE, -23), 2.0f32.powi(-149));
+
+    assert_eq!(Float::ilogb(1.0f64), 0);
+    assert_eq!(Float::ilogb(-0.5f64), -1);
+    assert_eq!(Float::ilogb(f64::MAX), 1023);
+    assert_eq!(Float::ilogb(5e-324f64), -1074);
+    assert_eq!(Float::ilogb(f64::MIN_POSITIVE / 3.0), -1024);
+    assert_eq!(Float::ilogb(0.0f64), i32::MIN);
+    assert_eq!(Float::ilogb(f64::NAN), i32::MIN);
+    assert_eq!(Float::ilogb(f64::NEG_INFINITY), i32::MAX);
+    assert_eq!(Float::ilogb(f32::MAX), 127);
+    assert_eq!(Float::ilogb(2.0f32.powi(-149)), -149);
+}
+
+#[test]
+fn prop_exp_mantissa_round_trip() {
+    check("exp_mantissa_round_trip_f64", 10_000, |x: &FiniteF64| {
+        let (e, m) = Float::to_exp_mantissa(x.0);
+        let y: f64 = Float::from_exp_mantissa(e, m);
+        // the mantissa may be shifted by any amount without changing the value
+        let lz = m.leading_zeros() as i32;
+        let z: f64 = Float::from_exp_mantissa(e - lz, m << lz);
+        y == x.0 && z == x.0
+    });
+    check("exp_mantissa_round_trip_f32", 10_000, |x: &FiniteF32| {
+        let (e, m) = Float::to_exp_mantissa(x.0);
+        let y: f32 = Float::from_exp_mantissa(e, m);
+        y == x.0
+    });
+}
+
+#[test]
+fn prop_ldexp_ilogb_against_libm() {
+    check("ldexp_ilogb_f64", 10_000, |x: &FiniteF64| {
+        (-1100..1100).filter(|n| n % 37 == 0).all(|n| unsafe {
+            bits64(Float::ldexp(x.0, n)) == bits64(ldexp(x.0, n)) &&
+            bits64(Float::ldexp(-x.0, n)) == bits64(ldexp(-x.0, n))
+        }) && (x.0 == 0.0 || Float::ilogb(x.0) == unsafe { ilogb(x.0) })
+    });
+}
@@ -0,0 +1,161 @@
+// Copyright 2016 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use core::num::RoundingMode::*;
+use core::num::convert::FromInt;
+use core::num::{Float, i128, u128};
+use core::{f32, i64, u64};
+use num::prop::{f32_next_up, f64_next_up};
+
+// Integers with all numbers of significant bits, and especially the ones
+// around the halfway points of `f32` and `f64`.
+fn interesting() -> Vec<u64> {
+    let mut v = vec![0, 1, 2, 3, u64::MAX, u64::MAX - 1];
+    for s in 0..64 {
+        for &k in &[1u64, 3, 5, 0x00ff_ffff, 0x0100_0001, 0x001f_ffff_ffff_ffff] {
+            let x = k.wrapping_shl(s);
+            for d in 0..4 {
+                v.push(x.wrapping_add(d));
+                v.push(x.wrapping_sub(d));
+                v.push(x.wrapping_add(x >> 25).wrapping_add(d));
+                v.push(x.wrapping_add(x >> 54).wrapping_sub(d));
+            }
+        }
+    }
+    v
+}
+
+#[test]
+fn test_from_int_exact() {
+    assert_eq!(<f64 as FromInt<u64>>::try_from_int_exact(0), Ok(0.0));
+    assert_eq!(<f64 as FromInt<i64>>::try_from_int_exact(i64::MIN), Ok(-9223372036854775808.0));
+    assert!(<f64 as FromInt<i64>>::try_from_int_exact(i64::MAX).is_err());
+    assert_eq!(<f64 as FromInt<u64>>::try_from_int_exact(0x001f_ffff_ffff_ffff),
+               Ok(9007199254740991.0));
+    assert_eq!(<f64 as FromInt<u64>>::try_from_int_exact(0xffff_ffff_ffff_f800),
+               Ok(18446744073709549568.0));
+    assert!(<f64 as FromInt<u64>>::try_from_int_exact(0x0020_0000_0000_0001).is_err());
+    assert_eq!(<f64 as FromInt<u32>>::try_from_int_exact(u32::max_value()), Ok(4294967295.0));
+    assert_eq!(<f32 as FromInt<u8>>::try_from_int_exact(255), Ok(255.0));
+    assert_eq!(<f32 as FromInt<i32>>::try_from_int_exact(-0x0100_0000), Ok(-16777216.0));
+    assert!(<f32 as FromInt<i32>>::try_from_int_exact(-0x0100_0001).is_err());
+    assert!(<f32 as FromInt<u32>>::try_from_int_exact(u32::max_value()).is_err());
+}
+
+#[test]
+fn test_from_int_round() {
+    let x = 0x0020_0000_0000_0001u64;
+    assert_eq!(<f64 as FromInt<u64>>::from_int_round(x, TiesToEven), 9007199254740992.0);
+    assert_eq!(<f64 as FromInt<u64>>::from_int_round(x, TiesToAway), 9007199254740994.0);
+    assert_eq!(<f64 as FromInt<u64>>::from_int_round(x, TowardZero), 9007199254740992.0);
+    assert_eq!(<f64 as FromInt<u64>>::from_int_round(x, TowardPositive), 9007199254740994.0);
+    assert_eq!(<f64 as FromInt<u64>>::from_int_round(x, TowardNegative), 9007199254740992.0);
+    assert_eq!(<f64 as FromInt<u64>>::from_int_round(x, ToOdd), 9007199254740994.0);
+    let x = -(x as i64);
+    assert_eq!(<f64 as FromInt<i64>>::from_int_round(x, TiesToAway), -9007199254740994.0);
+    assert_eq!(<f64 as FromInt<i64>>::from_int_round(x, TowardZero), -9007199254740992.0);
+    assert_eq!(<f64 as FromInt<i64>>::from_int_round(x, TowardPositive), -9007199254740992.0);
+    assert_eq!(<f64 as FromInt<i64>>::from_int_round(x, TowardNegative), -9007199254740994.0);
+    // rounding up to the next power of two
+    assert_eq!(<f64 as FromInt<u64>>::from_int_round(u64::MAX, TowardPositive),
+               18446744073709551616.0);
+    assert_eq!(<f64 as FromInt<u64>>::from_int_round(u64::MAX, TowardZero),
+               18446744073709549568.0);
+    assert_eq!(<f32 as FromInt<i64>>::from_int_round(i64::MIN + 1, TowardNegative),
+               -9223372036854775808.0);
+    assert_eq!(<f32 as FromInt<i64>>::from_int_round(i64::MIN + 1, TowardZero),
+               -9223371487098961920.0);
+    assert_eq!(<f32 as FromInt<u32>>::from_int_round(0x0100_0003, TiesToEven), 16777220.0);
+    assert_eq!(<f32 as FromInt<u32>>::from_int_round(0x0100_0005, TiesToEven), 16777220.0);
+    assert_eq!(<f32 as FromInt<u32>>::from_int_round(0x0100_0005, TiesToAway), 16777222.0);
+    assert_eq!(<f32 as FromInt<u32>>::from_int_round(0x0100_0003, ToOdd), 16777218.0);
+    assert_eq!(<f32 as FromInt<u32>>::from_int_round(0x0100_0005, ToOdd), 16777222.0);
+    assert_eq!(<f32 as FromInt<u32>>::from_int_round(0x0100_0004, ToOdd), 16777220.0);
+}
+
+// Checks the rounding of `x`, using that the results of all the modes are one
+// of the two floats around `x`, `down` and `next_up(down)`.
+macro_rules! check_rounding {
+    ($float:ident, $next_up:ident, $x:expr) => ({
+        let x: u64 = $x;
+        let round = |mode| <$float as FromInt<u64>>::from_int_round(x, mode);
+        let down = round(TowardZero);
+        assert_eq!(round(TowardNegative), down);
+        assert_eq!(round(TiesToEven), x as $float, "{}", x);
+        if down as u64 == x {
+            assert_eq!(<$float as FromInt<u64>>::try_from_int_exact(x), Ok(down));
+            assert_eq!(round(TowardPositive), down);
+            assert_eq!(round(TiesToAway), down);
+            assert_eq!(round(ToOdd), down);
+        } else {
+            assert!(<$float as FromInt<u64>>::try_from_int_exact(x).is_err());
+            let up = $next_up(down);
+            let ulp = (up - down) as u64;
+            assert!((down as u64) < x && (down as u64).checked_add(ulp).map_or(true, |u| u > x));
+            assert_eq!(round(TowardPositive), up);
+            let away = if x - down as u64 >= ulp / 2 { up } else { down };
+            assert_eq!(round(TiesToAway), away, "{}", x);
+            assert!(round(ToOdd) == down || round(ToOdd) == up);
+        }
+        if x <= i64::MAX as u64 {
+            let neg = |mode| <$float as FromInt<i64>>::from_int_round(-(x as i64), mode);
+            assert_eq!(neg(TowardNegative), -round(TowardPositive));
+            assert_eq!(neg(TowardPositive), -round(TowardNegative));
+            assert_eq!(neg(TowardZero), -round(TowardZero));
+            assert_eq!(neg(TiesToAway), -round(TiesToAway));
+            assert_eq!(neg(ToOdd), -round(ToOdd));
+            assert_eq!(neg(TiesToEven), -(x as i64) as $float);
+        }
+    })
+}
+
+#[test]
+fn test_from_int_round_modes() {
+    for &x in &interesting() {
+        check_rounding!(f64, f64_next_up, x);
+        check_rounding!(f32, f32_next_up, x);
+    }
+}
+
+#[test]
+fn test_from_wide_int() {
+    let modes = [TiesToEven, TiesToAway, TowardZero, TowardPositive, TowardNegative, ToOdd];
+    for &x in &interesting() {
+        for &mode in &modes {
+            let f = <f64 as FromInt<u64>>::from_int_round(x, mode);
+            let wide = u128::from(x);
+            assert_eq!(<f64 as FromInt<u128>>::from_int_round(wide, mode), f);
+            assert_eq!(<f64 as FromInt<u128>>::from_int_round(wide << 64, mode),
+                       Float::ldexp(f, 64));
+            let f = <f32 as FromInt<u64>>::from_int_round(x, mode);
+            assert_eq!(<f32 as FromInt<u128>>::from_int_round(wide << 32, mode),
+                       Float::ldexp(f, 32));
+        }
+    }
+    // the bits below the top 64 still count
+    let x = (u128::from(1u8) << 100) + u128::from(1u8);
+    assert!(<f64 as FromInt<u128>>::try_from_int_exact(x).is_err());
+    assert_eq!(<f64 as FromInt<u128>>::from_int_round(x, TowardZero),
+               1267650600228229401496703205376.0);
+    assert_eq!(<f64 as FromInt<u128>>::from_int_round(x, TowardPositive),
+               1267650600228229682971679916032.0);
+    assert_eq!(<f64 as FromInt<u128>>::try_from_int_exact(u128::from(1u8) << 127),
+               Ok(170141183460469231731687303715884105728.0));
+    let max = core::u128::MAX;
+    assert_eq!(<f32 as FromInt<u128>>::from_int_round(max, TowardZero), f32::MAX);
+    assert_eq!(<f32 as FromInt<u128>>::from_int_round(max, TiesToEven), f32::INFINITY);
+    let (min, max) = (core::i128::MIN, core::i128::MAX);
+    assert_eq!(<f64 as FromInt<i128>>::try_from_int_exact(min),
+               Ok(-170141183460469231731687303715884105728.0));
+    assert!(<f64 as FromInt<i128>>::try_from_int_exact(max).is_err());
+    assert_eq!(<f64 as FromInt<i128>>::from_int_round(-i128::from(3i8), TowardNegative), -3.0);
+    assert_eq!(<f32 as FromInt<i128>>::from_int_round(min + i128::from(1i8), TowardZero),
+               -170141173319264429905852091742258462720.0);
+}
@@ -0,0 +1,105 @@
+// Copyright 2016 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use core::num::Float;
+use core::{f32, f64, mem};
+use num::prop::{check, f64_next_up, FiniteF32, FiniteF64};
+
+// The reference implementations, which are correctly rounded.
+extern {
+    fn fma(x: f64, y: f64, z: f64) -> f64;
+    fn fmaf(x: f32, y: f32, z: f32) -> f32;
+}
+
+fn bits64(x: f64) -> u64 { unsafe { mem::transmute(x) } }
+fn bits32(x: f32) -> u32 { unsafe { mem::transmute(x) } }
+
+// Compares the results bit by bit, so that the sign of zero matters, for all
+// the combinations of signs.
+fn check_f64(x: f64, y: f64, z: f64) -> bool {
+    [(x, y, z), (-x, y, z), (x, y, -z), (-x, -y, -z)].iter().all(|&(x, y, z)| unsafe {
+        bits64(Float::mul_add(x, y, z)) == bits64(fma(x, y, z))
+    })
+}
+
+fn check_f32(x: f32, y: f32, z: f32) -> bool {
+    [(x, y, z), (-x, y, z), (x, y, -z), (-x, -y, -z)].iter().all(|&(x, y, z)| unsafe {
+        bits32(Float::mul_add(x, y, z)) == bits32(fmaf(x, y, z))
+    })
+}
+
+#[test]
+fn test_mul_add_f64() {
+    let from = |e: i32, m: u64| -> f64 { Float::from_exp_mantissa(e, m) };
+    assert_eq!(Float::mul_add(10.0f64, 4.0, 60.0), 100.0);
+    // the product 1 + 2^-29 + 2^-60 is not rounded
+    let x = 1.0 + from(-30, 1);
+    assert_eq!(Float::mul_add(x, x, -(x * x)), from(-60, 1));
+    assert_eq!(Float::mul_add(0.1f64, 10.0, -1.0), from(-54, 1));
+    // the exact result 1 + 2^-53 + 2^-105 is just above a tie, while the
+    // rounded product 1 is not
+    let (x, y) = (from(-52, (1 << 52) + 1), from(-53, (1 << 53) - 1));
+    assert_eq!(Float::mul_add(x, y, from(-104, 1)), from(-52, (1 << 52) + 1));
+    assert_eq!(x * y + from(-104, 1), 1.0);
+    // and the other way around, 1 + 2^-53 - 2^-105 is just below
+    assert_eq!(Float::mul_add(x, y, 0.0), 1.0);
+    assert_eq!(Float::mul_add(x, y, from(-106, 1)), 1.0);
+
+    // overflow in the product alone does not matter
+    assert_eq!(Float::mul_add(f64::MAX, 2.0, -f64::MAX), f64::MAX);
+    assert_eq!(Float::mul_add(f64::MAX, 2.0, f64::MAX), f64::INFINITY);
+    assert_eq!(Float::mul_add(1e308, 10.0, f64::NEG_INFINITY), f64::NEG_INFINITY);
+    // subnormal results are rounded only once
+    assert_eq!(Float::mul_add(5e-324f64, 0.5, -1e-323), -1e-323);
+    assert_eq!(Float::mul_add(5e-324f64, 0.5, 5e-324), 1e-323);
+    assert_eq!(5e-324f64 * 0.5 + 5e-324, 5e-324);
+    assert_eq!(Float::mul_add(1e-200f64, 1e-200, 5e-324), 5e-324);
+
+    // signs of zero
+    assert!(Float::mul_add(1.0f64, 1.0, -1.0).is_sign_positive());
+    assert!(Float::mul_add(-0.0f64, 1.0, 0.0).is_sign_positive());
+    assert!(Float::mul_add(-0.0f64, 1.0, -0.0).is_sign_negative());
+    assert!(Float::mul_add(-1e-200f64, 1e-200, 0.0).is_sign_negative());
+    assert!(Float::mul_add(-5e-324f64, 0.5, 0.0).is_sign_negative());
+
+    assert!(Float::mul_add(f64::INFINITY, 0.0, 1.0).is_nan());
+    assert!(Float::mul_add(f64::INFINITY, 1.0, f64::NEG_INFINITY).is_nan());
+    assert!(Float::mul_add(2.0f64, 3.0, f64::NAN).is_nan());
+    assert_eq!(Float::mul_add(f64::INFINITY, -1.0, 1.0), f64::NEG_INFINITY);
+}
+
+#[test]
+fn test_mul_add_f32() {
+    let from = |e: i32, m: u64| -> f32 { Float::from_exp_mantissa(e, m) };
+    assert_eq!(Float::mul_add(10.0f32, 4.0, 60.0), 100.0);
+    // the exact result 1 + 2^-24 + 2^-70 rounds to a tie in `f64`, so the
+    // product and sum in `f64` and then rounding to `f32` rounds down instead
+    let (x, y) = (from(-23, (1 << 23) + 1), from(-24, (1 << 24) - 1));
+    let z = from(-70, (1 << 23) + 1);
+    assert_eq!(Float::mul_add(x, y, z), from(-23, (1 << 23) + 1));
+    assert_eq!((x as f64 * y as f64 + z as f64) as f32, 1.0);
+    assert_eq!(Float::mul_add(f32::MAX, 2.0, -f32::MAX), f32::MAX);
+    assert!(Float::mul_add(-1e-30f32, 1e-30, 0.0).is_sign_negative());
+    assert!(Float::mul_add(2.0f32, 3.0, f32::NAN).is_nan());
+}
+
+#[test]
+fn test_mul_add_against_libm() {
+    check("mul_add_f64", 10_000, |&((x, y), z): &((FiniteF64, FiniteF64), FiniteF64)| {
+        let p = x.0 * y.0;
+        check_f64(x.0, y.0, z.0) && check_f64(x.0, y.0, 1.0) &&
+        (!p.is_finite() || (check_f64(x.0, y.0, p) && check_f64(x.0, y.0, f64_next_up(p))))
+    });
+    check("mul_add_f32", 10_000, |&((x, y), z): &((FiniteF32, FiniteF32), FiniteF32)| {
+        let p = x.0 * y.0;
+        check_f32(x.0, y.0, z.0) && check_f32(x.0, y.0, 1.0) &&
+        (!p.is_finite() || check_f32(x.0, y.0, p))
+    });
+}
@@ -0,0 +1,121 @@
+// Copyright 2016 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use core::num::Float;
+use core::{f32, f64, mem};
+use num::prop::{check, FiniteF32, FiniteF64};
+
+// The reference implementations. `rint` rounds ties to even in the default
+// rounding mode. There are no `f32` versions as they are missing on MSVC, but
+// the `f64` results of an `f32` are exact.
+extern {
+    fn floor(x: f64) -> f64;
+    fn ceil(x: f64) -> f64;
+    fn trunc(x: f64) -> f64;
+    fn round(x: f64) -> f64;
+    fn rint(x: f64) -> f64;
+}
+
+fn bits64(x: f64) -> u64 { unsafe { mem::transmute(x) } }
+fn bits32(x: f32) -> u32 { unsafe { mem::transmute(x) } }
+
+// Compares the results bit by bit, so that the sign of zero matters.
+fn check_f64(x: f64) -> bool {
+    unsafe {
+        bits64(Float::floor(x)) == bits64(floor(x)) &&
+        bits64(Float::ceil(x)) == bits64(ceil(x)) &&
+        bits64(Float::trunc(x)) == bits64(trunc(x)) &&
+        bits64(Float::round(x)) == bits64(round(x)) &&
+        bits64(Float::round_ties_even(x)) == bits64(rint(x))
+    }
+}
+
+fn check_f32(x: f32) -> bool {
+    let y = x as f64;
+    unsafe {
+        bits32(Float::floor(x)) == bits32(floor(y) as f32) &&
+        bits32(Float::ceil(x)) == bits32(ceil(y) as f32) &&
+        bits32(Float::trunc(x)) == bits32(trunc(y) as f32) &&
+        bits32(Float::round(x)) == bits32(round(y) as f32) &&
+        bits32(Float::round_ties_even(x)) == bits32(rint(y) as f32)
+    }
+}
+
+#[test]
+fn test_rounding_f64() {
+    // x, floor, ceil, trunc, round, round_ties_even
+    let cases = [
+        (0.0, 0.0, 0.0, 0.0, 0.0, 0.0),
+        (0.5, 0.0, 1.0, 0.0, 1.0, 0.0),
+        (1.5, 1.0, 2.0, 1.0, 2.0, 2.0),
+        (2.5, 2.0, 3.0, 2.0, 3.0, 2.0),
+        (-2.5, -3.0, -2.0, -2.0, -3.0, -2.0),
+        (-3.75, -4.0, -3.0, -3.0, -4.0, -4.0),
+        (0.49999999999999994, 0.0, 1.0, 0.0, 0.0, 0.0),
+        (4503599627370495.5, 4503599627370495.0, 4503599627370496.0,
+         4503599627370495.0, 4503599627370496.0, 4503599627370496.0),
+        (4503599627370497.0, 4503599627370497.0, 4503599627370497.0,
+         4503599627370497.0, 4503599627370497.0, 4503599627370497.0),
+        (1e300, 1e300, 1e300, 1e300, 1e300, 1e300),
+        (5e-324, 0.0, 1.0, 0.0, 0.0, 0.0),
+        (f64::INFINITY, f64::INFINITY, f64::INFINITY,
+         f64::INFINITY, f64::INFINITY, f64::INFINITY),
+        (f64::NEG_INFINITY, f64::NEG_INFINITY, f64::NEG_INFINITY,
+         f64::NEG_INFINITY, f64::NEG_INFINITY, f64::NEG_INFINITY),
+    ];
+    for &(x, fl, ce, tr, ro, rte) in &cases {
+        assert_eq!(Float::floor(x), fl, "floor({})", x);
+        assert_eq!(Float::ceil(x), ce, "ceil({})", x);
+        assert_eq!(Float::trunc(x), tr, "trunc({})", x);
+        assert_eq!(Float::round(x), ro, "round({})", x);
+        assert_eq!(Float::round_ties_even(x), rte, "round_ties_even({})", x);
+    }
+
+    // the sign of zero is kept
+    for &x in &[-0.0, -0.25, -0.5, -5e-324] {
+        assert!(Float::ceil(x).is_sign_negative());
+        assert!(Float::trunc(x).is_sign_negative());
+        assert!(Float::round_ties_even(x).is_sign_negative());
+    }
+    assert!(Float::round(-0.25f64).is_sign_negative());
+    assert!(Float::floor(-0.0f64).is_sign_negative());
+
+    assert!(Float::floor(f64::NAN).is_nan());
+    assert!(Float::round(f64::NAN).is_nan());
+    assert!(Float::round_ties_even(f64::NAN).is_nan());
+    assert_eq!(Float::fract(-3.75f64), -0.75);
+    assert!(Float::fract(f64::INFINITY).is_nan());
+}
+
+#[test]
+fn test_rounding_f32() {
+    assert_eq!(Float::floor(-0.5f32), -1.0);
+    assert_eq!(Float::ceil(0.5f32), 1.0);
+    assert_eq!(Float::trunc(-8388607.5f32), -8388607.0);
+    assert_eq!(Float::round(8388607.5f32), 8388608.0);
+    assert_eq!(Float::round_ties_even(8388606.5f32), 8388606.0);
+    assert_eq!(Float::round_ties_even(-0.5f32), 0.0);
+    assert!(Float::round_ties_even(-0.5f32).is_sign_negative());
+    assert_eq!(Float::round(f32::MAX), f32::MAX);
+    assert!(Float::trunc(f32::NAN).is_nan());
+    assert_eq!(Float::fract(2.25f32), 0.25);
+}
+
+#[test]
+fn test_rounding_against_libm() {
+    // all the ties and their neighbours close to zero
+    for i in -1000..1000 {
+        let x = i as f64 / 8.0;
+        assert!(check_f64(x), "{}", x);
+        assert!(check_f32(x as f32), "{}", x);
+    }
+    check("float_rounding_f64", 10_000, |x: &FiniteF64| check_f64(x.0) && check_f64(-x.0));
+    check("float_rounding_f32", 10_000, |x: &FiniteF32| check_f32(x.0) && check_f32(-x.0));
+}
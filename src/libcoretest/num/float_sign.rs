@@ -0,0 +1,97 @@
+// Copyright 2016 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use core::num::Float;
+use core::{f32, f64, mem};
+
+fn neg_nan64() -> f64 { unsafe { mem::transmute(0xfff8_0000_0000_0000u64) } }
+
+#[test]
+fn test_signum_copysign_f64() {
+    let values = [f64::NEG_INFINITY, -1.5, -f64::MIN_POSITIVE, -5e-324, -0.0,
+                  0.0, 5e-324, 1.5, f64::INFINITY];
+    for &x in &values {
+        let one: f64 = 1.0;
+        assert_eq!(Float::signum(x), Float::copysign(one, x), "{}", x);
+        assert_eq!(Float::signum(x).is_sign_negative(), x.is_sign_negative());
+        for &y in &values {
+            let z = Float::copysign(x, y);
+            assert_eq!(Float::abs(z), Float::abs(x));
+            assert_eq!(z.is_sign_negative(), y.is_sign_negative(), "{} {}", x, y);
+        }
+    }
+    assert!(Float::signum(f64::NAN).is_nan());
+    assert!(Float::copysign(f64::NAN, -1.0).is_nan());
+    assert!(Float::copysign(f64::NAN, -1.0).is_sign_negative());
+    assert_eq!(Float::copysign(2.0, neg_nan64()), -2.0);
+}
+
+#[test]
+fn test_signum_copysign_f32() {
+    assert_eq!(Float::copysign(2.5f32, -0.0), -2.5);
+    assert_eq!(Float::copysign(-2.5f32, 0.0), 2.5);
+    assert_eq!(Float::signum(-0.0f32), -1.0);
+    assert!(Float::copysign(0.0f32, -3.0).is_sign_negative());
+    assert!(Float::copysign(f32::NAN, -1.0).is_sign_negative());
+}
+
+#[test]
+fn test_clamp() {
+    assert_eq!(Float::clamp(-3.0f64, -2.0, 1.0), -2.0);
+    assert_eq!(Float::clamp(0.5f64, -2.0, 1.0), 0.5);
+    assert_eq!(Float::clamp(3.0f64, -2.0, 1.0), 1.0);
+    assert_eq!(Float::clamp(3.0f64, 1.0, 1.0), 1.0);
+    assert_eq!(Float::clamp(f64::NEG_INFINITY, -2.0, 1.0), -2.0);
+    assert!(Float::clamp(f64::NAN, -2.0, 1.0).is_nan());
+    assert_eq!(Float::clamp(-3.0f32, -2.0, 1.0), -2.0);
+    assert!(Float::clamp(f32::NAN, -2.0, 1.0).is_nan());
+}
+
+#[test]
+#[should_panic]
+fn test_clamp_nan_min() {
+    Float::clamp(0.0f64, f64::NAN, 1.0);
+}
+
+#[test]
+#[should_panic]
+fn test_clamp_nan_max() {
+    Float::clamp(0.0f32, -1.0, f32::NAN);
+}
+
+#[test]
+#[should_panic]
+fn test_clamp_min_greater_than_max() {
+    Float::clamp(0.0f64, 1.0, -1.0);
+}
+
+#[test]
+fn test_clamp_total() {
+    assert_eq!(Float::clamp_total(-3.0f64, -2.0, 1.0), -2.0);
+    assert_eq!(Float::clamp_total(0.5f64, -2.0, 1.0), 0.5);
+    assert_eq!(Float::clamp_total(3.0f64, -2.0, 1.0), 1.0);
+    // NaNs are above infinity, whatever their sign
+    assert_eq!(Float::clamp_total(f64::NAN, -2.0, 1.0), 1.0);
+    assert_eq!(Float::clamp_total(neg_nan64(), -2.0, 1.0), 1.0);
+    assert_eq!(Float::clamp_total(f64::INFINITY, -2.0, f64::NAN), f64::INFINITY);
+    assert!(Float::clamp_total(f64::NAN, -2.0, f64::NAN).is_nan());
+    assert!(Float::clamp_total(1.0f64, f64::NAN, f64::NAN).is_nan());
+    // -0.0 and 0.0 are equal, so both are within either bound
+    assert!(Float::clamp_total(-0.0f64, 0.0, 1.0).is_sign_negative());
+    assert!(Float::clamp_total(0.0f64, -1.0, -0.0).is_sign_positive());
+    assert_eq!(Float::clamp_total(f32::NAN, -2.0, 1.0), 1.0);
+    assert_eq!(Float::clamp_total(-3.0f32, -2.0, f32::NAN), -2.0);
+}
+
+#[test]
+#[should_panic]
+fn test_clamp_total_nan_min() {
+    Float::clamp_total(0.0f64, f64::NAN, 1.0);
+}
@@ -17,11 +17,14 @@ use rand::distributions::{IndependentSample, Range};
 use core::num::flt2dec::{decode, DecodableFloat, FullDecoded, Decoded};
 use core::num::flt2dec::{MAX_SIG_DIGITS, round_up, Part, Formatted, Sign};
 use core::num::flt2dec::{to_shortest_str, to_shortest_exp_str,
-                         to_exact_exp_str, to_exact_fixed_str};
+                         to_exact_exp_str, to_exact_fixed_str, to_exact_fixed_str_rounded};
+use core::num::flt2dec::Rounding;
+use core::num::RoundingMode;
 
 pub use test::Bencher;
 
 mod estimator;
+mod random;
 mod strategy {
     mod dragon;
     mod grisu;
@@ -1199,3 +1202,91 @@ pub fn to_exact_fixed_str_test<F>(mut f_: F)
                           2787392447107715776066783064379706047475337982177734375{:0>79881}", ""));
 }
 
+pub fn to_exact_fixed_str_rounded_test<F>(mut f_: F)
+        where F: FnMut(&Decoded, &mut [u8], i16, Rounding) -> (usize, i16) {
+    use core::num::RoundingMode::*;
+
+    fn to_string<T, F>(f: &mut F, v: T, frac_digits: usize, mode: RoundingMode) -> String
+            where T: DecodableFloat, F: FnMut(&Decoded, &mut [u8], i16, Rounding) -> (usize, i16) {
+        to_string_with_parts(|buf, parts| {
+            to_exact_fixed_str_rounded(|d,b,l,r| f(d,b,l,r), v, Sign::Minus, frac_digits, mode,
+                                       false, buf, parts)
+        })
+    }
+
+    let f = &mut f_;
+
+    // exact ties
+    assert_eq!(to_string(f,  0.5, 0, TiesToEven),     "0");
+    assert_eq!(to_string(f,  0.5, 0, TiesToAway),     "1");
+    assert_eq!(to_string(f,  0.5, 0, TowardZero),     "0");
+    assert_eq!(to_string(f,  0.5, 0, TowardPositive), "1");
+    assert_eq!(to_string(f,  0.5, 0, TowardNegative), "0");
+    assert_eq!(to_string(f, -0.5, 0, TiesToEven),     "-0");
+    assert_eq!(to_string(f, -0.5, 0, TiesToAway),     "-1");
+    assert_eq!(to_string(f, -0.5, 0, TowardZero),     "-0");
+    assert_eq!(to_string(f, -0.5, 0, TowardPositive), "-0");
+    assert_eq!(to_string(f, -0.5, 0, TowardNegative), "-1");
+    assert_eq!(to_string(f,  2.5, 0, TiesToEven),     "2");
+    assert_eq!(to_string(f,  2.5, 0, TiesToAway),     "3");
+    assert_eq!(to_string(f, 0.125, 2, TiesToEven),    "0.12");
+    assert_eq!(to_string(f, 0.125, 2, TiesToAway),    "0.13");
+    assert_eq!(to_string(f, 0.375, 2, TiesToEven),    "0.38");
+    assert_eq!(to_string(f, 0.375, 2, TiesToAway),    "0.38");
+
+    // directed rounding of inexact values
+    assert_eq!(to_string(f, 0.1, 3, TiesToEven),      "0.100");
+    assert_eq!(to_string(f, 0.1, 3, TowardZero),      "0.100");
+    assert_eq!(to_string(f, 0.1, 3, TowardPositive),  "0.101");
+    assert_eq!(to_string(f, -0.1, 3, TowardNegative), "-0.101");
+    assert_eq!(to_string(f, 0.3, 1, TiesToEven),      "0.3"); // 0.29999999999999998889...
+    assert_eq!(to_string(f, 0.3, 1, TowardZero),      "0.2");
+    assert_eq!(to_string(f, 0.3, 1, TowardPositive),  "0.3");
+    assert_eq!(to_string(f, 1.0 / 3.0, 3, TiesToAway),     "0.333");
+    assert_eq!(to_string(f, 1.0 / 3.0, 3, TowardPositive), "0.334");
+    assert_eq!(to_string(f, 2.0 / 3.0, 3, TowardZero),     "0.666");
+    assert_eq!(to_string(f, 2.0 / 3.0, 3, TiesToEven),     "0.667");
+
+    // rounding to odd keeps an odd last digit, and makes an even one odd if anything follows
+    assert_eq!(to_string(f,  0.5, 0, ToOdd),    "1");
+    assert_eq!(to_string(f, -0.5, 0, ToOdd),    "-1");
+    assert_eq!(to_string(f,  1.5, 0, ToOdd),    "1");
+    assert_eq!(to_string(f,  2.5, 0, ToOdd),    "3");
+    assert_eq!(to_string(f, 0.125, 2, ToOdd),   "0.13");
+    assert_eq!(to_string(f, 0.375, 2, ToOdd),   "0.37");
+    assert_eq!(to_string(f, 0.1, 3, ToOdd),     "0.101");
+    assert_eq!(to_string(f, 0.3, 1, ToOdd),     "0.3");
+    assert_eq!(to_string(f, 9.99, 1, ToOdd),    "9.9");
+    assert_eq!(to_string(f, 0.1, 0, ToOdd),     "1");
+    assert_eq!(to_string(f, 1.0e-10, 2, ToOdd), "0.01");
+    assert_eq!(to_string(f, 1.0, 0, ToOdd),     "1");
+    assert_eq!(to_string(f, 2.0, 2, ToOdd),     "2.00");
+    assert_eq!(to_string(f, 0.0, 2, ToOdd),     "0.00");
+
+    // carries into a new digit, and rounding up to a digit past the value
+    assert_eq!(to_string(f, 9.99, 1, TowardZero),     "9.9");
+    assert_eq!(to_string(f, 9.99, 1, TowardPositive), "10.0");
+    assert_eq!(to_string(f, 9.99, 1, TiesToEven),     "10.0");
+    assert_eq!(to_string(f, 999.9, 0, TowardZero),    "999");
+    assert_eq!(to_string(f, 0.1, 0, TowardPositive),  "1");
+    assert_eq!(to_string(f, 0.1, 0, TowardZero),      "0");
+    assert_eq!(to_string(f, 1.0e-10, 2, TowardPositive), "0.01");
+    assert_eq!(to_string(f, 1.0e-10, 2, TiesToAway),     "0.00");
+    assert_eq!(to_string(f, -1.0e-10, 2, TowardNegative), "-0.01");
+    assert_eq!(to_string(f, -1.0e-10, 2, TowardPositive), "-0.00");
+
+    // exact values are never rounded
+    assert_eq!(to_string(f, 0.1, 60, TowardPositive),
+               "0.100000000000000005551115123125782702118158340454101562500000");
+    assert_eq!(to_string(f, 0.1, 60, TowardZero),
+               "0.100000000000000005551115123125782702118158340454101562500000");
+    assert_eq!(to_string(f, 1.0, 2, TowardPositive), "1.00");
+    assert_eq!(to_string(f, 0.0, 2, TowardPositive), "0.00");
+    assert_eq!(to_string(f, f64::INFINITY, 2, TowardZero), "inf");
+    assert_eq!(to_string(f, f64::NAN, 2, TowardZero), "NaN");
+
+    // f32 rounds its own value, not the one widened to f64
+    assert_eq!(to_string(f, 0.1f32, 9, TowardZero), "0.100000001");
+    assert_eq!(to_string(f, 0.1f32, 9, TiesToEven), "0.100000001");
+    assert_eq!(to_string(f, 0.1f32, 9, TowardPositive), "0.100000002");
+}
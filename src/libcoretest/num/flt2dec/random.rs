@@ -0,0 +1,214 @@
+// Copyright 2016 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// Property tests relating the output of flt2dec to the input of dec2flt.
+
+use std::{i16, mem, str};
+use std::__rand as rand;
+use rand::{Rand, XorShiftRng};
+use rand::distributions::{IndependentSample, Range};
+
+use core::num::flt2dec::{Decoded, MAX_SIG_DIGITS};
+use core::num::flt2dec::strategy::{dragon, grisu};
+
+use num::prop::{check, FiniteF32, FiniteF64, f32_next_up, f64_next_up};
+use super::decode_finite;
+
+#[test]
+fn shortest_round_trip() {
+    check("f64_shortest_round_trip", 10_000, |&FiniteF64(x)| {
+        format!("{:?}", x).parse() == Ok(x) && format!("{:e}", x).parse() == Ok(x)
+    });
+    check("f32_shortest_round_trip", 10_000, |&FiniteF32(x)| {
+        format!("{:?}", x).parse() == Ok(x) && format!("{:e}", x).parse() == Ok(x)
+    });
+}
+
+// Whether no decimal with fewer significant digits than the shortest `{:e}`
+// of `x` parses to `x`. Only the two decimals around `x` with one digit less
+// need to be checked, which are its digits cut short and then rounded up.
+macro_rules! is_shortest {
+    ($ty:ident, $x:expr) => ({
+        let x: $ty = $x;
+        let s = format!("{:e}", x);
+        let (mantissa, exp) = s.split_at(s.find('e').unwrap());
+        let exp: i32 = exp[1..].parse().unwrap();
+        let digits: String = mantissa.chars().filter(|&c| c != '.').collect();
+        let n = digits.len() as i32;
+        n == 1 || {
+            let down: u64 = digits[..digits.len() - 1].parse().unwrap();
+            [down, down + 1].iter().all(|&d| {
+                format!("{}e{}", d, exp - n + 2).parse::<$ty>() != Ok(x)
+            })
+        }
+    })
+}
+
+// Whether `Display`, `Debug` and `LowerExp` of `x` and `-x` parse back to
+// them, with the same sign of zero only for `Debug`.
+macro_rules! formats_round_trip {
+    ($ty:ident, $x:expr) => ({
+        let x: $ty = $x;
+        [x, -x].iter().all(|&x| {
+            let bits = |y: $ty| (y, y.is_sign_negative());
+            format!("{}", x).parse::<$ty>() == Ok(x) &&
+            format!("{:?}", x).parse::<$ty>().map(&bits) == Ok(bits(x)) &&
+            format!("{:e}", x).parse::<$ty>() == Ok(x)
+        })
+    })
+}
+
+#[test]
+fn shortest_is_shortest() {
+    check("f64_shortest_is_shortest", 10_000, |&FiniteF64(x)| is_shortest!(f64, x));
+    check("f32_shortest_is_shortest", 10_000, |&FiniteF32(x)| is_shortest!(f32, x));
+}
+
+#[test]
+fn display_round_trip() {
+    check("f64_display_round_trip", 10_000, |&FiniteF64(x)| formats_round_trip!(f64, x));
+    check("f32_display_round_trip", 10_000, |&FiniteF32(x)| formats_round_trip!(f32, x));
+}
+
+#[test]
+fn shortest_literals() {
+    // Powers of two with a closer neighbour below, subnormals, the extremes,
+    // and decimals halfway between floats.
+    for &x in &[0.0, 0.1, 0.3, 1.0, 1e23, 5e-324, 1e-323, 2.2250738585072014e-308,
+                2.2250738585072009e-308, 9007199254740993.0, 1.7976931348623157e308,
+                8.41e21, 4.35, 0.3 + 0.6, 1.0 / 3.0, 2f64.powi(-1022),
+                2f64.powi(60), 2f64.powi(-60)] {
+        assert!(formats_round_trip!(f64, x), "{:e}", x);
+        assert!(is_shortest!(f64, x), "{:e}", x);
+    }
+    for &x in &[0.0, 0.1, 1.0, 1e-45, 1.17549435e-38, 3.40282347e38, 16777217.0, 7.038531e-26,
+                0.3 + 0.6, 1.0 / 3.0, 2f32.powi(-126), 2f32.powi(30)] {
+        assert!(formats_round_trip!(f32, x), "{:e}", x);
+        assert!(is_shortest!(f32, x), "{:e}", x);
+    }
+    assert_eq!(format!("{}", 0.3f64), "0.3");
+    assert_eq!(format!("{}", 0.1f32 + 0.2), "0.3");
+    assert_eq!(format!("{}", 0.1f64 + 0.2), "0.30000000000000004");
+    assert_eq!(format!("{:?}", 1e23f64), "100000000000000000000000");
+    assert_eq!(format!("{:e}", 5e-324f64), "5e-324");
+}
+
+#[test]
+fn exact_round_trip() {
+    // 17 and 9 significant digits are always enough to recover the value.
+    check("f64_exact_round_trip", 10_000, |&FiniteF64(x)| {
+        format!("{:.16e}", x).parse() == Ok(x)
+    });
+    check("f32_exact_round_trip", 10_000, |&FiniteF32(x)| {
+        format!("{:.8e}", x).parse() == Ok(x)
+    });
+}
+
+#[test]
+fn exact_monotonic() {
+    // Rounding to a fixed number of digits never reverses the order of two
+    // adjacent floats.
+    check("f64_exact_monotonic", 10_000, |&FiniteF64(x)| {
+        let y = f64_next_up(x);
+        let (a, b): (f64, f64) = (format!("{:.5e}", x).parse().unwrap(),
+                                  format!("{:.5e}", y).parse().unwrap());
+        a <= b
+    });
+    check("f32_exact_monotonic", 10_000, |&FiniteF32(x)| {
+        let y = f32_next_up(x);
+        let (a, b): (f32, f32) = (format!("{:.5e}", x).parse().unwrap(),
+                                  format!("{:.5e}", y).parse().unwrap());
+        a <= b
+    });
+}
+
+// Converts the digits and exponent returned by a strategy back into a string
+// that dec2flt accepts.
+fn digits_to_string(digits: &[u8], exp: i16) -> String {
+    format!("0.{}e{}", str::from_utf8(digits).unwrap(), exp)
+}
+
+type ShortestFn = fn(&Decoded, &mut [u8]) -> (usize, i16);
+type ExactFn = fn(&Decoded, &mut [u8], i16) -> (usize, i16);
+
+// Checks every way of formatting `x` with one strategy, returning a
+// description of the first inconsistency.
+fn check_round_trip(x: f64, shortest: ShortestFn, exact: ExactFn) -> Result<(), String> {
+    let decoded = decode_finite(x);
+    let mut buf = [0; 1024];
+
+    let (len, k) = shortest(&decoded, &mut buf[..MAX_SIG_DIGITS]);
+    let s = digits_to_string(&buf[..len], k);
+    if s.parse() != Ok(x) {
+        return Err(format!("shortest: {} parsed as {:?}", s, s.parse::<f64>()));
+    }
+
+    // 17 digits always recover the value, and with up to 15 digits, every
+    // decimal is the correctly rounded form of the float closest to it.
+    for ndigits in 1..18 {
+        let (len, k) = exact(&decoded, &mut buf[..ndigits], i16::MIN);
+        let s = digits_to_string(&buf[..len], k);
+        let y: f64 = match s.parse() {
+            Ok(y) => y,
+            Err(e) => return Err(format!("exact({}): {} failed to parse: {:?}", ndigits, s, e)),
+        };
+        if ndigits >= 17 && y != x {
+            return Err(format!("exact({}): {} parsed as {:?}", ndigits, s, y));
+        }
+        if ndigits <= 15 {
+            let mut buf2 = [0; 17];
+            let (len2, k2) = exact(&decode_finite(y), &mut buf2[..ndigits], i16::MIN);
+            if (&buf2[..len2], k2) != (&buf[..len], k) {
+                return Err(format!("exact({}): {} parsed as {:?}, which formats as {}",
+                                   ndigits, s, y, digits_to_string(&buf2[..len2], k2)));
+            }
+        }
+    }
+
+    // Fixed mode with enough fractional digits to print the value exactly,
+    // which yields very long inputs for dec2flt.
+    let (len, k) = exact(&decoded, &mut buf, -1100);
+    let s = digits_to_string(&buf[..len], k);
+    if s.parse() != Ok(x) {
+        return Err(format!("exact fixed: {} digits with exponent {} parsed as {:?}",
+                           len, k, s.parse::<f64>()));
+    }
+    Ok(())
+}
+
+// A consistency checker between all flt2dec strategies and dec2flt. It is
+// slow, so run it with `--ignored` after changing either algorithm.
+#[test]
+#[ignore]
+fn round_trip_all_strategies() {
+    const N: usize = 100_000;
+    let strategies: [(&str, ShortestFn, ExactFn); 2] = [
+        ("dragon", dragon::format_shortest, dragon::format_exact),
+        ("grisu", grisu::format_shortest, grisu::format_exact),
+    ];
+
+    let mut rng: XorShiftRng = Rand::rand(&mut rand::thread_rng());
+    let f64_range = Range::new(0x0000_0000_0000_0001u64, 0x7ff0_0000_0000_0000);
+    let mut nfailed = 0;
+    for i in 0..N {
+        let bits: u64 = f64_range.ind_sample(&mut rng);
+        let x: f64 = unsafe { mem::transmute(bits) };
+        for &(name, shortest, exact) in &strategies {
+            if let Err(e) = check_round_trip(x, shortest, exact) {
+                nfailed += 1;
+                if nfailed <= 20 {
+                    println!("{}/{}: {} failed for {:e} ({:016x}): {}",
+                             i, N, name, x, bits, e);
+                }
+            }
+        }
+    }
+    assert!(nfailed == 0, "{} out of {} checks failed", nfailed, 2 * N);
+}
@@ -179,3 +179,8 @@ fn test_to_exact_fixed_str() {
     to_exact_fixed_str_test(format_exact);
 }
 
+#[test]
+fn test_to_exact_fixed_str_rounded() {
+    to_exact_fixed_str_rounded_test(format_exact_rounded);
+}
+
@@ -0,0 +1,85 @@
+// Copyright 2016 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use std::{f32, f64};
+use core::num::fp::{unpack, encode_normal, next_float, prev_float, Unpacked};
+
+const SOME_FLOATS: [f64; 7] = [0.1, 1.0, 33.568, 777.0e9, 12456.0e-150, f64::MIN_POSITIVE, f64::MAX];
+
+#[test]
+fn unpack_normal_invariants() {
+    for &x in &SOME_FLOATS {
+        let Unpacked { sig, k } = unpack(x);
+        assert!(sig >> 52 == 1, "significand of {:e} not normalized: {:x}", x, sig);
+        assert_eq!(encode_normal::<f64>(Unpacked::new(sig, k)), x);
+    }
+    assert_eq!(unpack(1.0f64), Unpacked::new(1 << 52, -52));
+    assert_eq!(unpack(f64::MIN_POSITIVE), Unpacked::new(1 << 52, -1074));
+    let Unpacked { sig, k } = unpack(f32::MAX);
+    assert_eq!((sig, k), ((1 << 24) - 1, 127 - 23));
+    assert_eq!(encode_normal::<f32>(Unpacked::new(sig, k)), f32::MAX);
+}
+
+#[test]
+fn unpack_subnormal_and_zero() {
+    assert_eq!(unpack(5e-324f64), Unpacked::new(1, -1074));
+    assert_eq!(unpack(0.0f64).sig, 0);
+    assert_eq!(unpack(-0.0f64).sig, 0);
+}
+
+#[test]
+#[should_panic]
+fn unpack_negative() {
+    unpack(-1.0f64);
+}
+
+#[test]
+#[should_panic]
+fn unpack_infinite() {
+    unpack(f32::INFINITY);
+}
+
+#[test]
+#[should_panic]
+fn encode_normal_unnormalized() {
+    encode_normal::<f64>(Unpacked::new(1 << 51, 0));
+}
+
+#[test]
+#[should_panic]
+fn encode_normal_exponent_too_small() {
+    encode_normal::<f64>(Unpacked::new(1 << 52, -1075));
+}
+
+#[test]
+fn next_prev_inverse() {
+    for &x in &SOME_FLOATS {
+        let y = next_float(x);
+        assert!(y > x);
+        assert_eq!(prev_float(y), x);
+        assert_eq!(next_float(prev_float(x)), x);
+    }
+    assert_eq!(next_float(-0.0f64), 5e-324);
+    assert_eq!(prev_float(f64::MIN_POSITIVE), next_float(0.0f64) * ((1u64 << 52) - 1) as f64);
+    assert_eq!(prev_float(f32::INFINITY), f32::MAX);
+    assert_eq!(next_float(f32::MAX), f32::INFINITY);
+}
+
+#[test]
+#[should_panic]
+fn prev_float_zero() {
+    prev_float(0.0f32);
+}
+
+#[test]
+#[should_panic]
+fn next_float_negative() {
+    next_float(-1.0f64);
+}
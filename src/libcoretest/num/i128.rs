@@ -0,0 +1,132 @@
+// Copyright 2016 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use core::i128::{MIN, MAX};
+use core::num::{i128, u128, Signed};
+
+fn i(x: i64) -> i128 {
+    i128::from(x)
+}
+
+fn parse(s: &str) -> i128 {
+    s.parse().unwrap()
+}
+
+#[test]
+fn test_arithmetic() {
+    assert_eq!((i(-1) + i(1)).to_parts(), (0, 0));
+    assert_eq!((i(0) - i(1)).to_parts(), (-1, !0));
+    assert_eq!((i(i64::min_value()) * i(4)).to_parts(), (-2, 0));
+    assert_eq!(i(-7) / i(2), i(-3));
+    assert_eq!(i(-7) % i(2), i(-1));
+    assert_eq!(i(7) / i(-2), i(-3));
+    assert_eq!(i(7) % i(-2), i(1));
+    let x = parse("-1000000000000000000000000000000");
+    assert_eq!(x / i(7), parse("-142857142857142857142857142857"));
+    assert_eq!(x % i(7), i(-1));
+    assert_eq!(x / x, i(1));
+    assert_eq!(MIN / i(1), MIN);
+    assert_eq!(MIN / i(2), i(-1) << 126);
+    assert_eq!(MIN % i(3), i(-2));
+    assert_eq!(-MAX, MIN + i(1));
+    assert_eq!(i(-3).pow(3), i(-27));
+    assert_eq!(i(-5).abs(), i(5));
+    assert_eq!(i(-5).signum(), i(-1));
+    assert_eq!(i(0).signum(), i(0));
+    assert_eq!(i(5).copysign(i(-1)), i(-5));
+    assert!(i(1).is_positive() && !i(0).is_positive() && MIN.is_negative());
+    assert_eq!(MIN.unsigned_abs(), u128::from_parts(1 << 63, 0));
+}
+
+#[test]
+fn test_overflow() {
+    assert_eq!(MAX.checked_add(i(1)), None);
+    assert_eq!(MIN.checked_add(i(-1)), None);
+    assert_eq!(MIN.checked_sub(i(1)), None);
+    assert_eq!(i(-1).checked_sub(MAX), Some(MIN));
+    assert_eq!(MIN.checked_mul(i(-1)), None);
+    assert_eq!(MIN.checked_mul(i(1)), Some(MIN));
+    assert_eq!((i(1) << 63).checked_mul(i(-1) << 64), Some(MIN));
+    assert_eq!((i(1) << 63).checked_mul(i(1) << 64), None);
+    assert_eq!(MIN.checked_div(i(-1)), None);
+    assert_eq!(MIN.checked_rem(i(-1)), None);
+    assert_eq!(i(1).checked_rem(i(0)), None);
+    assert_eq!(MIN.checked_neg(), None);
+    assert_eq!(MIN.wrapping_div(i(-1)), MIN);
+    assert_eq!(MIN.wrapping_rem(i(-1)), i(0));
+    assert_eq!(MAX.wrapping_add(i(1)), MIN);
+    assert_eq!(MIN.wrapping_mul(i(-1)), MIN);
+    assert_eq!(MAX.saturating_add(i(1)), MAX);
+    assert_eq!(MIN.saturating_sub(i(1)), MIN);
+    assert_eq!(MAX.saturating_mul(i(-2)), MIN);
+    assert_eq!(MIN.saturating_mul(i(-2)), MAX);
+}
+
+#[test]
+#[cfg_attr(debug_assertions, should_panic)]
+fn test_neg_overflow() {
+    assert_eq!(-MIN, MIN);
+}
+
+#[test]
+#[should_panic]
+fn test_div_overflow() {
+    let _ = MIN / i(-1);
+}
+
+#[test]
+fn test_bits() {
+    assert_eq!(MIN >> 127, i(-1));
+    assert_eq!(i(-2) >> 1, i(-1));
+    assert_eq!((MIN >> 64).to_parts(), (-1, 1 << 63));
+    assert_eq!((i(-1) << 127), MIN);
+    assert_eq!(MIN.wrapping_shr(129), MIN >> 1);
+    assert_eq!(!i(0), i(-1));
+    assert_eq!(i(-1).count_ones(), 128);
+    assert_eq!(MAX.leading_zeros(), 1);
+    assert_eq!(MIN.as_u128().as_i128(), MIN);
+    assert_eq!(MIN.as_u128() - MAX.as_u128(), u128::from(1u8));
+}
+
+#[test]
+fn test_parse() {
+    assert_eq!(parse("-170141183460469231731687303715884105728"), MIN);
+    assert_eq!(parse("170141183460469231731687303715884105727"), MAX);
+    assert!("170141183460469231731687303715884105728".parse::<i128>().is_err());
+    assert!("-170141183460469231731687303715884105729".parse::<i128>().is_err());
+    assert!("-".parse::<i128>().is_err());
+    assert_eq!(i128::from_str_radix("-80000000000000000000000000000000", 16), Ok(MIN));
+    assert_eq!(i128::from_str_radix("-zz", 36), Ok(i(-1295)));
+    assert_eq!(i128::from_ascii(b"-9223372036854775809"), Ok(i(i64::min_value()) - i(1)));
+    assert_eq!(i128::from_ascii_radix(b"-ff", 16), Ok(i(-255)));
+    assert_eq!(i128::from_str_radix_lenient("-1_000", 10), Ok(i(-1000)));
+    assert_eq!(i128::from_str_partial("-12;"), Ok((i(-12), 3)));
+    assert_eq!(i128::from_str_radix_partial("-zz!", 36), Ok((i(-1295), 3)));
+}
+
+#[test]
+fn test_format() {
+    assert_eq!(format!("{}", MIN), "-170141183460469231731687303715884105728");
+    assert_eq!(format!("{:?}", MAX), "170141183460469231731687303715884105727");
+    assert_eq!(format!("{}", i(-42)), "-42");
+    assert_eq!(format!("{:x}", i(-1)), "ffffffffffffffffffffffffffffffff");
+    let zeros: String = (0..127).map(|_| "0").collect();
+    assert_eq!(format!("{:#b}", MIN), format!("0b1{}", zeros));
+    assert_eq!(format!("{:05}", i(-42)), "-0042");
+}
+
+#[test]
+fn test_signed() {
+    fn unsigned_abs<T: Signed>(x: T) -> T::Unsigned {
+        if x.is_negative() { x.wrapping_neg().to_unsigned() } else { x.to_unsigned() }
+    }
+    assert_eq!(unsigned_abs(MIN), MIN.unsigned_abs());
+    assert_eq!(unsigned_abs(i(-3)), u128::from(3u8));
+}
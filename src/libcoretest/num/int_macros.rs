@@ -45,6 +45,24 @@ mod tests {
         assert!((-1 as $T).signum() == -1 as $T);
     }
 
+    #[test]
+    fn test_copysign() {
+        assert_eq!((3 as $T).copysign(-1), -3);
+        assert_eq!((3 as $T).copysign(5), 3);
+        assert_eq!((-3 as $T).copysign(0), 3);
+        assert_eq!((-3 as $T).copysign(-5), -3);
+        assert_eq!((0 as $T).copysign(-1), 0);
+        assert_eq!(MIN.copysign(-1), MIN);
+        assert_eq!(MAX.copysign(-1), -MAX);
+        // the sign of the result always agrees with `signum`, apart from zero
+        for &x in &[-7 as $T, -1, 1, 7] {
+            for &y in &[-2 as $T, -1, 0, 1, 2] {
+                assert_eq!(x.copysign(y).signum(), if y < 0 { -1 } else { 1 });
+                assert_eq!(x.copysign(y).abs(), x.abs());
+            }
+        }
+    }
+
     #[test]
     fn test_is_positive() {
         assert!((1 as $T).is_positive());
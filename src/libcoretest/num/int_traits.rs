@@ -0,0 +1,67 @@
+// Copyright 2016 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use core::num::{PrimInt, Signed, Unsigned};
+
+fn round_trip<T: PrimInt>(x: T) {
+    assert_eq!(T::from_le_bytes(x.to_le_bytes()), x);
+    assert_eq!(T::from_be_bytes(x.to_be_bytes()), x);
+    let mut le = x.to_le_bytes();
+    le.as_mut().reverse();
+    assert_eq!(le.as_ref(), x.to_be_bytes().as_ref());
+    assert_eq!(le.as_ref().len() as u32 * 8, T::BITS);
+}
+
+// Sums with an explicit overflow check, for any width.
+fn checked_sum<T: PrimInt>(xs: &[T]) -> Option<T> {
+    let mut total = T::ZERO;
+    for &x in xs {
+        total = match total.checked_add(x) {
+            Some(t) => t,
+            None => return None,
+        };
+    }
+    Some(total)
+}
+
+fn unsigned_abs<T: Signed>(x: T) -> T::Unsigned {
+    if x.is_negative() { x.wrapping_neg().to_unsigned() } else { x.to_unsigned() }
+}
+
+#[test]
+fn test_prim_int() {
+    round_trip(0x12u8);
+    round_trip(0x1234_5678u32);
+    round_trip(-2i64);
+    round_trip(usize::max_value());
+    assert_eq!(0x0102u16.to_be_bytes(), [1, 2]);
+    assert_eq!(0x0102u16.to_le_bytes(), [2, 1]);
+    assert_eq!(<i32 as PrimInt>::from_le_bytes([0xff; 4]), -1);
+
+    assert_eq!(<u8 as PrimInt>::MAX, 255);
+    assert_eq!(<i16 as PrimInt>::MIN, -32768);
+    assert_eq!(<isize as PrimInt>::BITS as usize, ::core::mem::size_of::<isize>() * 8);
+
+    assert_eq!(checked_sum(&[100u8, 100, 55]), Some(255));
+    assert_eq!(checked_sum(&[100u8, 100, 56]), None);
+    assert_eq!(checked_sum(&[-100i8, -28]), Some(-128));
+    assert_eq!(checked_sum::<u64>(&[]), Some(0));
+}
+
+#[test]
+fn test_signed_unsigned() {
+    assert_eq!(unsigned_abs(-128i8), 128u8);
+    assert_eq!(unsigned_abs(5i64), 5u64);
+    assert_eq!(Signed::signum(-7i32), -1);
+    assert_eq!(Signed::copysign(7i64, -1), -7);
+    assert_eq!(Unsigned::to_signed(0xffu8), -1i8);
+    assert_eq!(Unsigned::checked_next_power_of_two(200u8), None);
+    assert_eq!(Unsigned::next_power_of_two(5u32), 8);
+}
@@ -32,9 +32,23 @@ mod u16;
 mod u32;
 mod u64;
 
+mod fp;
+mod prop;
 mod flt2dec;
 mod dec2flt;
 mod bignum;
+mod total_ord;
+mod int_traits;
+mod fenv;
+mod float_exp;
+mod float_from_int;
+mod float_mul_add;
+mod float_rounding;
+mod float_sign;
+mod f128;
+mod f16;
+mod i128;
+mod u128;
 
 /// Helper function for testing numeric operations
 pub fn test_num<T>(ten: T, two: T) where
@@ -114,6 +128,84 @@ fn test_empty() {
     assert_eq!("".parse::<u8>().ok(), None);
 }
 
+#[test]
+fn test_from_str_partial() {
+    assert_eq!(u8::from_str_partial("255"), Ok((255, 3)));
+    assert_eq!(i32::from_str_partial("-12, 3"), Ok((-12, 3)));
+    assert_eq!(i32::from_str_partial("+7x"), Ok((7, 2)));
+    assert_eq!(u32::from_str_partial("12€"), Ok((12, 2)));
+    assert_eq!(i64::from_str_partial("0.5"), Ok((0, 1)));
+    assert_eq!(i32::from_str_radix_partial("ffz", 16), Ok((255, 2)));
+    assert_eq!(u64::from_str_radix_partial("1012", 2), Ok((5, 3)));
+    assert_eq!(i8::from_str_radix_partial("-80 ", 16), Ok((-128, 3)));
+
+    // Overflow is still an error, not a shorter prefix.
+    assert_eq!(u8::from_str_partial("256,"), "256".parse::<u8>().map(|x| (x, 3)));
+    assert_eq!(i8::from_str_partial("-129"), "-129".parse::<i8>().map(|x| (x, 4)));
+    assert!(u8::from_str_partial("256").is_err());
+
+    // No digits at all, with the same errors as `parse`.
+    assert_eq!(u8::from_str_partial(""), "".parse::<u8>().map(|x| (x, 0)));
+    assert_eq!(i8::from_str_partial("-"), "-".parse::<i8>().map(|x| (x, 0)));
+    assert_eq!(i8::from_str_partial("x1"), "x1".parse::<i8>().map(|x| (x, 0)));
+    assert_eq!(i8::from_str_partial("--1"), "--1".parse::<i8>().map(|x| (x, 0)));
+    assert_eq!(u8::from_str_partial("-1"), "-1".parse::<u8>().map(|x| (x, 0)));
+    assert!(u8::from_str_partial(" 1").is_err());
+}
+
+#[test]
+fn test_from_ascii() {
+    assert_eq!(u8::from_ascii(b"255"), Ok(255));
+    assert_eq!(i64::from_ascii(b"-9223372036854775808"), Ok(i64::min_value()));
+    assert_eq!(u64::from_ascii_radix(b"ffffffffffffffff", 16), Ok(u64::max_value()));
+    assert_eq!(i16::from_ascii_radix(b"+z", 36), Ok(35));
+    for s in &["", "-", "256", "-1", "1 ", "x", "+-1"] {
+        assert_eq!(u8::from_ascii(s.as_bytes()), s.parse::<u8>());
+    }
+    for s in &["-129", "128", "--1", "-"] {
+        assert_eq!(i8::from_ascii(s.as_bytes()), s.parse::<i8>());
+    }
+    assert!(u32::from_ascii(b"1\xff").is_err());
+    assert!(u32::from_ascii(b"\xef\xbc\x91").is_err());
+}
+
+#[test]
+fn test_from_str_digit_chunks() {
+    // Eight digits at a time, then one at a time, with the same results and errors.
+    assert_eq!("000000001234567890".parse::<u64>(), Ok(1234567890));
+    assert_eq!("-1234567812345678".parse::<i64>(), Ok(-1234567812345678));
+    assert_eq!("-2147483648".parse::<i32>(), Ok(i32::min_value()));
+    assert_eq!("18446744073709551615".parse::<u64>(), Ok(u64::max_value()));
+    assert_eq!("18446744073709551616".parse::<u64>(),
+               "99999999999999999999".parse::<u64>());
+    assert_eq!("1234567x".parse::<u32>(), "x".parse::<u32>());
+    assert_eq!("12345678x".parse::<u32>(), "x".parse::<u32>());
+    assert_eq!("99999999999x".parse::<u32>(), "99999999999".parse::<u32>());
+    assert_eq!("-99999999999x".parse::<i32>(), "-99999999999".parse::<i32>());
+    assert_eq!(u64::from_str_partial("1234567890123,"), Ok((1234567890123, 13)));
+    assert_eq!("123456789012345678.5".parse::<f64>(), Ok(123456789012345678.5));
+    assert_eq!("0.1234567890123456789".parse::<f64>(), Ok(0.1234567890123456789));
+}
+
+#[test]
+fn test_from_str_radix_lenient() {
+    assert_eq!(u32::from_str_radix_lenient("1_000_000", 10), Ok(1000000));
+    assert_eq!(i64::from_str_radix_lenient("-9_223_372_036_854_775_808", 10),
+               Ok(i64::min_value()));
+    assert_eq!(u16::from_str_radix_lenient("ff_ff", 16), Ok(0xffff));
+    assert_eq!(i8::from_str_radix_lenient("+1_1", 2), Ok(3));
+    assert_eq!(u8::from_str_radix_lenient("2_56", 10), "256".parse::<u8>());
+    for s in &["_1", "1_", "1__0", "-_1", "_", "1_ ", "1_x"] {
+        assert!(i32::from_str_radix_lenient(s, 10).is_err(), "{:?}", s);
+    }
+    // an underscore next to a digit of a larger radix is still invalid
+    assert!(u32::from_str_radix_lenient("1_a", 10).is_err());
+    assert!(u32::from_str_radix_lenient("1_a", 16).is_ok());
+    // without the opt-in, underscores are invalid as before
+    assert!(u32::from_str_radix("1_000", 10).is_err());
+    assert!("1_000".parse::<u32>().is_err());
+}
+
 macro_rules! test_impl_from {
     ($fn_name: ident, $Small: ty, $Large: ty) => {
         #[test]
@@ -366,3 +458,25 @@ test_impl_try_from_same_sign_err! { test_try_i32i16, i32, i16 }
 test_impl_try_from_same_sign_err! { test_try_i64i8, i64, i8 }
 test_impl_try_from_same_sign_err! { test_try_i64i16, i64, i16 }
 test_impl_try_from_same_sign_err! { test_try_i64i32, i64, i32 }
+
+#[test]
+fn test_saturating_ops() {
+    use core::num::Saturating;
+    use core::{i8, u32};
+
+    assert_eq!(Saturating(u32::MAX) + Saturating(1), Saturating(u32::MAX));
+    assert_eq!(Saturating(1u32) - Saturating(2), Saturating(0));
+    assert_eq!(Saturating(1u32 << 20) * Saturating(1 << 20), Saturating(u32::MAX));
+    assert_eq!(Saturating(-100i8) + Saturating(-100), Saturating(i8::MIN));
+    assert_eq!(Saturating(100i8) - Saturating(-100), Saturating(i8::MAX));
+    assert_eq!(Saturating(-100i8) * Saturating(2), Saturating(i8::MIN));
+    assert_eq!(Saturating(5i8) * Saturating(-3), Saturating(-15));
+
+    let mut x = Saturating(250u8);
+    x += Saturating(10);
+    assert_eq!(x, Saturating(255));
+    x -= Saturating(5);
+    x *= Saturating(2);
+    assert_eq!(x, Saturating(255));
+    assert_eq!(format!("{:?} {}", x, Saturating(-3i8)), "255 -3");
+}
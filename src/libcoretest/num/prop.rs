@@ -0,0 +1,225 @@
+// Copyright 2016 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! A tiny property testing helper for the numeric conversion tests.
+//!
+//! `check` feeds randomly generated inputs to a property and, once it finds
+//! one for which the property does not hold, repeatedly replaces it with a
+//! simpler failing input before panicking. The seed is included in the panic
+//! message so that a failure can be replayed with `check_seeded`.
+
+use std::prelude::v1::*;
+use std::fmt;
+use core::num::Float;
+use std::__rand as rand;
+use rand::{Rng, SeedableRng, XorShiftRng};
+
+/// A type whose values can be generated randomly and shrunk towards simpler
+/// values.
+pub trait Arbitrary: Clone + fmt::Debug {
+    fn arbitrary<R: Rng>(rng: &mut R) -> Self;
+
+    /// Returns candidates that are strictly simpler than `self`, most
+    /// aggressive first. Shrinking stops once this is empty.
+    fn shrink(&self) -> Vec<Self>;
+}
+
+/// Checks that `prop` holds for `n` random inputs.
+pub fn check<T, F>(name: &str, n: usize, prop: F)
+        where T: Arbitrary, F: FnMut(&T) -> bool {
+    let mut rng = rand::thread_rng();
+    let mut seed = [0; 4];
+    while seed == [0; 4] {
+        seed = [rng.gen(), rng.gen(), rng.gen(), rng.gen()];
+    }
+    check_seeded(name, seed, n, prop)
+}
+
+/// Checks that `prop` holds for `n` inputs generated from `seed`.
+pub fn check_seeded<T, F>(name: &str, seed: [u32; 4], n: usize, mut prop: F)
+        where T: Arbitrary, F: FnMut(&T) -> bool {
+    let mut rng = XorShiftRng::from_seed(seed);
+    for i in 0..n {
+        let input = T::arbitrary(&mut rng);
+        if prop(&input) {
+            continue
+        }
+
+        let mut minimal = input.clone();
+        let mut nshrinks = 0;
+        while let Some(simpler) = minimal.shrink().into_iter().find(|x| !prop(x)) {
+            minimal = simpler;
+            nshrinks += 1;
+        }
+        panic!("{}: property failed for input {}/{} (seed {:?}): {:?}, \
+                shrunk {} times to {:?}",
+               name, i, n, seed, input, nshrinks, minimal);
+    }
+}
+
+impl<A: Arbitrary, B: Arbitrary> Arbitrary for (A, B) {
+    fn arbitrary<R: Rng>(rng: &mut R) -> (A, B) {
+        (A::arbitrary(rng), B::arbitrary(rng))
+    }
+
+    fn shrink(&self) -> Vec<(A, B)> {
+        let mut candidates: Vec<_> = self.0.shrink().into_iter()
+                                         .map(|a| (a, self.1.clone())).collect();
+        candidates.extend(self.1.shrink().into_iter().map(|b| (self.0.clone(), b)));
+        candidates
+    }
+}
+
+// Candidates for shrinking `x` towards `target`: the target itself, then
+// successively smaller steps towards it.
+fn shrink_towards(x: i64, target: i64) -> Vec<i64> {
+    let mut candidates = Vec::new();
+    let mut step = x - target;
+    while step != 0 {
+        candidates.push(x - step);
+        step /= 2;
+    }
+    candidates
+}
+
+/// A decimal number `mantissa * 10^exp`, with mantissas of all lengths up to
+/// 18 digits and exponents reaching well past the limits of `f64`.
+#[derive(Copy, Clone, PartialEq, Eq)]
+pub struct Decimal {
+    pub mantissa: u64,
+    pub exp: i16,
+}
+
+impl fmt::Debug for Decimal {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}e{}", self.mantissa, self.exp)
+    }
+}
+
+impl fmt::Display for Decimal {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}e{}", self.mantissa, self.exp)
+    }
+}
+
+impl Arbitrary for Decimal {
+    fn arbitrary<R: Rng>(rng: &mut R) -> Decimal {
+        // Choosing the number of digits first gives short mantissas
+        // (which take the fast paths) the same weight as long ones.
+        let digits = rng.gen_range(1, 19);
+        Decimal {
+            mantissa: rng.gen_range(0, 10u64.pow(digits)),
+            exp: rng.gen_range(-360, 330),
+        }
+    }
+
+    fn shrink(&self) -> Vec<Decimal> {
+        let mut candidates = Vec::new();
+        // Fewer digits first, keeping the magnitude, then smaller digits.
+        if self.mantissa >= 10 {
+            candidates.push(Decimal { mantissa: self.mantissa / 10, exp: self.exp + 1 });
+        }
+        for m in shrink_towards(self.mantissa as i64, 1) {
+            if m > 0 {
+                candidates.push(Decimal { mantissa: m as u64, exp: self.exp });
+            }
+        }
+        for e in shrink_towards(self.exp as i64, 0) {
+            candidates.push(Decimal { mantissa: self.mantissa, exp: e as i16 });
+        }
+        candidates
+    }
+}
+
+// Shrinks a positive finite float given as `(biased exponent, fraction)`
+// towards 1.0 by first dropping low fraction bits and then moving the
+// exponent towards the bias.
+fn shrink_float_parts(exp: u64, frac: u64, bias: u64) -> Vec<(u64, u64)> {
+    let mut candidates = Vec::new();
+    let mut bits = 64 - frac.leading_zeros();
+    while bits > 0 {
+        let cleared = frac >> bits << bits;
+        if cleared != frac {
+            candidates.push((exp, cleared));
+        }
+        bits /= 2;
+    }
+    for e in shrink_towards(exp as i64, bias as i64) {
+        candidates.push((e as u64, frac));
+    }
+    candidates
+}
+
+/// A positive finite `f64`, with all bit patterns equally likely.
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct FiniteF64(pub f64);
+
+impl Arbitrary for FiniteF64 {
+    fn arbitrary<R: Rng>(rng: &mut R) -> FiniteF64 {
+        let bits = rng.gen_range(0x0000_0000_0000_0001u64, 0x7ff0_0000_0000_0000);
+        FiniteF64(Float::from_bits(bits))
+    }
+
+    fn shrink(&self) -> Vec<FiniteF64> {
+        let bits = self.0.to_bits();
+        let (exp, frac) = (bits >> 52, bits & ((1 << 52) - 1));
+        shrink_float_parts(exp, frac, 1023).into_iter()
+            .map(|(exp, frac)| (exp << 52) | frac)
+            .filter(|&bits| bits != 0)
+            .map(|bits| FiniteF64(Float::from_bits(bits)))
+            .filter(|x| x.0.is_finite())
+            .collect()
+    }
+}
+
+/// A positive finite `f32`, with all bit patterns equally likely.
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct FiniteF32(pub f32);
+
+impl Arbitrary for FiniteF32 {
+    fn arbitrary<R: Rng>(rng: &mut R) -> FiniteF32 {
+        let bits = rng.gen_range(0x0000_0001u32, 0x7f80_0000);
+        FiniteF32(Float::from_bits(bits as u64))
+    }
+
+    fn shrink(&self) -> Vec<FiniteF32> {
+        let bits = self.0.to_bits();
+        let (exp, frac) = (bits as u64 >> 23, bits as u64 & ((1 << 23) - 1));
+        shrink_float_parts(exp, frac, 127).into_iter()
+            .map(|(exp, frac)| ((exp << 23) | frac) as u32)
+            .filter(|&bits| bits != 0)
+            .map(|bits| FiniteF32(Float::from_bits(bits as u64)))
+            .filter(|x| x.0.is_finite())
+            .collect()
+    }
+}
+
+/// Returns the smallest float greater than the positive finite `x`.
+pub fn f64_next_up(x: f64) -> f64 {
+    assert!(x >= 0.0 && x.is_finite());
+    Float::from_bits(x.to_bits() + 1)
+}
+
+/// Returns the smallest float greater than the positive finite `x`.
+pub fn f32_next_up(x: f32) -> f32 {
+    assert!(x >= 0.0 && x.is_finite());
+    Float::from_bits(x.to_bits() as u64 + 1)
+}
+
+#[test]
+fn shrinks_to_minimal_counterexample() {
+    let result = ::std::panic::catch_unwind(|| {
+        check_seeded("mantissa_below_1000", [1, 2, 3, 4], 1000,
+                     |d: &Decimal| d.mantissa < 1000);
+    });
+    let message = result.unwrap_err();
+    let message = message.downcast_ref::<String>().unwrap();
+    assert!(message.ends_with("to 1000e0"), "unexpected message: {}", message);
+}
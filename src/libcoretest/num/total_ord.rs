@@ -0,0 +1,106 @@
+// Copyright 2016 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use core::cmp::Ordering::{Equal, Greater, Less};
+use core::hash::{Hash, Hasher, SipHasher};
+use core::num::{Float, TotalOrdF32, TotalOrdF64, f16};
+use core::{f32, f64, mem};
+
+fn hash<T: Hash>(t: &T) -> u64 {
+    let mut s = SipHasher::new();
+    t.hash(&mut s);
+    s.finish()
+}
+
+#[test]
+fn test_total_ord_f64() {
+    let other_nan: f64 = unsafe { mem::transmute(0xfff0_0000_0000_0001u64) };
+    let sorted = [f64::NEG_INFINITY, -1.0, -f64::MIN_POSITIVE, 0.0, 1e-310, 2.0,
+                  f64::INFINITY, f64::NAN];
+    for (i, &a) in sorted.iter().enumerate() {
+        for (j, &b) in sorted.iter().enumerate() {
+            assert_eq!(TotalOrdF64(a).cmp(&TotalOrdF64(b)), i.cmp(&j), "{} {}", a, b);
+        }
+    }
+
+    assert_eq!(TotalOrdF64(-0.0), TotalOrdF64(0.0));
+    assert_eq!(hash(&TotalOrdF64(-0.0)), hash(&TotalOrdF64(0.0)));
+    assert_eq!(TotalOrdF64(other_nan), TotalOrdF64(f64::NAN));
+    assert_eq!(hash(&TotalOrdF64(other_nan)), hash(&TotalOrdF64(f64::NAN)));
+    assert!(TotalOrdF64(-f64::NAN) > TotalOrdF64(f64::INFINITY));
+    assert!(TotalOrdF64(1.0) != TotalOrdF64(2.0));
+}
+
+#[test]
+fn test_total_ord_f32() {
+    let sorted = [f32::NEG_INFINITY, -1.0, 0.0, 1e-40, 2.0, f32::INFINITY, f32::NAN];
+    for (i, &a) in sorted.iter().enumerate() {
+        for (j, &b) in sorted.iter().enumerate() {
+            assert_eq!(TotalOrdF32(a).partial_cmp(&TotalOrdF32(b)), Some(i.cmp(&j)));
+        }
+    }
+    assert_eq!(TotalOrdF32(-0.0).cmp(&TotalOrdF32(0.0)), Equal);
+    assert_eq!(TotalOrdF32(-f32::NAN).cmp(&TotalOrdF32(f32::NAN)), Equal);
+    assert_eq!(TotalOrdF32(-1.0).cmp(&TotalOrdF32(-2.0)), Greater);
+    assert_eq!(TotalOrdF32(-2.0).cmp(&TotalOrdF32(-1.0)), Less);
+}
+
+#[test]
+fn test_total_ord_conversions() {
+    let x = TotalOrdF64::from(-0.0);
+    assert!(x.is_sign_negative());
+    assert_eq!(*x, 0.0);
+    let y: f64 = x.into();
+    assert_eq!(y, 0.0);
+    let z: f32 = TotalOrdF32(1.5).into();
+    assert_eq!(z, 1.5);
+    assert_eq!(format!("{:?} {}", TotalOrdF32(1.5), TotalOrdF64(0.25)), "1.5 0.25");
+}
+
+#[test]
+fn test_total_cmp_f64() {
+    let bits = [0xfff8_0000_0000_0001u64, 0xfff8_0000_0000_0000, 0xfff0_0000_0000_0001,
+                0xfff0_0000_0000_0000, 0xbff0_0000_0000_0000, 0x8000_0000_0000_0001,
+                0x8000_0000_0000_0000, 0, 1, 0x3ff0_0000_0000_0000, 0x7ff0_0000_0000_0000,
+                0x7ff0_0000_0000_0001, 0x7ff8_0000_0000_0000, 0x7ff8_0000_0000_0001];
+    for (i, &a) in bits.iter().enumerate() {
+        for (j, &b) in bits.iter().enumerate() {
+            let (x, y): (f64, f64) = (Float::from_bits(a), Float::from_bits(b));
+            assert_eq!(Float::total_cmp(&x, &y), i.cmp(&j), "{:#x} {:#x}", a, b);
+        }
+    }
+    let mut v = [3.0, -0.0, f64::NEG_INFINITY, 0.0, -3.0];
+    v.sort_by(Float::total_cmp);
+    assert_eq!(v.iter().map(|&x| Float::to_bits(x)).collect::<Vec<_>>(),
+               [0xfff0_0000_0000_0000, 0xc008_0000_0000_0000, 1 << 63, 0,
+                0x4008_0000_0000_0000]);
+}
+
+#[test]
+fn test_total_cmp_f32_f16() {
+    let bits = [0xffc0_0001u32, 0xff80_0001, 0xff80_0000, 0x8000_0000, 0, 0x3f80_0000,
+                0x7f80_0000, 0x7f80_0001, 0x7fc0_0000];
+    for (i, &a) in bits.iter().enumerate() {
+        for (j, &b) in bits.iter().enumerate() {
+            let (x, y): (f32, f32) = (Float::from_bits(a as u64), Float::from_bits(b as u64));
+            assert_eq!(Float::total_cmp(&x, &y), i.cmp(&j), "{:#x} {:#x}", a, b);
+        }
+    }
+    let bits = [0xfe01u16, 0xfc00, 0x8001, 0x8000, 0, 0x3c00, 0x7c00, 0x7c01, 0x7e00];
+    for (i, &a) in bits.iter().enumerate() {
+        for (j, &b) in bits.iter().enumerate() {
+            let (x, y) = (f16::from_bits(a), f16::from_bits(b));
+            assert_eq!(Float::total_cmp(&x, &y), i.cmp(&j), "{:#x} {:#x}", a, b);
+        }
+    }
+    assert_eq!(Float::total_cmp(&-0.0f32, &0.0), Less);
+    assert_eq!(Float::total_cmp(&f32::INFINITY, &f32::INFINITY), Equal);
+    assert_eq!(Float::total_cmp(&f32::NAN.abs(), &f32::INFINITY), Greater);
+}
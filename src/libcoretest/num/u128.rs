@@ -0,0 +1,160 @@
+// Copyright 2016 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use core::hash::{Hash, Hasher, SipHasher};
+use core::num::{u128, PrimInt};
+use core::u128::{MIN, MAX};
+
+fn u(x: u64) -> u128 {
+    u128::from(x)
+}
+
+fn repeat(s: &str, n: usize) -> String {
+    (0..n).map(|_| s).collect()
+}
+
+#[test]
+fn test_arithmetic() {
+    assert_eq!((u(!0) + u(1)).to_parts(), (1, 0));
+    assert_eq!((u128::from_parts(1, 0) - u(1)).to_parts(), (0, !0));
+    assert_eq!((u(!0) * u(!0)).to_parts(), (!0 - 1, 1));
+    assert_eq!(u(!0) * u(3), "55340232221128654845".parse().unwrap());
+
+    let a = u128::from_parts(0x0123_4567_89ab_cdef, 0xfedc_ba98_7654_3210);
+    let b = u(0x1_0000_0000_0001);
+    assert_eq!((a / b).to_parts(), (0x123, 0x4567_89ab_cccc_b975));
+    assert_eq!((a % b).to_parts(), (0, 0x30ec_a987_789b));
+    assert_eq!(a / b * b + a % b, a);
+    let e38 = u(10).pow(38);
+    assert_eq!(e38.to_parts(), (0x4b3b_4ca8_5a86_c47a, 0x098a_2240_0000_0000));
+    assert_eq!(e38 / u(10_000_000_000_000_000_007), u(0x8ac7_2304_89e7_fff9));
+    assert_eq!(e38 % u(10_000_000_000_000_000_007), u(49));
+    assert_eq!(MAX % u(10), u(5));
+    assert_eq!(u(7) / MAX, MIN);
+
+    let mut x = u(3);
+    x += u(4);
+    x *= x;
+    x -= u(9);
+    x /= u(8);
+    x %= u(4);
+    assert_eq!(x, u(1));
+}
+
+#[test]
+fn test_overflow() {
+    assert_eq!(MAX.checked_add(u(1)), None);
+    assert_eq!(MIN.checked_sub(u(1)), None);
+    assert_eq!(u128::from_parts(1, 0).checked_mul(u128::from_parts(1, 0)), None);
+    assert_eq!(u128::from_parts(1 << 63, 0).checked_mul(u(2)), None);
+    assert_eq!(u128::from_parts(1 << 62, 0).checked_mul(u(2)),
+               Some(u128::from_parts(1 << 63, 0)));
+    assert_eq!(u(1).checked_div(MIN), None);
+    assert_eq!(u(1).checked_shl(128), None);
+    assert_eq!(MAX.wrapping_add(u(2)), u(1));
+    assert_eq!(MIN.wrapping_sub(u(1)), MAX);
+    assert_eq!(MAX.wrapping_mul(MAX), u(1));
+    assert_eq!(u(1).wrapping_neg(), MAX);
+    assert_eq!(MAX.overflowing_add(u(1)), (MIN, true));
+    assert_eq!(MAX.saturating_add(u(1)), MAX);
+    assert_eq!(u(1).saturating_sub(u(2)), MIN);
+    assert_eq!(u(3).pow(80), "147808829414345923316083210206383297601".parse().unwrap());
+    assert_eq!(u(3).checked_next_power_of_two(), Some(u(4)));
+    assert_eq!(MAX.checked_next_power_of_two(), None);
+}
+
+#[test]
+#[cfg_attr(debug_assertions, should_panic)]
+fn test_add_overflow() {
+    assert_eq!(MAX + u(1), MIN);
+}
+
+#[test]
+#[cfg_attr(debug_assertions, should_panic)]
+fn test_shl_overflow() {
+    assert_eq!(u(1) << 129, u(2));
+}
+
+#[test]
+#[should_panic]
+fn test_div_by_zero() {
+    let _ = u(1) / MIN;
+}
+
+#[test]
+fn test_hash() {
+    fn hash<T: Hash>(t: &T) -> u64 {
+        let mut s = SipHasher::new();
+        t.hash(&mut s);
+        s.finish()
+    }
+    let x = u128::from_parts(1, 2);
+    assert_eq!(hash(&x), hash(&(1u64, 2u64)));
+    assert!(hash(&x) != hash(&u128::from_parts(2, 1)));
+}
+
+#[test]
+fn test_bits() {
+    assert_eq!((u(1) << 127).to_parts(), (1 << 63, 0));
+    assert_eq!((u(1) << 100 >> 37).to_parts(), (0, 1 << 63));
+    assert_eq!(u(1).wrapping_shl(129), u(2));
+    assert_eq!(u(1).rotate_right(1).to_parts(), (1 << 63, 0));
+    assert_eq!(u128::from_parts(1, 1 << 63).rotate_left(65).to_parts(), (0, 3));
+    assert_eq!((!MIN ^ u(1) & u(3) | u(4)), MAX - u(1));
+    assert_eq!(u128::from_parts(0, 1 << 40).leading_zeros(), 87);
+    assert_eq!(u128::from_parts(1 << 40, 0).trailing_zeros(), 104);
+    assert_eq!(MAX.count_ones(), 128);
+    assert_eq!(MIN.count_zeros(), 128);
+    let x = u128::from_parts(0x0f0e_0d0c_0b0a_0908, 0x0706_0504_0302_0100);
+    assert_eq!(x.to_le_bytes(), [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15]);
+    assert_eq!(u128::from_be_bytes(x.to_be_bytes()), x);
+    assert_eq!(x.swap_bytes().swap_bytes(), x);
+}
+
+#[test]
+fn test_parse() {
+    assert_eq!("340282366920938463463374607431768211455".parse(), Ok(MAX));
+    assert!("340282366920938463463374607431768211456".parse::<u128>().is_err());
+    assert_eq!("+0".parse(), Ok(MIN));
+    assert!("-1".parse::<u128>().is_err());
+    assert!("".parse::<u128>().is_err());
+    assert_eq!(u128::from_str_radix(&repeat("f", 32), 16), Ok(MAX));
+    assert_eq!(u128::from_str_radix("1000000000000000000000000", 36),
+               Ok(u(36).pow(24)));
+    assert!(u128::from_str_radix("1g", 16).is_err());
+    assert_eq!(u128::from_ascii(b"18446744073709551616"), Ok(u(1) << 64));
+    assert_eq!(u128::from_ascii_radix(b"ff", 16), Ok(u(255)));
+    assert_eq!(u128::from_str_radix_lenient("1_0000_0000_0000_0000", 16), Ok(u(1) << 64));
+    assert!(u128::from_str_radix_lenient("1__0", 16).is_err());
+    assert_eq!(u128::from_str_partial("42, 7"), Ok((u(42), 2)));
+    assert_eq!(u128::from_str_radix_partial("ffz", 16), Ok((u(255), 2)));
+}
+
+#[test]
+fn test_format() {
+    assert_eq!(format!("{}", MAX), "340282366920938463463374607431768211455");
+    assert_eq!(format!("{:?}", MIN), "0");
+    assert_eq!(format!("{:x}", MAX), repeat("f", 32));
+    assert_eq!(format!("{:#X}", u(0xabc) << 64), "0xABC0000000000000000");
+    assert_eq!(format!("{:o}", MAX), format!("3{}", repeat("7", 42)));
+    assert_eq!(format!("{:b}", MAX), repeat("1", 128));
+    assert_eq!(format!("{:>6}", u(42)), "    42");
+    assert_eq!(format!("{:+}", u(42)), "+42");
+}
+
+#[test]
+fn test_prim_int() {
+    fn sum<T: PrimInt>(xs: &[T]) -> T {
+        xs.iter().fold(T::ZERO, |acc, &x| acc + x)
+    }
+    assert_eq!(sum(&[u(!0), u(1), u(2)]).to_parts(), (1, 2));
+    assert_eq!(<u128 as PrimInt>::BITS, 128);
+    assert_eq!(<u128 as PrimInt>::MAX, MAX);
+}
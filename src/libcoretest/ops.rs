@@ -9,7 +9,9 @@
 // except according to those terms.
 
 use test::Bencher;
+use core::ops::{ControlFlow, Coroutine, CoroutineState, Try};
 use core::ops::{Range, RangeFull, RangeFrom, RangeTo};
+use core::pin::Pin;
 
 // Overhead of dtors
 
@@ -66,3 +68,67 @@ fn test_full_range() {
     // Not much to test.
     let _ = RangeFull;
 }
+
+#[test]
+fn test_control_flow() {
+    let b: ControlFlow<i32, &str> = ControlFlow::Break(3);
+    let c: ControlFlow<i32, &str> = ControlFlow::Continue("go on");
+    assert!(b.is_break() && !b.is_continue());
+    assert!(c.is_continue() && !c.is_break());
+    assert_eq!(b.break_value(), Some(3));
+    assert_eq!(b.continue_value(), None);
+    assert_eq!(c.break_value(), None);
+    assert_eq!(c.continue_value(), Some("go on"));
+}
+
+#[test]
+fn test_try_branch() {
+    assert_eq!(Some(1).branch().continue_value(), Some(1));
+    assert!(None::<i32>.branch().is_break());
+    assert_eq!(Ok::<i32, ()>(2).branch().continue_value(), Some(2));
+    assert!(Err::<i32, ()>(()).branch().is_break());
+    assert_eq!(ControlFlow::Continue::<(), i32>(3).branch().continue_value(), Some(3));
+    assert!(ControlFlow::Break::<(), i32>(()).branch().is_break());
+
+    assert_eq!(<Option<i32> as Try>::from_output(4), Some(4));
+    assert_eq!(<Result<i32, ()> as Try>::from_output(5), Ok(5));
+}
+
+// Yields the running total of the values it is resumed with, and returns
+// the number of resumptions once the total reaches `limit`.
+struct Total {
+    total: u32,
+    limit: u32,
+    steps: usize,
+}
+
+impl Coroutine<u32> for Total {
+    type Yield = u32;
+    type Return = usize;
+
+    fn resume(mut this: Pin<&mut Total>, arg: u32) -> CoroutineState<u32, usize> {
+        this.steps += 1;
+        this.total += arg;
+        if this.total >= this.limit {
+            CoroutineState::Complete(this.steps)
+        } else {
+            CoroutineState::Yielded(this.total)
+        }
+    }
+}
+
+#[test]
+fn test_coroutine_resume() {
+    let mut total = Total { total: 0, limit: 10, steps: 0 };
+    let mut pinned = Pin::new(&mut total);
+    assert_eq!(Coroutine::resume(pinned.as_mut(), 3), CoroutineState::Yielded(3));
+    assert_eq!(Coroutine::resume(pinned.as_mut(), 4), CoroutineState::Yielded(7));
+
+    // Through the forwarding impls for `&mut C` and `Pin<&mut C>`.
+    {
+        let mut by_ref = &mut *pinned;
+        assert_eq!(Coroutine::resume(Pin::new(&mut by_ref), 2), CoroutineState::Yielded(9));
+    }
+    let mut inner = pinned.as_mut();
+    assert_eq!(Coroutine::resume(Pin::new(&mut inner), 1), CoroutineState::Complete(4));
+}
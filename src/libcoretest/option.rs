@@ -130,6 +130,15 @@ fn test_or_else() {
     assert_eq!(x.or_else(|| None), None);
 }
 
+#[test]
+fn test_try_map() {
+    assert_eq!(Some("12").try_map(|s| s.parse::<u8>()), Ok(Some(12)));
+    assert!(Some("300").try_map(|s| s.parse::<u8>()).is_err());
+    assert_eq!(None.try_map(|s: &str| s.parse::<u8>()), Ok(None));
+    assert_eq!(Some(1u8).try_map(|x| x.checked_sub(1)), Some(Some(0)));
+    assert_eq!(Some(0u8).try_map(|x| x.checked_sub(1)), None);
+}
+
 #[test]
 fn test_unwrap() {
     assert_eq!(Some(1).unwrap(), 1);
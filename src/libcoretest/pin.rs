@@ -0,0 +1,65 @@
+// Copyright 2016 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use core::marker::PhantomPinned;
+use core::pin::Pin;
+
+struct Unmovable {
+    value: u32,
+    _pinned: PhantomPinned,
+}
+
+fn bump(pinned: Pin<&mut Unmovable>) -> u32 {
+    let inner = unsafe { pinned.get_unchecked_mut() };
+    inner.value += 1;
+    inner.value
+}
+
+#[test]
+fn pin_macro() {
+    let mut pinned = pin!(Unmovable { value: 0, _pinned: PhantomPinned });
+    assert_eq!(bump(pinned.as_mut()), 1);
+    assert_eq!(bump(pinned.as_mut()), 2);
+    assert_eq!(pinned.as_ref().get_ref().value, 2);
+}
+
+#[test]
+fn unpin() {
+    let mut x = 5;
+    let mut pinned = Pin::new(&mut x);
+    *pinned += 1;
+    let doubled = *pinned * 2;
+    pinned.set(doubled);
+    assert_eq!(*pinned.as_ref(), 12);
+    assert_eq!(*Pin::into_inner(pinned), 12);
+
+    let boxed = Pin::new(Box::new(1));
+    assert_eq!(*boxed, 1);
+    assert_eq!(format!("{:?} {}", boxed, boxed), "1 1");
+}
+
+static ANSWER: u32 = 42;
+
+#[test]
+fn static_ref() {
+    let pinned = Pin::static_ref(&ANSWER);
+    assert_eq!(*pinned.get_ref(), 42);
+}
+
+#[test]
+fn as_deref_mut() {
+    let mut boxed = unsafe { Pin::new_unchecked(Box::new(Unmovable {
+        value: 0,
+        _pinned: PhantomPinned,
+    })) };
+    let mut outer = Pin::new(&mut boxed);
+    assert_eq!(bump(outer.as_mut().as_deref_mut()), 1);
+    assert_eq!(bump(outer.as_deref_mut()), 2);
+}
@@ -0,0 +1,154 @@
+// Copyright 2016 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use core::ops;
+use core::range::{Range, RangeFrom, RangeInclusive};
+
+#[test]
+fn test_range_is_copy() {
+    let r = Range { start: 1, end: 4 };
+    let copy = r;
+    assert_eq!(r.into_iter().collect::<Vec<_>>(), [1, 2, 3]);
+    assert_eq!(copy.into_iter().rev().collect::<Vec<_>>(), [3, 2, 1]);
+    assert_eq!(r.iter().len(), 3);
+}
+
+#[test]
+fn test_range_contains_and_is_empty() {
+    let r = Range { start: 1, end: 4 };
+    assert!(!r.contains(0));
+    assert!(r.contains(1));
+    assert!(r.contains(3));
+    assert!(!r.contains(4));
+    assert!(!r.is_empty());
+    assert!(Range { start: 4, end: 4 }.is_empty());
+    assert!(Range { start: 5, end: 4 }.is_empty());
+
+    let r = RangeInclusive { start: 1, end: 4 };
+    assert!(r.contains(4));
+    assert!(!r.contains(5));
+    assert!(!RangeInclusive { start: 4, end: 4 }.is_empty());
+    assert!(RangeInclusive { start: 5, end: 4 }.is_empty());
+
+    let r = RangeFrom { start: 1 };
+    assert!(!r.contains(0));
+    assert!(r.contains(1000));
+}
+
+#[test]
+fn test_range_conversions() {
+    let r: Range<u8> = (2..5).into();
+    assert_eq!(r, Range { start: 2, end: 5 });
+    let legacy: ops::Range<u8> = r.into();
+    assert_eq!(legacy, 2..5);
+
+    let r: RangeFrom<u8> = (2..).into();
+    assert_eq!(r, RangeFrom { start: 2 });
+    let legacy: ops::RangeFrom<u8> = r.into();
+    assert_eq!(legacy, 2..);
+
+    let legacy: ops::RangeInclusive<u8> = RangeInclusive { start: 2, end: 5 }.into();
+    assert_eq!(legacy, ops::RangeInclusive::NonEmpty { start: 2, end: 5 });
+}
+
+#[test]
+fn test_range_iter_remainder() {
+    let mut iter = Range { start: 0, end: 5 }.into_iter();
+    assert_eq!(iter.next(), Some(0));
+    assert_eq!(iter.next_back(), Some(4));
+    assert_eq!(iter.remainder(), Range { start: 1, end: 4 });
+
+    let mut iter = RangeFrom { start: 0u32 }.into_iter();
+    assert_eq!(iter.nth(9), Some(9));
+    assert_eq!(iter.remainder(), RangeFrom { start: 10 });
+
+    let mut iter = RangeInclusive { start: 0u8, end: 255 }.into_iter();
+    assert_eq!(iter.len(), 256);
+    assert_eq!(iter.next(), Some(0));
+    assert_eq!(iter.clone().remainder(), Some(RangeInclusive { start: 1, end: 255 }));
+    assert_eq!(iter.by_ref().count(), 255);
+    assert_eq!(iter.remainder(), None);
+}
+
+#[test]
+fn test_range_index() {
+    let v = [0, 1, 2, 3, 4];
+    assert_eq!(&v[Range { start: 1, end: 3 }], &[1, 2]);
+    assert_eq!(&v[RangeFrom { start: 3 }], &[3, 4]);
+    assert_eq!(&v[RangeInclusive { start: 1, end: 3 }], &[1, 2, 3]);
+    assert_eq!(&v[RangeInclusive { start: 3, end: 2 }], &[]);
+
+    let mut v = [0, 1, 2, 3, 4];
+    v[Range { start: 0, end: 2 }].copy_from_slice(&[5, 6]);
+    v[RangeFrom { start: 4 }][0] = 7;
+    v[RangeInclusive { start: 2, end: 3 }].copy_from_slice(&[8, 9]);
+    assert_eq!(v, [5, 6, 8, 9, 7]);
+
+    let s = "hello world";
+    assert_eq!(&s[Range { start: 0, end: 5 }], "hello");
+    assert_eq!(&s[RangeFrom { start: 6 }], "world");
+    assert_eq!(&s[RangeInclusive { start: 6, end: 10 }], "world");
+}
+
+#[test]
+#[should_panic]
+fn test_range_index_out_of_bounds() {
+    let v = [0, 1, 2];
+    let _ = &v[Range { start: 1, end: 4 }];
+}
+
+#[test]
+fn test_range_slice_get() {
+    let mut v = [0, 1, 2, 3, 4];
+    assert_eq!(v.get_slice(Range { start: 1, end: 3 }), Some(&[1, 2][..]));
+    assert_eq!(v.get_slice(Range { start: 1, end: 6 }), None);
+    assert_eq!(v.get_slice(Range { start: 3, end: 2 }), None);
+    assert_eq!(v.get_slice(RangeFrom { start: 5 }), Some(&[][..]));
+    assert_eq!(v.get_slice(RangeFrom { start: 6 }), None);
+    assert_eq!(v.get_slice(RangeInclusive { start: 3, end: 4 }), Some(&[3, 4][..]));
+    assert_eq!(v.get_slice(RangeInclusive { start: 3, end: 5 }), None);
+    assert_eq!(v.get_slice(RangeInclusive { start: 0, end: usize::max_value() }), None);
+    assert_eq!(v.get_slice(RangeInclusive { start: 3, end: 2 }), Some(&[][..]));
+    assert_eq!(v.get_slice(RangeInclusive { start: 6, end: 2 }), None);
+    v.get_slice_mut(Range { start: 0, end: 2 }).unwrap().copy_from_slice(&[5, 6]);
+    assert_eq!(unsafe { v.get_slice_unchecked(RangeFrom { start: 3 }) }, &[3, 4]);
+    assert_eq!(v, [5, 6, 2, 3, 4]);
+}
+
+#[test]
+fn test_range_inclusive_empty_index() {
+    let mut v = [0, 1, 2, 3, 4];
+    assert_eq!(&v[RangeInclusive { start: 3, end: 2 }], &[]);
+    assert_eq!(&v[RangeInclusive { start: 5, end: 2 }], &[]);
+    assert_eq!(&mut v[RangeInclusive { start: 0, end: 0 }], &mut [0]);
+    let s = "hello";
+    assert_eq!(&s[RangeInclusive { start: 5, end: 2 }], "");
+    assert_eq!(&s[RangeInclusive { start: 1, end: 2 }], "el");
+}
+
+#[test]
+#[should_panic]
+fn test_range_inclusive_empty_index_out_of_bounds() {
+    let v = [0, 1, 2, 3, 4];
+    let _ = &v[RangeInclusive { start: 6, end: 2 }];
+}
+
+#[test]
+fn test_range_reexports() {
+    let _: ops::RangeTo<usize> = ::core::range::RangeTo { end: 1 };
+    let _: ops::RangeFull = ::core::range::RangeFull;
+}
+
+#[test]
+fn test_range_debug() {
+    assert_eq!(format!("{:?}", Range { start: 1, end: 2 }), "1..2");
+    assert_eq!(format!("{:?}", RangeFrom { start: 1 }), "1..");
+    assert_eq!(format!("{:?}", RangeInclusive { start: 1, end: 2 }), "1...2");
+}
@@ -8,6 +8,9 @@
 // option. This file may not be copied, modified, or distributed
 // except according to those terms.
 
+use core::result::Partial;
+use std::collections::HashSet;
+
 fn op1() -> Result<isize, &'static str> { Ok(666) }
 fn op2() -> Result<isize, &'static str> { Err("sadface") }
 
@@ -86,6 +89,35 @@ fn test_collect() {
     assert!(v == Err(1));
 }
 
+#[test]
+fn test_collect_partial() {
+    let p: Partial<Vec<isize>, ()> = (0..3).map(|x| Ok::<isize, ()>(x)).collect();
+    assert!(p.is_complete());
+    assert_eq!(p.into_result(), Ok(vec![0, 1, 2]));
+
+    let p: Partial<Vec<isize>, isize> = (0..5).map(|x| {
+        if x > 1 { Err(x) } else { Ok(x) }
+    }).collect();
+    assert_eq!(p.values(), &[0, 1]);
+    assert_eq!(p.error(), Some((2, &2)));
+    assert_eq!(p.clone().into_result(), Err((2, 2)));
+
+    // the index counts the elements, not the collected values
+    let p: Partial<HashSet<isize>, &str> = vec![Ok(1), Ok(1), Ok(2), Err("bad"), Ok(3)]
+        .into_iter().collect();
+    assert_eq!(p.into_parts(), ([1, 2].iter().cloned().collect(), Some((3, "bad"))));
+
+    let p: Partial<Vec<isize>, ()> = vec![Some(1), Some(2), None, Some(3)].into_iter().collect();
+    assert_eq!(p.into_parts(), (vec![1, 2], Some((2, ()))));
+
+    // test that it does not take more elements than it needs
+    let mut functions: [Box<Fn() -> Result<(), isize>>; 3] =
+        [box || Ok(()), box || Err(1), box || panic!()];
+
+    let p: Partial<Vec<()>, isize> = functions.iter_mut().map(|f| (*f)()).collect();
+    assert_eq!(p.into_parts(), (vec![()], Some((1, 1))));
+}
+
 #[test]
 fn test_fmt_default() {
     let ok: Result<isize, &'static str> = Ok(100);
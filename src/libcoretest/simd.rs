@@ -0,0 +1,122 @@
+// Copyright 2016 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use core::simd::*;
+
+#[test]
+fn test_lanes() {
+    let v = i32x4::new(1, 2, 3, 4);
+    assert_eq!(i32x4::lanes(), 4);
+    assert_eq!(<u8x16 as Simd>::lanes(), 16);
+    assert_eq!(v.to_array(), [1, 2, 3, 4]);
+    assert_eq!(v.extract(2), 3);
+    assert_eq!(v.replace(0, 9).to_array(), [9, 2, 3, 4]);
+    assert_eq!(i32x4::splat(7), i32x4::from_array([7; 4]));
+    assert_eq!(format!("{:?}", v), "i32x4(1, 2, 3, 4)");
+}
+
+#[test]
+#[should_panic]
+fn test_extract_out_of_bounds() {
+    f64x2::splat(1.0).extract(2);
+}
+
+#[test]
+fn test_arithmetic() {
+    let a = f32x4::new(1.0, 2.0, 3.0, 4.0);
+    let b = f32x4::splat(2.0);
+    assert_eq!((a + b).to_array(), [3.0, 4.0, 5.0, 6.0]);
+    assert_eq!((a - b).to_array(), [-1.0, 0.0, 1.0, 2.0]);
+    assert_eq!((a * b).to_array(), [2.0, 4.0, 6.0, 8.0]);
+    assert_eq!((a / b).to_array(), [0.5, 1.0, 1.5, 2.0]);
+    assert_eq!((-a).to_array(), [-1.0, -2.0, -3.0, -4.0]);
+
+    let x = u8x16::splat(200);
+    assert_eq!((x + x).to_array(), [144; 16]);
+    assert_eq!((-i32x4::new(1, -2, 0, 4)).to_array(), [-1, 2, 0, -4]);
+    assert_eq!((u32x4::splat(1) << u32x4::new(0, 1, 2, 31)).to_array(), [1, 2, 4, 1 << 31]);
+    assert_eq!((i16x8::splat(-8) >> i16x8::splat(2)).to_array(), [-2; 8]);
+    assert_eq!((u32x4::splat(1) << u32x4::new(32, 33, 63, 1)).to_array(), [1, 2, 1 << 31, 2]);
+    assert_eq!((i8x16::splat(-128) >> i8x16::splat(9)).to_array(), [-64; 16]);
+    assert_eq!((!u64x2::new(0, !0)).to_array(), [!0, 0]);
+    assert_eq!((u32x4::splat(0b1100) & u32x4::splat(0b1010)).to_array(), [0b1000; 4]);
+}
+
+#[test]
+fn test_masks() {
+    let a = i32x4::new(1, 5, 3, 7);
+    let b = i32x4::splat(4);
+    let gt = a.gt(b);
+    assert_eq!(gt, m32x4::new(false, true, false, true));
+    assert!(gt.any() && !gt.all() && !gt.none());
+    assert!(a.eq(a).all());
+    assert!(a.ne(a).none());
+    assert!(!gt.test(0) && gt.test(1));
+    assert_eq!(gt.set(0, true), !m32x4::new(false, false, true, false));
+    assert_eq!(gt.select(a, b).to_array(), [4, 5, 4, 7]);
+    assert_eq!(a.min(b).to_array(), [1, 4, 3, 4]);
+    assert_eq!(a.max(b).to_array(), [4, 5, 4, 7]);
+
+    let x = f64x2::new(1.0, ::std::f64::NAN);
+    assert_eq!(x.eq(x), m64x2::new(true, false));
+    assert_eq!(m64x2::splat(true).select(x, f64x2::splat(0.0)).extract(0), 1.0);
+}
+
+#[test]
+fn test_swizzles() {
+    let a = u16x8::new(0, 1, 2, 3, 4, 5, 6, 7);
+    let b = u16x8::splat(9);
+    assert_eq!(a.reverse().to_array(), [7, 6, 5, 4, 3, 2, 1, 0]);
+    assert_eq!(a.interleave_low(b).to_array(), [0, 9, 1, 9, 2, 9, 3, 9]);
+    assert_eq!(a.interleave_high(b).to_array(), [4, 9, 5, 9, 6, 9, 7, 9]);
+    assert_eq!(f64x2::new(1.0, 2.0).reverse(), f64x2::new(2.0, 1.0));
+}
+
+#[test]
+fn test_load_store() {
+    let data = [1, 2, 3, 4, 5, 6];
+    let v = i64x2::load(&data[3..]);
+    assert_eq!(v.to_array(), [4, 5]);
+    let mut out = [0; 3];
+    v.store(&mut out[1..]);
+    assert_eq!(out, [0, 4, 5]);
+}
+
+#[test]
+#[should_panic]
+fn test_load_too_short() {
+    u32x4::load(&[1, 2, 3]);
+}
+
+#[test]
+fn test_gather_scatter() {
+    let data = [10.0, 11.0, 12.0, 13.0, 14.0];
+    let v = f32x4::gather(&data, [4, 0, 0, 2]);
+    assert_eq!(v.to_array(), [14.0, 10.0, 10.0, 12.0]);
+    let mask = m32x4::new(true, false, true, false);
+    let w = f32x4::gather_select(&data, [1, 100, 3, 100], mask, f32x4::splat(-1.0));
+    assert_eq!(w.to_array(), [11.0, -1.0, 13.0, -1.0]);
+
+    let mut out = [0u8; 4];
+    u8x16::new(0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15)
+        .scatter(&mut out, [0, 1, 2, 3, 0, 1, 2, 3, 0, 1, 2, 3, 0, 1, 2, 3]);
+    assert_eq!(out, [12, 13, 14, 15]);
+
+    let mut out = [0; 4];
+    i32x4::new(1, 2, 3, 4).scatter_select(&mut out, [3, 100, 1, 100], mask);
+    assert_eq!(out, [0, 3, 0, 1]);
+}
+
+#[test]
+fn test_casts() {
+    assert_eq!(f32x4::new(1.5, -2.5, 3.0, 0.0).as_i32x4().to_array(), [1, -2, 3, 0]);
+    assert_eq!(u32x4::new(1, 2, 3, 4).as_f32x4().to_array(), [1.0, 2.0, 3.0, 4.0]);
+    assert_eq!(i8x16::splat(-1).as_u8x16(), u8x16::splat(255));
+}
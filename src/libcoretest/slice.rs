@@ -10,6 +10,24 @@
 
 use core::result::Result::{Ok, Err};
 
+#[test]
+fn test_get() {
+    let mut v = [0, 1, 2, 3];
+    assert_eq!(v.get(3), Some(&3));
+    assert_eq!(v.get(4), None);
+    assert_eq!(v.get_slice(1..3), Some(&[1, 2][..]));
+    assert_eq!(v.get_slice(2..1), None);
+    assert_eq!(v.get_slice(..5), None);
+    assert_eq!(v.get_slice(4..), Some(&[][..]));
+    assert_eq!(v.get_slice(..), Some(&[0, 1, 2, 3][..]));
+    assert_eq!(v.get_slice(1...3), Some(&[1, 2, 3][..]));
+    assert_eq!(v.get_slice(...usize::max_value()), None);
+    *v.get_mut(0).unwrap() = 4;
+    v.get_slice_mut(2..).unwrap().copy_from_slice(&[5, 6]);
+    assert_eq!(unsafe { *v.get_unchecked(0) }, 4);
+    assert_eq!(unsafe { v.get_slice_unchecked_mut(1..) }, &mut [1, 5, 6]);
+}
+
 #[test]
 fn test_binary_search() {
     let b = [1, 2, 4, 6, 8, 9];
@@ -0,0 +1,109 @@
+// Copyright 2016 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use core::sync::atomic::{AtomicUsize, Ordering, ATOMIC_USIZE_INIT};
+use core::task::*;
+
+#[test]
+fn poll_map() {
+    assert_eq!(Poll::Ready(1).map(|x| x + 1), Poll::Ready(2));
+    assert_eq!(Poll::Pending::<i32>.map(|x| x + 1), Poll::Pending);
+    assert!(Poll::Ready(()).is_ready());
+    assert!(Poll::Pending::<()>.is_pending());
+    assert_eq!(Poll::from(3), Poll::Ready(3));
+
+    let ok: Poll<Result<i32, i32>> = Poll::Ready(Ok(1));
+    let err: Poll<Result<i32, i32>> = Poll::Ready(Err(1));
+    assert_eq!(ok.map_ok(|x| x * 10), Poll::Ready(Ok(10)));
+    assert_eq!(err.map_ok(|x| x * 10), Poll::Ready(Err(1)));
+    assert_eq!(err.map_err(|x| x * 10), Poll::Ready(Err(10)));
+}
+
+fn double(poll: Poll<i32>) -> Poll<i32> {
+    Poll::Ready(ready!(poll) * 2)
+}
+
+fn double_with_try(poll: Poll<i32>) -> Poll<i32> {
+    Poll::Ready(poll.ready()? * 2)
+}
+
+#[test]
+fn poll_ready() {
+    assert_eq!(double(Poll::Ready(2)), Poll::Ready(4));
+    assert_eq!(double(Poll::Pending), Poll::Pending);
+    assert_eq!(double_with_try(Poll::Ready(2)), Poll::Ready(4));
+    assert_eq!(double_with_try(Poll::Pending), Poll::Pending);
+    assert_eq!(Poll::Ready(1).ready().into_poll(), Poll::Ready(1));
+}
+
+#[test]
+fn noop_waker() {
+    let waker = Waker::noop();
+    waker.wake_by_ref();
+    let clone = waker.clone();
+    assert!(clone.will_wake(&waker));
+    clone.wake();
+
+    let cx = Context::from_waker(&waker);
+    cx.waker().wake_by_ref();
+    cx.local_waker().wake_by_ref();
+    assert!(cx.local_waker().will_wake(&LocalWaker::noop()));
+}
+
+static CLONES: AtomicUsize = ATOMIC_USIZE_INIT;
+static WAKES: AtomicUsize = ATOMIC_USIZE_INIT;
+static DROPS: AtomicUsize = ATOMIC_USIZE_INIT;
+
+unsafe fn counting_clone(data: *const ()) -> RawWaker {
+    CLONES.fetch_add(1, Ordering::SeqCst);
+    RawWaker::new(data, &COUNTING_VTABLE)
+}
+
+unsafe fn counting_wake(data: *const ()) {
+    counting_wake_by_ref(data);
+    counting_drop(data);
+}
+
+unsafe fn counting_wake_by_ref(_: *const ()) {
+    WAKES.fetch_add(1, Ordering::SeqCst);
+}
+
+unsafe fn counting_drop(_: *const ()) {
+    DROPS.fetch_add(1, Ordering::SeqCst);
+}
+
+static COUNTING_VTABLE: RawWakerVTable = RawWakerVTable::new(counting_clone,
+                                                               counting_wake,
+                                                               counting_wake_by_ref,
+                                                               counting_drop);
+
+#[test]
+fn custom_vtable() {
+    let waker = unsafe { Waker::from_raw(RawWaker::new(1 as *const (), &COUNTING_VTABLE)) };
+    waker.wake_by_ref();
+    let clone = waker.clone();
+    clone.wake();
+    assert_eq!(CLONES.load(Ordering::SeqCst), 1);
+    assert_eq!(WAKES.load(Ordering::SeqCst), 2);
+    assert_eq!(DROPS.load(Ordering::SeqCst), 1);
+
+    let local = unsafe { LocalWaker::from_raw(RawWaker::new(2 as *const (), &COUNTING_VTABLE)) };
+    {
+        let cx = Context::with_local_waker(&waker, &local);
+        assert!(cx.local_waker().will_wake(&local));
+        assert!(!cx.local_waker().will_wake(waker.as_ref()));
+        cx.local_waker().wake_by_ref();
+    }
+    assert_eq!(WAKES.load(Ordering::SeqCst), 3);
+
+    drop(local);
+    drop(waker);
+    assert_eq!(DROPS.load(Ordering::SeqCst), 3);
+}
@@ -1528,54 +1528,70 @@ impl<'a> LoweringContext<'a> {
                     // to:
                     //
                     // {
-                    //     match <expr> {
-                    //         Ok(val) => val,
-                    //         Err(err) => {
-                    //             return Err(From::from(err))
+                    //     match ::std::ops::Try::branch(<expr>) {
+                    //         ::std::ops::ControlFlow::Continue(val) => val,
+                    //         ::std::ops::ControlFlow::Break(residual) => {
+                    //             return ::std::ops::FromResidual::from_residual(residual)
                     //         }
                     //     }
                     // }
 
                     // expand <expr>
                     let sub_expr = self.lower_expr(sub_expr);
+                    let sub_expr = self.signal_block_expr(hir_vec![],
+                                                          sub_expr,
+                                                          e.span,
+                                                          hir::PopUnstableBlock,
+                                                          ThinVec::new());
+
+                    // ::std::ops::Try::branch(<expr>)
+                    let branch_expr = {
+                        let path = self.std_path(&["ops", "Try", "branch"]);
+                        let path = self.path_global(e.span, path);
+                        let branch = self.expr_path(path, ThinVec::new());
+                        self.expr_call(e.span, branch, hir_vec![sub_expr])
+                    };
 
-                    // Ok(val) => val
-                    let ok_arm = {
+                    // ControlFlow::Continue(val) => val
+                    let continue_arm = {
                         let val_ident = self.str_to_ident("val");
                         let val_pat = self.pat_ident(e.span, val_ident);
                         let val_expr = self.expr_ident(e.span, val_ident, val_pat.id);
-                        let ok_pat = self.pat_ok(e.span, val_pat);
+                        let continue_pat = self.pat_continue(e.span, val_pat);
 
-                        self.arm(hir_vec![ok_pat], val_expr)
+                        self.arm(hir_vec![continue_pat], val_expr)
                     };
 
-                    // Err(err) => return Err(From::from(err))
-                    let err_arm = {
-                        let err_ident = self.str_to_ident("err");
-                        let err_local = self.pat_ident(e.span, err_ident);
-                        let from_expr = {
-                            let path = self.std_path(&["convert", "From", "from"]);
+                    // ControlFlow::Break(residual) => return FromResidual::from_residual(residual)
+                    let break_arm = {
+                        let residual_ident = self.str_to_ident("residual");
+                        let residual_local = self.pat_ident(e.span, residual_ident);
+                        let from_residual_expr = {
+                            let path = self.std_path(&["ops", "FromResidual", "from_residual"]);
                             let path = self.path_global(e.span, path);
-                            let from = self.expr_path(path, ThinVec::new());
-                            let err_expr = self.expr_ident(e.span, err_ident, err_local.id);
+                            let from_residual = self.expr_path(path, ThinVec::new());
+                            let residual_expr = self.expr_ident(e.span,
+                                                                residual_ident,
+                                                                residual_local.id);
 
-                            self.expr_call(e.span, from, hir_vec![err_expr])
-                        };
-                        let err_expr = {
-                            let path = self.std_path(&["result", "Result", "Err"]);
-                            let path = self.path_global(e.span, path);
-                            let err_ctor = self.expr_path(path, ThinVec::new());
-                            self.expr_call(e.span, err_ctor, hir_vec![from_expr])
+                            self.expr_call(e.span, from_residual, hir_vec![residual_expr])
                         };
-                        let err_pat = self.pat_err(e.span, err_local);
+                        let break_pat = self.pat_break(e.span, residual_local);
                         let ret_expr = self.expr(e.span,
-                                                 hir::Expr_::ExprRet(Some(err_expr)),
+                                                 hir::Expr_::ExprRet(Some(from_residual_expr)),
                                                  ThinVec::new());
-                        self.arm(hir_vec![err_pat], ret_expr)
+                        self.arm(hir_vec![break_pat], ret_expr)
                     };
 
-                    return self.expr_match(e.span, sub_expr, hir_vec![err_arm, ok_arm],
-                                           hir::MatchSource::TryDesugar);
+                    let match_expr = self.expr_match(e.span,
+                                                     branch_expr,
+                                                     hir_vec![break_arm, continue_arm],
+                                                     hir::MatchSource::TryDesugar);
+                    return self.signal_block_expr(hir_vec![],
+                                                  match_expr,
+                                                  e.span,
+                                                  hir::PushUnstableBlock,
+                                                  e.attrs.clone());
                 }
 
                 ExprKind::Mac(_) => panic!("Shouldn't exist here"),
@@ -1803,15 +1819,15 @@ impl<'a> LoweringContext<'a> {
         })
     }
 
-    fn pat_ok(&mut self, span: Span, pat: P<hir::Pat>) -> P<hir::Pat> {
-        let ok = self.std_path(&["result", "Result", "Ok"]);
-        let path = self.path_global(span, ok);
+    fn pat_continue(&mut self, span: Span, pat: P<hir::Pat>) -> P<hir::Pat> {
+        let cont = self.std_path(&["ops", "ControlFlow", "Continue"]);
+        let path = self.path_global(span, cont);
         self.pat_enum(span, path, hir_vec![pat])
     }
 
-    fn pat_err(&mut self, span: Span, pat: P<hir::Pat>) -> P<hir::Pat> {
-        let err = self.std_path(&["result", "Result", "Err"]);
-        let path = self.path_global(span, err);
+    fn pat_break(&mut self, span: Span, pat: P<hir::Pat>) -> P<hir::Pat> {
+        let brk = self.std_path(&["ops", "ControlFlow", "Break"]);
+        let path = self.path_global(span, brk);
         self.pat_enum(span, path, hir_vec![pat])
     }
 
@@ -271,6 +271,7 @@ pub fn collect_language_items(session: &Session,
 
 language_item_table! {
 //  Variant name,                    Name,                      Method name;
+    BoolImplItem,                    "bool",                    bool_impl;
     CharImplItem,                    "char",                    char_impl;
     StrImplItem,                     "str",                     str_impl;
     SliceImplItem,                   "slice",                   slice_impl;
@@ -27,11 +27,21 @@ const ARM_WHITELIST: &'static [&'static str] = &[
     "vfp4\0",
 ];
 
+const AARCH64_WHITELIST: &'static [&'static str] = &[
+    "crypto\0",
+    "neon\0",
+];
+
 const X86_WHITELIST: &'static [&'static str] = &[
+    "aes\0",
     "avx\0",
     "avx2\0",
+    "avx512bw\0",
+    "avx512f\0",
     "bmi\0",
     "bmi2\0",
+    "fma\0",
+    "pclmul\0",
     "sse\0",
     "sse2\0",
     "sse3\0",
@@ -51,6 +61,7 @@ pub fn add_configuration(cfg: &mut ast::CrateConfig, sess: &Session) {
 
     let whitelist = match &*sess.target.target.arch {
         "arm" => ARM_WHITELIST,
+        "aarch64" => AARCH64_WHITELIST,
         "x86" | "x86_64" => X86_WHITELIST,
         _ => &[],
     };
@@ -3412,6 +3412,76 @@ pub fn find(name: &str) -> Option<Intrinsic> {
             output: &::U8x16,
             definition: Named("llvm.aarch64.neon.tbx4.v16i8")
         },
+        "aeseq_u8" => Intrinsic {
+            inputs: { static INPUTS: [&'static Type; 2] = [&::U8x16, &::U8x16]; &INPUTS },
+            output: &::U8x16,
+            definition: Named("llvm.aarch64.crypto.aese")
+        },
+        "aesdq_u8" => Intrinsic {
+            inputs: { static INPUTS: [&'static Type; 2] = [&::U8x16, &::U8x16]; &INPUTS },
+            output: &::U8x16,
+            definition: Named("llvm.aarch64.crypto.aesd")
+        },
+        "aesmcq_u8" => Intrinsic {
+            inputs: { static INPUTS: [&'static Type; 1] = [&::U8x16]; &INPUTS },
+            output: &::U8x16,
+            definition: Named("llvm.aarch64.crypto.aesmc")
+        },
+        "aesimcq_u8" => Intrinsic {
+            inputs: { static INPUTS: [&'static Type; 1] = [&::U8x16]; &INPUTS },
+            output: &::U8x16,
+            definition: Named("llvm.aarch64.crypto.aesimc")
+        },
+        "sha1cq_u32" => Intrinsic {
+            inputs: { static INPUTS: [&'static Type; 3] = [&::U32x4, &::U32, &::U32x4]; &INPUTS },
+            output: &::U32x4,
+            definition: Named("llvm.aarch64.crypto.sha1c")
+        },
+        "sha1pq_u32" => Intrinsic {
+            inputs: { static INPUTS: [&'static Type; 3] = [&::U32x4, &::U32, &::U32x4]; &INPUTS },
+            output: &::U32x4,
+            definition: Named("llvm.aarch64.crypto.sha1p")
+        },
+        "sha1mq_u32" => Intrinsic {
+            inputs: { static INPUTS: [&'static Type; 3] = [&::U32x4, &::U32, &::U32x4]; &INPUTS },
+            output: &::U32x4,
+            definition: Named("llvm.aarch64.crypto.sha1m")
+        },
+        "sha1h_u32" => Intrinsic {
+            inputs: { static INPUTS: [&'static Type; 1] = [&::U32]; &INPUTS },
+            output: &::U32,
+            definition: Named("llvm.aarch64.crypto.sha1h")
+        },
+        "sha1su0q_u32" => Intrinsic {
+            inputs: { static INPUTS: [&'static Type; 3] = [&::U32x4, &::U32x4, &::U32x4]; &INPUTS },
+            output: &::U32x4,
+            definition: Named("llvm.aarch64.crypto.sha1su0")
+        },
+        "sha1su1q_u32" => Intrinsic {
+            inputs: { static INPUTS: [&'static Type; 2] = [&::U32x4, &::U32x4]; &INPUTS },
+            output: &::U32x4,
+            definition: Named("llvm.aarch64.crypto.sha1su1")
+        },
+        "sha256hq_u32" => Intrinsic {
+            inputs: { static INPUTS: [&'static Type; 3] = [&::U32x4, &::U32x4, &::U32x4]; &INPUTS },
+            output: &::U32x4,
+            definition: Named("llvm.aarch64.crypto.sha256h")
+        },
+        "sha256h2q_u32" => Intrinsic {
+            inputs: { static INPUTS: [&'static Type; 3] = [&::U32x4, &::U32x4, &::U32x4]; &INPUTS },
+            output: &::U32x4,
+            definition: Named("llvm.aarch64.crypto.sha256h2")
+        },
+        "sha256su0q_u32" => Intrinsic {
+            inputs: { static INPUTS: [&'static Type; 2] = [&::U32x4, &::U32x4]; &INPUTS },
+            output: &::U32x4,
+            definition: Named("llvm.aarch64.crypto.sha256su0")
+        },
+        "sha256su1q_u32" => Intrinsic {
+            inputs: { static INPUTS: [&'static Type; 3] = [&::U32x4, &::U32x4, &::U32x4]; &INPUTS },
+            output: &::U32x4,
+            definition: Named("llvm.aarch64.crypto.sha256su1")
+        },
         _ => return None,
     })
 }
@@ -56,6 +56,7 @@ static I8x16: Type = Type::Vector(&I8, None, 16);
 static U8x16: Type = Type::Vector(&U8, None, 16);
 static I8x32: Type = Type::Vector(&I8, None, 32);
 static U8x32: Type = Type::Vector(&U8, None, 32);
+static U8x64: Type = Type::Vector(&U8, None, 64);
 
 static I16x4: Type = Type::Vector(&I16, None, 4);
 static U16x4: Type = Type::Vector(&U16, None, 4);
@@ -70,6 +71,8 @@ static I32x4: Type = Type::Vector(&I32, None, 4);
 static U32x4: Type = Type::Vector(&U32, None, 4);
 static I32x8: Type = Type::Vector(&I32, None, 8);
 static U32x8: Type = Type::Vector(&U32, None, 8);
+static I32x16: Type = Type::Vector(&I32, None, 16);
+static U32x16: Type = Type::Vector(&U32, None, 16);
 
 static I64x1: Type = Type::Vector(&I64, None, 1);
 static U64x1: Type = Type::Vector(&U64, None, 1);
@@ -77,6 +80,8 @@ static I64x2: Type = Type::Vector(&I64, None, 2);
 static U64x2: Type = Type::Vector(&U64, None, 2);
 static I64x4: Type = Type::Vector(&I64, None, 4);
 static U64x4: Type = Type::Vector(&U64, None, 4);
+static I64x8: Type = Type::Vector(&I64, None, 8);
+static U64x8: Type = Type::Vector(&U64, None, 8);
 
 static F32x2: Type = Type::Vector(&F32, None, 2);
 static F32x4: Type = Type::Vector(&F32, None, 4);
@@ -22,6 +22,36 @@ use IntrinsicDef::Named;
 pub fn find(name: &str) -> Option<Intrinsic> {
     if !name.starts_with("x86") { return None }
     Some(match &name["x86".len()..] {
+        "_mm_aesenc_si128" => Intrinsic {
+            inputs: { static INPUTS: [&'static Type; 2] = [&::I64x2, &::I64x2]; &INPUTS },
+            output: &::I64x2,
+            definition: Named("llvm.x86.aesni.aesenc")
+        },
+        "_mm_aesenclast_si128" => Intrinsic {
+            inputs: { static INPUTS: [&'static Type; 2] = [&::I64x2, &::I64x2]; &INPUTS },
+            output: &::I64x2,
+            definition: Named("llvm.x86.aesni.aesenclast")
+        },
+        "_mm_aesdec_si128" => Intrinsic {
+            inputs: { static INPUTS: [&'static Type; 2] = [&::I64x2, &::I64x2]; &INPUTS },
+            output: &::I64x2,
+            definition: Named("llvm.x86.aesni.aesdec")
+        },
+        "_mm_aesdeclast_si128" => Intrinsic {
+            inputs: { static INPUTS: [&'static Type; 2] = [&::I64x2, &::I64x2]; &INPUTS },
+            output: &::I64x2,
+            definition: Named("llvm.x86.aesni.aesdeclast")
+        },
+        "_mm_aesimc_si128" => Intrinsic {
+            inputs: { static INPUTS: [&'static Type; 1] = [&::I64x2]; &INPUTS },
+            output: &::I64x2,
+            definition: Named("llvm.x86.aesni.aesimc")
+        },
+        "_mm_aeskeygenassist_si128" => Intrinsic {
+            inputs: { static INPUTS: [&'static Type; 2] = [&::I64x2, &::I32_8]; &INPUTS },
+            output: &::I64x2,
+            definition: Named("llvm.x86.aesni.aeskeygenassist")
+        },
         "_mm256_abs_epi8" => Intrinsic {
             inputs: { static INPUTS: [&'static Type; 1] = [&::I8x32]; &INPUTS },
             output: &::I8x32,
@@ -397,6 +427,21 @@ pub fn find(name: &str) -> Option<Intrinsic> {
             output: &::U16x16,
             definition: Named("llvm.x86.avx2.psubus.w")
         },
+        "_mm512_mul_epi32" => Intrinsic {
+            inputs: { static INPUTS: [&'static Type; 2] = [&::I32x16, &::I32x16]; &INPUTS },
+            output: &::I64x8,
+            definition: Named("llvm.x86.avx512.pmul.dq.512")
+        },
+        "_mm512_mul_epu32" => Intrinsic {
+            inputs: { static INPUTS: [&'static Type; 2] = [&::U32x16, &::U32x16]; &INPUTS },
+            output: &::U64x8,
+            definition: Named("llvm.x86.avx512.pmulu.dq.512")
+        },
+        "_mm512_sad_epu8" => Intrinsic {
+            inputs: { static INPUTS: [&'static Type; 2] = [&::U8x64, &::U8x64]; &INPUTS },
+            output: &::U64x8,
+            definition: Named("llvm.x86.avx512.psad.bw.512")
+        },
         "_mm256_addsub_ps" => Intrinsic {
             inputs: { static INPUTS: [&'static Type; 2] = [&::F32x8, &::F32x8]; &INPUTS },
             output: &::F32x8,
@@ -872,6 +917,11 @@ pub fn find(name: &str) -> Option<Intrinsic> {
             output: &::F64x4,
             definition: Named("llvm.x86.fma.vfnmsub.pd.256")
         },
+        "_mm_clmulepi64_si128" => Intrinsic {
+            inputs: { static INPUTS: [&'static Type; 3] = [&::I64x2, &::I64x2, &::I32_8]; &INPUTS },
+            output: &::I64x2,
+            definition: Named("llvm.x86.pclmulqdq")
+        },
         "_mm_adds_epi8" => Intrinsic {
             inputs: { static INPUTS: [&'static Type; 2] = [&::I8x16, &::I8x16]; &INPUTS },
             output: &::I8x16,
@@ -86,6 +86,13 @@ impl<'cx, 'tcx> OrphanChecker<'cx, 'tcx> {
                             Err(msg) => self.tcx.sess.span_fatal(item.span, &msg),
                         }
                     }
+                    ty::TyBool => {
+                        self.check_primitive_impl(def_id,
+                                                  self.tcx.lang_items.bool_impl(),
+                                                  "bool",
+                                                  "bool",
+                                                  item.span);
+                    }
                     ty::TyChar => {
                         self.check_primitive_impl(def_id,
                                                   self.tcx.lang_items.char_impl(),
@@ -436,6 +436,28 @@ impl char {
         C::encode_utf16(self)
     }
 
+    /// Writes this character as UTF-16 into the start of `dst`, and returns
+    /// the part of `dst` that it takes up.
+    ///
+    /// Returns `None`, and leaves `dst` alone, if `dst` is shorter than
+    /// `len_utf16()`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(encode_utf16_into)]
+    ///
+    /// let mut buf = [0; 2];
+    /// assert_eq!('ß'.encode_utf16_checked(&mut buf).unwrap(), [0xdf]);
+    /// assert_eq!('𝕊'.encode_utf16_checked(&mut buf).unwrap(), [0xd835, 0xdd4a]);
+    /// assert_eq!('𝕊'.encode_utf16_checked(&mut buf[1..]), None);
+    /// ```
+    #[unstable(feature = "encode_utf16_into", issue = "0")]
+    #[inline]
+    pub fn encode_utf16_checked(self, dst: &mut [u16]) -> Option<&mut [u16]> {
+        C::encode_utf16_checked(self, dst)
+    }
+
     /// Returns true if this `char` is an alphabetic code point, and false if not.
     ///
     /// # Examples
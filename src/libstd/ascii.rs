@@ -16,6 +16,7 @@ use prelude::v1::*;
 
 use mem;
 use ops::Range;
+use ptr;
 
 /// Extension methods for ASCII-subset only operations on string slices.
 ///
@@ -221,27 +222,107 @@ impl AsciiExt for [u8] {
         return me
     }
 
-    #[inline]
     fn eq_ignore_ascii_case(&self, other: &[u8]) -> bool {
         self.len() == other.len() &&
-        self.iter().zip(other).all(|(a, b)| {
-            a.eq_ignore_ascii_case(b)
+        self.chunks(WORD_BYTES).zip(other.chunks(WORD_BYTES)).all(|(a, b)| {
+            if a.len() == WORD_BYTES {
+                word_to_ascii_lowercase(read_word(a)) == word_to_ascii_lowercase(read_word(b))
+            } else {
+                a.iter().zip(b).all(|(a, b)| a.eq_ignore_ascii_case(b))
+            }
         })
     }
 
     fn make_ascii_uppercase(&mut self) {
-        for byte in self {
-            byte.make_ascii_uppercase();
+        for chunk in self.chunks_mut(WORD_BYTES) {
+            if chunk.len() == WORD_BYTES {
+                let word = word_to_ascii_uppercase(read_word(chunk));
+                write_word(chunk, word);
+            } else {
+                for byte in chunk {
+                    byte.make_ascii_uppercase();
+                }
+            }
         }
     }
 
     fn make_ascii_lowercase(&mut self) {
-        for byte in self {
-            byte.make_ascii_lowercase();
+        for chunk in self.chunks_mut(WORD_BYTES) {
+            if chunk.len() == WORD_BYTES {
+                let word = word_to_ascii_lowercase(read_word(chunk));
+                write_word(chunk, word);
+            } else {
+                for byte in chunk {
+                    byte.make_ascii_lowercase();
+                }
+            }
         }
     }
 }
 
+// The case conversions and comparisons of byte slices work on a word at a
+// time, and only fall back to the lookup tables for the tail that doesn't
+// fill a whole word.
+
+#[cfg(target_pointer_width = "32")]
+const WORD_BYTES: usize = 4;
+#[cfg(target_pointer_width = "64")]
+const WORD_BYTES: usize = 8;
+
+// use truncation to fit u64 into usize
+const LO_USIZE: usize = 0x01010101_01010101u64 as usize;
+const HI_USIZE: usize = 0x80808080_80808080u64 as usize;
+
+/// Reads the first `WORD_BYTES` bytes of `bytes` as a word, whatever their
+/// alignment.
+#[inline]
+fn read_word(bytes: &[u8]) -> usize {
+    assert!(bytes.len() >= WORD_BYTES);
+    let mut word = 0;
+    unsafe {
+        ptr::copy_nonoverlapping(bytes.as_ptr(),
+                                 &mut word as *mut usize as *mut u8,
+                                 WORD_BYTES);
+    }
+    word
+}
+
+/// Writes `word` over the first `WORD_BYTES` bytes of `bytes`.
+#[inline]
+fn write_word(bytes: &mut [u8], word: usize) {
+    assert!(bytes.len() >= WORD_BYTES);
+    unsafe {
+        ptr::copy_nonoverlapping(&word as *const usize as *const u8,
+                                 bytes.as_mut_ptr(),
+                                 WORD_BYTES);
+    }
+}
+
+/// Returns a word with `0x20` set in each byte of `word` that lies in
+/// `first...last`, and zero elsewhere.
+///
+/// Adding `0x80 - first` to the low seven bits of a byte sets its top bit
+/// exactly when the byte is at least `first`, and can't carry into the next
+/// byte. Bytes with their top bit set are never ASCII letters, and are
+/// masked out at the end.
+#[inline]
+fn ascii_range_mask(word: usize, first: u8, last: u8) -> usize {
+    let low_bits = word & !HI_USIZE;
+    let at_least_first = low_bits + LO_USIZE * (0x80 - first as usize);
+    let above_last = low_bits + LO_USIZE * (0x80 - last as usize - 1);
+    (at_least_first & !above_last & !word & HI_USIZE) >> 2
+}
+
+#[inline]
+fn word_to_ascii_uppercase(word: usize) -> usize {
+    word ^ ascii_range_mask(word, b'a', b'z')
+}
+
+#[inline]
+fn word_to_ascii_lowercase(word: usize) -> usize {
+    word ^ ascii_range_mask(word, b'A', b'Z')
+}
+
 #[stable(feature = "rust1", since = "1.0.0")]
 impl AsciiExt for u8 {
     type Owned = u8;
@@ -564,6 +645,47 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_word_at_a_time_case() {
+        // Every byte value, at every offset relative to the word boundaries
+        // and with every tail length.
+        let all: Vec<u8> = (0..512).map(|b| b as u8).collect();
+        for start in 0..16 {
+            for end in all.len() - 16..all.len() {
+                let bytes = &all[start..end];
+
+                let mut upper = bytes.to_vec();
+                upper.make_ascii_uppercase();
+                let mut lower = bytes.to_vec();
+                lower.make_ascii_lowercase();
+                for (i, &b) in bytes.iter().enumerate() {
+                    assert_eq!(upper[i], ASCII_UPPERCASE_MAP[b as usize]);
+                    assert_eq!(lower[i], ASCII_LOWERCASE_MAP[b as usize]);
+                }
+
+                assert!(bytes.eq_ignore_ascii_case(&upper));
+                assert!(lower.eq_ignore_ascii_case(&upper[..]));
+            }
+        }
+    }
+
+    #[test]
+    fn test_word_at_a_time_eq_ignore_ascii_case() {
+        let a = b"content-type: text/html; charset=utf-8";
+        for i in 0..a.len() {
+            let mut b = a.to_vec();
+            b.make_ascii_uppercase();
+            assert!(a.eq_ignore_ascii_case(&b));
+            // `@` and `[` are next to the letters, and `\xc1` is `A` with
+            // the top bit set: none of them may compare equal to a letter.
+            for &c in &[b'@', b'[', b'`', b'{', 0xc1, 0xe1] {
+                b[i] = c;
+                assert_eq!(a.eq_ignore_ascii_case(&b), a[i] == c, "{} {}", i, c);
+            }
+        }
+        assert!(!b"abc".eq_ignore_ascii_case(b"abcd"));
+    }
+
     #[test]
     fn inference_works() {
         let x = "a".to_string();
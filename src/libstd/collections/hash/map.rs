@@ -13,6 +13,7 @@ use self::VacantEntryState::*;
 
 use borrow::Borrow;
 use cmp::max;
+use env;
 use fmt::{self, Debug};
 use hash::{Hash, Hasher, BuildHasher, SipHasher13};
 use iter::FromIterator;
@@ -41,12 +42,29 @@ const INITIAL_CAPACITY: usize = 1 << INITIAL_LOG2_CAP; // 2^5
 /// This behavior is characterized by the following condition:
 ///
 /// - if size > 0.909 * capacity: grow the map
+///
+/// `reserve_with_max_load` can lower the load factor to
+/// `max_load_percent / 100`, which then applies to every later resize.
 #[derive(Clone)]
-struct DefaultResizePolicy;
+struct DefaultResizePolicy {
+    // The map grows when `size * num / den > capacity`.
+    num: usize,
+    den: usize,
+}
 
 impl DefaultResizePolicy {
     fn new() -> DefaultResizePolicy {
-        DefaultResizePolicy
+        DefaultResizePolicy { num: 11, den: 10 }
+    }
+
+    /// A policy with a load factor of at most `max_load_percent` percent. The
+    /// default load factor is used if it is lower.
+    fn with_max_load(max_load_percent: usize) -> DefaultResizePolicy {
+        if max_load_percent * 11 >= 1000 {
+            DefaultResizePolicy::new()
+        } else {
+            DefaultResizePolicy { num: 100, den: max_load_percent }
+        }
     }
 
     #[inline]
@@ -55,7 +73,7 @@ impl DefaultResizePolicy {
         // on capacity:
         //
         // - if `cap < size * 1.1`: grow the map
-        usable_size * 11 / 10
+        usable_size * self.num / self.den
     }
 
     /// An inverse of `min_capacity`, approximately.
@@ -69,23 +87,38 @@ impl DefaultResizePolicy {
         // division.
         //
         // This doesn't have to be checked for overflow since allocation size
-        // in bytes will overflow earlier than multiplication by 10.
+        // in bytes will overflow earlier than multiplication by 100.
         //
         // As per https://github.com/rust-lang/rust/pull/30991 this is updated
         // to be: (cap * den + den - 1) / num
-        (cap * 10 + 10 - 1) / 11
+        (cap * self.den + self.den - 1) / self.num
     }
 }
 
 #[test]
 fn test_resize_policy() {
-    let rp = DefaultResizePolicy;
+    let rp = DefaultResizePolicy::new();
     for n in 0..1000 {
         assert!(rp.min_capacity(rp.usable_capacity(n)) <= n);
         assert!(rp.usable_capacity(rp.min_capacity(n)) <= n);
     }
 }
 
+#[test]
+fn test_resize_policy_with_max_load() {
+    for load in 1..101 {
+        let rp = DefaultResizePolicy::with_max_load(load);
+        for n in 0..1000 {
+            assert!(rp.min_capacity(rp.usable_capacity(n)) <= n);
+            assert!(rp.usable_capacity(rp.min_capacity(n)) <= n);
+        }
+    }
+    let rp = DefaultResizePolicy::with_max_load(50);
+    assert_eq!((rp.min_capacity(100), rp.usable_capacity(256)), (200, 128));
+    let rp = DefaultResizePolicy::with_max_load(95);
+    assert_eq!(rp.min_capacity(100), DefaultResizePolicy::new().min_capacity(100));
+}
+
 // The main performance trick in this hashmap is called Robin Hood Hashing.
 // It gains its excellent performance from one essential operation:
 //
@@ -400,22 +433,22 @@ fn search_hashed<K, V, M, F>(table: M,
     }
 }
 
-fn pop_internal<K, V>(starting_bucket: FullBucketMut<K, V>) -> (K, V) {
+fn pop_internal<K, V>(starting_bucket: FullBucketMut<K, V>) -> (K, V, &mut RawTable<K, V>) {
     let (empty, retkey, retval) = starting_bucket.take();
     let mut gap = match empty.gap_peek() {
-        Some(b) => b,
-        None => return (retkey, retval)
+        Ok(b) => b,
+        Err(b) => return (retkey, retval, b.into_table())
     };
 
     while gap.full().displacement() != 0 {
         gap = match gap.shift() {
-            Some(b) => b,
-            None => break
+            Ok(b) => b,
+            Err(b) => return (retkey, retval, b.into_table())
         };
     }
 
     // Now we've done all our shifting. Return the value we grabbed earlier.
-    (retkey, retval)
+    (retkey, retval, gap.into_table())
 }
 
 /// Perform robin hood bucket stealing at the given `bucket`. You must
@@ -423,12 +456,13 @@ fn pop_internal<K, V>(starting_bucket: FullBucketMut<K, V>) -> (K, V) {
 /// to recalculate it.
 ///
 /// `hash`, `k`, and `v` are the elements to "robin hood" into the hashtable.
+/// Returns the given `bucket`, which then holds them.
 fn robin_hood<'a, K: 'a, V: 'a>(bucket: FullBucketMut<'a, K, V>,
                         mut ib: usize,
                         mut hash: SafeHash,
                         mut key: K,
                         mut val: V)
-                        -> &'a mut V {
+                        -> FullBucketMut<'a, K, V> {
     let starting_index = bucket.index();
     let size = bucket.table().size();
     // Save the *starting point*.
@@ -452,14 +486,14 @@ fn robin_hood<'a, K: 'a, V: 'a>(bucket: FullBucketMut<'a, K, V>,
                 Empty(bucket) => {
                     // Found a hole!
                     let bucket = bucket.put(hash, key, val);
-                    // Now that it's stolen, just read the value's pointer
-                    // right out of the table! Go back to the *starting point*.
+                    // Now that it's stolen, go back to the *starting point*,
+                    // which holds the key and value we were given.
                     //
                     // This use of `into_table` is misleading. It turns the
                     // bucket, which is a FullBucket on top of a
                     // FullBucketMut, into just one FullBucketMut. The "table"
                     // refers to the inner FullBucketMut in this context.
-                    return bucket.into_table().into_mut_refs().1;
+                    return bucket.into_table();
                 },
                 Full(bucket) => bucket
             };
@@ -645,6 +679,28 @@ impl<K, V, S> HashMap<K, V, S>
         self.resize_policy.usable_capacity(self.table.capacity())
     }
 
+    /// Returns the number of bytes allocated for the elements of the map.
+    ///
+    /// This is the memory used by the buckets, including the empty ones and
+    /// the hashes stored next to the keys and values, but not including any
+    /// memory owned by the keys and values themselves.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(hashmap_capacity_policy)]
+    /// use std::collections::HashMap;
+    ///
+    /// let mut map: HashMap<u32, u32> = HashMap::new();
+    /// assert_eq!(map.allocation_size(), 0);
+    /// map.insert(1, 2);
+    /// assert!(map.allocation_size() >= map.capacity() * 16);
+    /// ```
+    #[unstable(feature = "hashmap_capacity_policy", issue = "0")]
+    pub fn allocation_size(&self) -> usize {
+        self.table.allocation_size()
+    }
+
     /// Reserves capacity for at least `additional` more elements to be inserted
     /// in the `HashMap`. The collection may reserve more space to avoid
     /// frequent reallocations.
@@ -675,6 +731,45 @@ impl<K, V, S> HashMap<K, V, S>
         }
     }
 
+    /// Reserves capacity for at least `additional` more elements to be inserted
+    /// in the `HashMap`, and from now on keeps the map at most
+    /// `max_load_percent` percent full.
+    ///
+    /// A lower load makes lookups faster at the cost of memory. The new load
+    /// factor applies to every later growth and to `shrink_to`, and
+    /// `capacity` reports how many elements fit under it. The map never gets
+    /// fuller than its default load factor of about 90%, so values above that
+    /// restore the default.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `max_load_percent` is zero or greater than 100, or if the new
+    /// allocation size overflows `usize`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(hashmap_capacity_policy)]
+    /// use std::collections::HashMap;
+    ///
+    /// let mut map: HashMap<&str, isize> = HashMap::new();
+    /// map.reserve_with_max_load(100, 50);
+    /// assert!(map.capacity() >= 100);
+    /// assert!(map.allocation_size() >= 200 * 16);
+    /// ```
+    #[unstable(feature = "hashmap_capacity_policy", issue = "0")]
+    pub fn reserve_with_max_load(&mut self, additional: usize, max_load_percent: usize) {
+        assert!(max_load_percent > 0 && max_load_percent <= 100,
+               "load factor out of range");
+
+        // `min_capacity` multiplies the size by up to 100.
+        let new_size = self.len().checked_add(additional).expect("capacity overflow");
+        assert!(new_size.checked_mul(100).is_some(), "capacity overflow");
+
+        self.resize_policy = DefaultResizePolicy::with_max_load(max_load_percent);
+        self.reserve(additional);
+    }
+
     /// Resizes the internal vectors to a new capacity. It's your responsibility to:
     ///   1) Make sure the new capacity is enough for all the elements, accounting
     ///      for the load factor.
@@ -773,13 +868,47 @@ impl<K, V, S> HashMap<K, V, S>
     /// ```
     #[stable(feature = "rust1", since = "1.0.0")]
     pub fn shrink_to_fit(&mut self) {
-        let min_capacity = self.resize_policy.min_capacity(self.len());
+        self.shrink_to(0);
+    }
+
+    /// Shrinks the capacity of the map with a lower limit. It will drop
+    /// down no lower than the supplied limit while maintaining the internal
+    /// rules and possibly leaving some space in accordance with the resize
+    /// policy.
+    ///
+    /// Shrinking rehashes all elements, so a limit that leaves room for the
+    /// expected number of elements avoids doing that again when the map grows.
+    /// If the current capacity is less than the lower limit, this is a no-op.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(hashmap_capacity_policy)]
+    /// use std::collections::HashMap;
+    ///
+    /// let mut map: HashMap<isize, isize> = HashMap::with_capacity(100);
+    /// map.insert(1, 2);
+    /// map.insert(3, 4);
+    /// assert!(map.capacity() >= 100);
+    /// map.shrink_to(10);
+    /// assert!(map.capacity() >= 10);
+    /// map.shrink_to(0);
+    /// assert!(map.capacity() >= 2);
+    /// ```
+    #[unstable(feature = "hashmap_capacity_policy", issue = "0")]
+    pub fn shrink_to(&mut self, min_capacity: usize) {
+        let min_capacity = max(self.len(), min_capacity);
+        if min_capacity >= self.capacity() {
+            return;
+        }
+
+        let min_capacity = self.resize_policy.min_capacity(min_capacity);
         let min_capacity = max(min_capacity.next_power_of_two(), INITIAL_CAPACITY);
 
         // An invalid value shouldn't make us run out of space.
         debug_assert!(self.len() <= min_capacity);
 
-        if self.table.capacity() != min_capacity {
+        if self.table.capacity() > min_capacity {
             let old_table = replace(&mut self.table, RawTable::new(min_capacity));
             let old_size = old_table.size();
 
@@ -1535,6 +1664,63 @@ impl<'a, K, V> Entry<'a, K, V> {
         }
     }
 
+    /// Ensures a value is in the entry by inserting the result of the default function if empty,
+    /// and returns a mutable reference to the value in the entry.
+    ///
+    /// The function is given a reference to the key, so that the key does not need to be
+    /// cloned beforehand to compute the value from it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(entry_or_insert_with_key)]
+    /// use std::collections::HashMap;
+    ///
+    /// let mut lengths = HashMap::new();
+    /// for word in "hash map".split(' ') {
+    ///     lengths.entry(word).or_insert_with_key(|w| w.len());
+    /// }
+    /// assert_eq!(lengths["hash"], 4);
+    /// assert_eq!(lengths["map"], 3);
+    /// ```
+    #[unstable(feature = "entry_or_insert_with_key", issue = "0")]
+    pub fn or_insert_with_key<F: FnOnce(&K) -> V>(self, default: F) -> &'a mut V {
+        match self {
+            Occupied(entry) => entry.into_mut(),
+            Vacant(entry) => {
+                let value = default(entry.key());
+                entry.insert(value)
+            }
+        }
+    }
+
+    /// Calls `f` with a mutable reference to the value if the entry is occupied, and returns
+    /// the entry for further use, usually with one of the `or_insert` methods.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(entry_and_modify)]
+    /// use std::collections::HashMap;
+    ///
+    /// let mut counts = HashMap::new();
+    /// for ch in "banana".chars() {
+    ///     counts.entry(ch).and_modify(|n| *n += 1).or_insert(1);
+    /// }
+    /// assert_eq!(counts[&'a'], 3);
+    /// assert_eq!(counts[&'b'], 1);
+    /// ```
+    #[unstable(feature = "entry_and_modify", issue = "0")]
+    pub fn and_modify<F: FnOnce(&mut V)>(self, f: F) -> Self {
+        match self {
+            Occupied(mut entry) => {
+                f(entry.get_mut());
+                Occupied(entry)
+            }
+            Vacant(entry) => Vacant(entry),
+        }
+    }
+
     /// Returns a reference to this entry's key.
     #[stable(feature = "map_entry_keys", since = "1.10.0")]
     pub fn key(&self) -> &K {
@@ -1555,7 +1741,8 @@ impl<'a, K, V> OccupiedEntry<'a, K, V> {
     /// Take the ownership of the key and value from the map.
     #[unstable(feature = "map_entry_recover_keys", issue = "34285")]
     pub fn remove_pair(self) -> (K, V) {
-        pop_internal(self.elem)
+        let (k, v, _) = pop_internal(self.elem);
+        (k, v)
     }
 
     /// Gets a reference to the value in the entry.
@@ -1591,6 +1778,51 @@ impl<'a, K, V> OccupiedEntry<'a, K, V> {
         pop_internal(self.elem).1
     }
 
+    /// Takes the value out of the entry and replaces it with the result of `f`, or removes the
+    /// entry if `f` returns `None`. Returns the entry in its new state.
+    ///
+    /// If `f` panics, the entry is removed from the map.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(entry_replace_with)]
+    /// use std::collections::HashMap;
+    /// use std::collections::hash_map::Entry;
+    ///
+    /// let mut map = HashMap::new();
+    /// map.insert("a", 2);
+    ///
+    /// if let Entry::Occupied(o) = map.entry("a") {
+    ///     let entry = o.replace_entry_with(|_, v| if v > 1 { Some(v - 1) } else { None });
+    ///     assert!(match entry { Entry::Occupied(..) => true, Entry::Vacant(..) => false });
+    /// }
+    /// assert_eq!(map["a"], 1);
+    ///
+    /// if let Entry::Occupied(o) = map.entry("a") {
+    ///     let entry = o.replace_entry_with(|_, v| if v > 1 { Some(v - 1) } else { None });
+    ///     assert_eq!(entry.key(), &"a");
+    /// }
+    /// assert!(map.is_empty());
+    /// ```
+    #[unstable(feature = "entry_replace_with", issue = "0")]
+    pub fn replace_entry_with<F>(self, f: F) -> Entry<'a, K, V>
+        where F: FnOnce(&K, V) -> Option<V>
+    {
+        let hash = self.elem.hash();
+        let (key, value, table) = pop_internal(self.elem);
+        // The key is no longer in the table, so nothing matches, and the
+        // search ends at the place where it would be inserted again.
+        let entry = match search_hashed(table, hash, |_| false).into_entry(key) {
+            Some(Vacant(entry)) => entry,
+            _ => unreachable!(),
+        };
+        match f(entry.key(), value) {
+            Some(value) => Occupied(entry.insert_entry(value)),
+            None => Vacant(entry),
+        }
+    }
+
     /// Returns a key that was used for search.
     ///
     /// The key was retained for further use.
@@ -1617,13 +1849,40 @@ impl<'a, K: 'a, V: 'a> VacantEntry<'a, K, V> {
     /// and returns a mutable reference to it
     #[stable(feature = "rust1", since = "1.0.0")]
     pub fn insert(self, value: V) -> &'a mut V {
-        match self.elem {
+        self.insert_entry(value).into_mut()
+    }
+
+    /// Sets the value of the entry with the VacantEntry's key,
+    /// and returns an OccupiedEntry for it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(entry_insert)]
+    /// use std::collections::HashMap;
+    /// use std::collections::hash_map::Entry;
+    ///
+    /// let mut map = HashMap::new();
+    /// if let Entry::Vacant(v) = map.entry("poneyland") {
+    ///     let mut o = v.insert_entry(37);
+    ///     assert_eq!(o.key(), &"poneyland");
+    ///     *o.get_mut() += 5;
+    /// }
+    /// assert_eq!(map["poneyland"], 42);
+    /// ```
+    #[unstable(feature = "entry_insert", issue = "0")]
+    pub fn insert_entry(self, value: V) -> OccupiedEntry<'a, K, V> {
+        let elem = match self.elem {
             NeqElem(bucket, ib) => {
                 robin_hood(bucket, ib, self.hash, self.key, value)
             }
             NoElem(bucket) => {
-                bucket.put(self.hash, self.key, value).into_mut_refs().1
+                bucket.put(self.hash, self.key, value)
             }
+        };
+        OccupiedEntry {
+            key: None,
+            elem: elem,
         }
     }
 }
@@ -1646,10 +1905,30 @@ impl<K, V, S> Extend<(K, V)> for HashMap<K, V, S>
     where K: Eq + Hash, S: BuildHasher
 {
     fn extend<T: IntoIterator<Item=(K, V)>>(&mut self, iter: T) {
+        let iter = iter.into_iter();
+        Extend::<(K, V)>::extend_reserve(self, iter.size_hint().0);
         for (k, v) in iter {
             self.insert(k, v);
         }
     }
+
+    #[inline]
+    fn extend_one(&mut self, (k, v): (K, V)) {
+        self.insert(k, v);
+    }
+
+    fn extend_reserve(&mut self, additional: usize) {
+        // Keys may be already present or show multiple times in the iterator.
+        // Reserve the entire hint lower bound if the map is empty.
+        // Otherwise reserve half the hint (rounded up), so the map
+        // will only resize twice in the worst case.
+        let reserve = if self.is_empty() {
+            additional
+        } else {
+            (additional + 1) / 2
+        };
+        self.reserve(reserve);
+    }
 }
 
 #[stable(feature = "hash_extend_copy", since = "1.4.0")]
@@ -1659,6 +1938,16 @@ impl<'a, K, V, S> Extend<(&'a K, &'a V)> for HashMap<K, V, S>
     fn extend<T: IntoIterator<Item=(&'a K, &'a V)>>(&mut self, iter: T) {
         self.extend(iter.into_iter().map(|(&key, &value)| (key, value)));
     }
+
+    #[inline]
+    fn extend_one(&mut self, (&k, &v): (&'a K, &'a V)) {
+        self.insert(k, v);
+    }
+
+    #[inline]
+    fn extend_reserve(&mut self, additional: usize) {
+        Extend::<(K, V)>::extend_reserve(self, additional);
+    }
 }
 
 /// `RandomState` is the default state for `HashMap` types.
@@ -1666,6 +1955,11 @@ impl<'a, K, V, S> Extend<(&'a K, &'a V)> for HashMap<K, V, S>
 /// A particular instance `RandomState` will create the same instances of
 /// `Hasher`, but the hashers created by two different `RandomState`
 /// instances are unlikely to produce the same result for the same values.
+///
+/// To reproduce the layout of a map, like for a bug that depends on its
+/// iteration order, the keys of its `RandomState` can be read with `seeds`
+/// and given back to `with_seeds`, or taken from the environment with
+/// `from_env`.
 #[derive(Clone)]
 #[stable(feature = "hashmap_build_hasher", since = "1.7.0")]
 pub struct RandomState {
@@ -1707,6 +2001,111 @@ impl RandomState {
             RandomState { k0: k0, k1: k1 }
         })
     }
+
+    /// Constructs a new `RandomState` with the given keys, so that it hashes
+    /// the same way every time.
+    ///
+    /// This defeats the protection against hash collision attacks, so it is
+    /// meant for tests that need to replay the exact layout of a map.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(hash_random_seeds)]
+    ///
+    /// use std::collections::HashMap;
+    /// use std::collections::hash_map::RandomState;
+    ///
+    /// let mut a = HashMap::with_hasher(RandomState::with_seeds(1, 2));
+    /// let mut b = HashMap::with_hasher(RandomState::with_seeds(1, 2));
+    /// for i in 0..100 {
+    ///     a.insert(i, ());
+    ///     b.insert(i, ());
+    /// }
+    /// assert!(a.keys().eq(b.keys()));
+    /// ```
+    #[inline]
+    #[unstable(feature = "hash_random_seeds", issue = "0")]
+    pub fn with_seeds(k0: u64, k1: u64) -> RandomState {
+        RandomState { k0: k0, k1: k1 }
+    }
+
+    /// Constructs a new `RandomState` with the keys given by the
+    /// `RUST_HASH_SEED` environment variable, or with random keys like `new`
+    /// if it isn't set.
+    ///
+    /// Set to `k0,k1`, the variable gives the keys of `with_seeds(k0, k1)`,
+    /// and set to a single number `n`, those of `with_seeds(n, 0)`. A value
+    /// that is not one of those is ignored. `new` never looks at the
+    /// variable, so only the maps that are created with this hash the same
+    /// way from one run to the next.
+    ///
+    /// Like `with_seeds`, this defeats the protection against hash collision
+    /// attacks whenever the variable is set, so it is meant for tests.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(hash_random_seeds)]
+    ///
+    /// use std::collections::HashMap;
+    /// use std::collections::hash_map::RandomState;
+    ///
+    /// let mut map = HashMap::with_hasher(RandomState::from_env());
+    /// map.insert(1, "a");
+    /// ```
+    #[unstable(feature = "hash_random_seeds", issue = "0")]
+    pub fn from_env() -> RandomState {
+        match env_seeds() {
+            Some((k0, k1)) => RandomState::with_seeds(k0, k1),
+            None => RandomState::new(),
+        }
+    }
+
+    /// Returns the keys of this `RandomState`, which `with_seeds` takes to
+    /// create another one that hashes the same way.
+    ///
+    /// Anyone who knows the keys can choose keys of their own that collide,
+    /// which is what the random keys protect against. So only reveal them,
+    /// like in a log, when the map never holds keys that come from untrusted
+    /// input.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(hash_random_seeds)]
+    ///
+    /// use std::collections::hash_map::RandomState;
+    ///
+    /// let s = RandomState::new();
+    /// let (k0, k1) = s.seeds();
+    /// println!("to replay, create the map with `from_env` and run with \
+    ///           RUST_HASH_SEED={},{}", k0, k1);
+    /// assert_eq!(RandomState::with_seeds(k0, k1).seeds(), (k0, k1));
+    /// ```
+    #[inline]
+    #[unstable(feature = "hash_random_seeds", issue = "0")]
+    pub fn seeds(&self) -> (u64, u64) {
+        (self.k0, self.k1)
+    }
+}
+
+// The keys given by `RUST_HASH_SEED`, if it is set to either `k0,k1` or `k0`.
+fn env_seeds() -> Option<(u64, u64)> {
+    let seed = match env::var("RUST_HASH_SEED") {
+        Ok(seed) => seed,
+        Err(_) => return None,
+    };
+    let mut parts = seed.splitn(2, ',');
+    let k0 = parts.next().and_then(|k| k.parse().ok());
+    let k1 = match parts.next() {
+        Some(k) => k.parse().ok(),
+        None => Some(0),
+    };
+    match (k0, k1) {
+        (Some(k0), Some(k1)) => Some((k0, k1)),
+        _ => None,
+    }
 }
 
 #[stable(feature = "hashmap_build_hasher", since = "1.7.0")]
@@ -1802,6 +2201,15 @@ mod test_map {
     use cell::RefCell;
     use rand::{thread_rng, Rng};
 
+    #[test]
+    fn test_hash_one_matches_map() {
+        use hash::BuildHasher;
+
+        let m: HashMap<&str, i32> = HashMap::new();
+        let hash = m.hasher().hash_one(&"key");
+        assert_eq!(m.make_hash(&"key").inspect(), hash | 0x8000_0000_0000_0000);
+    }
+
     #[test]
     fn test_create_capacity_zero() {
         let mut m = HashMap::with_capacity(0);
@@ -2362,6 +2770,92 @@ mod test_map {
         assert_eq!(m.remove(&0), Some(0));
     }
 
+    #[test]
+    fn test_shrink_to() {
+        let mut m = HashMap::with_capacity(1000);
+        for i in 0..100 {
+            m.insert(i, i);
+        }
+        let cap = m.capacity();
+        m.shrink_to(2000);
+        assert_eq!(m.capacity(), cap);
+
+        m.shrink_to(500);
+        assert!(m.capacity() >= 500 && m.capacity() < cap);
+        m.shrink_to(10);
+        assert!(m.capacity() >= 100 && m.capacity() < 500);
+        assert!((0..100).all(|i| m[&i] == i));
+
+        // an empty map does not allocate
+        let mut m: HashMap<i32, i32> = HashMap::new();
+        m.shrink_to(0);
+        m.shrink_to_fit();
+        assert_eq!(m.allocation_size(), 0);
+    }
+
+    #[test]
+    fn test_allocation_size() {
+        let mut m: HashMap<u64, u8> = HashMap::new();
+        assert_eq!(m.allocation_size(), 0);
+        m.insert(1, 1);
+        // a hash, a key and a value per bucket, and the values are packed
+        let buckets = m.table.capacity();
+        assert_eq!(m.allocation_size(), buckets * 17);
+
+        m.reserve(1000);
+        assert_eq!(m.allocation_size(), m.table.capacity() * 17);
+        assert!(m.allocation_size() > buckets * 17);
+    }
+
+    #[test]
+    fn test_reserve_with_max_load() {
+        let mut m = HashMap::new();
+        m.reserve_with_max_load(100, 50);
+        assert!(m.capacity() >= 100);
+        assert!(m.table.capacity() >= 200);
+
+        // the load factor is kept when the map grows and shrinks again
+        for i in 0..1000 {
+            m.insert(i, i);
+            assert!(m.len() * 2 <= m.table.capacity());
+        }
+        m.shrink_to_fit();
+        assert!(m.table.capacity() >= 2000);
+        for i in 0..500 {
+            m.remove(&i);
+        }
+        m.shrink_to(0);
+        assert!(m.table.capacity() >= 1000 && m.table.capacity() < 2048);
+        assert!((500..1000).all(|i| m[&i] == i));
+
+        // limits above the default load factor restore the default
+        let mut m: HashMap<i32, i32> = HashMap::new();
+        m.reserve_with_max_load(100, 50);
+        m.reserve_with_max_load(100, 100);
+        for i in 0..1000 {
+            m.insert(i, i);
+        }
+        let mut n: HashMap<i32, i32> = HashMap::new();
+        for i in 0..1000 {
+            n.insert(i, i);
+        }
+        assert_eq!(m.capacity(), n.capacity());
+
+        // reserving less than is already there does not reallocate
+        let mut m = HashMap::with_capacity(1000);
+        m.insert(1, 1);
+        let cap = m.table.capacity();
+        m.reserve_with_max_load(10, 10);
+        assert_eq!(m.table.capacity(), cap);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_reserve_with_max_load_zero() {
+        let mut m: HashMap<i32, i32> = HashMap::new();
+        m.reserve_with_max_load(10, 0);
+    }
+
     #[test]
     fn test_from_iter() {
         let xs = [(1, 1), (2, 2), (3, 3), (4, 4), (5, 5), (6, 6)];
@@ -2550,6 +3044,23 @@ mod test_map {
         assert_eq!(a[&3], "three");
     }
 
+    #[test]
+    fn test_extend_one_reserve() {
+        let mut a = HashMap::new();
+        Extend::<(i32, i32)>::extend_reserve(&mut a, 100);
+        assert!(a.capacity() >= 100);
+        let cap = a.capacity();
+        a.extend_one((1, 10));
+        a.extend_one((&2, &20));
+        a.extend_one((1, 11));
+        assert_eq!((a.len(), a[&1], a[&2]), (2, 11, 20));
+
+        // a non-empty map reserves only half, as the keys may already be in it
+        Extend::<(i32, i32)>::extend_reserve(&mut a, 1000);
+        assert!(a.capacity() >= 500 && a.capacity() < 1002);
+        assert!(a.capacity() > cap);
+    }
+
     #[test]
     fn test_capacity_not_less_than_len() {
         let mut a = HashMap::new();
@@ -2610,4 +3121,108 @@ mod test_map {
         assert_eq!(a.len(), 1);
         assert_eq!(a[key], value);
     }
+
+    #[test]
+    fn test_entry_and_modify() {
+        let mut map = HashMap::new();
+        for &x in &[3, 1, 3, 2, 3, 1] {
+            map.entry(x).and_modify(|n| *n += 1).or_insert(1);
+        }
+        assert_eq!((map[&1], map[&2], map[&3], map.len()), (2, 1, 3, 3));
+    }
+
+    #[test]
+    fn test_or_insert_with_key() {
+        let mut map = HashMap::new();
+        assert_eq!(*map.entry(5).or_insert_with_key(|k| k * 10), 50);
+        assert_eq!(*map.entry(5).or_insert_with_key(|_| panic!()), 50);
+        assert_eq!(map.len(), 1);
+    }
+
+    #[test]
+    fn test_insert_entry() {
+        let mut map = HashMap::new();
+        for k in 0..1000 {
+            match map.entry(k) {
+                Occupied(_) => unreachable!(),
+                Vacant(view) => {
+                    let mut view = view.insert_entry(k);
+                    assert_eq!(*view.key(), k);
+                    assert_eq!(view.insert(k * 2), k);
+                }
+            }
+        }
+        assert_eq!(map.len(), 1000);
+        assert!((0..1000).all(|k| map[&k] == k * 2));
+    }
+
+    #[test]
+    fn test_replace_entry_with() {
+        let mut map: HashMap<_, _> = (0..1000).map(|i| (i, i)).collect();
+        let mut removed = 0;
+        let f = |&k: &i32, v: i32| if k % 3 == 0 { Some(v + 1) } else { None };
+        for k in 0..1000 {
+            match map.entry(k) {
+                Vacant(_) => unreachable!(),
+                Occupied(view) => {
+                    match view.replace_entry_with(&f) {
+                        Occupied(view) => assert_eq!((k % 3, *view.get()), (0, k + 1)),
+                        Vacant(view) => {
+                            assert_eq!(*view.key(), k);
+                            removed += 1;
+                        }
+                    }
+                }
+            }
+            assert_eq!(map.len(), 1000 - removed);
+            // the pairs moved by the removal can still be found
+            assert!((k + 1..1000).all(|j| map[&j] == j));
+        }
+        assert_eq!(map.len(), 334);
+        assert!(map.iter().all(|(&k, &v)| k % 3 == 0 && v == k + 1));
+
+        match map.entry(0) {
+            Vacant(_) => unreachable!(),
+            Occupied(view) => {
+                if let Vacant(view) = view.replace_entry_with(|_, _| None) {
+                    view.insert(7);
+                }
+            }
+        }
+        assert_eq!((map[&0], map.len()), (7, 334));
+    }
+
+    #[test]
+    fn test_replace_entry_with_panic() {
+        use panic::{self, AssertUnwindSafe};
+
+        let mut map: HashMap<_, _> = (0..100).map(|i| (i, i)).collect();
+        let result = panic::catch_unwind(AssertUnwindSafe(|| {
+            if let Occupied(view) = map.entry(50) {
+                view.replace_entry_with(|_, _| panic!());
+            }
+        }));
+        assert!(result.is_err());
+        assert_eq!(map.len(), 99);
+        assert_eq!(map.get(&50), None);
+        assert!((0..100).filter(|&k| k != 50).all(|k| map[&k] == k));
+    }
+
+    #[test]
+    fn test_with_seeds() {
+        use super::RandomState;
+
+        let s = RandomState::with_seeds(0x0123456789abcdef, 42);
+        assert_eq!(s.seeds(), (0x0123456789abcdef, 42));
+        let mut a = HashMap::with_hasher(s.clone());
+        let mut b = HashMap::with_hasher(RandomState::with_seeds(0x0123456789abcdef, 42));
+        for i in 0..1000 {
+            a.insert(i, i);
+            b.insert(i, i);
+        }
+        assert!(a.iter().eq(b.iter()));
+
+        let c: HashMap<_, _> = a.iter().map(|(&k, &v)| (k, v)).collect();
+        assert_eq!(c.hasher().seeds(), RandomState::new().seeds());
+    }
 }
@@ -214,6 +214,28 @@ impl<T, S> HashSet<T, S>
         self.map.capacity()
     }
 
+    /// Returns the number of bytes allocated for the elements of the set.
+    ///
+    /// See [`HashMap::allocation_size`] for what this includes.
+    ///
+    /// [`HashMap::allocation_size`]: struct.HashMap.html#method.allocation_size
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(hashmap_capacity_policy)]
+    /// use std::collections::HashSet;
+    ///
+    /// let mut set: HashSet<u32> = HashSet::new();
+    /// assert_eq!(set.allocation_size(), 0);
+    /// set.insert(1);
+    /// assert!(set.allocation_size() >= set.capacity() * 12);
+    /// ```
+    #[unstable(feature = "hashmap_capacity_policy", issue = "0")]
+    pub fn allocation_size(&self) -> usize {
+        self.map.allocation_size()
+    }
+
     /// Reserves capacity for at least `additional` more elements to be inserted
     /// in the `HashSet`. The collection may reserve more space to avoid
     /// frequent reallocations.
@@ -234,6 +256,34 @@ impl<T, S> HashSet<T, S>
         self.map.reserve(additional)
     }
 
+    /// Reserves capacity for at least `additional` more elements to be inserted
+    /// in the `HashSet`, and from now on keeps the set at most
+    /// `max_load_percent` percent full.
+    ///
+    /// See [`HashMap::reserve_with_max_load`] for details.
+    ///
+    /// [`HashMap::reserve_with_max_load`]: struct.HashMap.html#method.reserve_with_max_load
+    ///
+    /// # Panics
+    ///
+    /// Panics if `max_load_percent` is zero or greater than 100, or if the new
+    /// allocation size overflows `usize`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(hashmap_capacity_policy)]
+    /// use std::collections::HashSet;
+    ///
+    /// let mut set: HashSet<i32> = HashSet::new();
+    /// set.reserve_with_max_load(100, 50);
+    /// assert!(set.capacity() >= 100);
+    /// ```
+    #[unstable(feature = "hashmap_capacity_policy", issue = "0")]
+    pub fn reserve_with_max_load(&mut self, additional: usize, max_load_percent: usize) {
+        self.map.reserve_with_max_load(additional, max_load_percent)
+    }
+
     /// Shrinks the capacity of the set as much as possible. It will drop
     /// down as much as possible while maintaining the internal rules
     /// and possibly leaving some space in accordance with the resize policy.
@@ -255,6 +305,33 @@ impl<T, S> HashSet<T, S>
         self.map.shrink_to_fit()
     }
 
+    /// Shrinks the capacity of the set with a lower limit. It will drop
+    /// down no lower than the supplied limit while maintaining the internal
+    /// rules and possibly leaving some space in accordance with the resize
+    /// policy.
+    ///
+    /// If the current capacity is less than the lower limit, this is a no-op.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(hashmap_capacity_policy)]
+    /// use std::collections::HashSet;
+    ///
+    /// let mut set = HashSet::with_capacity(100);
+    /// set.insert(1);
+    /// set.insert(2);
+    /// assert!(set.capacity() >= 100);
+    /// set.shrink_to(10);
+    /// assert!(set.capacity() >= 10);
+    /// set.shrink_to(0);
+    /// assert!(set.capacity() >= 2);
+    /// ```
+    #[unstable(feature = "hashmap_capacity_policy", issue = "0")]
+    pub fn shrink_to(&mut self, min_capacity: usize) {
+        self.map.shrink_to(min_capacity)
+    }
+
     /// An iterator visiting all elements in arbitrary order.
     /// Iterator element type is &'a T.
     ///
@@ -644,9 +721,17 @@ impl<T, S> Extend<T> for HashSet<T, S>
           S: BuildHasher,
 {
     fn extend<I: IntoIterator<Item=T>>(&mut self, iter: I) {
-        for k in iter {
-            self.insert(k);
-        }
+        self.map.extend(iter.into_iter().map(|k| (k, ())));
+    }
+
+    #[inline]
+    fn extend_one(&mut self, k: T) {
+        self.map.insert(k, ());
+    }
+
+    #[inline]
+    fn extend_reserve(&mut self, additional: usize) {
+        Extend::<(T, ())>::extend_reserve(&mut self.map, additional);
     }
 }
 
@@ -658,6 +743,16 @@ impl<'a, T, S> Extend<&'a T> for HashSet<T, S>
     fn extend<I: IntoIterator<Item=&'a T>>(&mut self, iter: I) {
         self.extend(iter.into_iter().cloned());
     }
+
+    #[inline]
+    fn extend_one(&mut self, &k: &'a T) {
+        self.map.insert(k, ());
+    }
+
+    #[inline]
+    fn extend_reserve(&mut self, additional: usize) {
+        Extend::<(T, ())>::extend_reserve(&mut self.map, additional);
+    }
 }
 
 #[stable(feature = "rust1", since = "1.0.0")]
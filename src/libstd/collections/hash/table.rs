@@ -11,7 +11,7 @@
 use alloc::heap::{EMPTY, allocate, deallocate};
 
 use cmp;
-use hash::{BuildHasher, Hash, Hasher};
+use hash::{BuildHasher, Hash};
 use intrinsics::needs_drop;
 use marker;
 use mem::{align_of, size_of};
@@ -155,13 +155,11 @@ pub fn make_hash<T: ?Sized, S>(hash_state: &S, t: &T) -> SafeHash
     where T: Hash,
           S: BuildHasher
 {
-    let mut state = hash_state.build_hasher();
-    t.hash(&mut state);
     // We need to avoid 0 in order to prevent collisions with
     // EMPTY_HASH. We can maintain our precious uniform distribution
     // of initial indexes by unconditionally setting the MSB,
     // effectively reducing 64-bits hashes to 63 bits.
-    SafeHash { hash: 0x8000_0000_0000_0000 | state.finish() }
+    SafeHash { hash: 0x8000_0000_0000_0000 | hash_state.hash_one(t) }
 }
 
 // `replace` casts a `*u64` to a `*SafeHash`. Since we statically
@@ -217,6 +215,10 @@ impl<K, V, M> Bucket<K, V, M> {
     pub fn index(&self) -> usize {
         self.idx
     }
+    /// Move out the reference to the table.
+    pub fn into_table(self) -> M {
+        self.table
+    }
 }
 
 impl<K, V, M> Deref for FullBucket<K, V, M>
@@ -339,7 +341,9 @@ impl<K, V, M: Deref<Target = RawTable<K, V>>> EmptyBucket<K, V, M> {
         }
     }
 
-    pub fn gap_peek(self) -> Option<GapThenFull<K, V, M>> {
+    /// Returns the gap and the bucket after it if that one is full, or the
+    /// empty bucket after the gap otherwise.
+    pub fn gap_peek(self) -> Result<GapThenFull<K, V, M>, Bucket<K, V, M>> {
         let gap = EmptyBucket {
             raw: self.raw,
             idx: self.idx,
@@ -348,12 +352,12 @@ impl<K, V, M: Deref<Target = RawTable<K, V>>> EmptyBucket<K, V, M> {
 
         match self.next().peek() {
             Full(bucket) => {
-                Some(GapThenFull {
+                Ok(GapThenFull {
                     gap: gap,
                     full: bucket,
                 })
             }
-            Empty(..) => None,
+            Empty(bucket) => Err(bucket.into_bucket()),
         }
     }
 }
@@ -515,7 +519,12 @@ impl<K, V, M> GapThenFull<K, V, M>
         &self.full
     }
 
-    pub fn shift(mut self) -> Option<GapThenFull<K, V, M>> {
+    /// Move out the reference to the table.
+    pub fn into_table(self) -> M {
+        self.full.into_table()
+    }
+
+    pub fn shift(mut self) -> Result<GapThenFull<K, V, M>, Bucket<K, V, M>> {
         unsafe {
             *self.gap.raw.hash = mem::replace(&mut *self.full.raw.hash, EMPTY_BUCKET);
             ptr::copy_nonoverlapping(self.full.raw.key, self.gap.raw.key as *mut K, 1);
@@ -531,9 +540,9 @@ impl<K, V, M> GapThenFull<K, V, M>
 
                 self.full = bucket;
 
-                Some(self)
+                Ok(self)
             }
-            Empty(..) => None,
+            Empty(bucket) => Err(bucket.into_bucket()),
         }
     }
 }
@@ -709,6 +718,25 @@ impl<K, V> RawTable<K, V> {
         self.size
     }
 
+    /// The size in bytes of the buffer holding the hashes, keys and values.
+    pub fn allocation_size(&self) -> usize {
+        if self.capacity == 0 {
+            return 0;
+        }
+
+        let hashes_size = self.capacity * size_of::<u64>();
+        let keys_size = self.capacity * size_of::<K>();
+        let vals_size = self.capacity * size_of::<V>();
+        let (_, _, size, oflo) = calculate_allocation(hashes_size,
+                                                      align_of::<u64>(),
+                                                      keys_size,
+                                                      align_of::<K>(),
+                                                      vals_size,
+                                                      align_of::<V>());
+        debug_assert!(!oflo, "should be impossible");
+        size
+    }
+
     fn raw_buckets(&self) -> RawBuckets<K, V> {
         RawBuckets {
             raw: self.first_bucket_raw(),
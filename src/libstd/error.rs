@@ -49,12 +49,14 @@
 
 use any::TypeId;
 use boxed::Box;
+use cell;
 use char;
 use fmt::{self, Debug, Display};
 use marker::{Send, Sync, Reflect};
 use mem::transmute;
 use num;
 use raw::TraitObject;
+use slice;
 use str;
 use string::{self, String};
 
@@ -173,6 +175,20 @@ impl Error for num::ParseFloatError {
     }
 }
 
+#[unstable(feature = "float_from_int", issue = "0")]
+impl Error for num::convert::InexactIntError {
+    fn description(&self) -> &str {
+        self.__description()
+    }
+}
+
+#[unstable(feature = "slice_try_copy", issue = "0")]
+impl Error for slice::LengthMismatchError {
+    fn description(&self) -> &str {
+        "destination and source slices have different lengths"
+    }
+}
+
 #[stable(feature = "rust1", since = "1.0.0")]
 impl Error for string::FromUtf8Error {
     fn description(&self) -> &str {
@@ -219,6 +235,20 @@ impl Error for fmt::Error {
     }
 }
 
+#[unstable(feature = "try_borrow", issue = "0")]
+impl Error for cell::BorrowError {
+    fn description(&self) -> &str {
+        "already mutably borrowed"
+    }
+}
+
+#[unstable(feature = "try_borrow", issue = "0")]
+impl Error for cell::BorrowMutError {
+    fn description(&self) -> &str {
+        "already borrowed"
+    }
+}
+
 // copied from any.rs
 impl Error + 'static {
     /// Returns true if the boxed type is the same as `T`
@@ -13,6 +13,7 @@ use borrow::{Cow, ToOwned, Borrow};
 use boxed::Box;
 use convert::{Into, From};
 use cmp::{PartialEq, Eq, PartialOrd, Ord, Ordering};
+use core::nonzero::NonZero;
 use error::Error;
 use fmt::{self, Write};
 use io;
@@ -296,6 +297,32 @@ impl CString {
     pub fn as_bytes_with_nul(&self) -> &[u8] {
         &self.inner
     }
+
+    /// Returns the underlying byte buffer as bytes that are known not to be
+    /// zero, without the trailing nul byte.
+    ///
+    /// This only drops the nul byte; the bytes are neither checked nor
+    /// copied. Converting the vector back with `from_nonzero_bytes` is just
+    /// as cheap.
+    #[unstable(feature = "cstring_nonzero", issue = "0")]
+    pub fn into_nonzero_bytes(self) -> Vec<NonZero<u8>> {
+        let mut vec = self.into_bytes();
+        let (ptr, len, cap) = (vec.as_mut_ptr(), vec.len(), vec.capacity());
+        mem::forget(vec);
+        // `NonZero<u8>` has the layout of `u8`, and none of the bytes are 0
+        unsafe { Vec::from_raw_parts(ptr as *mut NonZero<u8>, len, cap) }
+    }
+
+    /// Creates a C-compatible string from bytes that are known not to be
+    /// zero, without looking for interior nul bytes the way `new` has to.
+    ///
+    /// Only the trailing nul byte is appended, which may reallocate.
+    #[unstable(feature = "cstring_nonzero", issue = "0")]
+    pub fn from_nonzero_bytes(mut v: Vec<NonZero<u8>>) -> CString {
+        let (ptr, len, cap) = (v.as_mut_ptr(), v.len(), v.capacity());
+        mem::forget(v);
+        unsafe { CString::from_vec_unchecked(Vec::from_raw_parts(ptr as *mut u8, len, cap)) }
+    }
 }
 
 #[stable(feature = "rust1", since = "1.0.0")]
@@ -577,6 +604,16 @@ impl CStr {
         unsafe { mem::transmute(&self.inner) }
     }
 
+    /// Converts this C string to a slice of bytes that are known not to be
+    /// zero, without the trailing nul byte.
+    ///
+    /// Like `to_bytes`, this is currently a 0-cost cast.
+    #[unstable(feature = "cstring_nonzero", issue = "0")]
+    pub fn to_nonzero_bytes(&self) -> &[NonZero<u8>] {
+        let bytes = self.to_bytes();
+        unsafe { slice::from_raw_parts(bytes.as_ptr() as *const NonZero<u8>, bytes.len()) }
+    }
+
     /// Yields a `&str` slice if the `CStr` contains valid UTF-8.
     ///
     /// This function will calculate the length of this string and check for
@@ -799,4 +836,21 @@ mod tests {
         let cstr = CStr::from_bytes_with_nul(data);
         assert!(cstr.is_err());
     }
+
+    #[test]
+    fn nonzero_bytes() {
+        use core::nonzero::NonZero;
+
+        let s = CString::new("abc").unwrap();
+        assert_eq!(s.to_nonzero_bytes().iter().map(|b| **b).collect::<Vec<u8>>(), b"abc");
+        let bytes = s.into_nonzero_bytes();
+        assert_eq!(bytes.iter().map(|b| **b).collect::<Vec<u8>>(), b"abc");
+
+        let mut bytes = bytes;
+        bytes.push(unsafe { NonZero::new(b'd') });
+        let s = CString::from_nonzero_bytes(bytes);
+        assert_eq!(s.as_bytes_with_nul(), b"abcd\0");
+        assert_eq!(CString::from_nonzero_bytes(Vec::new()).as_bytes_with_nul(), b"\0");
+        assert!(CString::default().into_nonzero_bytes().is_empty());
+    }
 }
@@ -19,6 +19,8 @@ pub use self::c_str::{FromBytesWithNulError};
 
 #[stable(feature = "rust1", since = "1.0.0")]
 pub use self::os_str::{OsString, OsStr};
+#[unstable(feature = "os_str_pattern", issue = "0")]
+pub use self::os_str::OsStrSplit;
 
 mod c_str;
 mod os_str;
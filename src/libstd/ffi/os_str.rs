@@ -9,12 +9,14 @@
 // except according to those terms.
 
 use borrow::{Borrow, Cow, ToOwned};
+use boxed::Box;
 use fmt::{self, Debug};
 use mem;
 use string::String;
 use ops;
 use cmp;
 use hash::{Hash, Hasher};
+use memchr;
 use vec::Vec;
 
 use sys::os_str::{Buf, Slice};
@@ -134,6 +136,157 @@ impl OsString {
     pub fn reserve_exact(&mut self, additional: usize) {
         self.inner.reserve_exact(additional)
     }
+
+    /// Shortens this `OsString` to the specified length.
+    ///
+    /// The length is measured in the same units as `OsStr::len`. If
+    /// `new_len` is greater than the current length, this has no effect.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `new_len` is not a boundary at which this string can be
+    /// split, as reported by `OsStr::is_boundary`. The lengths of strings
+    /// that were pushed onto this one are always valid boundaries, except
+    /// on Windows right after a string ending in an unpaired high surrogate
+    /// which the following push completed into a pair.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(os_string_mutation)]
+    /// use std::ffi::OsString;
+    ///
+    /// let mut path = OsString::from("dir");
+    /// let len = path.len();
+    /// path.push("/file.txt");
+    /// path.truncate(len);
+    /// assert_eq!(&path, "dir");
+    /// ```
+    #[unstable(feature = "os_string_mutation", issue = "0")]
+    pub fn truncate(&mut self, new_len: usize) {
+        if new_len <= self.len() {
+            assert!(self.is_boundary(new_len),
+                    "OsString::truncate: length is not on a boundary");
+            self.inner.truncate(new_len)
+        }
+    }
+
+    /// Removes the last character from this `OsString` and returns it.
+    ///
+    /// Returns `None`, leaving the string unchanged, if it is empty or doesn't
+    /// end with a character: on Unix if it ends with bytes that aren't valid
+    /// UTF-8, and on Windows if it ends with an unpaired surrogate.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(os_string_mutation)]
+    /// use std::ffi::OsString;
+    ///
+    /// let mut os_string = OsString::from("ab");
+    /// assert_eq!(os_string.pop(), Some('b'));
+    /// assert_eq!(os_string.pop(), Some('a'));
+    /// assert_eq!(os_string.pop(), None);
+    /// ```
+    #[unstable(feature = "os_string_mutation", issue = "0")]
+    pub fn pop(&mut self) -> Option<char> {
+        self.inner.pop()
+    }
+
+    /// Retains only the characters for which `f` returns `true`, in place.
+    ///
+    /// The parts of the string that aren't characters, which are bytes that
+    /// aren't valid UTF-8 on Unix and unpaired surrogates on Windows, are
+    /// always kept. On Windows, surrogates that become adjacent form a pair.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(os_string_mutation)]
+    /// use std::ffi::OsString;
+    ///
+    /// let mut os_string = OsString::from("a-b-c");
+    /// os_string.retain(|c| c != '-');
+    /// assert_eq!(&os_string, "abc");
+    /// ```
+    #[unstable(feature = "os_string_mutation", issue = "0")]
+    pub fn retain<F>(&mut self, f: F) where F: FnMut(char) -> bool {
+        self.inner.retain(f)
+    }
+
+    /// Shrinks the capacity of the `OsString` to match its length.
+    #[unstable(feature = "os_string_mutation", issue = "0")]
+    pub fn shrink_to_fit(&mut self) {
+        self.inner.shrink_to_fit()
+    }
+
+    /// Shrinks the capacity of the `OsString` to the larger of its length and
+    /// `min_capacity`.
+    ///
+    /// Does nothing if the capacity is already at most `min_capacity`.
+    #[unstable(feature = "os_string_mutation", issue = "0")]
+    pub fn shrink_to(&mut self, min_capacity: usize) {
+        self.inner.shrink_to(min_capacity)
+    }
+
+    /// Converts this `OsString` into a boxed `OsStr`, dropping any excess
+    /// capacity but without copying the contents.
+    #[unstable(feature = "os_string_mutation", issue = "0")]
+    pub fn into_boxed_os_str(self) -> Box<OsStr> {
+        unsafe { mem::transmute(self.inner.into_box()) }
+    }
+
+    /// Consumes and leaks the `OsString`, returning a mutable reference to
+    /// its contents which lives for the rest of the program.
+    ///
+    /// Excess capacity is dropped first, so the leaked allocation is exactly
+    /// as large as the string.
+    #[unstable(feature = "os_string_mutation", issue = "0")]
+    pub fn leak<'a>(self) -> &'a mut OsStr {
+        unsafe { &mut *Box::into_raw(self.into_boxed_os_str()) }
+    }
+
+    /// Converts bytes in the encoding of `OsStr::as_encoded_bytes` into an
+    /// `OsString`, without copying them.
+    ///
+    /// If `bytes` isn't validly encoded for the current platform, it is
+    /// returned unchanged. On Unix this never happens.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(os_str_bytes)]
+    /// use std::ffi::OsString;
+    ///
+    /// let os_string = OsString::from_encoded_bytes(b"file.txt".to_vec()).unwrap();
+    /// assert_eq!(&os_string, "file.txt");
+    /// ```
+    #[unstable(feature = "os_str_bytes", issue = "0")]
+    pub fn from_encoded_bytes(bytes: Vec<u8>) -> Result<OsString, Vec<u8>> {
+        Buf::from_encoded_bytes(bytes).map(|inner| OsString { inner: inner })
+    }
+
+    /// Converts bytes in the encoding of `OsStr::as_encoded_bytes` into an
+    /// `OsString`, without copying or checking them.
+    ///
+    /// # Safety
+    ///
+    /// `bytes` must be validly encoded for the current platform, which is
+    /// the case if they come from `as_encoded_bytes` or `into_encoded_bytes`
+    /// on this platform, or are split from such bytes at a valid boundary.
+    #[unstable(feature = "os_str_bytes", issue = "0")]
+    pub unsafe fn from_encoded_bytes_unchecked(bytes: Vec<u8>) -> OsString {
+        OsString { inner: Buf::from_encoded_bytes_unchecked(bytes) }
+    }
+
+    /// Converts this `OsString` into the bytes of its encoding, without
+    /// copying them.
+    ///
+    /// See `OsStr::as_encoded_bytes` for the encoding.
+    #[unstable(feature = "os_str_bytes", issue = "0")]
+    pub fn into_encoded_bytes(self) -> Vec<u8> {
+        self.inner.into_encoded_bytes()
+    }
 }
 
 #[stable(feature = "rust1", since = "1.0.0")]
@@ -301,6 +454,207 @@ impl OsStr {
         self.inner.inner.len()
     }
 
+    /// Returns whether `index` is a position at which this string can be
+    /// split or truncated.
+    ///
+    /// Indices are measured in the same units as `len`, which are bytes on
+    /// Unix, where every index up to and including `len` is a boundary. On
+    /// Windows only indices between two code points are boundaries.
+    #[unstable(feature = "os_string_mutation", issue = "0")]
+    pub fn is_boundary(&self, index: usize) -> bool {
+        self.inner.is_boundary(index)
+    }
+
+    /// Converts a boxed `OsStr` into an `OsString` without copying or
+    /// allocating.
+    #[unstable(feature = "os_string_mutation", issue = "0")]
+    pub fn into_os_string(self: Box<OsStr>) -> OsString {
+        let inner: Box<Slice> = unsafe { mem::transmute(self) };
+        OsString { inner: Buf::from_box(inner) }
+    }
+
+    /// Returns the bytes of the encoding of this `OsStr`.
+    ///
+    /// The encoding is documented and can be relied on:
+    ///
+    /// * On Unix, these are the bytes of the string exactly as the platform
+    ///   sees them.
+    ///
+    /// * On Windows, these are the
+    ///   [WTF-8](https://simonsapin.github.io/wtf-8/) encoding of the
+    ///   platform's 16-bit code units: UTF-8, extended to also encode
+    ///   unpaired surrogates.
+    ///
+    /// On both, the encoding is a superset of UTF-8, so the bytes of any
+    /// `str` are valid, and any valid `OsStr` can be split at an ASCII byte
+    /// or a UTF-8 boundary. The bytes can be stored, say in an archive, and
+    /// converted back with `OsStr::from_encoded_bytes` on the same platform.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(os_str_bytes)]
+    /// use std::ffi::OsStr;
+    ///
+    /// let os_str = OsStr::new("dir/file.txt");
+    /// let bytes = os_str.as_encoded_bytes();
+    /// let slash = bytes.iter().position(|&b| b == b'/').unwrap();
+    /// let file = OsStr::from_encoded_bytes(&bytes[slash + 1..]).unwrap();
+    /// assert_eq!(file, "file.txt");
+    /// ```
+    #[unstable(feature = "os_str_bytes", issue = "0")]
+    pub fn as_encoded_bytes(&self) -> &[u8] {
+        self.inner.as_encoded_bytes()
+    }
+
+    /// Converts bytes in the encoding of `as_encoded_bytes` back into an
+    /// `OsStr`.
+    ///
+    /// Returns `None` if `bytes` isn't validly encoded for the current
+    /// platform. On Unix this never happens.
+    #[unstable(feature = "os_str_bytes", issue = "0")]
+    pub fn from_encoded_bytes(bytes: &[u8]) -> Option<&OsStr> {
+        Slice::from_encoded_bytes(bytes).map(OsStr::from_inner)
+    }
+
+    /// Converts bytes in the encoding of `as_encoded_bytes` back into an
+    /// `OsStr`, without checking them.
+    ///
+    /// # Safety
+    ///
+    /// `bytes` must be validly encoded for the current platform, which is
+    /// the case if they come from `as_encoded_bytes` on this platform, or
+    /// are split from such bytes at an ASCII byte or a UTF-8 boundary.
+    #[unstable(feature = "os_str_bytes", issue = "0")]
+    pub unsafe fn from_encoded_bytes_unchecked(bytes: &[u8]) -> &OsStr {
+        OsStr::from_inner(Slice::from_encoded_bytes_unchecked(bytes))
+    }
+
+    /// Returns `true` if `needle` occurs in this `OsStr`.
+    ///
+    /// Like the other searching methods, this works on the encoded string
+    /// without converting it, so it also finds `needle` in strings that
+    /// aren't valid Unicode.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(os_str_pattern)]
+    /// use std::ffi::OsStr;
+    ///
+    /// assert!(OsStr::new("backup.tar.gz").contains(".tar"));
+    /// assert!(!OsStr::new("backup.zip").contains(".tar"));
+    /// ```
+    #[unstable(feature = "os_str_pattern", issue = "0")]
+    pub fn contains(&self, needle: &str) -> bool {
+        find_bytes(self.bytes(), needle.as_bytes()).is_some()
+    }
+
+    /// Returns `true` if this `OsStr` starts with `needle`.
+    #[unstable(feature = "os_str_pattern", issue = "0")]
+    pub fn starts_with(&self, needle: &str) -> bool {
+        self.bytes().starts_with(needle.as_bytes())
+    }
+
+    /// Returns `true` if this `OsStr` ends with `needle`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(os_str_pattern)]
+    /// use std::ffi::OsStr;
+    ///
+    /// assert!(OsStr::new("backup.tar.gz").ends_with(".tar.gz"));
+    /// ```
+    #[unstable(feature = "os_str_pattern", issue = "0")]
+    pub fn ends_with(&self, needle: &str) -> bool {
+        self.bytes().ends_with(needle.as_bytes())
+    }
+
+    /// Returns the index of the first occurrence of `needle`.
+    ///
+    /// The index is in the units of `len`, and is always a boundary in the
+    /// sense of `is_boundary`, since `needle` is valid Unicode.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(os_str_pattern)]
+    /// use std::ffi::OsStr;
+    ///
+    /// let os_str = OsStr::new("a=b=c");
+    /// assert_eq!(os_str.find("="), Some(1));
+    /// assert_eq!(os_str.rfind("="), Some(3));
+    /// assert_eq!(os_str.find("x"), None);
+    /// ```
+    #[unstable(feature = "os_str_pattern", issue = "0")]
+    pub fn find(&self, needle: &str) -> Option<usize> {
+        find_bytes(self.bytes(), needle.as_bytes())
+    }
+
+    /// Returns the index of the last occurrence of `needle`, like `find`.
+    #[unstable(feature = "os_str_pattern", issue = "0")]
+    pub fn rfind(&self, needle: &str) -> Option<usize> {
+        rfind_bytes(self.bytes(), needle.as_bytes())
+    }
+
+    /// Splits this `OsStr` around the first occurrence of `needle`, and
+    /// returns the parts before and after it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(os_str_pattern)]
+    /// use std::ffi::OsStr;
+    ///
+    /// let (key, value) = OsStr::new("PATH=/bin:/usr/bin").split_once("=").unwrap();
+    /// assert_eq!(key, "PATH");
+    /// assert_eq!(value, "/bin:/usr/bin");
+    /// ```
+    #[unstable(feature = "os_str_pattern", issue = "0")]
+    pub fn split_once(&self, needle: &str) -> Option<(&OsStr, &OsStr)> {
+        self.find(needle).map(|i| self.split_around(i, needle.len()))
+    }
+
+    /// Splits this `OsStr` around the last occurrence of `needle`, like
+    /// `split_once`.
+    #[unstable(feature = "os_str_pattern", issue = "0")]
+    pub fn rsplit_once(&self, needle: &str) -> Option<(&OsStr, &OsStr)> {
+        self.rfind(needle).map(|i| self.split_around(i, needle.len()))
+    }
+
+    /// Returns an iterator over the parts of this `OsStr` separated by
+    /// `needle`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `needle` is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(os_str_pattern)]
+    /// use std::ffi::OsStr;
+    ///
+    /// let parts: Vec<_> = OsStr::new("/bin::/usr/bin").split(":").collect();
+    /// assert_eq!(parts, ["/bin", "", "/usr/bin"]);
+    /// ```
+    #[unstable(feature = "os_str_pattern", issue = "0")]
+    pub fn split<'a, 'b>(&'a self, needle: &'b str) -> OsStrSplit<'a, 'b> {
+        assert!(!needle.is_empty(), "cannot split an OsStr with an empty needle");
+        OsStrSplit { rest: Some(self), needle: needle }
+    }
+
+    /// Returns the parts before and after the `len` units at `index`, which
+    /// are an occurrence of a `str`, so that both ends are boundaries.
+    fn split_around(&self, index: usize, len: usize) -> (&OsStr, &OsStr) {
+        let bytes = self.bytes();
+        unsafe {
+            (OsStr::from_encoded_bytes_unchecked(&bytes[..index]),
+             OsStr::from_encoded_bytes_unchecked(&bytes[index + len..]))
+        }
+    }
+
     /// Gets the underlying byte representation.
     ///
     /// Note: it is *crucial* that this API is private, to avoid
@@ -310,6 +664,93 @@ impl OsStr {
     }
 }
 
+/// Returns the index of the first occurrence of `needle` in `haystack`.
+fn find_bytes(haystack: &[u8], needle: &[u8]) -> Option<usize> {
+    let first = match needle.first() {
+        Some(&first) => first,
+        None => return Some(0),
+    };
+    let mut start = 0;
+    while let Some(i) = memchr::memchr(first, &haystack[start..]) {
+        let i = start + i;
+        if haystack[i..].starts_with(needle) {
+            return Some(i);
+        }
+        start = i + 1;
+    }
+    None
+}
+
+/// Returns the index of the last occurrence of `needle` in `haystack`.
+fn rfind_bytes(haystack: &[u8], needle: &[u8]) -> Option<usize> {
+    let last = match needle.last() {
+        Some(&last) => last,
+        None => return Some(haystack.len()),
+    };
+    let mut end = haystack.len();
+    while let Some(i) = memchr::memrchr(last, &haystack[..end]) {
+        if haystack[..i + 1].ends_with(needle) {
+            return Some(i + 1 - needle.len());
+        }
+        end = i;
+    }
+    None
+}
+
+/// An iterator over the parts of an `OsStr` separated by a `str`.
+///
+/// This struct is created by the `split` method on `OsStr`.
+#[derive(Clone, Debug)]
+#[unstable(feature = "os_str_pattern", issue = "0")]
+pub struct OsStrSplit<'a, 'b> {
+    /// What is left to split, or `None` once the last part was returned.
+    rest: Option<&'a OsStr>,
+    needle: &'b str,
+}
+
+#[unstable(feature = "os_str_pattern", issue = "0")]
+impl<'a, 'b> Iterator for OsStrSplit<'a, 'b> {
+    type Item = &'a OsStr;
+
+    fn next(&mut self) -> Option<&'a OsStr> {
+        let rest = match self.rest {
+            Some(rest) => rest,
+            None => return None,
+        };
+        match rest.split_once(self.needle) {
+            Some((part, rest)) => {
+                self.rest = Some(rest);
+                Some(part)
+            }
+            None => {
+                self.rest = None;
+                Some(rest)
+            }
+        }
+    }
+}
+
+#[unstable(feature = "os_string_mutation", issue = "0")]
+impl<'a> From<&'a OsStr> for Box<OsStr> {
+    fn from(s: &'a OsStr) -> Box<OsStr> {
+        s.to_os_string().into_boxed_os_str()
+    }
+}
+
+#[unstable(feature = "os_string_mutation", issue = "0")]
+impl From<Box<OsStr>> for OsString {
+    fn from(boxed: Box<OsStr>) -> OsString {
+        boxed.into_os_string()
+    }
+}
+
+#[unstable(feature = "os_string_mutation", issue = "0")]
+impl From<OsString> for Box<OsStr> {
+    fn from(s: OsString) -> Box<OsStr> {
+        s.into_boxed_os_str()
+    }
+}
+
 #[stable(feature = "osstring_default", since = "1.9.0")]
 impl<'a> Default for &'a OsStr {
     #[inline]
@@ -488,6 +929,7 @@ impl AsInner<Slice> for OsStr {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use boxed::Box;
     use sys_common::{AsInner, IntoInner};
 
     #[test]
@@ -570,6 +1012,211 @@ mod tests {
         assert!(os_string.capacity() >= 33)
     }
 
+    #[test]
+    fn test_os_str_encoded_bytes() {
+        let os_str = OsStr::new("h\u{e9}llo/w\u{1F4A9}rld");
+        let bytes = os_str.as_encoded_bytes();
+        assert_eq!(bytes, "h\u{e9}llo/w\u{1F4A9}rld".as_bytes());
+        assert_eq!(OsStr::from_encoded_bytes(bytes), Some(os_str));
+        assert_eq!(unsafe { OsStr::from_encoded_bytes_unchecked(&bytes[7..]) },
+                   "w\u{1F4A9}rld");
+
+        let os_string = os_str.to_os_string();
+        let vec = os_string.clone().into_encoded_bytes();
+        assert_eq!(vec, bytes);
+        assert_eq!(OsString::from_encoded_bytes(vec.clone()), Ok(os_string.clone()));
+        assert_eq!(unsafe { OsString::from_encoded_bytes_unchecked(vec) }, os_string);
+    }
+
+    #[test]
+    fn test_os_str_pattern() {
+        let os_str = OsStr::new("a\u{e9}b=\u{e9}=");
+        assert!(os_str.contains("\u{e9}="));
+        assert!(!os_str.contains("=="));
+        assert!(os_str.contains(""));
+        assert!(os_str.starts_with("a\u{e9}"));
+        assert!(!os_str.starts_with("\u{e9}"));
+        assert!(os_str.ends_with("\u{e9}="));
+        assert!(os_str.ends_with(""));
+        assert_eq!(os_str.find("\u{e9}"), Some(1));
+        assert_eq!(os_str.rfind("\u{e9}"), Some(5));
+        assert_eq!(os_str.find(""), Some(0));
+        assert_eq!(os_str.rfind(""), Some(os_str.len()));
+        assert_eq!(os_str.find("\u{e8}"), None);
+        assert!(os_str.is_boundary(os_str.rfind("=").unwrap()));
+
+        assert_eq!(os_str.split_once("="), Some((OsStr::new("a\u{e9}b"), OsStr::new("\u{e9}="))));
+        assert_eq!(os_str.rsplit_once("="), Some((OsStr::new("a\u{e9}b=\u{e9}"), OsStr::new(""))));
+        assert_eq!(os_str.split_once("x"), None);
+
+        let parts: Vec<_> = os_str.split("=").collect();
+        assert_eq!(parts, ["a\u{e9}b", "\u{e9}", ""]);
+        let parts: Vec<_> = OsStr::new("").split("=").collect();
+        assert_eq!(parts, [""]);
+        let parts: Vec<_> = OsStr::new("aaaa").split("aa").collect();
+        assert_eq!(parts, ["", "", ""]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_os_str_split_empty_needle() {
+        OsStr::new("abc").split("");
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn test_os_str_pattern_unix() {
+        let os_str = OsStr::from_encoded_bytes(b"\xFFname.\xFE.txt").unwrap();
+        assert!(os_str.ends_with(".txt"));
+        assert_eq!(os_str.find("."), Some(5));
+        assert_eq!(os_str.rfind("."), Some(7));
+        let (stem, ext) = os_str.rsplit_once(".").unwrap();
+        assert_eq!(stem.as_encoded_bytes(), b"\xFFname.\xFE");
+        assert_eq!(ext, "txt");
+        let parts: Vec<_> = os_str.split(".").map(OsStr::as_encoded_bytes).collect();
+        assert_eq!(parts, [&b"\xFFname"[..], b"\xFE", b"txt"]);
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn test_os_str_encoded_bytes_unix() {
+        let bytes = b"\xFF\xFEnot utf-8";
+        let os_str = OsStr::from_encoded_bytes(bytes).unwrap();
+        assert_eq!(os_str.as_encoded_bytes(), bytes);
+        assert!(os_str.to_str().is_none());
+    }
+
+    #[test]
+    #[cfg(windows)]
+    fn test_os_str_encoded_bytes_windows() {
+        use os::windows::ffi::{OsStrExt, OsStringExt};
+
+        // An unpaired surrogate is encoded as in WTF-8.
+        let os_string = OsString::from_wide(&[0x61, 0xD800]);
+        assert_eq!(os_string.as_encoded_bytes(), b"a\xED\xA0\x80");
+        let os_str = OsStr::from_encoded_bytes(b"a\xED\xA0\x80").unwrap();
+        assert_eq!(os_str.encode_wide().collect::<Vec<_>>(), [0x61, 0xD800]);
+
+        // A surrogate pair must be encoded as a supplementary code point.
+        assert!(OsStr::from_encoded_bytes(b"\xED\xA0\x80\xED\xB0\x80").is_none());
+        assert!(OsStr::from_encoded_bytes(b"\xFF").is_none());
+        assert_eq!(OsString::from_encoded_bytes(vec![0xFF]), Err(vec![0xFF]));
+    }
+
+    #[test]
+    fn test_os_string_truncate() {
+        let mut os_string = OsString::from("hello world");
+        os_string.truncate(20);
+        assert_eq!(&os_string, "hello world");
+        os_string.truncate(5);
+        assert_eq!(&os_string, "hello");
+        os_string.truncate(0);
+        assert!(os_string.is_empty());
+    }
+
+    #[test]
+    #[cfg(windows)]
+    #[should_panic]
+    fn test_os_string_truncate_not_boundary() {
+        let mut os_string = OsString::from("\u{e9}");
+        assert!(!os_string.is_boundary(1));
+        os_string.truncate(1);
+    }
+
+    #[test]
+    fn test_os_string_pop() {
+        let mut os_string = OsString::from("a\u{e9}\u{1f4a9}");
+        assert_eq!(os_string.pop(), Some('\u{1f4a9}'));
+        assert_eq!(os_string.pop(), Some('\u{e9}'));
+        assert_eq!(&os_string, "a");
+        assert_eq!(os_string.pop(), Some('a'));
+        assert_eq!(os_string.pop(), None);
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn test_os_string_pop_invalid() {
+        use os::unix::ffi::OsStringExt;
+        let mut os_string = OsString::from_vec(b"a\xc3\xa9\xa9".to_vec());
+        assert_eq!(os_string.pop(), None);
+        assert_eq!(os_string.len(), 4);
+        let mut os_string = OsString::from_vec(b"\xff\xc3\xa9".to_vec());
+        assert_eq!(os_string.pop(), Some('\u{e9}'));
+        assert_eq!(os_string.pop(), None);
+        assert_eq!(os_string.into_vec(), b"\xff");
+    }
+
+    #[test]
+    #[cfg(windows)]
+    fn test_os_string_pop_invalid() {
+        use os::windows::ffi::{OsStrExt, OsStringExt};
+        use vec::Vec;
+        let mut os_string = OsString::from_wide(&[0x61, 0xd83d]);
+        assert_eq!(os_string.pop(), None);
+        assert_eq!(os_string.encode_wide().collect::<Vec<_>>(), [0x61, 0xd83d]);
+    }
+
+    #[test]
+    fn test_os_string_retain() {
+        let mut os_string = OsString::from("h\u{e9}llo, w\u{f6}rld");
+        os_string.retain(|c| (c as u32) < 0x80);
+        assert_eq!(&os_string, "hllo, wrld");
+        os_string.retain(|c| c != 'l');
+        assert_eq!(&os_string, "ho, wrd");
+        os_string.retain(|_| false);
+        assert!(os_string.is_empty());
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn test_os_string_retain_invalid() {
+        use os::unix::ffi::OsStringExt;
+        let mut os_string = OsString::from_vec(b"a\xffb\xc3\xa9\xc3".to_vec());
+        os_string.retain(|c| c == 'b');
+        assert_eq!(os_string.into_vec(), b"\xffb\xc3");
+    }
+
+    #[test]
+    #[cfg(windows)]
+    fn test_os_string_retain_invalid() {
+        use os::windows::ffi::{OsStrExt, OsStringExt};
+        use vec::Vec;
+        let mut os_string = OsString::from_wide(&[0xd83d, 0x61, 0xdca9, 0xdc00]);
+        os_string.retain(|c| c != 'a');
+        assert_eq!(os_string.encode_wide().collect::<Vec<_>>(), [0xd83d, 0xdca9, 0xdc00]);
+    }
+
+    #[test]
+    fn test_os_string_shrink() {
+        let mut os_string = OsString::with_capacity(64);
+        os_string.push("abc");
+        os_string.shrink_to(16);
+        assert!(os_string.capacity() >= 16);
+        assert!(os_string.capacity() < 64);
+        os_string.shrink_to(32);
+        assert!(os_string.capacity() < 32);
+        os_string.shrink_to_fit();
+        assert!(os_string.capacity() >= 3);
+        assert_eq!(&os_string, "abc");
+    }
+
+    #[test]
+    fn test_os_string_boxed() {
+        let boxed = OsString::from("hello").into_boxed_os_str();
+        assert_eq!(&*boxed, "hello");
+        let os_string = boxed.into_os_string();
+        assert_eq!(&os_string, "hello");
+
+        let boxed: Box<OsStr> = From::from(OsStr::new("world"));
+        assert_eq!(&OsString::from(boxed), "world");
+    }
+
+    #[test]
+    fn test_os_string_leak() {
+        let leaked: &'static mut OsStr = OsString::from("leaked").leak();
+        assert_eq!(leaked, "leaked");
+    }
+
     #[test]
     fn test_os_string_default() {
         let os_string: OsString = Default::default();
@@ -17,10 +17,12 @@
 
 #![stable(feature = "rust1", since = "1.0.0")]
 
+use env;
 use fmt;
 use ffi::OsString;
 use io::{self, SeekFrom, Seek, Read, Write};
 use path::{Path, PathBuf};
+use rand::{self, Rng};
 use sys::fs as fs_imp;
 use sys_common::{AsInnerMut, FromInner, AsInner, IntoInner};
 use vec::Vec;
@@ -153,6 +155,47 @@ pub struct DirBuilder {
     recursive: bool,
 }
 
+/// Options and flags which can be used to configure how a directory tree is
+/// copied by `copy_dir_all`.
+///
+/// By default symbolic links are recreated rather than followed, nothing
+/// that already exists at the destination is overwritten, and the
+/// permissions of every copied directory are preserved.
+///
+/// # Examples
+///
+/// ```no_run
+/// #![feature(fs_copy_dir_all)]
+/// use std::fs::{self, CopyOptions};
+///
+/// # fn foo() -> std::io::Result<()> {
+/// let mut options = CopyOptions::new();
+/// options.overwrite(true);
+/// try!(fs::copy_dir_all("foo", "bar", &options));
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Debug)]
+#[unstable(feature = "fs_copy_dir_all", issue = "0")]
+pub struct CopyOptions {
+    follow_symlinks: bool,
+    overwrite: bool,
+    preserve_permissions: bool,
+}
+
+/// A directory in the filesystem that is automatically deleted, along with
+/// all of its contents, when it goes out of scope.
+///
+/// Instances of `TempDir` are created with the `tempdir` and `tempdir_in`
+/// functions of this module.
+///
+/// Errors encountered while removing the directory on drop are ignored; use
+/// the `close` method to observe them.
+#[unstable(feature = "fs_tempfile", issue = "0")]
+pub struct TempDir {
+    path: Option<PathBuf>,
+}
+
 impl File {
     /// Attempts to open a file in read-only mode.
     ///
@@ -1052,6 +1095,51 @@ pub fn copy<P: AsRef<Path>, Q: AsRef<Path>>(from: P, to: Q) -> io::Result<u64> {
     fs_imp::copy(from.as_ref(), to.as_ref())
 }
 
+/// Recursively copies the contents of the directory `from` to the path `to`,
+/// creating `to` and any directories below it as needed.
+///
+/// Regular files are copied with `fs::copy`, so their permissions are copied
+/// as well. How symbolic links, existing destination entries and directory
+/// permissions are treated is controlled by `options`; see `CopyOptions` for
+/// the available settings. If `from` is itself a symbolic link to a directory
+/// and links are not followed, a link to the same target is created at `to`.
+///
+/// When symbolic links are followed no attempt is made to detect cycles, so a
+/// link pointing at one of its own ancestors will make this function recurse
+/// until it runs out of path length.
+///
+/// # Errors
+///
+/// This function will return an error in the following situations, but is not
+/// limited to just these cases:
+///
+/// * The `from` path is not a directory.
+/// * The `to` path lies inside of `from`.
+/// * An entry already exists at the destination and overwriting is disabled.
+/// * The source tree contains entries which are neither regular files,
+///   directories nor symbolic links.
+/// * Any of the underlying `fs::copy`, `fs::create_dir` or `fs::read_dir`
+///   operations fail.
+///
+/// Entries copied before an error is encountered are not removed.
+///
+/// # Examples
+///
+/// ```no_run
+/// #![feature(fs_copy_dir_all)]
+/// use std::fs::{self, CopyOptions};
+///
+/// # fn foo() -> std::io::Result<()> {
+/// try!(fs::copy_dir_all("foo", "bar", &CopyOptions::new()));
+/// # Ok(())
+/// # }
+/// ```
+#[unstable(feature = "fs_copy_dir_all", issue = "0")]
+pub fn copy_dir_all<P: AsRef<Path>, Q: AsRef<Path>>(from: P, to: Q, options: &CopyOptions)
+                                                    -> io::Result<()> {
+    options.copy_dir_all(from.as_ref(), to.as_ref())
+}
+
 /// Creates a new hard link on the filesystem.
 ///
 /// The `dst` path will be a link pointing to the `src` path. Note that systems
@@ -1178,6 +1266,46 @@ pub fn canonicalize<P: AsRef<Path>>(path: P) -> io::Result<PathBuf> {
     fs_imp::canonicalize(path.as_ref())
 }
 
+/// Returns the canonical form of a path like `canonicalize`, but in the
+/// simplest syntax that still refers to the same file.
+///
+/// This is intended for paths that are displayed to users or passed on to
+/// other programs, many of which do not understand the extended-length paths
+/// returned by `canonicalize` on Windows.
+///
+/// # Platform-specific behavior
+///
+/// On Unix this function is identical to `canonicalize`.
+///
+/// On Windows the `\\?\` prefix is removed from the canonical path when the
+/// result refers to the same file without it, turning `\\?\C:\dir` into
+/// `C:\dir` and `\\?\UNC\server\share` into `\\server\share`. Paths that
+/// exceed `MAX_PATH` or contain components that Win32 path normalization
+/// would alter, such as reserved device names or names ending in a dot, keep
+/// their prefix.
+/// Note that, this [may change in the future][changes].
+/// [changes]: ../io/index.html#platform-specific-behavior
+///
+/// # Errors
+///
+/// This function returns an error in the same situations as `canonicalize`.
+///
+/// # Examples
+///
+/// ```
+/// #![feature(fs_canonicalize_simplified)]
+/// use std::fs;
+///
+/// # fn foo() -> std::io::Result<()> {
+/// let path = try!(fs::canonicalize_simplified("../a/../foo.txt"));
+/// # Ok(())
+/// # }
+/// ```
+#[unstable(feature = "fs_canonicalize_simplified", issue = "0")]
+pub fn canonicalize_simplified<P: AsRef<Path>>(path: P) -> io::Result<PathBuf> {
+    fs_imp::canonicalize_simplified(path.as_ref())
+}
+
 /// Creates a new, empty directory at the provided path
 ///
 /// # Platform-specific behavior
@@ -1285,9 +1413,17 @@ pub fn remove_dir<P: AsRef<Path>>(path: P) -> io::Result<()> {
 ///
 /// # Platform-specific behavior
 ///
-/// This function currently corresponds to `opendir`, `lstat`, `rm` and `rmdir` functions on Unix
-/// and the `FindFirstFile`, `GetFileAttributesEx`, `DeleteFile`, and `RemoveDirectory` functions
-/// on Windows.
+/// This function currently corresponds to the `openat`, `fdopendir` and `unlinkat` functions on
+/// Unix and the `FindFirstFile`, `CreateFile` and `SetFileInformationByHandle` functions on
+/// Windows.
+///
+/// On Unix every directory is opened relative to its already opened parent without following
+/// symbolic links, so swapping a directory for a symbolic link while this function runs cannot
+/// redirect it outside of the tree being removed.
+///
+/// On Windows entries are deleted with POSIX semantics where the system supports them, and
+/// deletions failing because another process briefly holds an entry open are retried for up to
+/// about a second.
 /// Note that, this [may change in the future][changes].
 /// [changes]: ../io/index.html#platform-specific-behavior
 ///
@@ -1394,6 +1530,175 @@ pub fn set_permissions<P: AsRef<Path>>(path: P, perm: Permissions)
     fs_imp::set_perm(path.as_ref(), perm.0)
 }
 
+/// Creates a new temporary file in the directory returned by `env::temp_dir`.
+///
+/// See `tempfile_in` for details.
+///
+/// # Examples
+///
+/// ```
+/// #![feature(fs_tempfile)]
+/// use std::fs;
+/// use std::io::{Read, Seek, SeekFrom, Write};
+///
+/// # fn foo() -> std::io::Result<()> {
+/// let mut file = try!(fs::tempfile());
+/// try!(file.write_all(b"scratch data"));
+/// try!(file.seek(SeekFrom::Start(0)));
+/// let mut contents = String::new();
+/// try!(file.read_to_string(&mut contents));
+/// assert_eq!(contents, "scratch data");
+/// # Ok(())
+/// # }
+/// ```
+#[unstable(feature = "fs_tempfile", issue = "0")]
+pub fn tempfile() -> io::Result<File> {
+    tempfile_in(env::temp_dir())
+}
+
+/// Creates a new temporary file in the directory `dir`, opened for both
+/// reading and writing.
+///
+/// The file is removed from the filesystem automatically once the returned
+/// `File`, and every handle duplicated from it, is closed. Other processes
+/// can't open the file by guessing its name, as it is either never given
+/// one or given a random one and created exclusively.
+///
+/// # Platform-specific behavior
+///
+/// On Linux the file is created with `O_TMPFILE` when both the kernel and the
+/// filesystem support it, in which case it never appears in `dir` at all.
+/// On other Unix platforms, or if `O_TMPFILE` is unavailable, the file is
+/// created with `O_CREAT | O_EXCL` and permissions `0600`, and unlinked right
+/// away.
+///
+/// On Windows the file is created with `CREATE_NEW`,
+/// `FILE_ATTRIBUTE_TEMPORARY` and `FILE_FLAG_DELETE_ON_CLOSE`. It remains
+/// visible in `dir` until it is closed, and if the process is terminated
+/// abnormally the system deletes it as soon as the handle is released.
+///
+/// Note that, this [may change in the future][changes].
+/// [changes]: ../io/index.html#platform-specific-behavior
+///
+/// # Errors
+///
+/// This function will return an error if `dir` doesn't exist or isn't a
+/// writable directory.
+#[unstable(feature = "fs_tempfile", issue = "0")]
+pub fn tempfile_in<P: AsRef<Path>>(dir: P) -> io::Result<File> {
+    let dir = dir.as_ref();
+    if let Some(inner) = fs_imp::tempfile_anonymous(dir)? {
+        return Ok(File { inner: inner })
+    }
+    create_unique(dir, |path| fs_imp::tempfile_create(path))
+        .map(|(inner, _)| File { inner: inner })
+}
+
+/// Creates a new, empty temporary directory in the directory returned by
+/// `env::temp_dir`.
+///
+/// See `tempdir_in` for details.
+///
+/// # Examples
+///
+/// ```
+/// #![feature(fs_tempfile)]
+/// use std::fs::{self, File};
+///
+/// # fn foo() -> std::io::Result<()> {
+/// let dir = try!(fs::tempdir());
+/// try!(File::create(dir.path().join("scratch.txt")));
+/// // `dir` and `scratch.txt` are deleted here.
+/// # Ok(())
+/// # }
+/// ```
+#[unstable(feature = "fs_tempfile", issue = "0")]
+pub fn tempdir() -> io::Result<TempDir> {
+    tempdir_in(env::temp_dir())
+}
+
+/// Creates a new, empty temporary directory with a random name inside of the
+/// directory `dir`.
+///
+/// The directory and everything inside of it is removed when the returned
+/// `TempDir` is dropped.
+///
+/// # Platform-specific behavior
+///
+/// On Unix the directory is created with permissions `0700`. On Windows it
+/// inherits the permissions of `dir`.
+///
+/// Note that, this [may change in the future][changes].
+/// [changes]: ../io/index.html#platform-specific-behavior
+///
+/// # Errors
+///
+/// This function will return an error if `dir` doesn't exist or isn't a
+/// writable directory.
+#[unstable(feature = "fs_tempfile", issue = "0")]
+pub fn tempdir_in<P: AsRef<Path>>(dir: P) -> io::Result<TempDir> {
+    create_unique(dir.as_ref(), |path| fs_imp::tempdir_create(path))
+        .map(|((), path)| TempDir { path: Some(path) })
+}
+
+// Repeatedly calls `create` with random paths in `dir` until it doesn't fail
+// because the path already exists.
+fn create_unique<F, T>(dir: &Path, mut create: F) -> io::Result<(T, PathBuf)>
+    where F: FnMut(&Path) -> io::Result<T>
+{
+    const MAX_ATTEMPTS: u32 = 100;
+
+    let mut rng = rand::thread_rng();
+    for _ in 0..MAX_ATTEMPTS {
+        let path = dir.join(&format!(".tmp{:016x}", rng.next_u64()));
+        match create(&path) {
+            Ok(t) => return Ok((t, path)),
+            Err(ref e) if e.kind() == io::ErrorKind::AlreadyExists => {}
+            Err(e) => return Err(e),
+        }
+    }
+    Err(io::Error::new(io::ErrorKind::AlreadyExists,
+                       "too many temporary files exist"))
+}
+
+impl TempDir {
+    /// Returns the path of this temporary directory.
+    #[unstable(feature = "fs_tempfile", issue = "0")]
+    pub fn path(&self) -> &Path {
+        self.path.as_ref().unwrap()
+    }
+
+    /// Consumes the `TempDir` without deleting the directory, returning its
+    /// path.
+    #[unstable(feature = "fs_tempfile", issue = "0")]
+    pub fn into_path(mut self) -> PathBuf {
+        self.path.take().unwrap()
+    }
+
+    /// Deletes the directory and all of its contents, returning any error
+    /// that is encountered while doing so.
+    #[unstable(feature = "fs_tempfile", issue = "0")]
+    pub fn close(mut self) -> io::Result<()> {
+        remove_dir_all(self.path.take().unwrap())
+    }
+}
+
+#[unstable(feature = "fs_tempfile", issue = "0")]
+impl Drop for TempDir {
+    fn drop(&mut self) {
+        if let Some(ref path) = self.path {
+            let _ = remove_dir_all(path);
+        }
+    }
+}
+
+#[unstable(feature = "fs_tempfile", issue = "0")]
+impl fmt::Debug for TempDir {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("TempDir").field("path", &self.path()).finish()
+    }
+}
+
 impl DirBuilder {
     /// Creates a new set of options with default mode/security settings for all
     /// platforms and also non-recursive.
@@ -1453,6 +1758,170 @@ impl DirBuilder {
     }
 }
 
+impl CopyOptions {
+    /// Creates a blank new set of options ready for configuration.
+    ///
+    /// Symbolic links are not followed, existing entries are not overwritten
+    /// and directory permissions are preserved.
+    #[unstable(feature = "fs_copy_dir_all", issue = "0")]
+    pub fn new() -> CopyOptions {
+        CopyOptions {
+            follow_symlinks: false,
+            overwrite: false,
+            preserve_permissions: true,
+        }
+    }
+
+    /// Sets the option for following symbolic links.
+    ///
+    /// When `true`, the target of each symbolic link is copied in its place.
+    /// When `false`, a new symbolic link with the same target is created at
+    /// the destination instead. This also applies to `from` itself.
+    ///
+    /// This option defaults to `false`.
+    #[unstable(feature = "fs_copy_dir_all", issue = "0")]
+    pub fn follow_symlinks(&mut self, follow_symlinks: bool) -> &mut CopyOptions {
+        self.follow_symlinks = follow_symlinks;
+        self
+    }
+
+    /// Sets the option for overwriting entries which already exist at the
+    /// destination.
+    ///
+    /// When `true`, existing files and symbolic links are replaced and
+    /// existing directories are merged with the copied ones. When `false`,
+    /// the copy stops with an error of kind `AlreadyExists` at the first
+    /// destination entry which is already present.
+    ///
+    /// This option defaults to `false`.
+    #[unstable(feature = "fs_copy_dir_all", issue = "0")]
+    pub fn overwrite(&mut self, overwrite: bool) -> &mut CopyOptions {
+        self.overwrite = overwrite;
+        self
+    }
+
+    /// Sets the option for copying the permissions of source directories to
+    /// the directories that are created for them.
+    ///
+    /// Permissions are applied once a directory's contents have been copied,
+    /// so read-only source directories can still be copied. Timestamps and
+    /// ownership are never preserved.
+    ///
+    /// This option defaults to `true`.
+    #[unstable(feature = "fs_copy_dir_all", issue = "0")]
+    pub fn preserve_permissions(&mut self, preserve: bool) -> &mut CopyOptions {
+        self.preserve_permissions = preserve;
+        self
+    }
+
+    fn copy_dir_all(&self, from: &Path, to: &Path) -> io::Result<()> {
+        let meta = if self.follow_symlinks {
+            metadata(from)?
+        } else {
+            symlink_metadata(from)?
+        };
+        if meta.file_type().is_symlink() && metadata(from)?.is_dir() {
+            // Like the links inside of the tree, a link to the source
+            // directory itself is recreated rather than followed.
+            self.make_room_for(to)?;
+            return fs_imp::copy_symlink(from, to)
+        }
+        if !meta.is_dir() {
+            return Err(io::Error::new(io::ErrorKind::InvalidInput,
+                                      "the source path is not an existing directory"))
+        }
+
+        // Copying a directory into one of its own descendants would never
+        // terminate, as every created directory is copied again. This is
+        // checked before anything is created.
+        if destination_path(to)?.starts_with(canonicalize(from)?) {
+            return Err(io::Error::new(io::ErrorKind::InvalidInput,
+                                      "the destination path is inside of the source directory"))
+        }
+        self.create_dir(to)?;
+        self.copy_dir_contents(from, to, &meta)
+    }
+
+    fn create_dir(&self, to: &Path) -> io::Result<()> {
+        match DirBuilder::new().create(to) {
+            Err(ref e) if e.kind() == io::ErrorKind::AlreadyExists &&
+                          self.overwrite && to.is_dir() => Ok(()),
+            result => result,
+        }
+    }
+
+    fn copy_dir_contents(&self, from: &Path, to: &Path, meta: &Metadata) -> io::Result<()> {
+        for entry in read_dir(from)? {
+            let entry = entry?;
+            let src = entry.path();
+            let dst = to.join(entry.file_name());
+            let file_type = entry.file_type()?;
+
+            if file_type.is_symlink() && !self.follow_symlinks {
+                self.make_room_for(&dst)?;
+                fs_imp::copy_symlink(&src, &dst)?;
+                continue
+            }
+
+            let src_meta = if file_type.is_symlink() {
+                metadata(&src)?
+            } else {
+                entry.metadata()?
+            };
+            if src_meta.is_dir() {
+                self.create_dir(&dst)?;
+                self.copy_dir_contents(&src, &dst, &src_meta)?;
+            } else {
+                self.make_room_for(&dst)?;
+                copy(&src, &dst)?;
+            }
+        }
+
+        if self.preserve_permissions {
+            set_permissions(to, meta.permissions())?;
+        }
+        Ok(())
+    }
+
+    // Checks that a file or symlink may be created at `dst`, removing a
+    // symlink already there so that it is replaced rather than written
+    // through.
+    fn make_room_for(&self, dst: &Path) -> io::Result<()> {
+        let existing = match symlink_metadata(dst) {
+            Ok(meta) => meta,
+            Err(ref e) if e.kind() == io::ErrorKind::NotFound => return Ok(()),
+            Err(e) => return Err(e),
+        };
+        if !self.overwrite {
+            return Err(io::Error::new(io::ErrorKind::AlreadyExists,
+                                      "the destination path already exists"))
+        }
+        if existing.is_dir() {
+            return Err(io::Error::new(io::ErrorKind::InvalidInput,
+                                      "cannot overwrite a directory with a non-directory"))
+        }
+        if existing.file_type().is_symlink() {
+            fs_imp::remove_symlink(dst)?;
+        }
+        Ok(())
+    }
+}
+
+// The canonical path that `to` has, or will have once it is created in its
+// existing parent directory.
+fn destination_path(to: &Path) -> io::Result<PathBuf> {
+    if let Ok(path) = canonicalize(to) {
+        return Ok(path)
+    }
+    match (to.parent(), to.file_name()) {
+        (Some(parent), Some(name)) => {
+            let parent = if parent.as_os_str().is_empty() { Path::new(".") } else { parent };
+            Ok(canonicalize(parent)?.join(name))
+        }
+        _ => canonicalize(to),
+    }
+}
+
 impl AsInnerMut<fs_imp::DirBuilder> for DirBuilder {
     fn as_inner_mut(&mut self) -> &mut fs_imp::DirBuilder {
         &mut self.inner
@@ -1464,7 +1933,7 @@ mod tests {
     use prelude::v1::*;
     use io::prelude::*;
 
-    use fs::{self, File, OpenOptions};
+    use fs::{self, CopyOptions, File, OpenOptions};
     use io::{ErrorKind, SeekFrom};
     use path::Path;
     use rand::{StdRng, Rng};
@@ -1867,6 +2336,76 @@ mod tests {
         }
     }
 
+    #[test]
+    #[cfg(unix)]
+    fn recursive_rmdir_toctou() {
+        use sync::Arc;
+        use sync::atomic::{AtomicBool, Ordering};
+        use thread;
+
+        // Another thread keeps swapping a directory in the tree for a
+        // symlink to a directory outside of it while the tree is removed.
+        let tmpdir = tmpdir();
+        let victim = tmpdir.join("victim");
+        let canary = victim.join("do_not_delete");
+        check!(fs::create_dir_all(&victim));
+        check!(check!(File::create(&canary)).write(b"foo"));
+
+        let tree = tmpdir.join("tree");
+        let dir = tree.join("dir");
+        let moved = tmpdir.join("moved");
+        let done = Arc::new(AtomicBool::new(false));
+        let attacker = {
+            let (done, victim, dir) = (done.clone(), victim.clone(), dir.clone());
+            thread::spawn(move || {
+                // any of these may fail, depending on how far the removal got
+                while !done.load(Ordering::SeqCst) {
+                    let _ = fs::rename(&dir, &moved);
+                    let _ = symlink_dir(&victim, &dir);
+                    let _ = fs::remove_file(&dir);
+                    let _ = fs::rename(&moved, &dir);
+                }
+            })
+        };
+
+        // the files next to `dir` give the other thread time to swap it
+        // between listing the tree and opening `dir`
+        for _ in 0..300 {
+            let _ = fs::create_dir_all(&dir);
+            for i in 0..100 {
+                let _ = File::create(tree.join(i.to_string()));
+            }
+            let _ = fs::remove_dir_all(&tree);
+            assert!(canary.exists());
+        }
+        done.store(true, Ordering::SeqCst);
+        attacker.join().unwrap();
+        assert!(canary.exists());
+    }
+
+    #[test]
+    #[cfg(windows)]
+    fn recursive_rmdir_with_open_file() {
+        use os::windows::fs::OpenOptionsExt;
+        use thread;
+        use time::Duration;
+
+        // The file can't be deleted while it is open without sharing delete
+        // access, so the removal has to wait until the handle is closed.
+        let tmpdir = tmpdir();
+        let dir = tmpdir.join("d1");
+        let file = dir.join("f");
+        check!(fs::create_dir_all(&dir));
+        let f = check!(OpenOptions::new().write(true).create(true).share_mode(0).open(&file));
+        let closer = thread::spawn(move || {
+            thread::sleep(Duration::from_millis(100));
+            drop(f);
+        });
+        check!(fs::remove_dir_all(&dir));
+        closer.join().unwrap();
+        assert!(!dir.exists());
+    }
+
     #[test]
     fn unicode_path_is_dir() {
         assert!(Path::new(".").is_dir());
@@ -2457,6 +2996,203 @@ mod tests {
         assert!(d.exists());
     }
 
+    #[test]
+    fn copy_dir_all_ok() {
+        let tmpdir = tmpdir();
+        let from = tmpdir.join("from");
+        let to = tmpdir.join("to");
+        check!(fs::create_dir_all(&from.join("a/b")));
+        check!(check!(File::create(&from.join("top.txt"))).write(b"top"));
+        check!(check!(File::create(&from.join("a/b/deep.txt"))).write(b"deep"));
+
+        check!(fs::copy_dir_all(&from, &to, &CopyOptions::new()));
+        assert!(to.join("a/b").is_dir());
+        let mut v = Vec::new();
+        check!(check!(File::open(&to.join("top.txt"))).read_to_end(&mut v));
+        assert_eq!(v, b"top");
+        v.clear();
+        check!(check!(File::open(&to.join("a/b/deep.txt"))).read_to_end(&mut v));
+        assert_eq!(v, b"deep");
+        assert!(from.join("a/b/deep.txt").exists());
+    }
+
+    #[test]
+    fn copy_dir_all_overwrite() {
+        let tmpdir = tmpdir();
+        let from = tmpdir.join("from");
+        let to = tmpdir.join("to");
+        check!(fs::create_dir(&from));
+        check!(fs::create_dir(&to));
+        check!(check!(File::create(&from.join("f"))).write(b"new"));
+        check!(check!(File::create(&to.join("f"))).write(b"old"));
+
+        match fs::copy_dir_all(&from, &to, &CopyOptions::new()) {
+            Ok(..) => panic!("wanted a failure"),
+            Err(e) => assert_eq!(e.kind(), ErrorKind::AlreadyExists),
+        }
+
+        let mut options = CopyOptions::new();
+        options.overwrite(true);
+        check!(fs::copy_dir_all(&from, &to, &options));
+        let mut v = Vec::new();
+        check!(check!(File::open(&to.join("f"))).read_to_end(&mut v));
+        assert_eq!(v, b"new");
+    }
+
+    #[test]
+    fn copy_dir_all_into_itself() {
+        let tmpdir = tmpdir();
+        let from = tmpdir.join("from");
+        check!(fs::create_dir(&from));
+        match fs::copy_dir_all(&from, &from.join("inner"), &CopyOptions::new()) {
+            Ok(..) => panic!("wanted a failure"),
+            Err(e) => assert_eq!(e.kind(), ErrorKind::InvalidInput),
+        }
+        assert!(!from.join("inner").exists());
+    }
+
+    #[test]
+    fn copy_dir_all_src_not_dir() {
+        let tmpdir = tmpdir();
+        let from = tmpdir.join("from");
+        let to = tmpdir.join("to");
+        check!(File::create(&from));
+        assert!(fs::copy_dir_all(&from, &to, &CopyOptions::new()).is_err());
+        assert!(!to.exists());
+    }
+
+    #[test]
+    fn copy_dir_all_symlinks() {
+        let tmpdir = tmpdir();
+        if !got_symlink_permission(&tmpdir) { return };
+
+        let from = tmpdir.join("from");
+        let target = tmpdir.join("target.txt");
+        check!(fs::create_dir(&from));
+        check!(check!(File::create(&target)).write(b"target"));
+        check!(symlink_file(&target, &from.join("link")));
+
+        let kept = tmpdir.join("kept");
+        check!(fs::copy_dir_all(&from, &kept, &CopyOptions::new()));
+        let meta = check!(fs::symlink_metadata(&kept.join("link")));
+        assert!(meta.file_type().is_symlink());
+        assert_eq!(check!(fs::read_link(&kept.join("link"))), target);
+
+        let followed = tmpdir.join("followed");
+        let mut options = CopyOptions::new();
+        options.follow_symlinks(true);
+        check!(fs::copy_dir_all(&from, &followed, &options));
+        let meta = check!(fs::symlink_metadata(&followed.join("link")));
+        assert!(meta.is_file());
+    }
+
+    #[test]
+    fn copy_dir_all_symlinked_source() {
+        let tmpdir = tmpdir();
+        if !got_symlink_permission(&tmpdir) { return };
+
+        let from = tmpdir.join("from");
+        let link = tmpdir.join("link");
+        check!(fs::create_dir(&from));
+        check!(File::create(&from.join("f")));
+        check!(symlink_dir(&from, &link));
+
+        let kept = tmpdir.join("kept");
+        check!(fs::copy_dir_all(&link, &kept, &CopyOptions::new()));
+        assert!(check!(fs::symlink_metadata(&kept)).file_type().is_symlink());
+        assert_eq!(check!(fs::read_link(&kept)), from);
+
+        let followed = tmpdir.join("followed");
+        let mut options = CopyOptions::new();
+        options.follow_symlinks(true);
+        check!(fs::copy_dir_all(&link, &followed, &options));
+        assert!(check!(fs::symlink_metadata(&followed)).is_dir());
+        assert!(followed.join("f").is_file());
+
+        match fs::copy_dir_all(&link, &from.join("inner"), &options) {
+            Ok(..) => panic!("wanted a failure"),
+            Err(e) => assert_eq!(e.kind(), ErrorKind::InvalidInput),
+        }
+        assert!(!from.join("inner").exists());
+    }
+
+    #[test]
+    fn tempfile_read_write() {
+        let tmpdir = tmpdir();
+        let mut f = check!(fs::tempfile_in(tmpdir.path()));
+        check!(f.write_all(b"hello"));
+        check!(f.seek(SeekFrom::Start(0)));
+        let mut v = Vec::new();
+        check!(f.read_to_end(&mut v));
+        assert_eq!(v, b"hello");
+        drop(f);
+        assert_eq!(check!(fs::read_dir(tmpdir.path())).count(), 0);
+    }
+
+    #[test]
+    fn tempfile_names_are_unique() {
+        let tmpdir = tmpdir();
+        let mut names = Vec::new();
+        for _ in 0..16 {
+            let (_, path) = check!(super::create_unique(tmpdir.path(), |path| {
+                OpenOptions::new().write(true).create_new(true).open(path)
+            }));
+            assert_eq!(path.parent(), Some(tmpdir.path()));
+            assert!(!names.contains(&path));
+            names.push(path);
+        }
+
+        let a = check!(fs::tempdir_in(tmpdir.path()));
+        let b = check!(fs::tempdir_in(tmpdir.path()));
+        assert!(a.path() != b.path());
+        let _f = check!(fs::tempfile_in(tmpdir.path()));
+        let _g = check!(fs::tempfile_in(tmpdir.path()));
+    }
+
+    #[test]
+    fn tempfile_in_missing_dir() {
+        let tmpdir = tmpdir();
+        assert!(fs::tempfile_in(&tmpdir.join("missing")).is_err());
+    }
+
+    #[test]
+    fn tempdir_deleted_on_drop() {
+        let tmpdir = tmpdir();
+        let path = {
+            let dir = check!(fs::tempdir_in(tmpdir.path()));
+            assert!(dir.path().is_dir());
+            assert!(dir.path().starts_with(tmpdir.path()));
+            check!(fs::create_dir(&dir.path().join("sub")));
+            check!(File::create(&dir.path().join("sub/file")));
+            dir.path().to_path_buf()
+        };
+        assert!(!path.exists());
+    }
+
+    #[test]
+    fn tempdir_into_path_and_close() {
+        let tmpdir = tmpdir();
+        let kept = check!(fs::tempdir_in(tmpdir.path())).into_path();
+        assert!(kept.is_dir());
+
+        let dir = check!(fs::tempdir_in(tmpdir.path()));
+        let path = dir.path().to_path_buf();
+        check!(dir.close());
+        assert!(!path.exists());
+    }
+
+    #[test]
+    fn canonicalize_simplified_ok() {
+        let tmpdir = tmpdir();
+        let file = tmpdir.join("file.txt");
+        check!(File::create(&file));
+        let simplified = check!(fs::canonicalize_simplified(&file));
+        assert_eq!(check!(fs::canonicalize(&simplified)), check!(fs::canonicalize(&file)));
+        if cfg!(windows) {
+            assert!(!simplified.to_str().unwrap().starts_with(r"\\?\"));
+        }
+    }
+
     #[test]
     fn metadata_access_times() {
         let tmpdir = tmpdir();
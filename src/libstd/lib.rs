@@ -216,21 +216,38 @@
 #![feature(allow_internal_unstable)]
 #![feature(asm)]
 #![feature(associated_consts)]
+#![feature(async_iterator)]
 #![feature(borrow_state)]
 #![feature(box_syntax)]
+#![feature(build_hasher_simple_hash_one)]
 #![feature(cfg_target_vendor)]
 #![feature(cfg_target_thread_local)]
 #![feature(char_internals)]
 #![feature(collections)]
 #![feature(collections_bound)]
 #![feature(const_fn)]
+#![feature(copysign)]
 #![feature(core_float)]
+#![feature(core_float_rounding)]
 #![feature(core_intrinsics)]
 #![feature(dropck_parametricity)]
+#![feature(extend_one)]
+#![feature(float_bits)]
+#![feature(float_clamp)]
+#![feature(float_decomposition)]
+#![feature(float_display_len)]
+#![feature(float_exp_mantissa)]
 #![feature(float_extras)]
+#![feature(float_from_int)]
+#![feature(float_from_str_exact)]
+#![feature(float_from_str_rounded)]
 #![feature(float_from_str_radix)]
+#![feature(float_nan_payload)]
+#![feature(float_rounding_mode)]
+#![feature(flt2dec)]
 #![feature(fnbox)]
 #![feature(fn_traits)]
+#![feature(futures_api)]
 #![feature(heap_api)]
 #![feature(hashmap_hasher)]
 #![feature(inclusive_range)]
@@ -242,6 +259,7 @@
 #![feature(linkage)]
 #![feature(macro_reexport)]
 #![cfg_attr(test, feature(map_values_mut))]
+#![feature(nonzero)]
 #![feature(num_bits_bytes)]
 #![feature(old_wrapping)]
 #![feature(on_unimplemented)]
@@ -249,11 +267,15 @@
 #![feature(optin_builtin_traits)]
 #![feature(panic_unwind)]
 #![feature(placement_in_syntax)]
+#![feature(portable_simd)]
 #![feature(rand)]
 #![feature(raw)]
+#![feature(raw_float)]
 #![feature(repr_simd)]
 #![feature(reflect_marker)]
+#![feature(round_ties_even)]
 #![feature(rustc_attrs)]
+#![feature(saturating_int)]
 #![feature(shared)]
 #![feature(sip_hash_13)]
 #![feature(slice_bytes)]
@@ -266,6 +288,8 @@
 #![feature(str_utf16)]
 #![feature(test, rustc_private)]
 #![feature(thread_local)]
+#![feature(total_cmp)]
+#![feature(try_borrow)]
 #![feature(unboxed_closures)]
 #![feature(unicode)]
 #![feature(unique)]
@@ -294,7 +318,8 @@
 // imported by the compiler (via our #[no_std] attribute) In this case we just
 // add a new crate name so we can attach the reexports to it.
 #[macro_reexport(assert, assert_eq, debug_assert, debug_assert_eq,
-                 unreachable, unimplemented, write, writeln, try)]
+                 unreachable, unimplemented, write, writeln, try, ready, pin,
+                 borrow_at, borrow_mut_at)]
 extern crate core as __core;
 
 #[macro_use]
@@ -349,11 +374,27 @@ pub use core::raw;
 pub use core::result;
 #[stable(feature = "rust1", since = "1.0.0")]
 pub use core::option;
+#[unstable(feature = "portable_simd", issue = "0")]
+pub use core::simd;
+#[unstable(feature = "futures_api", issue = "0")]
+pub use core::future;
+#[unstable(feature = "async_iterator", issue = "0")]
+pub use core::async_iter;
+#[unstable(feature = "pin", issue = "0")]
+pub use core::pin;
+#[unstable(feature = "hint", issue = "0")]
+pub use core::hint;
+#[unstable(feature = "new_range_api", issue = "0")]
+pub use core::range;
+#[unstable(feature = "fixed_size_array", issue = "27778")]
+pub use core::array;
 
 pub mod error;
 
 #[stable(feature = "rust1", since = "1.0.0")]
 pub use alloc::boxed;
+#[unstable(feature = "heap_api", issue = "27700")]
+pub use alloc::heap;
 #[stable(feature = "rust1", since = "1.0.0")]
 pub use alloc::rc;
 
@@ -401,6 +442,8 @@ pub use core::i16;
 pub use core::i32;
 #[stable(feature = "rust1", since = "1.0.0")]
 pub use core::i64;
+#[unstable(feature = "i128", issue = "0")]
+pub use core::i128;
 
 #[stable(feature = "rust1", since = "1.0.0")]
 pub use core::usize;
@@ -412,9 +455,15 @@ pub use core::u16;
 pub use core::u32;
 #[stable(feature = "rust1", since = "1.0.0")]
 pub use core::u64;
+#[unstable(feature = "i128", issue = "0")]
+pub use core::u128;
 
 #[path = "num/f32.rs"]   pub mod f32;
 #[path = "num/f64.rs"]   pub mod f64;
+#[unstable(feature = "f16", issue = "0")]
+pub use core::f16;
+#[unstable(feature = "f128", issue = "0")]
+pub use core::f128;
 
 pub mod ascii;
 
@@ -438,6 +487,7 @@ pub mod panic;
 pub mod path;
 pub mod process;
 pub mod sync;
+pub mod task;
 pub mod time;
 mod memchr;
 
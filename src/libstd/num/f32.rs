@@ -15,14 +15,22 @@
 #![stable(feature = "rust1", since = "1.0.0")]
 #![allow(missing_docs)]
 
+#[cfg(not(test))]
+use cmp::Ordering;
 #[cfg(not(test))]
 use core::num;
 #[cfg(not(test))]
+use core::num::flt2dec;
+#[cfg(not(test))]
 use intrinsics;
 #[cfg(not(test))]
 use libc::c_int;
 #[cfg(not(test))]
 use num::FpCategory;
+#[cfg(not(test))]
+use num::{ParseFloatError, ParseFloatOptions, RoundingMode};
+#[cfg(not(test))]
+use num::convert::{FromInt, InexactIntError};
 
 
 #[stable(feature = "rust1", since = "1.0.0")]
@@ -33,6 +41,8 @@ pub use core::f32::{MIN_EXP, MAX_EXP, MIN_10_EXP};
 pub use core::f32::{MAX_10_EXP, NAN, INFINITY, NEG_INFINITY};
 #[stable(feature = "rust1", since = "1.0.0")]
 pub use core::f32::{MIN, MIN_POSITIVE, MAX};
+#[unstable(feature = "float_display_len", issue = "0")]
+pub use core::f32::{MAX_SIG_DIGITS, MAX_DISPLAY_LEN, MAX_EXP_DISPLAY_LEN};
 #[stable(feature = "rust1", since = "1.0.0")]
 pub use core::f32::consts;
 
@@ -276,6 +286,71 @@ impl f32 {
         num::Float::integer_decode(self)
     }
 
+    /// Returns `(exp, mantissa)` such that `abs(self) = mantissa * 2^exp`.
+    ///
+    /// Normal numbers have a 24-bit `mantissa`, with the hidden bit made
+    /// explicit, and so have a unique representation. Subnormal numbers and
+    /// zeroes have the smallest exponent, `-149`. Infinities and NaNs decode
+    /// like normal numbers with the largest exponent field.
+    ///
+    /// This is the `frexp` of C with an integral mantissa.
+    ///
+    /// ```
+    /// #![feature(float_exp_mantissa)]
+    ///
+    /// assert_eq!(1.0_f32.to_exp_mantissa(), (-23, 1 << 23));
+    /// assert_eq!((-0.75_f32).to_exp_mantissa(), (-24, 3 << 22));
+    /// assert_eq!(2.0_f32.powi(-149).to_exp_mantissa(), (-149, 1));
+    /// ```
+    #[unstable(feature = "float_exp_mantissa", issue = "0")]
+    #[inline]
+    pub fn to_exp_mantissa(self) -> (i32, u64) { num::Float::to_exp_mantissa(self) }
+
+    /// Returns `mantissa * 2^exp`, rounded to the nearest value and ties to
+    /// even.
+    ///
+    /// Results too large for `f32` become infinity, and results too small
+    /// are correctly rounded to subnormals or zero.
+    ///
+    /// ```
+    /// #![feature(float_exp_mantissa)]
+    ///
+    /// use std::f32;
+    ///
+    /// assert_eq!(f32::from_exp_mantissa(-2, 3), 0.75);
+    /// assert_eq!(f32::from_exp_mantissa(10000, 1), f32::INFINITY);
+    /// let x = -1234.5_f32;
+    /// let (exp, mantissa) = x.to_exp_mantissa();
+    /// assert_eq!(f32::from_exp_mantissa(exp, mantissa), x.abs());
+    /// ```
+    #[unstable(feature = "float_exp_mantissa", issue = "0")]
+    #[inline]
+    pub fn from_exp_mantissa(exp: i32, mantissa: u64) -> f32 {
+        num::Float::from_exp_mantissa(exp, mantissa)
+    }
+
+    /// Returns the exponent of the highest set bit of `abs(self)`, i.e.
+    /// `floor(log2(abs(self)))`, as an integer.
+    ///
+    /// Subnormal numbers give their actual exponent. Zero and NaN give
+    /// `i32::MIN`, and infinities give `i32::MAX`.
+    ///
+    /// ```
+    /// #![feature(float_exp_mantissa)]
+    ///
+    /// use std::{f32, i32};
+    ///
+    /// assert_eq!(1.0_f32.ilogb(), 0);
+    /// assert_eq!((-10.0_f32).ilogb(), 3);
+    /// assert_eq!(0.3_f32.ilogb(), -2);
+    /// assert_eq!(2.0_f32.powi(-149).ilogb(), -149);
+    /// assert_eq!(0.0_f32.ilogb(), i32::MIN);
+    /// assert_eq!(f32::INFINITY.ilogb(), i32::MAX);
+    /// ```
+    #[unstable(feature = "float_exp_mantissa", issue = "0")]
+    #[inline]
+    pub fn ilogb(self) -> i32 { num::Float::ilogb(self) }
+
     /// Returns the largest integer less than or equal to a number.
     ///
     /// ```
@@ -342,6 +417,27 @@ impl f32 {
         unsafe { intrinsics::roundf32(self) }
     }
 
+    /// Returns the nearest integer to a number. Round half-way cases to the
+    /// even integer.
+    ///
+    /// ```
+    /// #![feature(round_ties_even)]
+    ///
+    /// let f = 3.3_f32;
+    /// let g = -3.3_f32;
+    ///
+    /// assert_eq!(f.round_ties_even(), 3.0);
+    /// assert_eq!(g.round_ties_even(), -3.0);
+    /// assert_eq!(2.5_f32.round_ties_even(), 2.0);
+    /// assert_eq!(3.5_f32.round_ties_even(), 4.0);
+    /// assert_eq!((-2.5_f32).round_ties_even(), -2.0);
+    /// ```
+    #[unstable(feature = "round_ties_even", issue = "0")]
+    #[inline]
+    pub fn round_ties_even(self) -> f32 {
+        num::Float::round_ties_even(self)
+    }
+
     /// Returns the integer part of a number.
     ///
     /// ```
@@ -401,6 +497,9 @@ impl f32 {
     /// - `-1.0` if the number is negative, `-0.0` or `NEG_INFINITY`
     /// - `NAN` if the number is `NAN`
     ///
+    /// Unlike the integer `signum`, zeroes are not mapped to zero. Apart from
+    /// NaNs, `x.signum()` is the same as `1.0.copysign(x)`.
+    ///
     /// ```
     /// use std::f32;
     ///
@@ -415,6 +514,235 @@ impl f32 {
     #[inline]
     pub fn signum(self) -> f32 { num::Float::signum(self) }
 
+    /// Returns a number with the magnitude of `self` and the sign of `sign`.
+    ///
+    /// The sign bit of `sign` is used as it is, so a `sign` of `-0.0` or of a
+    /// negative NaN gives a negative result. If `self` is NaN, the result is
+    /// a NaN with the sign of `sign`.
+    ///
+    /// ```
+    /// #![feature(copysign)]
+    ///
+    /// use std::f32;
+    ///
+    /// assert_eq!(3.5_f32.copysign(-0.0), -3.5);
+    /// assert_eq!((-3.5_f32).copysign(1.0), 3.5);
+    /// assert!(0.0_f32.copysign(-1.0).is_sign_negative());
+    /// assert!(f32::NAN.copysign(-1.0).is_nan());
+    /// ```
+    #[unstable(feature = "copysign", issue = "0")]
+    #[inline]
+    pub fn copysign(self, sign: f32) -> f32 { num::Float::copysign(self, sign) }
+
+    /// Returns the IEEE 754 binary32 encoding of `self`.
+    ///
+    /// This is the same as transmuting `self` to `u32`, without `unsafe`. It
+    /// keeps the sign of zeroes and the payload of NaNs, so it tells apart
+    /// values that `==` doesn't.
+    ///
+    /// ```
+    /// assert_eq!(1.0_f32.to_bits(), 0x3f800000);
+    /// assert!((-0.0_f32).to_bits() != 0.0_f32.to_bits());
+    /// ```
+    #[stable(feature = "float_bits_conv", since = "1.12.0")]
+    #[inline]
+    pub fn to_bits(self) -> u32 { num::Float::to_bits(self) as u32 }
+
+    /// Returns the `f32` with the IEEE 754 binary32 encoding `v`.
+    ///
+    /// This is the same as transmuting `v` to `f32`, without `unsafe`. Every
+    /// value of `v` is a valid encoding, although some are NaNs.
+    ///
+    /// ```
+    /// assert_eq!(f32::from_bits(0x40490fdb), 3.1415927);
+    /// assert!(f32::from_bits(!0).is_nan());
+    /// assert_eq!(f32::from_bits(std::f32::consts::PI.to_bits()), std::f32::consts::PI);
+    /// ```
+    #[stable(feature = "float_bits_conv", since = "1.12.0")]
+    #[inline]
+    pub fn from_bits(v: u32) -> f32 { num::Float::from_bits(v as u64) }
+
+    /// Returns a positive quiet NaN with the given payload.
+    ///
+    /// The payload goes into the low 22 bits of the significand, below
+    /// the quiet bit, where NaN-boxing keeps its values. `NanPayload`
+    /// formats it, in a syntax that `parse` turns back into the same NaN.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `payload` does not fit in 22 bits.
+    ///
+    /// ```
+    /// #![feature(float_nan_payload)]
+    ///
+    /// use std::f32;
+    /// use std::num::NanPayload;
+    ///
+    /// let x = f32::nan_with_payload(0x1f);
+    /// assert!(x.is_nan());
+    /// assert_eq!(x.payload(), Some(0x1f));
+    /// assert_eq!(format!("{:?}", NanPayload(-x)), "-NaN(0x1f)");
+    /// assert_eq!("-NaN(0x1f)".parse::<f32>().unwrap().payload(), Some(0x1f));
+    /// ```
+    #[unstable(feature = "float_nan_payload", issue = "0")]
+    #[inline]
+    pub fn nan_with_payload(payload: u64) -> f32 { num::Float::nan_with_payload(payload) }
+
+    /// Returns the payload of a NaN, or `None` if `self` is not NaN.
+    ///
+    /// The payload is the significand without the quiet bit, so it is
+    /// `Some(0)` for the NaN that arithmetic produces, and a signaling NaN
+    /// has the same payload as the quiet NaN it would become.
+    ///
+    /// ```
+    /// #![feature(float_nan_payload)]
+    ///
+    /// use std::f32;
+    ///
+    /// assert_eq!(f32::nan_with_payload(0x3f_ffff).payload(), Some(0x3f_ffff));
+    /// assert_eq!(f32::NAN.payload(), Some(0));
+    /// assert_eq!(1.0_f32.payload(), None);
+    /// ```
+    #[unstable(feature = "float_nan_payload", issue = "0")]
+    #[inline]
+    pub fn payload(self) -> Option<u64> { num::Float::payload(self) }
+
+    /// Writes `self` into `buf` as `Display` does without a precision, and
+    /// returns the written string, or `None` if `buf` is too short.
+    ///
+    /// This prints the shortest digits that read back as `self`, and neither
+    /// allocates nor depends on anything but `self`, so it can format into a
+    /// buffer on the stack. A buffer of `MAX_DISPLAY_LEN` bytes is always
+    /// enough.
+    ///
+    /// ```
+    /// #![feature(float_write_buf, float_display_len)]
+    ///
+    /// use std::f32;
+    ///
+    /// let mut buf = [0; f32::MAX_DISPLAY_LEN];
+    /// assert_eq!((0.1_f32 + 0.2).write_shortest(&mut buf), Some("0.3"));
+    /// assert_eq!(f32::NEG_INFINITY.write_shortest(&mut buf), Some("-inf"));
+    /// assert_eq!(1e10_f32.write_shortest(&mut buf[..10]), None);
+    /// ```
+    #[unstable(feature = "float_write_buf", issue = "0")]
+    #[inline]
+    pub fn write_shortest(self, buf: &mut [u8]) -> Option<&str> {
+        num::Float::write_shortest(self, buf)
+    }
+
+    /// Writes `self` into `buf` as `Display` does with a precision of
+    /// `precision`, and returns the written string, or `None` if `buf` is too
+    /// short.
+    ///
+    /// The output has at most 39 integral digits, so a buffer of
+    /// `1 + 39 + 1 + precision` bytes is always enough.
+    ///
+    /// ```
+    /// #![feature(float_write_buf)]
+    ///
+    /// let mut buf = [0; 16];
+    /// assert_eq!((-2.5_f32).write_fixed(&mut buf, 3), Some("-2.500"));
+    /// assert_eq!(0.126_f32.write_fixed(&mut buf, 2), Some("0.13"));
+    /// assert_eq!(1e10_f32.write_fixed(&mut buf, 6), None);
+    /// ```
+    #[unstable(feature = "float_write_buf", issue = "0")]
+    #[inline]
+    pub fn write_fixed(self, buf: &mut [u8], precision: usize) -> Option<&str> {
+        num::Float::write_fixed(self, buf, precision)
+    }
+
+    /// Restricts a value to the interval `[min, max]`.
+    ///
+    /// Returns `max` if `self` is greater than `max`, and `min` if `self` is
+    /// less than `min`. Otherwise this returns `self`, which may be NaN.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `min > max`, `min` is NaN, or `max` is NaN. Use
+    /// [`clamp_total`](#method.clamp_total) to accept any bounds.
+    ///
+    /// ```
+    /// #![feature(float_clamp)]
+    ///
+    /// use std::f32;
+    ///
+    /// assert_eq!((-3.0_f32).clamp(-2.0, 1.0), -2.0);
+    /// assert_eq!(0.0_f32.clamp(-2.0, 1.0), 0.0);
+    /// assert_eq!(2.0_f32.clamp(-2.0, 1.0), 1.0);
+    /// assert!(f32::NAN.clamp(-2.0, 1.0).is_nan());
+    /// ```
+    #[unstable(feature = "float_clamp", issue = "0")]
+    #[inline]
+    pub fn clamp(self, min: f32, max: f32) -> f32 { num::Float::clamp(self, min, max) }
+
+    /// Restricts a value to the interval `[min, max]`, comparing in the total
+    /// order used by `TotalOrdF32`.
+    ///
+    /// In that order NaN is greater than infinity, and `-0.0` equals `0.0`.
+    /// A NaN `self` is therefore clamped to `max`, and a NaN `max` leaves the
+    /// interval unbounded above.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `min > max` in the total order, e.g. if `min` is NaN but
+    /// `max` isn't.
+    ///
+    /// ```
+    /// #![feature(float_clamp)]
+    ///
+    /// use std::f32;
+    ///
+    /// assert_eq!(f32::NAN.clamp_total(-2.0, 1.0), 1.0);
+    /// assert_eq!(f32::INFINITY.clamp_total(-2.0, f32::NAN), f32::INFINITY);
+    /// assert_eq!(2.0_f32.clamp_total(-2.0, 1.0), 1.0);
+    /// ```
+    #[unstable(feature = "float_clamp", issue = "0")]
+    #[inline]
+    pub fn clamp_total(self, min: f32, max: f32) -> f32 {
+        num::Float::clamp_total(self, min, max)
+    }
+
+    /// Compares `self` and `other` in the `totalOrder` predicate of IEEE 754.
+    ///
+    /// Unlike `partial_cmp`, this orders every value, including NaNs, and
+    /// tells apart the encodings that `==` treats alike. From least to
+    /// greatest, the order is:
+    ///
+    /// - negative quiet NaNs, then negative signaling NaNs
+    /// - negative infinity
+    /// - negative numbers, then `-0.0`
+    /// - `+0.0`, then positive numbers
+    /// - positive infinity
+    /// - positive signaling NaNs, then positive quiet NaNs
+    ///
+    /// NaNs of the same sign and kind are ordered by their payload, the
+    /// larger payload being further from zero.
+    ///
+    /// This is not the order of `TotalOrdF32`, which merges all NaNs and both
+    /// zeroes into one value each.
+    ///
+    /// ```
+    /// #![feature(total_cmp)]
+    ///
+    /// use std::cmp::Ordering;
+    ///
+    /// let nan = f32::from_bits(0x7fc0_0000);
+    /// let neg_inf = -1.0 / 0.0;
+    /// let mut v = [5.0, nan, -nan, -0.0, 0.0, neg_inf, -1.5];
+    /// v.sort_by(f32::total_cmp);
+    /// assert!(v[0].is_nan() && v[0].is_sign_negative());
+    /// assert_eq!(&v[1..6], &[neg_inf, -1.5, -0.0, 0.0, 5.0]);
+    /// assert!(v[3].is_sign_negative() && v[6].is_nan());
+    ///
+    /// assert_eq!(nan.total_cmp(&f32::from_bits(0x7fc0_0001)), Ordering::Less);
+    /// ```
+    #[unstable(feature = "total_cmp", issue = "0")]
+    #[inline]
+    pub fn total_cmp(&self, other: &f32) -> Ordering {
+        num::Float::total_cmp(self, other)
+    }
+
     /// Returns `true` if `self`'s sign bit is positive, including
     /// `+0.0` and `INFINITY`.
     ///
@@ -710,6 +1038,228 @@ impl f32 {
     #[inline]
     pub fn to_radians(self) -> f32 { num::Float::to_radians(self) }
 
+    /// Parses a decimal string like `parse`, but returns an error if its
+    /// value is not exactly representable as an `f32`, so that the result
+    /// would have been rounded.
+    ///
+    /// Infinity and NaN are accepted as they are.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(float_from_str_exact)]
+    ///
+    /// assert_eq!(f32::from_str_exact("0.375"), Ok(0.375));
+    /// assert_eq!(f32::from_str_exact("-12.5e3"), Ok(-12500.0));
+    /// assert!(f32::from_str_exact("0.1").is_err());
+    /// assert!("0.1".parse::<f32>().is_ok());
+    /// ```
+    #[unstable(feature = "float_from_str_exact", issue = "0")]
+    #[inline]
+    pub fn from_str_exact(src: &str) -> Result<f32, ParseFloatError> {
+        num::Float::from_str_exact(src)
+    }
+
+    /// Parses a decimal string like `parse`, but rounds its value as `mode`
+    /// says instead of to the nearest `f32`. The result is still correctly
+    /// rounded: it is the value itself if that is representable, and
+    /// otherwise one of the two `f32`s around it.
+    ///
+    /// `RoundingMode::ToOdd` picks the one with an odd significand, which
+    /// lets a later rounding to fewer bits avoid the error of rounding twice.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(float_from_str_rounded, float_rounding_mode)]
+    ///
+    /// use std::num::RoundingMode;
+    ///
+    /// let down = f32::from_str_rounded("0.1", RoundingMode::TowardZero).unwrap();
+    /// let up = f32::from_str_rounded("0.1", RoundingMode::TowardPositive).unwrap();
+    /// assert_eq!(down, 0.099999994);
+    /// assert_eq!(up, 0.1);
+    /// assert_eq!(f32::from_str_rounded("-0.1", RoundingMode::TowardPositive), Ok(-down));
+    /// assert_eq!(f32::from_str_rounded("0.5", RoundingMode::TowardZero), Ok(0.5));
+    /// ```
+    #[unstable(feature = "float_from_str_rounded", issue = "0")]
+    #[inline]
+    pub fn from_str_rounded(src: &str, mode: RoundingMode) -> Result<f32, ParseFloatError> {
+        num::Float::from_str_rounded(src, mode)
+    }
+
+    /// Parses a string of digits in the given radix into the nearest `f32`.
+    ///
+    /// The string may start with a sign and have a point between the digits,
+    /// but unlike for `parse`, there is no exponent, `inf` or `NaN`, since
+    /// their letters are digits in the larger radices. Like for integers,
+    /// digits above 9 are letters in either case, and the result is correctly
+    /// rounded to the nearest, however many digits there are.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `radix` is not in the range from 2 to 36.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(float_from_str_radix)]
+    ///
+    /// assert_eq!(f32::from_str_radix("-1.1", 2), Ok(-1.5));
+    /// assert_eq!(f32::from_str_radix("fF.8", 16), Ok(255.5));
+    /// assert_eq!(f32::from_str_radix("0.1", 3), Ok(1.0 / 3.0));
+    /// assert_eq!(f32::from_str_radix("1e5", 16), Ok(485.0));
+    /// assert!(f32::from_str_radix("1e5", 10).is_err());
+    /// ```
+    #[unstable(feature = "float_from_str_radix", issue = "0")]
+    #[inline]
+    pub fn from_str_radix(src: &str, radix: u32) -> Result<f32, ParseFloatError> {
+        num::Float::from_str_radix(src, radix)
+    }
+
+    /// Parses the longest prefix of `src` that is a float in the syntax of
+    /// `parse`, and returns it with the number of bytes it takes up.
+    ///
+    /// The rest of the string is not looked at, so a parser can continue
+    /// right after the number. An `e` without exponent digits after it is
+    /// left in the rest. An error is only returned if there is no such prefix.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(from_str_partial)]
+    ///
+    /// assert_eq!(f32::from_str_partial("2.5e3,1"), Ok((2500.0, 5)));
+    /// assert_eq!(f32::from_str_partial("-0x1.8p1)"), Ok((-3.0, 8)));
+    /// assert_eq!(f32::from_str_partial("1.5em"), Ok((1.5, 3)));
+    /// assert!(f32::from_str_partial("e5").is_err());
+    /// ```
+    #[unstable(feature = "from_str_partial", issue = "0")]
+    #[inline]
+    pub fn from_str_partial(src: &str) -> Result<(f32, usize), ParseFloatError> {
+        num::Float::from_str_partial(src)
+    }
+
+    /// Parses a float from ASCII bytes, in the same syntax as `parse` does
+    /// from a string.
+    ///
+    /// This saves going through `str::from_utf8` first when parsing from a
+    /// byte buffer. Bytes that are not ASCII are simply an error.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(num_from_ascii)]
+    ///
+    /// assert_eq!(f32::from_ascii(b"-2.5e3"), Ok(-2500.0));
+    /// assert_eq!(f32::from_ascii(b"0x1p-2"), Ok(0.25));
+    /// assert!(f32::from_ascii(b"1.5\xff").is_err());
+    /// ```
+    #[unstable(feature = "num_from_ascii", issue = "0")]
+    #[inline]
+    pub fn from_ascii(src: &[u8]) -> Result<f32, ParseFloatError> {
+        num::Float::from_ascii(src)
+    }
+
+    /// Parses a float in the syntax given by `options`, for example with a
+    /// decimal comma or with grouped digits.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(float_parse_options)]
+    /// use std::num::ParseFloatOptions;
+    ///
+    /// let mut options = ParseFloatOptions::new();
+    /// options.grouping(&[',']).infinity(&["inf", "Infinity"]);
+    ///
+    /// assert_eq!(f32::from_str_with("-1,000.5", &options), Ok(-1000.5));
+    /// assert_eq!(f32::from_str_with("Infinity", &options), Ok(std::f32::INFINITY));
+    /// assert!(f32::from_str_with("1,,000", &options).is_err());
+    /// ```
+    #[unstable(feature = "float_parse_options", issue = "0")]
+    #[inline]
+    pub fn from_str_with(src: &str, options: &ParseFloatOptions) -> Result<f32, ParseFloatError> {
+        num::Float::from_str_with(src, options)
+    }
+
+    /// Formats the number in decimal with exactly `precision` fractional
+    /// digits, rounding the last one with `mode`.
+    ///
+    /// `format!("{:.*}", precision, x)` rounds to the nearest and ties to
+    /// even, which is the same as `RoundingMode::TiesToEven`, except that it
+    /// rounds a half up when no digit is left to break the tie: `{:.0}` of
+    /// `0.5` is `1`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(float_rounding_mode)]
+    ///
+    /// use std::num::RoundingMode;
+    ///
+    /// assert_eq!(0.5f32.format_fixed(0, RoundingMode::TiesToEven), "0");
+    /// assert_eq!(0.5f32.format_fixed(0, RoundingMode::TiesToAway), "1");
+    /// assert_eq!(2.375f32.format_fixed(2, RoundingMode::TiesToEven), "2.38");
+    /// assert_eq!(2.375f32.format_fixed(2, RoundingMode::TowardZero), "2.37");
+    /// assert_eq!((-0.001f32).format_fixed(2, RoundingMode::TowardNegative), "-0.01");
+    /// assert_eq!((-0.001f32).format_fixed(2, RoundingMode::TowardPositive), "-0.00");
+    /// ```
+    #[unstable(feature = "float_rounding_mode", issue = "0")]
+    pub fn format_fixed(self, precision: usize, mode: RoundingMode) -> String {
+        let mut buf = [0; 1024]; // enough for f32 and f64
+        let mut parts = [flt2dec::Part::Zero(0); 16];
+        let formatted = flt2dec::to_exact_fixed_str_rounded(
+            flt2dec::strategy::grisu::format_exact_rounded, self, flt2dec::Sign::Minus,
+            precision, mode, false, &mut buf, &mut parts);
+        let mut out = vec![0; formatted.len()];
+        formatted.write(&mut out);
+        unsafe { String::from_utf8_unchecked(out) }
+    }
+
+    /// Converts an integer to an `f32`, or returns an error if it is not
+    /// exactly representable, unlike an `as` cast which rounds it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(float_from_int)]
+    ///
+    /// assert_eq!(f32::try_from_int_exact(-3i32), Ok(-3.0));
+    /// assert_eq!(f32::try_from_int_exact(1i32 << 24), Ok(16777216.0));
+    /// assert!(f32::try_from_int_exact((1i32 << 24) + 1).is_err());
+    /// ```
+    #[unstable(feature = "float_from_int", issue = "0")]
+    #[inline]
+    pub fn try_from_int_exact<I>(i: I) -> Result<f32, InexactIntError>
+        where f32: FromInt<I>
+    {
+        FromInt::try_from_int_exact(i)
+    }
+
+    /// Converts an integer to an `f32`, rounding in the direction given by
+    /// `mode` if it is not exactly representable.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(float_from_int, float_rounding_mode)]
+    ///
+    /// use std::num::RoundingMode;
+    ///
+    /// let x = (1i32 << 24) + 1;
+    /// assert_eq!(f32::from_int_round(x, RoundingMode::TiesToEven), x as f32);
+    /// assert_eq!(f32::from_int_round(x, RoundingMode::TowardPositive), 16777218.0);
+    /// assert_eq!(f32::from_int_round(-x, RoundingMode::TowardPositive), -16777216.0);
+    /// ```
+    #[unstable(feature = "float_from_int", issue = "0")]
+    #[inline]
+    pub fn from_int_round<I>(i: I, mode: RoundingMode) -> f32
+        where f32: FromInt<I>
+    {
+        FromInt::from_int_round(i, mode)
+    }
+
     /// Constructs a floating point number of `x*2^exp`.
     ///
     /// ```
@@ -23,6 +23,38 @@ pub use core::num::{Zero, One};
 pub use core::num::{FpCategory, ParseIntError, ParseFloatError, TryFromIntError};
 #[stable(feature = "rust1", since = "1.0.0")]
 pub use core::num::Wrapping;
+#[unstable(feature = "saturating_int", issue = "0")]
+pub use core::num::Saturating;
+#[unstable(feature = "float_nan_payload", issue = "0")]
+pub use core::num::NanPayload;
+#[unstable(feature = "float_decomposition", issue = "0")]
+pub use core::num::fp;
+#[unstable(feature = "bignum", issue = "0")]
+pub use core::num::bignum;
+#[unstable(feature = "float_env", issue = "0")]
+pub use core::num::fenv;
+#[unstable(feature = "float_from_int", issue = "0")]
+pub use core::num::convert;
+#[unstable(feature = "float_rounding_mode", issue = "0")]
+pub use core::num::RoundingMode;
+#[unstable(feature = "float_parse_options", issue = "0")]
+pub use core::num::ParseFloatOptions;
+#[unstable(feature = "float_error_kind", issue = "0")]
+pub use core::num::FloatErrorKind;
+#[unstable(feature = "raw_float", issue = "0")]
+pub use core::num::{RawFloat, parse_float, parse_float_ascii, parse_float_exact};
+#[unstable(feature = "raw_float", issue = "0")]
+pub use core::num::{parse_float_partial, parse_float_with};
+#[unstable(feature = "f16", issue = "0")]
+pub use core::num::f16;
+#[unstable(feature = "f128", issue = "0")]
+pub use core::num::f128;
+#[unstable(feature = "i128", issue = "0")]
+pub use core::num::{u128, i128};
+#[unstable(feature = "float_total_ord", issue = "0")]
+pub use core::num::{TotalOrdF32, TotalOrdF64};
+#[unstable(feature = "int_traits", issue = "0")]
+pub use core::num::{PrimInt, Signed, Unsigned};
 
 #[cfg(test)] use cmp::PartialEq;
 #[cfg(test)] use fmt;
@@ -138,6 +138,7 @@ pub fn take_hook() -> Box<Fn(&PanicInfo) + 'static + Sync + Send> {
 #[stable(feature = "panic_hooks", since = "1.10.0")]
 pub struct PanicInfo<'a> {
     payload: &'a (Any + Send),
+    message: Option<&'a fmt::Arguments<'a>>,
     location: Location<'a>,
 }
 
@@ -145,11 +146,41 @@ impl<'a> PanicInfo<'a> {
     /// Returns the payload associated with the panic.
     ///
     /// This will commonly, but not always, be a `&'static str` or `String`.
+    /// A panic raised with a single value of any other type, as in
+    /// `panic!(MyError { .. })`, has exactly that value as its payload, so it
+    /// can be recovered with `downcast_ref::<MyError>()`.
     #[stable(feature = "panic_hooks", since = "1.10.0")]
     pub fn payload(&self) -> &(Any + Send) {
         self.payload
     }
 
+    /// Returns the message of the panic, if it was raised with one.
+    ///
+    /// This is `Some` for panics raised with a format string, as in
+    /// `panic!("{} went wrong", x)`, and for those whose payload is a
+    /// `&'static str` or a `String`. It is `None` for panics raised with a
+    /// value of any other type, which should be inspected with
+    /// [`payload`](#method.payload) instead.
+    ///
+    /// # Examples
+    ///
+    /// ```should_panic
+    /// #![feature(panic_info_message)]
+    /// use std::panic;
+    ///
+    /// panic::set_hook(Box::new(|info| {
+    ///     if let Some(message) = info.message() {
+    ///         println!("the panic said: {}", message);
+    ///     }
+    /// }));
+    ///
+    /// panic!("Normal panic {}", 42);
+    /// ```
+    #[unstable(feature = "panic_info_message", issue = "0")]
+    pub fn message(&self) -> Option<&fmt::Arguments> {
+        self.message
+    }
+
     /// Returns information about the location from which the panic originated,
     /// if available.
     ///
@@ -192,20 +223,17 @@ fn default_hook(info: &PanicInfo) {
     let file = info.location.file;
     let line = info.location.line;
 
-    let msg = match info.payload.downcast_ref::<&'static str>() {
-        Some(s) => *s,
-        None => match info.payload.downcast_ref::<String>() {
-            Some(s) => &s[..],
-            None => "Box<Any>",
-        }
-    };
     let mut err = Stderr::new().ok();
     let thread = thread_info::current_thread();
     let name = thread.as_ref().and_then(|t| t.name()).unwrap_or("<unnamed>");
 
     let write = |err: &mut ::io::Write| {
-        let _ = writeln!(err, "thread '{}' panicked at '{}', {}:{}",
-                         name, msg, file, line);
+        let _ = match info.message {
+            Some(msg) => writeln!(err, "thread '{}' panicked at '{}', {}:{}",
+                                  name, msg, file, line),
+            None => writeln!(err, "thread '{}' panicked at 'Box<Any>', {}:{}",
+                             name, file, line),
+        };
 
         if log_backtrace {
             let _ = backtrace::write(err);
@@ -358,17 +386,14 @@ fn rust_panic_with_hook(msg: Box<Any + Send>,
     }
 
     unsafe {
-        let info = PanicInfo {
-            payload: &*msg,
-            location: Location {
-                file: file,
-                line: line,
-            },
-        };
         HOOK_LOCK.read();
-        match HOOK {
-            Hook::Default => default_hook(&info),
-            Hook::Custom(ptr) => (*ptr)(&info),
+        // The message is taken from a string payload, which is also what a
+        // format string panic has been formatted into once already, so that
+        // hooks don't have to downcast the payload to find it.
+        match (msg.downcast_ref::<&'static str>(), msg.downcast_ref::<String>()) {
+            (Some(s), _) => run_hook(&*msg, Some(&format_args!("{}", s)), file, line),
+            (None, Some(s)) => run_hook(&*msg, Some(&format_args!("{}", s)), file, line),
+            (None, None) => run_hook(&*msg, None, file, line),
         }
         HOOK_LOCK.read_unlock();
     }
@@ -386,6 +411,25 @@ fn rust_panic_with_hook(msg: Box<Any + Send>,
     rust_panic(msg)
 }
 
+/// Calls the panic hook. `HOOK_LOCK` must be held for reading.
+unsafe fn run_hook(payload: &(Any + Send),
+                   message: Option<&fmt::Arguments>,
+                   file: &str,
+                   line: u32) {
+    let info = PanicInfo {
+        payload: payload,
+        message: message,
+        location: Location {
+            file: file,
+            line: line,
+        },
+    };
+    match HOOK {
+        Hook::Default => default_hook(&info),
+        Hook::Custom(ptr) => (*ptr)(&info),
+    }
+}
+
 /// A private no-mangle function on which to slap yer breakpoints.
 #[no_mangle]
 #[allow(private_no_mangle_fns)] // yes we get it, but we like breakpoints
@@ -1102,6 +1102,140 @@ impl PathBuf {
         }
     }
 
+    /// Truncates `self` to its `n`th ancestor by calling `pop` up to `n`
+    /// times, stopping early once `pop` returns `false`.
+    ///
+    /// Returns the number of components that were actually removed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(path_buf_in_place)]
+    /// use std::path::{Path, PathBuf};
+    ///
+    /// let mut path = PathBuf::from("/usr/lib/rustlib/etc");
+    /// assert_eq!(path.pop_n(2), 2);
+    /// assert_eq!(path, Path::new("/usr/lib"));
+    /// assert_eq!(path.pop_n(5), 2);
+    /// assert_eq!(path, Path::new("/"));
+    /// ```
+    #[unstable(feature = "path_buf_in_place", issue = "0")]
+    pub fn pop_n(&mut self, n: usize) -> usize {
+        let mut popped = 0;
+        while popped < n && self.pop() {
+            popped += 1;
+        }
+        popped
+    }
+
+    /// Resolves `.` and `..` components in place, without accessing the
+    /// filesystem.
+    ///
+    /// Each `..` removes the preceding normal component, and `.` components
+    /// are removed. Separators between the remaining components are
+    /// normalized to a single one, and the path is rewritten within its
+    /// existing allocation.
+    ///
+    /// Because symbolic links are not resolved, the result may refer to a
+    /// different file than the original path if a component removed by `..`
+    /// is a symbolic link. Use `fs::canonicalize` when that matters.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error, leaving `self` unmodified, if a `..` component would
+    /// escape above the root of an absolute path or above the start of a
+    /// relative path.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(path_buf_in_place)]
+    /// use std::path::{Path, PathBuf};
+    ///
+    /// let mut path = PathBuf::from("/usr/./lib/../share//doc");
+    /// path.normalize_lexically().unwrap();
+    /// assert_eq!(path, Path::new("/usr/share/doc"));
+    ///
+    /// let mut path = PathBuf::from("/usr/../..");
+    /// assert!(path.normalize_lexically().is_err());
+    /// ```
+    #[unstable(feature = "path_buf_in_place", issue = "0")]
+    pub fn normalize_lexically(&mut self) -> Result<(), NormalizeError> {
+        // Byte ranges of the components to keep, with `None` standing for
+        // the root, which has no bytes of its own if it's implicit.
+        let kept = {
+            let base = self.as_u8_slice().as_ptr() as usize;
+            self.components_normalized()?.into_iter().map(|component| {
+                match component {
+                    Component::RootDir => None,
+                    component => {
+                        let bytes = os_str_as_u8_slice(component.as_os_str());
+                        Some((bytes.as_ptr() as usize - base, bytes.len()))
+                    }
+                }
+            }).collect::<Vec<_>>()
+        };
+        let (prefix_len, prefix_is_drive, has_physical_root, verbatim) = {
+            let comps = self.components();
+            (comps.prefix_len(),
+             comps.prefix.map(|p| p.is_drive()).unwrap_or(false),
+             comps.has_physical_root,
+             comps.prefix_verbatim())
+        };
+        let is_sep = if verbatim { is_verbatim_sep } else { is_sep_byte };
+
+        // Components are only ever moved towards the front, and at most one
+        // separator is written between two of them where there was at least
+        // one before, so everything can be rewritten front to back.
+        let vec = self.as_mut_vec();
+        let mut len = 0;
+        for range in kept {
+            match range {
+                None => {
+                    if has_physical_root {
+                        vec[len] = vec[prefix_len];
+                        len += 1;
+                    }
+                }
+                Some((start, n)) => {
+                    let need_sep = len > 0 && !is_sep(vec[len - 1]) &&
+                                   !(len == prefix_len && prefix_is_drive);
+                    if need_sep {
+                        vec[len] = MAIN_SEP_STR.as_bytes()[0];
+                        len += 1;
+                    }
+                    debug_assert!(len <= start);
+                    for i in 0..n {
+                        vec[len + i] = vec[start + i];
+                    }
+                    len += n;
+                }
+            }
+        }
+        vec.truncate(len);
+        Ok(())
+    }
+
+    /// Returns a mutable reference to the underlying `OsString`.
+    ///
+    /// This allows arbitrary in-place edits of the path, such as truncating
+    /// it to a previously recorded length or reserving capacity up front.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(path_buf_in_place)]
+    /// use std::path::{Path, PathBuf};
+    ///
+    /// let mut path = PathBuf::from("/tmp");
+    /// path.as_mut_os_string().push("/file.txt");
+    /// assert_eq!(path, Path::new("/tmp/file.txt"));
+    /// ```
+    #[unstable(feature = "path_buf_in_place", issue = "0")]
+    pub fn as_mut_os_string(&mut self) -> &mut OsString {
+        &mut self.inner
+    }
+
     /// Updates `self.file_name()` to `file_name`.
     ///
     /// If `self.file_name()` was `None`, this is equivalent to pushing
@@ -1207,6 +1341,11 @@ impl<P: AsRef<Path>> iter::Extend<P> for PathBuf {
             self.push(p.as_ref())
         }
     }
+
+    #[inline]
+    fn extend_one(&mut self, p: P) {
+        self.push(p.as_ref());
+    }
 }
 
 #[stable(feature = "rust1", since = "1.0.0")]
@@ -1334,6 +1473,13 @@ pub struct Path {
 #[stable(since = "1.7.0", feature = "strip_prefix")]
 pub struct StripPrefixError(());
 
+/// An error returned from `PathBuf::normalize_lexically` and
+/// `Path::components_normalized` indicating that a `..` component would
+/// escape above the start of the path.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[unstable(feature = "path_buf_in_place", issue = "0")]
+pub struct NormalizeError(());
+
 impl Path {
     // The following (private!) function allows construction of a path from a u8
     // slice, which is only safe when it is known to follow the OsStr encoding.
@@ -1676,6 +1822,32 @@ impl Path {
         self.file_name().map(split_file_at_dot).and_then(|(before, after)| before.and(after))
     }
 
+    /// Returns `true` if the extension of `self.file_name()`, as returned by
+    /// `extension`, is one of `extensions`.
+    ///
+    /// The extensions are compared exactly, so case matters, and the file
+    /// name doesn't have to be valid Unicode.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(os_str_pattern)]
+    /// use std::path::Path;
+    ///
+    /// let path = Path::new("photos/cat.jpg");
+    ///
+    /// assert!(path.has_extension(&["jpg", "png"]));
+    /// assert!(!path.has_extension(&["gif"]));
+    /// assert!(!Path::new("photos/jpg").has_extension(&["jpg"]));
+    /// ```
+    #[unstable(feature = "os_str_pattern", issue = "0")]
+    pub fn has_extension(&self, extensions: &[&str]) -> bool {
+        match self.extension() {
+            Some(extension) => extensions.iter().any(|&e| extension == e),
+            None => false,
+        }
+    }
+
     /// Creates an owned `PathBuf` with `path` adjoined to `self`.
     ///
     /// See `PathBuf::push` for more details on what it means to adjoin a path.
@@ -1771,6 +1943,55 @@ impl Path {
         }
     }
 
+    /// Returns the components of the path with `.` and `..` components
+    /// resolved lexically, without accessing the filesystem.
+    ///
+    /// Each `..` removes the preceding normal component, and `.` components
+    /// are dropped. See `PathBuf::normalize_lexically` for the in-place
+    /// equivalent and its caveats regarding symbolic links.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a `..` component would escape above the root of an
+    /// absolute path or above the start of a relative path.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(path_buf_in_place)]
+    /// use std::path::{Path, Component};
+    /// use std::ffi::OsStr;
+    ///
+    /// let components = Path::new("/tmp/./a/../b").components_normalized().unwrap();
+    /// assert_eq!(components, [Component::RootDir,
+    ///                         Component::Normal(OsStr::new("tmp")),
+    ///                         Component::Normal(OsStr::new("b"))]);
+    /// assert!(Path::new("a/../..").components_normalized().is_err());
+    /// ```
+    #[unstable(feature = "path_buf_in_place", issue = "0")]
+    pub fn components_normalized(&self) -> Result<Vec<Component>, NormalizeError> {
+        let mut normalized = Vec::new();
+        // The prefix and root can't be removed by `..`.
+        let mut fixed = 0;
+        for component in self.components() {
+            match component {
+                Component::Prefix(..) | Component::RootDir => {
+                    normalized.push(component);
+                    fixed = normalized.len();
+                }
+                Component::CurDir => {}
+                Component::ParentDir => {
+                    if normalized.len() == fixed {
+                        return Err(NormalizeError(()))
+                    }
+                    normalized.pop();
+                }
+                Component::Normal(..) => normalized.push(component),
+            }
+        }
+        Ok(normalized)
+    }
+
     /// Produce an iterator over the path's components viewed as `OsStr` slices.
     ///
     /// # Examples
@@ -2143,6 +2364,18 @@ impl Error for StripPrefixError {
     fn description(&self) -> &str { "prefix not found" }
 }
 
+#[unstable(feature = "path_buf_in_place", issue = "0")]
+impl fmt::Display for NormalizeError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.description().fmt(f)
+    }
+}
+
+#[unstable(feature = "path_buf_in_place", issue = "0")]
+impl Error for NormalizeError {
+    fn description(&self) -> &str { "parent directory escapes the path" }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -3270,6 +3503,95 @@ mod tests {
         }
     }
 
+    #[test]
+    pub fn test_pop_n() {
+        let mut path = PathBuf::from("/a/b/c");
+        assert_eq!(path.pop_n(0), 0);
+        assert_eq!(path.to_str(), Some("/a/b/c"));
+        assert_eq!(path.pop_n(2), 2);
+        assert_eq!(path.to_str(), Some("/a"));
+        assert_eq!(path.pop_n(3), 1);
+        assert_eq!(path.to_str(), Some("/"));
+
+        let mut path = PathBuf::from("a/b");
+        assert_eq!(path.pop_n(usize::max_value()), 2);
+        assert_eq!(path.to_str(), Some(""));
+    }
+
+    #[test]
+    pub fn test_normalize_lexically() {
+        macro_rules! tn(
+            ($path:expr, $expected:expr) => ( {
+                let mut actual = PathBuf::from($path);
+                let capacity = actual.as_mut_os_string().capacity();
+                let result = actual.normalize_lexically();
+                assert!(result.is_ok() && actual.to_str() == Some($expected),
+                        "normalizing {:?}: Expected {:?}, got {:?} ({:?})",
+                        $path, $expected, actual.to_str().unwrap(), result);
+                assert_eq!(actual.as_mut_os_string().capacity(), capacity);
+                let components: Vec<_> = Path::new($path).components_normalized()
+                                                         .unwrap();
+                assert_eq!(components, Path::new($expected).components().collect::<Vec<_>>());
+            });
+            ($path:expr) => ( {
+                let mut actual = PathBuf::from($path);
+                assert!(actual.normalize_lexically().is_err(),
+                        "normalizing {:?}: Expected an error", $path);
+                assert_eq!(actual.to_str(), Some($path));
+                assert!(Path::new($path).components_normalized().is_err());
+            });
+        );
+
+        tn!("", "");
+        tn!(".", "");
+        tn!("a", "a");
+        tn!("./a", "a");
+        tn!("a/.", "a");
+        tn!("a/..", "");
+        tn!("a/b/../c", "a/c");
+        tn!("a//b///c", "a/b/c");
+        tn!("a/./b/./../c/", "a/c");
+        tn!("/", "/");
+        tn!("/..");
+        tn!("..");
+        tn!("a/../..");
+        tn!("/a/./b/../../c", "/c");
+        tn!("//a", "/a");
+
+        if cfg!(windows) {
+            tn!("a\\b\\..\\c", "a\\c");
+            tn!("C:\\a\\..\\b", "C:\\b");
+            tn!("C:a\\..\\b", "C:b");
+            tn!("C:\\..");
+            tn!("C:..");
+            tn!("\\\\server\\share\\a\\..\\b", "\\\\server\\share\\b");
+            tn!("\\\\server\\share\\..");
+            tn!("\\\\?\\C:\\a\\.\\b", "\\\\?\\C:\\a\\b");
+        }
+    }
+
+    #[test]
+    pub fn test_as_mut_os_string() {
+        let mut path = PathBuf::from("/tmp");
+        let len = path.as_os_str().len();
+        path.push("file.txt");
+        path.as_mut_os_string().truncate(len);
+        assert_eq!(path.to_str(), Some("/tmp"));
+    }
+
+    #[test]
+    pub fn test_has_extension() {
+        assert!(Path::new("a/b.tar.gz").has_extension(&["gz"]));
+        assert!(Path::new("b.TXT").has_extension(&["txt", "TXT"]));
+        assert!(!Path::new("b.TXT").has_extension(&["txt"]));
+        assert!(!Path::new("b.tar.gz").has_extension(&["tar", "tar.gz"]));
+        assert!(!Path::new(".gz").has_extension(&["gz"]));
+        assert!(!Path::new("b.gz/..").has_extension(&["gz"]));
+        assert!(Path::new("b.").has_extension(&[""]));
+        assert!(!Path::new("b").has_extension(&[""]));
+        assert!(!Path::new("b.gz").has_extension(&[]));
+    }
+
     #[test]
     pub fn test_set_file_name() {
         macro_rules! tfn(
@@ -488,6 +488,19 @@ mod prim_tuple { }
 #[doc(primitive = "f32")]
 /// The 32-bit floating point type.
 ///
+/// Without a precision, the `Display`, `Debug`, `LowerExp` and `UpperExp`
+/// formats of a finite `f32` show the shortest decimal that `parse` turns
+/// back into the same value, and of those the closest one. `Debug` keeps the
+/// sign of a negative zero, so that it gives back the same bits, while the
+/// other formats drop it.
+///
+/// ```
+/// let x = 0.1 + 0.2_f32;
+/// assert_eq!(x.to_string().parse::<f32>(), Ok(x));
+/// assert_eq!(format!("{:?}", 0.1_f32), "0.1");
+/// assert_eq!(format!("{:?}", -0.0_f32), "-0");
+/// ```
+///
 /// *[See also the `std::f32` module](f32/index.html).*
 ///
 mod prim_f32 { }
@@ -496,6 +509,19 @@ mod prim_f32 { }
 //
 /// The 64-bit floating point type.
 ///
+/// Without a precision, the `Display`, `Debug`, `LowerExp` and `UpperExp`
+/// formats of a finite `f64` show the shortest decimal that `parse` turns
+/// back into the same value, and of those the closest one. `Debug` keeps the
+/// sign of a negative zero, so that it gives back the same bits, while the
+/// other formats drop it.
+///
+/// ```
+/// let x = 0.1 + 0.2_f64;
+/// assert_eq!(x.to_string().parse::<f64>(), Ok(x));
+/// assert_eq!(format!("{:?}", 0.1_f64), "0.1");
+/// assert_eq!(format!("{:?}", -0.0_f64), "-0");
+/// ```
+///
 /// *[See also the `std::f64` module](f64/index.html).*
 ///
 mod prim_f64 { }
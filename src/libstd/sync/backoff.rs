@@ -0,0 +1,149 @@
+// Copyright 2016 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use cmp;
+use hint;
+use thread;
+
+// Spinning stops doubling after 2^6 iterations, and yielding is worth it for
+// four more steps before the thread should block.
+const SPIN_LIMIT: u32 = 6;
+const YIELD_LIMIT: u32 = 10;
+
+/// Exponential backoff for loops that wait on other threads.
+///
+/// Each call to `spin` or `snooze` waits about twice as long as the one
+/// before, with `hint::spin_loop`, until spinning any longer isn't worth it.
+/// From then on `snooze` gives up the thread's timeslice instead, and once
+/// `is_completed` says that has gone on long enough as well, a thread that
+/// waits for an event should block until it is woken up, with `thread::park`
+/// or a `Condvar`.
+///
+/// # Examples
+///
+/// Waiting for a flag that another thread sets, and then wakes us up:
+///
+/// ```
+/// #![feature(backoff)]
+///
+/// use std::sync::{Arc, Backoff};
+/// use std::sync::atomic::{AtomicBool, Ordering};
+/// use std::thread;
+///
+/// let ready = Arc::new(AtomicBool::new(false));
+/// let setter = {
+///     let ready = ready.clone();
+///     let waiter = thread::current();
+///     thread::spawn(move || {
+///         ready.store(true, Ordering::Release);
+///         waiter.unpark();
+///     })
+/// };
+///
+/// let mut backoff = Backoff::new();
+/// while !ready.load(Ordering::Acquire) {
+///     if backoff.is_completed() {
+///         thread::park();
+///     } else {
+///         backoff.snooze();
+///     }
+/// }
+/// setter.join().unwrap();
+/// ```
+#[derive(Debug)]
+#[unstable(feature = "backoff", issue = "0")]
+pub struct Backoff {
+    step: u32,
+}
+
+#[unstable(feature = "backoff", issue = "0")]
+impl Backoff {
+    /// Creates a new `Backoff`, which starts with the shortest wait.
+    #[unstable(feature = "backoff", issue = "0")]
+    pub fn new() -> Backoff {
+        Backoff { step: 0 }
+    }
+
+    /// Starts over with the shortest wait.
+    #[unstable(feature = "backoff", issue = "0")]
+    pub fn reset(&mut self) {
+        self.step = 0;
+    }
+
+    /// Spins for a while, about twice as long as the last time, up to a
+    /// limit.
+    ///
+    /// This is for retrying an operation that failed because another thread
+    /// got there first, like a compare-and-swap. Unlike `snooze`, it never
+    /// yields, since the other thread is making progress.
+    #[unstable(feature = "backoff", issue = "0")]
+    pub fn spin(&mut self) {
+        for _ in 0..1u32 << cmp::min(self.step, SPIN_LIMIT) {
+            hint::spin_loop();
+        }
+        if self.step <= SPIN_LIMIT {
+            self.step += 1;
+        }
+    }
+
+    /// Waits for another thread to make progress, by spinning like `spin` at
+    /// first and then by yielding.
+    #[unstable(feature = "backoff", issue = "0")]
+    pub fn snooze(&mut self) {
+        if self.step <= SPIN_LIMIT {
+            for _ in 0..1u32 << self.step {
+                hint::spin_loop();
+            }
+        } else {
+            thread::yield_now();
+        }
+        if self.step <= YIELD_LIMIT {
+            self.step += 1;
+        }
+    }
+
+    /// Returns whether `snooze` has been waiting long enough that the thread
+    /// should block instead, if it has a way to be woken up.
+    #[unstable(feature = "backoff", issue = "0")]
+    pub fn is_completed(&self) -> bool {
+        self.step > YIELD_LIMIT
+    }
+}
+
+#[unstable(feature = "backoff", issue = "0")]
+impl Default for Backoff {
+    fn default() -> Backoff {
+        Backoff::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Backoff;
+
+    #[test]
+    fn completes_after_yielding() {
+        let mut backoff = Backoff::new();
+        for _ in 0..20 {
+            backoff.spin();
+        }
+        assert!(!backoff.is_completed());
+        let mut snoozes = 0;
+        while !backoff.is_completed() {
+            backoff.snooze();
+            snoozes += 1;
+        }
+        assert_eq!(snoozes, 4);
+        backoff.snooze();
+        assert!(backoff.is_completed());
+        backoff.reset();
+        assert!(!backoff.is_completed());
+    }
+}
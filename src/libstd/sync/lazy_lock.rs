@@ -0,0 +1,245 @@
+// Copyright 2016 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use prelude::v1::*;
+
+use cell::UnsafeCell;
+use fmt;
+use ops::Deref;
+use sync::OnceLock;
+
+/// A value which is computed on first access, from any thread.
+///
+/// The first dereference runs the function given to `new`, and every later
+/// one returns the stored result. Other threads that dereference the value
+/// meanwhile block until it is computed.
+///
+/// If the function panics, the `LazyLock` is poisoned, and any further
+/// attempt to get at the value panics as well.
+///
+/// These are associated functions rather than methods, written
+/// `LazyLock::force(&lazy)`, so that they don't shadow methods of `T`.
+///
+/// # Examples
+///
+/// ```
+/// #![feature(lazy_lock)]
+///
+/// use std::sync::LazyLock;
+///
+/// fn expensive() -> Vec<u32> {
+///     (1..6).collect()
+/// }
+///
+/// let lazy = LazyLock::new(expensive as fn() -> Vec<u32>);
+/// assert_eq!(LazyLock::get(&lazy), None);
+/// assert_eq!(lazy.len(), 5);
+/// assert_eq!(LazyLock::get(&lazy), Some(&vec![1, 2, 3, 4, 5]));
+/// ```
+#[unstable(feature = "lazy_lock", issue = "0")]
+pub struct LazyLock<T, F = fn() -> T> {
+    once: OnceLock<T>,
+    // Taken by the initialization that `once` runs, or through a `&mut`.
+    init: UnsafeCell<Option<F>>,
+}
+
+#[unstable(feature = "lazy_lock", issue = "0")]
+unsafe impl<T: Send + Sync, F: Send> Sync for LazyLock<T, F> {}
+
+impl<T, F> LazyLock<T, F> {
+    /// Creates a `LazyLock` which computes its value with `f`.
+    #[unstable(feature = "lazy_lock", issue = "0")]
+    pub const fn new(f: F) -> LazyLock<T, F> {
+        LazyLock {
+            once: OnceLock::new(),
+            init: UnsafeCell::new(Some(f)),
+        }
+    }
+
+    /// Returns the value, or `None` if it hasn't been computed yet.
+    #[unstable(feature = "lazy_lock", issue = "0")]
+    pub fn get(this: &LazyLock<T, F>) -> Option<&T> {
+        this.once.get()
+    }
+
+    /// Returns a mutable reference to the value, or `None` if it hasn't been
+    /// computed yet.
+    #[unstable(feature = "lazy_lock", issue = "0")]
+    pub fn get_mut(this: &mut LazyLock<T, F>) -> Option<&mut T> {
+        this.once.get_mut()
+    }
+
+    /// Consumes the `LazyLock`, returning the value if it has been computed,
+    /// and otherwise the function that would have computed it.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the `LazyLock` is poisoned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(lazy_lock)]
+    ///
+    /// use std::sync::LazyLock;
+    ///
+    /// let lazy = LazyLock::new(|| 92);
+    /// let f = LazyLock::into_inner(lazy).err().unwrap();
+    /// assert_eq!(f(), 92);
+    ///
+    /// let lazy = LazyLock::new(|| 92);
+    /// assert_eq!(*lazy, 92);
+    /// assert_eq!(LazyLock::into_inner(lazy).ok(), Some(92));
+    /// ```
+    #[unstable(feature = "lazy_lock", issue = "0")]
+    pub fn into_inner(this: LazyLock<T, F>) -> Result<T, F> {
+        let LazyLock { once, init } = this;
+        match once.into_inner() {
+            Some(value) => Ok(value),
+            None => Err(unsafe { init.into_inner() }.expect(POISONED)),
+        }
+    }
+}
+
+impl<T, F: FnOnce() -> T> LazyLock<T, F> {
+    /// Computes the value if that hasn't been done yet, and returns it.
+    ///
+    /// This is what dereferencing a `LazyLock` does.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the `LazyLock` is poisoned, and propagates the panic of the
+    /// function if it is the one that poisons it.
+    #[unstable(feature = "lazy_lock", issue = "0")]
+    pub fn force(this: &LazyLock<T, F>) -> &T {
+        let init = &this.init;
+        this.once.get_or_init(|| {
+            match unsafe { (*init.get()).take() } {
+                Some(f) => f(),
+                None => panic!(POISONED),
+            }
+        })
+    }
+
+    /// Computes the value if that hasn't been done yet, and returns a mutable
+    /// reference to it.
+    ///
+    /// # Panics
+    ///
+    /// Panics like `force`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(lazy_lock)]
+    ///
+    /// use std::sync::LazyLock;
+    ///
+    /// let mut lazy = LazyLock::new(|| vec![1]);
+    /// LazyLock::force_mut(&mut lazy).push(2);
+    /// assert_eq!(*lazy, [1, 2]);
+    /// ```
+    #[unstable(feature = "lazy_lock", issue = "0")]
+    pub fn force_mut(this: &mut LazyLock<T, F>) -> &mut T {
+        if this.once.get_mut().is_none() {
+            let f = unsafe { (*this.init.get()).take() }.expect(POISONED);
+            let _ = this.once.set(f());
+        }
+        this.once.get_mut().unwrap()
+    }
+}
+
+const POISONED: &'static str = "LazyLock instance has previously been poisoned";
+
+#[unstable(feature = "lazy_lock", issue = "0")]
+impl<T, F: FnOnce() -> T> Deref for LazyLock<T, F> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        LazyLock::force(self)
+    }
+}
+
+#[unstable(feature = "lazy_lock", issue = "0")]
+impl<T: fmt::Debug, F> fmt::Debug for LazyLock<T, F> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match LazyLock::get(self) {
+            Some(value) => write!(f, "LazyLock {{ value: {:?} }}", value),
+            None => write!(f, "LazyLock {{ <uninit> }}"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use prelude::v1::*;
+
+    use cell::Cell;
+    use panic;
+    use sync::{Arc, LazyLock};
+    use sync::atomic::{AtomicUsize, Ordering};
+    use thread;
+
+    #[test]
+    fn smoke() {
+        let runs = Cell::new(0);
+        let lazy = LazyLock::new(|| {
+            runs.set(runs.get() + 1);
+            String::from("lazy")
+        });
+        assert_eq!(LazyLock::get(&lazy), None);
+        assert_eq!(*lazy, "lazy");
+        assert_eq!(lazy.len(), 4);
+        assert_eq!(runs.get(), 1);
+        assert_eq!(format!("{:?}", lazy), "LazyLock { value: \"lazy\" }");
+    }
+
+    #[test]
+    fn shared_between_threads() {
+        let runs = Arc::new(AtomicUsize::new(0));
+        let lazy = {
+            let runs = runs.clone();
+            Arc::new(LazyLock::new(move || {
+                runs.fetch_add(1, Ordering::SeqCst);
+                7
+            }))
+        };
+        let threads: Vec<_> = (0..4).map(|_| {
+            let lazy = lazy.clone();
+            thread::spawn(move || **lazy)
+        }).collect();
+        for t in threads {
+            assert_eq!(t.join().unwrap(), 7);
+        }
+        assert_eq!(runs.load(Ordering::SeqCst), 1);
+    }
+
+    #[test]
+    fn force_mut_and_into_inner() {
+        let mut lazy = LazyLock::new(|| 1);
+        assert_eq!(LazyLock::get_mut(&mut lazy), None);
+        *LazyLock::force_mut(&mut lazy) += 1;
+        *LazyLock::get_mut(&mut lazy).unwrap() += 1;
+        assert_eq!(LazyLock::into_inner(lazy).ok(), Some(3));
+
+        let lazy = LazyLock::new(|| 1);
+        assert_eq!(LazyLock::into_inner(lazy).err().map(|f| f()), Some(1));
+    }
+
+    #[test]
+    fn poisoned() {
+        let lazy: LazyLock<i32, _> = LazyLock::new(|| -> i32 { panic!("init") });
+        assert!(panic::catch_unwind(panic::AssertUnwindSafe(|| *lazy)).is_err());
+        assert!(panic::catch_unwind(panic::AssertUnwindSafe(|| *lazy)).is_err());
+        assert_eq!(LazyLock::get(&lazy), None);
+        let res = panic::catch_unwind(panic::AssertUnwindSafe(move || LazyLock::into_inner(lazy)));
+        assert!(res.is_err());
+    }
+}
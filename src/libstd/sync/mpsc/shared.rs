@@ -28,8 +28,7 @@ use sync::mpsc::blocking::{self, SignalToken};
 use sync::mpsc::mpsc_queue as mpsc;
 use sync::mpsc::select::StartResult::*;
 use sync::mpsc::select::StartResult;
-use sync::{Mutex, MutexGuard};
-use thread;
+use sync::{Backoff, Mutex, MutexGuard};
 use time::Instant;
 
 const DISCONNECTED: isize = isize::MIN;
@@ -186,13 +185,14 @@ impl<T> Packet<T> {
 
                 if self.sender_drain.fetch_add(1, Ordering::SeqCst) == 0 {
                     loop {
-                        // drain the queue, for info on the thread yield see the
+                        // drain the queue, for info on the backoff see the
                         // discussion in try_recv
+                        let mut backoff = Backoff::new();
                         loop {
                             match self.queue.pop() {
                                 mpsc::Data(..) => {}
                                 mpsc::Empty => break,
-                                mpsc::Inconsistent => thread::yield_now(),
+                                mpsc::Inconsistent => backoff.snooze(),
                             }
                         }
                         // maybe we're done, if we're not the last ones
@@ -276,19 +276,20 @@ impl<T> Packet<T> {
             // having data available, but our pop() has failed due to the queue
             // being in an inconsistent state.  This means that there is some
             // pusher somewhere which has yet to complete, but we are guaranteed
-            // that a pop will eventually succeed. In this case, we spin in a
-            // yield loop because the remote sender should finish their enqueue
-            // operation "very quickly".
+            // that a pop will eventually succeed. In this case, we spin and
+            // then yield in a loop because the remote sender should finish
+            // their enqueue operation "very quickly".
             //
-            // Avoiding this yield loop would require a different queue
+            // Avoiding this loop would require a different queue
             // abstraction which provides the guarantee that after M pushes have
             // succeeded, at least M pops will succeed. The current queues
             // guarantee that if there are N active pushes, you can pop N times
             // once all N have finished.
             mpsc::Inconsistent => {
                 let data;
+                let mut backoff = Backoff::new();
                 loop {
-                    thread::yield_now();
+                    backoff.snooze();
                     match self.queue.pop() {
                         mpsc::Data(t) => { data = t; break }
                         mpsc::Empty => panic!("inconsistent => empty"),
@@ -464,8 +465,9 @@ impl<T> Packet<T> {
             if prev < 0 {
                 drop(self.take_to_wake());
             } else {
+                let mut backoff = Backoff::new();
                 while self.to_wake.load(Ordering::SeqCst) != 0 {
-                    thread::yield_now();
+                    backoff.snooze();
                 }
             }
             // if the number of steals is -1, it was the pre-emptive -1 steal
@@ -24,9 +24,9 @@ use self::Message::*;
 
 use core::cmp;
 use core::isize;
-use thread;
 use time::Instant;
 
+use sync::Backoff;
 use sync::atomic::{AtomicIsize, AtomicUsize, Ordering, AtomicBool};
 use sync::mpsc::Receiver;
 use sync::mpsc::blocking::{self, SignalToken};
@@ -441,8 +441,9 @@ impl<T> Packet<T> {
             if prev < 0 {
                 drop(self.take_to_wake());
             } else {
+                let mut backoff = Backoff::new();
                 while self.to_wake.load(Ordering::SeqCst) != 0 {
-                    thread::yield_now();
+                    backoff.snooze();
                 }
             }
             assert_eq!(self.steals, 0);
@@ -186,6 +186,23 @@ pub struct MutexGuard<'a, T: ?Sized + 'a> {
 #[stable(feature = "rust1", since = "1.0.0")]
 impl<'a, T: ?Sized> !marker::Send for MutexGuard<'a, T> {}
 
+/// An RAII guard for a part of the data protected by a mutex, returned by
+/// `MutexGuard::map`. When this structure is dropped, the lock is unlocked,
+/// just like for the `MutexGuard` that it was made from.
+///
+/// A panic while this guard is held poisons the mutex.
+#[must_use]
+#[unstable(feature = "mapped_lock_guards", issue = "0")]
+#[allow(deprecated)]
+pub struct MappedMutexGuard<'a, T: ?Sized + 'a> {
+    __lock: &'a StaticMutex,
+    __data: &'a mut T,
+    __poison: poison::Guard,
+}
+
+#[unstable(feature = "mapped_lock_guards", issue = "0")]
+impl<'a, T: ?Sized> !marker::Send for MappedMutexGuard<'a, T> {}
+
 /// Static initialization of a mutex. This constant can be used to initialize
 /// other mutex constants.
 #[unstable(feature = "static_mutex",
@@ -429,6 +446,108 @@ impl<'mutex, T: ?Sized> MutexGuard<'mutex, T> {
             }
         })
     }
+
+    /// Makes a `MappedMutexGuard` for a part of the locked data, such as one
+    /// of its fields.
+    ///
+    /// The mutex stays locked until the returned guard is dropped. If `f`
+    /// panics, the original guard is dropped, which poisons the mutex.
+    ///
+    /// This is an associated function, written `MutexGuard::map(guard, f)`,
+    /// so that it doesn't shadow a method of the same name on `T`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(mapped_lock_guards)]
+    ///
+    /// use std::sync::{Mutex, MutexGuard};
+    ///
+    /// let mutex = Mutex::new((0, String::new()));
+    /// {
+    ///     let mut name = MutexGuard::map(mutex.lock().unwrap(), |x| &mut x.1);
+    ///     name.push_str("ferris");
+    /// }
+    /// assert_eq!(mutex.lock().unwrap().1, "ferris");
+    /// ```
+    #[unstable(feature = "mapped_lock_guards", issue = "0")]
+    pub fn map<U: ?Sized, F>(mut orig: Self, f: F) -> MappedMutexGuard<'mutex, U>
+        where F: FnOnce(&mut T) -> &mut U
+    {
+        // The data lives as long as the lock, not just the borrow of `orig`,
+        // which still unlocks the mutex if `f` panics.
+        let data = f(unsafe { &mut *(&mut *orig.__data as *mut T) });
+        unsafe {
+            let poison = ptr::read(&orig.__poison);
+            MappedMutexGuard::new(orig.__lock, data, poison, orig)
+        }
+    }
+
+    /// Makes a `MappedMutexGuard` for a part of the locked data if `f`
+    /// returns one, and otherwise gives back the original guard.
+    ///
+    /// Like `map`, this is an associated function.
+    #[unstable(feature = "mapped_lock_guards", issue = "0")]
+    pub fn try_map<U: ?Sized, F>(mut orig: Self, f: F)
+                                 -> Result<MappedMutexGuard<'mutex, U>, Self>
+        where F: FnOnce(&mut T) -> Option<&mut U>
+    {
+        match f(unsafe { &mut *(&mut *orig.__data as *mut T) }) {
+            Some(data) => unsafe {
+                let poison = ptr::read(&orig.__poison);
+                Ok(MappedMutexGuard::new(orig.__lock, data, poison, orig))
+            },
+            None => Err(orig),
+        }
+    }
+}
+
+#[allow(deprecated)]
+impl<'mutex, T: ?Sized> MappedMutexGuard<'mutex, T> {
+    // Takes over the lock held by `orig`, which is forgotten, so that the
+    // lock is unlocked only once, by the new guard.
+    unsafe fn new<G>(lock: &'mutex StaticMutex, data: &'mutex mut T,
+                     poison: poison::Guard, orig: G)
+                     -> MappedMutexGuard<'mutex, T> {
+        mem::forget(orig);
+        MappedMutexGuard {
+            __lock: lock,
+            __data: data,
+            __poison: poison,
+        }
+    }
+
+    /// Makes a `MappedMutexGuard` for a part of the data that this guard
+    /// already points to.
+    ///
+    /// Like `MutexGuard::map`, this is an associated function.
+    #[unstable(feature = "mapped_lock_guards", issue = "0")]
+    pub fn map<U: ?Sized, F>(mut orig: Self, f: F) -> MappedMutexGuard<'mutex, U>
+        where F: FnOnce(&mut T) -> &mut U
+    {
+        let data = f(unsafe { &mut *(&mut *orig.__data as *mut T) });
+        unsafe {
+            let poison = ptr::read(&orig.__poison);
+            MappedMutexGuard::new(orig.__lock, data, poison, orig)
+        }
+    }
+
+    /// Makes a `MappedMutexGuard` for a part of the data that this guard
+    /// already points to if `f` returns one, and otherwise gives back the
+    /// original guard.
+    #[unstable(feature = "mapped_lock_guards", issue = "0")]
+    pub fn try_map<U: ?Sized, F>(mut orig: Self, f: F)
+                                 -> Result<MappedMutexGuard<'mutex, U>, Self>
+        where F: FnOnce(&mut T) -> Option<&mut U>
+    {
+        match f(unsafe { &mut *(&mut *orig.__data as *mut T) }) {
+            Some(data) => unsafe {
+                let poison = ptr::read(&orig.__poison);
+                Ok(MappedMutexGuard::new(orig.__lock, data, poison, orig))
+            },
+            None => Err(orig),
+        }
+    }
 }
 
 #[stable(feature = "rust1", since = "1.0.0")]
@@ -455,6 +574,30 @@ impl<'a, T: ?Sized> Drop for MutexGuard<'a, T> {
     }
 }
 
+#[unstable(feature = "mapped_lock_guards", issue = "0")]
+impl<'mutex, T: ?Sized> Deref for MappedMutexGuard<'mutex, T> {
+    type Target = T;
+
+    fn deref(&self) -> &T { self.__data }
+}
+
+#[unstable(feature = "mapped_lock_guards", issue = "0")]
+impl<'mutex, T: ?Sized> DerefMut for MappedMutexGuard<'mutex, T> {
+    fn deref_mut(&mut self) -> &mut T { self.__data }
+}
+
+#[unstable(feature = "mapped_lock_guards", issue = "0")]
+#[allow(deprecated)]
+impl<'a, T: ?Sized> Drop for MappedMutexGuard<'a, T> {
+    #[inline]
+    fn drop(&mut self) {
+        unsafe {
+            self.__lock.poison.done(&self.__poison);
+            self.__lock.lock.unlock();
+        }
+    }
+}
+
 #[allow(deprecated)]
 pub fn guard_lock<'a, T: ?Sized>(guard: &MutexGuard<'a, T>) -> &'a sys::Mutex {
     &guard.__lock.lock
@@ -471,7 +614,7 @@ mod tests {
     use prelude::v1::*;
 
     use sync::mpsc::channel;
-    use sync::{Arc, Mutex, StaticMutex, Condvar};
+    use sync::{Arc, Mutex, StaticMutex, Condvar, MutexGuard, MappedMutexGuard};
     use sync::atomic::{AtomicUsize, Ordering};
     use thread;
 
@@ -715,4 +858,46 @@ mod tests {
         let comp: &[i32] = &[4, 2, 5];
         assert_eq!(&*mutex.lock().unwrap(), comp);
     }
+
+    #[test]
+    fn test_mutex_guard_map() {
+        let mutex = Mutex::new((1, vec![2, 3]));
+        {
+            let mut v = MutexGuard::map(mutex.lock().unwrap(), |x| &mut x.1);
+            v.push(4);
+            let mut last = MappedMutexGuard::map(v, |v| v.last_mut().unwrap());
+            *last += 10;
+            assert!(mutex.try_lock().is_err());
+        }
+        assert_eq!(*mutex.lock().unwrap(), (1, vec![2, 3, 14]));
+
+        let guard = mutex.lock().unwrap();
+        let guard = MutexGuard::try_map(guard, |x| x.1.get_mut(5)).err().unwrap();
+        let v = MutexGuard::try_map(guard, |x| x.1.get_mut(0)).ok().unwrap();
+        assert_eq!(*v, 2);
+    }
+
+    #[test]
+    fn test_mapped_mutex_guard_poison() {
+        let arc = Arc::new(Mutex::new((1, 2)));
+        let arc2 = arc.clone();
+        let _ = thread::spawn(move || {
+            let _guard = MutexGuard::map(arc2.lock().unwrap(), |x| &mut x.0);
+            panic!();
+        }).join();
+        assert!(arc.is_poisoned());
+
+        assert_eq!(*arc.lock().unwrap_err().into_inner(), (1, 2));
+
+        // A panic in the mapping closure poisons the mutex too.
+        let arc = Arc::new(Mutex::new((1, 2)));
+        let arc2 = arc.clone();
+        let _ = thread::spawn(move || {
+            let _guard = MutexGuard::map(arc2.lock().unwrap(), |x| {
+                assert!(x.0 == 0);
+                &mut x.0
+            });
+        }).join();
+        assert!(arc.is_poisoned());
+    }
 }
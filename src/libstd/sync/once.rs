@@ -65,6 +65,7 @@
 // it!
 
 use marker;
+use sync::Backoff;
 use sync::atomic::{AtomicUsize, AtomicBool, Ordering};
 use thread::{self, Thread};
 
@@ -130,9 +131,9 @@ struct Waiter {
 
 // Helper struct used to clean up after a closure call with a `Drop`
 // implementation to also run on panic.
-struct Finish {
+struct Finish<'a> {
     panicked: bool,
-    me: &'static Once,
+    me: &'a Once,
 }
 
 impl Once {
@@ -243,10 +244,11 @@ impl Once {
     // currently no way to take an `FnOnce` and call it via virtual dispatch
     // without some allocation overhead.
     #[cold]
-    fn call_inner(&'static self,
+    fn call_inner(&self,
                   ignore_poisoning: bool,
                   mut init: &mut FnMut(bool)) {
         let mut state = self.state.load(Ordering::SeqCst);
+        let mut backoff = Backoff::new();
 
         'outer: loop {
             match state {
@@ -294,6 +296,14 @@ impl Once {
                 // not RUNNING.
                 _ => {
                     assert!(state & STATE_MASK == RUNNING);
+                    // A short initialization routine may well be done before
+                    // we could even enqueue ourselves, so wait for a little
+                    // while before we do.
+                    if !backoff.is_completed() {
+                        backoff.snooze();
+                        state = self.state.load(Ordering::SeqCst);
+                        continue
+                    }
                     let mut node = Waiter {
                         thread: Some(thread::current()),
                         signaled: AtomicBool::new(false),
@@ -327,7 +337,7 @@ impl Once {
     }
 }
 
-impl Drop for Finish {
+impl<'a> Drop for Finish<'a> {
     fn drop(&mut self) {
         // Swap out our state with however we finished. We should only ever see
         // an old state which was RUNNING.
@@ -366,6 +376,25 @@ impl OnceState {
     }
 }
 
+// `OnceLock` owns its `Once`, which then isn't `'static`. The methods above
+// take `&'static self` only because they were stabilized that way: waiters
+// are queued by `call_inner` calls that each borrow the `Once`, so it outlives
+// all of them whatever the lifetime of the borrow.
+pub fn call_once_force<F>(once: &Once, f: F) where F: FnOnce(&OnceState) {
+    if once.state.load(Ordering::SeqCst) == COMPLETE {
+        return
+    }
+
+    let mut f = Some(f);
+    once.call_inner(true, &mut |p| {
+        f.take().unwrap()(&OnceState { poisoned: p })
+    });
+}
+
+pub fn is_completed(once: &Once) -> bool {
+    once.state.load(Ordering::SeqCst) == COMPLETE
+}
+
 #[cfg(test)]
 mod tests {
     use prelude::v1::*;
@@ -0,0 +1,225 @@
+// Copyright 2016 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use prelude::v1::*;
+
+use cell::UnsafeCell;
+use fmt;
+use mem;
+use sync::once::{self, Once};
+
+/// A cell which is written at most once, from any thread.
+///
+/// The first call to `get_or_init` or `set` stores a value, which every
+/// later call then returns. If several threads race to do so, only one of
+/// them runs its initialization, and the others block until it is done.
+///
+/// Unlike a `Once`, the initialization may panic without making the cell
+/// unusable: the next call tries again, as with `Once::call_once_force`.
+///
+/// With a `&mut OnceLock`, `take` resets the cell, which lets tests and
+/// reconfiguration paths start over deliberately.
+///
+/// # Examples
+///
+/// ```
+/// #![feature(once_lock)]
+///
+/// use std::sync::{Arc, OnceLock};
+/// use std::thread;
+///
+/// let cell = Arc::new(OnceLock::new());
+/// let other = cell.clone();
+/// thread::spawn(move || {
+///     assert_eq!(*other.get_or_init(|| 42), 42);
+/// }).join().unwrap();
+/// assert_eq!(cell.get(), Some(&42));
+/// assert_eq!(cell.set(7), Err(7));
+/// ```
+#[unstable(feature = "once_lock", issue = "0")]
+pub struct OnceLock<T> {
+    once: Once,
+    // Only written by the initialization that `once` runs, which happens
+    // before any reads through `get`.
+    value: UnsafeCell<Option<T>>,
+}
+
+#[unstable(feature = "once_lock", issue = "0")]
+unsafe impl<T: Send + Sync> Sync for OnceLock<T> {}
+
+impl<T> OnceLock<T> {
+    /// Creates an empty cell.
+    #[unstable(feature = "once_lock", issue = "0")]
+    pub const fn new() -> OnceLock<T> {
+        OnceLock {
+            once: Once::new(),
+            value: UnsafeCell::new(None),
+        }
+    }
+
+    /// Returns the value, or `None` if the cell is empty or another thread is
+    /// still initializing it.
+    #[unstable(feature = "once_lock", issue = "0")]
+    pub fn get(&self) -> Option<&T> {
+        if once::is_completed(&self.once) {
+            unsafe { (*self.value.get()).as_ref() }
+        } else {
+            None
+        }
+    }
+
+    /// Returns a mutable reference to the value, or `None` if the cell is
+    /// empty.
+    #[unstable(feature = "once_lock", issue = "0")]
+    pub fn get_mut(&mut self) -> Option<&mut T> {
+        unsafe { (*self.value.get()).as_mut() }
+    }
+
+    /// Stores `value` if the cell is empty, and otherwise returns it back.
+    ///
+    /// This blocks while another thread initializes the cell.
+    #[unstable(feature = "once_lock", issue = "0")]
+    pub fn set(&self, value: T) -> Result<(), T> {
+        let mut value = Some(value);
+        self.get_or_init(|| value.take().unwrap());
+        match value {
+            None => Ok(()),
+            Some(value) => Err(value),
+        }
+    }
+
+    /// Returns the value, storing the result of `f` first if the cell is
+    /// empty.
+    ///
+    /// This blocks while another thread initializes the cell. If `f` panics,
+    /// the panic is propagated and the cell stays empty.
+    ///
+    /// It is an error to initialize the cell again from within `f`, which
+    /// deadlocks.
+    #[unstable(feature = "once_lock", issue = "0")]
+    pub fn get_or_init<F>(&self, f: F) -> &T where F: FnOnce() -> T {
+        if let Some(value) = self.get() {
+            return value
+        }
+        let value = &self.value;
+        once::call_once_force(&self.once, |_| {
+            let x = f();
+            unsafe { *value.get() = Some(x); }
+        });
+        self.get().unwrap()
+    }
+
+    /// Consumes the cell, returning the value if there is one.
+    #[unstable(feature = "once_lock", issue = "0")]
+    pub fn into_inner(self) -> Option<T> {
+        unsafe { self.value.into_inner() }
+    }
+
+    /// Takes the value out of the cell, which is then empty again.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(once_lock)]
+    ///
+    /// use std::sync::OnceLock;
+    ///
+    /// let mut cell = OnceLock::new();
+    /// cell.get_or_init(|| String::from("first"));
+    /// assert_eq!(cell.take(), Some(String::from("first")));
+    /// assert_eq!(cell.get(), None);
+    /// assert_eq!(cell.get_or_init(|| String::from("second")), "second");
+    /// ```
+    #[unstable(feature = "once_lock", issue = "0")]
+    pub fn take(&mut self) -> Option<T> {
+        mem::replace(self, OnceLock::new()).into_inner()
+    }
+}
+
+#[unstable(feature = "once_lock", issue = "0")]
+impl<T> Default for OnceLock<T> {
+    fn default() -> OnceLock<T> {
+        OnceLock::new()
+    }
+}
+
+#[unstable(feature = "once_lock", issue = "0")]
+impl<T: fmt::Debug> fmt::Debug for OnceLock<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.get() {
+            Some(value) => write!(f, "OnceLock {{ value: {:?} }}", value),
+            None => write!(f, "OnceLock {{ <uninit> }}"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use prelude::v1::*;
+
+    use panic;
+    use sync::{Arc, Barrier, OnceLock};
+    use sync::atomic::{AtomicUsize, Ordering};
+    use thread;
+
+    #[test]
+    fn smoke() {
+        let cell = OnceLock::new();
+        assert_eq!(cell.get(), None);
+        assert_eq!(cell.set(1), Ok(()));
+        assert_eq!(cell.set(2), Err(2));
+        assert_eq!(*cell.get_or_init(|| 3), 1);
+        assert_eq!(cell.into_inner(), Some(1));
+    }
+
+    #[test]
+    fn racing_initializers() {
+        let cell = Arc::new(OnceLock::new());
+        let runs = Arc::new(AtomicUsize::new(0));
+        let barrier = Arc::new(Barrier::new(8));
+        let threads: Vec<_> = (0..8).map(|i| {
+            let (cell, runs, barrier) = (cell.clone(), runs.clone(), barrier.clone());
+            thread::spawn(move || {
+                barrier.wait();
+                *cell.get_or_init(|| {
+                    runs.fetch_add(1, Ordering::SeqCst);
+                    i
+                })
+            })
+        }).collect();
+        let values: Vec<_> = threads.into_iter().map(|t| t.join().unwrap()).collect();
+        assert_eq!(runs.load(Ordering::SeqCst), 1);
+        assert!(values.iter().all(|&x| Some(&x) == cell.get()));
+    }
+
+    #[test]
+    fn retries_after_panic() {
+        let cell = OnceLock::new();
+        let res = panic::catch_unwind(panic::AssertUnwindSafe(|| {
+            cell.get_or_init(|| -> i32 { panic!() });
+        }));
+        assert!(res.is_err());
+        assert_eq!(cell.get(), None);
+        assert_eq!(*cell.get_or_init(|| 5), 5);
+    }
+
+    #[test]
+    fn take_and_get_mut() {
+        let mut cell = OnceLock::new();
+        assert_eq!(cell.take(), None);
+        assert_eq!(cell.get_mut(), None);
+        cell.set(vec![1]).unwrap();
+        cell.get_mut().unwrap().push(2);
+        assert_eq!(cell.take(), Some(vec![1, 2]));
+        assert_eq!(cell.set(vec![3]), Ok(()));
+        assert_eq!(format!("{:?}", cell), "OnceLock { value: [3] }");
+        assert_eq!(format!("{:?}", OnceLock::<i32>::new()), "OnceLock { <uninit> }");
+    }
+}
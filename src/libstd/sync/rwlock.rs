@@ -154,6 +154,37 @@ pub struct RwLockWriteGuard<'a, T: ?Sized + 'a> {
 #[stable(feature = "rust1", since = "1.0.0")]
 impl<'a, T: ?Sized> !marker::Send for RwLockWriteGuard<'a, T> {}
 
+/// RAII structure for a part of the data protected by a lock, returned by
+/// `RwLockReadGuard::map`. The shared read access is released when this
+/// structure is dropped.
+#[must_use]
+#[unstable(feature = "mapped_lock_guards", issue = "0")]
+#[allow(deprecated)]
+pub struct MappedRwLockReadGuard<'a, T: ?Sized + 'a> {
+    __lock: &'a StaticRwLock,
+    __data: &'a T,
+}
+
+#[unstable(feature = "mapped_lock_guards", issue = "0")]
+impl<'a, T: ?Sized> !marker::Send for MappedRwLockReadGuard<'a, T> {}
+
+/// RAII structure for a part of the data protected by a lock, returned by
+/// `RwLockWriteGuard::map`. The exclusive write access is released when this
+/// structure is dropped.
+///
+/// A panic while this guard is held poisons the lock.
+#[must_use]
+#[unstable(feature = "mapped_lock_guards", issue = "0")]
+#[allow(deprecated)]
+pub struct MappedRwLockWriteGuard<'a, T: ?Sized + 'a> {
+    __lock: &'a StaticRwLock,
+    __data: &'a mut T,
+    __poison: poison::Guard,
+}
+
+#[unstable(feature = "mapped_lock_guards", issue = "0")]
+impl<'a, T: ?Sized> !marker::Send for MappedRwLockWriteGuard<'a, T> {}
+
 #[allow(deprecated)]
 impl<T> RwLock<T> {
     /// Creates a new instance of an `RwLock<T>` which is unlocked.
@@ -470,6 +501,91 @@ impl<'rwlock, T: ?Sized> RwLockReadGuard<'rwlock, T> {
     }
 }
 
+#[allow(deprecated)]
+impl<'rwlock, T: ?Sized> RwLockReadGuard<'rwlock, T> {
+    /// Makes a `MappedRwLockReadGuard` for a part of the locked data, such as
+    /// one of its fields.
+    ///
+    /// The shared read access is held until the returned guard is dropped.
+    ///
+    /// This is an associated function, written `RwLockReadGuard::map(guard,
+    /// f)`, so that it doesn't shadow a method of the same name on `T`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(mapped_lock_guards)]
+    ///
+    /// use std::sync::{RwLock, RwLockReadGuard};
+    ///
+    /// let lock = RwLock::new((0, String::from("ferris")));
+    /// let name = RwLockReadGuard::map(lock.read().unwrap(), |x| &x.1);
+    /// assert_eq!(*name, "ferris");
+    /// ```
+    #[unstable(feature = "mapped_lock_guards", issue = "0")]
+    pub fn map<U: ?Sized, F>(orig: Self, f: F) -> MappedRwLockReadGuard<'rwlock, U>
+        where F: FnOnce(&T) -> &U
+    {
+        let data = f(orig.__data);
+        MappedRwLockReadGuard::new(orig.__lock, data, orig)
+    }
+
+    /// Makes a `MappedRwLockReadGuard` for a part of the locked data if `f`
+    /// returns one, and otherwise gives back the original guard.
+    ///
+    /// Like `map`, this is an associated function.
+    #[unstable(feature = "mapped_lock_guards", issue = "0")]
+    pub fn try_map<U: ?Sized, F>(orig: Self, f: F)
+                                 -> Result<MappedRwLockReadGuard<'rwlock, U>, Self>
+        where F: FnOnce(&T) -> Option<&U>
+    {
+        match f(orig.__data) {
+            Some(data) => Ok(MappedRwLockReadGuard::new(orig.__lock, data, orig)),
+            None => Err(orig),
+        }
+    }
+}
+
+#[allow(deprecated)]
+impl<'rwlock, T: ?Sized> MappedRwLockReadGuard<'rwlock, T> {
+    // Takes over the read access held by `orig`, which is forgotten, so that
+    // it is released only once, by the new guard.
+    fn new<G>(lock: &'rwlock StaticRwLock, data: &'rwlock T, orig: G)
+              -> MappedRwLockReadGuard<'rwlock, T> {
+        mem::forget(orig);
+        MappedRwLockReadGuard {
+            __lock: lock,
+            __data: data,
+        }
+    }
+
+    /// Makes a `MappedRwLockReadGuard` for a part of the data that this
+    /// guard already points to.
+    ///
+    /// Like `RwLockReadGuard::map`, this is an associated function.
+    #[unstable(feature = "mapped_lock_guards", issue = "0")]
+    pub fn map<U: ?Sized, F>(orig: Self, f: F) -> MappedRwLockReadGuard<'rwlock, U>
+        where F: FnOnce(&T) -> &U
+    {
+        let data = f(orig.__data);
+        MappedRwLockReadGuard::new(orig.__lock, data, orig)
+    }
+
+    /// Makes a `MappedRwLockReadGuard` for a part of the data that this
+    /// guard already points to if `f` returns one, and otherwise gives back
+    /// the original guard.
+    #[unstable(feature = "mapped_lock_guards", issue = "0")]
+    pub fn try_map<U: ?Sized, F>(orig: Self, f: F)
+                                 -> Result<MappedRwLockReadGuard<'rwlock, U>, Self>
+        where F: FnOnce(&T) -> Option<&U>
+    {
+        match f(orig.__data) {
+            Some(data) => Ok(MappedRwLockReadGuard::new(orig.__lock, data, orig)),
+            None => Err(orig),
+        }
+    }
+}
+
 #[allow(deprecated)]
 impl<'rwlock, T: ?Sized> RwLockWriteGuard<'rwlock, T> {
     unsafe fn new(lock: &'rwlock StaticRwLock, data: &'rwlock UnsafeCell<T>)
@@ -482,6 +598,89 @@ impl<'rwlock, T: ?Sized> RwLockWriteGuard<'rwlock, T> {
             }
         })
     }
+
+    /// Makes a `MappedRwLockWriteGuard` for a part of the locked data, such
+    /// as one of its fields.
+    ///
+    /// The exclusive write access is held until the returned guard is
+    /// dropped. If `f` panics, the original guard is dropped, which poisons
+    /// the lock.
+    ///
+    /// Like `RwLockReadGuard::map`, this is an associated function.
+    #[unstable(feature = "mapped_lock_guards", issue = "0")]
+    pub fn map<U: ?Sized, F>(mut orig: Self, f: F) -> MappedRwLockWriteGuard<'rwlock, U>
+        where F: FnOnce(&mut T) -> &mut U
+    {
+        // The data lives as long as the lock, not just the borrow of `orig`,
+        // which still releases the lock if `f` panics.
+        let data = f(unsafe { &mut *(&mut *orig.__data as *mut T) });
+        unsafe {
+            let poison = ptr::read(&orig.__poison);
+            MappedRwLockWriteGuard::new(orig.__lock, data, poison, orig)
+        }
+    }
+
+    /// Makes a `MappedRwLockWriteGuard` for a part of the locked data if `f`
+    /// returns one, and otherwise gives back the original guard.
+    #[unstable(feature = "mapped_lock_guards", issue = "0")]
+    pub fn try_map<U: ?Sized, F>(mut orig: Self, f: F)
+                                 -> Result<MappedRwLockWriteGuard<'rwlock, U>, Self>
+        where F: FnOnce(&mut T) -> Option<&mut U>
+    {
+        match f(unsafe { &mut *(&mut *orig.__data as *mut T) }) {
+            Some(data) => unsafe {
+                let poison = ptr::read(&orig.__poison);
+                Ok(MappedRwLockWriteGuard::new(orig.__lock, data, poison, orig))
+            },
+            None => Err(orig),
+        }
+    }
+}
+
+#[allow(deprecated)]
+impl<'rwlock, T: ?Sized> MappedRwLockWriteGuard<'rwlock, T> {
+    // Takes over the write access held by `orig`, which is forgotten, so
+    // that it is released only once, by the new guard.
+    unsafe fn new<G>(lock: &'rwlock StaticRwLock, data: &'rwlock mut T,
+                     poison: poison::Guard, orig: G)
+                     -> MappedRwLockWriteGuard<'rwlock, T> {
+        mem::forget(orig);
+        MappedRwLockWriteGuard {
+            __lock: lock,
+            __data: data,
+            __poison: poison,
+        }
+    }
+
+    /// Makes a `MappedRwLockWriteGuard` for a part of the data that this
+    /// guard already points to.
+    #[unstable(feature = "mapped_lock_guards", issue = "0")]
+    pub fn map<U: ?Sized, F>(mut orig: Self, f: F) -> MappedRwLockWriteGuard<'rwlock, U>
+        where F: FnOnce(&mut T) -> &mut U
+    {
+        let data = f(unsafe { &mut *(&mut *orig.__data as *mut T) });
+        unsafe {
+            let poison = ptr::read(&orig.__poison);
+            MappedRwLockWriteGuard::new(orig.__lock, data, poison, orig)
+        }
+    }
+
+    /// Makes a `MappedRwLockWriteGuard` for a part of the data that this
+    /// guard already points to if `f` returns one, and otherwise gives back
+    /// the original guard.
+    #[unstable(feature = "mapped_lock_guards", issue = "0")]
+    pub fn try_map<U: ?Sized, F>(mut orig: Self, f: F)
+                                 -> Result<MappedRwLockWriteGuard<'rwlock, U>, Self>
+        where F: FnOnce(&mut T) -> Option<&mut U>
+    {
+        match f(unsafe { &mut *(&mut *orig.__data as *mut T) }) {
+            Some(data) => unsafe {
+                let poison = ptr::read(&orig.__poison);
+                Ok(MappedRwLockWriteGuard::new(orig.__lock, data, poison, orig))
+            },
+            None => Err(orig),
+        }
+    }
 }
 
 #[stable(feature = "rust1", since = "1.0.0")]
@@ -522,6 +721,44 @@ impl<'a, T: ?Sized> Drop for RwLockWriteGuard<'a, T> {
     }
 }
 
+#[unstable(feature = "mapped_lock_guards", issue = "0")]
+impl<'rwlock, T: ?Sized> Deref for MappedRwLockReadGuard<'rwlock, T> {
+    type Target = T;
+
+    fn deref(&self) -> &T { self.__data }
+}
+
+#[unstable(feature = "mapped_lock_guards", issue = "0")]
+impl<'rwlock, T: ?Sized> Deref for MappedRwLockWriteGuard<'rwlock, T> {
+    type Target = T;
+
+    fn deref(&self) -> &T { self.__data }
+}
+
+#[unstable(feature = "mapped_lock_guards", issue = "0")]
+impl<'rwlock, T: ?Sized> DerefMut for MappedRwLockWriteGuard<'rwlock, T> {
+    fn deref_mut(&mut self) -> &mut T {
+        self.__data
+    }
+}
+
+#[allow(deprecated)]
+#[unstable(feature = "mapped_lock_guards", issue = "0")]
+impl<'a, T: ?Sized> Drop for MappedRwLockReadGuard<'a, T> {
+    fn drop(&mut self) {
+        unsafe { self.__lock.lock.read_unlock(); }
+    }
+}
+
+#[allow(deprecated)]
+#[unstable(feature = "mapped_lock_guards", issue = "0")]
+impl<'a, T: ?Sized> Drop for MappedRwLockWriteGuard<'a, T> {
+    fn drop(&mut self) {
+        self.__lock.poison.done(&self.__poison);
+        unsafe { self.__lock.lock.write_unlock(); }
+    }
+}
+
 #[cfg(test)]
 #[allow(deprecated)]
 mod tests {
@@ -533,6 +770,7 @@ mod tests {
     use sync::mpsc::channel;
     use thread;
     use sync::{Arc, RwLock, StaticRwLock, TryLockError};
+    use sync::{RwLockReadGuard, RwLockWriteGuard, MappedRwLockReadGuard};
     use sync::atomic::{AtomicUsize, Ordering};
 
     #[derive(Eq, PartialEq, Debug)]
@@ -780,4 +1018,49 @@ mod tests {
             Ok(x) => panic!("get_mut of poisoned RwLock is Ok: {:?}", x),
         }
     }
+
+    #[test]
+    fn test_rwlock_guard_map() {
+        let lock = RwLock::new((1, vec![2, 3]));
+        {
+            let v = RwLockReadGuard::map(lock.read().unwrap(), |x| &x.1);
+            let first = MappedRwLockReadGuard::map(v, |v| &v[0]);
+            assert_eq!(*first, 2);
+            assert!(lock.try_write().is_err());
+            assert!(lock.try_read().is_ok());
+        }
+        {
+            let mut v = RwLockWriteGuard::map(lock.write().unwrap(), |x| &mut x.1);
+            v.push(4);
+            assert!(lock.try_read().is_err());
+        }
+        assert_eq!(*lock.read().unwrap(), (1, vec![2, 3, 4]));
+
+        let guard = lock.read().unwrap();
+        let guard = RwLockReadGuard::try_map(guard, |x| x.1.get(5)).err().unwrap();
+        drop(guard);
+        let guard = lock.write().unwrap();
+        let mut v = RwLockWriteGuard::try_map(guard, |x| x.1.get_mut(0)).ok().unwrap();
+        *v = 5;
+        drop(v);
+        assert_eq!(lock.read().unwrap().1[0], 5);
+    }
+
+    #[test]
+    fn test_mapped_rwlock_guard_poison() {
+        let arc = Arc::new(RwLock::new((1, 2)));
+        let arc2 = arc.clone();
+        let _ = thread::spawn(move || {
+            let _guard = RwLockReadGuard::map(arc2.read().unwrap(), |x| &x.0);
+            panic!();
+        }).join();
+        assert!(!arc.is_poisoned());
+
+        let arc2 = arc.clone();
+        let _ = thread::spawn(move || {
+            let _guard = RwLockWriteGuard::map(arc2.write().unwrap(), |x| &mut x.0);
+            panic!();
+        }).join();
+        assert!(arc.is_poisoned());
+    }
 }
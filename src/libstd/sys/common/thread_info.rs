@@ -48,8 +48,9 @@ pub fn stack_guard() -> Option<usize> {
     ThreadInfo::with(|info| info.stack_guard).and_then(|o| o)
 }
 
+// The main thread may already have an info from code that ran before `main` and asked for the
+// current thread, which `thread` then replaces with the same id.
 pub fn set(stack_guard: Option<usize>, thread: Thread) {
-    THREAD_INFO.with(|c| assert!(c.borrow().is_none()));
     THREAD_INFO.with(move |c| *c.borrow_mut() = Some(ThreadInfo{
         stack_guard: stack_guard,
         thread: thread,
@@ -29,7 +29,9 @@ use core::str::next_code_point;
 
 use ascii::*;
 use borrow::Cow;
+use boxed::Box;
 use char;
+use cmp;
 use fmt;
 use hash::{Hash, Hasher};
 use iter::FromIterator;
@@ -242,6 +244,21 @@ impl Wtf8Buf {
         self.bytes.capacity()
     }
 
+    #[inline]
+    pub fn shrink_to_fit(&mut self) {
+        self.bytes.shrink_to_fit()
+    }
+
+    /// Shrinks the capacity of the string buffer to the larger of its length
+    /// and `min_capacity`, if it is currently larger than that.
+    pub fn shrink_to(&mut self, min_capacity: usize) {
+        if self.bytes.capacity() > min_capacity {
+            let mut bytes = Vec::with_capacity(cmp::max(self.bytes.len(), min_capacity));
+            bytes.extend_from_slice(&self.bytes);
+            self.bytes = bytes;
+        }
+    }
+
     /// Append a UTF-8 slice at the end of the string.
     #[inline]
     pub fn push_str(&mut self, other: &str) {
@@ -308,6 +325,33 @@ impl Wtf8Buf {
         self.bytes.truncate(new_len)
     }
 
+    /// Removes the last code point and returns it, or `None` if the string
+    /// is empty.
+    #[inline]
+    pub fn pop(&mut self) -> Option<CodePoint> {
+        match self.last_code_point_start() {
+            Some(start) => {
+                let code_point = self[start..].code_points().next();
+                self.bytes.truncate(start);
+                code_point
+            }
+            None => None,
+        }
+    }
+
+    /// Retains only the code points for which `f` returns `true`.
+    ///
+    /// This pairs surrogates that become adjacent, like `push`.
+    pub fn retain<F: FnMut(CodePoint) -> bool>(&mut self, mut f: F) {
+        let mut kept = Wtf8Buf::with_capacity(self.len());
+        for code_point in self.code_points() {
+            if f(code_point) {
+                kept.push(code_point);
+            }
+        }
+        *self = kept;
+    }
+
     /// Consumes the WTF-8 string and tries to convert it to UTF-8.
     ///
     /// This does not copy the data.
@@ -322,6 +366,46 @@ impl Wtf8Buf {
         }
     }
 
+    /// Creates a WTF-8 string from a byte vector, if it is well-formed WTF-8.
+    ///
+    /// This does not copy the data. The vector is returned unchanged if it
+    /// is not well-formed.
+    pub fn from_bytes(bytes: Vec<u8>) -> Result<Wtf8Buf, Vec<u8>> {
+        if is_well_formed(&bytes) {
+            Ok(Wtf8Buf { bytes: bytes })
+        } else {
+            Err(bytes)
+        }
+    }
+
+    /// Creates a WTF-8 string from a byte vector without checking that it
+    /// is well-formed WTF-8.
+    #[inline]
+    pub unsafe fn from_bytes_unchecked(bytes: Vec<u8>) -> Wtf8Buf {
+        Wtf8Buf { bytes: bytes }
+    }
+
+    /// Converts this WTF-8 string into its underlying bytes.
+    ///
+    /// This does not copy the data.
+    #[inline]
+    pub fn into_bytes(self) -> Vec<u8> {
+        self.bytes
+    }
+
+    /// Converts this `Wtf8Buf` into a boxed `Wtf8`, dropping any excess capacity.
+    #[inline]
+    pub fn into_box(self) -> Box<Wtf8> {
+        unsafe { mem::transmute(self.bytes.into_boxed_slice()) }
+    }
+
+    /// Converts a boxed `Wtf8` back into a `Wtf8Buf` without copying.
+    #[inline]
+    pub fn from_box(boxed: Box<Wtf8>) -> Wtf8Buf {
+        let bytes: Box<[u8]> = unsafe { mem::transmute(boxed) };
+        Wtf8Buf { bytes: bytes.into_vec() }
+    }
+
     /// Consumes the WTF-8 string and converts it lossily to UTF-8.
     ///
     /// This does not copy the data (but may overwrite parts of it in place).
@@ -368,6 +452,16 @@ impl Extend<CodePoint> for Wtf8Buf {
             self.push(code_point);
         }
     }
+
+    #[inline]
+    fn extend_one(&mut self, code_point: CodePoint) {
+        self.push(code_point);
+    }
+
+    #[inline]
+    fn extend_reserve(&mut self, additional: usize) {
+        self.bytes.reserve(additional);
+    }
 }
 
 /// A borrowed slice of well-formed WTF-8 data.
@@ -430,12 +524,21 @@ impl Wtf8 {
         unsafe { Wtf8::from_bytes_unchecked(value.as_bytes()) }
     }
 
+    /// Creates a WTF-8 slice from a byte slice, if it is well-formed WTF-8.
+    pub fn from_bytes(value: &[u8]) -> Option<&Wtf8> {
+        if is_well_formed(value) {
+            Some(unsafe { Wtf8::from_bytes_unchecked(value) })
+        } else {
+            None
+        }
+    }
+
     /// Creates a WTF-8 slice from a WTF-8 byte slice.
     ///
     /// Since the byte slice is not checked for valid WTF-8, this functions is
     /// marked unsafe.
     #[inline]
-    unsafe fn from_bytes_unchecked(value: &[u8]) -> &Wtf8 {
+    pub unsafe fn from_bytes_unchecked(value: &[u8]) -> &Wtf8 {
         mem::transmute(value)
     }
 
@@ -560,6 +663,20 @@ impl Wtf8 {
         }
     }
 
+    /// Returns the last code point, or `None` if the string is empty.
+    #[inline]
+    pub fn last_code_point(&self) -> Option<CodePoint> {
+        self.last_code_point_start().and_then(|start| self[start..].code_points().next())
+    }
+
+    // The index of the first byte of the last code point, which is at most
+    // three bytes before the end.
+    #[inline]
+    fn last_code_point_start(&self) -> Option<usize> {
+        let len = self.len();
+        (1..cmp::min(len, 4) + 1).map(|n| len - n).find(|&i| is_code_point_boundary(self, i))
+    }
+
     #[inline]
     fn final_lead_surrogate(&self) -> Option<u16> {
         let len = self.len();
@@ -657,6 +774,39 @@ impl ops::Index<ops::RangeFull> for Wtf8 {
     }
 }
 
+/// Returns whether `bytes` is well-formed WTF-8.
+///
+/// That is UTF-8, except that the three-byte encodings of surrogate code
+/// points are allowed too, as long as no lead surrogate is directly followed
+/// by a trail surrogate: such a pair is encoded as the four bytes of its
+/// supplementary code point instead.
+fn is_well_formed(bytes: &[u8]) -> bool {
+    let mut pos = 0;
+    // The position right after the last lead surrogate.
+    let mut after_lead = None;
+    loop {
+        match str::from_utf8(&bytes[pos..]) {
+            Ok(_) => return true,
+            Err(e) => pos += e.valid_up_to(),
+        }
+        // `from_utf8` rejects surrogates, so if this is well-formed WTF-8
+        // this is where one starts.
+        let is_surrogate = bytes.len() >= pos + 3 &&
+                           bytes[pos] == 0xED &&
+                           bytes[pos + 1] >= 0xA0 && bytes[pos + 1] <= 0xBF &&
+                           bytes[pos + 2] & 0xC0 == 0x80;
+        if !is_surrogate {
+            return false
+        }
+        if bytes[pos + 1] < 0xB0 {
+            after_lead = Some(pos + 3);
+        } else if after_lead == Some(pos) {
+            return false
+        }
+        pos += 3;
+    }
+}
+
 #[inline]
 fn decode_surrogate(second_byte: u8, third_byte: u8) -> u16 {
     // The first byte is assumed to be 0xED
@@ -810,6 +960,31 @@ mod tests {
     use prelude::v1::*;
     use borrow::Cow;
     use super::*;
+    use sys_common::AsInner;
+
+    #[test]
+    fn wtf8_from_bytes() {
+        let valid: &[&[u8]] = &[
+            b"", b"a\xC3\xA9 \xF0\x9F\x92\xA9",
+            b"\xED\xA0\xBD", b"\xED\xB2\xA9", b"a\xED\xB2\xA9\xED\xA0\xBDb",
+            b"\xED\xA0\xBDa\xED\xB2\xA9", b"\xED\xA0\xBD\xED\xA0\xBD",
+        ];
+        for bytes in valid {
+            assert_eq!(Wtf8::from_bytes(bytes).unwrap().as_inner(), *bytes);
+            assert_eq!(Wtf8Buf::from_bytes(bytes.to_vec()).unwrap().into_bytes(), *bytes);
+        }
+
+        let invalid: &[&[u8]] = &[
+            // A surrogate pair must be encoded as a supplementary code point.
+            b"\xED\xA0\xBD\xED\xB2\xA9", b"a\xED\xA0\xBD\xED\xB2\xA9b",
+            // Truncated and otherwise invalid UTF-8.
+            b"\xED\xA0", b"\xED\xA0a", b"\xF0\x9F\x92", b"\xC0\x80", b"\xFF",
+        ];
+        for bytes in invalid {
+            assert!(Wtf8::from_bytes(bytes).is_none());
+            assert_eq!(Wtf8Buf::from_bytes(bytes.to_vec()).unwrap_err(), *bytes);
+        }
+    }
 
     #[test]
     fn code_point_from_u32() {
@@ -995,6 +1170,34 @@ mod tests {
         assert_eq!(string.bytes, b"a");
     }
 
+    #[test]
+    fn wtf8buf_pop() {
+        let mut string = Wtf8Buf::from_str("aé 💩");
+        string.push(CodePoint::from_u32(0xD800).unwrap());
+        assert_eq!(string.last_code_point(), Some(CodePoint::from_u32(0xD800).unwrap()));
+        assert_eq!(string.pop(), Some(CodePoint::from_u32(0xD800).unwrap()));
+        assert_eq!(string.pop(), Some(CodePoint::from_char('💩')));
+        assert_eq!(string.pop(), Some(CodePoint::from_char(' ')));
+        assert_eq!(string.pop(), Some(CodePoint::from_char('é')));
+        assert_eq!(string.bytes, b"a");
+        assert_eq!(string.pop(), Some(CodePoint::from_char('a')));
+        assert_eq!(string.pop(), None);
+        assert_eq!(string.last_code_point(), None);
+    }
+
+    #[test]
+    fn wtf8buf_retain() {
+        let mut string = Wtf8Buf::from_str("aé");
+        string.push(CodePoint::from_u32(0xD83D).unwrap());
+        string.push_char('b');
+        string.push(CodePoint::from_u32(0xDCA9).unwrap());
+        string.retain(|c| c.to_u32() != 'b' as u32);
+        // The surrogates that are now adjacent form a pair.
+        assert_eq!(string.bytes, "aé💩".as_bytes());
+        string.retain(|c| c.to_u32() < 0x80);
+        assert_eq!(string.bytes, b"a");
+    }
+
     #[test]
     #[should_panic]
     fn wtf8buf_truncate_fail_code_point_boundary() {
@@ -12,10 +12,15 @@
 
 #![stable(feature = "rust1", since = "1.0.0")]
 
+use fmt;
 use fs;
+use io;
+use libc;
+use marker::PhantomData;
 use net;
 use os::raw;
 use sys;
+use sys::fd::FileDesc;
 use sys_common::{self, AsInner, FromInner, IntoInner};
 
 /// Raw file descriptors.
@@ -72,6 +77,164 @@ pub trait IntoRawFd {
     fn into_raw_fd(self) -> RawFd;
 }
 
+/// An owned file descriptor, which is closed when it is dropped.
+///
+/// Unlike a `RawFd`, an `OwnedFd` is known to be open and to have no other
+/// owner, so it can be converted to and from the I/O types of the standard
+/// library, such as `File` and `TcpStream`, with `From` and without
+/// `unsafe`.
+///
+/// # Examples
+///
+/// ```no_run
+/// #![feature(io_safety)]
+///
+/// use std::fs::File;
+/// use std::os::unix::io::OwnedFd;
+///
+/// # fn foo() -> std::io::Result<()> {
+/// let fd = OwnedFd::from(File::open("foo.txt")?);
+/// let file = File::from(fd.try_clone()?);
+/// # Ok(())
+/// # }
+/// ```
+#[unstable(feature = "io_safety", issue = "0")]
+pub struct OwnedFd(FileDesc);
+
+/// A borrowed file descriptor, which is open for as long as the lifetime
+/// `'fd` that it borrows from.
+///
+/// This is what `AsFd::as_fd` returns, and it can be passed to functions
+/// that use a file descriptor without taking ownership of it.
+#[derive(Copy, Clone)]
+#[unstable(feature = "io_safety", issue = "0")]
+pub struct BorrowedFd<'fd> {
+    fd: RawFd,
+    _marker: PhantomData<&'fd OwnedFd>,
+}
+
+impl OwnedFd {
+    /// Creates a new `OwnedFd` for the same file description as `self`,
+    /// with its close-on-exec flag set.
+    #[unstable(feature = "io_safety", issue = "0")]
+    pub fn try_clone(&self) -> io::Result<OwnedFd> {
+        self.0.duplicate().map(OwnedFd)
+    }
+}
+
+impl<'fd> BorrowedFd<'fd> {
+    /// Borrows the raw file descriptor `fd` for the lifetime `'fd`.
+    ///
+    /// # Safety
+    ///
+    /// `fd` must be an open file descriptor, and stay open for all of
+    /// `'fd`, which is not checked.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `fd` is -1, which is never an open file descriptor.
+    #[unstable(feature = "io_safety", issue = "0")]
+    pub unsafe fn borrow_raw(fd: RawFd) -> BorrowedFd<'fd> {
+        assert!(fd != -1, "-1 is not a file descriptor");
+        BorrowedFd { fd: fd, _marker: PhantomData }
+    }
+
+    /// Creates a new `OwnedFd` for the same file description as `self`,
+    /// with its close-on-exec flag set.
+    #[unstable(feature = "io_safety", issue = "0")]
+    pub fn try_clone_to_owned(&self) -> io::Result<OwnedFd> {
+        // `duplicate` takes a `FileDesc`, which must not close the
+        // descriptor that it doesn't own.
+        let fd = FileDesc::new(self.fd);
+        let dup = fd.duplicate();
+        fd.into_raw();
+        dup.map(OwnedFd)
+    }
+}
+
+/// A trait to borrow the file descriptor from an underlying object.
+///
+/// This is the safe counterpart of `AsRawFd`: the returned `BorrowedFd`
+/// can't outlive `self`, so the file descriptor is always open while it is
+/// in use.
+#[unstable(feature = "io_safety", issue = "0")]
+pub trait AsFd {
+    /// Borrows the file descriptor.
+    #[unstable(feature = "io_safety", issue = "0")]
+    fn as_fd(&self) -> BorrowedFd;
+}
+
+#[unstable(feature = "io_safety", issue = "0")]
+impl<'a, T: AsFd + ?Sized> AsFd for &'a T {
+    fn as_fd(&self) -> BorrowedFd {
+        T::as_fd(self)
+    }
+}
+
+#[unstable(feature = "io_safety", issue = "0")]
+impl<'a, T: AsFd + ?Sized> AsFd for &'a mut T {
+    fn as_fd(&self) -> BorrowedFd {
+        T::as_fd(self)
+    }
+}
+
+#[unstable(feature = "io_safety", issue = "0")]
+impl AsFd for OwnedFd {
+    fn as_fd(&self) -> BorrowedFd {
+        BorrowedFd { fd: self.0.raw(), _marker: PhantomData }
+    }
+}
+
+#[unstable(feature = "io_safety", issue = "0")]
+impl<'fd> AsFd for BorrowedFd<'fd> {
+    fn as_fd(&self) -> BorrowedFd {
+        *self
+    }
+}
+
+#[unstable(feature = "io_safety", issue = "0")]
+impl AsRawFd for OwnedFd {
+    fn as_raw_fd(&self) -> RawFd {
+        self.0.raw()
+    }
+}
+
+#[unstable(feature = "io_safety", issue = "0")]
+impl FromRawFd for OwnedFd {
+    unsafe fn from_raw_fd(fd: RawFd) -> OwnedFd {
+        assert!(fd != -1, "-1 is not a file descriptor");
+        OwnedFd(FileDesc::new(fd))
+    }
+}
+
+#[unstable(feature = "io_safety", issue = "0")]
+impl IntoRawFd for OwnedFd {
+    fn into_raw_fd(self) -> RawFd {
+        self.0.into_raw()
+    }
+}
+
+#[unstable(feature = "io_safety", issue = "0")]
+impl<'fd> AsRawFd for BorrowedFd<'fd> {
+    fn as_raw_fd(&self) -> RawFd {
+        self.fd
+    }
+}
+
+#[unstable(feature = "io_safety", issue = "0")]
+impl fmt::Debug for OwnedFd {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("OwnedFd").field("fd", &self.0.raw()).finish()
+    }
+}
+
+#[unstable(feature = "io_safety", issue = "0")]
+impl<'fd> fmt::Debug for BorrowedFd<'fd> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("BorrowedFd").field("fd", &self.fd).finish()
+    }
+}
+
 #[stable(feature = "rust1", since = "1.0.0")]
 impl AsRawFd for fs::File {
     fn as_raw_fd(&self) -> RawFd {
@@ -144,3 +307,123 @@ impl IntoRawFd for net::UdpSocket {
         self.into_inner().into_socket().into_inner()
     }
 }
+
+// The standard library types own the file descriptors that they return from
+// `as_raw_fd` and `into_raw_fd`, and take ownership of those passed to
+// `from_raw_fd`, so these conversions are all safe. The conversions for the
+// types of `std::process` and `std::os::unix::net` are next to their raw ones.
+//
+// Note that the attributes on trait impls aren't enforced, so unlike the rest
+// of `io_safety` the `From` conversions are usable on stable as soon as they
+// are added, and can't be taken back.
+macro_rules! as_fd_impl {
+    ($($t:ty),*) => ($(
+        #[unstable(feature = "io_safety", issue = "0")]
+        impl AsFd for $t {
+            fn as_fd(&self) -> BorrowedFd {
+                BorrowedFd { fd: self.as_raw_fd(), _marker: PhantomData }
+            }
+        }
+
+        #[unstable(feature = "io_safety", issue = "0")]
+        impl From<$t> for OwnedFd {
+            fn from(x: $t) -> OwnedFd {
+                OwnedFd(FileDesc::new(x.into_raw_fd()))
+            }
+        }
+    )*)
+}
+
+macro_rules! from_fd_impl {
+    ($($t:ty),*) => ($(
+        #[unstable(feature = "io_safety", issue = "0")]
+        impl From<OwnedFd> for $t {
+            fn from(fd: OwnedFd) -> $t {
+                unsafe { FromRawFd::from_raw_fd(fd.into_raw_fd()) }
+            }
+        }
+    )*)
+}
+
+as_fd_impl! { fs::File, net::TcpStream, net::TcpListener, net::UdpSocket }
+
+from_fd_impl! { fs::File, net::TcpStream, net::TcpListener, net::UdpSocket }
+
+// The standard streams are open for as long as the process, or at least are
+// not ours to close.
+#[unstable(feature = "io_safety", issue = "0")]
+impl AsFd for io::Stdin {
+    fn as_fd(&self) -> BorrowedFd {
+        BorrowedFd { fd: libc::STDIN_FILENO, _marker: PhantomData }
+    }
+}
+
+#[unstable(feature = "io_safety", issue = "0")]
+impl AsFd for io::Stdout {
+    fn as_fd(&self) -> BorrowedFd {
+        BorrowedFd { fd: libc::STDOUT_FILENO, _marker: PhantomData }
+    }
+}
+
+#[unstable(feature = "io_safety", issue = "0")]
+impl AsFd for io::Stderr {
+    fn as_fd(&self) -> BorrowedFd {
+        BorrowedFd { fd: libc::STDERR_FILENO, _marker: PhantomData }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use prelude::v1::*;
+    use fs::File;
+    use io::prelude::*;
+    use sys_common::io::test::tmpdir;
+
+    use super::*;
+
+    #[test]
+    fn file_round_trip() {
+        let dir = tmpdir();
+        let path = dir.path().join("file");
+        File::create(&path).unwrap().write_all(b"hello").unwrap();
+
+        let fd = OwnedFd::from(File::open(&path).unwrap());
+        let raw = fd.as_raw_fd();
+        assert_eq!(fd.as_fd().as_raw_fd(), raw);
+
+        let mut file = File::from(fd);
+        assert_eq!(file.as_raw_fd(), raw);
+        let mut s = String::new();
+        file.read_to_string(&mut s).unwrap();
+        assert_eq!(s, "hello");
+    }
+
+    #[test]
+    fn try_clone() {
+        let dir = tmpdir();
+        let path = dir.path().join("file");
+        File::create(&path).unwrap().write_all(b"hello").unwrap();
+
+        let fd = OwnedFd::from(File::open(&path).unwrap());
+        let dup = fd.try_clone().unwrap();
+        assert!(dup.as_raw_fd() != fd.as_raw_fd());
+        let dup2 = fd.as_fd().try_clone_to_owned().unwrap();
+        assert!(dup2.as_raw_fd() != fd.as_raw_fd());
+
+        // The duplicates share the file offset, and stay open without `fd`.
+        let mut buf = [0; 2];
+        File::from(fd).read_exact(&mut buf).unwrap();
+        assert_eq!(&buf, b"he");
+        let mut s = String::new();
+        File::from(dup).read_to_string(&mut s).unwrap();
+        assert_eq!(s, "llo");
+        File::from(dup2).read_to_string(&mut s).unwrap();
+        assert_eq!(s, "llo");
+    }
+
+    #[test]
+    #[should_panic]
+    fn borrow_raw_minus_one() {
+        unsafe { BorrowedFd::borrow_raw(-1); }
+    }
+}
@@ -36,6 +36,7 @@ pub mod process;
 pub mod raw;
 pub mod thread;
 pub mod net;
+pub mod signal;
 
 /// A prelude for conveniently writing platform-specific code.
 ///
@@ -44,6 +45,8 @@ pub mod net;
 pub mod prelude {
     #[doc(no_inline)] #[stable(feature = "rust1", since = "1.0.0")]
     pub use super::io::{RawFd, AsRawFd, FromRawFd, IntoRawFd};
+    #[doc(no_inline)] #[unstable(feature = "io_safety", issue = "0")]
+    pub use super::io::{OwnedFd, BorrowedFd, AsFd};
     #[doc(no_inline)] #[stable(feature = "rust1", since = "1.0.0")]
     pub use super::ffi::{OsStrExt, OsStringExt};
     #[doc(no_inline)] #[stable(feature = "rust1", since = "1.0.0")]
@@ -22,7 +22,7 @@ use io;
 use mem;
 use net::Shutdown;
 use os::unix::ffi::OsStrExt;
-use os::unix::io::{RawFd, AsRawFd, FromRawFd, IntoRawFd};
+use os::unix::io::{RawFd, AsRawFd, FromRawFd, IntoRawFd, AsFd, BorrowedFd, OwnedFd};
 use path::Path;
 use time::Duration;
 use sys::cvt;
@@ -368,6 +368,27 @@ impl IntoRawFd for UnixStream {
     }
 }
 
+#[unstable(feature = "io_safety", issue = "0")]
+impl AsFd for UnixStream {
+    fn as_fd(&self) -> BorrowedFd {
+        unsafe { BorrowedFd::borrow_raw(self.as_raw_fd()) }
+    }
+}
+
+#[unstable(feature = "io_safety", issue = "0")]
+impl From<UnixStream> for OwnedFd {
+    fn from(socket: UnixStream) -> OwnedFd {
+        unsafe { OwnedFd::from_raw_fd(socket.into_raw_fd()) }
+    }
+}
+
+#[unstable(feature = "io_safety", issue = "0")]
+impl From<OwnedFd> for UnixStream {
+    fn from(fd: OwnedFd) -> UnixStream {
+        unsafe { UnixStream::from_raw_fd(fd.into_raw_fd()) }
+    }
+}
+
 /// A structure representing a Unix domain socket server.
 ///
 /// # Examples
@@ -505,6 +526,27 @@ impl IntoRawFd for UnixListener {
     }
 }
 
+#[unstable(feature = "io_safety", issue = "0")]
+impl AsFd for UnixListener {
+    fn as_fd(&self) -> BorrowedFd {
+        unsafe { BorrowedFd::borrow_raw(self.as_raw_fd()) }
+    }
+}
+
+#[unstable(feature = "io_safety", issue = "0")]
+impl From<UnixListener> for OwnedFd {
+    fn from(socket: UnixListener) -> OwnedFd {
+        unsafe { OwnedFd::from_raw_fd(socket.into_raw_fd()) }
+    }
+}
+
+#[unstable(feature = "io_safety", issue = "0")]
+impl From<OwnedFd> for UnixListener {
+    fn from(fd: OwnedFd) -> UnixListener {
+        unsafe { UnixListener::from_raw_fd(fd.into_raw_fd()) }
+    }
+}
+
 #[stable(feature = "unix_socket", since = "1.10.0")]
 impl<'a> IntoIterator for &'a UnixListener {
     type Item = io::Result<UnixStream>;
@@ -787,6 +829,27 @@ impl IntoRawFd for UnixDatagram {
     }
 }
 
+#[unstable(feature = "io_safety", issue = "0")]
+impl AsFd for UnixDatagram {
+    fn as_fd(&self) -> BorrowedFd {
+        unsafe { BorrowedFd::borrow_raw(self.as_raw_fd()) }
+    }
+}
+
+#[unstable(feature = "io_safety", issue = "0")]
+impl From<UnixDatagram> for OwnedFd {
+    fn from(socket: UnixDatagram) -> OwnedFd {
+        unsafe { OwnedFd::from_raw_fd(socket.into_raw_fd()) }
+    }
+}
+
+#[unstable(feature = "io_safety", issue = "0")]
+impl From<OwnedFd> for UnixDatagram {
+    fn from(fd: OwnedFd) -> UnixDatagram {
+        unsafe { UnixDatagram::from_raw_fd(fd.into_raw_fd()) }
+    }
+}
+
 #[cfg(test)]
 mod test {
     use prelude::v1::*;
@@ -807,6 +870,20 @@ mod test {
         }
     }
 
+    #[test]
+    fn owned_fd_round_trip() {
+        use os::unix::io::OwnedFd;
+
+        let (s1, s2) = or_panic!(UnixStream::pair());
+        let mut s1 = UnixStream::from(OwnedFd::from(s1));
+        let mut s2 = UnixStream::from(OwnedFd::from(s2));
+
+        or_panic!(s1.write_all(b"hello"));
+        let mut buf = [0; 5];
+        or_panic!(s2.read_exact(&mut buf));
+        assert_eq!(&buf, b"hello");
+    }
+
     #[test]
     fn basic() {
         let dir = tmpdir();
@@ -15,7 +15,7 @@
 use prelude::v1::*;
 
 use io;
-use os::unix::io::{FromRawFd, RawFd, AsRawFd, IntoRawFd};
+use os::unix::io::{FromRawFd, RawFd, AsRawFd, IntoRawFd, AsFd, BorrowedFd, OwnedFd};
 use process;
 use sys;
 use sys_common::{AsInnerMut, AsInner, FromInner, IntoInner};
@@ -183,3 +183,52 @@ impl IntoRawFd for process::ChildStderr {
         self.into_inner().into_fd().into_raw()
     }
 }
+
+#[unstable(feature = "io_safety", issue = "0")]
+impl From<OwnedFd> for process::Stdio {
+    fn from(fd: OwnedFd) -> process::Stdio {
+        unsafe { process::Stdio::from_raw_fd(fd.into_raw_fd()) }
+    }
+}
+
+#[unstable(feature = "io_safety", issue = "0")]
+impl AsFd for process::ChildStdin {
+    fn as_fd(&self) -> BorrowedFd {
+        unsafe { BorrowedFd::borrow_raw(self.as_raw_fd()) }
+    }
+}
+
+#[unstable(feature = "io_safety", issue = "0")]
+impl AsFd for process::ChildStdout {
+    fn as_fd(&self) -> BorrowedFd {
+        unsafe { BorrowedFd::borrow_raw(self.as_raw_fd()) }
+    }
+}
+
+#[unstable(feature = "io_safety", issue = "0")]
+impl AsFd for process::ChildStderr {
+    fn as_fd(&self) -> BorrowedFd {
+        unsafe { BorrowedFd::borrow_raw(self.as_raw_fd()) }
+    }
+}
+
+#[unstable(feature = "io_safety", issue = "0")]
+impl From<process::ChildStdin> for OwnedFd {
+    fn from(stdin: process::ChildStdin) -> OwnedFd {
+        unsafe { OwnedFd::from_raw_fd(stdin.into_raw_fd()) }
+    }
+}
+
+#[unstable(feature = "io_safety", issue = "0")]
+impl From<process::ChildStdout> for OwnedFd {
+    fn from(stdout: process::ChildStdout) -> OwnedFd {
+        unsafe { OwnedFd::from_raw_fd(stdout.into_raw_fd()) }
+    }
+}
+
+#[unstable(feature = "io_safety", issue = "0")]
+impl From<process::ChildStderr> for OwnedFd {
+    fn from(stderr: process::ChildStderr) -> OwnedFd {
+        unsafe { OwnedFd::from_raw_fd(stderr.into_raw_fd()) }
+    }
+}
@@ -0,0 +1,157 @@
+// Copyright 2016 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Unix-specific signal handling.
+
+#![unstable(feature = "unix_signals", issue = "0")]
+
+use prelude::v1::*;
+
+use fmt;
+use io;
+use os::unix::io::{AsFd, AsRawFd, BorrowedFd, RawFd};
+use sys;
+
+/// A listener for signals.
+///
+/// Creating a `Signals` installs a handler for each of the signals that it
+/// listens for, which replaces their default action, such as terminating the
+/// process for `SIGTERM`. Every time that one of them is delivered to the
+/// process, the handler makes a note of it for each `Signals` listening for
+/// it, and the signal can then be received with `wait`, `try_wait` or by
+/// iterating over the `Signals`, in the order the signals were delivered.
+/// Dropping the last `Signals` listening for a signal puts its previous
+/// action back.
+///
+/// The handler does nothing but write the number of the signal to a pipe, so
+/// all the code that runs in response to a signal runs outside of the
+/// handler, where it need not be async-signal-safe. The pipe also makes a
+/// `Signals` easy to wait on in an event loop along with other file
+/// descriptors: the file descriptor returned by `as_raw_fd` becomes readable
+/// when a signal can be received with `try_wait`.
+///
+/// A signal that is delivered again before the listener has received it may
+/// be received only once.
+///
+/// # Examples
+///
+/// ```no_run
+/// #![feature(unix_signals)]
+///
+/// use std::os::unix::signal::Signals;
+///
+/// const SIGINT: i32 = 2;
+/// const SIGTERM: i32 = 15;
+///
+/// let signals = Signals::new(&[SIGINT, SIGTERM]).unwrap();
+/// for signal in &signals {
+///     println!("received signal {}, shutting down", signal.unwrap());
+///     break;
+/// }
+/// ```
+#[unstable(feature = "unix_signals", issue = "0")]
+pub struct Signals(sys::signal::Signals);
+
+impl Signals {
+    /// Starts listening for the signals with the given numbers.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error of the kind `InvalidInput` if any of the signals
+    /// can't be listened for: those that aren't signals at all, `SIGKILL` and
+    /// `SIGSTOP`, which can't be caught, and `SIGSEGV`, `SIGBUS`, `SIGILL`
+    /// and `SIGFPE`, which are raised by faults that aren't gone when the
+    /// handler returns. Also returns an error if creating the pipe fails, or
+    /// if a signal already has too many listeners.
+    #[unstable(feature = "unix_signals", issue = "0")]
+    pub fn new(signals: &[i32]) -> io::Result<Signals> {
+        sys::signal::Signals::new(signals).map(Signals)
+    }
+
+    /// Blocks the current thread until one of the signals can be received,
+    /// and returns its number.
+    #[unstable(feature = "unix_signals", issue = "0")]
+    pub fn wait(&self) -> io::Result<i32> {
+        self.0.wait()
+    }
+
+    /// Returns the number of one of the signals if it can be received
+    /// without blocking, or `None` otherwise.
+    #[unstable(feature = "unix_signals", issue = "0")]
+    pub fn try_wait(&self) -> io::Result<Option<i32>> {
+        self.0.try_wait()
+    }
+
+    /// Returns an iterator over the signals received by this listener, which
+    /// blocks until the next one is delivered.
+    ///
+    /// The iterator never returns `None`. It is equivalent to calling `wait`
+    /// in a loop.
+    #[unstable(feature = "unix_signals", issue = "0")]
+    pub fn iter(&self) -> Iter {
+        Iter { signals: self }
+    }
+}
+
+#[unstable(feature = "unix_signals", issue = "0")]
+impl AsRawFd for Signals {
+    fn as_raw_fd(&self) -> RawFd {
+        self.0.fd().raw()
+    }
+}
+
+#[unstable(feature = "io_safety", issue = "0")]
+impl AsFd for Signals {
+    fn as_fd(&self) -> BorrowedFd {
+        unsafe { BorrowedFd::borrow_raw(self.0.fd().raw()) }
+    }
+}
+
+#[unstable(feature = "unix_signals", issue = "0")]
+impl fmt::Debug for Signals {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Signals")
+            .field("fd", &self.0.fd().raw())
+            .finish()
+    }
+}
+
+#[unstable(feature = "unix_signals", issue = "0")]
+impl<'a> IntoIterator for &'a Signals {
+    type Item = io::Result<i32>;
+    type IntoIter = Iter<'a>;
+
+    fn into_iter(self) -> Iter<'a> {
+        self.iter()
+    }
+}
+
+/// An iterator over the signals received by a `Signals`.
+///
+/// It will never return `None`. It is created by the `iter` method on
+/// `Signals`.
+#[derive(Debug)]
+#[unstable(feature = "unix_signals", issue = "0")]
+pub struct Iter<'a> {
+    signals: &'a Signals,
+}
+
+#[unstable(feature = "unix_signals", issue = "0")]
+impl<'a> Iterator for Iter<'a> {
+    type Item = io::Result<i32>;
+
+    fn next(&mut self) -> Option<io::Result<i32>> {
+        Some(self.signals.wait())
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (usize::max_value(), None)
+    }
+}
@@ -288,6 +288,22 @@ impl DirEntry {
         }
     }
 
+    // Whether this entry may be a directory, judging only by the type
+    // recorded in the directory entry itself.
+    #[cfg(target_os = "solaris")]
+    fn may_be_dir(&self) -> bool {
+        true
+    }
+
+    #[cfg(not(target_os = "solaris"))]
+    fn may_be_dir(&self) -> bool {
+        match self.entry.d_type {
+            libc::DT_CHR | libc::DT_FIFO | libc::DT_LNK |
+            libc::DT_REG | libc::DT_SOCK | libc::DT_BLK => false,
+            _ => true,
+        }
+    }
+
     #[cfg(any(target_os = "macos",
               target_os = "ios",
               target_os = "linux",
@@ -632,20 +648,69 @@ pub fn remove_dir_all(path: &Path) -> io::Result<()> {
     if filetype.is_symlink() {
         unlink(path)
     } else {
-        remove_dir_all_recursive(path)
+        let path = cstr(path)?;
+        let (dir, fd) = open_dir_at(libc::AT_FDCWD, &path)?;
+        remove_dir_contents(dir, fd)?;
+        cvt(unsafe { libc::unlinkat(libc::AT_FDCWD, path.as_ptr(), libc::AT_REMOVEDIR) })?;
+        Ok(())
     }
 }
 
-fn remove_dir_all_recursive(path: &Path) -> io::Result<()> {
-    for child in readdir(path)? {
+// Every directory is opened relative to the descriptor of its parent with
+// `O_NOFOLLOW`, and its entries are then removed relative to its own
+// descriptor. If some other process swaps a directory for a symlink while
+// we're working, either opening it fails and just the symlink is unlinked, or
+// we keep operating on the directory we originally opened; a symlink is never
+// followed out of the tree being removed.
+fn remove_dir_contents(dir: Dir, fd: c_int) -> io::Result<()> {
+    let entries = ReadDir { dirp: dir, root: Arc::new(PathBuf::new()) };
+    for child in entries {
         let child = child?;
-        if child.file_type()?.is_dir() {
-            remove_dir_all_recursive(&child.path())?;
+        let name = CString::new(child.name_bytes())?;
+        let subdir = if child.may_be_dir() {
+            match open_dir_at(fd, &name) {
+                Ok(subdir) => Some(subdir),
+                Err(ref e) if is_not_dir_error(e) => None,
+                Err(e) => return Err(e),
+            }
         } else {
-            unlink(&child.path())?;
+            None
+        };
+        match subdir {
+            Some((subdir, subdir_fd)) => {
+                remove_dir_contents(subdir, subdir_fd)?;
+                cvt(unsafe { libc::unlinkat(fd, name.as_ptr(), libc::AT_REMOVEDIR) })?;
+            }
+            None => {
+                cvt(unsafe { libc::unlinkat(fd, name.as_ptr(), 0) })?;
+            }
         }
     }
-    rmdir(path)
+    Ok(())
+}
+
+// Opens the directory `path` relative to `parent_fd` without following a
+// symlink in its place. The returned descriptor is owned by the `Dir` and
+// stays valid for as long as it is alive.
+fn open_dir_at(parent_fd: c_int, path: &CStr) -> io::Result<(Dir, c_int)> {
+    let flags = libc::O_RDONLY | libc::O_DIRECTORY | libc::O_NOFOLLOW | libc::O_CLOEXEC;
+    let fd = cvt_r(|| unsafe { libc::openat(parent_fd, path.as_ptr(), flags) })?;
+    let ptr = unsafe { libc::fdopendir(fd) };
+    if ptr.is_null() {
+        let err = Error::last_os_error();
+        unsafe { libc::close(fd); }
+        return Err(err)
+    }
+    Ok((Dir(ptr), fd))
+}
+
+fn is_not_dir_error(e: &io::Error) -> bool {
+    match e.raw_os_error() {
+        Some(libc::ENOTDIR) | Some(libc::ELOOP) => true,
+        // FreeBSD and DragonFly report `O_NOFOLLOW` hitting a symlink as EMLINK.
+        Some(libc::EMLINK) => cfg!(any(target_os = "freebsd", target_os = "dragonfly")),
+        _ => false,
+    }
 }
 
 pub fn readlink(p: &Path) -> io::Result<PathBuf> {
@@ -681,6 +746,14 @@ pub fn symlink(src: &Path, dst: &Path) -> io::Result<()> {
     Ok(())
 }
 
+pub fn copy_symlink(src: &Path, dst: &Path) -> io::Result<()> {
+    symlink(&readlink(src)?, dst)
+}
+
+pub fn remove_symlink(p: &Path) -> io::Result<()> {
+    unlink(p)
+}
+
 pub fn link(src: &Path, dst: &Path) -> io::Result<()> {
     let src = cstr(src)?;
     let dst = cstr(dst)?;
@@ -720,6 +793,67 @@ pub fn canonicalize(p: &Path) -> io::Result<PathBuf> {
     Ok(PathBuf::from(OsString::from_vec(buf)))
 }
 
+// Creates a file in `dir` which never has a name on the filesystem, if both
+// the platform and the filesystem support it.
+#[cfg(any(target_os = "linux", target_os = "android"))]
+pub fn tempfile_anonymous(dir: &Path) -> io::Result<Option<File>> {
+    // `O_TMPFILE` isn't in libc for all targets yet. Most architectures use
+    // the value of `asm-generic/fcntl.h`, and these are the ones overriding it.
+    #[cfg(any(target_arch = "sparc", target_arch = "sparc64"))]
+    const __O_TMPFILE: c_int = 0x2000000;
+    #[cfg(target_arch = "alpha")]
+    const __O_TMPFILE: c_int = 0o100000000;
+    #[cfg(any(target_arch = "hppa", target_arch = "parisc"))]
+    const __O_TMPFILE: c_int = 0o40000000;
+    #[cfg(not(any(target_arch = "sparc", target_arch = "sparc64", target_arch = "alpha",
+                  target_arch = "hppa", target_arch = "parisc")))]
+    const __O_TMPFILE: c_int = 0o20000000;
+    const O_TMPFILE: c_int = __O_TMPFILE | libc::O_DIRECTORY;
+
+    let mut opts = OpenOptions::new();
+    opts.read(true);
+    opts.write(true);
+    opts.custom_flags(O_TMPFILE);
+    opts.mode(0o600);
+    match File::open(dir, &opts) {
+        Ok(file) => Ok(Some(file)),
+        // Kernels older than 3.11 ignore the flag and try to open the
+        // directory itself for writing, and many filesystems don't implement
+        // it at all.
+        Err(ref e) if e.raw_os_error() == Some(libc::EISDIR) ||
+                      e.raw_os_error() == Some(libc::EOPNOTSUPP) => Ok(None),
+        Err(e) => Err(e),
+    }
+}
+
+#[cfg(not(any(target_os = "linux", target_os = "android")))]
+pub fn tempfile_anonymous(_dir: &Path) -> io::Result<Option<File>> {
+    Ok(None)
+}
+
+// Exclusively creates the file at `path`, readable and writable by the owner
+// only, and immediately unlinks it again so that it disappears once closed.
+pub fn tempfile_create(path: &Path) -> io::Result<File> {
+    let mut opts = OpenOptions::new();
+    opts.read(true);
+    opts.write(true);
+    opts.create_new(true);
+    opts.mode(0o600);
+    let file = File::open(path, &opts)?;
+    unlink(path)?;
+    Ok(file)
+}
+
+pub fn tempdir_create(path: &Path) -> io::Result<()> {
+    let mut builder = DirBuilder::new();
+    builder.set_mode(0o700);
+    builder.mkdir(path)
+}
+
+pub fn canonicalize_simplified(p: &Path) -> io::Result<PathBuf> {
+    canonicalize(p)
+}
+
 pub fn copy(from: &Path, to: &Path) -> io::Result<u64> {
     use fs::{File, set_permissions};
     if !from.is_file() {
@@ -43,6 +43,7 @@ pub mod pipe;
 pub mod process;
 pub mod rand;
 pub mod rwlock;
+pub mod signal;
 pub mod stack_overflow;
 pub mod thread;
 pub mod thread_local;
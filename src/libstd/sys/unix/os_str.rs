@@ -12,6 +12,8 @@
 /// a `Vec<u8>`/`[u8]`.
 
 use borrow::Cow;
+use boxed::Box;
+use cmp;
 use fmt::{self, Debug};
 use vec::Vec;
 use str;
@@ -85,10 +87,74 @@ impl Buf {
         self.inner.reserve_exact(additional)
     }
 
+    #[inline]
+    pub fn truncate(&mut self, new_len: usize) {
+        self.inner.truncate(new_len)
+    }
+
+    #[inline]
+    pub fn shrink_to_fit(&mut self) {
+        self.inner.shrink_to_fit()
+    }
+
+    pub fn pop(&mut self) -> Option<char> {
+        // The last character starts at most three bytes before the end, and
+        // is the shortest suffix that is valid UTF-8.
+        let len = self.inner.len();
+        for n in 1..cmp::min(len, 4) + 1 {
+            if let Ok(s) = str::from_utf8(&self.inner[len - n..]) {
+                let c = s.chars().next();
+                self.inner.truncate(len - n);
+                return c
+            }
+        }
+        None
+    }
+
+    pub fn retain<F: FnMut(char) -> bool>(&mut self, mut f: F) {
+        let len = self.inner.len();
+        let (mut read, mut write) = (0, 0);
+        while read < len {
+            // Bytes that aren't part of a character are always kept.
+            let (n, keep) = match first_char(&self.inner[read..]) {
+                Some(c) => (c.len_utf8(), f(c)),
+                None => (1, true),
+            };
+            if keep {
+                for i in 0..n {
+                    self.inner[write + i] = self.inner[read + i];
+                }
+                write += n;
+            }
+            read += n;
+        }
+        self.inner.truncate(write);
+    }
+
+    pub fn shrink_to(&mut self, min_capacity: usize) {
+        // `Vec` can only shrink to its length, so move into a smaller buffer.
+        if self.inner.capacity() > min_capacity {
+            let mut inner = Vec::with_capacity(cmp::max(self.inner.len(), min_capacity));
+            inner.extend_from_slice(&self.inner);
+            self.inner = inner;
+        }
+    }
+
     pub fn as_slice(&self) -> &Slice {
         unsafe { mem::transmute(&*self.inner) }
     }
 
+    #[inline]
+    pub fn into_box(self) -> Box<Slice> {
+        unsafe { mem::transmute(self.inner.into_boxed_slice()) }
+    }
+
+    #[inline]
+    pub fn from_box(boxed: Box<Slice>) -> Buf {
+        let inner: Box<[u8]> = unsafe { mem::transmute(boxed) };
+        Buf { inner: inner.into_vec() }
+    }
+
     pub fn into_string(self) -> Result<String, Buf> {
         String::from_utf8(self.inner).map_err(|p| Buf { inner: p.into_bytes() } )
     }
@@ -96,6 +162,21 @@ impl Buf {
     pub fn push_slice(&mut self, s: &Slice) {
         self.inner.extend_from_slice(&s.inner)
     }
+
+    // The encoded bytes of an `OsString` on Unix are the bytes themselves,
+    // so any vector is valid.
+
+    pub fn from_encoded_bytes(bytes: Vec<u8>) -> Result<Buf, Vec<u8>> {
+        Ok(Buf { inner: bytes })
+    }
+
+    pub unsafe fn from_encoded_bytes_unchecked(bytes: Vec<u8>) -> Buf {
+        Buf { inner: bytes }
+    }
+
+    pub fn into_encoded_bytes(self) -> Vec<u8> {
+        self.inner
+    }
 }
 
 impl Slice {
@@ -118,4 +199,32 @@ impl Slice {
     pub fn to_owned(&self) -> Buf {
         Buf { inner: self.inner.to_vec() }
     }
+
+    #[inline]
+    pub fn is_boundary(&self, index: usize) -> bool {
+        index <= self.inner.len()
+    }
+
+    pub fn from_encoded_bytes(bytes: &[u8]) -> Option<&Slice> {
+        Some(Slice::from_u8_slice(bytes))
+    }
+
+    pub unsafe fn from_encoded_bytes_unchecked(bytes: &[u8]) -> &Slice {
+        Slice::from_u8_slice(bytes)
+    }
+
+    pub fn as_encoded_bytes(&self) -> &[u8] {
+        &self.inner
+    }
+}
+
+/// Decodes the character at the start of `bytes`, if they start with valid
+/// UTF-8.
+fn first_char(bytes: &[u8]) -> Option<char> {
+    let prefix = &bytes[..cmp::min(bytes.len(), 4)];
+    let valid = match str::from_utf8(prefix) {
+        Ok(s) => s,
+        Err(e) => str::from_utf8(&prefix[..e.valid_up_to()]).unwrap(),
+    };
+    valid.chars().next()
 }
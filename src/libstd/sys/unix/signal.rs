@@ -0,0 +1,234 @@
+// Copyright 2016 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Listening for signals through a pipe.
+//!
+//! Every listener has a pipe of its own, and the handler that we install for
+//! a signal writes the signal number to the write end of the pipes of all of
+//! its listeners, which is about all that can be done in a signal handler.
+//! The table of those write ends is only ever changed, one entry at a time,
+//! with `LOCK` held, and the handler reads it with atomic loads and nothing
+//! else. Before a listener closes its pipe, it waits for the handlers that
+//! may still be writing to it to be done, or they might write to some other
+//! file that has been opened with the same file descriptor in the meantime.
+
+use prelude::v1::*;
+
+use intrinsics;
+use io;
+use libc::{self, c_int, c_void};
+use mem;
+use ptr;
+use sync::Backoff;
+use sync::atomic::{AtomicUsize, Ordering};
+use sys::{cvt, cvt_r};
+use sys::fd::FileDesc;
+use sys::os;
+use sys::pipe::anon_pipe;
+use sys_common::mutex::Mutex;
+
+/// One more than the highest signal number that can be listened for.
+const NSIG: usize = 65;
+/// How many listeners a signal can have at the same time.
+const MAX_LISTENERS: usize = 4;
+
+static LOCK: Mutex = Mutex::new();
+// The write ends of the pipes of the listeners of each signal, or -1 for a
+// free slot.
+static mut WRITE_FDS: [[c_int; MAX_LISTENERS]; NSIG] = [[-1; MAX_LISTENERS]; NSIG];
+// The actions that our handler replaced, to be put back once a signal has no
+// listeners anymore. Only used with `LOCK` held.
+static mut OLD_ACTIONS: [Option<libc::sigaction>; NSIG] = [None; NSIG];
+// How many of our handlers are running right now.
+static RUNNING: AtomicUsize = AtomicUsize::new(0);
+
+extern fn handler(signum: c_int) {
+    RUNNING.fetch_add(1, Ordering::SeqCst);
+    // `write` may fail and set `errno`, which must not change under the feet
+    // of the code that we've interrupted.
+    let errno = os::errno();
+    let byte = signum as u8;
+    for i in 0..MAX_LISTENERS {
+        let fd = unsafe { intrinsics::atomic_load(&WRITE_FDS[signum as usize][i]) };
+        if fd != -1 {
+            // If the pipe is full, the listener has yet to read the same
+            // signal anyway, or so many others that losing this one is the
+            // least of its problems.
+            unsafe {
+                libc::write(fd, &byte as *const u8 as *const c_void, 1);
+            }
+        }
+    }
+    os::set_errno(errno);
+    RUNNING.fetch_sub(1, Ordering::SeqCst);
+}
+
+/// Whether our handler can be installed for `signum`.
+///
+/// Signals for faults can't be handled by returning from the handler, and
+/// `SIGKILL` and `SIGSTOP` can't be handled at all.
+fn is_listenable(signum: c_int) -> bool {
+    0 < signum && (signum as usize) < NSIG &&
+        signum != libc::SIGKILL && signum != libc::SIGSTOP &&
+        signum != libc::SIGSEGV && signum != libc::SIGBUS &&
+        signum != libc::SIGILL && signum != libc::SIGFPE
+}
+
+/// Adds `fd` to the listeners of `signals`. Requires `LOCK` to be held.
+unsafe fn register(signals: &[c_int], fd: c_int) -> io::Result<()> {
+    for &signum in signals {
+        let slots = &mut WRITE_FDS[signum as usize];
+        if slots.contains(&fd) {
+            continue
+        }
+        let slot = match slots.iter().position(|&s| s == -1) {
+            Some(slot) => slot,
+            None => {
+                return Err(io::Error::new(io::ErrorKind::Other,
+                                          "too many listeners for a signal"))
+            }
+        };
+        if OLD_ACTIONS[signum as usize].is_none() {
+            let mut action: libc::sigaction = mem::zeroed();
+            action.sa_sigaction = handler as libc::sighandler_t;
+            action.sa_flags = libc::SA_RESTART;
+            cvt(libc::sigemptyset(&mut action.sa_mask))?;
+            let mut old: libc::sigaction = mem::zeroed();
+            cvt(libc::sigaction(signum, &action, &mut old))?;
+            OLD_ACTIONS[signum as usize] = Some(old);
+        }
+        intrinsics::atomic_store(&mut slots[slot], fd);
+    }
+    Ok(())
+}
+
+/// Removes `fd` from the listeners of all signals, and puts the old action
+/// back for those that are left without any. Requires `LOCK` to be held.
+unsafe fn unregister(fd: c_int) {
+    for signum in 1..NSIG {
+        let slots = &mut WRITE_FDS[signum];
+        for slot in slots.iter_mut() {
+            if *slot == fd {
+                intrinsics::atomic_store(slot, -1);
+            }
+        }
+        if slots.iter().all(|&s| s == -1) {
+            if let Some(old) = OLD_ACTIONS[signum].take() {
+                libc::sigaction(signum as c_int, &old, ptr::null_mut());
+            }
+        }
+    }
+}
+
+pub struct Signals {
+    read: FileDesc,
+    write: FileDesc,
+}
+
+impl Signals {
+    pub fn new(signals: &[c_int]) -> io::Result<Signals> {
+        if let Some(&signum) = signals.iter().find(|&&s| !is_listenable(s)) {
+            return Err(io::Error::new(io::ErrorKind::InvalidInput,
+                                      format!("cannot listen for signal {}", signum)))
+        }
+        let (read, write) = anon_pipe()?;
+        let (read, write) = (read.into_fd(), write.into_fd());
+        read.set_nonblocking(true)?;
+        write.set_nonblocking(true)?;
+        // If registering fails halfway, dropping this undoes the rest.
+        let listener = Signals { read: read, write: write };
+        unsafe {
+            LOCK.lock();
+            let ret = register(signals, listener.write.raw());
+            LOCK.unlock();
+            ret?;
+        }
+        Ok(listener)
+    }
+
+    pub fn wait(&self) -> io::Result<c_int> {
+        loop {
+            if let Some(signum) = self.try_wait()? {
+                return Ok(signum)
+            }
+            let mut pfd = libc::pollfd {
+                fd: self.read.raw(),
+                events: libc::POLLIN,
+                revents: 0,
+            };
+            cvt_r(|| unsafe { libc::poll(&mut pfd, 1, -1) })?;
+        }
+    }
+
+    pub fn try_wait(&self) -> io::Result<Option<c_int>> {
+        let mut byte = [0];
+        loop {
+            match self.read.read(&mut byte) {
+                Ok(0) => return Ok(None),
+                Ok(_) => return Ok(Some(byte[0] as c_int)),
+                Err(ref e) if e.kind() == io::ErrorKind::Interrupted => {}
+                Err(ref e) if e.kind() == io::ErrorKind::WouldBlock => return Ok(None),
+                Err(e) => return Err(e),
+            }
+        }
+    }
+
+    pub fn fd(&self) -> &FileDesc { &self.read }
+}
+
+impl Drop for Signals {
+    fn drop(&mut self) {
+        unsafe {
+            LOCK.lock();
+            unregister(self.write.raw());
+            LOCK.unlock();
+        }
+        // A handler that started before we unregistered may still be about
+        // to write to our pipe, which is about to be closed.
+        let mut backoff = Backoff::new();
+        while RUNNING.load(Ordering::SeqCst) != 0 {
+            backoff.snooze();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use prelude::v1::*;
+
+    use io::ErrorKind;
+    use libc;
+    use super::Signals;
+
+    #[test]
+    #[cfg_attr(target_os = "nacl", ignore)] // no signals on NaCl.
+    fn listen() {
+        let signals = Signals::new(&[libc::SIGUSR1, libc::SIGUSR2]).unwrap();
+        let other = Signals::new(&[libc::SIGUSR2]).unwrap();
+        assert_eq!(signals.try_wait().unwrap(), None);
+        unsafe {
+            assert_eq!(libc::raise(libc::SIGUSR2), 0);
+            assert_eq!(libc::raise(libc::SIGUSR1), 0);
+        }
+        assert_eq!(signals.wait().unwrap(), libc::SIGUSR2);
+        assert_eq!(signals.wait().unwrap(), libc::SIGUSR1);
+        assert_eq!(signals.try_wait().unwrap(), None);
+        assert_eq!(other.try_wait().unwrap(), Some(libc::SIGUSR2));
+        assert_eq!(other.try_wait().unwrap(), None);
+    }
+
+    #[test]
+    fn unlistenable() {
+        for &signum in &[0, libc::SIGKILL, libc::SIGSTOP, libc::SIGSEGV, 1000] {
+            let err = Signals::new(&[signum]).err().unwrap();
+            assert_eq!(err.kind(), ErrorKind::InvalidInput);
+        }
+    }
+}
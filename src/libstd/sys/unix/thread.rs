@@ -18,7 +18,8 @@ use io;
 use libc;
 use mem;
 use ptr;
-use sys::os;
+use sys::{cvt, os};
+use thread::Priority;
 use time::Duration;
 
 use sys_common::thread::*;
@@ -124,6 +125,59 @@ impl Thread {
         // Newlib and Illumos has no way to set a thread name.
     }
 
+    // Real-time threads use `SCHED_FIFO`, and the others `SCHED_OTHER` at the
+    // lowest, the middle or the highest of its static priorities.
+    pub fn set_priority(priority: Priority) -> io::Result<()> {
+        let policy = match priority {
+            Priority::Realtime => libc::SCHED_FIFO,
+            _ => libc::SCHED_OTHER,
+        };
+        unsafe {
+            let min = libc::sched_get_priority_min(policy);
+            let max = libc::sched_get_priority_max(policy);
+            if min == -1 || max == -1 {
+                return Err(io::Error::last_os_error());
+            }
+            let mut param: libc::sched_param = mem::zeroed();
+            param.sched_priority = match priority {
+                Priority::Low => min,
+                Priority::Normal | Priority::Realtime => min + (max - min) / 2,
+                Priority::High => max,
+            };
+            match libc::pthread_setschedparam(libc::pthread_self(), policy, &param) {
+                0 => {}
+                n => return Err(io::Error::from_raw_os_error(n)),
+            }
+            match priority {
+                Priority::Low | Priority::High if min == max => set_nice(priority),
+                _ => Ok(()),
+            }
+        }
+    }
+
+    #[cfg(any(target_os = "linux", target_os = "android"))]
+    pub fn set_affinity(cpus: &[usize]) -> io::Result<()> {
+        unsafe {
+            let mut set: libc::cpu_set_t = mem::zeroed();
+            for &cpu in cpus {
+                if cpu >= mem::size_of::<libc::cpu_set_t>() * 8 {
+                    return Err(io::Error::new(io::ErrorKind::InvalidInput,
+                                              "CPU index out of range"));
+                }
+                libc::CPU_SET(cpu, &mut set);
+            }
+            // thread id 0 is the calling thread
+            cvt(libc::sched_setaffinity(0, mem::size_of::<libc::cpu_set_t>(), &set))
+                .map(|_| ())
+        }
+    }
+
+    #[cfg(not(any(target_os = "linux", target_os = "android")))]
+    pub fn set_affinity(_cpus: &[usize]) -> io::Result<()> {
+        Err(io::Error::new(io::ErrorKind::Other,
+                           "thread affinity is not supported on this platform"))
+    }
+
     pub fn sleep(dur: Duration) {
         let mut secs = dur.as_secs();
         let mut nsecs = dur.subsec_nanos() as libc::c_long;
@@ -165,6 +219,25 @@ impl Thread {
     }
 }
 
+// On Linux, the threads that aren't real-time all have the same static
+// priority, but each of them has its own nice value, unlike in POSIX where
+// that is per process.
+#[cfg(any(target_os = "linux", target_os = "android"))]
+unsafe fn set_nice(priority: Priority) -> io::Result<()> {
+    let nice = match priority {
+        Priority::Low => 10,
+        _ => -10,
+    };
+    let tid = libc::syscall(libc::SYS_gettid) as libc::id_t;
+    cvt(libc::setpriority(libc::PRIO_PROCESS, tid, nice)).map(|_| ())
+}
+
+#[cfg(not(any(target_os = "linux", target_os = "android")))]
+unsafe fn set_nice(_priority: Priority) -> io::Result<()> {
+    Err(io::Error::new(io::ErrorKind::Other,
+                       "thread priorities are not supported on this platform"))
+}
+
 impl Drop for Thread {
     fn drop(&mut self) {
         let ret = unsafe { libc::pthread_detach(self.id) };
@@ -336,6 +409,86 @@ pub mod guard {
     }
 }
 
+// The lowest and one past the highest address of the current thread's stack,
+// not counting the guard page where the platform tells us about it.
+#[cfg(any(all(target_os = "linux", not(target_env = "musl")),
+          target_os = "android",
+          target_os = "freebsd",
+          all(target_os = "netbsd", not(target_vendor = "rumprun"))))]
+pub fn stack_bounds() -> Option<(usize, usize)> {
+    unsafe {
+        let mut ret = None;
+        let mut attr: libc::pthread_attr_t = mem::zeroed();
+        assert_eq!(libc::pthread_attr_init(&mut attr), 0);
+        #[cfg(target_os = "freebsd")]
+            let e = libc::pthread_attr_get_np(libc::pthread_self(), &mut attr);
+        #[cfg(not(target_os = "freebsd"))]
+            let e = libc::pthread_getattr_np(libc::pthread_self(), &mut attr);
+        if e == 0 {
+            let mut guardsize = 0;
+            assert_eq!(libc::pthread_attr_getguardsize(&attr, &mut guardsize), 0);
+            let mut stackaddr = ptr::null_mut();
+            let mut size = 0;
+            assert_eq!(libc::pthread_attr_getstack(&attr, &mut stackaddr,
+                                                   &mut size), 0);
+            // As in `guard::current`, only Linux counts the guard page as
+            // part of the stack.
+            let low = if cfg!(any(target_os = "linux", target_os = "android")) {
+                stackaddr as usize + guardsize as usize
+            } else {
+                stackaddr as usize
+            };
+            ret = Some((low, stackaddr as usize + size as usize));
+        }
+        assert_eq!(libc::pthread_attr_destroy(&mut attr), 0);
+        ret
+    }
+}
+
+#[cfg(target_os = "macos")]
+pub fn stack_bounds() -> Option<(usize, usize)> {
+    unsafe {
+        let high = libc::pthread_get_stackaddr_np(libc::pthread_self()) as usize;
+        let size = libc::pthread_get_stacksize_np(libc::pthread_self()) as usize;
+        Some((high - size, high))
+    }
+}
+
+#[cfg(any(target_os = "openbsd", target_os = "bitrig"))]
+pub fn stack_bounds() -> Option<(usize, usize)> {
+    unsafe {
+        let mut current_stack: libc::stack_t = mem::zeroed();
+        assert_eq!(libc::pthread_stackseg_np(libc::pthread_self(),
+                                             &mut current_stack), 0);
+        match guard::current() {
+            Some(low) => Some((low, current_stack.ss_sp as usize)),
+            None => None,
+        }
+    }
+}
+
+#[cfg(target_os = "solaris")]
+pub fn stack_bounds() -> Option<(usize, usize)> {
+    unsafe {
+        let mut current_stack: libc::stack_t = mem::zeroed();
+        assert_eq!(libc::stack_getbounds(&mut current_stack), 0);
+        let low = current_stack.ss_sp as usize;
+        Some((low, low + current_stack.ss_size as usize))
+    }
+}
+
+#[cfg(not(any(all(target_os = "linux", not(target_env = "musl")),
+              target_os = "android",
+              target_os = "freebsd",
+              all(target_os = "netbsd", not(target_vendor = "rumprun")),
+              target_os = "macos",
+              target_os = "openbsd",
+              target_os = "bitrig",
+              target_os = "solaris")))]
+pub fn stack_bounds() -> Option<(usize, usize)> {
+    None
+}
+
 // glibc >= 2.15 has a __pthread_get_minstack() function that returns
 // PTHREAD_STACK_MIN plus however many bytes are needed for thread-local
 // storage.  We need that information to avoid blowing up when a small stack
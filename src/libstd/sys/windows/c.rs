@@ -42,10 +42,12 @@ pub type UINT = c_uint;
 pub type WCHAR = u16;
 pub type USHORT = c_ushort;
 pub type SIZE_T = usize;
+pub type DWORD_PTR = usize;
 pub type WORD = u16;
 pub type CHAR = c_char;
 pub type HCRYPTPROV = LONG_PTR;
 pub type ULONG_PTR = c_ulonglong;
+pub type PULONG_PTR = *mut ULONG_PTR;
 pub type ULONG = c_ulong;
 pub type ULONGLONG = u64;
 pub type DWORDLONG = ULONGLONG;
@@ -84,6 +86,7 @@ pub const FALSE: BOOL = 0;
 
 pub const FILE_ATTRIBUTE_READONLY: DWORD = 0x1;
 pub const FILE_ATTRIBUTE_DIRECTORY: DWORD = 0x10;
+pub const FILE_ATTRIBUTE_TEMPORARY: DWORD = 0x100;
 pub const FILE_ATTRIBUTE_REPARSE_POINT: DWORD = 0x400;
 
 pub const FILE_SHARE_DELETE: DWORD = 0x4;
@@ -100,6 +103,7 @@ pub const FILE_WRITE_DATA: DWORD = 0x00000002;
 pub const FILE_APPEND_DATA: DWORD = 0x00000004;
 pub const FILE_WRITE_EA: DWORD = 0x00000010;
 pub const FILE_WRITE_ATTRIBUTES: DWORD = 0x00000100;
+pub const DELETE: DWORD = 0x00010000;
 pub const READ_CONTROL: DWORD = 0x00020000;
 pub const SYNCHRONIZE: DWORD = 0x00100000;
 pub const GENERIC_READ: DWORD = 0x80000000;
@@ -111,6 +115,7 @@ pub const FILE_GENERIC_WRITE: DWORD = STANDARD_RIGHTS_WRITE | FILE_WRITE_DATA |
                                       FILE_APPEND_DATA |
                                       SYNCHRONIZE;
 
+pub const FILE_FLAG_DELETE_ON_CLOSE: DWORD = 0x04000000;
 pub const FILE_FLAG_OPEN_REPARSE_POINT: DWORD = 0x00200000;
 pub const FILE_FLAG_BACKUP_SEMANTICS: DWORD = 0x02000000;
 pub const SECURITY_SQOS_PRESENT: DWORD = 0x00100000;
@@ -177,14 +182,19 @@ pub const PROGRESS_CONTINUE: DWORD = 0;
 
 pub const ERROR_FILE_NOT_FOUND: DWORD = 2;
 pub const ERROR_PATH_NOT_FOUND: DWORD = 3;
+pub const ERROR_INVALID_FUNCTION: DWORD = 1;
 pub const ERROR_ACCESS_DENIED: DWORD = 5;
 pub const ERROR_INVALID_HANDLE: DWORD = 6;
 pub const ERROR_NO_MORE_FILES: DWORD = 18;
+pub const ERROR_SHARING_VIOLATION: DWORD = 32;
 pub const ERROR_HANDLE_EOF: DWORD = 38;
 pub const ERROR_FILE_EXISTS: DWORD = 80;
+pub const ERROR_NOT_SUPPORTED: DWORD = 50;
+pub const ERROR_INVALID_PARAMETER: DWORD = 87;
 pub const ERROR_BROKEN_PIPE: DWORD = 109;
 pub const ERROR_CALL_NOT_IMPLEMENTED: DWORD = 120;
 pub const ERROR_INSUFFICIENT_BUFFER: DWORD = 122;
+pub const ERROR_DIR_NOT_EMPTY: DWORD = 145;
 pub const ERROR_ALREADY_EXISTS: DWORD = 183;
 pub const ERROR_NO_DATA: DWORD = 232;
 pub const ERROR_ENVVAR_NOT_FOUND: DWORD = 203;
@@ -202,6 +212,11 @@ pub const TLS_OUT_OF_INDEXES: DWORD = 0xFFFFFFFF;
 pub const DLL_THREAD_DETACH: DWORD = 3;
 pub const DLL_PROCESS_DETACH: DWORD = 0;
 
+pub const THREAD_PRIORITY_BELOW_NORMAL: c_int = -1;
+pub const THREAD_PRIORITY_NORMAL: c_int = 0;
+pub const THREAD_PRIORITY_HIGHEST: c_int = 2;
+pub const THREAD_PRIORITY_TIME_CRITICAL: c_int = 15;
+
 pub const INFINITE: DWORD = !0;
 
 pub const DUPLICATE_SAME_ACCESS: DWORD = 0x00000002;
@@ -385,6 +400,7 @@ pub enum FILE_INFO_BY_HANDLE_CLASS {
     FileIdInfo                      = 18, // 0x12
     FileIdExtdDirectoryInfo         = 19, // 0x13
     FileIdExtdDirectoryRestartInfo  = 20, // 0x14
+    FileDispositionInfoEx           = 21, // 0x15, Windows 10 1607+
     MaximumFileInfoByHandlesClass
 }
 
@@ -393,6 +409,20 @@ pub struct FILE_END_OF_FILE_INFO {
     pub EndOfFile: LARGE_INTEGER,
 }
 
+#[repr(C)]
+pub struct FILE_DISPOSITION_INFO {
+    pub DeleteFile: BOOLEAN,
+}
+
+pub const FILE_DISPOSITION_DELETE: DWORD = 0x00000001;
+pub const FILE_DISPOSITION_POSIX_SEMANTICS: DWORD = 0x00000002;
+pub const FILE_DISPOSITION_IGNORE_READONLY_ATTRIBUTE: DWORD = 0x00000010;
+
+#[repr(C)]
+pub struct FILE_DISPOSITION_INFO_EX {
+    pub Flags: DWORD,
+}
+
 #[repr(C)]
 pub struct REPARSE_DATA_BUFFER {
     pub ReparseTag: c_uint,
@@ -893,6 +923,9 @@ extern "system" {
     pub fn WaitForSingleObject(hHandle: HANDLE,
                                dwMilliseconds: DWORD) -> DWORD;
     pub fn SwitchToThread() -> BOOL;
+    pub fn SetThreadPriority(hThread: HANDLE, nPriority: c_int) -> BOOL;
+    pub fn SetThreadAffinityMask(hThread: HANDLE,
+                                 dwThreadAffinityMask: DWORD_PTR) -> DWORD_PTR;
     pub fn Sleep(dwMilliseconds: DWORD);
     pub fn GetProcessId(handle: HANDLE) -> DWORD;
     pub fn GetUserProfileDirectoryW(hToken: HANDLE,
@@ -1119,6 +1152,10 @@ compat_fn! {
     pub fn SetThreadStackGuarantee(_size: *mut c_ulong) -> BOOL {
         SetLastError(ERROR_CALL_NOT_IMPLEMENTED as DWORD); 0
     }
+    pub fn GetCurrentThreadStackLimits(LowLimit: PULONG_PTR,
+                                       HighLimit: PULONG_PTR) -> () {
+        *LowLimit = 0; *HighLimit = 0
+    }
     pub fn SetFileInformationByHandle(_hFile: HANDLE,
                     _FileInformationClass: FILE_INFO_BY_HANDLE_CLASS,
                     _lpFileInformation: LPVOID,
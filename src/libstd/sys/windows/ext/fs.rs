@@ -14,7 +14,7 @@
 
 use fs::{OpenOptions, Metadata};
 use io;
-use path::Path;
+use path::{Path, PathBuf};
 use sys;
 use sys_common::{AsInnerMut, AsInner};
 
@@ -197,6 +197,56 @@ impl MetadataExt for Metadata {
     fn file_size(&self) -> u64 { self.as_inner().size() }
 }
 
+/// Windows-specific extensions to `Path` for dealing with verbatim paths,
+/// which start with `\\?\` and bypass Win32 path normalization.
+///
+/// Such paths are returned by `fs::canonicalize`, but are not understood by
+/// many programs. See also `fs::canonicalize_simplified`.
+#[unstable(feature = "windows_path_verbatim", issue = "0")]
+pub trait PathExt {
+    /// Returns this path without its `\\?\` prefix if removing it doesn't
+    /// change which file the path refers to, and a copy of the unchanged
+    /// path otherwise.
+    ///
+    /// Only verbatim disk (`\\?\C:\`) and UNC (`\\?\UNC\`) paths are
+    /// simplified, and only if they are absolute, shorter than `MAX_PATH`,
+    /// and contain no `.` or `..` components, `/` characters, components
+    /// ending in a dot or space, or reserved device names like `NUL`.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// #![feature(windows_path_verbatim)]
+    /// use std::os::windows::fs::PathExt;
+    /// use std::path::Path;
+    ///
+    /// assert_eq!(Path::new(r"\\?\C:\foo").to_simplified(), Path::new(r"C:\foo"));
+    /// assert_eq!(Path::new(r"\\?\C:\nul").to_simplified(), Path::new(r"\\?\C:\nul"));
+    /// ```
+    #[unstable(feature = "windows_path_verbatim", issue = "0")]
+    fn to_simplified(&self) -> PathBuf;
+
+    /// Returns whether this path requires the verbatim syntax to refer to
+    /// the file it names.
+    ///
+    /// This is the case for verbatim paths which `to_simplified` can't
+    /// shorten, and for other paths which Win32 path normalization would
+    /// alter, such as those exceeding `MAX_PATH` characters.
+    #[unstable(feature = "windows_path_verbatim", issue = "0")]
+    fn requires_verbatim(&self) -> bool;
+}
+
+#[unstable(feature = "windows_path_verbatim", issue = "0")]
+impl PathExt for Path {
+    fn to_simplified(&self) -> PathBuf {
+        sys::path::simplify(self).unwrap_or_else(|| self.to_path_buf())
+    }
+
+    fn requires_verbatim(&self) -> bool {
+        sys::path::requires_verbatim(self)
+    }
+}
+
 /// Creates a new file symbolic link on the filesystem.
 ///
 /// The `dst` path will be a file symbolic link pointing to the `src`
@@ -10,7 +10,10 @@
 
 #![stable(feature = "rust1", since = "1.0.0")]
 
+use fmt;
 use fs;
+use io;
+use marker::PhantomData;
 use os::windows::raw;
 use net;
 use sys_common::{self, AsInner, FromInner, IntoInner};
@@ -64,6 +67,141 @@ pub trait IntoRawHandle {
     fn into_raw_handle(self) -> RawHandle;
 }
 
+/// An owned handle, which is closed when it is dropped.
+///
+/// Unlike a `RawHandle`, an `OwnedHandle` is known to be open and to have no
+/// other owner, so it can be converted to and from the I/O types of the
+/// standard library, such as `File` and `Child`, with `From` and without
+/// `unsafe`.
+#[unstable(feature = "io_safety", issue = "0")]
+pub struct OwnedHandle(sys::handle::Handle);
+
+/// A borrowed handle, which is open for as long as the lifetime `'handle`
+/// that it borrows from.
+#[derive(Copy, Clone)]
+#[unstable(feature = "io_safety", issue = "0")]
+pub struct BorrowedHandle<'handle> {
+    handle: RawHandle,
+    _marker: PhantomData<&'handle OwnedHandle>,
+}
+
+#[unstable(feature = "io_safety", issue = "0")]
+unsafe impl<'handle> Send for BorrowedHandle<'handle> {}
+#[unstable(feature = "io_safety", issue = "0")]
+unsafe impl<'handle> Sync for BorrowedHandle<'handle> {}
+
+impl OwnedHandle {
+    /// Creates a new `OwnedHandle` for the same object as `self`, which is
+    /// not inherited by child processes.
+    #[unstable(feature = "io_safety", issue = "0")]
+    pub fn try_clone(&self) -> io::Result<OwnedHandle> {
+        self.as_handle().try_clone_to_owned()
+    }
+}
+
+impl<'handle> BorrowedHandle<'handle> {
+    /// Borrows the raw handle `handle` for the lifetime `'handle`.
+    ///
+    /// # Safety
+    ///
+    /// `handle` must be an open handle, and stay open for all of `'handle`,
+    /// which is not checked.
+    #[unstable(feature = "io_safety", issue = "0")]
+    pub unsafe fn borrow_raw(handle: RawHandle) -> BorrowedHandle<'handle> {
+        BorrowedHandle { handle: handle, _marker: PhantomData }
+    }
+
+    /// Creates a new `OwnedHandle` for the same object as `self`, which is
+    /// not inherited by child processes.
+    #[unstable(feature = "io_safety", issue = "0")]
+    pub fn try_clone_to_owned(&self) -> io::Result<OwnedHandle> {
+        let raw = sys::handle::RawHandle::new(self.handle as c::HANDLE);
+        raw.duplicate(0, false, c::DUPLICATE_SAME_ACCESS).map(OwnedHandle)
+    }
+}
+
+/// A trait to borrow the handle from an underlying object.
+///
+/// This is the safe counterpart of `AsRawHandle`: the returned
+/// `BorrowedHandle` can't outlive `self`, so the handle is always open while
+/// it is in use.
+#[unstable(feature = "io_safety", issue = "0")]
+pub trait AsHandle {
+    /// Borrows the handle.
+    #[unstable(feature = "io_safety", issue = "0")]
+    fn as_handle(&self) -> BorrowedHandle;
+}
+
+#[unstable(feature = "io_safety", issue = "0")]
+impl<'a, T: AsHandle + ?Sized> AsHandle for &'a T {
+    fn as_handle(&self) -> BorrowedHandle {
+        T::as_handle(self)
+    }
+}
+
+#[unstable(feature = "io_safety", issue = "0")]
+impl<'a, T: AsHandle + ?Sized> AsHandle for &'a mut T {
+    fn as_handle(&self) -> BorrowedHandle {
+        T::as_handle(self)
+    }
+}
+
+#[unstable(feature = "io_safety", issue = "0")]
+impl AsHandle for OwnedHandle {
+    fn as_handle(&self) -> BorrowedHandle {
+        BorrowedHandle { handle: self.as_raw_handle(), _marker: PhantomData }
+    }
+}
+
+#[unstable(feature = "io_safety", issue = "0")]
+impl<'handle> AsHandle for BorrowedHandle<'handle> {
+    fn as_handle(&self) -> BorrowedHandle {
+        *self
+    }
+}
+
+#[unstable(feature = "io_safety", issue = "0")]
+impl AsRawHandle for OwnedHandle {
+    fn as_raw_handle(&self) -> RawHandle {
+        self.0.raw() as RawHandle
+    }
+}
+
+#[unstable(feature = "io_safety", issue = "0")]
+impl FromRawHandle for OwnedHandle {
+    unsafe fn from_raw_handle(handle: RawHandle) -> OwnedHandle {
+        OwnedHandle(sys::handle::Handle::new(handle as c::HANDLE))
+    }
+}
+
+#[unstable(feature = "io_safety", issue = "0")]
+impl IntoRawHandle for OwnedHandle {
+    fn into_raw_handle(self) -> RawHandle {
+        self.0.into_raw() as RawHandle
+    }
+}
+
+#[unstable(feature = "io_safety", issue = "0")]
+impl<'handle> AsRawHandle for BorrowedHandle<'handle> {
+    fn as_raw_handle(&self) -> RawHandle {
+        self.handle
+    }
+}
+
+#[unstable(feature = "io_safety", issue = "0")]
+impl fmt::Debug for OwnedHandle {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("OwnedHandle").field("handle", &self.as_raw_handle()).finish()
+    }
+}
+
+#[unstable(feature = "io_safety", issue = "0")]
+impl<'handle> fmt::Debug for BorrowedHandle<'handle> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("BorrowedHandle").field("handle", &self.handle).finish()
+    }
+}
+
 #[stable(feature = "rust1", since = "1.0.0")]
 impl AsRawHandle for fs::File {
     fn as_raw_handle(&self) -> RawHandle {
@@ -124,6 +262,140 @@ pub trait IntoRawSocket {
     fn into_raw_socket(self) -> RawSocket;
 }
 
+/// An owned socket, which is closed when it is dropped.
+///
+/// This is the `SOCKET` counterpart of `OwnedHandle`, and converts to and
+/// from the socket types of `std::net`.
+#[unstable(feature = "io_safety", issue = "0")]
+pub struct OwnedSocket(sys::net::Socket);
+
+/// A borrowed socket, which is open for as long as the lifetime `'socket`
+/// that it borrows from.
+#[derive(Copy, Clone)]
+#[unstable(feature = "io_safety", issue = "0")]
+pub struct BorrowedSocket<'socket> {
+    socket: RawSocket,
+    _marker: PhantomData<&'socket OwnedSocket>,
+}
+
+impl OwnedSocket {
+    /// Creates a new `OwnedSocket` for the same socket as `self`.
+    #[unstable(feature = "io_safety", issue = "0")]
+    pub fn try_clone(&self) -> io::Result<OwnedSocket> {
+        self.0.duplicate().map(OwnedSocket)
+    }
+}
+
+impl<'socket> BorrowedSocket<'socket> {
+    /// Borrows the raw socket `socket` for the lifetime `'socket`.
+    ///
+    /// # Safety
+    ///
+    /// `socket` must be an open socket, and stay open for all of `'socket`,
+    /// which is not checked.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `socket` is `INVALID_SOCKET`.
+    #[unstable(feature = "io_safety", issue = "0")]
+    pub unsafe fn borrow_raw(socket: RawSocket) -> BorrowedSocket<'socket> {
+        assert!(socket != c::INVALID_SOCKET as RawSocket, "INVALID_SOCKET is not a socket");
+        BorrowedSocket { socket: socket, _marker: PhantomData }
+    }
+
+    /// Creates a new `OwnedSocket` for the same socket as `self`.
+    #[unstable(feature = "io_safety", issue = "0")]
+    pub fn try_clone_to_owned(&self) -> io::Result<OwnedSocket> {
+        // `duplicate` takes a `Socket`, which must not close the socket that
+        // it doesn't own.
+        let socket = sys::net::Socket::from_inner(self.socket as c::SOCKET);
+        let dup = socket.duplicate();
+        socket.into_inner();
+        dup.map(OwnedSocket)
+    }
+}
+
+/// A trait to borrow the socket from an underlying object.
+///
+/// This is the safe counterpart of `AsRawSocket`.
+#[unstable(feature = "io_safety", issue = "0")]
+pub trait AsSocket {
+    /// Borrows the socket.
+    #[unstable(feature = "io_safety", issue = "0")]
+    fn as_socket(&self) -> BorrowedSocket;
+}
+
+#[unstable(feature = "io_safety", issue = "0")]
+impl<'a, T: AsSocket + ?Sized> AsSocket for &'a T {
+    fn as_socket(&self) -> BorrowedSocket {
+        T::as_socket(self)
+    }
+}
+
+#[unstable(feature = "io_safety", issue = "0")]
+impl<'a, T: AsSocket + ?Sized> AsSocket for &'a mut T {
+    fn as_socket(&self) -> BorrowedSocket {
+        T::as_socket(self)
+    }
+}
+
+#[unstable(feature = "io_safety", issue = "0")]
+impl AsSocket for OwnedSocket {
+    fn as_socket(&self) -> BorrowedSocket {
+        BorrowedSocket { socket: self.as_raw_socket(), _marker: PhantomData }
+    }
+}
+
+#[unstable(feature = "io_safety", issue = "0")]
+impl<'socket> AsSocket for BorrowedSocket<'socket> {
+    fn as_socket(&self) -> BorrowedSocket {
+        *self
+    }
+}
+
+#[unstable(feature = "io_safety", issue = "0")]
+impl AsRawSocket for OwnedSocket {
+    fn as_raw_socket(&self) -> RawSocket {
+        *self.0.as_inner() as RawSocket
+    }
+}
+
+#[unstable(feature = "io_safety", issue = "0")]
+impl FromRawSocket for OwnedSocket {
+    unsafe fn from_raw_socket(socket: RawSocket) -> OwnedSocket {
+        assert!(socket != c::INVALID_SOCKET as RawSocket, "INVALID_SOCKET is not a socket");
+        OwnedSocket(sys::net::Socket::from_inner(socket as c::SOCKET))
+    }
+}
+
+#[unstable(feature = "io_safety", issue = "0")]
+impl IntoRawSocket for OwnedSocket {
+    fn into_raw_socket(self) -> RawSocket {
+        self.0.into_inner() as RawSocket
+    }
+}
+
+#[unstable(feature = "io_safety", issue = "0")]
+impl<'socket> AsRawSocket for BorrowedSocket<'socket> {
+    fn as_raw_socket(&self) -> RawSocket {
+        self.socket
+    }
+}
+
+#[unstable(feature = "io_safety", issue = "0")]
+impl fmt::Debug for OwnedSocket {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("OwnedSocket").field("socket", &self.as_raw_socket()).finish()
+    }
+}
+
+#[unstable(feature = "io_safety", issue = "0")]
+impl<'socket> fmt::Debug for BorrowedSocket<'socket> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("BorrowedSocket").field("socket", &self.socket).finish()
+    }
+}
+
 #[stable(feature = "rust1", since = "1.0.0")]
 impl AsRawSocket for net::TcpStream {
     fn as_raw_socket(&self) -> RawSocket {
@@ -185,3 +457,71 @@ impl IntoRawSocket for net::UdpSocket {
         self.into_inner().into_socket().into_inner()
     }
 }
+
+// The standard library types own the handles and sockets that they return
+// from `as_raw_*` and `into_raw_*`, and take ownership of those passed to
+// `from_raw_*`, so these conversions are all safe. The conversions for the
+// types of `std::process` and `std::thread` are next to their raw ones.
+//
+// Note that the attributes on trait impls aren't enforced, so unlike the rest
+// of `io_safety` the `From` conversions are usable on stable as soon as they
+// are added, and can't be taken back.
+macro_rules! as_handle_impl {
+    ($($t:ty),*) => ($(
+        #[unstable(feature = "io_safety", issue = "0")]
+        impl AsHandle for $t {
+            fn as_handle(&self) -> BorrowedHandle {
+                BorrowedHandle { handle: self.as_raw_handle(), _marker: PhantomData }
+            }
+        }
+
+        #[unstable(feature = "io_safety", issue = "0")]
+        impl From<$t> for OwnedHandle {
+            fn from(x: $t) -> OwnedHandle {
+                unsafe { OwnedHandle::from_raw_handle(x.into_raw_handle()) }
+            }
+        }
+    )*)
+}
+
+macro_rules! from_handle_impl {
+    ($($t:ty),*) => ($(
+        #[unstable(feature = "io_safety", issue = "0")]
+        impl From<OwnedHandle> for $t {
+            fn from(handle: OwnedHandle) -> $t {
+                unsafe { FromRawHandle::from_raw_handle(handle.into_raw_handle()) }
+            }
+        }
+    )*)
+}
+
+macro_rules! socket_impl {
+    ($($t:ty),*) => ($(
+        #[unstable(feature = "io_safety", issue = "0")]
+        impl AsSocket for $t {
+            fn as_socket(&self) -> BorrowedSocket {
+                BorrowedSocket { socket: self.as_raw_socket(), _marker: PhantomData }
+            }
+        }
+
+        #[unstable(feature = "io_safety", issue = "0")]
+        impl From<$t> for OwnedSocket {
+            fn from(x: $t) -> OwnedSocket {
+                unsafe { OwnedSocket::from_raw_socket(x.into_raw_socket()) }
+            }
+        }
+
+        #[unstable(feature = "io_safety", issue = "0")]
+        impl From<OwnedSocket> for $t {
+            fn from(socket: OwnedSocket) -> $t {
+                unsafe { FromRawSocket::from_raw_socket(socket.into_raw_socket()) }
+            }
+        }
+    )*)
+}
+
+as_handle_impl! { fs::File }
+
+from_handle_impl! { fs::File }
+
+socket_impl! { net::TcpStream, net::TcpListener, net::UdpSocket }
@@ -32,6 +32,10 @@ pub mod prelude {
     pub use super::io::{RawSocket, RawHandle, AsRawSocket, AsRawHandle};
     #[doc(no_inline)] #[stable(feature = "rust1", since = "1.0.0")]
     pub use super::io::{FromRawSocket, FromRawHandle, IntoRawSocket, IntoRawHandle};
+    #[doc(no_inline)] #[unstable(feature = "io_safety", issue = "0")]
+    pub use super::io::{OwnedHandle, BorrowedHandle, AsHandle};
+    #[doc(no_inline)] #[unstable(feature = "io_safety", issue = "0")]
+    pub use super::io::{OwnedSocket, BorrowedSocket, AsSocket};
     #[doc(no_inline)] #[stable(feature = "rust1", since = "1.0.0")]
     pub use super::ffi::{OsStrExt, OsStringExt};
     #[doc(no_inline)] #[stable(feature = "rust1", since = "1.0.0")]
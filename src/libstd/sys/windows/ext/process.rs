@@ -13,6 +13,7 @@
 #![stable(feature = "process_extensions", since = "1.2.0")]
 
 use os::windows::io::{FromRawHandle, RawHandle, AsRawHandle, IntoRawHandle};
+use os::windows::io::{AsHandle, BorrowedHandle, OwnedHandle};
 use process;
 use sys;
 use sys_common::{AsInner, FromInner, IntoInner};
@@ -82,6 +83,69 @@ impl IntoRawHandle for process::ChildStderr {
     }
 }
 
+#[unstable(feature = "io_safety", issue = "0")]
+impl From<OwnedHandle> for process::Stdio {
+    fn from(handle: OwnedHandle) -> process::Stdio {
+        unsafe { process::Stdio::from_raw_handle(handle.into_raw_handle()) }
+    }
+}
+
+#[unstable(feature = "io_safety", issue = "0")]
+impl AsHandle for process::Child {
+    fn as_handle(&self) -> BorrowedHandle {
+        unsafe { BorrowedHandle::borrow_raw(self.as_raw_handle()) }
+    }
+}
+
+#[unstable(feature = "io_safety", issue = "0")]
+impl From<process::Child> for OwnedHandle {
+    fn from(child: process::Child) -> OwnedHandle {
+        unsafe { OwnedHandle::from_raw_handle(child.into_raw_handle()) }
+    }
+}
+
+#[unstable(feature = "io_safety", issue = "0")]
+impl AsHandle for process::ChildStdin {
+    fn as_handle(&self) -> BorrowedHandle {
+        unsafe { BorrowedHandle::borrow_raw(self.as_raw_handle()) }
+    }
+}
+
+#[unstable(feature = "io_safety", issue = "0")]
+impl From<process::ChildStdin> for OwnedHandle {
+    fn from(stdin: process::ChildStdin) -> OwnedHandle {
+        unsafe { OwnedHandle::from_raw_handle(stdin.into_raw_handle()) }
+    }
+}
+
+#[unstable(feature = "io_safety", issue = "0")]
+impl AsHandle for process::ChildStdout {
+    fn as_handle(&self) -> BorrowedHandle {
+        unsafe { BorrowedHandle::borrow_raw(self.as_raw_handle()) }
+    }
+}
+
+#[unstable(feature = "io_safety", issue = "0")]
+impl From<process::ChildStdout> for OwnedHandle {
+    fn from(stdout: process::ChildStdout) -> OwnedHandle {
+        unsafe { OwnedHandle::from_raw_handle(stdout.into_raw_handle()) }
+    }
+}
+
+#[unstable(feature = "io_safety", issue = "0")]
+impl AsHandle for process::ChildStderr {
+    fn as_handle(&self) -> BorrowedHandle {
+        unsafe { BorrowedHandle::borrow_raw(self.as_raw_handle()) }
+    }
+}
+
+#[unstable(feature = "io_safety", issue = "0")]
+impl From<process::ChildStderr> for OwnedHandle {
+    fn from(stderr: process::ChildStderr) -> OwnedHandle {
+        unsafe { OwnedHandle::from_raw_handle(stderr.into_raw_handle()) }
+    }
+}
+
 /// Windows-specific extensions to `std::process::ExitStatus`
 #[unstable(feature = "exit_status_from", issue = "32713")]
 pub trait ExitStatusExt {
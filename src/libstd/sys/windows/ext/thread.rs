@@ -13,6 +13,7 @@
 #![stable(feature = "thread_extensions", since = "1.9.0")]
 
 use os::windows::io::{RawHandle, AsRawHandle, IntoRawHandle};
+use os::windows::io::{AsHandle, BorrowedHandle, FromRawHandle, OwnedHandle};
 use thread;
 use sys_common::{AsInner, IntoInner};
 
@@ -29,3 +30,17 @@ impl<T> IntoRawHandle for thread::JoinHandle<T>  {
         self.into_inner().into_handle().into_raw() as *mut _
     }
 }
+
+#[unstable(feature = "io_safety", issue = "0")]
+impl<T> AsHandle for thread::JoinHandle<T> {
+    fn as_handle(&self) -> BorrowedHandle {
+        unsafe { BorrowedHandle::borrow_raw(self.as_raw_handle()) }
+    }
+}
+
+#[unstable(feature = "io_safety", issue = "0")]
+impl<T> From<thread::JoinHandle<T>> for OwnedHandle {
+    fn from(handle: thread::JoinHandle<T>) -> OwnedHandle {
+        unsafe { OwnedHandle::from_raw_handle(handle.into_raw_handle()) }
+    }
+}
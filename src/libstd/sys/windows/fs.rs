@@ -285,6 +285,36 @@ impl File {
         Ok(())
     }
 
+    fn posix_delete(&self) -> io::Result<()> {
+        let mut info = c::FILE_DISPOSITION_INFO_EX {
+            Flags: c::FILE_DISPOSITION_DELETE |
+                   c::FILE_DISPOSITION_POSIX_SEMANTICS |
+                   c::FILE_DISPOSITION_IGNORE_READONLY_ATTRIBUTE,
+        };
+        let size = mem::size_of_val(&info);
+        cvt(unsafe {
+            c::SetFileInformationByHandle(self.handle.raw(),
+                                          c::FileDispositionInfoEx,
+                                          &mut info as *mut _ as *mut _,
+                                          size as c::DWORD)
+        })?;
+        Ok(())
+    }
+
+    fn delete_on_close(&self) -> io::Result<()> {
+        let mut info = c::FILE_DISPOSITION_INFO {
+            DeleteFile: 1,
+        };
+        let size = mem::size_of_val(&info);
+        cvt(unsafe {
+            c::SetFileInformationByHandle(self.handle.raw(),
+                                          c::FileDispositionInfo,
+                                          &mut info as *mut _ as *mut _,
+                                          size as c::DWORD)
+        })?;
+        Ok(())
+    }
+
     pub fn file_attr(&self) -> io::Result<FileAttr> {
         unsafe {
             let mut info: c::BY_HANDLE_FILE_INFORMATION = mem::zeroed();
@@ -581,13 +611,72 @@ fn remove_dir_all_recursive(path: &Path) -> io::Result<()> {
         let child_type = child.file_type()?;
         if child_type.is_dir() {
             remove_dir_all_recursive(&child.path())?;
-        } else if child_type.is_symlink_dir() {
-            rmdir(&child.path())?;
         } else {
-            unlink(&child.path())?;
+            remove_with_retry(&child.path(), child_type.is_symlink_dir())?;
+        }
+    }
+    remove_with_retry(path, true)
+}
+
+// Virus scanners, indexers and the like routinely open files for a few
+// milliseconds right after they are created or modified, which makes a
+// deletion fail with a sharing violation, or makes deleting the parent
+// directory fail because a file marked for deletion is still lingering in
+// it. Retry such failures with an exponential backoff of up to about a
+// second in total before giving up.
+fn remove_with_retry(path: &Path, dir: bool) -> io::Result<()> {
+    const MAX_RETRIES: u32 = 10;
+
+    let mut retries = 0;
+    loop {
+        let err = match remove_by_handle(path, dir) {
+            Ok(()) => return Ok(()),
+            Err(e) => e,
+        };
+        if !is_transient_remove_error(&err) || retries == MAX_RETRIES {
+            return Err(err)
         }
+        unsafe { c::Sleep(1 << retries) }
+        retries += 1;
+    }
+}
+
+// Access denied is not among these: it is what read-only files and missing
+// permissions give, which waiting doesn't fix.
+fn is_transient_remove_error(err: &io::Error) -> bool {
+    match err.raw_os_error().map(|e| e as c::DWORD) {
+        Some(c::ERROR_SHARING_VIOLATION) |
+        Some(c::ERROR_DIR_NOT_EMPTY) => true,
+        _ => false,
     }
-    rmdir(path)
+}
+
+// Removes `path` itself, never anything a reparse point at `path` refers to.
+//
+// POSIX semantics unlink the name immediately even if other handles to the
+// file are still open, so that the parent directory can be removed right
+// afterwards. They are only available since Windows 10 1607 and only on some
+// filesystems; elsewhere we fall back to a regular delete-on-close, and on
+// systems without `SetFileInformationByHandle` to deleting by path.
+fn remove_by_handle(path: &Path, dir: bool) -> io::Result<()> {
+    let mut opts = OpenOptions::new();
+    opts.access_mode(c::DELETE);
+    opts.custom_flags(c::FILE_FLAG_BACKUP_SEMANTICS |
+                      c::FILE_FLAG_OPEN_REPARSE_POINT);
+    let file = File::open(path, &opts)?;
+
+    match file.posix_delete() {
+        Err(ref e) if e.raw_os_error() == Some(c::ERROR_INVALID_PARAMETER as i32) ||
+                      e.raw_os_error() == Some(c::ERROR_NOT_SUPPORTED as i32) ||
+                      e.raw_os_error() == Some(c::ERROR_INVALID_FUNCTION as i32) => {}
+        result => return result,
+    }
+    match file.delete_on_close() {
+        Err(ref e) if e.raw_os_error() == Some(c::ERROR_CALL_NOT_IMPLEMENTED as i32) => {}
+        result => return result,
+    }
+    drop(file);
+    if dir { rmdir(path) } else { unlink(path) }
 }
 
 pub fn readlink(path: &Path) -> io::Result<PathBuf> {
@@ -616,6 +705,20 @@ pub fn symlink_inner(src: &Path, dst: &Path, dir: bool) -> io::Result<()> {
     Ok(())
 }
 
+pub fn copy_symlink(src: &Path, dst: &Path) -> io::Result<()> {
+    let dir = lstat(src)?.file_type().is_symlink_dir();
+    symlink_inner(&readlink(src)?, dst, dir)
+}
+
+pub fn remove_symlink(p: &Path) -> io::Result<()> {
+    // Directory symlinks and junctions have to be removed with rmdir.
+    if lstat(p)?.file_type().is_symlink_dir() {
+        rmdir(p)
+    } else {
+        unlink(p)
+    }
+}
+
 pub fn link(src: &Path, dst: &Path) -> io::Result<()> {
     let src = to_u16s(src)?;
     let dst = to_u16s(dst)?;
@@ -671,6 +774,34 @@ pub fn canonicalize(p: &Path) -> io::Result<PathBuf> {
     get_path(&f)
 }
 
+// Windows has no way to create a file without a name.
+pub fn tempfile_anonymous(_dir: &Path) -> io::Result<Option<File>> {
+    Ok(None)
+}
+
+// Exclusively creates the file at `path`, marked as temporary so that it is
+// preferably kept in the cache and deleted by the system once every handle to
+// it is closed.
+pub fn tempfile_create(path: &Path) -> io::Result<File> {
+    let mut opts = OpenOptions::new();
+    opts.read(true);
+    opts.write(true);
+    opts.create_new(true);
+    opts.share_mode(c::FILE_SHARE_READ | c::FILE_SHARE_WRITE | c::FILE_SHARE_DELETE);
+    opts.attributes(c::FILE_ATTRIBUTE_TEMPORARY);
+    opts.custom_flags(c::FILE_FLAG_DELETE_ON_CLOSE);
+    File::open(path, &opts)
+}
+
+pub fn tempdir_create(path: &Path) -> io::Result<()> {
+    DirBuilder::new().mkdir(path)
+}
+
+pub fn canonicalize_simplified(p: &Path) -> io::Result<PathBuf> {
+    let path = canonicalize(p)?;
+    Ok(super::path::simplify(&path).unwrap_or(path))
+}
+
 pub fn copy(from: &Path, to: &Path) -> io::Result<u64> {
     unsafe extern "system" fn callback(
         _TotalFileSize: c::LARGE_INTEGER,
@@ -749,3 +880,19 @@ fn symlink_junction_inner(target: &Path, junction: &Path) -> io::Result<()> {
                                ptr::null_mut())).map(|_| ())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use io::{self, Error};
+    use sys::c;
+    use super::is_transient_remove_error;
+
+    #[test]
+    fn transient_remove_errors() {
+        let os = |code: c::DWORD| Error::from_raw_os_error(code as i32);
+        assert!(is_transient_remove_error(&os(c::ERROR_SHARING_VIOLATION)));
+        assert!(is_transient_remove_error(&os(c::ERROR_DIR_NOT_EMPTY)));
+        assert!(!is_transient_remove_error(&os(c::ERROR_ACCESS_DENIED)));
+        assert!(!is_transient_remove_error(&Error::new(io::ErrorKind::Other, "other")));
+    }
+}
@@ -12,12 +12,14 @@
 /// wrapper around the "WTF-8" encoding; see the `wtf8` module for more.
 
 use borrow::Cow;
+use boxed::Box;
 use fmt::{self, Debug};
-use sys_common::wtf8::{Wtf8, Wtf8Buf};
+use sys_common::wtf8::{self, Wtf8, Wtf8Buf};
 use string::String;
 use result::Result;
 use option::Option;
 use mem;
+use vec::Vec;
 use sys_common::{AsInner, IntoInner};
 
 #[derive(Clone, Hash)]
@@ -91,6 +93,56 @@ impl Buf {
     pub fn reserve_exact(&mut self, additional: usize) {
         self.inner.reserve_exact(additional)
     }
+
+    pub fn truncate(&mut self, new_len: usize) {
+        if new_len <= self.inner.len() {
+            self.inner.truncate(new_len)
+        }
+    }
+
+    pub fn pop(&mut self) -> Option<char> {
+        match self.inner.last_code_point().and_then(|c| c.to_char()) {
+            Some(c) => {
+                self.inner.pop();
+                Some(c)
+            }
+            None => None,
+        }
+    }
+
+    pub fn retain<F: FnMut(char) -> bool>(&mut self, mut f: F) {
+        // Unpaired surrogates are always kept.
+        self.inner.retain(|c| c.to_char().map_or(true, |c| f(c)))
+    }
+
+    pub fn shrink_to_fit(&mut self) {
+        self.inner.shrink_to_fit()
+    }
+
+    pub fn shrink_to(&mut self, min_capacity: usize) {
+        self.inner.shrink_to(min_capacity)
+    }
+
+    pub fn into_box(self) -> Box<Slice> {
+        unsafe { mem::transmute(self.inner.into_box()) }
+    }
+
+    pub fn from_box(boxed: Box<Slice>) -> Buf {
+        let inner: Box<Wtf8> = unsafe { mem::transmute(boxed) };
+        Buf { inner: Wtf8Buf::from_box(inner) }
+    }
+
+    pub fn from_encoded_bytes(bytes: Vec<u8>) -> Result<Buf, Vec<u8>> {
+        Wtf8Buf::from_bytes(bytes).map(|inner| Buf { inner: inner })
+    }
+
+    pub unsafe fn from_encoded_bytes_unchecked(bytes: Vec<u8>) -> Buf {
+        Buf { inner: Wtf8Buf::from_bytes_unchecked(bytes) }
+    }
+
+    pub fn into_encoded_bytes(self) -> Vec<u8> {
+        self.inner.into_bytes()
+    }
 }
 
 impl Slice {
@@ -111,4 +163,20 @@ impl Slice {
         buf.push_wtf8(&self.inner);
         Buf { inner: buf }
     }
+
+    pub fn is_boundary(&self, index: usize) -> bool {
+        wtf8::is_code_point_boundary(&self.inner, index)
+    }
+
+    pub fn from_encoded_bytes(bytes: &[u8]) -> Option<&Slice> {
+        Wtf8::from_bytes(bytes).map(|inner| unsafe { mem::transmute(inner) })
+    }
+
+    pub unsafe fn from_encoded_bytes_unchecked(bytes: &[u8]) -> &Slice {
+        mem::transmute(Wtf8::from_bytes_unchecked(bytes))
+    }
+
+    pub fn as_encoded_bytes(&self) -> &[u8] {
+        self.inner.as_inner()
+    }
 }
@@ -0,0 +1,188 @@
+// Copyright 2016 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Conversions between verbatim (`\\?\`) paths and regular Win32 paths.
+//!
+//! Verbatim paths are handed to the filesystem as-is, while every other path
+//! is first normalized by the Win32 layer: `/` becomes a separator, `.` and
+//! `..` are resolved, trailing dots and spaces are stripped from components,
+//! reserved device names like `NUL` or `COM1` refer to devices, and the
+//! length is limited to `MAX_PATH`. A verbatim path can only be simplified
+//! when none of these rules would change its meaning.
+
+use prelude::v1::*;
+use os::windows::prelude::*;
+
+use ascii::AsciiExt;
+use ffi::{OsStr, OsString};
+use path::{Component, Path, PathBuf, Prefix};
+
+// Includes the terminating null character.
+const MAX_PATH: usize = 260;
+
+const RESERVED_NAMES: &'static [&'static [u8]] = &[
+    b"CON", b"PRN", b"AUX", b"NUL", b"CONIN$", b"CONOUT$",
+    b"COM1", b"COM2", b"COM3", b"COM4", b"COM5", b"COM6", b"COM7", b"COM8", b"COM9",
+    b"LPT1", b"LPT2", b"LPT3", b"LPT4", b"LPT5", b"LPT6", b"LPT7", b"LPT8", b"LPT9",
+];
+
+/// Returns the equivalent of the verbatim path `path` without the `\\?\`
+/// prefix, or `None` if `path` isn't a verbatim disk or UNC path or if
+/// Win32 path normalization would change its meaning.
+pub fn simplify(path: &Path) -> Option<PathBuf> {
+    let mut components = path.components();
+    let mut simplified = match components.next() {
+        Some(Component::Prefix(prefix)) => match prefix.kind() {
+            Prefix::VerbatimDisk(drive) => {
+                let mut s = OsString::new();
+                s.push(&format!("{}:", drive as char));
+                s
+            }
+            Prefix::VerbatimUNC(server, share) => {
+                let mut s = OsString::from(r"\\");
+                s.push(server);
+                s.push(r"\");
+                s.push(share);
+                s
+            }
+            _ => return None,
+        },
+        _ => return None,
+    };
+
+    // `\\?\C:` is the root of the drive, but `C:` is relative to the current
+    // directory on that drive.
+    if components.next() != Some(Component::RootDir) {
+        return None
+    }
+    simplified.push(r"\");
+    let mut first = true;
+    for component in components {
+        match component {
+            Component::Normal(name) if is_plain_component(name) => {
+                if !first {
+                    simplified.push(r"\");
+                }
+                simplified.push(name);
+                first = false;
+            }
+            _ => return None,
+        }
+    }
+
+    if simplified.encode_wide().count() >= MAX_PATH {
+        return None
+    }
+    Some(PathBuf::from(simplified))
+}
+
+/// Returns whether `path` needs the verbatim syntax to refer to what it
+/// names: for verbatim paths, whether `simplify` can't remove the prefix, and
+/// for all other paths, whether Win32 path normalization would alter them.
+pub fn requires_verbatim(path: &Path) -> bool {
+    let mut components = path.components();
+    match components.clone().next() {
+        Some(Component::Prefix(prefix)) if prefix.kind().is_verbatim() => {
+            return simplify(path).is_none()
+        }
+        _ => {}
+    }
+    if path.as_os_str().encode_wide().count() >= MAX_PATH {
+        return true
+    }
+    components.any(|component| match component {
+        Component::Normal(name) => !is_plain_component(name),
+        _ => false,
+    })
+}
+
+// Whether the component `name` means the same thing with and without Win32
+// path normalization.
+fn is_plain_component(name: &OsStr) -> bool {
+    let chars: Vec<u16> = name.encode_wide().collect();
+    match chars.last() {
+        None => return false,
+        Some(&c) if c == b'.' as u16 || c == b' ' as u16 => return false,
+        Some(_) => {}
+    }
+    if chars.iter().any(|&c| c == b'/' as u16) {
+        return false
+    }
+
+    // Device names are reserved regardless of any extension or stream name,
+    // and of the case they are written in.
+    let stem_len = chars.iter()
+                        .position(|&c| c == b'.' as u16 || c == b':' as u16)
+                        .unwrap_or(chars.len());
+    let stem = &chars[..stem_len];
+    let stem_len = stem.iter().rposition(|&c| c != b' ' as u16).map_or(0, |i| i + 1);
+    let stem = &stem[..stem_len];
+    !RESERVED_NAMES.iter().any(|reserved| {
+        reserved.len() == stem.len() &&
+        reserved.iter().zip(stem).all(|(&r, &c)| {
+            c < 0x80 && (c as u8).to_ascii_uppercase() == r
+        })
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use prelude::v1::*;
+    use iter;
+    use path::{Path, PathBuf};
+    use super::{simplify, requires_verbatim};
+
+    fn simplified(path: &str) -> Option<PathBuf> {
+        simplify(Path::new(path))
+    }
+
+    #[test]
+    fn simplify_verbatim_disk() {
+        assert_eq!(simplified(r"\\?\C:\"), Some(PathBuf::from(r"C:\")));
+        assert_eq!(simplified(r"\\?\c:\foo\bar.txt"), Some(PathBuf::from(r"C:\foo\bar.txt")));
+        assert_eq!(simplified(r"\\?\C:"), None);
+        assert_eq!(simplified(r"\\?\C:\foo\..\bar"), None);
+        assert_eq!(simplified(r"\\?\C:\foo."), None);
+        assert_eq!(simplified(r"\\?\C:\foo \bar"), None);
+        assert_eq!(simplified(r"\\?\C:\a/b"), None);
+        assert_eq!(simplified(r"\\?\C:\nul"), None);
+        assert_eq!(simplified(r"\\?\C:\Com1.txt"), None);
+        assert_eq!(simplified(r"\\?\C:\console"), Some(PathBuf::from(r"C:\console")));
+    }
+
+    #[test]
+    fn simplify_verbatim_unc() {
+        assert_eq!(simplified(r"\\?\UNC\server\share\foo"),
+                   Some(PathBuf::from(r"\\server\share\foo")));
+    }
+
+    #[test]
+    fn simplify_other_prefixes() {
+        assert_eq!(simplified(r"C:\foo"), None);
+        assert_eq!(simplified(r"\\.\COM1"), None);
+        assert_eq!(simplified(r"\\?\Volume{00000000-0000-0000-0000-000000000000}\foo"), None);
+    }
+
+    #[test]
+    fn simplify_long_path() {
+        let long = format!(r"\\?\C:\{}", iter::repeat("a").take(300).collect::<String>());
+        assert_eq!(simplified(&long), None);
+        assert!(requires_verbatim(Path::new(&long)));
+    }
+
+    #[test]
+    fn test_requires_verbatim() {
+        assert!(!requires_verbatim(Path::new(r"C:\foo\bar")));
+        assert!(!requires_verbatim(Path::new(r"\\?\C:\foo\bar")));
+        assert!(requires_verbatim(Path::new(r"\\?\C:\aux")));
+        assert!(requires_verbatim(Path::new(r"C:\foo\prn.log")));
+        assert!(requires_verbatim(Path::new(r"C:\foo.")));
+    }
+}
@@ -11,14 +11,16 @@
 use prelude::v1::*;
 
 use alloc::boxed::FnBox;
+use cmp;
 use io;
 use ffi::CStr;
 use mem;
 use libc::c_void;
 use ptr;
-use sys::c;
+use sys::{c, cvt};
 use sys::handle::Handle;
 use sys_common::thread::*;
+use thread::Priority;
 use time::Duration;
 
 pub struct Thread {
@@ -62,6 +64,34 @@ impl Thread {
         // See https://msdn.microsoft.com/en-us/library/xcb2z8hs.aspx
     }
 
+    pub fn set_priority(priority: Priority) -> io::Result<()> {
+        let priority = match priority {
+            Priority::Low => c::THREAD_PRIORITY_BELOW_NORMAL,
+            Priority::Normal => c::THREAD_PRIORITY_NORMAL,
+            Priority::High => c::THREAD_PRIORITY_HIGHEST,
+            Priority::Realtime => c::THREAD_PRIORITY_TIME_CRITICAL,
+        };
+        unsafe {
+            cvt(c::SetThreadPriority(c::GetCurrentThread(), priority)).map(|_| ())
+        }
+    }
+
+    pub fn set_affinity(cpus: &[usize]) -> io::Result<()> {
+        // Only the processors of the current processor group can be chosen
+        // this way, which are at most as many as there are bits in the mask.
+        let mut mask: c::DWORD_PTR = 0;
+        for &cpu in cpus {
+            if cpu >= mem::size_of::<c::DWORD_PTR>() * 8 {
+                return Err(io::Error::new(io::ErrorKind::InvalidInput,
+                                          "CPU index out of range"));
+            }
+            mask |= 1 << cpu;
+        }
+        unsafe {
+            cvt(c::SetThreadAffinityMask(c::GetCurrentThread(), mask)).map(|_| ())
+        }
+    }
+
     pub fn join(self) {
         unsafe { c::WaitForSingleObject(self.handle.raw(), c::INFINITE); }
     }
@@ -84,6 +114,28 @@ impl Thread {
     pub fn into_handle(self) -> Handle { self.handle }
 }
 
+// Windows 8 tells us the whole reservation of the stack, and nothing older
+// does. The reservation ends in a page that is never committed and the guard
+// pages above it, which are as large as the stack guarantee that
+// `stack_overflow::Handler` sets up, so none of those are left to the thread.
+pub fn stack_bounds() -> Option<(usize, usize)> {
+    const PAGE_SIZE: usize = 4096;
+
+    let mut low = 0;
+    let mut high = 0;
+    unsafe { c::GetCurrentThreadStackLimits(&mut low, &mut high); }
+    if high == 0 {
+        return None
+    }
+    // Asking for a guarantee of zero only reads the current one.
+    let mut guarantee = 0;
+    if unsafe { c::SetThreadStackGuarantee(&mut guarantee) } == 0 {
+        guarantee = 0;
+    }
+    let guard = cmp::max(guarantee as usize, PAGE_SIZE);
+    Some((cmp::min(low as usize + PAGE_SIZE + guard, high as usize), high as usize))
+}
+
 #[cfg_attr(test, allow(dead_code))]
 pub mod guard {
     pub unsafe fn current() -> Option<usize> { None }
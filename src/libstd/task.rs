@@ -0,0 +1,179 @@
+// Copyright 2016 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Types and traits for working with asynchronous tasks.
+
+#![unstable(feature = "futures_api", issue = "0")]
+
+pub use core::task::*;
+pub use alloc::task::*;
+
+use future::Future;
+use sync::Arc;
+use thread::{self, Thread};
+
+// Wakes the thread blocked in `block_on`.
+struct ThreadWaker(Thread);
+
+impl Wake for ThreadWaker {
+    fn wake(&self) {
+        self.0.unpark();
+    }
+}
+
+/// Runs a future to completion on the current thread, blocking it until the
+/// future is ready.
+///
+/// The thread is parked whenever the future returns `Poll::Pending`, and
+/// unparked when its waker is woken, from any thread. This is meant for
+/// calling asynchronous code from synchronous code, such as `main` or
+/// tests, and is deliberately minimal: it runs only this one future, does
+/// not spawn anything, and has no I/O reactor or timers, so a future that
+/// relies on one of those to be woken will block forever.
+///
+/// A future that panics propagates the panic to the caller.
+///
+/// # Examples
+///
+/// ```
+/// #![feature(futures_api)]
+///
+/// use std::future::Future;
+/// use std::sync::{Arc, Mutex};
+/// use std::task::{self, Context, Poll, Waker};
+/// use std::thread;
+///
+/// // A value sent from another thread, and the waker of the task waiting
+/// // for it.
+/// struct Slot {
+///     value: Option<u32>,
+///     waker: Option<Waker>,
+/// }
+///
+/// struct Recv(Arc<Mutex<Slot>>);
+///
+/// impl Future for Recv {
+///     type Output = u32;
+///
+///     fn poll(&mut self, cx: &mut Context) -> Poll<u32> {
+///         let mut slot = self.0.lock().unwrap();
+///         match slot.value.take() {
+///             Some(x) => Poll::Ready(x),
+///             None => {
+///                 // Replaces the waker of any earlier poll.
+///                 slot.waker = Some(cx.waker().clone());
+///                 Poll::Pending
+///             }
+///         }
+///     }
+/// }
+///
+/// fn send(slot: &Mutex<Slot>, x: u32) {
+///     let mut slot = slot.lock().unwrap();
+///     slot.value = Some(x);
+///     if let Some(waker) = slot.waker.take() {
+///         waker.wake();
+///     }
+/// }
+///
+/// let slot = Arc::new(Mutex::new(Slot { value: None, waker: None }));
+/// let sender = slot.clone();
+/// thread::spawn(move || send(&sender, 42));
+/// assert_eq!(task::block_on(Recv(slot)), 42);
+/// ```
+pub fn block_on<F: Future>(mut future: F) -> F::Output {
+    let waker = Waker::from(Arc::new(ThreadWaker(thread::current())));
+    let mut cx = Context::from_waker(&waker);
+    loop {
+        match future.poll(&mut cx) {
+            Poll::Ready(output) => return output,
+            // A wakeup before parking is not lost: it makes `park` return
+            // immediately. `park` may also return spuriously, which only
+            // costs an extra poll.
+            Poll::Pending => thread::park(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use prelude::v1::*;
+
+    use future::Future;
+    use sync::atomic::{AtomicUsize, Ordering};
+    use sync::Arc;
+    use thread;
+    use time::Duration;
+    use super::{block_on, Context, Poll};
+
+    struct Countdown(usize);
+
+    impl Future for Countdown {
+        type Output = &'static str;
+
+        fn poll(&mut self, cx: &mut Context) -> Poll<&'static str> {
+            if self.0 == 0 {
+                return Poll::Ready("liftoff")
+            }
+            self.0 -= 1;
+            cx.waker().wake_by_ref();
+            Poll::Pending
+        }
+    }
+
+    #[test]
+    fn ready_immediately() {
+        assert_eq!(block_on(Countdown(0)), "liftoff");
+    }
+
+    #[test]
+    fn woken_by_ref() {
+        assert_eq!(block_on(Countdown(10)), "liftoff");
+    }
+
+    // Ready once another thread has woken it a given number of times.
+    struct Remote {
+        polls: Arc<AtomicUsize>,
+        wakes: usize,
+    }
+
+    impl Future for Remote {
+        type Output = usize;
+
+        fn poll(&mut self, cx: &mut Context) -> Poll<usize> {
+            let polls = self.polls.fetch_add(1, Ordering::SeqCst) + 1;
+            if self.wakes == 0 {
+                return Poll::Ready(polls)
+            }
+            self.wakes -= 1;
+            let waker = cx.waker().clone();
+            thread::spawn(move || {
+                thread::sleep(Duration::from_millis(10));
+                waker.wake();
+            });
+            Poll::Pending
+        }
+    }
+
+    #[test]
+    fn woken_from_other_thread() {
+        let polls = Arc::new(AtomicUsize::new(0));
+        let n = block_on(Remote { polls: polls.clone(), wakes: 3 });
+        assert!(n >= 4);
+        assert_eq!(n, polls.load(Ordering::SeqCst));
+    }
+
+    #[test]
+    fn by_mut_ref_and_box() {
+        let mut countdown = Countdown(2);
+        assert_eq!(block_on(&mut countdown), "liftoff");
+        assert_eq!(block_on(Box::new(Countdown(2))), "liftoff");
+    }
+}
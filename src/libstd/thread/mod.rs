@@ -164,19 +164,23 @@
 use prelude::v1::*;
 
 use any::Any;
-use cell::UnsafeCell;
+use cell::{Cell, UnsafeCell};
 use ffi::{CStr, CString};
 use fmt;
+use intrinsics;
 use io;
 use panic;
 use panicking;
+use ptr;
 use str;
-use sync::{Mutex, Condvar, Arc};
+use sync::{mpsc, Mutex, Condvar, Arc};
 use sys::thread as imp;
+use sys_common::mutex;
 use sys_common::thread_info;
 use sys_common::util;
 use sys_common::{AsInner, IntoInner};
 use time::Duration;
+use u64;
 
 ////////////////////////////////////////////////////////////////////////////////
 // Thread-local storage
@@ -205,6 +209,42 @@ pub struct Builder {
     name: Option<String>,
     // The size of the stack for the spawned thread
     stack_size: Option<usize>,
+    // The scheduling priority of the spawned thread
+    priority: Option<Priority>,
+    // The CPUs that the spawned thread may run on
+    affinity: Option<Vec<usize>>,
+}
+
+/// The scheduling priority of a thread, as set by `Builder::priority`.
+///
+/// The priorities map to the scheduler of each platform as follows:
+///
+/// * On Linux and Android, `Realtime` is the `SCHED_FIFO` policy at the
+///   middle of its priorities, and the others are the `SCHED_OTHER` policy.
+///   `Low` and `High` then use a nice value of 10 or -10 for the thread.
+/// * On other Unix platforms, `Realtime` is the same, and the others are the
+///   lowest, middle and highest priority of the `SCHED_OTHER` policy. Where
+///   that policy has only one priority, `Low` and `High` are not supported.
+/// * On Windows, they are `THREAD_PRIORITY_BELOW_NORMAL`,
+///   `THREAD_PRIORITY_NORMAL`, `THREAD_PRIORITY_HIGHEST` and
+///   `THREAD_PRIORITY_TIME_CRITICAL`.
+///
+/// Raising the priority, and especially real-time scheduling, usually needs
+/// privileges, like `CAP_SYS_NICE` or an `RLIMIT_RTPRIO` limit on Linux.
+#[unstable(feature = "thread_scheduling", issue = "0")]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Priority {
+    /// A lower priority than normal, for background work.
+    Low,
+    /// The priority that threads get by default, without real-time
+    /// scheduling even if the spawning thread has it.
+    Normal,
+    /// A higher priority than normal, among the threads that are not
+    /// real-time.
+    High,
+    /// Real-time scheduling, which runs the thread ahead of all the threads
+    /// that are not real-time whenever it is ready, like for audio.
+    Realtime,
 }
 
 impl Builder {
@@ -215,6 +255,8 @@ impl Builder {
         Builder {
             name: None,
             stack_size: None,
+            priority: None,
+            affinity: None,
         }
     }
 
@@ -248,6 +290,60 @@ impl Builder {
         self
     }
 
+    /// Sets the scheduling priority of the new thread.
+    ///
+    /// See `Priority` for what each priority means on each platform. If the
+    /// priority cannot be set, because it is not supported or not permitted,
+    /// `spawn` fails and the thread never runs its closure.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// #![feature(thread_scheduling)]
+    ///
+    /// use std::thread::{self, Priority};
+    ///
+    /// let builder = thread::Builder::new().priority(Priority::Low);
+    ///
+    /// match builder.spawn(|| 42) {
+    ///     Ok(handle) => assert_eq!(handle.join().unwrap(), 42),
+    ///     Err(e) => println!("cannot lower the priority here: {}", e),
+    /// }
+    /// ```
+    #[unstable(feature = "thread_scheduling", issue = "0")]
+    pub fn priority(mut self, priority: Priority) -> Builder {
+        self.priority = Some(priority);
+        self
+    }
+
+    /// Restricts the new thread to run only on the given CPUs, numbered from
+    /// zero as the operating system numbers them.
+    ///
+    /// This is supported on Linux, Android and Windows, where it may only
+    /// name the CPUs of the thread's processor group. Elsewhere, and if a CPU
+    /// does not exist or the list is empty, `spawn` fails and the thread
+    /// never runs its closure.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// #![feature(thread_scheduling)]
+    ///
+    /// use std::thread;
+    ///
+    /// let builder = thread::Builder::new().affinity(&[0]);
+    ///
+    /// match builder.spawn(|| 42) {
+    ///     Ok(handle) => assert_eq!(handle.join().unwrap(), 42),
+    ///     Err(e) => println!("cannot pin threads here: {}", e),
+    /// }
+    /// ```
+    #[unstable(feature = "thread_scheduling", issue = "0")]
+    pub fn affinity(mut self, cpus: &[usize]) -> Builder {
+        self.affinity = Some(cpus.to_vec());
+        self
+    }
+
     /// Spawns a new thread, and returns a join handle for it.
     ///
     /// The child thread may outlive the parent (unless the parent thread
@@ -259,26 +355,45 @@ impl Builder {
     ///
     /// Unlike the `spawn` free function, this method yields an
     /// `io::Result` to capture any failure to create the thread at
-    /// the OS level.
+    /// the OS level, or to give it the priority or affinity that was asked
+    /// for.
     #[stable(feature = "rust1", since = "1.0.0")]
     pub fn spawn<F, T>(self, f: F) -> io::Result<JoinHandle<T>> where
         F: FnOnce() -> T, F: Send + 'static, T: Send + 'static
     {
-        let Builder { name, stack_size } = self;
+        let Builder { name, stack_size, priority, affinity } = self;
 
         let stack_size = stack_size.unwrap_or(util::min_stack());
 
-        let my_thread = Thread::new(name);
+        let my_thread = Thread::new(name, ThreadId::new());
         let their_thread = my_thread.clone();
 
         let my_packet : Arc<UnsafeCell<Option<Result<T>>>>
             = Arc::new(UnsafeCell::new(None));
         let their_packet = my_packet.clone();
 
+        // The new thread changes its own scheduling before it runs `f`, and
+        // tells us whether that worked, so that it never runs `f` otherwise.
+        let (setup_tx, setup_rx) = if priority.is_some() || affinity.is_some() {
+            let (tx, rx) = mpsc::channel();
+            (Some(tx), Some(rx))
+        } else {
+            (None, None)
+        };
+
         let main = move || {
             if let Some(name) = their_thread.cname() {
                 imp::Thread::set_name(name);
             }
+            if let Some(tx) = setup_tx {
+                let result = set_scheduling(priority, affinity);
+                let failed = result.is_err();
+                let _ = tx.send(result);
+                if failed {
+                    return;
+                }
+            }
+            CURRENT_ID.with(|id| id.set(Some(their_thread.id())));
             unsafe {
                 thread_info::set(imp::guard::current(), their_thread);
                 let try_result = panic::catch_unwind(panic::AssertUnwindSafe(f));
@@ -286,16 +401,36 @@ impl Builder {
             }
         };
 
+        let native = unsafe { imp::Thread::new(stack_size, Box::new(main))? };
+        if let Some(rx) = setup_rx {
+            if let Ok(Err(e)) = rx.recv() {
+                native.join();
+                return Err(e);
+            }
+        }
+
         Ok(JoinHandle(JoinInner {
-            native: unsafe {
-                Some(imp::Thread::new(stack_size, Box::new(main))?)
-            },
+            native: Some(native),
             thread: my_thread,
             packet: Packet(my_packet),
         }))
     }
 }
 
+// Gives the current thread the scheduling that was asked for in a `Builder`.
+fn set_scheduling(priority: Option<Priority>, affinity: Option<Vec<usize>>) -> io::Result<()> {
+    if let Some(priority) = priority {
+        imp::Thread::set_priority(priority)?;
+    }
+    if let Some(cpus) = affinity {
+        if cpus.is_empty() {
+            return Err(io::Error::new(io::ErrorKind::InvalidInput, "no CPUs given"));
+        }
+        imp::Thread::set_affinity(&cpus)?;
+    }
+    Ok(())
+}
+
 ////////////////////////////////////////////////////////////////////////////////
 // Free functions
 ////////////////////////////////////////////////////////////////////////////////
@@ -322,13 +457,56 @@ pub fn spawn<F, T>(f: F) -> JoinHandle<T> where
 }
 
 /// Gets a handle to the thread that invokes it.
+///
+/// This works at any time, including in code that runs before `main` or
+/// while the thread's local data is being destroyed. The handle is created
+/// the first time it is asked for, but in the latter case a new handle
+/// without a name is returned each time, with the same `id`. Use
+/// `current_id` to identify the thread without ever allocating.
 #[stable(feature = "rust1", since = "1.0.0")]
 pub fn current() -> Thread {
-    thread_info::current_thread().expect("use of std::thread::current() is not \
-                                          possible after the thread's local \
-                                          data has been destroyed")
+    thread_info::current_thread().unwrap_or_else(|| Thread::new(None, current_id()))
+}
+
+/// Gets the id of the thread that invokes it, without allocating.
+///
+/// This is the same as `thread::current().id()`, and works at any time like
+/// `thread::current`. Only on platforms without native thread-local storage,
+/// a thread whose local data was already destroyed gets a new id here.
+///
+/// # Examples
+///
+/// ```
+/// #![feature(thread_id)]
+///
+/// use std::thread;
+///
+/// let id = thread::current_id();
+/// assert_eq!(id, thread::current().id());
+///
+/// let other = thread::spawn(|| thread::current_id()).join().unwrap();
+/// assert!(other != id);
+/// ```
+#[unstable(feature = "thread_id", issue = "0")]
+pub fn current_id() -> ThreadId {
+    // As a `Copy` type, this is never destroyed with native thread-local storage.
+    if CURRENT_ID.state() == LocalKeyState::Destroyed {
+        return ThreadId::new();
+    }
+    CURRENT_ID.with(|id| {
+        match id.get() {
+            Some(id) => id,
+            None => {
+                let new = ThreadId::new();
+                id.set(Some(new));
+                new
+            }
+        }
+    })
 }
 
+thread_local! { static CURRENT_ID: Cell<Option<ThreadId>> = Cell::new(None) }
+
 /// Cooperatively gives up a timeslice to the OS scheduler.
 #[stable(feature = "rust1", since = "1.0.0")]
 pub fn yield_now() {
@@ -371,6 +549,115 @@ pub fn panicking() -> bool {
     panicking::panicking()
 }
 
+/// Returns the size in bytes of the current thread's stack, if the platform
+/// can tell.
+///
+/// This is the whole stack, including the part that is already in use. For a
+/// spawned thread it is at least what was asked for with
+/// `Builder::stack_size`, and for the main thread it is usually the limit that
+/// the OS gives it.
+#[unstable(feature = "stack_introspection", issue = "0")]
+pub fn stack_size() -> Option<usize> {
+    stack_bounds().map(|(low, high)| high - low)
+}
+
+/// Returns roughly how many bytes of stack the current thread has left below
+/// the caller's frame, if the platform can tell.
+///
+/// A deeply recursive algorithm can check this now and then to give up, or to
+/// carry on with a stack of its own on the heap, well before it overflows.
+/// It is only an estimate, so leave a margin for the frames between the
+/// checks, and for whatever those frames call.
+///
+/// # Examples
+///
+/// ```
+/// #![feature(stack_introspection)]
+///
+/// use std::thread;
+///
+/// // Counts the opening parentheses, unless there are too many for the stack.
+/// fn nesting(s: &[u8]) -> Option<usize> {
+///     if thread::remaining_stack().map_or(false, |left| left < 64 * 1024) {
+///         return None;
+///     }
+///     match s.first() {
+///         Some(&b'(') => nesting(&s[1..]).map(|n| n + 1),
+///         _ => Some(0),
+///     }
+/// }
+///
+/// assert_eq!(nesting(b"((()))"), Some(3));
+/// ```
+#[unstable(feature = "stack_introspection", issue = "0")]
+pub fn remaining_stack() -> Option<usize> {
+    // The stack grows down on every platform that we support.
+    let here = 0u8;
+    let here = &here as *const u8 as usize;
+    stack_bounds().map(|(low, _)| here.saturating_sub(low))
+}
+
+/// Touches the next `size` bytes of the current thread's stack below the
+/// caller's frame, a page at a time from the top down.
+///
+/// A stack overflow is only noticed when it touches the guard page at the end
+/// of the stack. A frame that is larger than the guard page can skip it and
+/// write to whatever memory lies beyond, so a function that is about to put a
+/// large buffer on the stack can probe for it first: if there is not enough
+/// room, the probe hits the guard page, and the thread is reported as having
+/// overflowed its stack just like with any smaller frame. The probes are 4096
+/// bytes apart, which is no more than the guard page of any platform.
+///
+/// This costs about as much as clearing `size` bytes of memory.
+///
+/// # Examples
+///
+/// ```
+/// #![feature(stack_introspection)]
+///
+/// use std::thread;
+///
+/// thread::probe_stack(128 * 1024);
+/// let buf = [0u8; 128 * 1024];
+/// assert!(buf.iter().all(|&b| b == 0));
+/// ```
+#[unstable(feature = "stack_introspection", issue = "0")]
+pub fn probe_stack(size: usize) {
+    if size > 0 {
+        probe_pages((size - 1) / PROBE_STEP + 1);
+    }
+}
+
+const PROBE_STEP: usize = 4096;
+
+// Each call takes up a page of stack and writes all of it, and the read after
+// the recursive call keeps that from becoming a loop that reuses the frame.
+#[inline(never)]
+fn probe_pages(pages: usize) {
+    let mut page = [0u8; PROBE_STEP];
+    unsafe { intrinsics::volatile_set_memory(page.as_mut_ptr(), 0, PROBE_STEP); }
+    if pages > 1 {
+        probe_pages(pages - 1);
+    }
+    unsafe { ptr::read_volatile(&page[0]); }
+}
+
+// Finding the bounds may be slow, as for the main thread on Linux, where it
+// reads `/proc/self/maps`, so each thread looks them up once.
+fn stack_bounds() -> Option<(usize, usize)> {
+    thread_local! { static STACK_BOUNDS: Cell<Option<(usize, usize)>> = Cell::new(None) }
+
+    if STACK_BOUNDS.state() == LocalKeyState::Destroyed {
+        return imp::stack_bounds();
+    }
+    STACK_BOUNDS.with(|bounds| {
+        if bounds.get().is_none() {
+            bounds.set(imp::stack_bounds());
+        }
+        bounds.get()
+    })
+}
+
 /// Puts the current thread to sleep for the specified amount of time.
 ///
 /// The thread may sleep longer than the duration specified due to scheduling
@@ -489,12 +776,67 @@ pub fn park_timeout(dur: Duration) {
     *guard = false;
 }
 
+////////////////////////////////////////////////////////////////////////////////
+// ThreadId
+////////////////////////////////////////////////////////////////////////////////
+
+/// A unique identifier for a running thread.
+///
+/// Ids are never reused while the process runs, not even after the thread
+/// they identify has finished. They are numbered in the order in which
+/// threads are spawned, or for threads that std did not spawn, in the order
+/// in which they are first asked for their id.
+///
+/// # Examples
+///
+/// ```
+/// #![feature(thread_id)]
+///
+/// use std::thread;
+///
+/// let handle = thread::spawn(|| thread::current().id());
+/// let id = handle.thread().id();
+/// assert_eq!(handle.join().unwrap(), id);
+/// println!("spawned thread #{}", id.as_u64());
+/// ```
+#[unstable(feature = "thread_id", issue = "0")]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ThreadId(u64);
+
+impl ThreadId {
+    // Generates a new unique thread id.
+    fn new() -> ThreadId {
+        static GUARD: mutex::Mutex = mutex::Mutex::new();
+        static mut COUNTER: u64 = 0;
+
+        unsafe {
+            GUARD.lock();
+            if COUNTER == u64::MAX {
+                GUARD.unlock();
+                panic!("failed to generate unique thread ID: bitspace exhausted");
+            }
+            let id = COUNTER;
+            COUNTER += 1;
+            GUARD.unlock();
+            ThreadId(id)
+        }
+    }
+
+    /// Returns the id as a number, like for logs to be correlated with other
+    /// systems. No two threads of a process have the same number.
+    #[unstable(feature = "thread_id", issue = "0")]
+    pub fn as_u64(&self) -> u64 {
+        self.0
+    }
+}
+
 ////////////////////////////////////////////////////////////////////////////////
 // Thread
 ////////////////////////////////////////////////////////////////////////////////
 
 /// The internal representation of a `Thread` handle
 struct Inner {
+    id: ThreadId,
     name: Option<CString>,      // Guaranteed to be UTF-8
     lock: Mutex<bool>,          // true when there is a buffered unpark
     cvar: Condvar,
@@ -509,12 +851,13 @@ pub struct Thread {
 
 impl Thread {
     // Used only internally to construct a thread object without spawning
-    fn new(name: Option<String>) -> Thread {
+    fn new(name: Option<String>, id: ThreadId) -> Thread {
         let cname = name.map(|n| {
             CString::new(n).expect("thread name may not contain interior null bytes")
         });
         Thread {
             inner: Arc::new(Inner {
+                id: id,
                 name: cname,
                 lock: Mutex::new(false),
                 cvar: Condvar::new(),
@@ -534,6 +877,23 @@ impl Thread {
         }
     }
 
+    /// Gets the thread's unique identifier.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(thread_id)]
+    ///
+    /// use std::thread;
+    ///
+    /// let other = thread::spawn(|| thread::current().id());
+    /// assert!(thread::current().id() != other.join().unwrap());
+    /// ```
+    #[unstable(feature = "thread_id", issue = "0")]
+    pub fn id(&self) -> ThreadId {
+        self.inner.id
+    }
+
     /// Gets the thread's name.
     ///
     /// # Examples
@@ -585,7 +945,7 @@ impl fmt::Debug for Thread {
 
 // a hack to get around privacy restrictions
 impl thread_info::NewThread for Thread {
-    fn new(name: Option<String>) -> Thread { Thread::new(name) }
+    fn new(name: Option<String>) -> Thread { Thread::new(name, current_id()) }
 }
 
 ////////////////////////////////////////////////////////////////////////////////
@@ -711,9 +1071,10 @@ mod tests {
     use prelude::v1::*;
 
     use any::Any;
+    use io;
     use sync::mpsc::{channel, Sender};
     use result;
-    use super::{Builder};
+    use super::{Builder, Priority};
     use thread;
     use time::Duration;
     use u32;
@@ -735,6 +1096,73 @@ mod tests {
         }).unwrap().join().unwrap();
     }
 
+    #[test]
+    fn test_stack_introspection() {
+        // Uses `here` after the recursive call, so that each call keeps a frame
+        // of its own instead of becoming a loop.
+        fn recurse(depth: usize, left: usize) -> usize {
+            let here = thread::remaining_stack().unwrap();
+            assert!(here < left);
+            let deepest = if depth > 0 { recurse(depth - 1, here) } else { here };
+            assert!(deepest <= here);
+            deepest
+        }
+
+        Builder::new().stack_size(1 << 20).spawn(|| {
+            let size = match thread::stack_size() {
+                Some(size) => size,
+                None => return,
+            };
+            assert!(size >= 1 << 20);
+            let left = thread::remaining_stack().unwrap();
+            assert!(0 < left && left < size);
+            assert!(recurse(10, left) < left);
+            thread::probe_stack(256 * 1024);
+            thread::probe_stack(0);
+        }).unwrap().join().unwrap();
+    }
+
+    #[test]
+    fn test_thread_id() {
+        let main = thread::current().id();
+        assert_eq!(thread::current_id(), main);
+        assert_eq!(thread::current().id(), main);
+
+        let handle = thread::spawn(|| (thread::current_id(), thread::current().id()));
+        let id = handle.thread().id();
+        assert!(id != main && id.as_u64() != main.as_u64());
+        assert_eq!(handle.join().unwrap(), (id, id));
+    }
+
+    #[test]
+    fn test_current_in_tls_destructor() {
+        struct Foo;
+        impl Drop for Foo {
+            fn drop(&mut self) {
+                let _ = thread::current().name();
+                let _ = thread::current_id();
+            }
+        }
+        thread_local!(static FOO: Foo = Foo);
+
+        thread::spawn(|| FOO.with(|_| ())).join().unwrap();
+    }
+
+    #[test]
+    fn test_normal_priority() {
+        let handle = Builder::new().priority(Priority::Normal).spawn(|| 1).unwrap();
+        assert_eq!(handle.join().unwrap(), 1);
+    }
+
+    #[test]
+    fn test_empty_affinity() {
+        let (tx, rx) = channel();
+        let err = Builder::new().affinity(&[]).spawn(move|| tx.send(()).unwrap()).err().unwrap();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidInput);
+        // the closure was dropped without running
+        assert!(rx.recv().is_err());
+    }
+
     #[test]
     #[should_panic]
     fn test_invalid_named_thread() {
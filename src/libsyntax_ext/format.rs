@@ -579,6 +579,8 @@ impl<'a, 'b> Context<'a, 'b> {
                     "?" => "Debug",
                     "e" => "LowerExp",
                     "E" => "UpperExp",
+                    "g" => "LowerGeneral",
+                    "G" => "UpperGeneral",
                     "o" => "Octal",
                     "p" => "Pointer",
                     "b" => "Binary",
@@ -0,0 +1,39 @@
+// Copyright 2016 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// compile-flags: -C no-prepopulate-passes
+
+// The intrinsics behind the branch hints in `core::hint`.
+
+#![crate_type = "lib"]
+#![feature(core_intrinsics)]
+
+use std::intrinsics::{assume, likely, unlikely};
+
+// CHECK-LABEL: @check_likely
+#[no_mangle]
+pub fn check_likely(x: u32) -> u32 {
+// CHECK: call i1 @llvm.expect.i1(i1 %{{.*}}, i1 true)
+    if unsafe { likely(x == 1) } { 10 } else { 20 }
+}
+
+// CHECK-LABEL: @check_unlikely
+#[no_mangle]
+pub fn check_unlikely(x: u32) -> u32 {
+// CHECK: call i1 @llvm.expect.i1(i1 %{{.*}}, i1 false)
+    if unsafe { unlikely(x == 1) } { 10 } else { 20 }
+}
+
+// CHECK-LABEL: @check_assume
+#[no_mangle]
+pub fn check_assume(x: u32) {
+// CHECK: call void @llvm.assume(i1 %{{.*}})
+    unsafe { assume(x > 1) }
+}
@@ -0,0 +1,64 @@
+// Copyright 2016 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#![feature(const_fn, panic_info_message)]
+
+use std::fmt;
+use std::panic;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::thread;
+
+#[derive(Debug, PartialEq)]
+struct MyError {
+    code: u32,
+}
+
+// Counts how many times it is formatted.
+struct Counted;
+
+static FORMATS: AtomicUsize = AtomicUsize::new(0);
+
+impl fmt::Display for Counted {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        FORMATS.fetch_add(1, Ordering::SeqCst);
+        f.write_str("counted")
+    }
+}
+
+static HOOK_CALLS: AtomicUsize = AtomicUsize::new(0);
+
+fn main() {
+    panic::set_hook(Box::new(|info| {
+        let message = info.message().map(|m| m.to_string());
+        match info.payload().downcast_ref::<MyError>() {
+            Some(err) => {
+                assert_eq!(err.code, 7);
+                assert_eq!(message, None);
+            }
+            None => {
+                let message = message.unwrap();
+                assert!(message == "formatted 1" || message == "literal" ||
+                        message == "owned" || message == "formatted counted",
+                        "unexpected message {:?}", message);
+            }
+        }
+        HOOK_CALLS.fetch_add(1, Ordering::SeqCst);
+    }));
+
+    let _ = thread::spawn(|| panic!("formatted {}", 1)).join();
+    let _ = thread::spawn(|| panic!("formatted {}", Counted)).join();
+    assert_eq!(FORMATS.load(Ordering::SeqCst), 1);
+    let _ = thread::spawn(|| panic!("literal")).join();
+    let _ = thread::spawn(|| panic!(String::from("owned"))).join();
+    let err = thread::spawn(|| panic!(MyError { code: 7 })).join().unwrap_err();
+    assert_eq!(err.downcast_ref::<MyError>(), Some(&MyError { code: 7 }));
+
+    assert_eq!(HOOK_CALLS.load(Ordering::SeqCst), 5);
+}
@@ -0,0 +1,365 @@
+// Copyright 2016 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// Checks the AES, carry-less multiplication and AVX-512 platform intrinsics
+// against scalar versions of the same operations. Each group is only checked
+// when the target features that it needs are enabled at compile time.
+
+#![feature(repr_simd, platform_intrinsics, cfg_target_feature)]
+#![allow(dead_code, non_camel_case_types)]
+
+// The inputs: a few patterns and some pseudo-random bytes.
+fn blocks() -> Vec<[u8; 16]> {
+    let mut blocks = vec![[0; 16], [0xff; 16]];
+    let mut block = [0; 16];
+    for i in 0..16 {
+        block[i] = i as u8;
+    }
+    blocks.push(block);
+    let mut x = 0x2545f491u32;
+    for _ in 0..16 {
+        for b in &mut block {
+            x ^= x << 13;
+            x ^= x >> 17;
+            x ^= x << 5;
+            *b = x as u8;
+        }
+        blocks.push(block);
+    }
+    blocks
+}
+
+fn xtime(b: u8) -> u8 {
+    (b << 1) ^ if b & 0x80 != 0 { 0x1b } else { 0 }
+}
+
+fn gmul(mut a: u8, mut b: u8) -> u8 {
+    let mut p = 0;
+    while b != 0 {
+        if b & 1 != 0 {
+            p ^= a;
+        }
+        a = xtime(a);
+        b >>= 1;
+    }
+    p
+}
+
+fn sbox(b: u8) -> u8 {
+    // the multiplicative inverse is b^254, and 0 maps to 0
+    let mut inv = if b == 0 { 0 } else { 1 };
+    for _ in 0..254 {
+        inv = gmul(inv, b);
+    }
+    inv ^ inv.rotate_left(1) ^ inv.rotate_left(2) ^ inv.rotate_left(3) ^
+        inv.rotate_left(4) ^ 0x63
+}
+
+fn inv_sbox(b: u8) -> u8 {
+    (0..256).map(|x| x as u8).find(|&x| sbox(x) == b).unwrap()
+}
+
+// The state is in columns, so byte `4 * c + r` is in row `r` and column `c`.
+fn sub_bytes(s: [u8; 16]) -> [u8; 16] {
+    let mut out = [0; 16];
+    for i in 0..16 {
+        out[i] = sbox(s[i]);
+    }
+    out
+}
+
+fn inv_sub_bytes(s: [u8; 16]) -> [u8; 16] {
+    let mut out = [0; 16];
+    for i in 0..16 {
+        out[i] = inv_sbox(s[i]);
+    }
+    out
+}
+
+fn shift_rows(s: [u8; 16]) -> [u8; 16] {
+    let mut out = [0; 16];
+    for r in 0..4 {
+        for c in 0..4 {
+            out[r + 4 * c] = s[r + 4 * ((c + r) % 4)];
+        }
+    }
+    out
+}
+
+fn inv_shift_rows(s: [u8; 16]) -> [u8; 16] {
+    let mut out = [0; 16];
+    for r in 0..4 {
+        for c in 0..4 {
+            out[r + 4 * ((c + r) % 4)] = s[r + 4 * c];
+        }
+    }
+    out
+}
+
+fn mix(s: [u8; 16], m: [u8; 4]) -> [u8; 16] {
+    let mut out = [0; 16];
+    for c in 0..4 {
+        for r in 0..4 {
+            let mut b = 0;
+            for i in 0..4 {
+                b ^= gmul(s[4 * c + i], m[(i + 4 - r) % 4]);
+            }
+            out[4 * c + r] = b;
+        }
+    }
+    out
+}
+
+fn mix_columns(s: [u8; 16]) -> [u8; 16] {
+    mix(s, [2, 3, 1, 1])
+}
+
+fn inv_mix_columns(s: [u8; 16]) -> [u8; 16] {
+    mix(s, [14, 11, 13, 9])
+}
+
+fn xor(a: [u8; 16], b: [u8; 16]) -> [u8; 16] {
+    let mut out = [0; 16];
+    for i in 0..16 {
+        out[i] = a[i] ^ b[i];
+    }
+    out
+}
+
+// Returns the low and the high half of the carry-less product.
+fn clmul(a: u64, b: u64) -> (u64, u64) {
+    let (mut lo, mut hi) = (0, 0);
+    for i in 0..64 {
+        if b >> i & 1 != 0 {
+            lo ^= a << i;
+            if i > 0 {
+                hi ^= a >> (64 - i);
+            }
+        }
+    }
+    (lo, hi)
+}
+
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+mod x86 {
+    use std::mem;
+    use super::{blocks, clmul, xor};
+    use super::{sub_bytes, inv_sub_bytes, shift_rows, inv_shift_rows};
+    use super::{mix_columns, inv_mix_columns};
+
+    #[repr(simd)]
+    #[derive(Copy, Clone)]
+    pub struct i64x2(i64, i64);
+
+    #[repr(simd)]
+    #[derive(Copy, Clone)]
+    pub struct i32x16(i32, i32, i32, i32, i32, i32, i32, i32,
+                      i32, i32, i32, i32, i32, i32, i32, i32);
+
+    #[repr(simd)]
+    #[derive(Copy, Clone)]
+    pub struct u32x16(u32, u32, u32, u32, u32, u32, u32, u32,
+                      u32, u32, u32, u32, u32, u32, u32, u32);
+
+    #[repr(simd)]
+    #[derive(Copy, Clone)]
+    pub struct i64x8(i64, i64, i64, i64, i64, i64, i64, i64);
+
+    #[repr(simd)]
+    #[derive(Copy, Clone)]
+    pub struct u64x8(u64, u64, u64, u64, u64, u64, u64, u64);
+
+    #[repr(simd)]
+    #[derive(Copy, Clone)]
+    pub struct u8x64(u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8,
+                     u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8,
+                     u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8,
+                     u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8);
+
+    extern "platform-intrinsic" {
+        fn x86_mm_aesenc_si128(x: i64x2, k: i64x2) -> i64x2;
+        fn x86_mm_aesenclast_si128(x: i64x2, k: i64x2) -> i64x2;
+        fn x86_mm_aesdec_si128(x: i64x2, k: i64x2) -> i64x2;
+        fn x86_mm_aesdeclast_si128(x: i64x2, k: i64x2) -> i64x2;
+        fn x86_mm_aesimc_si128(x: i64x2) -> i64x2;
+        fn x86_mm_clmulepi64_si128(x: i64x2, y: i64x2, imm: i32) -> i64x2;
+        fn x86_mm512_mul_epi32(x: i32x16, y: i32x16) -> i64x8;
+        fn x86_mm512_mul_epu32(x: u32x16, y: u32x16) -> u64x8;
+        fn x86_mm512_sad_epu8(x: u8x64, y: u8x64) -> u64x8;
+    }
+
+    fn v(b: [u8; 16]) -> i64x2 { unsafe { mem::transmute(b) } }
+    fn b(v: i64x2) -> [u8; 16] { unsafe { mem::transmute(v) } }
+
+    #[cfg(target_feature = "aes")]
+    pub fn aes() {
+        for &s in &blocks() {
+            for &k in &blocks() {
+                unsafe {
+                    assert_eq!(b(x86_mm_aesenc_si128(v(s), v(k))),
+                               xor(mix_columns(shift_rows(sub_bytes(s))), k));
+                    assert_eq!(b(x86_mm_aesenclast_si128(v(s), v(k))),
+                               xor(shift_rows(sub_bytes(s)), k));
+                    assert_eq!(b(x86_mm_aesdec_si128(v(s), v(k))),
+                               xor(inv_mix_columns(inv_shift_rows(inv_sub_bytes(s))), k));
+                    assert_eq!(b(x86_mm_aesdeclast_si128(v(s), v(k))),
+                               xor(inv_shift_rows(inv_sub_bytes(s)), k));
+                }
+            }
+            unsafe {
+                assert_eq!(b(x86_mm_aesimc_si128(v(s))), inv_mix_columns(s));
+            }
+        }
+    }
+    #[cfg(not(target_feature = "aes"))]
+    pub fn aes() {}
+
+    #[cfg(target_feature = "pclmul")]
+    pub fn pclmul() {
+        for &x in &blocks() {
+            for &y in &blocks() {
+                let xs: [u64; 2] = unsafe { mem::transmute(x) };
+                let ys: [u64; 2] = unsafe { mem::transmute(y) };
+                let check = |r: i64x2, i: usize, j: usize| {
+                    let (lo, hi) = clmul(xs[i], ys[j]);
+                    let r: [u64; 2] = unsafe { mem::transmute(r) };
+                    assert_eq!(r, [lo, hi]);
+                };
+                // the immediate has to be a constant
+                unsafe {
+                    check(x86_mm_clmulepi64_si128(v(x), v(y), 0x00), 0, 0);
+                    check(x86_mm_clmulepi64_si128(v(x), v(y), 0x01), 1, 0);
+                    check(x86_mm_clmulepi64_si128(v(x), v(y), 0x10), 0, 1);
+                    check(x86_mm_clmulepi64_si128(v(x), v(y), 0x11), 1, 1);
+                }
+            }
+        }
+    }
+    #[cfg(not(target_feature = "pclmul"))]
+    pub fn pclmul() {}
+
+    #[cfg(all(target_feature = "avx512f", target_feature = "avx512bw"))]
+    pub fn avx512() {
+        let blocks = blocks();
+        for w in blocks.windows(4) {
+            let mut x = [0u8; 64];
+            let mut y = [0u8; 64];
+            for i in 0..4 {
+                x[16 * i..16 * (i + 1)].copy_from_slice(&w[i]);
+                y[16 * i..16 * (i + 1)].copy_from_slice(&w[3 - i]);
+            }
+
+            let xu: [u32; 16] = unsafe { mem::transmute(x) };
+            let yu: [u32; 16] = unsafe { mem::transmute(y) };
+            let r: [u64; 8] = unsafe {
+                mem::transmute(x86_mm512_mul_epu32(mem::transmute(x), mem::transmute(y)))
+            };
+            for i in 0..8 {
+                assert_eq!(r[i], xu[2 * i] as u64 * yu[2 * i] as u64);
+            }
+
+            let xi: [i32; 16] = unsafe { mem::transmute(x) };
+            let yi: [i32; 16] = unsafe { mem::transmute(y) };
+            let r: [i64; 8] = unsafe {
+                mem::transmute(x86_mm512_mul_epi32(mem::transmute(x), mem::transmute(y)))
+            };
+            for i in 0..8 {
+                assert_eq!(r[i], xi[2 * i] as i64 * yi[2 * i] as i64);
+            }
+
+            let r: [u64; 8] = unsafe {
+                mem::transmute(x86_mm512_sad_epu8(mem::transmute(x), mem::transmute(y)))
+            };
+            for i in 0..8 {
+                let sad = (8 * i..8 * (i + 1)).map(|j| {
+                    (x[j] as i32 - y[j] as i32).abs() as u64
+                }).sum::<u64>();
+                assert_eq!(r[i], sad);
+            }
+        }
+    }
+    #[cfg(not(all(target_feature = "avx512f", target_feature = "avx512bw")))]
+    pub fn avx512() {}
+
+    pub fn main() {
+        aes();
+        pclmul();
+        avx512();
+    }
+}
+
+#[cfg(target_arch = "aarch64")]
+mod aarch64 {
+    use std::mem;
+    use super::{blocks, xor};
+    use super::{sub_bytes, inv_sub_bytes, shift_rows, inv_shift_rows};
+    use super::{mix_columns, inv_mix_columns};
+
+    #[repr(simd)]
+    #[derive(Copy, Clone)]
+    pub struct u8x16(u8, u8, u8, u8, u8, u8, u8, u8,
+                     u8, u8, u8, u8, u8, u8, u8, u8);
+
+    extern "platform-intrinsic" {
+        fn aarch64_vaeseq_u8(x: u8x16, k: u8x16) -> u8x16;
+        fn aarch64_vaesdq_u8(x: u8x16, k: u8x16) -> u8x16;
+        fn aarch64_vaesmcq_u8(x: u8x16) -> u8x16;
+        fn aarch64_vaesimcq_u8(x: u8x16) -> u8x16;
+    }
+
+    fn v(b: [u8; 16]) -> u8x16 { unsafe { mem::transmute(b) } }
+    fn b(v: u8x16) -> [u8; 16] { unsafe { mem::transmute(v) } }
+
+    // Unlike on x86, the round key is added first, and the column mixing is
+    // an instruction of its own.
+    #[cfg(target_feature = "crypto")]
+    pub fn main() {
+        for &s in &blocks() {
+            for &k in &blocks() {
+                unsafe {
+                    assert_eq!(b(aarch64_vaeseq_u8(v(s), v(k))),
+                               shift_rows(sub_bytes(xor(s, k))));
+                    assert_eq!(b(aarch64_vaesdq_u8(v(s), v(k))),
+                               inv_shift_rows(inv_sub_bytes(xor(s, k))));
+                }
+            }
+            unsafe {
+                assert_eq!(b(aarch64_vaesmcq_u8(v(s))), mix_columns(s));
+                assert_eq!(b(aarch64_vaesimcq_u8(v(s))), inv_mix_columns(s));
+            }
+        }
+    }
+    #[cfg(not(target_feature = "crypto"))]
+    pub fn main() {}
+}
+
+fn main() {
+    // the scalar versions themselves, on the examples of FIPS-197
+    assert_eq!(sbox(0x53), 0xed);
+    assert_eq!(inv_sbox(0xed), 0x53);
+    let s = [0xdb, 0x13, 0x53, 0x45, 0xf2, 0x0a, 0x22, 0x5c,
+             0x01, 0x01, 0x01, 0x01, 0xc6, 0xc6, 0xc6, 0xc6];
+    let m = [0x8e, 0x4d, 0xa1, 0xbc, 0x9f, 0xdc, 0x58, 0x9d,
+             0x01, 0x01, 0x01, 0x01, 0xc6, 0xc6, 0xc6, 0xc6];
+    assert_eq!(mix_columns(s), m);
+    assert_eq!(inv_mix_columns(m), s);
+    assert_eq!(inv_shift_rows(shift_rows(blocks()[2])), blocks()[2]);
+    assert_eq!(clmul(3, 3), (5, 0));
+    assert_eq!(clmul(1 << 63, 2), (0, 1));
+
+    arch_main();
+}
+
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+use x86::main as arch_main;
+#[cfg(target_arch = "aarch64")]
+use aarch64::main as arch_main;
+#[cfg(not(any(target_arch = "x86", target_arch = "x86_64", target_arch = "aarch64")))]
+fn arch_main() {}
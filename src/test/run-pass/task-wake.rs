@@ -0,0 +1,64 @@
+// Copyright 2016 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#![feature(arc_counts, futures_api, local_waker, rc_counts)]
+
+use std::cell::Cell;
+use std::rc::Rc;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::task::{Context, LocalWake, LocalWaker, Wake, Waker};
+use std::thread;
+
+struct Counter(AtomicUsize);
+
+impl Wake for Counter {
+    fn wake(&self) {
+        self.0.fetch_add(1, Ordering::SeqCst);
+    }
+}
+
+struct LocalCounter(Cell<usize>);
+
+impl LocalWake for LocalCounter {
+    fn wake(&self) {
+        self.0.set(self.0.get() + 1);
+    }
+}
+
+fn main() {
+    let counter = Arc::new(Counter(AtomicUsize::new(0)));
+    let waker = Waker::from(counter.clone());
+    assert_eq!(Arc::strong_count(&counter), 2);
+
+    waker.wake_by_ref();
+    // The waker holds on to the `Arc` itself, so a clone is one more reference.
+    let clone = waker.clone();
+    assert_eq!(Arc::strong_count(&counter), 3);
+    thread::spawn(move || clone.wake()).join().unwrap();
+    assert_eq!(counter.0.load(Ordering::SeqCst), 2);
+    assert_eq!(Arc::strong_count(&counter), 2);
+
+    let local_counter = Rc::new(LocalCounter(Cell::new(0)));
+    let local_waker = LocalWaker::from(local_counter.clone());
+    {
+        let cx = Context::with_local_waker(&waker, &local_waker);
+        cx.local_waker().wake_by_ref();
+        cx.local_waker().clone().wake();
+        cx.waker().wake_by_ref();
+    }
+    assert_eq!(local_counter.0.get(), 2);
+    assert_eq!(counter.0.load(Ordering::SeqCst), 3);
+
+    drop(waker);
+    drop(local_waker);
+    assert_eq!(Arc::strong_count(&counter), 1);
+    assert_eq!(Rc::strong_count(&local_counter), 1);
+}
@@ -0,0 +1,87 @@
+// Copyright 2016 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#![feature(convert_infallible, question_mark, try_trait_v2)]
+
+use std::ops::{ControlFlow, FromResidual, Try};
+
+#[derive(Debug, PartialEq)]
+enum Status {
+    Good(u8),
+    Bad(&'static str),
+}
+
+struct BadStatus(&'static str);
+
+impl Try for Status {
+    type Output = u8;
+    type Residual = BadStatus;
+
+    fn from_output(output: u8) -> Status {
+        Status::Good(output)
+    }
+
+    fn branch(self) -> ControlFlow<BadStatus, u8> {
+        match self {
+            Status::Good(x) => ControlFlow::Continue(x),
+            Status::Bad(why) => ControlFlow::Break(BadStatus(why)),
+        }
+    }
+}
+
+impl FromResidual<BadStatus> for Status {
+    fn from_residual(residual: BadStatus) -> Status {
+        Status::Bad(residual.0)
+    }
+}
+
+// A custom type can accept the residual of other types, too.
+impl<E> FromResidual<Result<std::convert::Infallible, E>> for Status {
+    fn from_residual(_: Result<std::convert::Infallible, E>) -> Status {
+        Status::Bad("result")
+    }
+}
+
+fn add(a: Status, b: Status) -> Status {
+    Status::Good(a? + b?)
+}
+
+fn parse(s: &str) -> Status {
+    Status::Good(s.parse::<u8>()?)
+}
+
+fn first_even(xs: &[u32]) -> Option<u32> {
+    let x = *xs.first()?;
+    if x % 2 == 0 { Some(x) } else { first_even(&xs[1..]) }
+}
+
+fn visit(xs: &[i32]) -> ControlFlow<i32> {
+    for &x in xs {
+        if x < 0 {
+            ControlFlow::Break(x)?;
+        }
+    }
+    ControlFlow::Continue(())
+}
+
+fn main() {
+    assert_eq!(add(Status::Good(1), Status::Good(2)), Status::Good(3));
+    assert_eq!(add(Status::Bad("a"), Status::Good(2)), Status::Bad("a"));
+    assert_eq!(add(Status::Good(1), Status::Bad("b")), Status::Bad("b"));
+
+    assert_eq!(parse("7"), Status::Good(7));
+    assert_eq!(parse("x"), Status::Bad("result"));
+
+    assert_eq!(first_even(&[1, 3, 4, 5]), Some(4));
+    assert_eq!(first_even(&[1, 3]), None);
+
+    assert_eq!(visit(&[1, 2, 3]), ControlFlow::Continue(()));
+    assert_eq!(visit(&[1, -2, -3]), ControlFlow::Break(-2));
+}
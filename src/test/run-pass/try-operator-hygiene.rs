@@ -10,13 +10,13 @@
 
 // `expr?` expands to:
 //
-// match expr {
-//     Ok(val) => val,
-//     Err(err) => return Err(From::from(err)),
+// match Try::branch(expr) {
+//     ControlFlow::Continue(val) => val,
+//     ControlFlow::Break(residual) => return FromResidual::from_residual(residual),
 // }
 //
 // This test verifies that the expansion is hygienic, i.e. it's not affected by other `val` and
-// `err` bindings that may be in scope.
+// `residual` bindings that may be in scope.
 
 #![feature(question_mark)]
 
@@ -28,7 +28,7 @@ fn main() {
 
 fn parse() -> Result<i32, ParseIntError> {
     const val: char = 'a';
-    const err: char = 'b';
+    const residual: char = 'b';
 
     Ok("1".parse::<i32>()?)
 }